@@ -67,3 +67,68 @@ fn encode_upper(b: &mut Bencher) {
     });
     b.bytes = input.len() as u64;
 }
+
+#[bench]
+fn decode_lower_vartime(b: &mut Bencher) {
+    let input = vec![b'1'; 1 << 14];
+    let mut buf = vec![0u8; 1 << 13];
+
+    b.iter(|| {
+        let input = black_box(&input[..]);
+        let res = base16ct::lower::decode_vartime(input, &mut buf).unwrap();
+        black_box(res);
+    });
+    b.bytes = input.len() as u64;
+}
+
+#[bench]
+fn decode_upper_vartime(b: &mut Bencher) {
+    let input = vec![b'1'; 1 << 14];
+    let mut buf = vec![0u8; 1 << 13];
+
+    b.iter(|| {
+        let input = black_box(&input[..]);
+        let res = base16ct::upper::decode_vartime(input, &mut buf).unwrap();
+        black_box(res);
+    });
+    b.bytes = input.len() as u64;
+}
+
+#[bench]
+fn decode_mixed_vartime(b: &mut Bencher) {
+    let input = vec![b'1'; 1 << 14];
+    let mut buf = vec![0u8; 1 << 13];
+
+    b.iter(|| {
+        let input = black_box(&input[..]);
+        let res = base16ct::mixed::decode_vartime(input, &mut buf).unwrap();
+        black_box(res);
+    });
+    b.bytes = input.len() as u64;
+}
+
+#[bench]
+fn encode_lower_vartime(b: &mut Bencher) {
+    let input = vec![0x42; 1 << 14];
+    let mut buf = vec![0u8; 1 << 15];
+
+    b.iter(|| {
+        let input = black_box(&input[..]);
+        let res = base16ct::lower::encode_vartime(input, &mut buf).unwrap();
+        black_box(res);
+    });
+    b.bytes = input.len() as u64;
+}
+
+#[bench]
+fn encode_upper_vartime(b: &mut Bencher) {
+    let input = vec![0x42; 1 << 14];
+    let mut buf = vec![0u8; 1 << 15];
+
+    b.iter(|| {
+        let input = black_box(&input[..]);
+        let res = base16ct::upper::encode_vartime(input, &mut buf).unwrap();
+        black_box(res);
+    });
+    b.bytes = input.len() as u64;
+}
@@ -70,6 +70,8 @@ pub mod upper;
 mod display;
 /// Error types.
 mod error;
+/// SIMD-accelerated encoding and decoding.
+mod simd;
 
 pub use crate::{
     display::HexDisplay,
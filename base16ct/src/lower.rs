@@ -1,12 +1,32 @@
-use crate::{decode_inner, encoded_len, Error};
+use crate::{
+    decode_inner, decoded_len, encoded_len,
+    simd::{self, Case},
+    Error,
+};
 #[cfg(feature = "alloc")]
-use crate::{decoded_len, String, Vec};
+use crate::{String, Vec};
 
 /// Decode a lower Base16 (hex) string into the provided destination buffer.
 pub fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error> {
     decode_inner(src.as_ref(), dst, decode_nibble)
 }
 
+/// Decode a lower Base16 (hex) string into the provided destination buffer
+/// using SIMD instructions where available.
+///
+/// WARNING: this function is **not** constant-time and MUST NOT be used to
+/// decode secrets. It is intended for large inputs containing public data.
+/// Use [`decode`] for secrets.
+pub fn decode_vartime(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error> {
+    let src = src.as_ref();
+    let dst = dst
+        .get_mut(..decoded_len(src)?)
+        .ok_or(Error::InvalidLength)?;
+    let len = simd::decode(src, dst, Case::Lower)?;
+    decode(&src[(len * 2)..], &mut dst[len..])?;
+    Ok(dst)
+}
+
 /// Decode a lower Base16 (hex) string into a byte vector.
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
@@ -31,6 +51,21 @@ pub fn encode<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a [u8], Error> {
     Ok(dst)
 }
 
+/// Encode the input byte slice as lower Base16 using SIMD instructions
+/// where available.
+///
+/// WARNING: this function is **not** constant-time and MUST NOT be used to
+/// encode secrets. It is intended for large inputs containing public data.
+/// Use [`encode`] for secrets.
+pub fn encode_vartime<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a [u8], Error> {
+    let dst = dst
+        .get_mut(..encoded_len(src))
+        .ok_or(Error::InvalidLength)?;
+    let len = simd::encode(src, dst, false);
+    encode(&src[len..], &mut dst[(len * 2)..])?;
+    Ok(dst)
+}
+
 /// Encode input byte slice into a [`&str`] containing lower Base16 (hex).
 pub fn encode_str<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a str, Error> {
     encode(src, dst).map(|r| unsafe { core::str::from_utf8_unchecked(r) })
@@ -1,12 +1,32 @@
-use crate::{decode_inner, Error};
 #[cfg(feature = "alloc")]
-use crate::{decoded_len, Vec};
+use crate::Vec;
+use crate::{
+    decode_inner, decoded_len,
+    simd::{self, Case},
+    Error,
+};
 
 /// Decode a mixed Base16 (hex) string into the provided destination buffer.
 pub fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error> {
     decode_inner(src.as_ref(), dst, decode_nibble)
 }
 
+/// Decode a mixed Base16 (hex) string into the provided destination buffer
+/// using SIMD instructions where available.
+///
+/// WARNING: this function is **not** constant-time and MUST NOT be used to
+/// decode secrets. It is intended for large inputs containing public data.
+/// Use [`decode`] for secrets.
+pub fn decode_vartime(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error> {
+    let src = src.as_ref();
+    let dst = dst
+        .get_mut(..decoded_len(src)?)
+        .ok_or(Error::InvalidLength)?;
+    let len = simd::decode(src, dst, Case::Mixed)?;
+    decode(&src[(len * 2)..], &mut dst[len..])?;
+    Ok(dst)
+}
+
 /// Decode a mixed Base16 (hex) string into a byte vector.
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
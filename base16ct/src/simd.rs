@@ -0,0 +1,273 @@
+//! SIMD-accelerated Base16 (hex) for non-secret data.
+//!
+//! These functions process the bulk of their input using SIMD instructions
+//! where the CPU supports them, returning how much of the input they consumed
+//! so the caller can finish the remainder using the scalar implementation.
+//!
+//! SSSE3 is used on x86/x86_64 when detected at runtime. NEON is used on
+//! AArch64 when the `neon` feature is enabled, which requires Rust 1.59.
+
+use crate::Error;
+
+/// Cases accepted when decoding.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Case {
+    /// Lower case (`a-f`).
+    Lower,
+
+    /// Upper case (`A-F`).
+    Upper,
+
+    /// Either case.
+    Mixed,
+}
+
+/// Encode as many 16-byte blocks of `src` into `dst` as possible.
+///
+/// Returns the number of bytes of `src` which were encoded. The number of
+/// bytes written to `dst` is twice this value.
+#[inline]
+pub(crate) fn encode(src: &[u8], dst: &mut [u8], upper: bool) -> usize {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if x86::has_ssse3() {
+            // SAFETY: SSSE3 support was checked above
+            return unsafe { x86::encode(src, dst, upper) };
+        }
+    }
+
+    // NEON is part of the baseline of most AArch64 targets, so it's detected
+    // at compile time. It's opt-in as the intrinsics require Rust 1.59.
+    #[cfg(all(feature = "neon", target_arch = "aarch64", target_feature = "neon"))]
+    {
+        // SAFETY: NEON support is required by the `cfg` above
+        unsafe { neon::encode(src, dst, upper) }
+    }
+
+    #[cfg(not(all(feature = "neon", target_arch = "aarch64", target_feature = "neon")))]
+    {
+        let _ = (src, dst, upper);
+        0
+    }
+}
+
+/// Decode as many 32-byte blocks of `src` into `dst` as possible.
+///
+/// Returns the number of bytes of `dst` which were written. The number of
+/// bytes read from `src` is twice this value.
+#[inline]
+pub(crate) fn decode(src: &[u8], dst: &mut [u8], case: Case) -> Result<usize, Error> {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if x86::has_ssse3() {
+            // SAFETY: SSSE3 support was checked above
+            return unsafe { x86::decode(src, dst, case) };
+        }
+    }
+
+    // NEON is part of the baseline of most AArch64 targets, so it's detected
+    // at compile time. It's opt-in as the intrinsics require Rust 1.59.
+    #[cfg(all(feature = "neon", target_arch = "aarch64", target_feature = "neon"))]
+    {
+        // SAFETY: NEON support is required by the `cfg` above
+        unsafe { neon::decode(src, dst, case) }
+    }
+
+    #[cfg(not(all(feature = "neon", target_arch = "aarch64", target_feature = "neon")))]
+    {
+        let _ = (src, dst, case);
+        Ok(0)
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    use super::Case;
+    use crate::Error;
+
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    cpufeatures::new!(ssse3_cpuid, "ssse3");
+
+    /// Is SSSE3 available on the current CPU?
+    #[inline]
+    pub(super) fn has_ssse3() -> bool {
+        ssse3_cpuid::get()
+    }
+
+    /// Encode 16-byte blocks using SSSE3.
+    #[target_feature(enable = "ssse3")]
+    pub(super) unsafe fn encode(src: &[u8], dst: &mut [u8], upper: bool) -> usize {
+        let alphabet = if upper {
+            b"0123456789ABCDEF"
+        } else {
+            b"0123456789abcdef"
+        };
+
+        let alphabet = _mm_loadu_si128(alphabet.as_ptr() as *const __m128i);
+        let mask = _mm_set1_epi8(0x0f);
+        let mut len = 0;
+
+        for (src, dst) in src.chunks_exact(16).zip(dst.chunks_exact_mut(32)) {
+            let input = _mm_loadu_si128(src.as_ptr() as *const __m128i);
+            let hi = _mm_shuffle_epi8(alphabet, _mm_and_si128(_mm_srli_epi16(input, 4), mask));
+            let lo = _mm_shuffle_epi8(alphabet, _mm_and_si128(input, mask));
+
+            let dst = dst.as_mut_ptr() as *mut __m128i;
+            _mm_storeu_si128(dst, _mm_unpacklo_epi8(hi, lo));
+            _mm_storeu_si128(dst.add(1), _mm_unpackhi_epi8(hi, lo));
+            len += 16;
+        }
+
+        len
+    }
+
+    /// Compute a mask of the lanes of `value` which are at most `max`.
+    #[inline(always)]
+    unsafe fn in_range(value: __m128i, max: i8) -> __m128i {
+        _mm_cmpeq_epi8(_mm_min_epu8(value, _mm_set1_epi8(max)), value)
+    }
+
+    /// Decode 16 hex characters into nibbles, accumulating invalid
+    /// characters into `invalid`.
+    #[target_feature(enable = "ssse3")]
+    unsafe fn decode_nibbles(src: &[u8], case: Case, invalid: &mut __m128i) -> __m128i {
+        let input = _mm_loadu_si128(src.as_ptr() as *const __m128i);
+
+        let digit = _mm_sub_epi8(input, _mm_set1_epi8(b'0' as i8));
+        let digit_mask = in_range(digit, 9);
+
+        let alpha = match case {
+            Case::Lower => _mm_sub_epi8(input, _mm_set1_epi8(b'a' as i8)),
+            Case::Upper => _mm_sub_epi8(input, _mm_set1_epi8(b'A' as i8)),
+            Case::Mixed => _mm_sub_epi8(
+                _mm_or_si128(input, _mm_set1_epi8(0x20)),
+                _mm_set1_epi8(b'a' as i8),
+            ),
+        };
+        let alpha_mask = in_range(alpha, 5);
+
+        let valid = _mm_or_si128(digit_mask, alpha_mask);
+        *invalid = _mm_or_si128(*invalid, _mm_andnot_si128(valid, _mm_set1_epi8(-1)));
+
+        _mm_or_si128(
+            _mm_and_si128(digit_mask, digit),
+            _mm_and_si128(alpha_mask, _mm_add_epi8(alpha, _mm_set1_epi8(10))),
+        )
+    }
+
+    /// Decode 32-byte blocks using SSSE3.
+    #[target_feature(enable = "ssse3")]
+    pub(super) unsafe fn decode(src: &[u8], dst: &mut [u8], case: Case) -> Result<usize, Error> {
+        let mut invalid = _mm_setzero_si128();
+        let mut len = 0;
+
+        for (src, dst) in src.chunks_exact(32).zip(dst.chunks_exact_mut(16)) {
+            let nibbles_lo = decode_nibbles(&src[..16], case, &mut invalid);
+            let nibbles_hi = decode_nibbles(&src[16..], case, &mut invalid);
+
+            // Combine each pair of nibbles into a byte
+            let weights = _mm_set1_epi16(0x0110);
+            let output = _mm_packus_epi16(
+                _mm_maddubs_epi16(nibbles_lo, weights),
+                _mm_maddubs_epi16(nibbles_hi, weights),
+            );
+
+            _mm_storeu_si128(dst.as_mut_ptr() as *mut __m128i, output);
+            len += 16;
+        }
+
+        if _mm_movemask_epi8(invalid) == 0 {
+            Ok(len)
+        } else {
+            Err(Error::InvalidEncoding)
+        }
+    }
+}
+
+#[cfg(all(feature = "neon", target_arch = "aarch64", target_feature = "neon"))]
+mod neon {
+    use super::Case;
+    use crate::Error;
+    use core::arch::aarch64::*;
+
+    /// Encode 16-byte blocks using NEON.
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn encode(src: &[u8], dst: &mut [u8], upper: bool) -> usize {
+        let alphabet = if upper {
+            b"0123456789ABCDEF"
+        } else {
+            b"0123456789abcdef"
+        };
+
+        let alphabet = vld1q_u8(alphabet.as_ptr());
+        let mask = vdupq_n_u8(0x0f);
+        let mut len = 0;
+
+        for (src, dst) in src.chunks_exact(16).zip(dst.chunks_exact_mut(32)) {
+            let input = vld1q_u8(src.as_ptr());
+            let hi = vqtbl1q_u8(alphabet, vshrq_n_u8::<4>(input));
+            let lo = vqtbl1q_u8(alphabet, vandq_u8(input, mask));
+
+            // Interleave the high and low nibbles of each byte
+            vst2q_u8(dst.as_mut_ptr(), uint8x16x2_t(hi, lo));
+            len += 16;
+        }
+
+        len
+    }
+
+    /// Decode 16 hex characters into nibbles, accumulating invalid
+    /// characters into `invalid`.
+    #[target_feature(enable = "neon")]
+    unsafe fn decode_nibbles(
+        input: uint8x16_t,
+        case: Case,
+        invalid: &mut uint8x16_t,
+    ) -> uint8x16_t {
+        let digit = vsubq_u8(input, vdupq_n_u8(b'0'));
+        let digit_mask = vcleq_u8(digit, vdupq_n_u8(9));
+
+        let alpha = match case {
+            Case::Lower => vsubq_u8(input, vdupq_n_u8(b'a')),
+            Case::Upper => vsubq_u8(input, vdupq_n_u8(b'A')),
+            Case::Mixed => vsubq_u8(vorrq_u8(input, vdupq_n_u8(0x20)), vdupq_n_u8(b'a')),
+        };
+        let alpha_mask = vcleq_u8(alpha, vdupq_n_u8(5));
+
+        let valid = vorrq_u8(digit_mask, alpha_mask);
+        *invalid = vorrq_u8(*invalid, vmvnq_u8(valid));
+
+        vorrq_u8(
+            vandq_u8(digit_mask, digit),
+            vandq_u8(alpha_mask, vaddq_u8(alpha, vdupq_n_u8(10))),
+        )
+    }
+
+    /// Decode 32-byte blocks using NEON.
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn decode(src: &[u8], dst: &mut [u8], case: Case) -> Result<usize, Error> {
+        let mut invalid = vdupq_n_u8(0);
+        let mut len = 0;
+
+        for (src, dst) in src.chunks_exact(32).zip(dst.chunks_exact_mut(16)) {
+            // Split the input into the high and low nibble of each byte
+            let input = vld2q_u8(src.as_ptr());
+            let nibbles_hi = decode_nibbles(input.0, case, &mut invalid);
+            let nibbles_lo = decode_nibbles(input.1, case, &mut invalid);
+
+            let output = vorrq_u8(vshlq_n_u8::<4>(nibbles_hi), nibbles_lo);
+            vst1q_u8(dst.as_mut_ptr(), output);
+            len += 16;
+        }
+
+        if vmaxvq_u8(invalid) == 0 {
+            Ok(len)
+        } else {
+            Err(Error::InvalidEncoding)
+        }
+    }
+}
@@ -146,6 +146,74 @@ fn encode_and_decode_various_lengths() {
     }
 }
 
+#[test]
+fn vartime_matches_constant_time() {
+    let mut data = [0u8; 100];
+    for (i, byte) in data.iter_mut().enumerate() {
+        *byte = (i * 37) as u8;
+    }
+
+    for i in 0..data.len() {
+        let mut lower = [0u8; 200];
+        let mut upper = [0u8; 200];
+        let mut buf = [0u8; 200];
+
+        let lower = base16ct::lower::encode(&data[..i], &mut lower).unwrap();
+        assert_eq!(
+            base16ct::lower::encode_vartime(&data[..i], &mut buf).unwrap(),
+            lower
+        );
+
+        let upper = base16ct::upper::encode(&data[..i], &mut upper).unwrap();
+        assert_eq!(
+            base16ct::upper::encode_vartime(&data[..i], &mut buf).unwrap(),
+            upper
+        );
+
+        assert_eq!(
+            base16ct::lower::decode_vartime(lower, &mut buf).unwrap(),
+            &data[..i]
+        );
+        assert_eq!(
+            base16ct::upper::decode_vartime(upper, &mut buf).unwrap(),
+            &data[..i]
+        );
+        assert_eq!(
+            base16ct::mixed::decode_vartime(lower, &mut buf).unwrap(),
+            &data[..i]
+        );
+        assert_eq!(
+            base16ct::mixed::decode_vartime(upper, &mut buf).unwrap(),
+            &data[..i]
+        );
+    }
+}
+
+#[test]
+fn vartime_rejects_invalid() {
+    let mut lower = *b"000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+    let mut upper = *b"000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F";
+    let mut buf = [0u8; 32];
+
+    for i in 0..lower.len() {
+        for &byte in &[0x00, b'/', b':', b'@', b'G', b'`', b'g', 0x80, 0xff] {
+            let (l, u) = (lower[i], upper[i]);
+            lower[i] = byte;
+            upper[i] = byte;
+
+            assert!(base16ct::lower::decode_vartime(lower, &mut buf).is_err());
+            assert!(base16ct::upper::decode_vartime(upper, &mut buf).is_err());
+            assert!(base16ct::mixed::decode_vartime(lower, &mut buf).is_err());
+
+            lower[i] = l;
+            upper[i] = u;
+        }
+    }
+
+    assert!(base16ct::lower::decode_vartime(upper, &mut buf).is_err());
+    assert!(base16ct::upper::decode_vartime(lower, &mut buf).is_err());
+}
+
 #[test]
 fn hex_display_upper() {
     for vector in HEX_TEST_VECTORS {
@@ -0,0 +1,237 @@
+//! Base32 encodings
+
+use crate::{variant::Variant, Error, Result};
+use core::{fmt::Debug, str};
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+/// Padding character
+const PAD: u8 = b'=';
+
+/// Base32 encoding trait.
+///
+/// This trait must be imported to make use of any Base32 variant defined
+/// in this crate.
+pub trait Encoding: 'static + Copy + Debug + Eq + Send + Sized + Sync {
+    /// Decode a Base32 string into the provided destination buffer.
+    fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8]>;
+
+    /// Decode a Base32 string into a byte vector.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn decode_vec(input: &str) -> Result<Vec<u8>>;
+
+    /// Encode the input byte slice as Base32.
+    ///
+    /// Writes the result into the provided destination slice, returning an
+    /// ASCII-encoded Base32 string value.
+    fn encode<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a str>;
+
+    /// Encode input byte slice into a [`String`] containing Base32.
+    ///
+    /// # Panics
+    /// If `input` length is greater than `usize::MAX/8`.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn encode_string(input: &[u8]) -> String;
+
+    /// Get the length of Base32 produced by encoding the given bytes.
+    ///
+    /// WARNING: this function will return `0` for lengths greater than `usize::MAX/8`!
+    fn encoded_len(bytes: &[u8]) -> usize;
+}
+
+impl<T: Variant> Encoding for T {
+    fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8]> {
+        let src = if T::PADDED {
+            unpad(src.as_ref())?
+        } else {
+            src.as_ref()
+        };
+
+        let dst = dst
+            .get_mut(..decoded_len(src.len())?)
+            .ok_or(Error::InvalidLength)?;
+
+        let mut err = 0;
+
+        for (src, dst) in src.chunks(8).zip(dst.chunks_mut(5)) {
+            err |= decode_block::<T>(src, dst);
+        }
+
+        if err == 0 {
+            Ok(dst)
+        } else {
+            Err(Error::InvalidEncoding)
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn decode_vec(input: &str) -> Result<Vec<u8>> {
+        let mut output = vec![0u8; input.len() * 5 / 8];
+        let len = Self::decode(input, &mut output)?.len();
+        output.truncate(len);
+        Ok(output)
+    }
+
+    fn encode<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a str> {
+        let elen = encoded_len_inner(src.len(), T::PADDED).ok_or(Error::InvalidLength)?;
+        let dst = dst.get_mut(..elen).ok_or(Error::InvalidLength)?;
+
+        for (src, dst) in src.chunks(5).zip(dst.chunks_mut(8)) {
+            encode_block::<T>(src, dst);
+        }
+
+        Ok(str::from_utf8(dst)?)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn encode_string(input: &[u8]) -> String {
+        let elen = encoded_len_inner(input.len(), T::PADDED).expect("input is too big");
+        let mut dst = vec![0u8; elen];
+        let res = Self::encode(input, &mut dst).expect("encoding error");
+
+        debug_assert_eq!(elen, res.len());
+        String::from_utf8(dst).expect("encoding error")
+    }
+
+    fn encoded_len(bytes: &[u8]) -> usize {
+        encoded_len_inner(bytes.len(), T::PADDED).unwrap_or(0)
+    }
+}
+
+/// Strip padding from the input, validating that it is of the expected
+/// length.
+///
+/// Note that this only examines the amount of padding, which is determined by
+/// the length of the encoded data rather than its contents.
+fn unpad(input: &[u8]) -> Result<&[u8]> {
+    if input.len() % 8 != 0 {
+        return Err(Error::InvalidEncoding);
+    }
+
+    let pad_len = input
+        .iter()
+        .rev()
+        .take(6)
+        .take_while(|&&c| c == PAD)
+        .count();
+
+    // Valid amounts of padding for blocks of 5, 1, 2, 3, and 4 bytes
+    match pad_len {
+        0 | 6 | 4 | 3 | 1 => Ok(&input[..(input.len() - pad_len)]),
+        _ => Err(Error::InvalidEncoding),
+    }
+}
+
+/// Compute the length of the data decoded from unpadded Base32 of the given
+/// length, rejecting lengths which cannot be produced by an encoder.
+fn decoded_len(input_len: usize) -> Result<usize> {
+    match input_len % 8 {
+        0 | 2 | 4 | 5 | 7 => Ok(input_len / 8 * 5 + input_len % 8 * 5 / 8),
+        _ => Err(Error::InvalidEncoding),
+    }
+}
+
+/// Compute the length of Base32 produced by encoding data of the given
+/// length, returning `None` on overflow.
+const fn encoded_len_inner(n: usize, padded: bool) -> Option<usize> {
+    let blocks = n / 5;
+    let rem = n % 5;
+
+    let len = match blocks.checked_mul(8) {
+        Some(len) => len,
+        None => return None,
+    };
+
+    let rem_len = if rem == 0 {
+        0
+    } else if padded {
+        8
+    } else {
+        (rem * 8 + 4) / 5
+    };
+
+    len.checked_add(rem_len)
+}
+
+/// Decode a block of up to 8 Base32 characters into `dst`, returning a
+/// non-zero value if the block is invalid.
+///
+/// Bits beyond the last whole byte must be zero.
+#[inline(always)]
+fn decode_block<T: Variant>(src: &[u8], dst: &mut [u8]) -> i16 {
+    debug_assert!(src.len() <= 8);
+    debug_assert_eq!(dst.len(), src.len() * 5 / 8);
+
+    let mut err = 0;
+    let mut bits = 0u64;
+
+    for i in 0..8 {
+        let value = match src.get(i) {
+            Some(&c) => decode_5bits::<T>(c),
+            None => 0,
+        };
+
+        err |= value;
+        bits = (bits << 5) | (value & 0x1f) as u64;
+    }
+
+    for (i, byte) in dst.iter_mut().enumerate() {
+        *byte = (bits >> (32 - i * 8)) as u8;
+    }
+
+    // Bits beyond the last whole byte must be zero
+    let trailing = (bits << (dst.len() * 8)) & 0xff_ffff_ffff;
+    let trailing_err = ((trailing | trailing.wrapping_neg()) >> 63) as i16;
+
+    ((err >> 8) & 1) | trailing_err
+}
+
+/// Encode a block of up to 5 bytes as Base32, writing padding to the
+/// remainder of `dst`.
+#[inline(always)]
+fn encode_block<T: Variant>(src: &[u8], dst: &mut [u8]) {
+    debug_assert!(src.len() <= 5);
+
+    let mut buf = [0u8; 5];
+    buf[..src.len()].copy_from_slice(src);
+
+    let bits = buf.iter().fold(0u64, |acc, &b| (acc << 8) | u64::from(b));
+    let n = (src.len() * 8 + 4) / 5;
+
+    for (i, c) in dst.iter_mut().enumerate() {
+        *c = if i < n {
+            encode_5bits::<T>(((bits >> (35 - i * 5)) & 0x1f) as i16)
+        } else {
+            PAD
+        };
+    }
+}
+
+/// Decode 5-bits of a Base32 message, returning a negative value for
+/// characters outside of the alphabet.
+#[inline(always)]
+fn decode_5bits<T: Variant>(src: u8) -> i16 {
+    /// Adds `src - start + offset + 1` if `start <= src <= end`, or `0`
+    /// otherwise.
+    #[inline(always)]
+    fn range(src: i16, start: u8, end: u8, offset: i16) -> i16 {
+        let (start, end) = (i16::from(start), i16::from(end));
+        (((start - 1 - src) & (src - end - 1)) >> 8) & (src - start + offset + 1)
+    }
+
+    let src = i16::from(src);
+    -1 + range(src, T::BASE, T::BASE + 25, 0) + range(src, b'2', b'7', 26)
+}
+
+/// Encode 5-bits of a Base32 message.
+#[inline(always)]
+fn encode_5bits<T: Variant>(src: i16) -> u8 {
+    let base = i16::from(T::BASE);
+
+    // Values 26..32 are mapped to `2`..`7`
+    let diff = ((25 - src) >> 8) & (i16::from(b'2') - 26 - base);
+    (src + base + diff) as u8
+}
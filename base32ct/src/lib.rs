@@ -0,0 +1,79 @@
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_root_url = "https://docs.rs/base32ct/0.0.0"
+)]
+#![doc = include_str!("../README.md")]
+#![forbid(unsafe_code, clippy::unwrap_used)]
+#![warn(
+    missing_docs,
+    rust_2018_idioms,
+    unused_lifetimes,
+    unused_qualifications
+)]
+
+//! # Usage
+//!
+//! ## Allocating (enable `alloc` crate feature)
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use base32ct::{Base32, Encoding};
+//!
+//! let bytes = b"example bytestring!";
+//! let encoded = Base32::encode_string(bytes);
+//! assert_eq!(encoded, "MV4GC3LQNRSSAYTZORSXG5DSNFXGOII=");
+//!
+//! let decoded = Base32::decode_vec(&encoded).unwrap();
+//! assert_eq!(decoded, bytes);
+//! # }
+//! ```
+//!
+//! ## Heapless `no_std` usage
+//!
+//! ```
+//! use base32ct::{Base32LowerUnpadded, Encoding};
+//!
+//! const BUF_SIZE: usize = 128;
+//!
+//! let bytes = b"example bytestring!";
+//! assert!(Base32LowerUnpadded::encoded_len(bytes) <= BUF_SIZE);
+//!
+//! let mut enc_buf = [0u8; BUF_SIZE];
+//! let encoded = Base32LowerUnpadded::encode(bytes, &mut enc_buf).unwrap();
+//! assert_eq!(encoded, "mv4gc3lqnrssaytzorsxg5dsnfxgoii");
+//!
+//! let mut dec_buf = [0u8; BUF_SIZE];
+//! let decoded = Base32LowerUnpadded::decode(encoded, &mut dec_buf).unwrap();
+//! assert_eq!(decoded, bytes);
+//! ```
+//!
+//! # Implementation
+//!
+//! Implemented using integer arithmetic alone without any lookup tables or
+//! data-dependent branches, thereby providing portable "best effort"
+//! constant-time operation.
+//!
+//! Not constant-time with respect to message length (only data).
+//!
+//! Decoding is case-sensitive: each variant only accepts characters of its
+//! own case.
+
+#[cfg(feature = "alloc")]
+#[macro_use]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod encoding;
+mod error;
+mod variant;
+
+pub use crate::{
+    encoding::Encoding,
+    error::{Error, Result},
+    variant::{Base32, Base32Lower, Base32LowerUnpadded, Base32Unpadded},
+};
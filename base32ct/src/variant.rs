@@ -0,0 +1,68 @@
+//! Base32 variants
+
+use core::fmt::Debug;
+
+/// Core encoder/decoder parameters for a particular Base32 variant.
+pub trait Variant: 'static + Copy + Debug + Eq + Send + Sized + Sync {
+    /// Is this encoding padded?
+    const PADDED: bool;
+
+    /// First character in this Base32 alphabet, i.e. `A` or `a`.
+    const BASE: u8;
+}
+
+/// Upper case Base32 encoding with `=` padding, as described in RFC 4648.
+///
+/// ```text
+/// [A-Z]      [2-7]
+/// 0x41-0x5a, 0x32-0x37
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base32;
+
+impl Variant for Base32 {
+    const PADDED: bool = true;
+    const BASE: u8 = b'A';
+}
+
+/// Upper case Base32 encoding *without* padding.
+///
+/// ```text
+/// [A-Z]      [2-7]
+/// 0x41-0x5a, 0x32-0x37
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base32Unpadded;
+
+impl Variant for Base32Unpadded {
+    const PADDED: bool = false;
+    const BASE: u8 = b'A';
+}
+
+/// Lower case Base32 encoding with `=` padding.
+///
+/// ```text
+/// [a-z]      [2-7]
+/// 0x61-0x7a, 0x32-0x37
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base32Lower;
+
+impl Variant for Base32Lower {
+    const PADDED: bool = true;
+    const BASE: u8 = b'a';
+}
+
+/// Lower case Base32 encoding *without* padding.
+///
+/// ```text
+/// [a-z]      [2-7]
+/// 0x61-0x7a, 0x32-0x37
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base32LowerUnpadded;
+
+impl Variant for Base32LowerUnpadded {
+    const PADDED: bool = false;
+    const BASE: u8 = b'a';
+}
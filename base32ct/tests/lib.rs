@@ -0,0 +1,200 @@
+//! Integration tests.
+
+use base32ct::{Base32, Base32Lower, Base32LowerUnpadded, Base32Unpadded, Encoding, Error};
+
+/// Base32 test vector
+struct TestVector {
+    /// Raw bytes
+    raw: &'static [u8],
+    /// Padded upper case Base32
+    b32: &'static str,
+}
+
+/// Test vectors from RFC 4648 Section 10.
+const RFC4648_TEST_VECTORS: &[TestVector] = &[
+    TestVector { raw: b"", b32: "" },
+    TestVector {
+        raw: b"f",
+        b32: "MY======",
+    },
+    TestVector {
+        raw: b"fo",
+        b32: "MZXQ====",
+    },
+    TestVector {
+        raw: b"foo",
+        b32: "MZXW6===",
+    },
+    TestVector {
+        raw: b"foob",
+        b32: "MZXW6YQ=",
+    },
+    TestVector {
+        raw: b"fooba",
+        b32: "MZXW6YTB",
+    },
+    TestVector {
+        raw: b"foobar",
+        b32: "MZXW6YTBOI======",
+    },
+];
+
+/// Check the given variant against the RFC 4648 test vectors, transformed
+/// by `f`.
+fn check_test_vectors<E: Encoding>(f: impl Fn(&str) -> String) {
+    for vector in RFC4648_TEST_VECTORS {
+        let expected = f(vector.b32);
+        let mut buf = [0u8; 16];
+
+        let encoded = E::encode(vector.raw, &mut buf).unwrap();
+        assert_eq!(encoded, expected);
+        assert_eq!(E::encoded_len(vector.raw), expected.len());
+
+        let decoded = E::decode(&expected, &mut buf).unwrap();
+        assert_eq!(decoded, vector.raw);
+
+        #[cfg(feature = "alloc")]
+        {
+            assert_eq!(E::encode_string(vector.raw), expected);
+            assert_eq!(E::decode_vec(&expected).unwrap(), vector.raw);
+        }
+    }
+}
+
+#[test]
+fn upper_padded() {
+    check_test_vectors::<Base32>(|s| s.to_owned());
+}
+
+#[test]
+fn upper_unpadded() {
+    check_test_vectors::<Base32Unpadded>(|s| s.trim_end_matches('=').to_owned());
+}
+
+#[test]
+fn lower_padded() {
+    check_test_vectors::<Base32Lower>(|s| s.to_lowercase());
+}
+
+#[test]
+fn lower_unpadded() {
+    check_test_vectors::<Base32LowerUnpadded>(|s| s.trim_end_matches('=').to_lowercase());
+}
+
+#[test]
+fn full_alphabet() {
+    // Every 5-bit value in order
+    let raw = [
+        0x00, 0x44, 0x32, 0x14, 0xc7, 0x42, 0x54, 0xb6, 0x35, 0xcf, 0x84, 0x65, 0x3a, 0x56, 0xd7,
+        0xc6, 0x75, 0xbe, 0x77, 0xdf,
+    ];
+    let mut inbuf = [0u8; 32];
+    let mut outbuf = [0u8; 20];
+
+    let encoded = Base32::encode(&raw, &mut inbuf).unwrap();
+    assert_eq!(encoded, "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567");
+    assert_eq!(Base32::decode(encoded, &mut outbuf).unwrap(), raw);
+
+    let encoded = Base32Lower::encode(&raw, &mut inbuf).unwrap();
+    assert_eq!(encoded, "abcdefghijklmnopqrstuvwxyz234567");
+    assert_eq!(Base32Lower::decode(encoded, &mut outbuf).unwrap(), raw);
+}
+
+#[test]
+fn encode_and_decode_various_lengths() {
+    let data = [b'X'; 64];
+    let mut inbuf = [0u8; 128];
+    let mut outbuf = [0u8; 128];
+
+    for i in 0..data.len() {
+        let encoded = Base32::encode(&data[..i], &mut inbuf).unwrap();
+        assert_eq!(Base32::decode(encoded, &mut outbuf).unwrap(), &data[..i]);
+
+        let encoded = Base32LowerUnpadded::encode(&data[..i], &mut inbuf).unwrap();
+        assert_eq!(
+            Base32LowerUnpadded::decode(encoded, &mut outbuf).unwrap(),
+            &data[..i]
+        );
+    }
+}
+
+#[test]
+fn reject_wrong_case() {
+    let mut buf = [0u8; 16];
+    assert_eq!(
+        Base32::decode("mzxw6ytb", &mut buf),
+        Err(Error::InvalidEncoding)
+    );
+    assert_eq!(
+        Base32Lower::decode("MZXW6YTB", &mut buf),
+        Err(Error::InvalidEncoding)
+    );
+}
+
+#[test]
+fn reject_invalid_chars() {
+    let mut buf = [0u8; 16];
+
+    for &c in &[
+        b'0', b'1', b'8', b'9', b'=', b'@', b'[', b'`', b'{', 0x00, 0x80, 0xff,
+    ] {
+        let mut encoded = *b"MZXW6YTB";
+        encoded[3] = c;
+        assert_eq!(
+            Base32::decode(encoded, &mut buf),
+            Err(Error::InvalidEncoding)
+        );
+    }
+}
+
+#[test]
+fn reject_invalid_padding() {
+    let mut buf = [0u8; 16];
+
+    for encoded in ["MZXW6YT", "MZXW6YT=", "MZ=====", "MZXW6=====", "========"] {
+        assert_eq!(
+            Base32::decode(encoded, &mut buf),
+            Err(Error::InvalidEncoding)
+        );
+    }
+
+    // Padding is rejected by unpadded variants
+    assert_eq!(
+        Base32Unpadded::decode("MY======", &mut buf),
+        Err(Error::InvalidEncoding)
+    );
+
+    // Unpadded lengths which cannot be produced by an encoder
+    for encoded in ["M", "MZX", "MZXW6Y"] {
+        assert_eq!(
+            Base32Unpadded::decode(encoded, &mut buf),
+            Err(Error::InvalidEncoding)
+        );
+    }
+}
+
+#[test]
+fn reject_trailing_bits() {
+    let mut buf = [0u8; 16];
+    assert_eq!(
+        Base32::decode("MZ======", &mut buf),
+        Err(Error::InvalidEncoding)
+    );
+    assert_eq!(
+        Base32Unpadded::decode("MZXW6YR", &mut buf),
+        Err(Error::InvalidEncoding)
+    );
+}
+
+#[test]
+fn reject_short_buffer() {
+    let mut buf = [0u8; 5];
+    assert_eq!(
+        Base32::decode("MZXW6YTBOI======", &mut buf),
+        Err(Error::InvalidLength)
+    );
+    assert_eq!(
+        Base32::encode(b"foobar", &mut buf),
+        Err(Error::InvalidLength)
+    );
+}
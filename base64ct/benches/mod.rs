@@ -38,6 +38,17 @@ fn decode_bench(b: &mut Bencher) {
     b.bytes = RAW_LEN as u64;
 }
 
+#[bench]
+fn decode_vartime_bench(b: &mut Bencher) {
+    let b64_data = get_b64_data();
+    let mut buf = get_raw_data();
+    b.iter(|| {
+        let out = Base64Unpadded::decode_vartime(&b64_data, &mut buf).unwrap();
+        test::black_box(out);
+    });
+    b.bytes = RAW_LEN as u64;
+}
+
 #[bench]
 fn decode_in_place_bench(b: &mut Bencher) {
     let mut b64_data = get_b64_data().into_bytes();
@@ -60,3 +71,14 @@ fn encode_bench(b: &mut Bencher) {
     });
     b.bytes = RAW_LEN as u64;
 }
+
+#[bench]
+fn encode_vartime_bench(b: &mut Bencher) {
+    let mut buf = get_b64_data().into_bytes();
+    let raw_data = get_raw_data();
+    b.iter(|| {
+        let out = Base64Unpadded::encode_vartime(&raw_data, &mut buf).unwrap();
+        test::black_box(out);
+    });
+    b.bytes = RAW_LEN as u64;
+}
@@ -2,6 +2,7 @@
 
 use crate::{
     errors::{Error, InvalidEncodingError, InvalidLengthError},
+    simd,
     variant::Variant,
 };
 use core::{fmt::Debug, str};
@@ -20,6 +21,14 @@ pub trait Encoding: 'static + Copy + Debug + Eq + Send + Sized + Sync {
     /// Decode a Base64 string into the provided destination buffer.
     fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error>;
 
+    /// Decode a Base64 string into the provided destination buffer using
+    /// SIMD instructions where available.
+    ///
+    /// WARNING: this method is **not** constant-time and MUST NOT be used to
+    /// decode secrets. It is intended for large inputs containing public data
+    /// such as certificates. Use [`Encoding::decode`] for secrets.
+    fn decode_vartime(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error>;
+
     /// Decode a Base64 string in-place.
     ///
     /// NOTE: this method does not (yet) validate that padding is well-formed,
@@ -37,6 +46,14 @@ pub trait Encoding: 'static + Copy + Debug + Eq + Send + Sized + Sync {
     /// ASCII-encoded Base64 string value.
     fn encode<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a str, InvalidLengthError>;
 
+    /// Encode the input byte slice as Base64 using SIMD instructions where
+    /// available.
+    ///
+    /// WARNING: this method is **not** constant-time and MUST NOT be used to
+    /// encode secrets. It is intended for large inputs containing public data
+    /// such as certificates. Use [`Encoding::encode`] for secrets.
+    fn encode_vartime<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a str, InvalidLengthError>;
+
     /// Encode input byte slice into a [`String`] containing Base64.
     ///
     /// # Panics
@@ -91,6 +108,13 @@ impl<T: Variant> Encoding for T {
         }
     }
 
+    fn decode_vartime(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error> {
+        let src = src.as_ref();
+        let (src_pos, dst_pos) = simd::decode::<T>(src, dst)?;
+        let len = Self::decode(&src[src_pos..], &mut dst[dst_pos..])?.len();
+        Ok(&dst[..(dst_pos + len)])
+    }
+
     fn decode_in_place(mut buf: &mut [u8]) -> Result<&[u8], InvalidEncodingError> {
         // TODO: eliminate unsafe code when LLVM12 is stable
         // See: https://github.com/rust-lang/rust/issues/80963
@@ -216,6 +240,27 @@ impl<T: Variant> Encoding for T {
         Ok(unsafe { str::from_utf8_unchecked(dst) })
     }
 
+    fn encode_vartime<'a>(src: &[u8], dst: &'a mut [u8]) -> Result<&'a str, InvalidLengthError> {
+        let elen = match encoded_len_inner(src.len(), T::PADDED) {
+            Some(v) => v,
+            None => return Err(InvalidLengthError),
+        };
+
+        if elen > dst.len() {
+            return Err(InvalidLengthError);
+        }
+
+        let dst = &mut dst[..elen];
+        let src_pos = simd::encode::<T>(src, dst);
+        Self::encode(&src[src_pos..], &mut dst[(src_pos / 3 * 4)..])?;
+
+        debug_assert!(str::from_utf8(dst).is_ok());
+
+        // SAFETY: values written by `encode_3bytes` and `simd::encode` are
+        // valid one-byte UTF-8 chars
+        Ok(unsafe { str::from_utf8_unchecked(dst) })
+    }
+
     #[cfg(feature = "alloc")]
     fn encode_string(input: &[u8]) -> String {
         let elen = encoded_len_inner(input.len(), T::PADDED).expect("input is too big");
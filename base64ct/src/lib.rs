@@ -50,6 +50,16 @@
 //! assert_eq!(decoded, bytes);
 //! ```
 //!
+//! ## In-place decoding
+//!
+//! ```
+//! use base64ct::{Base64, Encoding};
+//!
+//! let mut buf = *b"ZXhhbXBsZSBieXRlc3RyaW5nIQ==";
+//! let decoded = Base64::decode_in_place(&mut buf).unwrap();
+//! assert_eq!(decoded, b"example bytestring!");
+//! ```
+//!
 //! # Implementation
 //!
 //! Implemented using integer arithmetic alone without any lookup tables or
@@ -76,6 +86,7 @@ mod encoder;
 mod encoding;
 mod errors;
 mod line_ending;
+mod simd;
 mod variant;
 
 #[cfg(test)]
@@ -0,0 +1,360 @@
+//! SIMD-accelerated Base64 for non-secret data.
+//!
+//! These functions process the bulk of their input using SIMD instructions
+//! where the CPU supports them, returning how much of the input they consumed
+//! so the caller can finish the remainder (including any padding) using the
+//! scalar implementation.
+//!
+//! SSSE3 is used on x86/x86_64 when detected at runtime. NEON is used on
+//! AArch64 when the `neon` feature is enabled, which requires Rust 1.59.
+//!
+//! They are only reachable via the `*_vartime` methods of [`Encoding`], as
+//! they make no constant-time guarantees.
+//!
+//! [`Encoding`]: crate::Encoding
+
+use crate::{errors::InvalidEncodingError, variant::Variant};
+
+/// Encode as many blocks of `src` into `dst` as possible.
+///
+/// Blocks are 12 bytes (SSSE3) or 48 bytes (NEON) long.
+///
+/// Returns the number of bytes of `src` which were encoded. The number of
+/// bytes written to `dst` is 4/3 of this value.
+#[inline]
+pub(crate) fn encode<T: Variant>(src: &[u8], dst: &mut [u8]) -> usize {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if x86::has_ssse3() {
+            // SAFETY: SSSE3 support was checked above
+            return unsafe { x86::encode::<T>(src, dst) };
+        }
+    }
+
+    // NEON is part of the baseline of most AArch64 targets, so it's detected
+    // at compile time. It's opt-in as the intrinsics require Rust 1.59.
+    #[cfg(all(feature = "neon", target_arch = "aarch64", target_feature = "neon"))]
+    {
+        // SAFETY: NEON support is required by the `cfg` above
+        unsafe { neon::encode::<T>(src, dst) }
+    }
+
+    #[cfg(not(all(feature = "neon", target_arch = "aarch64", target_feature = "neon")))]
+    {
+        let _ = (src, dst);
+        0
+    }
+}
+
+/// Decode as many blocks of `src` into `dst` as possible, always leaving at
+/// least one byte of `src` for the caller to decode.
+///
+/// Blocks are 16 bytes (SSSE3) or 64 bytes (NEON) long.
+///
+/// Returns the number of bytes read from `src` and written to `dst`.
+#[inline]
+pub(crate) fn decode<T: Variant>(
+    src: &[u8],
+    dst: &mut [u8],
+) -> Result<(usize, usize), InvalidEncodingError> {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if x86::has_ssse3() {
+            // SAFETY: SSSE3 support was checked above
+            return unsafe { x86::decode::<T>(src, dst) };
+        }
+    }
+
+    // NEON is part of the baseline of most AArch64 targets, so it's detected
+    // at compile time. It's opt-in as the intrinsics require Rust 1.59.
+    #[cfg(all(feature = "neon", target_arch = "aarch64", target_feature = "neon"))]
+    {
+        // SAFETY: NEON support is required by the `cfg` above
+        unsafe { neon::decode::<T>(src, dst) }
+    }
+
+    #[cfg(not(all(feature = "neon", target_arch = "aarch64", target_feature = "neon")))]
+    {
+        let _ = (src, dst);
+        Ok((0, 0))
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    use crate::{
+        errors::InvalidEncodingError,
+        variant::{Decode, Encode, Variant},
+    };
+
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    cpufeatures::new!(ssse3_cpuid, "ssse3");
+
+    /// Is SSSE3 available on the current CPU?
+    #[inline]
+    pub(super) fn has_ssse3() -> bool {
+        ssse3_cpuid::get()
+    }
+
+    /// Broadcast a byte to all lanes, flipping its high bit so that unsigned
+    /// comparisons can be performed using signed comparison instructions.
+    #[inline(always)]
+    unsafe fn biased(byte: u8) -> __m128i {
+        _mm_set1_epi8((byte ^ 0x80) as i8)
+    }
+
+    /// Encode 12-byte blocks using SSSE3.
+    ///
+    /// Based on the algorithm described in "Faster Base64 Encoding and
+    /// Decoding Using AVX2 Instructions" by Muła and Lemire.
+    #[target_feature(enable = "ssse3")]
+    pub(super) unsafe fn encode<T: Variant>(src: &[u8], dst: &mut [u8]) -> usize {
+        let mut src_pos = 0;
+        let mut dst_pos = 0;
+
+        // Each iteration loads 16 bytes of input, of which 12 are encoded
+        while src.len() - src_pos >= 16 && dst.len() - dst_pos >= 16 {
+            let input = _mm_loadu_si128(src[src_pos..].as_ptr() as *const __m128i);
+
+            // Split each 3-byte group into four 6-bit indices, one per byte
+            let input = _mm_shuffle_epi8(
+                input,
+                _mm_setr_epi8(1, 0, 2, 1, 4, 3, 5, 4, 7, 6, 8, 7, 10, 9, 11, 10),
+            );
+            let t0 = _mm_and_si128(input, _mm_set1_epi32(0x0fc0_fc00));
+            let t1 = _mm_mulhi_epu16(t0, _mm_set1_epi32(0x0400_0040));
+            let t2 = _mm_and_si128(input, _mm_set1_epi32(0x003f_03f0));
+            let t3 = _mm_mullo_epi16(t2, _mm_set1_epi32(0x0100_0010));
+            let indices = _mm_or_si128(t1, t3);
+
+            // Vectorized equivalent of `Variant::encode_6bits`
+            let mut output = _mm_add_epi8(indices, _mm_set1_epi8(T::BASE as i8));
+
+            for &encoder in T::ENCODER {
+                let (mask, offset) = match encoder {
+                    Encode::Apply(threshold, offset) => (
+                        _mm_cmpgt_epi8(_mm_xor_si128(output, biased(0)), biased(threshold)),
+                        offset,
+                    ),
+                    Encode::Diff(threshold, offset) => (
+                        _mm_cmpgt_epi8(indices, _mm_set1_epi8(threshold as i8)),
+                        offset,
+                    ),
+                };
+
+                output = _mm_add_epi8(output, _mm_and_si128(mask, _mm_set1_epi8(offset as i8)));
+            }
+
+            _mm_storeu_si128(dst[dst_pos..].as_mut_ptr() as *mut __m128i, output);
+            src_pos += 12;
+            dst_pos += 16;
+        }
+
+        src_pos
+    }
+
+    /// Decode 16-byte blocks using SSSE3.
+    #[target_feature(enable = "ssse3")]
+    pub(super) unsafe fn decode<T: Variant>(
+        src: &[u8],
+        dst: &mut [u8],
+    ) -> Result<(usize, usize), InvalidEncodingError> {
+        let mut src_pos = 0;
+        let mut dst_pos = 0;
+        let mut invalid = _mm_setzero_si128();
+
+        // The final block, which may contain padding, is left to the caller
+        while src.len() - src_pos > 16 && dst.len() - dst_pos >= 12 {
+            let input = _mm_loadu_si128(src[src_pos..].as_ptr() as *const __m128i);
+            let input_biased = _mm_xor_si128(input, biased(0));
+
+            // Vectorized equivalent of `Variant::decode_6bits`
+            let mut valid = _mm_setzero_si128();
+            let mut values = _mm_setzero_si128();
+
+            for decoder in T::DECODER {
+                let (mask, value) = match decoder {
+                    Decode::Range(range, offset) => {
+                        let mask = _mm_or_si128(
+                            _mm_cmpgt_epi8(biased(range.start), input_biased),
+                            _mm_cmpgt_epi8(input_biased, biased(range.end)),
+                        );
+                        let mask = _mm_andnot_si128(mask, _mm_set1_epi8(-1));
+                        let value = _mm_add_epi8(input, _mm_set1_epi8((*offset - 1) as i8));
+                        (mask, value)
+                    }
+                    Decode::Eq(byte, offset) => (
+                        _mm_cmpeq_epi8(input, _mm_set1_epi8(*byte as i8)),
+                        _mm_set1_epi8((*offset - 1) as i8),
+                    ),
+                };
+
+                valid = _mm_or_si128(valid, mask);
+                values = _mm_or_si128(values, _mm_and_si128(mask, value));
+            }
+
+            invalid = _mm_or_si128(invalid, _mm_andnot_si128(valid, _mm_set1_epi8(-1)));
+
+            // Pack four 6-bit values into each 3-byte group
+            let merged = _mm_maddubs_epi16(values, _mm_set1_epi32(0x0140_0140));
+            let merged = _mm_madd_epi16(merged, _mm_set1_epi32(0x0001_1000));
+            let output = _mm_shuffle_epi8(
+                merged,
+                _mm_setr_epi8(2, 1, 0, 6, 5, 4, 10, 9, 8, 14, 13, 12, -1, -1, -1, -1),
+            );
+
+            let mut block = [0u8; 16];
+            _mm_storeu_si128(block.as_mut_ptr() as *mut __m128i, output);
+            dst[dst_pos..][..12].copy_from_slice(&block[..12]);
+
+            src_pos += 16;
+            dst_pos += 12;
+        }
+
+        if _mm_movemask_epi8(invalid) == 0 {
+            Ok((src_pos, dst_pos))
+        } else {
+            Err(InvalidEncodingError)
+        }
+    }
+}
+
+#[cfg(all(feature = "neon", target_arch = "aarch64", target_feature = "neon"))]
+mod neon {
+    use crate::{
+        errors::InvalidEncodingError,
+        variant::{Decode, Encode, Variant},
+    };
+    use core::arch::aarch64::*;
+
+    /// Vectorized equivalent of `Variant::encode_6bits`.
+    #[inline(always)]
+    unsafe fn encode_6bits<T: Variant>(indices: uint8x16_t) -> uint8x16_t {
+        let mut output = vaddq_u8(indices, vdupq_n_u8(T::BASE));
+
+        for &encoder in T::ENCODER {
+            let (mask, offset) = match encoder {
+                Encode::Apply(threshold, offset) => {
+                    (vcgtq_u8(output, vdupq_n_u8(threshold)), offset)
+                }
+                Encode::Diff(threshold, offset) => {
+                    (vcgtq_u8(indices, vdupq_n_u8(threshold)), offset)
+                }
+            };
+
+            output = vaddq_u8(output, vandq_u8(mask, vdupq_n_u8(offset as u8)));
+        }
+
+        output
+    }
+
+    /// Encode 48-byte blocks using NEON.
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn encode<T: Variant>(src: &[u8], dst: &mut [u8]) -> usize {
+        let mut len = 0;
+
+        for (src, dst) in src.chunks_exact(48).zip(dst.chunks_exact_mut(64)) {
+            // Load the 1st, 2nd and 3rd byte of each 3-byte group into
+            // separate vectors, and split them into four 6-bit indices
+            let input = vld3q_u8(src.as_ptr());
+            let mask = vdupq_n_u8(0x3f);
+
+            let i0 = vshrq_n_u8::<2>(input.0);
+            let i1 = vandq_u8(
+                vorrq_u8(vshlq_n_u8::<4>(input.0), vshrq_n_u8::<4>(input.1)),
+                mask,
+            );
+            let i2 = vandq_u8(
+                vorrq_u8(vshlq_n_u8::<2>(input.1), vshrq_n_u8::<6>(input.2)),
+                mask,
+            );
+            let i3 = vandq_u8(input.2, mask);
+
+            let output = uint8x16x4_t(
+                encode_6bits::<T>(i0),
+                encode_6bits::<T>(i1),
+                encode_6bits::<T>(i2),
+                encode_6bits::<T>(i3),
+            );
+
+            vst4q_u8(dst.as_mut_ptr(), output);
+            len += 48;
+        }
+
+        len
+    }
+
+    /// Vectorized equivalent of `Variant::decode_6bits`, accumulating
+    /// invalid characters into `invalid`.
+    #[inline(always)]
+    unsafe fn decode_6bits<T: Variant>(input: uint8x16_t, invalid: &mut uint8x16_t) -> uint8x16_t {
+        let mut valid = vdupq_n_u8(0);
+        let mut values = vdupq_n_u8(0);
+
+        for decoder in T::DECODER {
+            let (mask, value) = match decoder {
+                Decode::Range(range, offset) => {
+                    let mask = vandq_u8(
+                        vcgeq_u8(input, vdupq_n_u8(range.start)),
+                        vcleq_u8(input, vdupq_n_u8(range.end)),
+                    );
+                    let value = vaddq_u8(input, vdupq_n_u8((*offset - 1) as u8));
+                    (mask, value)
+                }
+                Decode::Eq(byte, offset) => (
+                    vceqq_u8(input, vdupq_n_u8(*byte)),
+                    vdupq_n_u8((*offset - 1) as u8),
+                ),
+            };
+
+            valid = vorrq_u8(valid, mask);
+            values = vorrq_u8(values, vandq_u8(mask, value));
+        }
+
+        *invalid = vorrq_u8(*invalid, vmvnq_u8(valid));
+        values
+    }
+
+    /// Decode 64-byte blocks using NEON.
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn decode<T: Variant>(
+        src: &[u8],
+        dst: &mut [u8],
+    ) -> Result<(usize, usize), InvalidEncodingError> {
+        let mut src_pos = 0;
+        let mut dst_pos = 0;
+        let mut invalid = vdupq_n_u8(0);
+
+        // The final block, which may contain padding, is left to the caller
+        while src.len() - src_pos > 64 && dst.len() - dst_pos >= 48 {
+            // Load the 1st, 2nd, 3rd and 4th character of each 4-character
+            // group into separate vectors
+            let input = vld4q_u8(src[src_pos..].as_ptr());
+            let c0 = decode_6bits::<T>(input.0, &mut invalid);
+            let c1 = decode_6bits::<T>(input.1, &mut invalid);
+            let c2 = decode_6bits::<T>(input.2, &mut invalid);
+            let c3 = decode_6bits::<T>(input.3, &mut invalid);
+
+            // Pack four 6-bit values into each 3-byte group
+            let output = uint8x16x3_t(
+                vorrq_u8(vshlq_n_u8::<2>(c0), vshrq_n_u8::<4>(c1)),
+                vorrq_u8(vshlq_n_u8::<4>(c1), vshrq_n_u8::<2>(c2)),
+                vorrq_u8(vshlq_n_u8::<6>(c2), c3),
+            );
+
+            vst3q_u8(dst[dst_pos..].as_mut_ptr(), output);
+            src_pos += 64;
+            dst_pos += 48;
+        }
+
+        if vmaxvq_u8(invalid) == 0 {
+            Ok((src_pos, dst_pos))
+        } else {
+            Err(InvalidEncodingError)
+        }
+    }
+}
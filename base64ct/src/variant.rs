@@ -1,5 +1,9 @@
 //! Base64 variants
 
+// `Decode::Range` bounds are both inclusive, even though they're expressed
+// using `Range` rather than `RangeInclusive`
+#![allow(clippy::almost_complete_range)]
+
 use core::{fmt::Debug, ops::Range};
 
 pub mod bcrypt;
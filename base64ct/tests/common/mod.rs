@@ -76,5 +76,39 @@ macro_rules! impl_tests {
                 }
             }
         }
+
+        #[test]
+        fn vartime_matches_constant_time() {
+            let data = (0..=255u8).cycle().take(300).collect::<Vec<_>>();
+            let mut ct_buf = [0u8; 512];
+            let mut vt_buf = [0u8; 512];
+
+            for i in 0..data.len() {
+                let expected = <$encoding>::encode(&data[..i], &mut ct_buf).unwrap();
+                let encoded = <$encoding>::encode_vartime(&data[..i], &mut vt_buf).unwrap();
+                assert_eq!(expected, encoded);
+
+                let mut buf = [0u8; 512];
+                let decoded = <$encoding>::decode_vartime(encoded, &mut buf).unwrap();
+                assert_eq!(decoded, &data[..i]);
+            }
+
+            // Ensure invalid characters are rejected at every position
+            let encoded = <$encoding>::encode(&data, &mut ct_buf).unwrap().as_bytes();
+            let mut invalid = encoded.to_vec();
+
+            for i in 0..invalid.len() {
+                for &byte in &[0x00, b'!', b'=', 0x7f, 0x80, 0xff] {
+                    invalid[i] = byte;
+                    let mut buf = [0u8; 512];
+                    assert_eq!(
+                        <$encoding>::decode(&invalid, &mut buf).is_ok(),
+                        <$encoding>::decode_vartime(&invalid, &mut buf).is_ok()
+                    );
+                }
+
+                invalid[i] = encoded[i];
+            }
+        }
     };
 }
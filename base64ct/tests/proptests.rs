@@ -4,7 +4,6 @@
 
 use base64ct::{Base64 as Base64ct, Encoding};
 use proptest::{prelude::*, string::*};
-use std::iter;
 
 /// Incremental Base64 decoder.
 type Decoder<'a> = base64ct::Decoder<'a, Base64ct>;
@@ -109,7 +108,7 @@ proptest! {
             0 => base64ish,
             n => {
                 let padding_len = 4 - n;
-                base64ish + &iter::repeat("=").take(padding_len).collect::<String>()
+                base64ish + &"=".repeat(padding_len)
             }
         };
 
@@ -118,6 +117,25 @@ proptest! {
         prop_assert_eq!(decoded_ct, decoded_ref);
     }
 
+    /// Ensure `base64ct`'s variable-time decoder decodes randomly generated
+    /// inputs equivalently to the constant-time one.
+    #[test]
+    fn decode_vartime_random(base64ish in string_regex("[A-Za-z0-9+/]{0,256}").unwrap()) {
+        let base64ish_padded = match base64ish.len() % 4 {
+            0 => base64ish,
+            n => {
+                let padding_len = 4 - n;
+                base64ish + &"=".repeat(padding_len)
+            }
+        };
+
+        let mut ct_buf = [0u8; 192];
+        let mut vt_buf = [0u8; 192];
+        let decoded_ct = Base64ct::decode(&base64ish_padded, &mut ct_buf).ok();
+        let decoded_vt = Base64ct::decode_vartime(&base64ish_padded, &mut vt_buf).ok();
+        prop_assert_eq!(decoded_ct, decoded_vt);
+    }
+
     /// Ensure `base64ct` and the `base64` ref crate encode randomly generated
     /// inputs equivalently.
     #[test]
@@ -127,6 +145,16 @@ proptest! {
         prop_assert_eq!(encoded_ct, encoded_ref);
     }
 
+    /// Ensure `base64ct`'s variable-time encoder encodes randomly generated
+    /// inputs equivalently to the `base64` ref crate.
+    #[test]
+    fn encode_vartime_equiv(bytes in bytes_regex(".{0,256}").unwrap()) {
+        let mut buf = [0u8; 1376];
+        let encoded_vt = Base64ct::encode_vartime(&bytes, &mut buf).unwrap();
+        let encoded_ref = base64::encode(&bytes);
+        prop_assert_eq!(encoded_vt, encoded_ref);
+    }
+
     /// Ensure that `base64ct`'s incremental encoder is able to encode randomly
     /// generated inputs which match what's encoded by the `base64` ref crate
     #[test]
@@ -52,12 +52,12 @@ impl<'a> Arcs<'a> {
         match self.cursor {
             // Indicates we're on the root OID
             None => {
-                let root = RootArcs::try_from(self.oid.as_bytes()[0])?;
+                let root = RootArcs::try_from(self.root_byte()?)?;
                 self.cursor = Some(0);
                 Ok(Some(root.first_arc()))
             }
             Some(0) => {
-                let root = RootArcs::try_from(self.oid.as_bytes()[0])?;
+                let root = RootArcs::try_from(self.root_byte()?)?;
                 self.cursor = Some(1);
                 Ok(Some(root.second_arc()))
             }
@@ -66,7 +66,12 @@ impl<'a> Arcs<'a> {
                 let mut arc_bytes = 0;
 
                 loop {
-                    match self.oid.as_bytes().get(offset + arc_bytes).cloned() {
+                    match self
+                        .oid
+                        .as_bytes()
+                        .get(offset.saturating_add(arc_bytes))
+                        .cloned()
+                    {
                         Some(byte) => {
                             arc_bytes += 1;
 
@@ -77,7 +82,7 @@ impl<'a> Arcs<'a> {
                             result = result << 7 | (byte & 0b1111111) as Arc;
 
                             if byte & 0b10000000 == 0 {
-                                self.cursor = Some(offset + arc_bytes);
+                                self.cursor = Some(offset.saturating_add(arc_bytes));
                                 return Ok(Some(result));
                             }
                         }
@@ -93,6 +98,11 @@ impl<'a> Arcs<'a> {
             }
         }
     }
+
+    /// Get the byte containing the root arcs of the OID.
+    fn root_byte(&self) -> Result<u8> {
+        self.oid.as_bytes().first().cloned().ok_or(Error::Empty)
+    }
 }
 
 impl<'a> Iterator for Arcs<'a> {
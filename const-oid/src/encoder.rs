@@ -109,15 +109,12 @@ impl Encoder {
     const fn encode_base128_byte(mut self, mut n: u32, i: usize, continued: bool) -> Result<Self> {
         let mask = if continued { 0b10000000 } else { 0 };
 
-        if n > 0x80 {
+        if i > 0 {
             self.bytes[self.cursor + i] = (n & 0b1111111) as u8 | mask;
             n >>= 7;
-
-            if i > 0 {
-                self.encode_base128_byte(n, i.saturating_sub(1), true)
-            } else {
-                Err(Error::Base128)
-            }
+            self.encode_base128_byte(n, i.saturating_sub(1), true)
+        } else if n > 0b1111111 {
+            Err(Error::Base128)
         } else {
             self.bytes[self.cursor] = n as u8 | mask;
             Ok(self)
@@ -155,4 +152,11 @@ mod tests {
         let encoder = encoder.arc(1).unwrap();
         assert_eq!(&encoder.bytes[..encoder.cursor], EXAMPLE_OID_BER);
     }
+
+    #[test]
+    fn encode_arc_boundaries() {
+        let encoder = Encoder::new().arc(1).unwrap().arc(2).unwrap();
+        let encoder = encoder.arc(0x80).unwrap().arc(0x4000).unwrap();
+        assert_eq!(&encoder.bytes[..encoder.cursor], &hex!("2A8100818000"));
+    }
 }
@@ -77,6 +77,15 @@ impl ObjectIdentifier {
     /// pub const MY_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
     /// ```
     ///
+    /// Such constants can also be used as patterns in `match` arms. When
+    /// evaluated in a `const` context, invalid OIDs fail at compile time:
+    ///
+    /// ```compile_fail
+    /// use const_oid::ObjectIdentifier;
+    ///
+    /// pub const MY_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2..3");
+    /// ```
+    ///
     /// In future versions of Rust it should be possible to replace this with
     /// `ObjectIdentifier::new(...).unwrap()`.
     ///
@@ -125,7 +134,10 @@ impl ObjectIdentifier {
             _ => return Err(Error::NotEnoughArcs),
         }
         let mut bytes = [0u8; Self::MAX_SIZE];
-        bytes[..len].copy_from_slice(ber_bytes);
+        bytes
+            .get_mut(..len)
+            .ok_or(Error::Length)?
+            .copy_from_slice(ber_bytes);
 
         let oid = Self {
             bytes,
@@ -139,12 +151,21 @@ impl ObjectIdentifier {
         Ok(oid)
     }
 
+    /// Get the length of the BER/DER serialization of this OID in bytes.
+    ///
+    /// Like [`ObjectIdentifier::as_bytes`], this excludes the tag/length and
+    /// only covers the value portion of the encoded OID. Unlike `der`'s
+    /// `Encode::encoded_len`, it is usable in `const` contexts.
+    pub const fn byte_len(&self) -> usize {
+        self.length as usize
+    }
+
     /// Get the BER/DER serialization of this OID as bytes.
     ///
     /// Note that this encoding omits the tag/length, and only contains the
     /// value portion of the encoded OID.
     pub fn as_bytes(&self) -> &[u8] {
-        &self.bytes[..self.length as usize]
+        self.bytes.get(..self.length as usize).unwrap_or(&[])
     }
 
     /// Return the arc with the given index, if it exists.
@@ -170,6 +191,13 @@ impl ObjectIdentifier {
         Self::from_arcs(self.arcs().take(num_arcs)).ok()
     }
 
+    /// Look up the well-known name of this OID in the [`db::DB`] database.
+    #[cfg(feature = "db")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "db")))]
+    pub const fn name(&self) -> Option<&'static str> {
+        db::DB.by_oid(self)
+    }
+
     /// Push an additional arc onto this OID, returning the child OID.
     pub const fn push_arc(self, arc: Arc) -> Result<Self> {
         // TODO(tarcieri): use `?` when stable in `const fn`
@@ -210,6 +238,11 @@ impl From<&ObjectIdentifier> for ObjectIdentifier {
 
 impl fmt::Debug for ObjectIdentifier {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "db")]
+        if let Some(name) = self.name() {
+            return write!(f, "ObjectIdentifier({} {})", self, name);
+        }
+
         write!(f, "ObjectIdentifier({})", self)
     }
 }
@@ -229,3 +262,104 @@ impl fmt::Display for ObjectIdentifier {
         Ok(())
     }
 }
+
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+impl<'a> arbitrary::Arbitrary<'a> for ObjectIdentifier {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        /// Largest arc generated: arcs which encode as more than 4 bytes of
+        /// base 128 are rejected by the decoder.
+        const ARC_MAX: Arc = 0x0FFF_FFFF;
+
+        let first = u.int_in_range(0..=arcs::ARC_MAX_FIRST)?;
+        let second = u.int_in_range(0..=arcs::ARC_MAX_SECOND)?;
+        let third = u.int_in_range(0..=ARC_MAX)?;
+
+        let mut oid = Self::from_arcs([first, second, third])
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+
+        // Append arcs until the input or the OID's capacity is exhausted
+        while u.arbitrary()? {
+            oid = match oid.push_arc(u.int_in_range(0..=ARC_MAX)?) {
+                Ok(oid) => oid,
+                Err(_) => break,
+            };
+        }
+
+        Ok(oid)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for ObjectIdentifier {
+    /// Serialize as a dotted decimal string in human-readable formats, or
+    /// as BER-encoded bytes in binary formats.
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(self.as_bytes())
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for ObjectIdentifier {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        /// Visitor for [`ObjectIdentifier`] in either representation.
+        struct OidVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for OidVisitor {
+            type Value = ObjectIdentifier;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an object identifier")
+            }
+
+            fn visit_str<E: serde::de::Error>(
+                self,
+                s: &str,
+            ) -> core::result::Result<Self::Value, E> {
+                s.parse().map_err(E::custom)
+            }
+
+            fn visit_bytes<E: serde::de::Error>(
+                self,
+                bytes: &[u8],
+            ) -> core::result::Result<Self::Value, E> {
+                ObjectIdentifier::from_bytes(bytes).map_err(E::custom)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> core::result::Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut bytes = [0u8; ObjectIdentifier::MAX_SIZE];
+                let mut len = 0;
+
+                while let Some(byte) = seq.next_element()? {
+                    *bytes
+                        .get_mut(len)
+                        .ok_or_else(|| serde::de::Error::custom(Error::Length))? = byte;
+                    len += 1;
+                }
+
+                self.visit_bytes(&bytes[..len])
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(OidVisitor)
+        } else {
+            deserializer.deserialize_bytes(OidVisitor)
+        }
+    }
+}
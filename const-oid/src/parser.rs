@@ -51,12 +51,23 @@ impl Parser {
             },
             [byte @ b'0'..=b'9', remaining @ ..] => {
                 let digit = byte.saturating_sub(b'0');
-                self.current_arc = self.current_arc * 10 + digit as Arc;
+
+                // TODO(tarcieri): use `?` when stable in `const fn`
+                self.current_arc = match self.current_arc.checked_mul(10) {
+                    Some(arc) => match arc.checked_add(digit as Arc) {
+                        Some(arc) => arc,
+                        None => return Err(Error::ArcTooBig),
+                    },
+                    None => return Err(Error::ArcTooBig),
+                };
+
                 self.parse_bytes(remaining)
             }
             [b'.', remaining @ ..] => {
-                if remaining.is_empty() {
-                    return Err(Error::TrailingDot);
+                match remaining {
+                    [] => return Err(Error::TrailingDot),
+                    [b'0'..=b'9', ..] => (),
+                    [actual, ..] => return Err(Error::DigitExpected { actual: *actual }),
                 }
 
                 // TODO(tarcieri): use `?` when stable in `const fn`
@@ -107,4 +118,24 @@ mod tests {
     fn reject_trailing_dot() {
         assert_eq!(Parser::parse("1.23.").err().unwrap(), Error::TrailingDot);
     }
+
+    #[test]
+    fn reject_empty_arc() {
+        assert_eq!(
+            Parser::parse("1..23").err().unwrap(),
+            Error::DigitExpected { actual: b'.' }
+        );
+    }
+
+    #[test]
+    fn reject_arc_overflow() {
+        assert_eq!(
+            Parser::parse("1.2.4294967296").err().unwrap(),
+            Error::ArcTooBig
+        );
+        assert_eq!(
+            Parser::parse("1.2.99999999999999999999").err().unwrap(),
+            Error::ArcTooBig
+        );
+    }
 }
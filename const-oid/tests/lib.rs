@@ -165,6 +165,39 @@ fn as_bytes() {
     assert_eq!(EXAMPLE_OID_2.as_bytes(), EXAMPLE_OID_2_BER);
 }
 
+#[test]
+fn byte_len() {
+    const LEN: usize = EXAMPLE_OID_1.byte_len();
+    assert_eq!(LEN, EXAMPLE_OID_1_BER.len());
+}
+
+#[test]
+fn match_arms() {
+    let name = |oid| match oid {
+        EXAMPLE_OID_1 => Some("id-ecPublicKey"),
+        EXAMPLE_OID_2 => Some("id-aes256-GCM"),
+        _ => None,
+    };
+
+    assert_eq!(
+        name(EXAMPLE_OID_1_STR.parse().unwrap()),
+        Some("id-ecPublicKey")
+    );
+    assert_eq!(name(EXAMPLE_OID_2), Some("id-aes256-GCM"));
+    assert_eq!(name(EXAMPLE_OID_0), None);
+}
+
+#[cfg(feature = "db")]
+#[test]
+fn name() {
+    assert_eq!(EXAMPLE_OID_1.name(), Some("id-ecPublicKey"));
+    assert_eq!(
+        format!("{:?}", EXAMPLE_OID_1),
+        "ObjectIdentifier(1.2.840.10045.2.1 id-ecPublicKey)"
+    );
+    assert_eq!(ObjectIdentifier::new_unwrap("1.2.3.4").name(), None);
+}
+
 #[test]
 fn parse_empty() {
     assert_eq!(ObjectIdentifier::new(""), Err(Error::Empty));
@@ -207,3 +240,41 @@ fn push_arc() {
         ObjectIdentifier::new("1.2.3.4").unwrap()
     );
 }
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn arbitrary() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    let data: Vec<u8> = (0..=255).collect();
+    let mut u = Unstructured::new(&data);
+
+    while !u.is_empty() {
+        let oid = ObjectIdentifier::arbitrary(&mut u).unwrap();
+        assert!(oid.arcs().count() >= 3);
+        assert_eq!(oid.to_string().parse::<ObjectIdentifier>().unwrap(), oid);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_human_readable() {
+    let json = serde_json::to_string(&EXAMPLE_OID_1).unwrap();
+    assert_eq!(json, format!("\"{}\"", EXAMPLE_OID_1_STR));
+    assert_eq!(
+        serde_json::from_str::<ObjectIdentifier>(&json).unwrap(),
+        EXAMPLE_OID_1
+    );
+    assert!(serde_json::from_str::<ObjectIdentifier>("\"1\"").is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_binary() {
+    let bin = bincode::serialize(&EXAMPLE_OID_2).unwrap();
+    assert_eq!(&bin[8..], EXAMPLE_OID_2_BER);
+    assert_eq!(
+        bincode::deserialize::<ObjectIdentifier>(&bin).unwrap(),
+        EXAMPLE_OID_2
+    );
+}
@@ -0,0 +1,106 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use crypto_serde::{chunked::HexChunksOrBin, HexLowerOrBin};
+use serde::Deserialize;
+use std::borrow::Cow;
+
+/// Length of the byte strings being deserialized.
+const BYTES_LEN: usize = 1 << 14;
+
+/// Length of the large blobs being serialized as hex.
+const BLOB_LEN: usize = 1 << 22;
+
+/// Element-wise deserialization of a `Vec<u8>` via `SeqAccess`.
+#[derive(Deserialize)]
+struct SeqBytes(#[allow(dead_code)] Vec<u8>);
+
+/// Byte string borrowed from the input via `crypto_serde::slice`.
+#[derive(Deserialize)]
+struct CowBytes<'a>(
+    #[serde(
+        borrow,
+        deserialize_with = "crypto_serde::slice::deserialize_hex_or_bin_cow"
+    )]
+    #[allow(dead_code)]
+    Cow<'a, [u8]>,
+);
+
+/// Fixed-size array deserialized via `crypto_serde::array`.
+#[derive(Deserialize)]
+struct ArrayBytes(
+    #[serde(deserialize_with = "crypto_serde::array::deserialize_hex_or_bin")]
+    #[allow(dead_code)]
+    [u8; 64],
+);
+
+/// Build a bincode-encoded byte string of the given length.
+fn bincode_bytes(len: usize) -> Vec<u8> {
+    bincode::serialize(&HexLowerOrBin::from(vec![0xA5; len])).unwrap()
+}
+
+fn bincode_bytes_deserialize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bincode deserialize bytes");
+    group.throughput(Throughput::Bytes(BYTES_LEN as u64));
+    let serialized = bincode_bytes(BYTES_LEN);
+
+    group.bench_function("HexLowerOrBin", |b| {
+        b.iter(|| bincode::deserialize::<HexLowerOrBin>(black_box(&serialized)).unwrap())
+    });
+
+    group.bench_function("HexLowerOrBin borrowed", |b| {
+        b.iter(|| bincode::deserialize::<CowBytes<'_>>(black_box(&serialized)).unwrap())
+    });
+
+    group.bench_function("element-wise", |b| {
+        b.iter(|| bincode::deserialize::<SeqBytes>(black_box(&serialized)).unwrap())
+    });
+
+    group.finish();
+}
+
+fn bincode_array_deserialize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bincode deserialize array");
+    group.throughput(Throughput::Bytes(64));
+    let serialized = bincode_bytes(64);
+
+    group.bench_function("array", |b| {
+        b.iter(|| bincode::deserialize::<ArrayBytes>(black_box(&serialized)).unwrap())
+    });
+
+    group.bench_function("element-wise", |b| {
+        b.iter(|| bincode::deserialize::<SeqBytes>(black_box(&serialized)).unwrap())
+    });
+
+    group.finish();
+}
+
+fn json_blob_serialize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("json serialize blob");
+    group.throughput(Throughput::Bytes(BLOB_LEN as u64));
+    let mut out = Vec::with_capacity(BLOB_LEN * 3);
+
+    let blob = HexLowerOrBin::from(vec![0xA5; BLOB_LEN]);
+    group.bench_function("HexLowerOrBin", |b| {
+        b.iter(|| {
+            out.clear();
+            serde_json::to_writer(&mut out, black_box(&blob)).unwrap();
+        })
+    });
+
+    let blob = HexChunksOrBin::from(vec![0xA5; BLOB_LEN]);
+    group.bench_function("HexChunksOrBin", |b| {
+        b.iter(|| {
+            out.clear();
+            serde_json::to_writer(&mut out, black_box(&blob)).unwrap();
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bincode_bytes_deserialize,
+    bincode_array_deserialize,
+    json_blob_serialize
+);
+criterion_main!(benches);
@@ -0,0 +1,196 @@
+#![doc = include_str!("../README.md")]
+
+//! ## About
+//! Custom derive support for the [`crypto-serde`] crate.
+//!
+//! This crate shouldn't be used directly, but instead accessed by using the
+//! `derive` feature of the `crypto-serde` crate, which re-exports the
+//! [`HexOrBinSerde`][`derive@HexOrBinSerde`] macro from the toplevel.
+//!
+//! [`crypto-serde`]: https://docs.rs/crypto-serde/
+
+#![crate_type = "proc-macro"]
+#![forbid(unsafe_code, clippy::unwrap_used)]
+#![warn(rust_2018_idioms, trivial_casts, unused_qualifications)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro_error::{abort, proc_macro_error};
+use quote::quote;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, Meta, NestedMeta, Type};
+
+/// Attribute name.
+const ATTR_NAME: &str = "hex";
+
+/// Derive `Serialize` and `Deserialize` on a newtype wrapping bytes.
+///
+/// The newtype is serialized as hex when using human-readable formats, or
+/// as raw binary when the format is binary, in the same manner as
+/// `crypto_serde::HexOrBin`.
+///
+/// The struct must have exactly one field, either unnamed or named, whose
+/// type is either a byte array (`[u8; N]`), or impls `AsRef<[u8]>` and
+/// `TryFrom<Vec<u8>>` (e.g. `Vec<u8>`). Byte arrays are deserialized
+/// without allocating.
+///
+/// # Usage
+///
+/// ```ignore
+/// // NOTE: requires the `derive` feature of `crypto-serde`
+/// use crypto_serde::HexOrBinSerde;
+///
+/// #[derive(HexOrBinSerde)]
+/// #[hex(upper)]
+/// pub struct Digest([u8; 32]);
+/// ```
+///
+/// # `#[hex(...)]` attribute
+///
+/// - `#[hex(lower)]`: serialize as lower case hex (the default)
+/// - `#[hex(upper)]`: serialize as upper case hex
+///
+/// Hex in either case is accepted when deserializing.
+#[proc_macro_derive(HexOrBinSerde, attributes(hex))]
+#[proc_macro_error]
+pub fn derive_hex_or_bin_serde(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    DeriveHexOrBinSerde::new(input).to_tokens().into()
+}
+
+/// Derive `HexOrBinSerde` on a newtype.
+struct DeriveHexOrBinSerde {
+    /// The derive input.
+    input: DeriveInput,
+
+    /// Name of the field for structs with named fields.
+    field_name: Option<syn::Ident>,
+
+    /// Type of the field.
+    field_type: Type,
+
+    /// Serialize as upper case hex?
+    uppercase: bool,
+}
+
+impl DeriveHexOrBinSerde {
+    /// Parse [`DeriveInput`].
+    fn new(input: DeriveInput) -> Self {
+        let field = match &input.data {
+            Data::Struct(data) => match &data.fields {
+                Fields::Named(fields) if fields.named.len() == 1 => fields.named.first(),
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => fields.unnamed.first(),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        let field = match field {
+            Some(field) => field.clone(),
+            None => abort!(
+                input,
+                "`HexOrBinSerde` can only be derived on single-field structs"
+            ),
+        };
+
+        let uppercase = parse_case(&input.attrs);
+
+        Self {
+            field_name: field.ident,
+            field_type: field.ty,
+            uppercase,
+            input,
+        }
+    }
+
+    /// Lower the derived output into a [`TokenStream2`].
+    fn to_tokens(&self) -> TokenStream2 {
+        let ident = &self.input.ident;
+        let (impl_generics, ty_generics, where_clause) = self.input.generics.split_for_impl();
+
+        let mut de_generics = self.input.generics.clone();
+        de_generics.params.insert(0, syn::parse_quote!('__de));
+        let (de_impl_generics, _, _) = de_generics.split_for_impl();
+
+        let field = match &self.field_name {
+            Some(name) => quote!(#name),
+            None => quote!(0),
+        };
+
+        let serialize = if self.uppercase {
+            quote!(::crypto_serde::serialize_hex_upper_or_bin)
+        } else {
+            quote!(::crypto_serde::serialize_hex_lower_or_bin)
+        };
+
+        let decode = match &self.field_type {
+            Type::Array(_) => quote! {
+                ::crypto_serde::array::deserialize_hex_or_bin(deserializer)?
+            },
+            ty => quote! {{
+                let bytes = ::crypto_serde::slice::deserialize_hex_or_bin_vec(deserializer)?;
+                let len = bytes.len();
+                <#ty as ::core::convert::TryFrom<_>>::try_from(bytes).map_err(|_| {
+                    <__D::Error as ::crypto_serde::serde::de::Error>::invalid_length(
+                        len,
+                        &"a valid byte length",
+                    )
+                })?
+            }},
+        };
+
+        let construct = match &self.field_name {
+            Some(name) => quote!(Self { #name: #decode }),
+            None => quote!(Self(#decode)),
+        };
+
+        quote! {
+            impl #impl_generics ::crypto_serde::serde::Serialize for #ident #ty_generics #where_clause {
+                fn serialize<__S>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+                where
+                    __S: ::crypto_serde::serde::Serializer,
+                {
+                    #serialize(&self.#field, serializer)
+                }
+            }
+
+            impl #de_impl_generics ::crypto_serde::serde::Deserialize<'__de> for #ident #ty_generics #where_clause {
+                fn deserialize<__D>(deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+                where
+                    __D: ::crypto_serde::serde::Deserializer<'__de>,
+                {
+                    Ok(#construct)
+                }
+            }
+        }
+    }
+}
+
+/// Parse the `#[hex(...)]` attribute, returning whether upper case was
+/// selected.
+fn parse_case(attrs: &[Attribute]) -> bool {
+    let mut uppercase = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident(ATTR_NAME)) {
+        let nested = match attr.parse_meta() {
+            Ok(Meta::List(list)) => list.nested,
+            _ => abort!(attr, "expected `#[hex(lower)]` or `#[hex(upper)]`"),
+        };
+
+        for meta in nested {
+            let value = match &meta {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("lower") => false,
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("upper") => true,
+                _ => abort!(
+                    meta,
+                    "invalid `hex` attribute (valid options are `lower` and `upper`)"
+                ),
+            };
+
+            if uppercase.replace(value).is_some() {
+                abort!(meta, "duplicate `hex` case attribute");
+            }
+        }
+    }
+
+    uppercase.unwrap_or(false)
+}
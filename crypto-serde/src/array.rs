@@ -0,0 +1,323 @@
+//! Fixed-size byte array serialization helpers.
+//!
+//! These helpers are suitable for fixed-size values such as keys, nonces,
+//! and salts, and do not require `alloc` to deserialize.
+
+use core::fmt;
+use serde::{
+    de::{Error, Expected, SeqAccess, Visitor},
+    Deserializer,
+};
+
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+use {
+    alloc::{string::String, vec::Vec},
+    core::str,
+};
+
+#[cfg(feature = "zeroize")]
+use {
+    serde::{ser, Deserialize, Serialize},
+    zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing},
+};
+
+/// Deserialize either hex (upper or lower case) when using human-readable
+/// formats or binary if the format is binary, returning a `[u8; N]`.
+///
+/// The input must contain exactly `N` bytes.
+pub fn deserialize_hex_or_bin<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let mut array = [0u8; N];
+    deserialize_hex_or_bin_exact(deserializer, &mut array)?;
+    Ok(array)
+}
+
+/// Deserialize either hex (upper or lower case) when using human-readable
+/// formats or binary if the format is binary, returning a
+/// [`GenericArray`][`generic_array::GenericArray`].
+///
+/// The input must contain exactly `N` bytes.
+#[cfg(feature = "generic-array")]
+#[cfg_attr(docsrs, doc(cfg(feature = "generic-array")))]
+pub fn deserialize_hex_or_bin_generic_array<'de, D, N>(
+    deserializer: D,
+) -> Result<generic_array::GenericArray<u8, N>, D::Error>
+where
+    D: Deserializer<'de>,
+    N: generic_array::ArrayLength<u8>,
+{
+    let mut array = generic_array::GenericArray::default();
+    deserialize_hex_or_bin_exact(deserializer, &mut array)?;
+    Ok(array)
+}
+
+/// Deserialize either hex (upper or lower case) when using human-readable
+/// formats or binary if the format is binary, returning a
+/// [`hybrid_array::Array`].
+///
+/// The input must contain exactly `U` bytes.
+///
+/// The `hybrid-array` feature requires Rust 1.81 or newer.
+#[cfg(feature = "hybrid-array")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hybrid-array")))]
+pub fn deserialize_hex_or_bin_hybrid_array<'de, D, U>(
+    deserializer: D,
+) -> Result<hybrid_array::Array<u8, U>, D::Error>
+where
+    D: Deserializer<'de>,
+    U: hybrid_array::ArraySize,
+{
+    let mut array = hybrid_array::Array::default();
+    deserialize_hex_or_bin_exact(deserializer, array.as_mut())?;
+    Ok(array)
+}
+
+/// Deserialize hex or binary into the given buffer, which must be filled
+/// exactly.
+pub(crate) fn deserialize_hex_or_bin_exact<'de, D>(
+    deserializer: D,
+    buffer: &mut [u8],
+) -> Result<(), D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(ExactVisitor { buffer })
+    } else {
+        deserializer.deserialize_bytes(ExactVisitor { buffer })
+    }
+}
+
+/// Visitor which fills a fixed-size buffer from hex strings, bytes, or
+/// sequences.
+struct ExactVisitor<'b> {
+    buffer: &'b mut [u8],
+}
+
+impl<'de, 'b> Visitor<'de> for ExactVisitor<'b> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "hex string or bytes of length {}",
+            self.buffer.len()
+        )
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<(), E> {
+        let len = self.buffer.len();
+
+        match base16ct::mixed::decode(v, self.buffer) {
+            Ok(decoded) if decoded.len() == len => Ok(()),
+            Ok(decoded) => Err(E::invalid_length(decoded.len(), &ExpectedLen(len))),
+            Err(e) => Err(E::custom(e)),
+        }
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<(), E> {
+        if v.len() != self.buffer.len() {
+            return Err(E::invalid_length(v.len(), &self));
+        }
+
+        self.buffer.copy_from_slice(v);
+        Ok(())
+    }
+
+    // Owned inputs may contain secrets, so wipe them once decoded
+    #[cfg(all(feature = "alloc", feature = "zeroize"))]
+    fn visit_string<E: Error>(self, v: String) -> Result<(), E> {
+        let mut bytes = v.into_bytes();
+        let result = self.visit_str(str::from_utf8(&bytes).map_err(E::custom)?);
+        bytes.as_mut_slice().zeroize();
+        result
+    }
+
+    #[cfg(all(feature = "alloc", feature = "zeroize"))]
+    fn visit_byte_buf<E: Error>(self, mut v: Vec<u8>) -> Result<(), E> {
+        let result = self.visit_bytes(&v);
+        v.as_mut_slice().zeroize();
+        result
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<(), A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let len = self.buffer.len();
+
+        for (i, byte) in self.buffer.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| A::Error::invalid_length(i, &ExpectedLen(len)))?;
+        }
+
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(A::Error::invalid_length(len + 1, &ExpectedLen(len)));
+        }
+
+        Ok(())
+    }
+}
+
+/// Expected number of bytes, used in error messages.
+struct ExpectedLen(usize);
+
+impl Expected for ExpectedLen {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{} bytes", self.0)
+    }
+}
+
+/// Deserialize a secret as either hex (upper or lower case) when using
+/// human-readable formats or binary if the format is binary.
+///
+/// The bytes are decoded directly into the returned [`Zeroizing`] buffer,
+/// which is wiped if decoding fails. Owned strings and byte buffers handed
+/// over by the deserializer are wiped once decoded.
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+pub fn deserialize_secret_hex_or_bin<'de, D, const N: usize>(
+    deserializer: D,
+) -> Result<Zeroizing<[u8; N]>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let mut secret = Zeroizing::new([0u8; N]);
+    deserialize_hex_or_bin_exact(deserializer, secret.as_mut())?;
+    Ok(secret)
+}
+
+/// Serializer/deserializer newtype for secret fixed-size byte arrays which
+/// encodes them as either binary or lower case hex.
+///
+/// The contents are zeroized on drop, as are the intermediate buffers used
+/// when serializing and deserializing.
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+pub struct SecretHexOrBin<const N: usize>(pub [u8; N]);
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl<const N: usize> AsRef<[u8]> for SecretHexOrBin<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl<const N: usize> From<[u8; N]> for SecretHexOrBin<N> {
+    fn from(bytes: [u8; N]) -> SecretHexOrBin<N> {
+        Self(bytes)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl<const N: usize> Serialize for SecretHexOrBin<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        #[cfg(feature = "alloc")]
+        if serializer.is_human_readable() {
+            let hex = base16ct::lower::encode_string(&self.0);
+            let result = serializer.serialize_str(&hex);
+            hex.into_bytes().as_mut_slice().zeroize();
+            return result;
+        }
+
+        crate::serialize_bin(&self.0, serializer)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl<'de, const N: usize> Deserialize<'de> for SecretHexOrBin<N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut secret = Self([0u8; N]);
+        deserialize_hex_or_bin_exact(deserializer, &mut secret.0)?;
+        Ok(secret)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl<const N: usize> Drop for SecretHexOrBin<N> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl<const N: usize> Zeroize for SecretHexOrBin<N> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl<const N: usize> ZeroizeOnDrop for SecretHexOrBin<N> {}
+
+/// Helpers for `Option<[u8; N]>` which serialize `Some` values as lower
+/// case hex when using human-readable formats or binary if the format is
+/// binary.
+///
+/// Use with `#[serde(with = "crypto_serde::array::option")]`.
+pub mod option {
+    use super::deserialize_hex_or_bin;
+    use crate::serialize_hex_lower_or_bin;
+    use serde::{ser, Deserialize, Deserializer, Serialize};
+
+    /// Serialize an `Option<[u8; N]>`.
+    pub fn serialize<S, const N: usize>(
+        value: &Option<[u8; N]>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        struct Wrapper<'a, const N: usize>(&'a [u8; N]);
+
+        impl<const N: usize> Serialize for Wrapper<'_, N> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ser::Serializer,
+            {
+                serialize_hex_lower_or_bin(self.0, serializer)
+            }
+        }
+
+        match value {
+            Some(array) => serializer.serialize_some(&Wrapper(array)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize an `Option<[u8; N]>`.
+    pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<Option<[u8; N]>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Wrapper<const N: usize>([u8; N]);
+
+        impl<'de, const N: usize> Deserialize<'de> for Wrapper<N> {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserialize_hex_or_bin(deserializer).map(Self)
+            }
+        }
+
+        Option::<Wrapper<N>>::deserialize(deserializer).map(|opt| opt.map(|w| w.0))
+    }
+}
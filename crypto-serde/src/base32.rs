@@ -0,0 +1,345 @@
+//! Base32 (RFC 4648) serialization helpers, with padding.
+//!
+//! These helpers encode bytes as Base32 when using human-readable formats,
+//! or as raw binary when the format is binary. Both upper and lower case
+//! output are supported. Either case is accepted when decoding, but not a
+//! mix of both.
+//!
+//! Encoding and decoding use the constant-time [`base32ct`] crate.
+//!
+//! See [`crate::base32_unpadded`] for the unpadded variant.
+
+use alloc::vec::Vec;
+use base32ct::{Base32, Base32Lower, Base32LowerUnpadded, Base32Unpadded, Encoding};
+use core::fmt;
+use serde::{
+    de::{Error, Expected, SeqAccess, Visitor},
+    ser, Deserialize, Deserializer, Serialize,
+};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Serialize the given type as padded lower case Base32 when using
+/// human-readable formats or binary if the format is binary.
+pub fn serialize_base32_lower_or_bin<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+    T: AsRef<[u8]>,
+{
+    serialize_or_bin::<S, T, false, true>(value, serializer)
+}
+
+/// Serialize the given type as padded upper case Base32 when using
+/// human-readable formats or binary if the format is binary.
+pub fn serialize_base32_upper_or_bin<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+    T: AsRef<[u8]>,
+{
+    serialize_or_bin::<S, T, true, true>(value, serializer)
+}
+
+/// Deserialize padded upper or lower case Base32 when using
+/// human-readable formats or binary if the format is binary, returning a
+/// [`Vec`].
+pub fn deserialize_base32_or_bin_vec<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_vec::<D, true>(deserializer)
+}
+
+/// Deserialize padded upper or lower case Base32 when using
+/// human-readable formats or binary if the format is binary, returning a
+/// `[u8; N]`.
+///
+/// The input must contain exactly `N` bytes.
+pub fn deserialize_base32_or_bin<'de, D, const N: usize>(
+    deserializer: D,
+) -> Result<[u8; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_array::<D, N, true>(deserializer)
+}
+
+/// [`Base32OrBin`] serializer which uses padded lower case.
+pub type Base32LowerOrBin = Base32OrBin<false, true>;
+
+/// [`Base32OrBin`] serializer which uses padded upper case.
+pub type Base32UpperOrBin = Base32OrBin<true, true>;
+
+/// Serializer/deserializer newtype which encodes bytes as either binary or
+/// Base32.
+///
+/// Use Base32 with human-readable formats, or raw binary with binary formats.
+/// See [`Base32LowerOrBin`] and [`Base32UpperOrBin`] for padded Base32, or
+/// [`crate::base32_unpadded`] for the unpadded aliases.
+pub struct Base32OrBin<const UPPERCASE: bool, const PADDED: bool>(pub Vec<u8>);
+
+impl<const UPPERCASE: bool, const PADDED: bool> AsRef<[u8]> for Base32OrBin<UPPERCASE, PADDED> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl<const UPPERCASE: bool, const PADDED: bool> From<&[u8]> for Base32OrBin<UPPERCASE, PADDED> {
+    fn from(bytes: &[u8]) -> Base32OrBin<UPPERCASE, PADDED> {
+        Self(bytes.into())
+    }
+}
+
+impl<const UPPERCASE: bool, const PADDED: bool> From<Vec<u8>> for Base32OrBin<UPPERCASE, PADDED> {
+    fn from(vec: Vec<u8>) -> Base32OrBin<UPPERCASE, PADDED> {
+        Self(vec)
+    }
+}
+
+impl<const UPPERCASE: bool, const PADDED: bool> From<Base32OrBin<UPPERCASE, PADDED>> for Vec<u8> {
+    fn from(vec: Base32OrBin<UPPERCASE, PADDED>) -> Vec<u8> {
+        vec.0
+    }
+}
+
+impl<const UPPERCASE: bool, const PADDED: bool> Serialize for Base32OrBin<UPPERCASE, PADDED> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serialize_or_bin::<S, Self, UPPERCASE, PADDED>(self, serializer)
+    }
+}
+
+impl<'de, const UPPERCASE: bool, const PADDED: bool> Deserialize<'de>
+    for Base32OrBin<UPPERCASE, PADDED>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_vec::<D, PADDED>(deserializer).map(Self)
+    }
+}
+
+#[cfg(feature = "serde_with")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde_with")))]
+impl<T, const UPPERCASE: bool, const PADDED: bool> serde_with::SerializeAs<T>
+    for Base32OrBin<UPPERCASE, PADDED>
+where
+    T: AsRef<[u8]>,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serialize_or_bin::<S, T, UPPERCASE, PADDED>(source, serializer)
+    }
+}
+
+#[cfg(feature = "serde_with")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde_with")))]
+impl<'de, T, const UPPERCASE: bool, const PADDED: bool> serde_with::DeserializeAs<'de, T>
+    for Base32OrBin<UPPERCASE, PADDED>
+where
+    T: TryFrom<Vec<u8>>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = deserialize_vec::<D, PADDED>(deserializer)?;
+        let len = bytes.len();
+        T::try_from(bytes).map_err(|_| D::Error::invalid_length(len, &"a valid byte length"))
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl<const UPPERCASE: bool, const PADDED: bool> Zeroize for Base32OrBin<UPPERCASE, PADDED> {
+    fn zeroize(&mut self) {
+        self.0.as_mut_slice().zeroize();
+    }
+}
+
+/// Serialize as Base32 or binary.
+pub(crate) fn serialize_or_bin<S, T, const UPPERCASE: bool, const PADDED: bool>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+    T: AsRef<[u8]> + ?Sized,
+{
+    if serializer.is_human_readable() {
+        let bytes = value.as_ref();
+        let encoded = match (UPPERCASE, PADDED) {
+            (true, true) => Base32::encode_string(bytes),
+            (false, true) => Base32Lower::encode_string(bytes),
+            (true, false) => Base32Unpadded::encode_string(bytes),
+            (false, false) => Base32LowerUnpadded::encode_string(bytes),
+        };
+        return serializer.serialize_str(&encoded);
+    }
+
+    crate::serialize_bin(value.as_ref(), serializer)
+}
+
+/// Deserialize Base32 or binary into a [`Vec`].
+pub(crate) fn deserialize_vec<'de, D, const PADDED: bool>(
+    deserializer: D,
+) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        struct StrVisitor<const PADDED: bool>;
+
+        impl<'de, const PADDED: bool> Visitor<'de> for StrVisitor<PADDED> {
+            type Value = Vec<u8>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a Base32 string")
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<Vec<u8>, E> {
+                decode_vec::<PADDED>(v).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(StrVisitor::<PADDED>)
+    } else {
+        crate::slice::deserialize_byte_buf(deserializer)
+    }
+}
+
+/// Deserialize Base32 or binary into a `[u8; N]`.
+pub(crate) fn deserialize_array<'de, D, const N: usize, const PADDED: bool>(
+    deserializer: D,
+) -> Result<[u8; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let mut array = [0u8; N];
+
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(ArrayVisitor::<PADDED> { buffer: &mut array })?;
+    } else {
+        deserializer.deserialize_bytes(ArrayVisitor::<PADDED> { buffer: &mut array })?;
+    }
+
+    Ok(array)
+}
+
+/// Visitor which fills a fixed-size buffer from Base32 strings, bytes, or
+/// sequences.
+struct ArrayVisitor<'b, const PADDED: bool> {
+    buffer: &'b mut [u8],
+}
+
+impl<'de, 'b, const PADDED: bool> Visitor<'de> for ArrayVisitor<'b, PADDED> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "Base32 string or bytes of length {}",
+            self.buffer.len()
+        )
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<(), E> {
+        let len = self.buffer.len();
+        let decoded_len = match decode::<PADDED>(v, self.buffer) {
+            Ok(decoded_len) => decoded_len,
+            // The input is too long for the buffer: decode it in full to
+            // report its actual length, or any other error it contains
+            Err(base32ct::Error::InvalidLength) => decoded_len::<PADDED>(v).map_err(E::custom)?,
+            Err(err) => return Err(E::custom(err)),
+        };
+
+        if decoded_len != len {
+            return Err(E::invalid_length(decoded_len, &ExpectedLen(len)));
+        }
+
+        Ok(())
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<(), E> {
+        if v.len() != self.buffer.len() {
+            return Err(E::invalid_length(v.len(), &self));
+        }
+
+        self.buffer.copy_from_slice(v);
+        Ok(())
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<(), A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let len = self.buffer.len();
+
+        for (i, byte) in self.buffer.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| A::Error::invalid_length(i, &ExpectedLen(len)))?;
+        }
+
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(A::Error::invalid_length(len + 1, &ExpectedLen(len)));
+        }
+
+        Ok(())
+    }
+}
+
+/// Expected number of bytes, used in error messages.
+struct ExpectedLen(usize);
+
+impl Expected for ExpectedLen {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{} bytes", self.0)
+    }
+}
+
+/// Decode upper or lower case Base32 into `output`, returning the number of
+/// bytes decoded.
+///
+/// The case is determined by the first letter of the input, so mixed case
+/// input is rejected.
+fn decode<const PADDED: bool>(input: &str, output: &mut [u8]) -> base32ct::Result<usize> {
+    fn decode_as<E: Encoding>(input: &str, output: &mut [u8]) -> base32ct::Result<usize> {
+        E::decode(input, output).map(|bytes| bytes.len())
+    }
+
+    let lowercase = input
+        .bytes()
+        .find(u8::is_ascii_alphabetic)
+        .map_or(false, |c| c.is_ascii_lowercase());
+
+    match (lowercase, PADDED) {
+        (false, true) => decode_as::<Base32>(input, output),
+        (true, true) => decode_as::<Base32Lower>(input, output),
+        (false, false) => decode_as::<Base32Unpadded>(input, output),
+        (true, false) => decode_as::<Base32LowerUnpadded>(input, output),
+    }
+}
+
+/// Decode upper or lower case Base32 into a [`Vec`].
+fn decode_vec<const PADDED: bool>(input: &str) -> base32ct::Result<Vec<u8>> {
+    let mut bytes = alloc::vec![0u8; input.len() * 5 / 8];
+    let len = decode::<PADDED>(input, &mut bytes)?;
+    bytes.truncate(len);
+    Ok(bytes)
+}
+
+/// Decode upper or lower case Base32 only to find out how many bytes it
+/// contains, wiping them afterwards.
+fn decoded_len<const PADDED: bool>(input: &str) -> base32ct::Result<usize> {
+    let mut bytes = alloc::vec![0u8; input.len() * 5 / 8];
+    let result = decode::<PADDED>(input, &mut bytes);
+    #[cfg(feature = "zeroize")]
+    bytes.as_mut_slice().zeroize();
+    result
+}
@@ -0,0 +1,65 @@
+//! Base32 (RFC 4648) serialization helpers, without padding.
+//!
+//! Unpadded Base32 is commonly used for TOTP secrets and Tor onion service
+//! addresses. These helpers behave like those in [`crate::base32`], but
+//! never emit `=` padding and reject it when decoding.
+
+use crate::base32::{deserialize_array, deserialize_vec, serialize_or_bin, Base32OrBin};
+use alloc::vec::Vec;
+use serde::{ser, Deserializer};
+
+/// Serialize the given type as unpadded lower case Base32 when using
+/// human-readable formats or binary if the format is binary.
+pub fn serialize_base32_unpadded_lower_or_bin<S, T>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+    T: AsRef<[u8]>,
+{
+    serialize_or_bin::<S, T, false, false>(value, serializer)
+}
+
+/// Serialize the given type as unpadded upper case Base32 when using
+/// human-readable formats or binary if the format is binary.
+pub fn serialize_base32_unpadded_upper_or_bin<S, T>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+    T: AsRef<[u8]>,
+{
+    serialize_or_bin::<S, T, true, false>(value, serializer)
+}
+
+/// Deserialize unpadded upper or lower case Base32 when using
+/// human-readable formats or binary if the format is binary, returning a
+/// [`Vec`].
+pub fn deserialize_base32_unpadded_or_bin_vec<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_vec::<D, false>(deserializer)
+}
+
+/// Deserialize unpadded upper or lower case Base32 when using
+/// human-readable formats or binary if the format is binary, returning a
+/// `[u8; N]`.
+///
+/// The input must contain exactly `N` bytes.
+pub fn deserialize_base32_unpadded_or_bin<'de, D, const N: usize>(
+    deserializer: D,
+) -> Result<[u8; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_array::<D, N, false>(deserializer)
+}
+
+/// [`Base32OrBin`] serializer which uses unpadded lower case.
+pub type Base32UnpaddedLowerOrBin = Base32OrBin<false, false>;
+
+/// [`Base32OrBin`] serializer which uses unpadded upper case.
+pub type Base32UnpaddedUpperOrBin = Base32OrBin<true, false>;
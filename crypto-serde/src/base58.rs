@@ -0,0 +1,386 @@
+//! Base58 serialization helpers using the Bitcoin alphabet.
+//!
+//! These helpers encode bytes as Base58 when using human-readable formats,
+//! or as raw binary when the format is binary. With the `base58check`
+//! feature, [`Base58CheckOrBin`] additionally appends (and verifies) a
+//! 4-byte checksum derived from a double SHA-256 of the payload, as used by
+//! Bitcoin addresses and WIF private keys.
+//!
+//! The alphabet lookups are branch-free, and the base conversion loops run
+//! over the whole working buffer regardless of its contents. Only the
+//! number of leading zero bytes (encoded as `1` characters) and the length
+//! of the input affect timing.
+//!
+//! Base58 conversion takes time quadratic in the length of the data, so
+//! values longer than [`MAX_LEN`] bytes are rejected rather than letting
+//! untrusted input cause excessive work.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+use serde::{
+    de::{Error, Visitor},
+    ser, Deserialize, Deserializer, Serialize,
+};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Maximum length in bytes of the data which can be encoded or decoded as
+/// Base58, including the checksum when using Base58Check.
+pub const MAX_LEN: usize = 2048;
+
+/// Upper bound on the length of a Base58 string encoding [`MAX_LEN`] bytes.
+const MAX_ENCODED_LEN: usize = MAX_LEN * 138 / 100 + 1;
+
+/// Serialize the given type as Base58 when using human-readable formats or
+/// binary if the format is binary.
+pub fn serialize_base58_or_bin<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+    T: AsRef<[u8]>,
+{
+    if serializer.is_human_readable() {
+        let encoded = encode_string(value.as_ref()).map_err(ser::Error::custom)?;
+        return serializer.serialize_str(&encoded);
+    }
+
+    crate::serialize_bin(value.as_ref(), serializer)
+}
+
+/// Deserialize Base58 when using human-readable formats or binary if the
+/// format is binary.
+pub fn deserialize_base58_or_bin<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(StrVisitor)
+    } else {
+        crate::slice::deserialize_byte_buf(deserializer)
+    }
+}
+
+/// Serialize the given type as Base58Check (i.e. Base58 with a 4-byte
+/// checksum appended) when using human-readable formats or binary if the
+/// format is binary.
+///
+/// The checksum is only included in the human-readable encoding.
+#[cfg(feature = "base58check")]
+#[cfg_attr(docsrs, doc(cfg(feature = "base58check")))]
+pub fn serialize_base58check_or_bin<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+    T: AsRef<[u8]>,
+{
+    if serializer.is_human_readable() {
+        let mut payload = value.as_ref().to_vec();
+        payload.extend_from_slice(&checksum(value.as_ref()));
+        let encoded = encode_string(&payload).map_err(ser::Error::custom)?;
+        return serializer.serialize_str(&encoded);
+    }
+
+    crate::serialize_bin(value.as_ref(), serializer)
+}
+
+/// Deserialize Base58Check when using human-readable formats or binary if
+/// the format is binary.
+///
+/// Returns an error if the checksum does not match. The returned bytes do
+/// not include the checksum.
+#[cfg(feature = "base58check")]
+#[cfg_attr(docsrs, doc(cfg(feature = "base58check")))]
+pub fn deserialize_base58check_or_bin<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        let bytes = deserializer.deserialize_str(StrVisitor)?;
+        verify_checksum(bytes).map_err(D::Error::custom)
+    } else {
+        crate::slice::deserialize_byte_buf(deserializer)
+    }
+}
+
+/// Visitor for Base58 strings.
+struct StrVisitor;
+
+impl<'de> Visitor<'de> for StrVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a Base58 string")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Vec<u8>, E> {
+        decode_vec(v).map_err(E::custom)
+    }
+}
+
+/// Length of a Base58Check checksum.
+#[cfg(feature = "base58check")]
+const CHECKSUM_LEN: usize = 4;
+
+/// Compute the Base58Check checksum of the given payload.
+#[cfg(feature = "base58check")]
+fn checksum(payload: &[u8]) -> [u8; CHECKSUM_LEN] {
+    use sha2::{Digest, Sha256};
+
+    let digest = Sha256::digest(Sha256::digest(payload));
+    let mut checksum = [0u8; CHECKSUM_LEN];
+    checksum.copy_from_slice(&digest[..CHECKSUM_LEN]);
+    checksum
+}
+
+/// Verify and strip the trailing checksum from the given decoded bytes.
+#[cfg(feature = "base58check")]
+fn verify_checksum(mut bytes: Vec<u8>) -> Result<Vec<u8>, &'static str> {
+    let payload_len = bytes
+        .len()
+        .checked_sub(CHECKSUM_LEN)
+        .ok_or("Base58Check string too short")?;
+
+    if checksum(&bytes[..payload_len]) != bytes[payload_len..] {
+        return Err("invalid Base58Check checksum");
+    }
+
+    bytes.truncate(payload_len);
+    Ok(bytes)
+}
+
+/// Encode the given bytes as a Base58 string, returning an error if there
+/// are more than [`MAX_LEN`] of them.
+fn encode_string(input: &[u8]) -> Result<String, &'static str> {
+    if input.len() > MAX_LEN {
+        return Err("data too long for Base58");
+    }
+
+    let zeros = input.iter().take_while(|&&b| b == 0).count();
+
+    // log(256) / log(58) ≈ 1.366
+    let mut digits = alloc::vec![0u8; (input.len() - zeros) * 138 / 100 + 1];
+
+    for &byte in &input[zeros..] {
+        let mut carry = u32::from(byte);
+
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+
+        debug_assert_eq!(carry, 0);
+    }
+
+    // Digits are stored least significant first
+    let significant = digits.iter().rposition(|&d| d != 0).map_or(0, |i| i + 1);
+    let mut output = String::with_capacity(zeros + significant);
+
+    for _ in 0..zeros {
+        output.push('1');
+    }
+
+    for &digit in digits[..significant].iter().rev() {
+        output.push(char::from(encode_digit(digit)));
+    }
+
+    Ok(output)
+}
+
+/// Decode the given Base58 string, returning an error if it contains
+/// invalid characters or decodes to more than [`MAX_LEN`] bytes.
+fn decode_vec(input: &str) -> Result<Vec<u8>, &'static str> {
+    // Reject overly long strings before doing any quadratic work
+    if input.len() > MAX_ENCODED_LEN {
+        return Err("Base58 string too long");
+    }
+
+    let input = input.as_bytes();
+    let zeros = input.iter().take_while(|&&c| c == b'1').count();
+
+    // log(58) / log(256) ≈ 0.733
+    let mut bytes = alloc::vec![0u8; (input.len() - zeros) * 733 / 1000 + 1];
+    let mut invalid = 0i16;
+
+    for &c in &input[zeros..] {
+        let value = decode_digit(c);
+        invalid |= value;
+
+        // Invalid characters are treated as zero so they can't overflow the
+        // buffer, with the whole input rejected below
+        let mut carry = (value & 0xff & !(value >> 8)) as u32;
+
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+
+        debug_assert_eq!(carry, 0);
+    }
+
+    if invalid < 0 {
+        return Err("invalid Base58 character");
+    }
+
+    // Bytes are stored least significant first
+    let significant = bytes.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+
+    if zeros + significant > MAX_LEN {
+        return Err("Base58 string too long");
+    }
+
+    let mut output = alloc::vec![0u8; zeros];
+    output.extend(bytes[..significant].iter().rev());
+    Ok(output)
+}
+
+/// Returns `1` if `value >= threshold`, or `0` otherwise.
+#[inline(always)]
+fn ge(value: i16, threshold: i16) -> i16 {
+    ((threshold - 1 - value) >> 8) & 1
+}
+
+/// Branch-free mapping of a digit in the range `0..58` to the Bitcoin
+/// Base58 alphabet.
+#[inline(always)]
+fn encode_digit(digit: u8) -> u8 {
+    let d = i16::from(digit);
+
+    // Skip over the gaps in the alphabet: `:`-`@`, `I`, `O`, `[`-`` ` ``, `l`
+    let c = d + 0x31 + 7 * ge(d, 9) + ge(d, 17) + ge(d, 22) + 6 * ge(d, 33) + ge(d, 44);
+    c as u8
+}
+
+/// Branch-free mapping of a Bitcoin Base58 character to its digit value,
+/// returning `-1` for invalid characters.
+#[inline(always)]
+fn decode_digit(c: u8) -> i16 {
+    /// Adds `c - lo + offset + 1` if `lo <= c <= hi`, or `0` otherwise.
+    #[inline(always)]
+    fn range(c: i16, lo: u8, hi: u8, offset: i16) -> i16 {
+        let (lo, hi) = (i16::from(lo), i16::from(hi));
+        (((lo - 1 - c) & (c - hi - 1)) >> 8) & (c - lo + offset + 1)
+    }
+
+    let c = i16::from(c);
+
+    -1 + range(c, b'1', b'9', 0)
+        + range(c, b'A', b'H', 9)
+        + range(c, b'J', b'N', 17)
+        + range(c, b'P', b'Z', 22)
+        + range(c, b'a', b'k', 33)
+        + range(c, b'm', b'z', 44)
+}
+
+/// Serializer/deserializer newtype which encodes bytes as either binary or
+/// Base58.
+///
+/// Use Base58 with human-readable formats, or raw binary with binary formats.
+pub struct Base58OrBin(pub Vec<u8>);
+
+/// Serializer/deserializer newtype which encodes bytes as either binary or
+/// Base58Check.
+///
+/// Use Base58 with a trailing 4-byte checksum with human-readable formats,
+/// or raw binary (without a checksum) with binary formats.
+#[cfg(feature = "base58check")]
+#[cfg_attr(docsrs, doc(cfg(feature = "base58check")))]
+pub struct Base58CheckOrBin(pub Vec<u8>);
+
+macro_rules! impl_newtype {
+    ($name:ident, $serialize:ident, $deserialize:ident) => {
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                self.0.as_ref()
+            }
+        }
+
+        impl From<&[u8]> for $name {
+            fn from(bytes: &[u8]) -> $name {
+                Self(bytes.into())
+            }
+        }
+
+        impl From<Vec<u8>> for $name {
+            fn from(vec: Vec<u8>) -> $name {
+                Self(vec)
+            }
+        }
+
+        impl From<$name> for Vec<u8> {
+            fn from(vec: $name) -> Vec<u8> {
+                vec.0
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ser::Serializer,
+            {
+                $serialize(self, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                $deserialize(deserializer).map(Self)
+            }
+        }
+
+        #[cfg(feature = "serde_with")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "serde_with")))]
+        impl<T> serde_with::SerializeAs<T> for $name
+        where
+            T: AsRef<[u8]>,
+        {
+            fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ser::Serializer,
+            {
+                $serialize(source, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde_with")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "serde_with")))]
+        impl<'de, T> serde_with::DeserializeAs<'de, T> for $name
+        where
+            T: TryFrom<Vec<u8>>,
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let bytes = $deserialize(deserializer)?;
+                let len = bytes.len();
+                T::try_from(bytes)
+                    .map_err(|_| D::Error::invalid_length(len, &"a valid byte length"))
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+        impl Zeroize for $name {
+            fn zeroize(&mut self) {
+                self.0.as_mut_slice().zeroize();
+            }
+        }
+    };
+}
+
+impl_newtype!(
+    Base58OrBin,
+    serialize_base58_or_bin,
+    deserialize_base58_or_bin
+);
+
+#[cfg(feature = "base58check")]
+impl_newtype!(
+    Base58CheckOrBin,
+    serialize_base58check_or_bin,
+    deserialize_base58check_or_bin
+);
@@ -0,0 +1,283 @@
+//! Base64 serialization helpers.
+//!
+//! These helpers encode bytes as Base64 when using human-readable formats,
+//! or as raw binary when the format is binary. Base64 is considerably more
+//! compact than hex for large payloads.
+//!
+//! The Base64 alphabet is selected using a type parameter which impls
+//! [`Encoding`], e.g. [`Base64`] for the standard alphabet or
+//! [`Base64UrlUnpadded`] for the URL-safe alphabet without padding:
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use crypto_serde::base64::{self, Base64UrlUnpadded};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Signature {
+//!     #[serde(
+//!         serialize_with = "base64::serialize_base64_or_bin::<Base64UrlUnpadded, _, _>",
+//!         deserialize_with = "base64::deserialize_base64_or_bin::<Base64UrlUnpadded, _>"
+//!     )]
+//!     value: Vec<u8>,
+//! }
+//! # }
+//! ```
+
+pub use base64ct::{Base64, Base64Unpadded, Base64Url, Base64UrlUnpadded, Encoding};
+
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Error, SeqAccess, Visitor},
+    ser, Deserializer,
+};
+
+#[cfg(feature = "alloc")]
+use {
+    alloc::vec::Vec,
+    serde::{Deserialize, Serialize},
+};
+
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+use zeroize::Zeroize;
+
+/// Serialize the given type as Base64 using the alphabet `E` when using
+/// human-readable formats or binary if the format is binary.
+pub fn serialize_base64_or_bin<E, S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    E: Encoding,
+    S: ser::Serializer,
+    T: AsRef<[u8]>,
+{
+    #[cfg(feature = "alloc")]
+    if serializer.is_human_readable() {
+        return serializer.serialize_str(&E::encode_string(value.as_ref()));
+    }
+
+    crate::serialize_bin(value.as_ref(), serializer)
+}
+
+/// Deserialize Base64 using the alphabet `E` when using human-readable
+/// formats or binary if the format is binary.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn deserialize_base64_or_bin<'de, E, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    E: Encoding,
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        struct StrVisitor<E>(PhantomData<E>);
+
+        impl<'de, E: Encoding> Visitor<'de> for StrVisitor<E> {
+            type Value = Vec<u8>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a Base64 string")
+            }
+
+            fn visit_str<Err: Error>(self, v: &str) -> Result<Vec<u8>, Err> {
+                E::decode_vec(v).map_err(Err::custom)
+            }
+        }
+
+        deserializer.deserialize_str(StrVisitor::<E>(PhantomData))
+    } else {
+        crate::slice::deserialize_byte_buf(deserializer)
+    }
+}
+
+/// Deserialize Base64 using the alphabet `E` when using human-readable
+/// formats or binary if the format is binary, writing the decoded bytes into
+/// the provided buffer.
+///
+/// Returns the subslice of `buffer` containing the decoded bytes. An error
+/// is returned if the decoded bytes do not fit in `buffer`.
+///
+/// This function does not require `alloc`.
+pub fn deserialize_base64_or_bin_slice<'de, 'b, E, D>(
+    deserializer: D,
+    buffer: &'b mut [u8],
+) -> Result<&'b [u8], D::Error>
+where
+    E: Encoding,
+    D: Deserializer<'de>,
+{
+    let len = if deserializer.is_human_readable() {
+        deserializer.deserialize_str(SliceVisitor::<E>::new(buffer))?
+    } else {
+        deserializer.deserialize_bytes(SliceVisitor::<E>::new(buffer))?
+    };
+
+    Ok(&buffer[..len])
+}
+
+/// Visitor which decodes into a provided buffer, returning the number of
+/// bytes written.
+struct SliceVisitor<'b, E> {
+    buffer: &'b mut [u8],
+    encoding: PhantomData<E>,
+}
+
+impl<'b, E> SliceVisitor<'b, E> {
+    fn new(buffer: &'b mut [u8]) -> Self {
+        Self {
+            buffer,
+            encoding: PhantomData,
+        }
+    }
+}
+
+impl<'de, 'b, E: Encoding> Visitor<'de> for SliceVisitor<'b, E> {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "a Base64 string or bytes of at most {} bytes",
+            self.buffer.len()
+        )
+    }
+
+    fn visit_str<Err: Error>(self, v: &str) -> Result<usize, Err> {
+        E::decode(v, self.buffer)
+            .map(|decoded| decoded.len())
+            .map_err(Err::custom)
+    }
+
+    fn visit_bytes<Err: Error>(self, v: &[u8]) -> Result<usize, Err> {
+        if v.len() > self.buffer.len() {
+            return Err(Err::invalid_length(v.len(), &self));
+        }
+
+        self.buffer[..v.len()].copy_from_slice(v);
+        Ok(v.len())
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<usize, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut len = 0;
+
+        while let Some(byte) = seq.next_element()? {
+            *self
+                .buffer
+                .get_mut(len)
+                .ok_or_else(|| A::Error::invalid_length(len + 1, &"fewer bytes"))? = byte;
+
+            len += 1;
+        }
+
+        Ok(len)
+    }
+}
+
+/// Serializer/deserializer newtype which encodes bytes as either binary or
+/// Base64 using the alphabet `E`.
+///
+/// Use Base64 with human-readable formats, or raw binary with binary formats.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct Base64OrBin<E: Encoding = Base64>(pub Vec<u8>, PhantomData<E>);
+
+/// [`Base64OrBin`] serializer which uses the URL-safe alphabet without
+/// padding.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub type Base64UrlOrBin = Base64OrBin<Base64UrlUnpadded>;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<E: Encoding> AsRef<[u8]> for Base64OrBin<E> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<E: Encoding> From<&[u8]> for Base64OrBin<E> {
+    fn from(bytes: &[u8]) -> Base64OrBin<E> {
+        Self(bytes.into(), PhantomData)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<E: Encoding> From<Vec<u8>> for Base64OrBin<E> {
+    fn from(vec: Vec<u8>) -> Base64OrBin<E> {
+        Self(vec, PhantomData)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<E: Encoding> From<Base64OrBin<E>> for Vec<u8> {
+    fn from(vec: Base64OrBin<E>) -> Vec<u8> {
+        vec.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<E: Encoding> Serialize for Base64OrBin<E> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serialize_base64_or_bin::<E, _, _>(self, serializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'de, E: Encoding> Deserialize<'de> for Base64OrBin<E> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_base64_or_bin::<E, _>(deserializer).map(Self::from)
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "serde_with"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "serde_with"))))]
+impl<T, E> serde_with::SerializeAs<T> for Base64OrBin<E>
+where
+    T: AsRef<[u8]>,
+    E: Encoding,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serialize_base64_or_bin::<E, _, _>(source, serializer)
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "serde_with"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "serde_with"))))]
+impl<'de, T, E> serde_with::DeserializeAs<'de, T> for Base64OrBin<E>
+where
+    T: TryFrom<Vec<u8>>,
+    E: Encoding,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = deserialize_base64_or_bin::<E, _>(deserializer)?;
+        let len = bytes.len();
+        T::try_from(bytes).map_err(|_| D::Error::invalid_length(len, &"a valid byte length"))
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "zeroize"))))]
+impl<E: Encoding> Zeroize for Base64OrBin<E> {
+    fn zeroize(&mut self) {
+        self.0.as_mut_slice().zeroize();
+    }
+}
@@ -0,0 +1,207 @@
+//! Bech32 and Bech32m serialization helpers.
+//!
+//! These helpers encode bytes as Bech32 (BIP-173) or Bech32m (BIP-350) when
+//! using human-readable formats, or as raw binary when the format is binary.
+//!
+//! The human-readable part (HRP), variant, and case are selected using a type
+//! parameter which impls [`Hrp`]:
+//!
+//! ```
+//! use crypto_serde::bech32::{Bech32OrBin, Hrp};
+//!
+//! /// `age` X25519 recipient.
+//! struct AgeRecipient;
+//!
+//! impl Hrp for AgeRecipient {
+//!     const HRP: &'static str = "age";
+//! }
+//!
+//! let recipient = Bech32OrBin::<AgeRecipient>::from(&[0u8; 32][..]);
+//! let json = serde_json::to_string(&recipient).unwrap();
+//! assert!(json.starts_with("\"age1"));
+//! ```
+
+pub use bech32::Variant;
+
+use alloc::vec::Vec;
+use bech32::{FromBase32, ToBase32};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Error, Unexpected, Visitor},
+    ser, Deserialize, Deserializer, Serialize,
+};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Parameters for a Bech32 encoding.
+pub trait Hrp {
+    /// Human-readable part, e.g. `bc` or `age`.
+    ///
+    /// Must be lower case. It is compared case-insensitively when decoding.
+    const HRP: &'static str;
+
+    /// Checksum variant. Defaults to [`Variant::Bech32`].
+    const VARIANT: Variant = Variant::Bech32;
+
+    /// Serialize using upper case, e.g. for `AGE-SECRET-KEY-1...`.
+    /// Defaults to lower case.
+    const UPPERCASE: bool = false;
+}
+
+/// Serialize the given type as Bech32 using the parameters `H` when using
+/// human-readable formats or binary if the format is binary.
+pub fn serialize_bech32_or_bin<H, S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    H: Hrp,
+    S: ser::Serializer,
+    T: AsRef<[u8]>,
+{
+    if serializer.is_human_readable() {
+        let mut encoded = bech32::encode(H::HRP, value.as_ref().to_base32(), H::VARIANT)
+            .map_err(ser::Error::custom)?;
+
+        if H::UPPERCASE {
+            encoded.make_ascii_uppercase();
+        }
+
+        return serializer.serialize_str(&encoded);
+    }
+
+    crate::serialize_bin(value.as_ref(), serializer)
+}
+
+/// Deserialize Bech32 using the parameters `H` when using human-readable
+/// formats or binary if the format is binary.
+///
+/// The HRP and variant must match `H`. Either case is accepted, but not a
+/// mix of both.
+pub fn deserialize_bech32_or_bin<'de, H, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    H: Hrp,
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(StrVisitor::<H>(PhantomData))
+    } else {
+        crate::slice::deserialize_byte_buf(deserializer)
+    }
+}
+
+/// Visitor for Bech32 strings.
+struct StrVisitor<H>(PhantomData<H>);
+
+impl<'de, H: Hrp> Visitor<'de> for StrVisitor<H> {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let variant = match H::VARIANT {
+            Variant::Bech32 => "Bech32",
+            Variant::Bech32m => "Bech32m",
+        };
+
+        write!(formatter, "a {} string with HRP \"{}\"", variant, H::HRP)
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Vec<u8>, E> {
+        let (hrp, data, variant) = bech32::decode(v).map_err(E::custom)?;
+
+        if !hrp.eq_ignore_ascii_case(H::HRP) {
+            return Err(E::invalid_value(Unexpected::Str(v), &self));
+        }
+
+        if variant != H::VARIANT {
+            return Err(E::custom("unexpected Bech32 variant"));
+        }
+
+        Vec::<u8>::from_base32(&data).map_err(E::custom)
+    }
+}
+
+/// Serializer/deserializer newtype which encodes bytes as either binary or
+/// Bech32 using the parameters `H`.
+///
+/// Use Bech32 with human-readable formats, or raw binary with binary formats.
+pub struct Bech32OrBin<H: Hrp>(pub Vec<u8>, PhantomData<H>);
+
+impl<H: Hrp> AsRef<[u8]> for Bech32OrBin<H> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl<H: Hrp> From<&[u8]> for Bech32OrBin<H> {
+    fn from(bytes: &[u8]) -> Bech32OrBin<H> {
+        Self(bytes.into(), PhantomData)
+    }
+}
+
+impl<H: Hrp> From<Vec<u8>> for Bech32OrBin<H> {
+    fn from(vec: Vec<u8>) -> Bech32OrBin<H> {
+        Self(vec, PhantomData)
+    }
+}
+
+impl<H: Hrp> From<Bech32OrBin<H>> for Vec<u8> {
+    fn from(vec: Bech32OrBin<H>) -> Vec<u8> {
+        vec.0
+    }
+}
+
+impl<H: Hrp> Serialize for Bech32OrBin<H> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serialize_bech32_or_bin::<H, _, _>(self, serializer)
+    }
+}
+
+impl<'de, H: Hrp> Deserialize<'de> for Bech32OrBin<H> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_bech32_or_bin::<H, _>(deserializer).map(Self::from)
+    }
+}
+
+#[cfg(feature = "serde_with")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde_with")))]
+impl<T, H> serde_with::SerializeAs<T> for Bech32OrBin<H>
+where
+    T: AsRef<[u8]>,
+    H: Hrp,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serialize_bech32_or_bin::<H, _, _>(source, serializer)
+    }
+}
+
+#[cfg(feature = "serde_with")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde_with")))]
+impl<'de, T, H> serde_with::DeserializeAs<'de, T> for Bech32OrBin<H>
+where
+    T: TryFrom<Vec<u8>>,
+    H: Hrp,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = deserialize_bech32_or_bin::<H, _>(deserializer)?;
+        let len = bytes.len();
+        T::try_from(bytes).map_err(|_| D::Error::invalid_length(len, &"a valid byte length"))
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl<H: Hrp> Zeroize for Bech32OrBin<H> {
+    fn zeroize(&mut self) {
+        self.0.as_mut_slice().zeroize();
+    }
+}
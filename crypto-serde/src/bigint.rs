@@ -0,0 +1,67 @@
+//! Serialization helpers for [`crypto_bigint`] unsigned integers.
+//!
+//! Integers are serialized as fixed-width big endian lower case hex when
+//! using human-readable formats, or as little endian bytes (i.e. the limbs
+//! in order, least significant first) when the format is binary. Hex input
+//! may be upper or lower case, but must not omit leading zeros.
+//!
+//! Use with `#[serde(with = "crypto_serde::bigint")]`:
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use crypto_bigint::U256;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Point {
+//!     #[serde(with = "crypto_serde::bigint")]
+//!     x: U256,
+//!     #[serde(with = "crypto_serde::bigint")]
+//!     y: U256,
+//! }
+//! # }
+//! ```
+
+use crate::array::deserialize_hex_or_bin_exact;
+use crypto_bigint::{Encoding, UInt};
+use serde::{ser, Deserializer};
+
+/// Serialize a [`UInt`] as fixed-width big endian lower case hex when using
+/// human-readable formats or little endian bytes if the format is binary.
+pub fn serialize<S, const LIMBS: usize>(
+    value: &UInt<LIMBS>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+    UInt<LIMBS>: Encoding,
+{
+    #[cfg(feature = "alloc")]
+    if serializer.is_human_readable() {
+        return serializer.serialize_str(&base16ct::lower::encode_string(
+            value.to_be_bytes().as_ref(),
+        ));
+    }
+
+    crate::serialize_bin(value.to_le_bytes().as_ref(), serializer)
+}
+
+/// Deserialize a [`UInt`] from fixed-width big endian hex (upper or lower
+/// case) when using human-readable formats or little endian bytes if the
+/// format is binary.
+pub fn deserialize<'de, D, const LIMBS: usize>(deserializer: D) -> Result<UInt<LIMBS>, D::Error>
+where
+    D: Deserializer<'de>,
+    UInt<LIMBS>: Encoding,
+{
+    let human_readable = deserializer.is_human_readable();
+    let mut repr = UInt::<LIMBS>::ZERO.to_be_bytes();
+    deserialize_hex_or_bin_exact(deserializer, repr.as_mut())?;
+
+    if human_readable {
+        Ok(UInt::from_be_bytes(repr))
+    } else {
+        Ok(UInt::from_le_bytes(repr))
+    }
+}
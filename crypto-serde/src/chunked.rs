@@ -0,0 +1,188 @@
+//! Chunked hex serialization helpers for very large byte strings.
+//!
+//! Serializing a large blob (e.g. a multi-megabyte ciphertext) as a single
+//! hex string requires allocating the entire hex encoding up front, more
+//! than doubling peak memory usage. These helpers instead serialize the
+//! bytes as a sequence of hex strings of at most [`CHUNK_SIZE`] bytes each,
+//! encoding each chunk into a single reusable buffer.
+//!
+//! As with the other helpers in this crate, binary formats use raw bytes.
+//!
+//! When deserializing, both a sequence of hex strings and a single hex
+//! string are accepted, and each chunk is decoded directly into the output.
+
+use alloc::vec::Vec;
+use core::fmt;
+use serde::{
+    de::{DeserializeSeed, Error, SeqAccess, Visitor},
+    ser::{self, SerializeSeq},
+    Deserialize, Deserializer, Serialize,
+};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Maximum number of bytes encoded into each hex string.
+pub const CHUNK_SIZE: usize = 32 * 1024;
+
+/// Serialize the given type as a sequence of lower case hex strings when
+/// using human-readable formats or binary if the format is binary.
+pub fn serialize_hex_chunks_or_bin<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+    T: AsRef<[u8]>,
+{
+    let bytes = value.as_ref();
+
+    if !serializer.is_human_readable() {
+        return crate::serialize_bin(bytes, serializer);
+    }
+
+    let chunks = bytes.chunks(CHUNK_SIZE);
+    let mut seq = serializer.serialize_seq(Some(chunks.len()))?;
+    let mut buf = alloc::vec![0u8; bytes.len().min(CHUNK_SIZE) * 2];
+
+    for chunk in chunks {
+        let hex = base16ct::lower::encode_str(chunk, &mut buf).map_err(ser::Error::custom)?;
+        seq.serialize_element(hex)?;
+    }
+
+    seq.end()
+}
+
+/// Deserialize either a sequence of hex strings, a single hex string (upper
+/// or lower case) when using human-readable formats, or binary if the format
+/// is binary.
+pub fn deserialize_hex_chunks_or_bin<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(ChunksVisitor)
+    } else {
+        crate::slice::deserialize_byte_buf(deserializer)
+    }
+}
+
+/// Decode a hex chunk, appending it to `bytes`.
+fn decode_chunk(bytes: &mut Vec<u8>, hex: &str) -> Result<(), base16ct::Error> {
+    let start = bytes.len();
+    bytes.resize(start + hex.len() / 2, 0);
+
+    match base16ct::mixed::decode(hex, &mut bytes[start..]) {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            bytes.truncate(start);
+            Err(e)
+        }
+    }
+}
+
+/// Visitor for hex chunks.
+struct ChunksVisitor;
+
+impl<'de> Visitor<'de> for ChunksVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a hex string or sequence of hex strings")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Vec<u8>, E> {
+        let mut bytes = Vec::new();
+        decode_chunk(&mut bytes, v).map_err(E::custom)?;
+        Ok(bytes)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Vec<u8>, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        /// Hex chunk which is decoded as soon as it is deserialized.
+        struct Chunk<'a>(&'a mut Vec<u8>);
+
+        impl<'de> DeserializeSeed<'de> for Chunk<'_> {
+            type Value = ();
+
+            fn deserialize<D>(self, deserializer: D) -> Result<(), D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_str(self)
+            }
+        }
+
+        impl<'de> Visitor<'de> for Chunk<'_> {
+            type Value = ();
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a hex string")
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<(), E> {
+                decode_chunk(self.0, v).map_err(E::custom)
+            }
+        }
+
+        let mut bytes = Vec::new();
+        while seq.next_element_seed(Chunk(&mut bytes))?.is_some() {}
+        Ok(bytes)
+    }
+}
+
+/// Serializer/deserializer newtype which encodes bytes as either binary or
+/// a sequence of lower case hex strings.
+///
+/// Use chunked hexadecimal with human-readable formats, or raw binary with
+/// binary formats.
+pub struct HexChunksOrBin(pub Vec<u8>);
+
+impl AsRef<[u8]> for HexChunksOrBin {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl From<&[u8]> for HexChunksOrBin {
+    fn from(bytes: &[u8]) -> HexChunksOrBin {
+        Self(bytes.into())
+    }
+}
+
+impl From<Vec<u8>> for HexChunksOrBin {
+    fn from(vec: Vec<u8>) -> HexChunksOrBin {
+        Self(vec)
+    }
+}
+
+impl From<HexChunksOrBin> for Vec<u8> {
+    fn from(vec: HexChunksOrBin) -> Vec<u8> {
+        vec.0
+    }
+}
+
+impl Serialize for HexChunksOrBin {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serialize_hex_chunks_or_bin(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for HexChunksOrBin {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_hex_chunks_or_bin(deserializer).map(Self)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl Zeroize for HexChunksOrBin {
+    fn zeroize(&mut self) {
+        self.0.as_mut_slice().zeroize();
+    }
+}
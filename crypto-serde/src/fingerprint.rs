@@ -0,0 +1,149 @@
+//! Colon-delimited fingerprint serialization helpers.
+//!
+//! Fingerprints are serialized as colon-separated upper case hex (e.g.
+//! `AB:CD:EF`) when using human-readable formats, in the style of tools like
+//! OpenSSL, or as raw bytes when the format is binary. When deserializing,
+//! hex with or without delimiters is accepted in either case.
+
+use crate::array::deserialize_hex_or_bin;
+use core::fmt;
+use serde::{
+    de::{Error, Unexpected, Visitor},
+    ser, Deserialize, Deserializer, Serialize,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// Delimiter placed between each byte of a fingerprint.
+const DELIMITER: char = ':';
+
+/// Serialize the given type as colon-separated upper case hex when using
+/// human-readable formats or binary if the format is binary.
+pub fn serialize_fingerprint_or_bin<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+    T: AsRef<[u8]>,
+{
+    #[cfg(feature = "alloc")]
+    if serializer.is_human_readable() {
+        let bytes = value.as_ref();
+        let mut fingerprint = String::with_capacity((bytes.len() * 3).saturating_sub(1));
+
+        for (i, byte) in bytes.iter().enumerate() {
+            if i > 0 {
+                fingerprint.push(DELIMITER);
+            }
+
+            let mut hex = [0u8; 2];
+            fingerprint.push_str(
+                base16ct::upper::encode_str(&[*byte], &mut hex).map_err(ser::Error::custom)?,
+            );
+        }
+
+        return serializer.serialize_str(&fingerprint);
+    }
+
+    crate::serialize_bin(value.as_ref(), serializer)
+}
+
+/// Deserialize colon-separated or undelimited hex (upper or lower case)
+/// when using human-readable formats or binary if the format is binary,
+/// returning a `[u8; N]`.
+pub fn deserialize_fingerprint_or_bin<'de, D, const N: usize>(
+    deserializer: D,
+) -> Result<[u8; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(FingerprintVisitor::<N>)
+    } else {
+        deserialize_hex_or_bin(deserializer)
+    }
+}
+
+/// Visitor for colon-separated fingerprints.
+struct FingerprintVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for FingerprintVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "a {}-byte hex fingerprint", N)
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<[u8; N], E> {
+        let mut fingerprint = [0u8; N];
+
+        if !v.contains(DELIMITER) {
+            return match base16ct::mixed::decode(v, &mut fingerprint) {
+                Ok(decoded) if decoded.len() == N => Ok(fingerprint),
+                Ok(decoded) => Err(E::invalid_length(decoded.len(), &self)),
+                Err(e) => Err(E::custom(e)),
+            };
+        }
+
+        let mut len = 0;
+
+        for part in v.split(DELIMITER) {
+            let byte = fingerprint
+                .get_mut(len..len + 1)
+                .ok_or_else(|| E::invalid_length(len + 1, &self))?;
+
+            if part.len() != 2 {
+                return Err(E::invalid_value(Unexpected::Str(part), &self));
+            }
+
+            base16ct::mixed::decode(part, byte).map_err(E::custom)?;
+            len += 1;
+        }
+
+        if len != N {
+            return Err(E::invalid_length(len, &self));
+        }
+
+        Ok(fingerprint)
+    }
+}
+
+/// Serializer/deserializer newtype for fingerprints which encodes them as
+/// either binary or colon-separated upper case hex.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FingerprintHex<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> AsRef<[u8]> for FingerprintHex<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for FingerprintHex<N> {
+    fn from(bytes: [u8; N]) -> FingerprintHex<N> {
+        Self(bytes)
+    }
+}
+
+impl<const N: usize> From<FingerprintHex<N>> for [u8; N] {
+    fn from(fingerprint: FingerprintHex<N>) -> [u8; N] {
+        fingerprint.0
+    }
+}
+
+impl<const N: usize> Serialize for FingerprintHex<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serialize_fingerprint_or_bin(self, serializer)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for FingerprintHex<N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_fingerprint_or_bin(deserializer).map(Self)
+    }
+}
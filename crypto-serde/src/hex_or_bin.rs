@@ -0,0 +1,104 @@
+//! Helpers for types which convert to and from byte slices, such as
+//! signatures and public keys.
+//!
+//! Values are serialized as lower case hex when using human-readable formats,
+//! or as byte strings when the format is binary. Hex is accepted in either
+//! case when deserializing.
+//!
+//! Works with any type which impls `AsRef<[u8]>` (to serialize) and
+//! `TryFrom<&[u8]>` (to deserialize), so types which don't impl
+//! [`Serialize`] themselves don't need a bespoke module. Deserializing fails
+//! if the conversion does, e.g. if the input has the wrong length.
+//!
+//! Use with `#[serde(with = "crypto_serde::hex_or_bin")]`:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! # #[derive(Clone, Copy)]
+//! # struct Signature([u8; 64]);
+//! # impl AsRef<[u8]> for Signature {
+//! #     fn as_ref(&self) -> &[u8] { &self.0 }
+//! # }
+//! # impl TryFrom<&[u8]> for Signature {
+//! #     type Error = core::array::TryFromSliceError;
+//! #     fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> { bytes.try_into().map(Self) }
+//! # }
+//! #[derive(Deserialize, Serialize)]
+//! struct Signed {
+//!     message: String,
+//!     #[serde(with = "crypto_serde::hex_or_bin")]
+//!     signature: Signature,
+//! }
+//! ```
+//!
+//! Alternatively wrap values in a [`SerdeWrapper`].
+
+use crate::{serialize_hex_lower_or_bin, slice::deserialize_hex_or_bin_vec};
+use serde::{de::Error, ser, Deserialize, Deserializer, Serialize};
+
+/// Serialize a value as hex or bytes.
+pub fn serialize<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+    T: AsRef<[u8]> + ?Sized,
+{
+    serialize_hex_lower_or_bin(&value.as_ref(), serializer)
+}
+
+/// Deserialize a value from hex or bytes.
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: for<'a> TryFrom<&'a [u8]>,
+{
+    let bytes = deserialize_hex_or_bin_vec(deserializer)?;
+    T::try_from(&bytes).map_err(|_| D::Error::invalid_length(bytes.len(), &"a valid byte length"))
+}
+
+/// Serializer/deserializer newtype for types which convert to and from byte
+/// slices, encoding them as either binary or lower case hex.
+///
+/// See the [module-level documentation][`self`] for details.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct SerdeWrapper<T>(pub T);
+
+impl<T> SerdeWrapper<T> {
+    /// Unwrap the inner value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: AsRef<[u8]>> AsRef<[u8]> for SerdeWrapper<T> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl<T> From<T> for SerdeWrapper<T> {
+    fn from(value: T) -> SerdeWrapper<T> {
+        Self(value)
+    }
+}
+
+impl<T: AsRef<[u8]>> Serialize for SerdeWrapper<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for SerdeWrapper<T>
+where
+    T: for<'a> TryFrom<&'a [u8]>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize(deserializer).map(Self)
+    }
+}
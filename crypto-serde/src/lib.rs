@@ -15,18 +15,65 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "base16ct")]
+#[cfg_attr(docsrs, doc(cfg(feature = "base16ct")))]
+pub mod array;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod base32;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod base32_unpadded;
+#[cfg(feature = "base58")]
+#[cfg_attr(docsrs, doc(cfg(feature = "base58")))]
+pub mod base58;
+#[cfg(feature = "base64ct")]
+#[cfg_attr(docsrs, doc(cfg(feature = "base64ct")))]
+pub mod base64;
+#[cfg(all(feature = "alloc", feature = "bech32"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "bech32"))))]
+pub mod bech32;
+#[cfg(all(feature = "base16ct", feature = "crypto-bigint"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "base16ct", feature = "crypto-bigint"))))]
+pub mod bigint;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod chunked;
+#[cfg(feature = "base16ct")]
+#[cfg_attr(docsrs, doc(cfg(feature = "base16ct")))]
+pub mod fingerprint;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod hex_or_bin;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod map_key;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod prefixed_hex;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod slice;
+#[cfg(feature = "test-vectors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-vectors")))]
+pub mod test_vectors;
+
 pub use serde;
 
-use serde::{ser, Serialize};
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use crypto_serde_derive::HexOrBinSerde;
 
 #[cfg(feature = "alloc")]
-use {
-    alloc::vec::Vec,
-    serde::de::{self, Deserialize, Error},
+pub use crate::hex_or_bin::SerdeWrapper;
+#[cfg(feature = "alloc")]
+pub use crate::{
+    prefixed_hex::{PrefixedHexLowerOrBin, PrefixedHexOrBin, PrefixedHexUpperOrBin},
+    slice::{HexLowerOrBin, HexOrBin, HexUpperOrBin},
 };
 
-#[cfg(feature = "zeroize")]
-use zeroize::Zeroize;
+use serde::{ser, Serialize};
 
 /// Serialize the given type as lower case hex when using human-readable
 /// formats or binary if the format is binary.
@@ -37,10 +84,10 @@ where
 {
     #[cfg(feature = "alloc")]
     if serializer.is_human_readable() {
-        return base16ct::lower::encode_string(value.as_ref()).serialize(serializer);
+        return serializer.serialize_str(&base16ct::lower::encode_string(value.as_ref()));
     }
 
-    value.as_ref().serialize(serializer)
+    serialize_bin(value.as_ref(), serializer)
 }
 
 /// Serialize the given type as upper case hex when using human-readable
@@ -52,97 +99,22 @@ where
 {
     #[cfg(feature = "alloc")]
     if serializer.is_human_readable() {
-        return base16ct::upper::encode_string(value.as_ref()).serialize(serializer);
+        return serializer.serialize_str(&base16ct::upper::encode_string(value.as_ref()));
     }
 
-    value.as_ref().serialize(serializer)
+    serialize_bin(value.as_ref(), serializer)
 }
 
-/// [`HexOrBin`] serializer which uses lower case.
-#[cfg(feature = "alloc")]
-#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-pub type HexLowerOrBin = HexOrBin<false>;
-
-/// [`HexOrBin`] serializer which uses upper case.
-#[cfg(feature = "alloc")]
-#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-pub type HexUpperOrBin = HexOrBin<true>;
-
-/// Serializer/deserializer newtype which encodes bytes as either binary or hex.
+/// Serialize the given bytes when using a binary format.
 ///
-/// Use hexadecimal with human-readable formats, or raw binary with binary formats.
-#[cfg(feature = "alloc")]
-#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-pub struct HexOrBin<const UPPERCASE: bool>(pub Vec<u8>);
-
-#[cfg(feature = "alloc")]
-#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-impl<const UPPERCASE: bool> AsRef<[u8]> for HexOrBin<UPPERCASE> {
-    fn as_ref(&self) -> &[u8] {
-        self.0.as_ref()
-    }
-}
-
-#[cfg(feature = "alloc")]
-#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-impl<const UPPERCASE: bool> From<&[u8]> for HexOrBin<UPPERCASE> {
-    fn from(bytes: &[u8]) -> HexOrBin<UPPERCASE> {
-        Self(bytes.into())
-    }
-}
-
-#[cfg(feature = "alloc")]
-#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-impl<const UPPERCASE: bool> From<Vec<u8>> for HexOrBin<UPPERCASE> {
-    fn from(vec: Vec<u8>) -> HexOrBin<UPPERCASE> {
-        Self(vec)
-    }
-}
-
-#[cfg(feature = "alloc")]
-#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-impl<const UPPERCASE: bool> From<HexOrBin<UPPERCASE>> for Vec<u8> {
-    fn from(vec: HexOrBin<UPPERCASE>) -> Vec<u8> {
-        vec.0
-    }
-}
-
-#[cfg(feature = "alloc")]
-#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-impl<const UPPERCASE: bool> Serialize for HexOrBin<UPPERCASE> {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: ser::Serializer,
-    {
-        if UPPERCASE {
-            serialize_hex_upper_or_bin(self, serializer)
-        } else {
-            serialize_hex_lower_or_bin(self, serializer)
-        }
-    }
-}
-
-#[cfg(feature = "alloc")]
-#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-impl<'de, const UPPERCASE: bool> Deserialize<'de> for HexOrBin<UPPERCASE> {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: de::Deserializer<'de>,
-    {
-        if deserializer.is_human_readable() {
-            base16ct::mixed::decode_vec(<&str>::deserialize(deserializer)?)
-                .map_err(D::Error::custom)
-        } else {
-            Vec::deserialize(deserializer)
-        }
-        .map(Self)
-    }
-}
-
-#[cfg(all(feature = "alloc", feature = "zeroize"))]
-#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "zeroize"))))]
-impl<const UPPERCASE: bool> Zeroize for HexOrBin<UPPERCASE> {
-    fn zeroize(&mut self) {
-        self.0.as_mut_slice().zeroize();
-    }
+/// The bytes are serialized as a sequence rather than with
+/// [`ser::Serializer::serialize_bytes`]. The two are equivalent for formats
+/// such as bincode and postcard, but not for those which distinguish byte
+/// strings from sequences (e.g. CBOR), where values have always been
+/// serialized as sequences. Deserializers accept either.
+pub(crate) fn serialize_bin<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+{
+    bytes.serialize(serializer)
 }
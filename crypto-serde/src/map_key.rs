@@ -0,0 +1,118 @@
+//! Helpers for maps keyed by byte strings, e.g. `HashMap<[u8; 32], T>`.
+//!
+//! Keys are serialized as lower case hex when using human-readable formats,
+//! which is required by formats like JSON which only support string keys,
+//! or as byte strings when the format is binary. Values are serialized
+//! using their own [`Serialize`] impls.
+//!
+//! Works with any map type which can be iterated over and which impls
+//! [`Default`] and [`Extend`], such as `BTreeMap` and `HashMap`. Keys
+//! must be constructible from a `Vec<u8>`, as is the case for `[u8; N]`.
+//!
+//! Use with `#[serde(with = "crypto_serde::map_key")]`:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use std::collections::HashMap;
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Index {
+//!     #[serde(with = "crypto_serde::map_key")]
+//!     entries: HashMap<[u8; 32], u64>,
+//! }
+//! ```
+
+use crate::{serialize_hex_lower_or_bin, slice::deserialize_hex_or_bin_vec};
+use alloc::vec::Vec;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Error, MapAccess, Visitor},
+    ser, Deserialize, Deserializer, Serialize,
+};
+
+/// Serialize a map, encoding its keys as hex or bytes.
+pub fn serialize<'a, S, M, K, V>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+    &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+    K: AsRef<[u8]> + 'a,
+    V: Serialize + 'a,
+{
+    serializer.collect_map(map.into_iter().map(|(k, v)| (Key(k), v)))
+}
+
+/// Deserialize a map, decoding its keys from hex (upper or lower case) or
+/// bytes.
+pub fn deserialize<'de, D, M, K, V>(deserializer: D) -> Result<M, D::Error>
+where
+    D: Deserializer<'de>,
+    M: Default + Extend<(K, V)> + IntoIterator<Item = (K, V)>,
+    K: TryFrom<Vec<u8>>,
+    V: Deserialize<'de>,
+{
+    deserializer.deserialize_map(MapVisitor(PhantomData))
+}
+
+/// Serialization wrapper for map keys.
+struct Key<'a, K>(&'a K);
+
+impl<K: AsRef<[u8]>> Serialize for Key<'_, K> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        if serializer.is_human_readable() {
+            return serialize_hex_lower_or_bin(self.0, serializer);
+        }
+
+        // Unlike other values, keys are serialized as byte strings: binary
+        // formats such as CBOR key maps by byte string far more compactly
+        // than by arrays of integers
+        serializer.serialize_bytes(self.0.as_ref())
+    }
+}
+
+/// Deserialization wrapper for map keys.
+struct KeyBytes(Vec<u8>);
+
+impl<'de> Deserialize<'de> for KeyBytes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_hex_or_bin_vec(deserializer).map(Self)
+    }
+}
+
+/// Visitor for maps with byte string keys.
+struct MapVisitor<M, K, V>(PhantomData<(M, K, V)>);
+
+impl<'de, M, K, V> Visitor<'de> for MapVisitor<M, K, V>
+where
+    M: Default + Extend<(K, V)>,
+    K: TryFrom<Vec<u8>>,
+    V: Deserialize<'de>,
+{
+    type Value = M;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map with hex or byte string keys")
+    }
+
+    fn visit_map<A>(self, mut access: A) -> Result<M, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut map = M::default();
+
+        while let Some((KeyBytes(key), value)) = access.next_entry()? {
+            let len = key.len();
+            let key = K::try_from(key)
+                .map_err(|_| A::Error::invalid_length(len, &"a valid key length"))?;
+
+            map.extend(Some((key, value)));
+        }
+
+        Ok(map)
+    }
+}
@@ -0,0 +1,226 @@
+//! `0x`-prefixed hex serialization helpers.
+//!
+//! Ethereum-style JSON-RPC APIs encode byte strings as hex with a leading
+//! `0x`. These helpers emit the prefix when using human-readable formats and
+//! accept hex with or without it when deserializing. Binary formats use raw
+//! bytes, as with the other helpers in this crate.
+//!
+//! [`PrefixedHexOrBin`] is the equivalent of [`HexOrBin`][`crate::HexOrBin`]
+//! for variable-length byte strings.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+use serde::{
+    de::{Error, Visitor},
+    ser, Deserialize, Deserializer, Serialize,
+};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Prefix placed before the hex digits.
+const PREFIX: &str = "0x";
+
+/// Serialize the given type as `0x`-prefixed lower case hex when using
+/// human-readable formats or binary if the format is binary.
+pub fn serialize_prefixed_hex_lower_or_bin<S, T>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+    T: AsRef<[u8]>,
+{
+    if serializer.is_human_readable() {
+        return serializer.serialize_str(&prefixed(base16ct::lower::encode_string(value.as_ref())));
+    }
+
+    crate::serialize_bin(value.as_ref(), serializer)
+}
+
+/// Serialize the given type as `0x`-prefixed upper case hex when using
+/// human-readable formats or binary if the format is binary.
+///
+/// Only the hex digits are upper case: the prefix is always `0x`.
+pub fn serialize_prefixed_hex_upper_or_bin<S, T>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+    T: AsRef<[u8]>,
+{
+    if serializer.is_human_readable() {
+        return serializer.serialize_str(&prefixed(base16ct::upper::encode_string(value.as_ref())));
+    }
+
+    crate::serialize_bin(value.as_ref(), serializer)
+}
+
+/// Deserialize hex (upper or lower case) with an optional `0x` or `0X`
+/// prefix when using human-readable formats or binary if the format is
+/// binary, returning a [`Vec`].
+pub fn deserialize_prefixed_hex_or_bin_vec<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        struct StrVisitor;
+
+        impl<'de> Visitor<'de> for StrVisitor {
+            type Value = Vec<u8>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a hex string with an optional 0x prefix")
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<Vec<u8>, E> {
+                base16ct::mixed::decode_vec(strip_prefix(v)).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(StrVisitor)
+    } else {
+        crate::slice::deserialize_byte_buf(deserializer)
+    }
+}
+
+/// Prepend [`PREFIX`] to the given hex string.
+fn prefixed(hex: String) -> String {
+    let mut s = String::with_capacity(PREFIX.len() + hex.len());
+    s.push_str(PREFIX);
+    s.push_str(&hex);
+    s
+}
+
+/// Remove a leading `0x` or `0X`, if present.
+fn strip_prefix(s: &str) -> &str {
+    s.strip_prefix(PREFIX)
+        .or_else(|| s.strip_prefix("0X"))
+        .unwrap_or(s)
+}
+
+/// [`PrefixedHexOrBin`] serializer which uses lower case.
+pub type PrefixedHexLowerOrBin = PrefixedHexOrBin<false>;
+
+/// [`PrefixedHexOrBin`] serializer which uses upper case.
+pub type PrefixedHexUpperOrBin = PrefixedHexOrBin<true>;
+
+/// Serializer/deserializer newtype which encodes bytes as either binary or
+/// `0x`-prefixed hex.
+///
+/// Use hexadecimal with human-readable formats, or raw binary with binary
+/// formats. Hex is accepted with or without the prefix when deserializing.
+pub struct PrefixedHexOrBin<const UPPERCASE: bool>(pub Vec<u8>);
+
+impl<const UPPERCASE: bool> AsRef<[u8]> for PrefixedHexOrBin<UPPERCASE> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl<const UPPERCASE: bool> From<&[u8]> for PrefixedHexOrBin<UPPERCASE> {
+    fn from(bytes: &[u8]) -> PrefixedHexOrBin<UPPERCASE> {
+        Self(bytes.into())
+    }
+}
+
+impl<const UPPERCASE: bool> From<Vec<u8>> for PrefixedHexOrBin<UPPERCASE> {
+    fn from(vec: Vec<u8>) -> PrefixedHexOrBin<UPPERCASE> {
+        Self(vec)
+    }
+}
+
+#[cfg(feature = "generic-array")]
+#[cfg_attr(docsrs, doc(cfg(feature = "generic-array")))]
+impl<N, const UPPERCASE: bool> From<generic_array::GenericArray<u8, N>>
+    for PrefixedHexOrBin<UPPERCASE>
+where
+    N: generic_array::ArrayLength<u8>,
+{
+    fn from(array: generic_array::GenericArray<u8, N>) -> PrefixedHexOrBin<UPPERCASE> {
+        Self(array.to_vec())
+    }
+}
+
+#[cfg(feature = "hybrid-array")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hybrid-array")))]
+impl<U, const UPPERCASE: bool> From<hybrid_array::Array<u8, U>> for PrefixedHexOrBin<UPPERCASE>
+where
+    U: hybrid_array::ArraySize,
+{
+    fn from(array: hybrid_array::Array<u8, U>) -> PrefixedHexOrBin<UPPERCASE> {
+        Self(array.as_slice().to_vec())
+    }
+}
+
+impl<const UPPERCASE: bool> From<PrefixedHexOrBin<UPPERCASE>> for Vec<u8> {
+    fn from(vec: PrefixedHexOrBin<UPPERCASE>) -> Vec<u8> {
+        vec.0
+    }
+}
+
+impl<const UPPERCASE: bool> Serialize for PrefixedHexOrBin<UPPERCASE> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        if UPPERCASE {
+            serialize_prefixed_hex_upper_or_bin(self, serializer)
+        } else {
+            serialize_prefixed_hex_lower_or_bin(self, serializer)
+        }
+    }
+}
+
+impl<'de, const UPPERCASE: bool> Deserialize<'de> for PrefixedHexOrBin<UPPERCASE> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_prefixed_hex_or_bin_vec(deserializer).map(Self)
+    }
+}
+
+#[cfg(feature = "serde_with")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde_with")))]
+impl<T, const UPPERCASE: bool> serde_with::SerializeAs<T> for PrefixedHexOrBin<UPPERCASE>
+where
+    T: AsRef<[u8]>,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        if UPPERCASE {
+            serialize_prefixed_hex_upper_or_bin(source, serializer)
+        } else {
+            serialize_prefixed_hex_lower_or_bin(source, serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde_with")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde_with")))]
+impl<'de, T, const UPPERCASE: bool> serde_with::DeserializeAs<'de, T>
+    for PrefixedHexOrBin<UPPERCASE>
+where
+    T: TryFrom<Vec<u8>>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = deserialize_prefixed_hex_or_bin_vec(deserializer)?;
+        let len = bytes.len();
+        T::try_from(bytes).map_err(|_| D::Error::invalid_length(len, &"a valid byte length"))
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl<const UPPERCASE: bool> Zeroize for PrefixedHexOrBin<UPPERCASE> {
+    fn zeroize(&mut self) {
+        self.0.as_mut_slice().zeroize();
+    }
+}
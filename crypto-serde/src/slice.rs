@@ -0,0 +1,323 @@
+//! Variable-length byte slice serialization helpers.
+//!
+//! These helpers are suitable for dynamically sized values such as
+//! signatures and ciphertexts. Fixed-size values can use the functions
+//! in the crate root with `#[serde(serialize_with = "...")]`.
+
+use crate::{serialize_hex_lower_or_bin, serialize_hex_upper_or_bin};
+use alloc::{borrow::Cow, vec::Vec};
+use core::fmt;
+use serde::{
+    de::{Error, SeqAccess, Visitor},
+    ser, Deserialize, Deserializer, Serialize,
+};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Deserialize either hex (upper or lower case) when using human-readable
+/// formats or binary if the format is binary, returning a [`Vec`].
+///
+/// This is the counterpart of [`serialize_hex_lower_or_bin`] and
+/// [`serialize_hex_upper_or_bin`] for use with
+/// `#[serde(deserialize_with = "...")]`.
+pub fn deserialize_hex_or_bin_vec<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        struct StrVisitor;
+
+        impl<'de> Visitor<'de> for StrVisitor {
+            type Value = Vec<u8>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a hex string")
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<Vec<u8>, E> {
+                base16ct::mixed::decode_vec(v).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(StrVisitor)
+    } else {
+        deserialize_byte_buf(deserializer)
+    }
+}
+
+/// Deserialize either hex (upper or lower case) when using human-readable
+/// formats or binary if the format is binary, borrowing the bytes from the
+/// input where possible.
+///
+/// Binary formats which can provide a slice of their input (e.g. bincode and
+/// postcard when deserializing from a slice) are deserialized without
+/// copying, returning [`Cow::Borrowed`]. Otherwise, including when decoding
+/// hex, the bytes are copied into [`Cow::Owned`].
+///
+/// Use with `#[serde(borrow, deserialize_with = "...")]`:
+///
+/// ```
+/// use serde::Deserialize;
+/// use std::borrow::Cow;
+///
+/// #[derive(Deserialize)]
+/// struct Signature<'a> {
+///     #[serde(borrow, deserialize_with = "crypto_serde::slice::deserialize_hex_or_bin_cow")]
+///     bytes: Cow<'a, [u8]>,
+/// }
+/// ```
+pub fn deserialize_hex_or_bin_cow<'de, D>(deserializer: D) -> Result<Cow<'de, [u8]>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        return deserialize_hex_or_bin_vec(deserializer).map(Cow::Owned);
+    }
+
+    struct CowVisitor;
+
+    impl<'de> Visitor<'de> for CowVisitor {
+        type Value = Cow<'de, [u8]>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("bytes")
+        }
+
+        fn visit_borrowed_bytes<E: Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+            Ok(Cow::Borrowed(v))
+        }
+
+        fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            Ok(Cow::Owned(v.to_vec()))
+        }
+
+        fn visit_byte_buf<E: Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+            Ok(Cow::Owned(v))
+        }
+
+        fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            collect_seq(seq).map(Cow::Owned)
+        }
+    }
+
+    deserializer.deserialize_bytes(CowVisitor)
+}
+
+/// Deserialize a [`Vec`] of bytes from a binary format.
+///
+/// Uses [`Deserializer::deserialize_byte_buf`] so formats which support it
+/// (e.g. bincode, postcard) can provide the bytes as a single slice or
+/// buffer, falling back to deserializing them one element at a time for
+/// formats which represent them as a sequence.
+pub(crate) fn deserialize_byte_buf<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct ByteBufVisitor;
+
+    impl<'de> Visitor<'de> for ByteBufVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("bytes")
+        }
+
+        fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Vec<u8>, E> {
+            Ok(v.to_vec())
+        }
+
+        fn visit_byte_buf<E: Error>(self, v: Vec<u8>) -> Result<Vec<u8>, E> {
+            Ok(v)
+        }
+
+        fn visit_seq<A>(self, seq: A) -> Result<Vec<u8>, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            collect_seq(seq)
+        }
+    }
+
+    deserializer.deserialize_byte_buf(ByteBufVisitor)
+}
+
+/// Collect bytes which a format represents as a sequence.
+fn collect_seq<'de, A>(mut seq: A) -> Result<Vec<u8>, A::Error>
+where
+    A: SeqAccess<'de>,
+{
+    // Cap the preallocation so a bogus size hint can't exhaust memory
+    let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+
+    while let Some(byte) = seq.next_element()? {
+        bytes.push(byte);
+    }
+
+    Ok(bytes)
+}
+
+/// [`HexOrBin`] serializer which uses lower case.
+pub type HexLowerOrBin = HexOrBin<false>;
+
+/// [`HexOrBin`] serializer which uses upper case.
+pub type HexUpperOrBin = HexOrBin<true>;
+
+/// Serializer/deserializer newtype which encodes bytes as either binary or hex.
+///
+/// Use hexadecimal with human-readable formats, or raw binary with binary formats.
+pub struct HexOrBin<const UPPERCASE: bool>(pub Vec<u8>);
+
+impl<const UPPERCASE: bool> AsRef<[u8]> for HexOrBin<UPPERCASE> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl<const UPPERCASE: bool> From<&[u8]> for HexOrBin<UPPERCASE> {
+    fn from(bytes: &[u8]) -> HexOrBin<UPPERCASE> {
+        Self(bytes.into())
+    }
+}
+
+impl<const UPPERCASE: bool> From<Vec<u8>> for HexOrBin<UPPERCASE> {
+    fn from(vec: Vec<u8>) -> HexOrBin<UPPERCASE> {
+        Self(vec)
+    }
+}
+
+#[cfg(feature = "generic-array")]
+#[cfg_attr(docsrs, doc(cfg(feature = "generic-array")))]
+impl<N, const UPPERCASE: bool> From<generic_array::GenericArray<u8, N>> for HexOrBin<UPPERCASE>
+where
+    N: generic_array::ArrayLength<u8>,
+{
+    fn from(array: generic_array::GenericArray<u8, N>) -> HexOrBin<UPPERCASE> {
+        Self(array.to_vec())
+    }
+}
+
+#[cfg(feature = "hybrid-array")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hybrid-array")))]
+impl<U, const UPPERCASE: bool> From<hybrid_array::Array<u8, U>> for HexOrBin<UPPERCASE>
+where
+    U: hybrid_array::ArraySize,
+{
+    fn from(array: hybrid_array::Array<u8, U>) -> HexOrBin<UPPERCASE> {
+        Self(array.as_slice().to_vec())
+    }
+}
+
+impl<const UPPERCASE: bool> From<HexOrBin<UPPERCASE>> for Vec<u8> {
+    fn from(vec: HexOrBin<UPPERCASE>) -> Vec<u8> {
+        vec.0
+    }
+}
+
+impl<const UPPERCASE: bool> Serialize for HexOrBin<UPPERCASE> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        if UPPERCASE {
+            serialize_hex_upper_or_bin(self, serializer)
+        } else {
+            serialize_hex_lower_or_bin(self, serializer)
+        }
+    }
+}
+
+impl<'de, const UPPERCASE: bool> Deserialize<'de> for HexOrBin<UPPERCASE> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_hex_or_bin_vec(deserializer).map(Self)
+    }
+}
+
+#[cfg(feature = "serde_with")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde_with")))]
+impl<T, const UPPERCASE: bool> serde_with::SerializeAs<T> for HexOrBin<UPPERCASE>
+where
+    T: AsRef<[u8]>,
+{
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        if UPPERCASE {
+            serialize_hex_upper_or_bin(source, serializer)
+        } else {
+            serialize_hex_lower_or_bin(source, serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde_with")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde_with")))]
+impl<'de, T, const UPPERCASE: bool> serde_with::DeserializeAs<'de, T> for HexOrBin<UPPERCASE>
+where
+    T: TryFrom<Vec<u8>>,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = deserialize_hex_or_bin_vec(deserializer)?;
+        let len = bytes.len();
+        T::try_from(bytes).map_err(|_| D::Error::invalid_length(len, &"a valid byte length"))
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl<const UPPERCASE: bool> Zeroize for HexOrBin<UPPERCASE> {
+    fn zeroize(&mut self) {
+        self.0.as_mut_slice().zeroize();
+    }
+}
+
+/// Helpers for `Option<Vec<u8>>` which serialize `Some` values as lower case
+/// hex when using human-readable formats or binary if the format is binary.
+///
+/// Use with `#[serde(with = "crypto_serde::slice::option")]`.
+pub mod option {
+    use super::HexLowerOrBin;
+    use crate::serialize_hex_lower_or_bin;
+    use alloc::vec::Vec;
+    use serde::{ser, Deserialize, Deserializer, Serialize};
+
+    /// Serialize an `Option<Vec<u8>>`.
+    pub fn serialize<S>(value: &Option<Vec<u8>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        struct Wrapper<'a>(&'a [u8]);
+
+        impl Serialize for Wrapper<'_> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ser::Serializer,
+            {
+                serialize_hex_lower_or_bin(&self.0, serializer)
+            }
+        }
+
+        match value {
+            Some(bytes) => serializer.serialize_some(&Wrapper(bytes)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize an `Option<Vec<u8>>`.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Vec<u8>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<HexLowerOrBin>::deserialize(deserializer).map(|opt| opt.map(Vec::from))
+    }
+}
@@ -0,0 +1,220 @@
+//! Helpers for testing serialization across formats.
+//!
+//! Types using the helpers in this crate serialize differently depending on
+//! whether the format is human-readable, e.g. as hex in JSON but as a byte
+//! string in bincode. The assertions in this module check a value against
+//! each of the following [`Format`]s, so that downstream crates don't need
+//! to hand-roll the same test matrix:
+//!
+//! - Human-readable: JSON, TOML
+//! - Binary: CBOR, bincode, postcard
+//!
+//! ```
+//! use crypto_serde::{test_vectors, SerdeWrapper};
+//!
+//! let value = SerdeWrapper([0xAB, 0xCD]);
+//! test_vectors::assert_round_trip(&value);
+//! test_vectors::assert_human_readable(&value, r#""abcd""#);
+//! test_vectors::assert_binary(&value, &[0xAB, 0xCD]);
+//! ```
+//!
+//! TOML documents must be tables, so values are serialized as the `value`
+//! key of a table when using [`Format::Toml`]. Values which TOML can't
+//! represent, such as `None`, can only be tested with the other formats.
+//!
+//! This module is intended for use in tests. It requires `std`, and isn't
+//! covered by this crate's MSRV, as some of the formats it pulls in (e.g.
+//! `postcard`) require a newer Rust.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Debug;
+use serde::{de::DeserializeOwned, ser, Serialize};
+
+/// Key under which values are stored in TOML documents.
+const TOML_KEY: &str = "value";
+
+/// Serialization formats exercised by this module.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    /// JSON, using `serde_json`.
+    Json,
+
+    /// TOML, using `toml`.
+    Toml,
+
+    /// CBOR, using `ciborium`.
+    Cbor,
+
+    /// bincode, using its default configuration.
+    Bincode,
+
+    /// postcard.
+    Postcard,
+}
+
+impl Format {
+    /// All supported formats.
+    pub const ALL: &'static [Format] = &[
+        Format::Json,
+        Format::Toml,
+        Format::Cbor,
+        Format::Bincode,
+        Format::Postcard,
+    ];
+
+    /// Does this format report itself as human-readable to [`Serialize`]
+    /// impls?
+    pub fn is_human_readable(self) -> bool {
+        matches!(self, Format::Json | Format::Toml)
+    }
+
+    /// Serialize the given value using this format.
+    pub fn serialize<T>(self, value: &T) -> Result<Vec<u8>, String>
+    where
+        T: Serialize + ?Sized,
+    {
+        match self {
+            Format::Json => serde_json::to_vec(value).map_err(|e| e.to_string()),
+            Format::Toml => {
+                let mut table = toml::value::Table::new();
+                let value = toml::Value::try_from(value).map_err(|e| e.to_string())?;
+                table.insert(TOML_KEY.into(), value);
+                toml::to_vec(&table).map_err(|e| e.to_string())
+            }
+            Format::Cbor => {
+                let mut bytes = Vec::new();
+                ciborium::ser::into_writer(value, &mut bytes).map_err(|e| e.to_string())?;
+                Ok(bytes)
+            }
+            Format::Bincode => bincode::serialize(value).map_err(|e| e.to_string()),
+            Format::Postcard => postcard::to_allocvec(value).map_err(|e| e.to_string()),
+        }
+    }
+
+    /// Deserialize a value which was serialized using this format.
+    pub fn deserialize<T>(self, bytes: &[u8]) -> Result<T, String>
+    where
+        T: DeserializeOwned,
+    {
+        match self {
+            Format::Json => serde_json::from_slice(bytes).map_err(|e| e.to_string()),
+            Format::Toml => {
+                let mut table =
+                    toml::from_slice::<toml::value::Table>(bytes).map_err(|e| e.to_string())?;
+                table
+                    .remove(TOML_KEY)
+                    .ok_or_else(|| format!("missing `{}` key", TOML_KEY))?
+                    .try_into()
+                    .map_err(|e| e.to_string())
+            }
+            Format::Cbor => ciborium::de::from_reader(bytes).map_err(|e| e.to_string()),
+            Format::Bincode => bincode::deserialize(bytes).map_err(|e| e.to_string()),
+            Format::Postcard => postcard::from_bytes(bytes).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// Assert that the given value round-trips through every [`Format`], and
+/// that re-serializing the deserialized value produces identical output.
+///
+/// # Panics
+///
+/// Panics if serialization or deserialization fails, or if the results
+/// differ.
+pub fn assert_round_trip<T>(value: &T)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    for &format in Format::ALL {
+        let bytes = serialize(format, value);
+        let decoded = deserialize::<T>(format, &bytes);
+        assert_eq!(&decoded, value, "{:?}: value didn't round-trip", format);
+        assert_eq!(
+            serialize(format, &decoded),
+            bytes,
+            "{:?}: serialization isn't deterministic",
+            format
+        );
+    }
+}
+
+/// Assert that the given value serializes as the given JSON in every
+/// human-readable [`Format`], and can be deserialized from it.
+///
+/// The JSON is compared structurally, so whitespace and the order of keys
+/// don't matter.
+///
+/// # Panics
+///
+/// Panics if `expected` isn't valid JSON, or if the value is represented
+/// differently.
+pub fn assert_human_readable<T>(value: &T, expected: &str)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let expected = serde_json::from_str::<serde_json::Value>(expected)
+        .unwrap_or_else(|e| panic!("invalid expected JSON: {}", e));
+
+    for &format in Format::ALL.iter().filter(|f| f.is_human_readable()) {
+        let actual = deserialize::<serde_json::Value>(format, &serialize(format, value));
+        assert_eq!(actual, expected, "{:?}: unexpected representation", format);
+
+        let decoded = deserialize::<T>(format, &serialize(format, &expected));
+        assert_eq!(&decoded, value, "{:?}: value didn't deserialize", format);
+    }
+}
+
+/// Assert that the given value serializes identically to the given byte
+/// string in every binary [`Format`], and can be deserialized from it.
+///
+/// # Panics
+///
+/// Panics if the value is represented differently.
+pub fn assert_binary<T>(value: &T, expected: &[u8])
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    for &format in Format::ALL.iter().filter(|f| !f.is_human_readable()) {
+        let expected = serialize(format, &Bytes(expected));
+        assert_eq!(
+            serialize(format, value),
+            expected,
+            "{:?}: unexpected representation",
+            format
+        );
+
+        let decoded = deserialize::<T>(format, &expected);
+        assert_eq!(&decoded, value, "{:?}: value didn't deserialize", format);
+    }
+}
+
+/// Serialize a value, panicking on failure.
+fn serialize<T: Serialize + ?Sized>(format: Format, value: &T) -> Vec<u8> {
+    format
+        .serialize(value)
+        .unwrap_or_else(|e| panic!("{:?}: serialization failed: {}", format, e))
+}
+
+/// Deserialize a value, panicking on failure.
+fn deserialize<T: DeserializeOwned>(format: Format, bytes: &[u8]) -> T {
+    format
+        .deserialize(bytes)
+        .unwrap_or_else(|e| panic!("{:?}: deserialization failed: {}", format, e))
+}
+
+/// Byte string which serializes the same way as the helpers in this crate do
+/// when using binary formats.
+struct Bytes<'a>(&'a [u8]);
+
+impl Serialize for Bytes<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        crate::serialize_bin(self.0, serializer)
+    }
+}
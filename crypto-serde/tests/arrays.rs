@@ -0,0 +1,67 @@
+//! Tests for `GenericArray` and `hybrid_array::Array` support.
+
+#![cfg(all(feature = "alloc", feature = "generic-array", feature = "hybrid-array"))]
+
+use crypto_serde::{array, serialize_hex_lower_or_bin, HexLowerOrBin};
+use generic_array::{typenum::U4, GenericArray};
+use hex_literal::hex;
+use serde::{Deserialize, Serialize};
+use serde_json as json;
+
+/// Example type with array fields.
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct Example {
+    #[serde(
+        serialize_with = "serialize_hex_lower_or_bin",
+        deserialize_with = "array::deserialize_hex_or_bin_generic_array"
+    )]
+    generic: GenericArray<u8, U4>,
+
+    #[serde(
+        serialize_with = "serialize_hex_lower_or_bin",
+        deserialize_with = "array::deserialize_hex_or_bin_hybrid_array"
+    )]
+    hybrid: hybrid_array::Array<u8, hybrid_array::sizes::U4>,
+}
+
+/// Example value.
+fn example() -> Example {
+    Example {
+        generic: hex!("DEADBEEF").into(),
+        hybrid: hex!("01020304").into(),
+    }
+}
+
+#[test]
+fn json_round_trip() {
+    let serialized = json::to_string(&example()).unwrap();
+    assert_eq!(serialized, r#"{"generic":"deadbeef","hybrid":"01020304"}"#);
+    assert_eq!(json::from_str::<Example>(&serialized).unwrap(), example());
+}
+
+#[test]
+fn json_wrong_length() {
+    assert!(json::from_str::<Example>(r#"{"generic":"dead","hybrid":"01020304"}"#).is_err());
+}
+
+#[test]
+fn bincode_round_trip() {
+    let serialized = bincode::serialize(&example()).unwrap();
+    assert_eq!(
+        bincode::deserialize::<Example>(&serialized).unwrap(),
+        example()
+    );
+}
+
+#[test]
+fn from_arrays() {
+    let example = example();
+    assert_eq!(
+        HexLowerOrBin::from(example.generic).as_ref(),
+        &hex!("DEADBEEF")
+    );
+    assert_eq!(
+        HexLowerOrBin::from(example.hybrid).as_ref(),
+        &hex!("01020304")
+    );
+}
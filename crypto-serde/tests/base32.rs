@@ -0,0 +1,163 @@
+//! Tests for Base32.
+
+#![cfg(feature = "alloc")]
+
+use crypto_serde::{
+    base32::{deserialize_base32_or_bin, Base32LowerOrBin, Base32UpperOrBin},
+    base32_unpadded::{
+        deserialize_base32_unpadded_or_bin, Base32UnpaddedLowerOrBin, Base32UnpaddedUpperOrBin,
+    },
+};
+use hex_literal::hex;
+use serde::Deserialize;
+use serde_json as json;
+
+/// RFC 4648 test vectors.
+const VECTORS: &[(&[u8], &str)] = &[
+    (b"", ""),
+    (b"f", "MY======"),
+    (b"fo", "MZXQ===="),
+    (b"foo", "MZXW6==="),
+    (b"foob", "MZXW6YQ="),
+    (b"fooba", "MZXW6YTB"),
+    (b"foobar", "MZXW6YTBOI======"),
+];
+
+#[derive(Deserialize)]
+struct Secret {
+    #[serde(deserialize_with = "deserialize_base32_or_bin")]
+    padded: [u8; 3],
+    #[serde(deserialize_with = "deserialize_base32_unpadded_or_bin")]
+    unpadded: [u8; 3],
+}
+
+#[test]
+fn json_padded() {
+    for &(bytes, encoded) in VECTORS {
+        let upper = json::to_string(&Base32UpperOrBin::from(bytes)).unwrap();
+        assert_eq!(upper, format!("\"{}\"", encoded));
+
+        let lower = json::to_string(&Base32LowerOrBin::from(bytes)).unwrap();
+        assert_eq!(lower, format!("\"{}\"", encoded.to_lowercase()));
+
+        for input in [upper, lower] {
+            assert_eq!(json::from_str::<Base32UpperOrBin>(&input).unwrap().0, bytes);
+        }
+    }
+}
+
+#[test]
+fn json_unpadded() {
+    for &(bytes, encoded) in VECTORS {
+        let encoded = encoded.trim_end_matches('=');
+
+        let upper = json::to_string(&Base32UnpaddedUpperOrBin::from(bytes)).unwrap();
+        assert_eq!(upper, format!("\"{}\"", encoded));
+
+        let lower = json::to_string(&Base32UnpaddedLowerOrBin::from(bytes)).unwrap();
+        assert_eq!(lower, format!("\"{}\"", encoded.to_lowercase()));
+
+        for input in [upper, lower] {
+            assert_eq!(
+                json::from_str::<Base32UnpaddedLowerOrBin>(&input)
+                    .unwrap()
+                    .0,
+                bytes
+            );
+        }
+    }
+}
+
+#[test]
+fn json_rejects_malformed() {
+    for input in [
+        "\"MY\"",
+        "\"MY=====\"",
+        "\"M=======\"",
+        "\"MZ======\"",
+        "\"MY==MY==\"",
+        "\"MY======MY======\"",
+        "\"M1======\"",
+        "\"MzXQ====\"",
+    ] {
+        assert!(
+            json::from_str::<Base32UpperOrBin>(input).is_err(),
+            "{}",
+            input
+        );
+    }
+
+    for input in [
+        "\"MY======\"",
+        "\"M\"",
+        "\"MZ\"",
+        "\"MZXW6YTBO\"",
+        "\"mZXW6\"",
+    ] {
+        assert!(
+            json::from_str::<Base32UnpaddedUpperOrBin>(input).is_err(),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn json_array() {
+    let secret: Secret =
+        json::from_str("{\"padded\":\"UGZMG===\",\"unpadded\":\"ugzmg\"}").unwrap();
+    assert_eq!(secret.padded, hex!("a1b2c3"));
+    assert_eq!(secret.unpadded, hex!("a1b2c3"));
+
+    assert!(json::from_str::<Secret>("{\"padded\":\"UGZA====\",\"unpadded\":\"ugzmg\"}").is_err());
+    assert!(json::from_str::<Secret>("{\"padded\":\"UGZMGAA=\",\"unpadded\":\"ugzmg\"}").is_err());
+    assert!(json::from_str::<Secret>("{\"padded\":\"UG======\",\"unpadded\":\"ugzmg\"}").is_err());
+}
+
+#[test]
+fn json_array_errors() {
+    /// Deserialize `padded` from the given JSON string and return the error.
+    fn error(padded: &str) -> String {
+        let input = format!("{{\"padded\":\"{}\",\"unpadded\":\"ugzmg\"}}", padded);
+        let err = json::from_str::<Secret>(&input).err().expect(padded);
+        err.to_string()
+    }
+
+    // The decoded length is reported for inputs which are too short or long,
+    // in either case
+    for (input, len) in [
+        ("UGZA====", 2),
+        ("UGZMGAA=", 4),
+        ("ugzmgaa=", 4),
+        ("UGZMGAAB", 5),
+        ("UGZMGAABUGZMGAAB", 10),
+    ] {
+        assert!(
+            error(input).starts_with(&format!("invalid length {}, expected 3 bytes", len)),
+            "{}",
+            input
+        );
+    }
+
+    // Invalid encodings are reported as such, even if the input is too long
+    for input in ["UGZMGAA1", "UGZMGAAbUGZMGAAB", "ugzmgaaB"] {
+        assert!(
+            error(input).starts_with("invalid Base32 encoding"),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn bincode_round_trip() {
+    let bytes = b"foobar";
+    let serialized = bincode::serialize(&Base32UpperOrBin::from(&bytes[..])).unwrap();
+    assert_eq!(&serialized[8..], bytes);
+    assert_eq!(
+        bincode::deserialize::<Base32UpperOrBin>(&serialized)
+            .unwrap()
+            .0,
+        bytes
+    );
+}
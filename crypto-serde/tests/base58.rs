@@ -0,0 +1,138 @@
+//! Tests for Base58 and Base58Check.
+
+#![cfg(feature = "base58")]
+
+use crypto_serde::base58::{Base58OrBin, MAX_LEN};
+use hex_literal::hex;
+use serde_json as json;
+
+/// Bitcoin Base58 alphabet.
+const ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+#[test]
+fn json_vectors() {
+    for (bytes, encoded) in [
+        (&b""[..], ""),
+        (&b"Hello World!"[..], "2NEpo7TZRRrLZSi2U"),
+        (&hex!("0000287fb4cd")[..], "11233QC4"),
+        (&hex!("00000000")[..], "1111"),
+    ] {
+        let serialized = json::to_string(&Base58OrBin::from(bytes)).unwrap();
+        assert_eq!(serialized, format!("\"{}\"", encoded));
+        assert_eq!(json::from_str::<Base58OrBin>(&serialized).unwrap().0, bytes);
+    }
+}
+
+#[test]
+fn json_alphabet() {
+    for (digit, c) in ALPHABET.chars().enumerate() {
+        let serialized = json::to_string(&Base58OrBin::from(&[digit as u8][..])).unwrap();
+        let expected = if digit == 0 {
+            "1".into()
+        } else {
+            c.to_string()
+        };
+        assert_eq!(serialized, format!("\"{}\"", expected));
+    }
+
+    for c in (0u8..0x80).map(char::from) {
+        let result = json::from_str::<Base58OrBin>(&format!("\"2{}\"", c));
+        assert_eq!(result.is_ok(), ALPHABET.contains(c), "{:?}", c);
+    }
+}
+
+#[test]
+fn json_rejects_invalid() {
+    for encoded in [
+        "!!!!",
+        "1!",
+        "2NEpo7TZRRrLZSi2U!",
+        "0OIl",
+        "\u{e9}\u{e9}\u{e9}",
+    ] {
+        assert!(
+            json::from_str::<Base58OrBin>(&format!("\"{}\"", encoded)).is_err(),
+            "{:?}",
+            encoded
+        );
+    }
+
+    let long = "z".repeat(63) + &"!".repeat(64);
+    assert!(json::from_str::<Base58OrBin>(&format!("\"{}\"", long)).is_err());
+}
+
+#[test]
+fn json_round_trip() {
+    for len in 0..64 {
+        let bytes: Vec<u8> = (0..len).map(|i| (i * 37) as u8).collect();
+        let serialized = json::to_string(&Base58OrBin::from(bytes.clone())).unwrap();
+        assert_eq!(json::from_str::<Base58OrBin>(&serialized).unwrap().0, bytes);
+    }
+}
+
+#[test]
+fn json_max_len() {
+    let bytes = vec![0xff; MAX_LEN];
+    let serialized = json::to_string(&Base58OrBin::from(bytes.clone())).unwrap();
+    assert_eq!(json::from_str::<Base58OrBin>(&serialized).unwrap().0, bytes);
+
+    assert!(json::to_string(&Base58OrBin::from(vec![0xff; MAX_LEN + 1])).is_err());
+    assert!(json::to_string(&Base58OrBin::from(vec![0; MAX_LEN + 1])).is_err());
+
+    let long = format!("\"{}z\"", &serialized[1..serialized.len() - 1]);
+    assert!(json::from_str::<Base58OrBin>(&long).is_err());
+    let long = format!("\"{}\"", "1".repeat(MAX_LEN + 1));
+    assert!(json::from_str::<Base58OrBin>(&long).is_err());
+    let long = format!("\"{}\"", "z".repeat(1 << 20));
+    assert!(json::from_str::<Base58OrBin>(&long).is_err());
+}
+
+#[test]
+fn bincode_round_trip() {
+    let bytes = b"Hello World!";
+    let serialized = bincode::serialize(&Base58OrBin::from(&bytes[..])).unwrap();
+    assert_eq!(&serialized[8..], bytes);
+    assert_eq!(
+        bincode::deserialize::<Base58OrBin>(&serialized).unwrap().0,
+        bytes
+    );
+}
+
+#[cfg(feature = "base58check")]
+mod check {
+    use super::*;
+    use crypto_serde::base58::Base58CheckOrBin;
+
+    /// Bitcoin P2PKH address payload (version byte and HASH160).
+    const ADDRESS: [u8; 21] = hex!("00010966776006953D5567439E5E39F86A0D273BEE");
+
+    #[test]
+    fn json_round_trip() {
+        let serialized = json::to_string(&Base58CheckOrBin::from(&ADDRESS[..])).unwrap();
+        assert_eq!(serialized, "\"16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM\"");
+        assert_eq!(
+            json::from_str::<Base58CheckOrBin>(&serialized).unwrap().0,
+            ADDRESS
+        );
+    }
+
+    #[test]
+    fn json_rejects_bad_checksum() {
+        assert!(
+            json::from_str::<Base58CheckOrBin>("\"16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvN\"").is_err()
+        );
+        assert!(json::from_str::<Base58CheckOrBin>("\"111\"").is_err());
+    }
+
+    #[test]
+    fn bincode_omits_checksum() {
+        let serialized = bincode::serialize(&Base58CheckOrBin::from(&ADDRESS[..])).unwrap();
+        assert_eq!(serialized[8..], ADDRESS);
+        assert_eq!(
+            bincode::deserialize::<Base58CheckOrBin>(&serialized)
+                .unwrap()
+                .0,
+            ADDRESS
+        );
+    }
+}
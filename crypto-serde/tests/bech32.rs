@@ -0,0 +1,110 @@
+//! Tests for Bech32 and Bech32m.
+
+#![cfg(all(feature = "alloc", feature = "bech32"))]
+
+use crypto_serde::bech32::{Bech32OrBin, Hrp, Variant};
+use hex_literal::hex;
+use serde_json as json;
+
+/// BIP-173 test vector HRP.
+struct Abcdef;
+
+impl Hrp for Abcdef {
+    const HRP: &'static str = "abcdef";
+}
+
+/// BIP-350 test vector HRP.
+struct AbcdefM;
+
+impl Hrp for AbcdefM {
+    const HRP: &'static str = "abcdef";
+    const VARIANT: Variant = Variant::Bech32m;
+}
+
+/// `age` X25519 secret key.
+struct AgeSecretKey;
+
+impl Hrp for AgeSecretKey {
+    const HRP: &'static str = "age-secret-key-";
+    const UPPERCASE: bool = true;
+}
+
+/// Data part of the BIP-173 test vector.
+const BECH32_DATA: [u8; 20] = hex!("00443214c74254b635cf84653a56d7c675be77df");
+
+/// Data part of the BIP-350 test vector.
+const BECH32M_DATA: [u8; 20] = hex!("ffbbcdeb38bdab49ca307b9ac5a928398a418820");
+
+#[test]
+fn json_bech32() {
+    let encoded = "\"abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw\"";
+    assert_eq!(
+        json::to_string(&Bech32OrBin::<Abcdef>::from(&BECH32_DATA[..])).unwrap(),
+        encoded
+    );
+    assert_eq!(
+        json::from_str::<Bech32OrBin<Abcdef>>(encoded).unwrap().0,
+        BECH32_DATA
+    );
+    assert_eq!(
+        json::from_str::<Bech32OrBin<Abcdef>>(&encoded.to_uppercase())
+            .unwrap()
+            .0,
+        BECH32_DATA
+    );
+}
+
+#[test]
+fn json_bech32m() {
+    let encoded = "\"abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx\"";
+    assert_eq!(
+        json::to_string(&Bech32OrBin::<AbcdefM>::from(&BECH32M_DATA[..])).unwrap(),
+        encoded
+    );
+    assert_eq!(
+        json::from_str::<Bech32OrBin<AbcdefM>>(encoded).unwrap().0,
+        BECH32M_DATA
+    );
+
+    // Variant mismatch
+    assert!(json::from_str::<Bech32OrBin<Abcdef>>(encoded).is_err());
+}
+
+#[test]
+fn json_uppercase() {
+    let key = Bech32OrBin::<AgeSecretKey>::from(&[0x42; 32][..]);
+    let serialized = json::to_string(&key).unwrap();
+    assert!(serialized.starts_with("\"AGE-SECRET-KEY-1GFPYYSJZ"));
+    assert_eq!(
+        json::from_str::<Bech32OrBin<AgeSecretKey>>(&serialized)
+            .unwrap()
+            .0,
+        [0x42; 32]
+    );
+}
+
+#[test]
+fn json_rejects_wrong_hrp() {
+    let serialized = json::to_string(&Bech32OrBin::<Abcdef>::from(&BECH32_DATA[..])).unwrap();
+    assert!(json::from_str::<Bech32OrBin<AgeSecretKey>>(&serialized).is_err());
+}
+
+#[test]
+fn json_rejects_bad_checksum() {
+    assert!(json::from_str::<Bech32OrBin<Abcdef>>(
+        "\"abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxx\""
+    )
+    .is_err());
+}
+
+#[test]
+fn bincode_round_trip() {
+    let serialized = bincode::serialize(&Bech32OrBin::<Abcdef>::from(&BECH32_DATA[..])).unwrap();
+    assert_eq!(serialized[8..], BECH32_DATA);
+    assert_eq!(
+        bincode::deserialize::<Bech32OrBin<Abcdef>>(&serialized)
+            .unwrap()
+            .0,
+        BECH32_DATA
+    );
+}
@@ -0,0 +1,55 @@
+//! Tests for `crypto-bigint` integers.
+
+#![cfg(all(feature = "alloc", feature = "crypto-bigint"))]
+
+use crypto_bigint::{U128, U256};
+use serde::{Deserialize, Serialize};
+use serde_json as json;
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Element {
+    #[serde(with = "crypto_serde::bigint")]
+    x: U256,
+    #[serde(with = "crypto_serde::bigint")]
+    y: U128,
+}
+
+/// Example element.
+fn element() -> Element {
+    Element {
+        x: U256::from_be_hex("00000000000000000000000000000000000000000000000000000000000abcde"),
+        y: U128::from_u64(1),
+    }
+}
+
+#[test]
+fn json_round_trip() {
+    let serialized = json::to_string(&element()).unwrap();
+    assert_eq!(
+        serialized,
+        "{\"x\":\"00000000000000000000000000000000000000000000000000000000000abcde\",\
+         \"y\":\"00000000000000000000000000000001\"}"
+    );
+    assert_eq!(json::from_str::<Element>(&serialized).unwrap(), element());
+}
+
+#[test]
+fn json_rejects_short_hex() {
+    assert!(json::from_str::<Element>(
+        "{\"x\":\"abcde\",\"y\":\"00000000000000000000000000000001\"}"
+    )
+    .is_err());
+}
+
+#[test]
+fn bincode_little_endian() {
+    let serialized = bincode::serialize(&element()).unwrap();
+
+    // 8-byte length prefix before each field
+    assert_eq!(serialized[8..11], [0xde, 0xbc, 0x0a]);
+    assert_eq!(serialized[48], 1);
+    assert_eq!(
+        bincode::deserialize::<Element>(&serialized).unwrap(),
+        element()
+    );
+}
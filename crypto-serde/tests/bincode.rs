@@ -2,9 +2,16 @@
 
 #![cfg(feature = "alloc")]
 
-use crypto_serde::HexUpperOrBin;
+use bincode::Options;
+use crypto_serde::{
+    base64::Base64UrlOrBin,
+    base64::{self, Base64Url},
+    HexUpperOrBin,
+};
 use hex_literal::hex;
 use proptest::{prelude::*, string::*};
+use serde::Deserialize;
+use std::borrow::Cow;
 
 /// Example input to be serialized.
 const EXAMPLE_BYTES: &[u8] = &hex!("000102030405060708090A0B0C0D0E0F");
@@ -24,6 +31,42 @@ fn serialize() {
     assert_eq!(&serialized, BINCODE_BYTES);
 }
 
+/// Struct which borrows its bytes from the input where possible.
+#[derive(Deserialize)]
+struct Borrowed<'a>(
+    #[serde(
+        borrow,
+        deserialize_with = "crypto_serde::slice::deserialize_hex_or_bin_cow"
+    )]
+    Cow<'a, [u8]>,
+);
+
+#[test]
+fn deserialize_borrowed() {
+    let deserialized = bincode::deserialize::<Borrowed<'_>>(BINCODE_BYTES).unwrap();
+    assert!(matches!(deserialized.0, Cow::Borrowed(_)));
+    assert_eq!(deserialized.0.as_ref(), EXAMPLE_BYTES);
+    assert_eq!(deserialized.0.as_ptr(), BINCODE_BYTES[8..].as_ptr());
+}
+
+#[test]
+fn base64_serialize() {
+    let serialized = bincode::serialize(&Base64UrlOrBin::from(EXAMPLE_BYTES)).unwrap();
+    assert_eq!(&serialized, BINCODE_BYTES);
+
+    let deserialized = bincode::deserialize::<Base64UrlOrBin>(&serialized).unwrap();
+    assert_eq!(deserialized.as_ref(), EXAMPLE_BYTES);
+}
+
+#[test]
+fn base64_slice() {
+    let mut buf = [0u8; 16];
+    let mut de =
+        bincode::Deserializer::from_slice(BINCODE_BYTES, bincode::options().with_fixint_encoding());
+    let bytes = base64::deserialize_base64_or_bin_slice::<Base64Url, _>(&mut de, &mut buf).unwrap();
+    assert_eq!(bytes, EXAMPLE_BYTES);
+}
+
 proptest! {
     #[test]
     fn round_trip(bytes in bytes_regex(".{0,256}").unwrap()) {
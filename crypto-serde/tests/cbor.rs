@@ -19,6 +19,15 @@ fn deserialize() {
     assert_eq!(deserialized.as_ref(), EXAMPLE_BYTES);
 }
 
+#[test]
+fn deserialize_byte_string() {
+    // Values serialized as a CBOR byte string, rather than an array, are
+    // also accepted
+    let cbor = hex!("50000102030405060708090A0B0C0D0E0F");
+    let deserialized = de::from_reader::<HexUpperOrBin, _>(&cbor[..]).unwrap();
+    assert_eq!(deserialized.as_ref(), EXAMPLE_BYTES);
+}
+
 #[test]
 fn serialize() {
     let mut serialized = Vec::new();
@@ -0,0 +1,80 @@
+//! Tests for chunked hex.
+
+#![cfg(feature = "alloc")]
+
+use crypto_serde::chunked::{HexChunksOrBin, CHUNK_SIZE};
+use serde_json as json;
+
+/// Build an example blob of the given length.
+fn blob(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i % 251) as u8).collect()
+}
+
+#[test]
+fn json_small() {
+    let serialized = json::to_string(&HexChunksOrBin::from(&[0xAB, 0xCD][..])).unwrap();
+    assert_eq!(serialized, "[\"abcd\"]");
+    assert_eq!(
+        json::from_str::<HexChunksOrBin>(&serialized).unwrap().0,
+        [0xAB, 0xCD]
+    );
+
+    assert_eq!(
+        json::to_string(&HexChunksOrBin::from(Vec::new())).unwrap(),
+        "[]"
+    );
+}
+
+#[test]
+fn json_round_trip() {
+    for len in [CHUNK_SIZE - 1, CHUNK_SIZE, CHUNK_SIZE * 2 + 1] {
+        let bytes = blob(len);
+        let serialized = json::to_value(HexChunksOrBin::from(bytes.as_slice())).unwrap();
+        assert_eq!(
+            serialized.as_array().unwrap().len(),
+            (len + CHUNK_SIZE - 1) / CHUNK_SIZE
+        );
+        assert_eq!(
+            json::from_value::<HexChunksOrBin>(serialized).unwrap().0,
+            bytes
+        );
+    }
+}
+
+#[test]
+fn json_accepts_single_string() {
+    assert_eq!(
+        json::from_str::<HexChunksOrBin>("\"ABcd\"").unwrap().0,
+        [0xAB, 0xCD]
+    );
+    assert_eq!(
+        json::from_str::<HexChunksOrBin>("[\"ab\", \"CD\", \"\"]")
+            .unwrap()
+            .0,
+        [0xAB, 0xCD]
+    );
+}
+
+#[test]
+fn json_rejects_malformed() {
+    for input in ["[\"abc\"]", "[\"ab\", 1]", "[\"zz\"]", "1"] {
+        assert!(
+            json::from_str::<HexChunksOrBin>(input).is_err(),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn bincode_round_trip() {
+    let bytes = blob(CHUNK_SIZE + 1);
+    let serialized = bincode::serialize(&HexChunksOrBin::from(bytes.as_slice())).unwrap();
+    assert_eq!(serialized[8..], bytes[..]);
+    assert_eq!(
+        bincode::deserialize::<HexChunksOrBin>(&serialized)
+            .unwrap()
+            .0,
+        bytes
+    );
+}
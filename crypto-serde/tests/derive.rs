@@ -0,0 +1,67 @@
+//! Tests for the `HexOrBinSerde` custom derive.
+
+#![cfg(all(feature = "alloc", feature = "derive"))]
+
+use crypto_serde::HexOrBinSerde;
+use hex_literal::hex;
+use serde_json as json;
+
+/// Example digest.
+const DIGEST: [u8; 4] = hex!("ABCDEF01");
+
+#[derive(Debug, HexOrBinSerde, PartialEq)]
+struct Digest([u8; 4]);
+
+#[derive(Debug, HexOrBinSerde, PartialEq)]
+#[hex(upper)]
+struct UpperDigest([u8; 4]);
+
+#[derive(Debug, HexOrBinSerde, PartialEq)]
+#[hex(lower)]
+struct Ciphertext {
+    bytes: Vec<u8>,
+}
+
+#[test]
+fn json_array() {
+    let serialized = json::to_string(&Digest(DIGEST)).unwrap();
+    assert_eq!(serialized, "\"abcdef01\"");
+    assert_eq!(
+        json::from_str::<Digest>(&serialized).unwrap(),
+        Digest(DIGEST)
+    );
+    assert!(json::from_str::<Digest>("\"abcdef\"").is_err());
+}
+
+#[test]
+fn json_upper() {
+    let serialized = json::to_string(&UpperDigest(DIGEST)).unwrap();
+    assert_eq!(serialized, "\"ABCDEF01\"");
+    assert_eq!(
+        json::from_str::<UpperDigest>(&serialized).unwrap(),
+        UpperDigest(DIGEST)
+    );
+}
+
+#[test]
+fn json_named_vec() {
+    let ciphertext = Ciphertext {
+        bytes: DIGEST.to_vec(),
+    };
+    let serialized = json::to_string(&ciphertext).unwrap();
+    assert_eq!(serialized, "\"abcdef01\"");
+    assert_eq!(
+        json::from_str::<Ciphertext>(&serialized).unwrap(),
+        ciphertext
+    );
+}
+
+#[test]
+fn bincode_round_trip() {
+    let serialized = bincode::serialize(&Digest(DIGEST)).unwrap();
+    assert_eq!(serialized[8..], DIGEST);
+    assert_eq!(
+        bincode::deserialize::<Digest>(&serialized).unwrap(),
+        Digest(DIGEST)
+    );
+}
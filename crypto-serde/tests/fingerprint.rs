@@ -0,0 +1,60 @@
+//! Tests for colon-delimited fingerprints.
+
+#![cfg(feature = "alloc")]
+
+use crypto_serde::fingerprint::FingerprintHex;
+use hex_literal::hex;
+use serde_json as json;
+
+/// Example fingerprint.
+const FINGERPRINT: [u8; 4] = hex!("ABCDEF01");
+
+#[test]
+fn json_round_trip() {
+    let serialized = json::to_string(&FingerprintHex(FINGERPRINT)).unwrap();
+    assert_eq!(serialized, "\"AB:CD:EF:01\"");
+    assert_eq!(
+        json::from_str::<FingerprintHex<4>>(&serialized).unwrap().0,
+        FINGERPRINT
+    );
+}
+
+#[test]
+fn json_accepts_variants() {
+    for input in ["\"ab:cd:ef:01\"", "\"ABCDEF01\"", "\"abcdef01\""] {
+        assert_eq!(
+            json::from_str::<FingerprintHex<4>>(input).unwrap().0,
+            FINGERPRINT
+        );
+    }
+}
+
+#[test]
+fn json_rejects_malformed() {
+    for input in [
+        "\"AB:CD:EF\"",
+        "\"AB:CD:EF:01:02\"",
+        "\"ABC:DEF:01\"",
+        "\"AB::CD:EF\"",
+        "\"AB:CD:EF:0G\"",
+        "\"ABCDEF\"",
+    ] {
+        assert!(
+            json::from_str::<FingerprintHex<4>>(input).is_err(),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn bincode_round_trip() {
+    let serialized = bincode::serialize(&FingerprintHex(FINGERPRINT)).unwrap();
+    assert_eq!(serialized[8..], FINGERPRINT);
+    assert_eq!(
+        bincode::deserialize::<FingerprintHex<4>>(&serialized)
+            .unwrap()
+            .0,
+        FINGERPRINT
+    );
+}
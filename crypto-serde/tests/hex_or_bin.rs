@@ -0,0 +1,83 @@
+//! Tests for types which convert to and from byte slices.
+
+#![cfg(feature = "alloc")]
+
+use crypto_serde::SerdeWrapper;
+use hex_literal::hex;
+use serde::{Deserialize, Serialize};
+use serde_json as json;
+
+/// Example fixed-size type which doesn't impl `Serialize` itself.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Signature([u8; 4]);
+
+impl AsRef<[u8]> for Signature {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl TryFrom<&[u8]> for Signature {
+    type Error = core::array::TryFromSliceError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        bytes.try_into().map(Self)
+    }
+}
+
+/// Example signature.
+const SIGNATURE: Signature = Signature(hex!("ABCDEF01"));
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Signed {
+    #[serde(with = "crypto_serde::hex_or_bin")]
+    signature: Signature,
+}
+
+#[test]
+fn json_round_trip() {
+    let signed = Signed {
+        signature: SIGNATURE,
+    };
+    let serialized = json::to_string(&signed).unwrap();
+    assert_eq!(serialized, "{\"signature\":\"abcdef01\"}");
+    assert_eq!(json::from_str::<Signed>(&serialized).unwrap(), signed);
+
+    let serialized = json::to_string(&SerdeWrapper(SIGNATURE)).unwrap();
+    assert_eq!(serialized, "\"abcdef01\"");
+    assert_eq!(
+        json::from_str::<SerdeWrapper<Signature>>("\"ABCDEF01\"")
+            .unwrap()
+            .into_inner(),
+        SIGNATURE
+    );
+}
+
+#[test]
+fn json_rejects_wrong_length() {
+    for input in ["\"abcdef\"", "\"abcdef0102\"", "\"\""] {
+        assert!(
+            json::from_str::<SerdeWrapper<Signature>>(input).is_err(),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn bincode_round_trip() {
+    let serialized = bincode::serialize(&SerdeWrapper(SIGNATURE)).unwrap();
+    assert_eq!(serialized[8..], SIGNATURE.0);
+    assert_eq!(
+        bincode::deserialize::<SerdeWrapper<Signature>>(&serialized)
+            .unwrap()
+            .0,
+        SIGNATURE
+    );
+
+    let signed = Signed {
+        signature: SIGNATURE,
+    };
+    let serialized = bincode::serialize(&signed).unwrap();
+    assert_eq!(bincode::deserialize::<Signed>(&serialized).unwrap(), signed);
+}
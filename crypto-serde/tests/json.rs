@@ -2,7 +2,10 @@
 
 #![cfg(feature = "alloc")]
 
-use crypto_serde::{HexLowerOrBin, HexUpperOrBin};
+use crypto_serde::{
+    base64::{self, Base64, Base64OrBin, Base64UrlOrBin, Base64UrlUnpadded},
+    slice, HexLowerOrBin, HexUpperOrBin,
+};
 use hex_literal::hex;
 use proptest::{prelude::*, string::*};
 use serde_json as json;
@@ -16,6 +19,12 @@ const HEX_LOWER: &str = "\"000102030405060708090a0b0c0d0e0f\"";
 /// Upper-case hex serialization of [`EXAMPLE_BYTES`].
 const HEX_UPPER: &str = "\"000102030405060708090A0B0C0D0E0F\"";
 
+/// Standard Base64 serialization of [`EXAMPLE_BYTES`].
+const BASE64: &str = "\"AAECAwQFBgcICQoLDA0ODw==\"";
+
+/// URL-safe unpadded Base64 serialization of [`EXAMPLE_BYTES`].
+const BASE64_URL: &str = "\"AAECAwQFBgcICQoLDA0ODw\"";
+
 #[test]
 fn hex_lower() {
     let serialized = json::to_string(&HexLowerOrBin::from(EXAMPLE_BYTES)).unwrap();
@@ -34,6 +43,64 @@ fn hex_upper() {
     assert_eq!(deserialized.as_ref(), EXAMPLE_BYTES);
 }
 
+#[test]
+fn hex_vec() {
+    #[derive(serde::Deserialize, serde::Serialize)]
+    struct Ciphertext {
+        #[serde(
+            serialize_with = "crypto_serde::serialize_hex_lower_or_bin",
+            deserialize_with = "slice::deserialize_hex_or_bin_vec"
+        )]
+        bytes: Vec<u8>,
+    }
+
+    let serialized = json::to_string(&Ciphertext {
+        bytes: EXAMPLE_BYTES.to_vec(),
+    })
+    .unwrap();
+    assert_eq!(serialized, format!("{{\"bytes\":{}}}", HEX_LOWER));
+
+    // Owned (non-borrowed) strings are supported as well as mixed case
+    let reader = format!("{{\"bytes\":{}}}", HEX_UPPER);
+    let deserialized = json::from_reader::<_, Ciphertext>(reader.as_bytes()).unwrap();
+    assert_eq!(deserialized.bytes, EXAMPLE_BYTES);
+}
+
+#[test]
+fn base64_std() {
+    let serialized = json::to_string(&Base64OrBin::<Base64>::from(EXAMPLE_BYTES)).unwrap();
+    assert_eq!(serialized, BASE64);
+
+    let deserialized = json::from_str::<Base64OrBin>(&serialized).unwrap();
+    assert_eq!(deserialized.as_ref(), EXAMPLE_BYTES);
+}
+
+#[test]
+fn base64_url() {
+    let serialized = json::to_string(&Base64UrlOrBin::from(EXAMPLE_BYTES)).unwrap();
+    assert_eq!(serialized, BASE64_URL);
+
+    let deserialized = json::from_str::<Base64UrlOrBin>(&serialized).unwrap();
+    assert_eq!(deserialized.as_ref(), EXAMPLE_BYTES);
+
+    assert!(json::from_str::<Base64UrlOrBin>(BASE64).is_err());
+}
+
+#[test]
+fn base64_slice() {
+    let mut buf = [0u8; 16];
+    let mut de = json::Deserializer::from_str(BASE64_URL);
+    let bytes =
+        base64::deserialize_base64_or_bin_slice::<Base64UrlUnpadded, _>(&mut de, &mut buf).unwrap();
+    assert_eq!(bytes, EXAMPLE_BYTES);
+
+    let mut buf = [0u8; 15];
+    let mut de = json::Deserializer::from_str(BASE64_URL);
+    assert!(
+        base64::deserialize_base64_or_bin_slice::<Base64UrlUnpadded, _>(&mut de, &mut buf).is_err()
+    );
+}
+
 proptest! {
     #[test]
     fn round_trip_lower(bytes in bytes_regex(".{0,256}").unwrap()) {
@@ -48,4 +115,11 @@ proptest! {
         let deserialized = json::from_str::<HexUpperOrBin>(&serialized).unwrap();
         prop_assert_eq!(bytes, deserialized.0);
     }
+
+    #[test]
+    fn round_trip_base64(bytes in bytes_regex(".{0,256}").unwrap()) {
+        let serialized = json::to_string(&Base64UrlOrBin::from(bytes.as_ref())).unwrap();
+        let deserialized = json::from_str::<Base64UrlOrBin>(&serialized).unwrap();
+        prop_assert_eq!(bytes, deserialized.0);
+    }
 }
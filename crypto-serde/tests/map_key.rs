@@ -0,0 +1,65 @@
+//! Tests for maps with byte string keys.
+
+#![cfg(feature = "alloc")]
+
+use hex_literal::hex;
+use serde::{Deserialize, Serialize};
+use serde_json as json;
+use std::collections::{BTreeMap, HashMap};
+
+/// Example digest.
+const DIGEST: [u8; 4] = hex!("ABCDEF01");
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+struct Index {
+    #[serde(with = "crypto_serde::map_key")]
+    entries: HashMap<[u8; 4], u64>,
+    #[serde(with = "crypto_serde::map_key")]
+    sorted: BTreeMap<Vec<u8>, String>,
+}
+
+/// Example index.
+fn index() -> Index {
+    let mut index = Index::default();
+    index.entries.insert(DIGEST, 42);
+    index.sorted.insert(vec![0x01], "one".into());
+    index.sorted.insert(vec![0x02, 0x03], "two".into());
+    index
+}
+
+#[test]
+fn json_round_trip() {
+    let serialized = json::to_string(&index()).unwrap();
+    assert_eq!(
+        serialized,
+        "{\"entries\":{\"abcdef01\":42},\"sorted\":{\"01\":\"one\",\"0203\":\"two\"}}"
+    );
+    assert_eq!(json::from_str::<Index>(&serialized).unwrap(), index());
+}
+
+#[test]
+fn json_rejects_wrong_key_length() {
+    assert!(json::from_str::<Index>("{\"entries\":{\"abcdef\":42},\"sorted\":{}}").is_err());
+}
+
+#[test]
+fn cbor_byte_string_keys() {
+    let mut index = Index::default();
+    index.entries.insert(DIGEST, 1);
+
+    let mut serialized = Vec::new();
+    ciborium::ser::into_writer(&index, &mut serialized).unwrap();
+
+    // Map with a single 4-byte byte string key
+    let key = hex!("A1 44 ABCDEF01 01");
+    assert!(serialized.windows(key.len()).any(|w| w == key));
+
+    let deserialized: Index = ciborium::de::from_reader(serialized.as_slice()).unwrap();
+    assert_eq!(deserialized, index);
+}
+
+#[test]
+fn bincode_round_trip() {
+    let serialized = bincode::serialize(&index()).unwrap();
+    assert_eq!(bincode::deserialize::<Index>(&serialized).unwrap(), index());
+}
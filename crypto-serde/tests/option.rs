@@ -0,0 +1,68 @@
+//! Tests for the `Option` helpers.
+
+#![cfg(feature = "alloc")]
+
+use hex_literal::hex;
+use serde::{Deserialize, Serialize};
+use serde_json as json;
+
+/// Example type with optional byte fields.
+#[derive(Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+struct Params {
+    #[serde(with = "crypto_serde::array::option")]
+    salt: Option<[u8; 4]>,
+
+    #[serde(with = "crypto_serde::slice::option")]
+    label: Option<Vec<u8>>,
+}
+
+/// Example [`Params`] with all fields set.
+fn example() -> Params {
+    Params {
+        salt: Some(hex!("DEADBEEF")),
+        label: Some(b"hello".to_vec()),
+    }
+}
+
+#[test]
+fn json_some() {
+    let serialized = json::to_string(&example()).unwrap();
+    assert_eq!(serialized, r#"{"salt":"deadbeef","label":"68656c6c6f"}"#);
+    assert_eq!(json::from_str::<Params>(&serialized).unwrap(), example());
+}
+
+#[test]
+fn json_none() {
+    let serialized = json::to_string(&Params::default()).unwrap();
+    assert_eq!(serialized, r#"{"salt":null,"label":null}"#);
+    assert_eq!(
+        json::from_str::<Params>(&serialized).unwrap(),
+        Params::default()
+    );
+}
+
+#[test]
+fn json_wrong_length() {
+    assert!(json::from_str::<Params>(r#"{"salt":"deadbe","label":null}"#).is_err());
+    assert!(json::from_str::<Params>(r#"{"salt":"deadbeef00","label":null}"#).is_err());
+}
+
+#[test]
+fn bincode_round_trip() {
+    for params in [example(), Params::default()] {
+        let serialized = bincode::serialize(&params).unwrap();
+        assert_eq!(bincode::deserialize::<Params>(&serialized).unwrap(), params);
+    }
+}
+
+#[test]
+fn cbor_round_trip() {
+    for params in [example(), Params::default()] {
+        let mut serialized = Vec::new();
+        ciborium::ser::into_writer(&params, &mut serialized).unwrap();
+        assert_eq!(
+            ciborium::de::from_reader::<Params, _>(serialized.as_slice()).unwrap(),
+            params
+        );
+    }
+}
@@ -0,0 +1,81 @@
+//! Tests for `0x`-prefixed hex.
+
+#![cfg(feature = "alloc")]
+
+use crypto_serde::{
+    prefixed_hex::{deserialize_prefixed_hex_or_bin_vec, serialize_prefixed_hex_lower_or_bin},
+    HexLowerOrBin, HexOrBin, PrefixedHexLowerOrBin, PrefixedHexOrBin, PrefixedHexUpperOrBin,
+};
+use hex_literal::hex;
+use serde::{Deserialize, Serialize};
+use serde_json as json;
+
+/// Example address.
+const ADDRESS: [u8; 4] = hex!("ABCDEF01");
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Transaction {
+    #[serde(
+        serialize_with = "serialize_prefixed_hex_lower_or_bin",
+        deserialize_with = "deserialize_prefixed_hex_or_bin_vec"
+    )]
+    to: Vec<u8>,
+}
+
+#[test]
+fn json_round_trip() {
+    let serialized = json::to_string(&PrefixedHexLowerOrBin::from(&ADDRESS[..])).unwrap();
+    assert_eq!(serialized, "\"0xabcdef01\"");
+    assert_eq!(
+        json::from_str::<PrefixedHexLowerOrBin>(&serialized)
+            .unwrap()
+            .0,
+        ADDRESS
+    );
+
+    let serialized = json::to_string(&PrefixedHexUpperOrBin::from(&ADDRESS[..])).unwrap();
+    assert_eq!(serialized, "\"0xABCDEF01\"");
+
+    // `HexOrBin` is unaffected
+    let serialized = json::to_string(&HexOrBin::<true>::from(&ADDRESS[..])).unwrap();
+    assert_eq!(serialized, "\"ABCDEF01\"");
+    assert_eq!(
+        json::to_string(&PrefixedHexOrBin::<true>::from(&ADDRESS[..])).unwrap(),
+        "\"0xABCDEF01\""
+    );
+}
+
+#[test]
+fn json_accepts_with_or_without_prefix() {
+    for input in ["\"0xabcdef01\"", "\"0XABCDEF01\"", "\"abcdef01\""] {
+        assert_eq!(
+            json::from_str::<PrefixedHexLowerOrBin>(input).unwrap().0,
+            ADDRESS
+        );
+    }
+
+    assert!(json::from_str::<PrefixedHexLowerOrBin>("\"0xabcdef0\"").is_err());
+    assert!(json::from_str::<HexLowerOrBin>("\"0xabcdef01\"").is_err());
+}
+
+#[test]
+fn json_serde_with() {
+    let tx = Transaction {
+        to: ADDRESS.to_vec(),
+    };
+    let serialized = json::to_string(&tx).unwrap();
+    assert_eq!(serialized, "{\"to\":\"0xabcdef01\"}");
+    assert_eq!(json::from_str::<Transaction>(&serialized).unwrap(), tx);
+}
+
+#[test]
+fn bincode_round_trip() {
+    let serialized = bincode::serialize(&PrefixedHexLowerOrBin::from(&ADDRESS[..])).unwrap();
+    assert_eq!(serialized[8..], ADDRESS);
+    assert_eq!(
+        bincode::deserialize::<PrefixedHexLowerOrBin>(&serialized)
+            .unwrap()
+            .0,
+        ADDRESS
+    );
+}
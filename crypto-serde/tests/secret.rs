@@ -0,0 +1,40 @@
+//! Tests for secret (zeroizing) helpers.
+
+#![cfg(all(feature = "alloc", feature = "zeroize"))]
+
+use crypto_serde::array::{self, SecretHexOrBin};
+use hex_literal::hex;
+use serde_json as json;
+
+/// Example secret.
+const SECRET: [u8; 4] = hex!("DEADBEEF");
+
+#[test]
+fn json_round_trip() {
+    let serialized = json::to_string(&SecretHexOrBin(SECRET)).unwrap();
+    assert_eq!(serialized, "\"deadbeef\"");
+
+    let deserialized = json::from_str::<SecretHexOrBin<4>>(&serialized).unwrap();
+    assert_eq!(deserialized.0, SECRET);
+
+    // Owned strings are handled as well as borrowed ones
+    let deserialized = json::from_reader::<_, SecretHexOrBin<4>>(serialized.as_bytes()).unwrap();
+    assert_eq!(deserialized.0, SECRET);
+}
+
+#[test]
+fn bincode_round_trip() {
+    let serialized = bincode::serialize(&SecretHexOrBin(SECRET)).unwrap();
+    let deserialized = bincode::deserialize::<SecretHexOrBin<4>>(&serialized).unwrap();
+    assert_eq!(deserialized.0, SECRET);
+}
+
+#[test]
+fn deserialize_secret() {
+    let mut de = json::Deserializer::from_str("\"DEADBEEF\"");
+    let secret = array::deserialize_secret_hex_or_bin::<_, 4>(&mut de).unwrap();
+    assert_eq!(*secret, SECRET);
+
+    let mut de = json::Deserializer::from_str("\"DEAD\"");
+    assert!(array::deserialize_secret_hex_or_bin::<_, 4>(&mut de).is_err());
+}
@@ -0,0 +1,61 @@
+//! Tests for `serde_with` support.
+
+#![cfg(all(feature = "alloc", feature = "base64ct", feature = "serde_with"))]
+
+use crypto_serde::{base64::Base64UrlOrBin, HexLowerOrBin, HexUpperOrBin};
+use hex_literal::hex;
+use serde::{Deserialize, Serialize};
+use serde_json as json;
+use serde_with::serde_as;
+
+/// Example type with byte fields of various types.
+#[serde_as]
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct Example {
+    #[serde_as(as = "HexUpperOrBin")]
+    key: [u8; 4],
+
+    #[serde_as(as = "HexLowerOrBin")]
+    data: Vec<u8>,
+
+    #[serde_as(as = "Base64UrlOrBin")]
+    signature: Vec<u8>,
+
+    #[serde_as(as = "Option<HexLowerOrBin>")]
+    salt: Option<[u8; 2]>,
+}
+
+/// Example value.
+fn example() -> Example {
+    Example {
+        key: hex!("DEADBEEF"),
+        data: hex!("0102").to_vec(),
+        signature: hex!("FFFF").to_vec(),
+        salt: Some(hex!("ABCD")),
+    }
+}
+
+#[test]
+fn json_round_trip() {
+    let serialized = json::to_string(&example()).unwrap();
+    assert_eq!(
+        serialized,
+        r#"{"key":"DEADBEEF","data":"0102","signature":"__8","salt":"abcd"}"#
+    );
+    assert_eq!(json::from_str::<Example>(&serialized).unwrap(), example());
+}
+
+#[test]
+fn json_wrong_length() {
+    let input = r#"{"key":"DEAD","data":"","signature":"","salt":null}"#;
+    assert!(json::from_str::<Example>(input).is_err());
+}
+
+#[test]
+fn bincode_round_trip() {
+    let serialized = bincode::serialize(&example()).unwrap();
+    assert_eq!(
+        bincode::deserialize::<Example>(&serialized).unwrap(),
+        example()
+    );
+}
@@ -0,0 +1,68 @@
+//! Tests for the cross-format test harness.
+
+#![cfg(feature = "test-vectors")]
+
+use crypto_serde::{fingerprint::FingerprintHex, test_vectors, SerdeWrapper};
+use hex_literal::hex;
+use serde::{Deserialize, Serialize};
+
+/// Example digest.
+const DIGEST: [u8; 4] = hex!("ABCDEF01");
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Record {
+    name: String,
+    #[serde(with = "crypto_serde::hex_or_bin")]
+    digest: [u8; 4],
+}
+
+/// Type which serializes as a byte string (rather than a sequence) when
+/// using binary formats.
+#[derive(Debug, Deserialize, PartialEq)]
+struct ByteString(Vec<u8>);
+
+impl Serialize for ByteString {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+#[test]
+fn wrapper() {
+    let value = SerdeWrapper(DIGEST);
+    test_vectors::assert_round_trip(&value);
+    test_vectors::assert_human_readable(&value, "\"abcdef01\"");
+    test_vectors::assert_binary(&value, &DIGEST);
+}
+
+#[test]
+fn fingerprint() {
+    let value = FingerprintHex(DIGEST);
+    test_vectors::assert_round_trip(&value);
+    test_vectors::assert_human_readable(&value, "\"AB:CD:EF:01\"");
+    test_vectors::assert_binary(&value, &DIGEST);
+}
+
+#[test]
+fn record() {
+    let record = Record {
+        name: "example".into(),
+        digest: DIGEST,
+    };
+
+    test_vectors::assert_round_trip(&record);
+    test_vectors::assert_human_readable(&record, r#"{ "digest": "abcdef01", "name": "example" }"#);
+}
+
+#[test]
+#[should_panic(expected = "Json: unexpected representation")]
+fn human_readable_mismatch() {
+    test_vectors::assert_human_readable(&SerdeWrapper(DIGEST), "\"ABCDEF01\"");
+}
+
+#[test]
+#[should_panic(expected = "Cbor: unexpected representation")]
+fn binary_mismatch() {
+    // Serialized as a byte string rather than a sequence
+    test_vectors::assert_binary(&ByteString(DIGEST.to_vec()), &DIGEST);
+}
@@ -0,0 +1,104 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use der::{
+    asn1::{Ia5String, OctetString, PrintableString, Utf8String},
+    Decode, Encode, EncodeValue, Encoder, FixedTag, Length, Tag,
+};
+
+/// Length of the string values being decoded.
+const STRING_LEN: usize = 1 << 14;
+
+/// Build a DER document containing a string with the given tag.
+fn der_string(tag: u8, byte: u8) -> Vec<u8> {
+    let mut der = vec![tag, 0x82, (STRING_LEN >> 8) as u8, STRING_LEN as u8];
+    der.resize(der.len() + STRING_LEN, byte);
+    der
+}
+
+fn decode_strings(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode string");
+    group.throughput(Throughput::Bytes(STRING_LEN as u64));
+
+    let der = der_string(0x16, b'a');
+    group.bench_function("Ia5String", |b| {
+        b.iter(|| Ia5String::from_der(black_box(&der)).unwrap())
+    });
+
+    let der = der_string(0x13, b'a');
+    group.bench_function("PrintableString", |b| {
+        b.iter(|| PrintableString::from_der(black_box(&der)).unwrap())
+    });
+
+    let der = der_string(0x0C, b'a');
+    group.bench_function("Utf8String", |b| {
+        b.iter(|| Utf8String::from_der(black_box(&der)).unwrap())
+    });
+
+    group.finish();
+}
+
+/// `SEQUENCE` tree with roughly the size and nesting depth of an X.509
+/// certificate, with `OCTET STRING` leaves.
+struct Tree {
+    /// Number of levels of `SEQUENCE`s below this one.
+    depth: u8,
+}
+
+/// Number of children of each `SEQUENCE` in a [`Tree`].
+const TREE_WIDTH: usize = 2;
+
+/// Contents of each leaf of a [`Tree`].
+const TREE_LEAF: [u8; 32] = [0x42; 32];
+
+impl EncodeValue for Tree {
+    fn value_len(&self) -> der::Result<Length> {
+        (0..TREE_WIDTH).try_fold(Length::ZERO, |len, _| match self.depth.checked_sub(1) {
+            Some(depth) => len + Tree { depth }.encoded_len()?,
+            None => len + OctetString::new(&TREE_LEAF)?.encoded_len()?,
+        })
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> der::Result<()> {
+        for _ in 0..TREE_WIDTH {
+            match self.depth.checked_sub(1) {
+                Some(depth) => encoder.encode(&Tree { depth })?,
+                None => encoder.encode(&OctetString::new(&TREE_LEAF)?)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FixedTag for Tree {
+    const TAG: Tag = Tag::Sequence;
+}
+
+fn encode_tree(c: &mut Criterion) {
+    let tree = Tree { depth: 6 };
+    let mut group = c.benchmark_group("encode tree");
+
+    // Computes the length of each `SEQUENCE` when encoding its header, which
+    // recomputes the lengths of the values nested within it
+    let mut buf = vec![0u8; 8192];
+    group.bench_function("value_len", |b| {
+        b.iter(|| {
+            let len = black_box(&tree).encoded_len().unwrap();
+            let res = black_box(&tree).encode_to_slice(&mut buf).unwrap();
+            assert_eq!(len, res.len().try_into().unwrap());
+        })
+    });
+
+    // Uses lengths recorded by a single counting pass
+    let mut buf = Vec::with_capacity(8192);
+    group.bench_function("counting", |b| {
+        b.iter(|| {
+            buf.clear();
+            black_box(&tree).encode_to_vec(&mut buf).unwrap()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, decode_strings, encode_tree);
+criterion_main!(benches);
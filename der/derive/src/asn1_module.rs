@@ -0,0 +1,1120 @@
+//! Support for the `asn1!` macro, which generates Rust types from a subset
+//! of ASN.1 module notation.
+
+use crate::{TagMode, TagNumber};
+use proc_macro2::{Delimiter, Group, Ident, Literal, Span, TokenStream, TokenTree};
+use proc_macro_error::abort;
+use quote::quote;
+use std::collections::BTreeSet;
+
+/// Built-in ASN.1 types which aren't supported by the `asn1!` macro.
+const UNSUPPORTED_TYPES: &[&str] = &[
+    "BMPString",
+    "CHARACTER",
+    "CLASS",
+    "EMBEDDED",
+    "EXTERNAL",
+    "GeneralString",
+    "GraphicString",
+    "INSTANCE",
+    "NumericString",
+    "REAL",
+    "RELATIVE-OID",
+    "T61String",
+    "TeletexString",
+    "UniversalString",
+    "VideotexString",
+    "VisibleString",
+];
+
+/// Maximum number of type references followed when resolving a type.
+const MAX_REFERENCES: usize = 32;
+
+/// Parsed ASN.1 module.
+pub(crate) struct Asn1Module {
+    /// Default tagging mode of the module.
+    tag_mode: TagMode,
+
+    /// Type assignments in the order they're defined.
+    assignments: Vec<Assignment>,
+}
+
+/// ASN.1 type assignment, i.e. `Name ::= Type`.
+struct Assignment {
+    /// Name of the type.
+    name: String,
+
+    /// Span of the name.
+    span: Span,
+
+    /// Type being assigned.
+    ty: Type,
+}
+
+/// ASN.1 type.
+#[derive(Clone, Debug)]
+enum Type {
+    /// Built-in type.
+    Builtin(Builtin),
+
+    /// `INTEGER` with a list of named numbers.
+    NamedInteger(Vec<(String, i64)>),
+
+    /// Reference to a type defined elsewhere.
+    Reference(String, Span),
+
+    /// `SEQUENCE` with the given components.
+    Sequence(Vec<Component>),
+
+    /// `SEQUENCE OF` the given type.
+    SequenceOf(Box<Type>),
+
+    /// `SET OF` the given type.
+    SetOf(Box<Type>),
+
+    /// `CHOICE` between the given alternatives.
+    Choice(Vec<Component>),
+
+    /// `ENUMERATED` with the given items.
+    Enumerated(Vec<(String, i64)>),
+}
+
+/// Built-in ASN.1 types supported by the `asn1!` macro.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Builtin {
+    Any,
+    BitString,
+    Boolean,
+    GeneralizedTime,
+    Ia5String,
+    Integer,
+    Null,
+    ObjectIdentifier,
+    OctetString,
+    PrintableString,
+    UtcTime,
+    Utf8String,
+}
+
+/// Component of a `SEQUENCE` or alternative of a `CHOICE`.
+#[derive(Clone, Debug)]
+struct Component {
+    /// Identifier of the component.
+    name: String,
+
+    /// Span of the identifier.
+    span: Span,
+
+    /// `CONTEXT-SPECIFIC` tag number and tagging mode, if tagged.
+    tag: Option<(TagNumber, Option<TagMode>)>,
+
+    /// Type of the component.
+    ty: Type,
+
+    /// Is this component `OPTIONAL`?
+    optional: bool,
+
+    /// `DEFAULT` value of this component, if any.
+    default: Option<Value>,
+}
+
+/// Value of a `DEFAULT` component.
+#[derive(Clone, Debug)]
+enum Value {
+    Boolean(bool),
+    Integer(i64),
+    Named(String, Span),
+}
+
+impl Asn1Module {
+    /// Parse an ASN.1 module from the input of the `asn1!` macro.
+    pub fn new(input: TokenStream) -> Self {
+        let mut cursor = Cursor::new(input);
+        let mut tag_mode = TagMode::Explicit;
+
+        let has_header = cursor
+            .tokens
+            .iter()
+            .take(3)
+            .any(|token| matches!(token, TokenTree::Ident(ident) if ident == "DEFINITIONS"));
+
+        if has_header {
+            cursor.expect_name("module name");
+            cursor.eat_group(Delimiter::Brace);
+            cursor.expect_keyword("DEFINITIONS");
+
+            if cursor.eat_keyword("IMPLICIT") {
+                tag_mode = TagMode::Implicit;
+                cursor.expect_keyword("TAGS");
+            } else if cursor.eat_keyword("EXPLICIT") {
+                cursor.expect_keyword("TAGS");
+            } else if cursor.peek_keyword("AUTOMATIC") {
+                abort!(cursor.span(), "`AUTOMATIC` tagging is not supported");
+            }
+
+            if cursor.eat_keyword("EXTENSIBILITY") {
+                cursor.expect_keyword("IMPLIED");
+            }
+
+            cursor.expect_assignment();
+            cursor.expect_keyword("BEGIN");
+
+            for keyword in ["EXPORTS", "IMPORTS"] {
+                if cursor.eat_keyword(keyword) {
+                    while !cursor.eat_punct(';') {
+                        if cursor.next().is_none() {
+                            abort!(cursor.span(), "expected `;` after `{}`", keyword);
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut assignments = Vec::<Assignment>::new();
+
+        loop {
+            if has_header && cursor.eat_keyword("END") {
+                if !cursor.is_empty() {
+                    abort!(cursor.span(), "unexpected token after `END`");
+                }
+
+                break;
+            }
+
+            if cursor.is_empty() {
+                if has_header {
+                    abort!(cursor.span(), "expected `END`");
+                }
+
+                break;
+            }
+
+            let (name, span) = cursor.expect_name("type assignment");
+
+            // Value assignments (e.g. of OIDs) are skipped
+            if name.starts_with(|c: char| c.is_ascii_lowercase()) {
+                parse_type(&mut cursor);
+                cursor.expect_assignment();
+
+                if cursor.eat_group(Delimiter::Brace).is_none() && cursor.name().is_none() {
+                    cursor.number();
+                }
+
+                continue;
+            }
+
+            cursor.expect_assignment();
+            let ty = parse_type(&mut cursor);
+
+            if assignments.iter().any(|assignment| assignment.name == name) {
+                abort!(span, "duplicate definition of `{}`", name);
+            }
+
+            assignments.push(Assignment { name, span, ty });
+        }
+
+        Self {
+            tag_mode,
+            assignments,
+        }
+    }
+
+    /// Lower the module into a [`TokenStream`] of Rust type definitions.
+    pub fn to_tokens(&self) -> TokenStream {
+        let lifetimes = self.lifetimes();
+        let mut set_elements = BTreeSet::new();
+
+        for assignment in &self.assignments {
+            collect_set_elements(&assignment.ty, &mut set_elements);
+        }
+
+        let mut items = Vec::new();
+
+        for assignment in &self.assignments {
+            let ident = type_ident(&assignment.name, assignment.span);
+            let doc = format!("ASN.1 `{}` type.", assignment.name);
+            let generics = if lifetimes.contains(&assignment.name) {
+                quote!(<'a>)
+            } else {
+                quote!()
+            };
+            let value_ord = if set_elements.contains(&assignment.name) {
+                quote!(, ::der::ValueOrd)
+            } else {
+                quote!()
+            };
+
+            match &assignment.ty {
+                Type::Sequence(components) => {
+                    let mut fields = Vec::new();
+
+                    for component in components {
+                        let field_ident = field_ident(&component.name, component.span);
+                        let field_doc = format!("`{}` field.", component.name);
+                        let attrs = self.field_attrs(&assignment.name, component, &mut items);
+                        let ty = self.rust_type(&component.ty, &lifetimes);
+                        let ty = if component.optional {
+                            quote!(Option<#ty>)
+                        } else {
+                            ty
+                        };
+
+                        fields.push(quote! {
+                            #[doc = #field_doc]
+                            #attrs
+                            pub #field_ident: #ty
+                        });
+                    }
+
+                    items.push(quote! {
+                        #[doc = #doc]
+                        #[derive(Clone, Debug, Eq, PartialEq, ::der::Sequence #value_ord)]
+                        pub struct #ident #generics {
+                            #(#fields),*
+                        }
+                    });
+                }
+                Type::Choice(components) => {
+                    let mut variants = Vec::new();
+
+                    for component in components {
+                        if component.optional || component.default.is_some() {
+                            abort!(
+                                component.span,
+                                "`CHOICE` alternatives can't be `OPTIONAL` or have a `DEFAULT`"
+                            );
+                        }
+
+                        let variant_ident = variant_ident(&component.name, component.span);
+                        let variant_doc = format!("`{}` alternative.", component.name);
+                        let attrs = self.field_attrs(&assignment.name, component, &mut items);
+                        let ty = self.rust_type(&component.ty, &lifetimes);
+
+                        variants.push(quote! {
+                            #[doc = #variant_doc]
+                            #attrs
+                            #variant_ident(#ty)
+                        });
+                    }
+
+                    items.push(quote! {
+                        #[doc = #doc]
+                        #[derive(Clone, Debug, Eq, PartialEq, ::der::Choice #value_ord)]
+                        pub enum #ident #generics {
+                            #(#variants),*
+                        }
+                    });
+                }
+                Type::Enumerated(values) => {
+                    let variants = values.iter().map(|(name, value)| {
+                        let variant_ident = variant_ident(name, assignment.span);
+                        let variant_doc = format!("`{}` value.", name);
+                        let value = u32::try_from(*value).unwrap_or_else(|_| {
+                            abort!(
+                                assignment.span,
+                                "`ENUMERATED` value `{}` is out of range",
+                                name
+                            )
+                        });
+                        let value = Literal::u32_unsuffixed(value);
+
+                        quote! {
+                            #[doc = #variant_doc]
+                            #variant_ident = #value
+                        }
+                    });
+
+                    items.push(quote! {
+                        #[doc = #doc]
+                        #[derive(Copy, Clone, Debug, Eq, PartialEq, ::der::Enumerated)]
+                        #[repr(u32)]
+                        pub enum #ident {
+                            #(#variants),*
+                        }
+                    });
+                }
+                ty => {
+                    let rust_type = self.rust_type(ty, &lifetimes);
+
+                    items.push(quote! {
+                        #[doc = #doc]
+                        pub type #ident #generics = #rust_type;
+                    });
+
+                    if let Type::NamedInteger(values) = ty {
+                        for (name, value) in values {
+                            let const_ident = const_ident(&assignment.name, name, assignment.span);
+                            let const_doc = format!("`{}` value of `{}`.", name, assignment.name);
+                            let value = Literal::i64_unsuffixed(*value);
+
+                            items.push(quote! {
+                                #[doc = #const_doc]
+                                pub const #const_ident: #ident = #value;
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        quote!(#(#items)*)
+    }
+
+    /// Compute the names of the types which borrow from the input they're
+    /// decoded from, and so need a lifetime parameter.
+    fn lifetimes(&self) -> BTreeSet<String> {
+        let mut lifetimes = BTreeSet::new();
+
+        loop {
+            let mut changed = false;
+
+            for assignment in &self.assignments {
+                if !lifetimes.contains(&assignment.name) && borrows(&assignment.ty, &lifetimes) {
+                    lifetimes.insert(assignment.name.clone());
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                return lifetimes;
+            }
+        }
+    }
+
+    /// Resolve references to types defined in this module, returning the
+    /// name of the type definition along with it (if any).
+    fn resolve<'t>(&'t self, mut ty: &'t Type) -> (Option<&'t str>, &'t Type) {
+        let mut name = None;
+
+        for _ in 0..MAX_REFERENCES {
+            let reference = match ty {
+                Type::Reference(reference, _) => reference,
+                _ => break,
+            };
+
+            match self.assignments.iter().find(|a| &a.name == reference) {
+                Some(assignment) => {
+                    name = Some(assignment.name.as_str());
+                    ty = &assignment.ty;
+                }
+                None => break,
+            }
+        }
+
+        (name, ty)
+    }
+
+    /// Get the Rust type for the given ASN.1 type.
+    fn rust_type(&self, ty: &Type, lifetimes: &BTreeSet<String>) -> TokenStream {
+        match ty {
+            Type::Builtin(builtin) => builtin.to_tokens(),
+            Type::NamedInteger(_) => quote!(i64),
+            Type::Reference(name, span) => {
+                let ident = type_ident(name, *span);
+
+                if lifetimes.contains(name) {
+                    quote!(#ident<'a>)
+                } else {
+                    quote!(#ident)
+                }
+            }
+            Type::SequenceOf(inner) => {
+                let inner = self.rust_type(inner, lifetimes);
+                quote!(Vec<#inner>)
+            }
+            Type::SetOf(inner) => {
+                let inner = self.rust_type(inner, lifetimes);
+                quote!(::der::asn1::SetOfVec<#inner>)
+            }
+            Type::Sequence(_) | Type::Choice(_) | Type::Enumerated(_) => {
+                unreachable!("nested type definitions are rejected by the parser")
+            }
+        }
+    }
+
+    /// Get the `#[asn1(...)]` attribute for the given component, adding a
+    /// function which supplies its `DEFAULT` value to `items` if needed.
+    fn field_attrs(
+        &self,
+        type_name: &str,
+        component: &Component,
+        items: &mut Vec<TokenStream>,
+    ) -> TokenStream {
+        let mut args = Vec::new();
+        let (_, resolved) = self.resolve(&component.ty);
+
+        if let Some((number, tag_mode)) = component.tag {
+            // `CHOICE` and `ANY` types are always tagged explicitly
+            // (X.680 Section 31.2.7)
+            let tag_mode = match resolved {
+                Type::Choice(_) | Type::Builtin(Builtin::Any) => TagMode::Explicit,
+                _ => tag_mode.unwrap_or(self.tag_mode),
+            };
+
+            let constructed = tag_mode == TagMode::Explicit
+                || matches!(
+                    resolved,
+                    Type::Sequence(_) | Type::SequenceOf(_) | Type::SetOf(_)
+                );
+
+            let number = number.0.to_string();
+            let tag_mode = tag_mode.to_string();
+            args.push(quote!(context_specific = #number));
+            args.push(quote!(tag_mode = #tag_mode));
+
+            if constructed {
+                args.push(quote!(constructed = "true"));
+            }
+        }
+
+        if component.optional {
+            args.push(quote!(optional = "true"));
+        }
+
+        if let Some(value) = &component.default {
+            let fn_name = format!(
+                "default_{}_{}",
+                snake_case(type_name),
+                snake_case(&component.name)
+            );
+            let fn_ident = Ident::new(&fn_name, component.span);
+            let ty = self.rust_type(&component.ty, &BTreeSet::new());
+            let value = self.default_value(component, value);
+
+            items.push(quote! {
+                fn #fn_ident() -> #ty {
+                    #value
+                }
+            });
+
+            args.push(quote!(default = #fn_name));
+        }
+
+        if args.is_empty() {
+            quote!()
+        } else {
+            quote!(#[asn1(#(#args),*)])
+        }
+    }
+
+    /// Get an expression for the `DEFAULT` value of the given component.
+    fn default_value(&self, component: &Component, value: &Value) -> TokenStream {
+        match (self.resolve(&component.ty), value) {
+            ((_, Type::Builtin(Builtin::Boolean)), Value::Boolean(value)) => quote!(#value),
+            ((_, Type::NamedInteger(_)), Value::Integer(value)) => {
+                let value = Literal::i64_unsuffixed(*value);
+                quote!(#value)
+            }
+            ((Some(type_name), Type::NamedInteger(values)), Value::Named(name, span))
+                if values.iter().any(|(n, _)| n == name) =>
+            {
+                let const_ident = const_ident(type_name, name, *span);
+                quote!(#const_ident)
+            }
+            ((Some(type_name), Type::Enumerated(values)), Value::Named(name, span))
+                if values.iter().any(|(n, _)| n == name) =>
+            {
+                let type_ident = type_ident(type_name, *span);
+                let variant_ident = variant_ident(name, *span);
+                quote!(#type_ident::#variant_ident)
+            }
+            _ => abort!(
+                component.span,
+                "unsupported `DEFAULT` value for `{}`",
+                component.name
+            ),
+        }
+    }
+}
+
+impl Builtin {
+    /// Does the Rust type for this ASN.1 type borrow from its input?
+    fn borrows(self) -> bool {
+        !matches!(
+            self,
+            Builtin::Boolean
+                | Builtin::GeneralizedTime
+                | Builtin::Null
+                | Builtin::ObjectIdentifier
+                | Builtin::UtcTime
+        )
+    }
+
+    /// Get the Rust type for this ASN.1 type.
+    fn to_tokens(self) -> TokenStream {
+        match self {
+            Builtin::Any => quote!(::der::asn1::Any<'a>),
+            Builtin::BitString => quote!(::der::asn1::BitString<'a>),
+            Builtin::Boolean => quote!(bool),
+            Builtin::GeneralizedTime => quote!(::der::asn1::GeneralizedTime),
+            Builtin::Ia5String => quote!(::der::asn1::Ia5String<'a>),
+            Builtin::Integer => quote!(::der::asn1::UIntBytes<'a>),
+            Builtin::Null => quote!(::der::asn1::Null),
+            Builtin::ObjectIdentifier => quote!(::der::asn1::ObjectIdentifier),
+            Builtin::OctetString => quote!(::der::asn1::OctetString<'a>),
+            Builtin::PrintableString => quote!(::der::asn1::PrintableString<'a>),
+            Builtin::UtcTime => quote!(::der::asn1::UtcTime),
+            Builtin::Utf8String => quote!(::der::asn1::Utf8String<'a>),
+        }
+    }
+}
+
+/// Does the Rust type for the given ASN.1 type borrow from its input,
+/// given the names of the defined types which do?
+fn borrows(ty: &Type, lifetimes: &BTreeSet<String>) -> bool {
+    match ty {
+        Type::Builtin(builtin) => builtin.borrows(),
+        Type::NamedInteger(_) | Type::Enumerated(_) => false,
+        Type::Reference(name, _) => lifetimes.contains(name),
+        Type::Sequence(components) | Type::Choice(components) => components
+            .iter()
+            .any(|component| borrows(&component.ty, lifetimes)),
+        Type::SequenceOf(inner) | Type::SetOf(inner) => borrows(inner, lifetimes),
+    }
+}
+
+/// Collect the names of the types used as `SET OF` elements, which need
+/// to impl `ValueOrd`.
+fn collect_set_elements(ty: &Type, names: &mut BTreeSet<String>) {
+    match ty {
+        Type::Sequence(components) | Type::Choice(components) => {
+            for component in components {
+                collect_set_elements(&component.ty, names);
+            }
+        }
+        Type::SetOf(inner) => {
+            if let Type::Reference(name, _) = inner.as_ref() {
+                names.insert(name.clone());
+            }
+
+            collect_set_elements(inner, names);
+        }
+        Type::SequenceOf(inner) => collect_set_elements(inner, names),
+        _ => (),
+    }
+}
+
+/// Parse an ASN.1 type.
+fn parse_type(cursor: &mut Cursor) -> Type {
+    let span = cursor.span();
+    let (keyword, _) = cursor
+        .name()
+        .unwrap_or_else(|| abort!(span, "expected ASN.1 type"));
+
+    let ty = match keyword.as_str() {
+        "ANY" => {
+            if cursor.eat_keyword("DEFINED") {
+                cursor.expect_keyword("BY");
+                cursor.expect_name("component name");
+            }
+
+            Type::Builtin(Builtin::Any)
+        }
+        "BIT" => {
+            cursor.expect_keyword("STRING");
+            cursor.eat_group(Delimiter::Brace);
+            Type::Builtin(Builtin::BitString)
+        }
+        "BOOLEAN" => Type::Builtin(Builtin::Boolean),
+        "CHOICE" => Type::Choice(parse_components(cursor.expect_group(Delimiter::Brace))),
+        "ENUMERATED" => Type::Enumerated(parse_named_numbers(
+            cursor.expect_group(Delimiter::Brace),
+            true,
+        )),
+        "GeneralizedTime" => Type::Builtin(Builtin::GeneralizedTime),
+        "IA5String" => Type::Builtin(Builtin::Ia5String),
+        "INTEGER" => match cursor.eat_group(Delimiter::Brace) {
+            Some(group) => Type::NamedInteger(parse_named_numbers(group, false)),
+            None => Type::Builtin(Builtin::Integer),
+        },
+        "NULL" => Type::Builtin(Builtin::Null),
+        "OBJECT" => {
+            cursor.expect_keyword("IDENTIFIER");
+            Type::Builtin(Builtin::ObjectIdentifier)
+        }
+        "OCTET" => {
+            cursor.expect_keyword("STRING");
+            Type::Builtin(Builtin::OctetString)
+        }
+        "PrintableString" => Type::Builtin(Builtin::PrintableString),
+        "SEQUENCE" | "SET" => {
+            if let Some(group) = cursor.eat_group(Delimiter::Brace) {
+                if keyword == "SET" {
+                    abort!(span, "`SET` types are not supported (only `SET OF`)");
+                }
+
+                Type::Sequence(parse_components(group))
+            } else {
+                // Size constraint, e.g. `SEQUENCE SIZE (1..MAX) OF`
+                cursor.eat_keyword("SIZE");
+                cursor.eat_group(Delimiter::Parenthesis);
+                cursor.expect_keyword("OF");
+
+                let inner = Box::new(parse_type(cursor));
+
+                if is_definition(&inner) {
+                    abort!(
+                        span,
+                        "element types of `{} OF` must be defined as separate type assignments",
+                        keyword
+                    );
+                }
+
+                if keyword == "SET" {
+                    Type::SetOf(inner)
+                } else {
+                    Type::SequenceOf(inner)
+                }
+            }
+        }
+        "UTCTime" => Type::Builtin(Builtin::UtcTime),
+        "UTF8String" => Type::Builtin(Builtin::Utf8String),
+        name if UNSUPPORTED_TYPES.contains(&name) => {
+            abort!(span, "ASN.1 type `{}` is not supported", name)
+        }
+        name if name.starts_with(|c: char| c.is_ascii_uppercase()) => {
+            Type::Reference(keyword.clone(), span)
+        }
+        _ => abort!(span, "expected ASN.1 type"),
+    };
+
+    // Constraints are ignored
+    while cursor.eat_group(Delimiter::Parenthesis).is_some() {}
+
+    ty
+}
+
+/// Parse the components of a `SEQUENCE` or alternatives of a `CHOICE`.
+fn parse_components(group: Group) -> Vec<Component> {
+    let mut components = Vec::new();
+
+    for mut cursor in Cursor::split_commas(group.stream()) {
+        // Extension markers are ignored
+        if cursor.eat_ellipsis() {
+            cursor.expect_empty();
+            continue;
+        }
+
+        let (name, span) = cursor.expect_name("component name");
+
+        if !name.starts_with(|c: char| c.is_ascii_lowercase()) {
+            abort!(span, "expected component name, found `{}`", name);
+        }
+
+        let tag = cursor.eat_group(Delimiter::Bracket).map(|group| {
+            let tag_span = group.span();
+            let mut tag_cursor = Cursor::new(group.stream());
+
+            if tag_cursor.name().is_some() {
+                abort!(tag_span, "only `CONTEXT-SPECIFIC` tags are supported");
+            }
+
+            let number = tag_cursor
+                .number()
+                .and_then(|n| u8::try_from(n).ok())
+                .filter(|&n| n <= TagNumber::MAX)
+                .unwrap_or_else(|| abort!(tag_span, "invalid tag number"));
+
+            tag_cursor.expect_empty();
+
+            let tag_mode = if cursor.eat_keyword("IMPLICIT") {
+                Some(TagMode::Implicit)
+            } else if cursor.eat_keyword("EXPLICIT") {
+                Some(TagMode::Explicit)
+            } else {
+                None
+            };
+
+            (TagNumber(number), tag_mode)
+        });
+
+        let ty = parse_type(&mut cursor);
+
+        if is_definition(&ty) {
+            abort!(
+                span,
+                "type of `{}` must be defined as a separate type assignment",
+                name
+            );
+        }
+
+        let optional = cursor.eat_keyword("OPTIONAL");
+        let default = if !optional && cursor.eat_keyword("DEFAULT") {
+            let value_span = cursor.span();
+
+            Some(if cursor.eat_keyword("TRUE") {
+                Value::Boolean(true)
+            } else if cursor.eat_keyword("FALSE") {
+                Value::Boolean(false)
+            } else if let Some(n) = cursor.number() {
+                Value::Integer(n)
+            } else if let Some((name, span)) = cursor.name() {
+                Value::Named(name, span)
+            } else {
+                abort!(value_span, "unsupported `DEFAULT` value");
+            })
+        } else {
+            None
+        };
+
+        cursor.expect_empty();
+
+        components.push(Component {
+            name,
+            span,
+            tag,
+            ty,
+            optional,
+            default,
+        });
+    }
+
+    components
+}
+
+/// Parse the named numbers of an `INTEGER`, or the items of an
+/// `ENUMERATED` (whose numbers are optional).
+fn parse_named_numbers(group: Group, enumerated: bool) -> Vec<(String, i64)> {
+    let mut values = Vec::<(String, i64)>::new();
+
+    for mut cursor in Cursor::split_commas(group.stream()) {
+        if cursor.eat_ellipsis() {
+            cursor.expect_empty();
+            continue;
+        }
+
+        let (name, span) = cursor.expect_name("named number");
+        let value = match cursor.eat_group(Delimiter::Parenthesis) {
+            Some(group) => {
+                let mut value_cursor = Cursor::new(group.stream());
+                let value = value_cursor
+                    .number()
+                    .unwrap_or_else(|| abort!(group.span(), "expected number"));
+                value_cursor.expect_empty();
+                value
+            }
+            None if enumerated => values.last().map_or(0, |(_, value)| value + 1),
+            None => abort!(span, "expected number for `{}`", name),
+        };
+
+        cursor.expect_empty();
+        values.push((name, value));
+    }
+
+    values
+}
+
+/// Is the given type a definition which can only be used in a type
+/// assignment, rather than inline?
+fn is_definition(ty: &Type) -> bool {
+    matches!(
+        ty,
+        Type::Sequence(_) | Type::Choice(_) | Type::Enumerated(_) | Type::NamedInteger(_)
+    )
+}
+
+/// Cursor over the tokens of ASN.1 notation.
+struct Cursor {
+    /// Tokens being parsed.
+    tokens: Vec<TokenTree>,
+
+    /// Position of the next token.
+    pos: usize,
+}
+
+impl Cursor {
+    /// Create a new cursor over the given tokens.
+    fn new(input: TokenStream) -> Self {
+        Self {
+            tokens: input.into_iter().collect(),
+            pos: 0,
+        }
+    }
+
+    /// Split the given tokens on commas.
+    fn split_commas(input: TokenStream) -> Vec<Self> {
+        let mut cursors = vec![Cursor::new(TokenStream::new())];
+
+        for token in input {
+            match &token {
+                TokenTree::Punct(punct) if punct.as_char() == ',' => {
+                    cursors.push(Cursor::new(TokenStream::new()))
+                }
+                _ => cursors
+                    .last_mut()
+                    .expect("at least one cursor")
+                    .tokens
+                    .push(token),
+            }
+        }
+
+        // Allow a trailing comma
+        if cursors.last().map_or(false, Cursor::is_empty) {
+            cursors.pop();
+        }
+
+        cursors
+    }
+
+    /// Have all tokens been consumed?
+    fn is_empty(&self) -> bool {
+        self.pos >= self.tokens.len()
+    }
+
+    /// Get the span of the next token.
+    fn span(&self) -> Span {
+        self.tokens
+            .get(self.pos)
+            .or_else(|| self.tokens.last())
+            .map_or_else(Span::call_site, TokenTree::span)
+    }
+
+    /// Consume the next token.
+    fn next(&mut self) -> Option<TokenTree> {
+        let token = self.tokens.get(self.pos).cloned();
+
+        if token.is_some() {
+            self.pos += 1;
+        }
+
+        token
+    }
+
+    /// Is the token `n` tokens ahead the given punctuation character?
+    fn peek_punct(&self, n: usize, ch: char) -> bool {
+        matches!(self.tokens.get(self.pos + n), Some(TokenTree::Punct(p)) if p.as_char() == ch)
+    }
+
+    /// Consume the given punctuation character if it's next.
+    fn eat_punct(&mut self, ch: char) -> bool {
+        let matched = self.peek_punct(0, ch);
+
+        if matched {
+            self.pos += 1;
+        }
+
+        matched
+    }
+
+    /// Is the next token the given keyword?
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.tokens.get(self.pos), Some(TokenTree::Ident(ident)) if ident == keyword)
+    }
+
+    /// Consume the given keyword if it's next.
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let matched = self.peek_keyword(keyword);
+
+        if matched {
+            self.pos += 1;
+        }
+
+        matched
+    }
+
+    /// Consume the given keyword, aborting if it isn't next.
+    fn expect_keyword(&mut self, keyword: &str) {
+        if !self.eat_keyword(keyword) {
+            abort!(self.span(), "expected `{}`", keyword);
+        }
+    }
+
+    /// Consume the `::=` assignment operator, aborting if it isn't next.
+    fn expect_assignment(&mut self) {
+        if self.peek_punct(0, ':') && self.peek_punct(1, ':') && self.peek_punct(2, '=') {
+            self.pos += 3;
+        } else {
+            abort!(self.span(), "expected `::=`");
+        }
+    }
+
+    /// Consume the `...` extension marker if it's next.
+    fn eat_ellipsis(&mut self) -> bool {
+        let matched = (0..3).all(|n| self.peek_punct(n, '.'));
+
+        if matched {
+            self.pos += 3;
+        }
+
+        matched
+    }
+
+    /// Consume a group with the given delimiter if it's next.
+    fn eat_group(&mut self, delimiter: Delimiter) -> Option<Group> {
+        match self.tokens.get(self.pos) {
+            Some(TokenTree::Group(group)) if group.delimiter() == delimiter => {
+                let group = group.clone();
+                self.pos += 1;
+                Some(group)
+            }
+            _ => None,
+        }
+    }
+
+    /// Consume a group with the given delimiter, aborting if it isn't next.
+    fn expect_group(&mut self, delimiter: Delimiter) -> Group {
+        self.eat_group(delimiter)
+            .unwrap_or_else(|| abort!(self.span(), "expected `{{`"))
+    }
+
+    /// Consume a name (i.e. type reference or identifier) if it's next,
+    /// joining hyphenated words.
+    fn name(&mut self) -> Option<(String, Span)> {
+        let first = match self.tokens.get(self.pos) {
+            Some(TokenTree::Ident(ident)) => ident.clone(),
+            _ => return None,
+        };
+
+        self.pos += 1;
+        let mut name = first.to_string();
+
+        while self.peek_punct(0, '-') {
+            match self.tokens.get(self.pos + 1) {
+                Some(TokenTree::Ident(ident)) => {
+                    name.push('-');
+                    name.push_str(&ident.to_string());
+                    self.pos += 2;
+                }
+                _ => break,
+            }
+        }
+
+        Some((name, first.span()))
+    }
+
+    /// Consume a name, aborting if it isn't next.
+    fn expect_name(&mut self, what: &str) -> (String, Span) {
+        self.name()
+            .unwrap_or_else(|| abort!(self.span(), "expected {}", what))
+    }
+
+    /// Consume a (possibly negative) integer if it's next.
+    fn number(&mut self) -> Option<i64> {
+        let negative = self.peek_punct(0, '-');
+        let offset = usize::from(negative);
+
+        let value = match self.tokens.get(self.pos + offset) {
+            Some(TokenTree::Literal(literal)) => literal.to_string().parse::<i64>().ok()?,
+            _ => return None,
+        };
+
+        self.pos += offset + 1;
+        Some(if negative { -value } else { value })
+    }
+
+    /// Abort if any tokens remain.
+    fn expect_empty(&self) {
+        if !self.is_empty() {
+            abort!(self.span(), "unexpected token");
+        }
+    }
+}
+
+/// Convert an ASN.1 type reference to a Rust type name.
+fn type_ident(name: &str, span: Span) -> Ident {
+    Ident::new(&name.replace('-', ""), span)
+}
+
+/// Convert an ASN.1 identifier to a Rust field name.
+fn field_ident(name: &str, span: Span) -> Ident {
+    let name = snake_case(name);
+
+    if syn::parse_str::<Ident>(&name).is_ok() {
+        Ident::new(&name, span)
+    } else {
+        Ident::new_raw(&name, span)
+    }
+}
+
+/// Convert an ASN.1 identifier to a Rust enum variant name.
+fn variant_ident(name: &str, span: Span) -> Ident {
+    let name = name
+        .split('-')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect::<String>();
+
+    Ident::new(&name, span)
+}
+
+/// Get the Rust name of the constant for a named number of a type.
+fn const_ident(type_name: &str, name: &str, span: Span) -> Ident {
+    let name = format!("{}_{}", snake_case(type_name), snake_case(name)).to_ascii_uppercase();
+    Ident::new(&name, span)
+}
+
+/// Convert an ASN.1 name in `camelCase` or `kebab-case` to `snake_case`.
+fn snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut output = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '-' {
+            output.push('_');
+            continue;
+        }
+
+        if c.is_ascii_uppercase() {
+            let boundary = match i.checked_sub(1).map(|j| chars[j]) {
+                Some(prev) if prev.is_ascii_lowercase() || prev.is_ascii_digit() => true,
+                Some(prev) if prev.is_ascii_uppercase() => chars
+                    .get(i + 1)
+                    .map_or(false, |next| next.is_ascii_lowercase()),
+                _ => false,
+            };
+
+            if boundary && !output.ends_with('_') {
+                output.push('_');
+            }
+
+            output.push(c.to_ascii_lowercase());
+        } else {
+            output.push(c);
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{snake_case, Asn1Module};
+    use quote::quote;
+
+    #[test]
+    fn snake_case_names() {
+        assert_eq!(snake_case("extnID"), "extn_id");
+        assert_eq!(snake_case("issuerUniqueID"), "issuer_unique_id");
+        assert_eq!(snake_case("rfc822Name"), "rfc822_name");
+        assert_eq!(snake_case("CMSVersion"), "cms_version");
+        assert_eq!(snake_case("id-ce-keyUsage"), "id_ce_key_usage");
+    }
+
+    #[test]
+    fn parse_module() {
+        let module = Asn1Module::new(quote! {
+            Example { iso(1) 2 } DEFINITIONS IMPLICIT TAGS ::= BEGIN
+            IMPORTS Name FROM PKIX1Explicit88 { iso(1) 3 };
+
+            id-example OBJECT IDENTIFIER ::= { iso(1) 2 }
+
+            Version ::= INTEGER { v1(0), v2(1) }
+
+            Example ::= SEQUENCE {
+                version [0] EXPLICIT Version DEFAULT v1,
+                names SEQUENCE SIZE (1..MAX) OF Name,
+                ...
+            }
+            END
+        });
+
+        assert_eq!(module.tag_mode, crate::TagMode::Implicit);
+        assert_eq!(module.assignments.len(), 2);
+        assert_eq!(module.assignments[1].name, "Example");
+    }
+}
@@ -5,7 +5,9 @@ use proc_macro2::TokenStream;
 use proc_macro_error::{abort, abort_call_site};
 use quote::quote;
 use std::{fmt::Debug, str::FromStr};
-use syn::{Attribute, Lit, LitStr, Meta, MetaList, MetaNameValue, NestedMeta, Path};
+use syn::{
+    spanned::Spanned, Attribute, Lit, LitStr, Meta, MetaList, MetaNameValue, NestedMeta, Path,
+};
 
 /// Attribute name.
 pub(crate) const ATTR_NAME: &str = "asn1";
@@ -161,7 +163,8 @@ impl FieldAttrs {
                 abort!(
                     attr.name,
                     "unknown field-level `asn1` attribute \
-                    (valid options are `context_specific`, `type`)",
+                    (valid options are `constructed`, `context_specific`, `default`, \
+                    `extensible`, `optional`, `tag_mode`, `type`)",
                 );
             }
         }
@@ -228,7 +231,20 @@ impl FieldAttrs {
 
             if self.is_optional() {
                 if let Some(default) = &self.default {
-                    quote!(#context_specific.map(|cs| cs.value).unwrap_or_else(#default))
+                    let constructed = self.constructed;
+                    quote! {
+                        {
+                            let value = #context_specific.map(|cs| cs.value);
+                            decoder.resolve_default(
+                                ::der::Tag::ContextSpecific {
+                                    number: #tag_number,
+                                    constructed: #constructed
+                                },
+                                value,
+                                #default
+                            )?
+                        }
+                    }
                 } else {
                     quote!(#context_specific.map(|cs| cs.value))
                 }
@@ -245,10 +261,9 @@ impl FieldAttrs {
                 }
             }
         } else if let Some(default) = &self.default {
-            let type_params = self.asn1_type.map(|ty| ty.type_path()).unwrap_or_default();
-            self.asn1_type.map(|ty| ty.decoder()).unwrap_or_else(
-                || quote!(decoder.decode::<Option<#type_params>>()?.unwrap_or_else(#default)),
-            )
+            self.asn1_type
+                .map(|ty| ty.decoder())
+                .unwrap_or_else(|| quote!(decoder.decode_default(#default)?))
         } else {
             self.asn1_type
                 .map(|ty| ty.decoder())
@@ -314,6 +329,11 @@ impl AttrNameValue {
                         name: path.clone(),
                         value: lit_str.clone(),
                     }),
+                    // Bare flags, e.g. `optional`, are shorthand for `optional = "true"`
+                    NestedMeta::Meta(Meta::Path(path)) => out.push(Self {
+                        name: path.clone(),
+                        value: LitStr::new("true", path.span()),
+                    }),
                     _ => abort!(nested, "malformed `asn1` attribute"),
                 }
             }
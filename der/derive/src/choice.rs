@@ -45,7 +45,7 @@ impl DeriveChoice {
         let variants = data
             .variants
             .iter()
-            .map(|variant| ChoiceVariant::new(variant, &type_attrs))
+            .map(|variant| ChoiceVariant::new(variant, &input.ident, &type_attrs))
             .collect();
 
         Self {
@@ -1,6 +1,6 @@
 //! Choice variant IR and lowerings
 
-use crate::{FieldAttrs, Tag, TypeAttrs};
+use crate::{indirection::check_recursion, FieldAttrs, Tag, TypeAttrs};
 use proc_macro2::TokenStream;
 use proc_macro_error::abort;
 use quote::quote;
@@ -73,7 +73,7 @@ pub(super) struct ChoiceVariant {
 
 impl ChoiceVariant {
     /// Create a new [`ChoiceVariant`] from the input [`Variant`].
-    pub(super) fn new(input: &Variant, type_attrs: &TypeAttrs) -> Self {
+    pub(super) fn new(input: &Variant, type_ident: &Ident, type_attrs: &TypeAttrs) -> Self {
         let ident = input.ident.clone();
         let attrs = FieldAttrs::parse(&input.attrs, type_attrs);
 
@@ -84,7 +84,9 @@ impl ChoiceVariant {
         // Validate that variant is a 1-element tuple struct
         match &input.fields {
             // TODO(tarcieri): handle 0 bindings for ASN.1 NULL
-            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => (),
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                check_recursion(type_ident, &fields.unnamed[0].ty)
+            }
             _ => abort!(&ident, "enum variant must be a 1-element tuple struct"),
         }
 
@@ -0,0 +1,163 @@
+//! Support for recursive types which use heap indirection.
+
+use proc_macro_error::abort;
+use syn::{GenericArgument, Ident, PathArguments, Type};
+
+/// Types which provide heap indirection, and therefore break the infinite
+/// size of a recursive type.
+const INDIRECTION_TYPES: &[&str] = &["Arc", "Box", "Rc", "SetOfVec", "Vec"];
+
+/// Heap indirection used by a particular field.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Indirection {
+    /// No indirection: the field is the inner type.
+    None,
+
+    /// Field is `Box<T>`.
+    Boxed,
+
+    /// Field is `Option<Box<T>>`, where the inner `Box<T>` type is provided.
+    OptionBoxed(Box<Type>),
+}
+
+impl Indirection {
+    /// Determine the indirection used by the given field type.
+    pub fn new(ty: &Type) -> Self {
+        if generic_arg(ty, "Box").is_some() {
+            return Indirection::Boxed;
+        }
+
+        match generic_arg(ty, "Option") {
+            Some(inner) if generic_arg(inner, "Box").is_some() => {
+                Indirection::OptionBoxed(Box::new(inner.clone()))
+            }
+            _ => Indirection::None,
+        }
+    }
+
+    /// Is this field boxed?
+    pub fn is_boxed(&self) -> bool {
+        *self != Indirection::None
+    }
+}
+
+/// Ensure the provided field type doesn't refer to the type being derived
+/// (i.e. `ident`) without heap indirection.
+///
+/// Such types are infinitely sized, and while `rustc` will reject them, it
+/// can't provide guidance specific to ASN.1 decoding.
+///
+/// Only direct references are detected: a proc macro can't see the
+/// definitions of other types, so cycles through them are left to `rustc`.
+pub(crate) fn check_recursion(ident: &Ident, ty: &Type) {
+    if contains_without_indirection(ident, ty) {
+        abort!(
+            ty,
+            "recursive type `{}` requires indirection: use `Box<{}>` \
+             (or `Option<Box<{}>>` for `OPTIONAL` fields) and bound the \
+             maximum nesting depth with `Decoder::set_max_depth`",
+            ident,
+            ident,
+            ident
+        );
+    }
+}
+
+/// Does the given type contain `ident` without intermediate heap indirection?
+fn contains_without_indirection(ident: &Ident, ty: &Type) -> bool {
+    match ty {
+        Type::Array(array) => contains_without_indirection(ident, &array.elem),
+        Type::Group(group) => contains_without_indirection(ident, &group.elem),
+        Type::Paren(paren) => contains_without_indirection(ident, &paren.elem),
+        Type::Tuple(tuple) => tuple
+            .elems
+            .iter()
+            .any(|elem| contains_without_indirection(ident, elem)),
+        Type::Path(path) => path.path.segments.iter().any(|segment| {
+            if segment.ident == *ident || segment.ident == "Self" {
+                return true;
+            }
+
+            if INDIRECTION_TYPES.iter().any(|name| segment.ident == name) {
+                return false;
+            }
+
+            match &segment.arguments {
+                PathArguments::AngleBracketed(args) => args.args.iter().any(|arg| match arg {
+                    GenericArgument::Type(ty) => contains_without_indirection(ident, ty),
+                    _ => false,
+                }),
+                _ => false,
+            }
+        }),
+        _ => false,
+    }
+}
+
+/// If `ty` is the generic type `name<T>`, return `T`.
+fn generic_arg<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    let segment = match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last()?,
+        _ => return None,
+    };
+
+    if segment.ident != name {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match args.args.first()? {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{contains_without_indirection, Indirection};
+    use proc_macro2::Span;
+    use syn::{parse_quote, Ident, Type};
+
+    #[test]
+    fn indirection() {
+        let boxed: Type = parse_quote!(Box<Node>);
+        assert_eq!(Indirection::new(&boxed), Indirection::Boxed);
+
+        let optional: Type = parse_quote!(Option<Box<Node>>);
+        assert_eq!(
+            Indirection::new(&optional),
+            Indirection::OptionBoxed(Box::new(boxed))
+        );
+
+        let bare: Type = parse_quote!(Option<Node>);
+        assert_eq!(Indirection::new(&bare), Indirection::None);
+    }
+
+    #[test]
+    fn recursion() {
+        let ident = Ident::new("Node", Span::call_site());
+        let recursive: &[Type] = &[
+            parse_quote!(Node),
+            parse_quote!(Option<Node>),
+            parse_quote!([Node; 2]),
+            parse_quote!(SequenceOf<Node, 4>),
+        ];
+
+        for ty in recursive {
+            assert!(contains_without_indirection(&ident, ty));
+        }
+
+        let indirect: &[Type] = &[
+            parse_quote!(Box<Node>),
+            parse_quote!(Option<Box<Node>>),
+            parse_quote!(Vec<Node>),
+            parse_quote!(Option<Any<'a>>),
+        ];
+
+        for ty in indirect {
+            assert!(!contains_without_indirection(&ident, ty));
+        }
+    }
+}
@@ -11,6 +11,9 @@
 //! - [`Sequence`][`derive@Sequence`]: map ASN.1 `SEQUENCE` to a Rust struct.
 //! - [`ValueOrd`][`derive@ValueOrd`]: determine DER ordering for ASN.1 `SET OF`.
 //!
+//! It also contains the [`asn1!`] function-like macro, which generates types
+//! using the derives above from ASN.1 module notation.
+//!
 //! Note that this crate shouldn't be used directly, but instead accessed
 //! by using the `derive` feature of the `der` crate, which re-exports the
 //! above macros from the toplevel.
@@ -62,6 +65,11 @@
 //! This behaves like `serde_derive`'s `default` attribute, allowing you to
 //! specify the path to a function which returns a default value.
 //!
+//! As DER requires, a field equal to its default value is omitted when
+//! encoding. Decoding a field which explicitly encodes its default value is
+//! an error only if the decoder has been configured to reject such values
+//! (see `Decoder::set_reject_encoded_defaults`).
+//!
 //! ### `#[asn1(extensible = "true")]` attribute: support for `...` extensibility operator
 //!
 //! This attribute can be applied to the fields of `struct` types, and will
@@ -72,6 +80,15 @@
 //!
 //! This attribute explicitly annotates a field as `OPTIONAL`.
 //!
+//! Boolean attributes like this one can also be written as a bare flag,
+//! i.e. `#[asn1(optional)]` is equivalent to `#[asn1(optional = "true")]`.
+//!
+//! ### `#[asn1(tag_mode = "...")]` attribute: per-field `EXPLICIT` vs `IMPLICIT`
+//!
+//! This attribute overrides the toplevel tagging mode for a particular
+//! `CONTEXT-SPECIFIC` field, and is honored when both decoding and encoding,
+//! e.g. `#[asn1(context_specific = "2", tag_mode = "IMPLICIT", optional)]`.
+//!
 //! ### `#[asn1(type = "...")]` attribute: ASN.1 type declaration
 //!
 //! This attribute can be used to specify the ASN.1 type for a particular
@@ -101,6 +118,20 @@
 //! Note: please open a GitHub Issue if you would like to request support
 //! for additional ASN.1 types.
 //!
+//! ## Recursive types
+//!
+//! [`Sequence`] fields of type `Box<T>` and `Option<Box<T>>` are supported,
+//! which makes it possible to derive recursive types, e.g. a struct with a
+//! `next: Option<Box<Self>>` field.
+//!
+//! Fields which refer directly to the type being derived (by name or as
+//! `Self`) without such indirection are rejected with an error. Cycles through
+//! other types (e.g. `A` containing `B` containing `A`) can't be detected by
+//! the derive macros, and are instead rejected by `rustc` as infinitely sized.
+//!
+//! Decoding of recursive types, including through `EXPLICIT` tags, is bounded
+//! by the maximum nesting depth of the decoder (see `Decoder::set_max_depth`).
+//!
 //! [`der`]: https://docs.rs/der/
 //! [`Choice`]: derive@Choice
 //! [`Sequence`]: derive@Sequence
@@ -116,16 +147,19 @@
 #![forbid(unsafe_code, clippy::unwrap_used)]
 #![warn(rust_2018_idioms, trivial_casts, unused_qualifications)]
 
+mod asn1_module;
 mod asn1_type;
 mod attributes;
 mod choice;
 mod enumerated;
+mod indirection;
 mod newtype;
 mod sequence;
 mod tag;
 mod value_ord;
 
 use crate::{
+    asn1_module::Asn1Module,
     asn1_type::Asn1Type,
     attributes::{FieldAttrs, TypeAttrs, ATTR_NAME},
     choice::DeriveChoice,
@@ -283,3 +317,71 @@ pub fn derive_newtype(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     DeriveNewtype::new(input).to_tokens().into()
 }
+
+/// Generate Rust types from ASN.1 module notation.
+///
+/// Each type assignment in the input is mapped to a Rust type which uses
+/// the derives from this crate:
+///
+/// - `SEQUENCE { ... }`: `struct` deriving [`Sequence`][`derive@Sequence`]
+/// - `CHOICE { ... }`: `enum` deriving [`Choice`][`derive@Choice`]
+/// - `ENUMERATED { ... }`: `enum` deriving [`Enumerated`][`derive@Enumerated`]
+/// - `INTEGER { ... }`: `i64` type alias, plus a constant for each named number
+/// - any other type: type alias
+///
+/// Types used as the element of a `SET OF` also derive
+/// [`ValueOrd`][`derive@ValueOrd`].
+///
+/// The following built-in types are supported, and mapped to types from the
+/// `der` crate (borrowing types give the generated type an `'a` lifetime):
+///
+/// - `ANY` / `ANY DEFINED BY`: `Any<'a>`
+/// - `BIT STRING`: `BitString<'a>`
+/// - `BOOLEAN`: `bool`
+/// - `GeneralizedTime`: `GeneralizedTime`
+/// - `IA5String`: `Ia5String<'a>`
+/// - `INTEGER`: `UIntBytes<'a>`
+/// - `NULL`: `Null`
+/// - `OBJECT IDENTIFIER`: `ObjectIdentifier`
+/// - `OCTET STRING`: `OctetString<'a>`
+/// - `PrintableString`: `PrintableString<'a>`
+/// - `SEQUENCE OF`: `Vec<T>`
+/// - `SET OF`: `SetOfVec<T>`
+/// - `UTCTime`: `UtcTime`
+/// - `UTF8String`: `Utf8String<'a>`
+///
+/// References to other types are mapped to the Rust type of the same name
+/// with hyphens removed, which may be defined outside of the macro.
+///
+/// Components may be `CONTEXT-SPECIFIC` tagged, `OPTIONAL`, or have a
+/// `DEFAULT` value of `TRUE`/`FALSE`, a number or named number of an
+/// `INTEGER`, or an item of an `ENUMERATED`. Constraints, extension markers,
+/// `IMPORTS`/`EXPORTS` and value assignments are ignored.
+///
+/// Types defined inline within components (e.g. a nested `SEQUENCE { ... }`),
+/// `SET { ... }`, `AUTOMATIC` tagging, and `APPLICATION`/`PRIVATE` tags are
+/// not supported.
+///
+/// # Usage
+///
+/// ```ignore
+/// // NOTE: requires the `derive` and `alloc` features of `der`
+/// der::asn1! {
+///     Extension ::= SEQUENCE {
+///         extnID      OBJECT IDENTIFIER,
+///         critical    BOOLEAN DEFAULT FALSE,
+///         extnValue   OCTET STRING
+///     }
+///
+///     Extensions ::= SEQUENCE SIZE (1..MAX) OF Extension
+/// }
+/// ```
+///
+/// Input may optionally be wrapped in a module definition, i.e.
+/// `Name DEFINITIONS IMPLICIT TAGS ::= BEGIN ... END`, whose tagging mode
+/// is used for `CONTEXT-SPECIFIC` components.
+#[proc_macro]
+#[proc_macro_error]
+pub fn asn1(input: TokenStream) -> TokenStream {
+    Asn1Module::new(input.into()).to_tokens().into()
+}
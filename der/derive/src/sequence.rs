@@ -45,7 +45,7 @@ impl DeriveSequence {
         let fields = data
             .fields
             .iter()
-            .map(|field| SequenceField::new(field, &type_attrs))
+            .map(|field| SequenceField::new(field, &input.ident, &type_attrs))
             .collect();
 
         Self {
@@ -1,6 +1,9 @@
 //! Sequence field IR and lowerings
 
-use crate::{Asn1Type, FieldAttrs, TagMode, TagNumber, TypeAttrs};
+use crate::{
+    indirection::{check_recursion, Indirection},
+    Asn1Type, FieldAttrs, TagMode, TagNumber, TypeAttrs,
+};
 use proc_macro2::TokenStream;
 use proc_macro_error::abort;
 use quote::quote;
@@ -16,11 +19,14 @@ pub(super) struct SequenceField {
 
     /// Field type
     pub(super) field_type: Type,
+
+    /// Heap indirection (i.e. `Box`) used by the field.
+    pub(super) indirection: Indirection,
 }
 
 impl SequenceField {
     /// Create a new [`SequenceField`] from the input [`Field`].
-    pub(super) fn new(field: &Field, type_attrs: &TypeAttrs) -> Self {
+    pub(super) fn new(field: &Field, type_ident: &Ident, type_attrs: &TypeAttrs) -> Self {
         let ident = field.ident.as_ref().cloned().unwrap_or_else(|| {
             abort!(
                 field,
@@ -44,10 +50,30 @@ impl SequenceField {
             );
         }
 
+        check_recursion(type_ident, &field.ty);
+        let indirection = Indirection::new(&field.ty);
+
+        if indirection.is_boxed() && (attrs.asn1_type.is_some() || attrs.default.is_some()) {
+            abort!(
+                ident,
+                "ASN.1 `type` and `default` options are unsupported on `Box` fields"
+            );
+        }
+
+        if let Indirection::OptionBoxed(_) = indirection {
+            if attrs.context_specific.is_some() && !attrs.optional {
+                abort!(
+                    ident,
+                    "context-specific `Option<Box<T>>` fields must be `optional`"
+                );
+            }
+        }
+
         Self {
             ident,
             attrs,
             field_type: field.ty.clone(),
+            indirection,
         }
     }
 
@@ -72,12 +98,20 @@ impl SequenceField {
             }
         }
 
+        match &self.indirection {
+            Indirection::None => (),
+            Indirection::Boxed => lowerer.apply_box(&self.field_type),
+            Indirection::OptionBoxed(box_type) => {
+                lowerer.apply_option_box(box_type, self.attrs.context_specific.is_some())
+            }
+        }
+
         lowerer.into_tokens(&self.ident)
     }
 
     /// Derive code for encoding a field of a sequence.
     pub(super) fn to_encode_tokens(&self) -> TokenStream {
-        let mut lowerer = LowerFieldEncoder::new(&self.ident);
+        let mut lowerer = LowerFieldEncoder::new(&self.ident, &self.indirection);
         let attrs = &self.attrs;
 
         if let Some(ty) = &attrs.asn1_type {
@@ -98,7 +132,12 @@ impl SequenceField {
                 !attrs.optional,
                 "`default`, and `optional` are mutually exclusive"
             );
-            lowerer.apply_default(&self.ident, default, attrs.context_specific.is_none());
+            lowerer.apply_default(
+                &self.ident,
+                &self.field_type,
+                default,
+                attrs.context_specific.is_none(),
+            );
         }
 
         lowerer.into_tokens()
@@ -146,29 +185,68 @@ impl LowerFieldDecoder {
     /// Handle default value for a type.
     fn apply_default(&mut self, default: &Path, field_type: &Type) {
         self.decoder = quote! {
-            decoder.decode::<Option<#field_type>>()?.unwrap_or_else(#default);
+            decoder.decode_default::<#field_type, _>(#default)?
         }
     }
+
+    /// Box the decoded value of a `Box<T>` field.
+    fn apply_box(&mut self, box_type: &Type) {
+        let decoder = &self.decoder;
+        self.decoder = quote! {
+            <#box_type>::new(#decoder)
+        };
+    }
+
+    /// Box the decoded value of an `Option<Box<T>>` field.
+    fn apply_option_box(&mut self, box_type: &Type, context_specific: bool) {
+        let decoder = &self.decoder;
+
+        self.decoder = if context_specific {
+            quote! {
+                #decoder.map(<#box_type>::new)
+            }
+        } else {
+            quote! {
+                decoder.decode::<Option<_>>()?.map(<#box_type>::new)
+            }
+        };
+    }
 }
 
 /// AST lowerer for field encoders.
 struct LowerFieldEncoder {
     /// Encoder-in-progress.
     encoder: TokenStream,
+
+    /// Is the encoder-in-progress an `Option<&T>` obtained from an
+    /// `Option<Box<T>>` field?
+    option_deref: bool,
 }
 
 impl LowerFieldEncoder {
     /// Create a new field encoder lowerer.
-    fn new(ident: &Ident) -> Self {
+    fn new(ident: &Ident, indirection: &Indirection) -> Self {
+        let encoder = match indirection {
+            Indirection::None => quote!(self.#ident),
+            Indirection::Boxed => quote!((*self.#ident)),
+            Indirection::OptionBoxed(_) => quote!(self.#ident.as_deref()),
+        };
+
         Self {
-            encoder: quote!(self.#ident),
+            encoder,
+            option_deref: matches!(indirection, Indirection::OptionBoxed(_)),
         }
     }
 
     ///  the field encoder to tokens.
     fn into_tokens(self) -> TokenStream {
         let encoder = self.encoder;
-        quote! { &#encoder }
+
+        if self.option_deref {
+            quote! { &::der::asn1::OptionalRef(#encoder) }
+        } else {
+            quote! { &#encoder }
+        }
     }
 
     /// Apply the ASN.1 type (if defined).
@@ -191,11 +269,13 @@ impl LowerFieldEncoder {
     }
 
     /// Handle default value for a type.
-    fn apply_default(&mut self, ident: &Ident, default: &Path, is_bare: bool) {
+    fn apply_default(&mut self, ident: &Ident, field_type: &Type, default: &Path, is_bare: bool) {
         let mut encoder = &self.encoder;
 
+        // Fully qualified so that other `PartialEq` impls in scope (e.g.
+        // `serde_json`'s) can't make the comparison ambiguous
         self.encoder = quote! {
-            if &self.#ident == &#default() {
+            if <#field_type as ::core::cmp::PartialEq>::eq(&self.#ident, &#default()) {
                 None
             } else {
                 Some(#encoder)
@@ -222,8 +302,15 @@ impl LowerFieldEncoder {
         let mode_tokens = tag_mode.to_tokens();
 
         if optional {
+            let encoder = if self.option_deref {
+                encoder.clone()
+            } else {
+                quote!(#encoder.as_ref())
+            };
+
+            self.option_deref = false;
             self.encoder = quote! {
-                #encoder.as_ref().map(|field| {
+                #encoder.map(|field| {
                     ::der::asn1::ContextSpecificRef {
                         tag_number: #number_tokens,
                         tag_mode: #mode_tokens,
@@ -246,7 +333,7 @@ impl LowerFieldEncoder {
 #[cfg(test)]
 mod tests {
     use super::SequenceField;
-    use crate::{FieldAttrs, TagMode, TagNumber};
+    use crate::{indirection::Indirection, FieldAttrs, TagMode, TagNumber};
     use proc_macro2::Span;
     use quote::quote;
     use syn::{punctuated::Punctuated, Ident, Path, PathSegment, Type, TypePath};
@@ -289,6 +376,7 @@ mod tests {
             ident,
             attrs,
             field_type: type_path(field_type),
+            indirection: Indirection::None,
         };
 
         assert_eq!(
@@ -329,6 +417,7 @@ mod tests {
             ident,
             attrs,
             field_type: type_path(field_type),
+            indirection: Indirection::None,
         };
 
         assert_eq!(
@@ -75,15 +75,15 @@ impl DeriveValueOrd {
 
         let mut body = Vec::new();
 
-        for field in &self.fields {
-            body.push(field.to_tokens());
+        for (i, field) in self.fields.iter().enumerate() {
+            body.push(field.to_tokens(&self.fields[(i + 1)..]));
         }
 
         quote! {
             impl<#(#lt_params)*> ::der::ValueOrd for #ident<#(#lt_params)*> {
                 fn value_cmp(&self, other: &Self) -> ::der::Result<::core::cmp::Ordering> {
                     #[allow(unused_imports)]
-                    use ::der::DerOrd;
+                    use ::der::{DerOrd, Tagged};
 
                     #(#body)*
 
@@ -123,22 +123,132 @@ impl ValueField {
         Self { ident, attrs }
     }
 
-    /// Lower to [`TokenStream`].
-    fn to_tokens(&self) -> TokenStream {
-        let ident = &self.ident;
-        let mut binding1 = quote!(self.#ident);
-        let mut binding2 = quote!(other.#ident);
-
-        if let Some(ty) = &self.attrs.asn1_type {
-            binding1 = ty.encoder(&binding1);
-            binding2 = ty.encoder(&binding2);
-        }
+    /// Lower to [`TokenStream`], given the fields which follow this one.
+    ///
+    /// Fields are compared using the same encoding as the `Sequence` derive,
+    /// i.e. honoring the `type`, `context_specific`, `tag_mode`, `optional`,
+    /// and `default` attributes.
+    fn to_tokens(&self, following: &[ValueField]) -> TokenStream {
+        let binding1 = self.lower(&quote!(self));
+        let binding2 = self.lower(&quote!(other));
+
+        let ordering = if self.is_optional() {
+            // Absent fields are omitted from the encoding, so when only one
+            // of the fields is present its tag is compared with the tag of the
+            // next field which is present in the other value.
+            let following1 = following.iter().map(|field| field.tag(&quote!(self)));
+            let following2 = following.iter().map(|field| field.tag(&quote!(other)));
+            let following_len = following.len();
+
+            quote! {
+                match (#binding1, #binding2) {
+                    (Some(field1), Some(field2)) => field1.der_cmp(&field2)?,
+                    (None, None) => ::core::cmp::Ordering::Equal,
+                    (Some(field1), None) => {
+                        let tags2: [Option<::der::Tag>; #following_len] = [#(#following2),*];
+                        let tag2 = ::core::iter::IntoIterator::into_iter(tags2).flatten().next();
+
+                        Some(u8::from(field1.tag())).cmp(&tag2.map(u8::from))
+                    }
+                    (None, Some(field2)) => {
+                        let tags1: [Option<::der::Tag>; #following_len] = [#(#following1),*];
+                        let tag1 = ::core::iter::IntoIterator::into_iter(tags1).flatten().next();
+
+                        tag1.map(u8::from).cmp(&Some(u8::from(field2.tag())))
+                    }
+                }
+            }
+        } else {
+            quote!(#binding1.der_cmp(&#binding2)?)
+        };
 
         quote! {
-            match #binding1.der_cmp(&#binding2)? {
+            match #ordering {
                 ::core::cmp::Ordering::Equal => (),
                 other => return Ok(other),
             }
         }
     }
+
+    /// Is this field omitted from the encoding when absent or set to its
+    /// default value?
+    fn is_optional(&self) -> bool {
+        self.attrs.optional || self.attrs.default.is_some()
+    }
+
+    /// Lower the field of the given `root` binding to an `Option<Tag>` which
+    /// is `None` if the field is absent from the encoding.
+    fn tag(&self, root: &TokenStream) -> TokenStream {
+        let binding = self.lower(root);
+
+        if self.is_optional() {
+            quote!(#binding.map(|field| field.tag()))
+        } else {
+            quote!(Some(#binding.tag()))
+        }
+    }
+
+    /// Lower the field of the given `root` binding (i.e. `self` or `other`)
+    /// to the value which is encoded for it.
+    ///
+    /// Evaluates to an `Option` if the field is `OPTIONAL` or has a `DEFAULT`.
+    fn lower(&self, root: &TokenStream) -> TokenStream {
+        let ident = &self.ident;
+        let attrs = &self.attrs;
+        let mut binding = quote!(#root.#ident);
+
+        // Is the binding an `Option` of an owned (as opposed to borrowed) value?
+        let mut owned_option = false;
+
+        if let Some(ty) = &attrs.asn1_type {
+            if attrs.optional {
+                let encoder = ty.encoder(&quote!(field));
+                binding = quote! {
+                    #binding.as_ref().map(|field| ::der::Result::Ok(#encoder)).transpose()?
+                };
+                owned_option = true;
+            } else {
+                binding = ty.encoder(&binding);
+            }
+        } else if let Some(default) = &attrs.default {
+            binding = quote! {
+                if &#binding == &#default() {
+                    None
+                } else {
+                    Some(&#binding)
+                }
+            };
+        } else if attrs.optional {
+            binding = quote!(#binding.as_ref());
+        }
+
+        if let Some(tag_number) = &attrs.context_specific {
+            let tag_number = tag_number.to_tokens();
+            let tag_mode = attrs.tag_mode.to_tokens();
+
+            if self.is_optional() {
+                if owned_option {
+                    binding = quote!(#binding.as_ref());
+                }
+
+                binding = quote! {
+                    #binding.map(|field| ::der::asn1::ContextSpecificRef {
+                        tag_number: #tag_number,
+                        tag_mode: #tag_mode,
+                        value: field,
+                    })
+                };
+            } else {
+                binding = quote! {
+                    ::der::asn1::ContextSpecificRef {
+                        tag_number: #tag_number,
+                        tag_mode: #tag_mode,
+                        value: &#binding,
+                    }
+                };
+            }
+        }
+
+        binding
+    }
 }
@@ -28,9 +28,12 @@ impl<T, const N: usize> ArrayVec<T, N> {
     /// Items MUST be added in lexicographical order according to the `Ord`
     /// impl on `T`.
     pub fn add(&mut self, element: T) -> Result<()> {
-        match self.length.checked_add(1) {
-            Some(n) if n <= N => {
-                self.elements[self.length] = Some(element);
+        match (
+            self.length.checked_add(1),
+            self.elements.get_mut(self.length),
+        ) {
+            (Some(n), Some(slot)) => {
+                *slot = Some(element);
                 self.length = n;
                 Ok(())
             }
@@ -48,7 +51,7 @@ impl<T, const N: usize> ArrayVec<T, N> {
 
     /// Iterate over the elements in this [`ArrayVec`].
     pub fn iter(&self) -> Iter<'_, T> {
-        Iter::new(&self.elements)
+        Iter::new(self.elements.get(..self.length).unwrap_or(&[]))
     }
 
     /// Is this [`ArrayVec`] empty?
@@ -128,6 +131,18 @@ impl<'a, T> Iterator for Iter<'a, T> {
     }
 }
 
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        match self.elements.split_last() {
+            Some((Some(res), rest)) if rest.len() >= self.position => {
+                self.elements = rest;
+                Some(res)
+            }
+            _ => None,
+        }
+    }
+}
+
 impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
 
 #[cfg(test)]
@@ -15,14 +15,16 @@ mod octet_string;
 mod oid;
 mod optional;
 mod printable_string;
+mod segmented_string;
 mod sequence;
 mod sequence_of;
 mod set_of;
+mod time;
 mod utc_time;
 mod utf8_string;
 
 pub use self::{
-    any::Any,
+    any::{Any, AnyIter},
     bit_string::{BitString, BitStringIter},
     choice::Choice,
     context_specific::{ContextSpecific, ContextSpecificRef},
@@ -33,16 +35,20 @@ pub use self::{
     octet_string::OctetString,
     optional::OptionalRef,
     printable_string::PrintableString,
+    segmented_string::{SegmentedString, Segments},
     sequence::{Sequence, SequenceRef},
     sequence_of::{SequenceOf, SequenceOfIter},
     set_of::{SetOf, SetOfIter},
+    time::Time,
     utc_time::UtcTime,
     utf8_string::Utf8String,
 };
 
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-pub use self::set_of::SetOfVec;
+pub use self::{
+    any::OwnedAny, bit_string::OwnedBitString, octet_string::OwnedOctetString, set_of::SetOfVec,
+};
 
 #[cfg(feature = "oid")]
 #[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
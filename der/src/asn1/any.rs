@@ -4,7 +4,10 @@ use crate::{
     asn1::*, ByteSlice, Choice, Decode, DecodeValue, Decoder, DerOrd, EncodeValue, Encoder, Error,
     ErrorKind, FixedTag, Header, Length, Result, Tag, Tagged, ValueOrd,
 };
-use core::cmp::Ordering;
+use core::{cmp::Ordering, iter::FusedIterator};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 #[cfg(feature = "oid")]
 use crate::asn1::ObjectIdentifier;
@@ -59,6 +62,7 @@ impl<'a> Any<'a> {
         let header = Header {
             tag: self.tag,
             length: self.value.len(),
+            constructed: self.tag.is_constructed(),
         };
 
         let mut decoder = Decoder::new(self.value())?;
@@ -66,6 +70,67 @@ impl<'a> Any<'a> {
         decoder.finish(result)
     }
 
+    /// Attempt to decode this [`Any`] type into the inner value from within
+    /// another decoding operation, e.g. when decoding an `ANY DEFINED BY`
+    /// field.
+    ///
+    /// Unlike [`Any::decode_into`], the value is decoded using a decoder
+    /// nested within `decoder`, so it counts towards the maximum nesting
+    /// depth and inherits the decoder's settings (e.g. [`EncodingRules`]).
+    /// Error positions are relative to the current position of `decoder`.
+    ///
+    /// [`EncodingRules`]: crate::EncodingRules
+    pub fn decode_nested_into<T>(self, decoder: &mut Decoder<'a>) -> Result<T>
+    where
+        T: DecodeValue<'a> + FixedTag,
+    {
+        self.tag.assert_eq(T::TAG)?;
+        let header = Header {
+            tag: self.tag,
+            length: self.value.len(),
+            constructed: self.tag.is_constructed(),
+        };
+
+        let offset = decoder.position();
+        let mut nested_decoder = decoder.nested(self.value, offset)?;
+        let result = T::decode_value(&mut nested_decoder, header)?;
+        nested_decoder.finish(result)
+    }
+
+    /// Get a copy of this [`Any`] with the given [`Tag`], e.g. to re-emit a
+    /// captured value under an `IMPLICIT` tag.
+    ///
+    /// The value is left as-is, so the new tag should have the same
+    /// constructed/primitive form as the original.
+    pub fn with_tag(self, tag: Tag) -> Self {
+        Self { tag, ..self }
+    }
+
+    /// Iterate over the children of a captured constructed value, e.g. the
+    /// elements of a `SEQUENCE` or `SET`, or of an `IMPLICIT`ly tagged one.
+    ///
+    /// Returns an error if this value doesn't use the constructed form.
+    pub fn sequence_iter(self) -> Result<AnyIter<'a>> {
+        if !self.tag.is_constructed() {
+            return Err(self.tag.unexpected_error(None));
+        }
+
+        Ok(AnyIter {
+            decoder: Decoder::new(self.value())?,
+        })
+    }
+
+    /// Copy this [`Any`] into an [`OwnedAny`] which doesn't borrow from the
+    /// original input.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_owned(self) -> OwnedAny {
+        OwnedAny {
+            tag: self.tag,
+            value: self.value().to_vec(),
+        }
+    }
+
     /// Is this value an ASN.1 `NULL` value?
     pub fn is_null(self) -> bool {
         self == Self::NULL
@@ -197,3 +262,286 @@ impl<'a> TryFrom<&'a [u8]> for Any<'a> {
         Any::from_der(bytes)
     }
 }
+
+/// Iterator over the children of a constructed [`Any`] value.
+///
+/// Created by [`Any::sequence_iter`]. If an error occurs, it is yielded once
+/// and iteration stops.
+#[derive(Clone, Debug)]
+pub struct AnyIter<'a> {
+    /// Decoder for the contents of the constructed value.
+    decoder: Decoder<'a>,
+}
+
+impl<'a> Iterator for AnyIter<'a> {
+    type Item = Result<Any<'a>>;
+
+    fn next(&mut self) -> Option<Result<Any<'a>>> {
+        if self.decoder.is_failed() || self.decoder.is_finished() {
+            None
+        } else {
+            Some(self.decoder.decode())
+        }
+    }
+}
+
+impl<'a> FusedIterator for AnyIter<'a> {}
+
+/// Owned counterpart of [`Any`] which stores a copy of the value, e.g. for
+/// keeping captured values around after the input has been dropped.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct OwnedAny {
+    /// Tag representing the type of the encoded value.
+    tag: Tag,
+
+    /// Inner value encoded as bytes.
+    value: Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl OwnedAny {
+    /// Create a new [`OwnedAny`] from the provided [`Tag`] and value bytes.
+    pub fn new(tag: Tag, value: Vec<u8>) -> Result<Self> {
+        Length::try_from(value.len()).map_err(|_| ErrorKind::Length { tag })?;
+        Ok(Self { tag, value })
+    }
+
+    /// Get the raw value as a byte slice.
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// Borrow this value as an [`Any`].
+    pub fn as_any(&self) -> Any<'_> {
+        // Length was checked when this value was created
+        Any::new(self.tag, &self.value).expect("invalid length")
+    }
+
+    /// Attempt to decode this value into the inner value.
+    pub fn decode_into<'a, T>(&'a self) -> Result<T>
+    where
+        T: DecodeValue<'a> + FixedTag,
+    {
+        self.as_any().decode_into()
+    }
+
+    /// Get a copy of this [`OwnedAny`] with the given [`Tag`].
+    ///
+    /// See [`Any::with_tag`].
+    pub fn with_tag(self, tag: Tag) -> Self {
+        Self { tag, ..self }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> Decode<'a> for OwnedAny {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        Any::decode(decoder).map(Any::to_owned)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl EncodeValue for OwnedAny {
+    fn value_len(&self) -> Result<Length> {
+        Length::try_from(self.value.len())
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        encoder.bytes(&self.value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl Tagged for OwnedAny {
+    fn tag(&self) -> Tag {
+        self.tag
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl ValueOrd for OwnedAny {
+    fn value_cmp(&self, other: &Self) -> Result<Ordering> {
+        self.as_any().value_cmp(&other.as_any())
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl From<Any<'_>> for OwnedAny {
+    fn from(any: Any<'_>) -> OwnedAny {
+        any.to_owned()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> From<&'a OwnedAny> for Any<'a> {
+    fn from(any: &'a OwnedAny) -> Any<'a> {
+        any.as_any()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+impl<'a> arbitrary::Arbitrary<'a> for Any<'a> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Self::new(u.arbitrary()?, u.arbitrary()?).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl crypto_serde::serde::Serialize for Any<'_> {
+    /// Serialize the DER encoding of this value (including tag and length)
+    /// as hex in human-readable formats, or as bytes in binary formats.
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: crypto_serde::serde::Serializer,
+    {
+        use crate::Encode;
+        use crypto_serde::serde::ser::Error as _;
+
+        let der = self.to_vec().map_err(S::Error::custom)?;
+        crypto_serde::serialize_hex_lower_or_bin(&der, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de: 'a, 'a> crypto_serde::serde::Deserialize<'de> for Any<'a> {
+    /// Deserialize a DER-encoded value borrowed from the input.
+    ///
+    /// Only supported by binary formats: use [`OwnedAny`] to deserialize
+    /// from human-readable formats.
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: crypto_serde::serde::Deserializer<'de>,
+    {
+        use crypto_serde::serde::de::Error as _;
+
+        let bytes = ByteSlice::deserialize_borrowed(deserializer)?;
+        Any::from_der(bytes.as_bytes()).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl crypto_serde::serde::Serialize for OwnedAny {
+    /// Serialize this value in the same way as [`Any`].
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: crypto_serde::serde::Serializer,
+    {
+        self.as_any().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> crypto_serde::serde::Deserialize<'de> for OwnedAny {
+    /// Deserialize a DER-encoded value from hex in human-readable formats,
+    /// or from bytes in binary formats.
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: crypto_serde::serde::Deserializer<'de>,
+    {
+        use crypto_serde::serde::de::Error as _;
+
+        let der = crypto_serde::slice::deserialize_hex_or_bin_vec(deserializer)?;
+        OwnedAny::from_der(&der).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Any;
+    use crate::{asn1::SequenceRef, Decode, Decoder, Encode, ErrorKind, Tag, TagNumber};
+    use hex_literal::hex;
+
+    /// `SEQUENCE { INTEGER 1, BOOLEAN TRUE }`
+    const EXAMPLE_SEQUENCE: &[u8] = &hex!("30 06 02 01 01 01 01 ff");
+
+    #[test]
+    fn with_tag() {
+        let any = Any::from_der(EXAMPLE_SEQUENCE).unwrap();
+        let tag = Tag::ContextSpecific {
+            constructed: true,
+            number: TagNumber::N0,
+        };
+
+        let mut buf = [0u8; 16];
+        let encoded = any.with_tag(tag).encode_to_slice(&mut buf).unwrap();
+        assert_eq!(encoded, hex!("a0 06 02 01 01 01 01 ff"));
+        assert_eq!(Any::from_der(encoded).unwrap().with_tag(Tag::Sequence), any);
+    }
+
+    #[test]
+    fn decode_nested_into() {
+        let any = Any::from_der(EXAMPLE_SEQUENCE).unwrap();
+        let mut decoder = Decoder::new(&[]).unwrap();
+        decoder.set_max_depth(2);
+        let seq = any
+            .decode_nested_into::<SequenceRef<'_>>(&mut decoder)
+            .unwrap();
+        let value = seq
+            .decode_body(|decoder| Ok((decoder.decode::<u8>()?, decoder.decode::<bool>()?)))
+            .unwrap();
+        assert_eq!(value, (1, true));
+
+        // The nested decoder counts towards the parent's nesting depth
+        let mut decoder = Decoder::new(&[]).unwrap();
+        decoder.set_max_depth(0);
+        let err = any
+            .decode_nested_into::<SequenceRef<'_>>(&mut decoder)
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::DepthLimit { max_depth: 0 });
+    }
+
+    #[test]
+    fn sequence_iter() {
+        let any = Any::from_der(EXAMPLE_SEQUENCE).unwrap();
+        let mut iter = any.sequence_iter().unwrap();
+
+        assert_eq!(iter.next().unwrap().unwrap().decode_into::<u8>(), Ok(1));
+        assert_eq!(
+            iter.next().unwrap().unwrap().decode_into::<bool>(),
+            Ok(true)
+        );
+        assert!(iter.next().is_none());
+
+        // Primitive values have no children
+        let any = Any::from_der(&hex!("02 01 01")).unwrap();
+        assert!(any.sequence_iter().is_err());
+
+        // Errors are yielded once
+        let any = Any::new(Tag::Sequence, &hex!("02 01 01 02 05 01")).unwrap();
+        let mut iter = any.sequence_iter().unwrap();
+        assert!(iter.next().unwrap().is_ok());
+        assert!(iter.next().unwrap().is_err());
+        assert!(iter.next().is_none());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_owned() {
+        use super::OwnedAny;
+
+        let owned = {
+            let bytes = EXAMPLE_SEQUENCE.to_vec();
+            Any::from_der(&bytes).unwrap().to_owned()
+        };
+
+        assert_eq!(owned.as_any(), Any::from_der(EXAMPLE_SEQUENCE).unwrap());
+        assert_eq!(OwnedAny::from_der(EXAMPLE_SEQUENCE).unwrap(), owned);
+        assert_eq!(owned.to_vec().unwrap(), EXAMPLE_SEQUENCE);
+        assert_eq!(owned.as_any().sequence_iter().unwrap().count(), 2);
+    }
+}
@@ -6,6 +6,9 @@ use crate::{
 };
 use core::{cmp::Ordering, iter::FusedIterator};
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 /// ASN.1 `BIT STRING` type.
 ///
 /// This type contains a sequence of any number of bits, modeled internally as
@@ -118,8 +121,8 @@ impl<'a> BitString<'a> {
 impl<'a> DecodeValue<'a> for BitString<'a> {
     fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
         let header = Header {
-            tag: header.tag,
             length: (header.length - Length::ONE)?,
+            ..header
         };
 
         let unused_bits = decoder.byte()?;
@@ -293,6 +296,180 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl crypto_serde::serde::Serialize for BitString<'_> {
+    /// Serialize the DER value of this `BIT STRING`, i.e. the number of
+    /// unused bits followed by the raw bytes, as hex in human-readable
+    /// formats, or as bytes in binary formats.
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: crypto_serde::serde::Serializer,
+    {
+        let mut value = Vec::with_capacity(self.raw_bytes().len() + 1);
+        value.push(self.unused_bits);
+        value.extend_from_slice(self.raw_bytes());
+        crypto_serde::serialize_hex_lower_or_bin(&value, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de: 'a, 'a> crypto_serde::serde::Deserialize<'de> for BitString<'a> {
+    /// Deserialize a DER `BIT STRING` value borrowed from the input.
+    ///
+    /// Only supported by binary formats: use [`OwnedBitString`] to
+    /// deserialize from human-readable formats.
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: crypto_serde::serde::Deserializer<'de>,
+    {
+        use crypto_serde::serde::de::Error as _;
+
+        match ByteSlice::deserialize_borrowed(deserializer)?
+            .as_bytes()
+            .split_first()
+        {
+            Some((&unused_bits, bytes)) => Self::new(unused_bits, bytes).map_err(D::Error::custom),
+            None => Err(D::Error::custom(Self::TAG.length_error())),
+        }
+    }
+}
+
+/// Owned counterpart of [`BitString`] which stores a copy of the value, e.g.
+/// for deserializing from human-readable formats.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct OwnedBitString {
+    /// Number of unused bits in the final octet.
+    unused_bits: u8,
+
+    /// Bitstring represented as bytes.
+    inner: Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl OwnedBitString {
+    /// Create a new ASN.1 `BIT STRING` from the given bytes.
+    ///
+    /// See [`BitString::new`].
+    pub fn new(unused_bits: u8, bytes: Vec<u8>) -> Result<Self> {
+        BitString::new(unused_bits, &bytes)?;
+
+        Ok(Self {
+            unused_bits,
+            inner: bytes,
+        })
+    }
+
+    /// Borrow this value as a [`BitString`].
+    pub fn as_bit_string(&self) -> BitString<'_> {
+        // Value was checked when this `OwnedBitString` was created
+        BitString::new(self.unused_bits, &self.inner).expect("invalid BIT STRING")
+    }
+
+    /// Get the number of unused bits in the final octet.
+    pub fn unused_bits(&self) -> u8 {
+        self.unused_bits
+    }
+
+    /// Borrow the raw bytes of this `BIT STRING`.
+    ///
+    /// See [`BitString::raw_bytes`].
+    pub fn raw_bytes(&self) -> &[u8] {
+        &self.inner
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> DecodeValue<'a> for OwnedBitString {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        BitString::decode_value(decoder, header).map(Self::from)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl EncodeValue for OwnedBitString {
+    fn value_len(&self) -> Result<Length> {
+        self.as_bit_string().value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.as_bit_string().encode_value(encoder)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl FixedTag for OwnedBitString {
+    const TAG: Tag = Tag::BitString;
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl ValueOrd for OwnedBitString {
+    fn value_cmp(&self, other: &Self) -> Result<Ordering> {
+        self.as_bit_string().value_cmp(&other.as_bit_string())
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl From<BitString<'_>> for OwnedBitString {
+    fn from(bit_string: BitString<'_>) -> OwnedBitString {
+        Self {
+            unused_bits: bit_string.unused_bits(),
+            inner: bit_string.raw_bytes().to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> From<&'a OwnedBitString> for BitString<'a> {
+    fn from(bit_string: &'a OwnedBitString) -> BitString<'a> {
+        bit_string.as_bit_string()
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl crypto_serde::serde::Serialize for OwnedBitString {
+    /// Serialize this value in the same way as [`BitString`].
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: crypto_serde::serde::Serializer,
+    {
+        self.as_bit_string().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> crypto_serde::serde::Deserialize<'de> for OwnedBitString {
+    /// Deserialize a DER `BIT STRING` value from hex in human-readable
+    /// formats, or from bytes in binary formats.
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: crypto_serde::serde::Deserializer<'de>,
+    {
+        use crypto_serde::serde::de::Error as _;
+
+        let mut bytes = crypto_serde::slice::deserialize_hex_or_bin_vec(deserializer)?;
+
+        if bytes.is_empty() {
+            return Err(D::Error::custom(Self::TAG.length_error()));
+        }
+
+        let unused_bits = bytes.remove(0);
+        Self::new(unused_bits, bytes).map_err(D::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{BitString, Result, Tag};
@@ -307,7 +484,7 @@ mod tests {
     #[test]
     fn decode_empty_bitstring() {
         let bs = parse_bitstring(&hex!("00")).unwrap();
-        assert_eq!(bs.as_bytes().unwrap(), &[]);
+        assert_eq!(bs.as_bytes().unwrap(), &[] as &[u8]);
     }
 
     #[test]
@@ -9,6 +9,59 @@ use crate::{Decode, FixedTag, Tag, Tagged};
 /// This crate models choice as a trait, with a blanket impl for all types
 /// which impl `Decode + FixedTag` (i.e. they are modeled as a `CHOICE`
 /// with only one possible variant)
+///
+/// # Multi-tag types
+///
+/// Types which can be encoded with more than one tag, such as X.509's
+/// `Time` (either `UTCTime` or `GeneralizedTime`), can't impl [`FixedTag`].
+/// Besides using the `Choice` custom derive, they can be written by hand
+/// by impl'ing [`Decode`] using [`Tag::peek`] to select the alternative,
+/// along with [`Tagged`] and this trait. `Option<T>` then decodes `None`
+/// when the next tag isn't one of the alternatives:
+///
+/// ```
+/// use der::{asn1::{GeneralizedTime, UtcTime}, Choice, Decode, Decoder, Tag, Tagged};
+///
+/// enum Time {
+///     Utc(UtcTime),
+///     General(GeneralizedTime),
+/// }
+///
+/// impl<'a> Decode<'a> for Time {
+///     fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+///         match Tag::peek(decoder)? {
+///             Tag::UtcTime => decoder.decode().map(Time::Utc),
+///             Tag::GeneralizedTime => decoder.decode().map(Time::General),
+///             tag => Err(tag.unexpected_error(None)),
+///         }
+///     }
+/// }
+///
+/// impl Tagged for Time {
+///     fn tag(&self) -> Tag {
+///         match self {
+///             Time::Utc(_) => Tag::UtcTime,
+///             Time::General(_) => Tag::GeneralizedTime,
+///         }
+///     }
+/// }
+///
+/// impl<'a> Choice<'a> for Time {
+///     fn can_decode(tag: Tag) -> bool {
+///         matches!(tag, Tag::UtcTime | Tag::GeneralizedTime)
+///     }
+/// }
+///
+/// // UTCTime: 1991-05-06 23:45:40 UTC, followed by INTEGER: 42
+/// let mut decoder = Decoder::new(b"\x17\x0d910506234540Z\x02\x01\x2a").unwrap();
+///
+/// let time = decoder.decode::<Option<Time>>().unwrap();
+/// assert!(matches!(time, Some(Time::Utc(_))));
+///
+/// let time = decoder.decode::<Option<Time>>().unwrap();
+/// assert!(time.is_none());
+/// assert_eq!(decoder.decode::<u8>().unwrap(), 42);
+/// ```
 pub trait Choice<'a>: Decode<'a> + Tagged {
     /// Is the provided [`Tag`] decodable as a variant of this `CHOICE`?
     fn can_decode(tag: Tag) -> bool;
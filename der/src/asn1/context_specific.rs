@@ -1,8 +1,8 @@
 //! Context-specific field.
 
 use crate::{
-    asn1::Any, Choice, Decode, DecodeValue, Decoder, DerOrd, Encode, EncodeValue, Encoder, Error,
-    Header, Length, Result, Tag, TagMode, TagNumber, Tagged, ValueOrd,
+    asn1::Any, ByteSlice, Choice, Decode, DecodeValue, Decoder, DerOrd, Encode, EncodeValue,
+    Encoder, Error, Header, Length, Result, Tag, TagMode, TagNumber, Tagged, ValueOrd,
 };
 use core::cmp::Ordering;
 
@@ -47,13 +47,13 @@ impl<T> ContextSpecific<T> {
         T: Decode<'a>,
     {
         Self::decode_with(decoder, tag_number, |decoder| {
-            let any = Any::decode(decoder)?;
+            let header = Header::decode(decoder)?;
 
-            if !any.tag().is_constructed() {
-                return Err(any.tag().non_canonical_error());
+            if !header.tag.is_constructed() {
+                return Err(header.tag.non_canonical_error());
             }
 
-            Self::try_from(any)
+            Self::decode_explicit_value(decoder, header)
         })
     }
 
@@ -86,6 +86,35 @@ impl<T> ContextSpecific<T> {
         })
     }
 
+    /// Decode the value of an `EXPLICIT` field with the given header.
+    ///
+    /// The inner value is decoded using a decoder nested within `decoder`,
+    /// so it counts towards the maximum nesting depth and inherits the
+    /// decoder's settings.
+    fn decode_explicit_value<'a>(decoder: &mut Decoder<'a>, header: Header) -> Result<Self>
+    where
+        T: Decode<'a>,
+    {
+        match header.tag {
+            Tag::ContextSpecific {
+                number,
+                constructed: true,
+            } => {
+                let offset = decoder.position();
+                let body = ByteSlice::decode_value(decoder, header)?;
+                let mut nested_decoder = decoder.nested(body, offset)?;
+                let value = nested_decoder.decode()?;
+
+                Ok(Self {
+                    tag_number: number,
+                    tag_mode: TagMode::default(),
+                    value: nested_decoder.finish(value)?,
+                })
+            }
+            tag => Err(tag.unexpected_error(None)),
+        }
+    }
+
     /// Attempt to decode a context-specific field with the given
     /// helper callback.
     fn decode_with<'a, F>(
@@ -135,7 +164,8 @@ where
     T: Decode<'a>,
 {
     fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
-        Any::decode(decoder)?.try_into()
+        let header = Header::decode(decoder)?;
+        Self::decode_explicit_value(decoder, header)
     }
 }
 
@@ -250,8 +280,8 @@ where
 {
     fn value_cmp(&self, other: &Self) -> Result<Ordering> {
         match self.tag_mode {
-            TagMode::Explicit => self.der_cmp(other),
-            TagMode::Implicit => self.value_cmp(other),
+            TagMode::Explicit => self.value.der_cmp(other.value),
+            TagMode::Implicit => self.value.value_cmp(other.value),
         }
     }
 }
@@ -4,14 +4,17 @@ use crate::{
     asn1::Any,
     datetime::{self, DateTime},
     ord::OrdIsValueOrd,
-    ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, FixedTag, Header, Length, Result,
-    Tag,
+    ByteSlice, Decode, DecodeValue, Decoder, EncodeValue, Encoder, Error, FixedTag, Header, Length,
+    LenientDateTime, Result, Tag,
 };
 use core::time::Duration;
 
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
+#[cfg(feature = "chrono")]
+use chrono::{NaiveDateTime, Utc};
+
 #[cfg(feature = "time")]
 use time::PrimitiveDateTime;
 
@@ -33,6 +36,12 @@ impl GeneralizedTime {
     /// Length of an RFC 5280-flavored ASN.1 DER-encoded [`GeneralizedTime`].
     pub const LENGTH: Length = Length::new(15);
 
+    /// `99991231235959Z`: the "no well-defined expiration date" value from
+    /// [RFC 5280 Section 4.1.2.5].
+    ///
+    /// [RFC 5280 Section 4.1.2.5]: https://tools.ietf.org/html/rfc5280#section-4.1.2.5
+    pub const INFINITY: Self = Self(DateTime::INFINITY);
+
     /// Create a [`GeneralizedTime`] from a [`DateTime`].
     pub fn from_date_time(datetime: DateTime) -> Self {
         Self(datetime)
@@ -71,10 +80,28 @@ impl GeneralizedTime {
     pub fn to_system_time(&self) -> SystemTime {
         self.0.to_system_time()
     }
+
+    /// Leniently decode a `GeneralizedTime` which may not conform to
+    /// RFC 5280, e.g. legacy BER which includes a UTC offset (`+0100`) or
+    /// fractional seconds, normalizing it to UTC.
+    ///
+    /// Fractional seconds are discarded. Use
+    /// [`LenientDateTime::is_normalized`] to check whether the original value
+    /// was RFC 5280-conformant: decoding a [`GeneralizedTime`] is always
+    /// strict.
+    pub fn decode_lenient(decoder: &mut Decoder<'_>) -> Result<LenientDateTime> {
+        let header = Header::decode(decoder)?;
+        header.tag.assert_eq(Self::TAG)?;
+        let policy = decoder.leap_second_policy();
+        let bytes = ByteSlice::decode_value(decoder, header)?;
+        datetime::decode_lenient(Self::TAG, bytes.as_bytes(), None, policy)
+    }
 }
 
 impl DecodeValue<'_> for GeneralizedTime {
     fn decode_value(decoder: &mut Decoder<'_>, header: Header) -> Result<Self> {
+        let policy = decoder.leap_second_policy();
+
         match *ByteSlice::decode_value(decoder, header)?.as_bytes() {
             // RFC 5280 requires mandatory seconds and Z-normalized time zone
             [y1, y2, y3, y4, mon1, mon2, day1, day2, hour1, hour2, min1, min2, sec1, sec2, b'Z'] => {
@@ -86,9 +113,11 @@ impl DecodeValue<'_> for GeneralizedTime {
                 let minute = datetime::decode_decimal(Self::TAG, min1, min2)?;
                 let second = datetime::decode_decimal(Self::TAG, sec1, sec2)?;
 
-                DateTime::new(year, month, day, hour, minute, second)
-                    .map_err(|_| Self::TAG.value_error())
-                    .and_then(|dt| Self::from_unix_duration(dt.unix_duration()))
+                DateTime::new_with_leap_second_policy(
+                    year, month, day, hour, minute, second, policy,
+                )
+                .map_err(|_| Self::TAG.value_error())
+                .and_then(|dt| Self::from_unix_duration(dt.unix_duration()))
             }
             _ => Err(Self::TAG.value_error()),
         }
@@ -257,6 +286,126 @@ impl FixedTag for SystemTime {
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl OrdIsValueOrd for SystemTime {}
 
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl DecodeValue<'_> for NaiveDateTime {
+    fn decode_value(decoder: &mut Decoder<'_>, header: Header) -> Result<Self> {
+        GeneralizedTime::decode_value(decoder, header)?.try_into()
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl EncodeValue for NaiveDateTime {
+    fn value_len(&self) -> Result<Length> {
+        GeneralizedTime::try_from(self)?.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        GeneralizedTime::try_from(self)?.encode_value(encoder)
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl FixedTag for NaiveDateTime {
+    const TAG: Tag = Tag::GeneralizedTime;
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl OrdIsValueOrd for NaiveDateTime {}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl TryFrom<NaiveDateTime> for GeneralizedTime {
+    type Error = Error;
+
+    fn try_from(time: NaiveDateTime) -> Result<GeneralizedTime> {
+        Ok(GeneralizedTime::from_date_time(DateTime::try_from(time)?))
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl TryFrom<&NaiveDateTime> for GeneralizedTime {
+    type Error = Error;
+
+    fn try_from(time: &NaiveDateTime) -> Result<GeneralizedTime> {
+        Self::try_from(*time)
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl TryFrom<GeneralizedTime> for NaiveDateTime {
+    type Error = Error;
+
+    fn try_from(time: GeneralizedTime) -> Result<NaiveDateTime> {
+        time.to_date_time().try_into()
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl DecodeValue<'_> for chrono::DateTime<Utc> {
+    fn decode_value(decoder: &mut Decoder<'_>, header: Header) -> Result<Self> {
+        GeneralizedTime::decode_value(decoder, header)?.try_into()
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl EncodeValue for chrono::DateTime<Utc> {
+    fn value_len(&self) -> Result<Length> {
+        GeneralizedTime::try_from(self)?.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        GeneralizedTime::try_from(self)?.encode_value(encoder)
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl FixedTag for chrono::DateTime<Utc> {
+    const TAG: Tag = Tag::GeneralizedTime;
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl OrdIsValueOrd for chrono::DateTime<Utc> {}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl TryFrom<chrono::DateTime<Utc>> for GeneralizedTime {
+    type Error = Error;
+
+    fn try_from(time: chrono::DateTime<Utc>) -> Result<GeneralizedTime> {
+        Ok(GeneralizedTime::from_date_time(DateTime::try_from(time)?))
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl TryFrom<&chrono::DateTime<Utc>> for GeneralizedTime {
+    type Error = Error;
+
+    fn try_from(time: &chrono::DateTime<Utc>) -> Result<GeneralizedTime> {
+        Self::try_from(*time)
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl TryFrom<GeneralizedTime> for chrono::DateTime<Utc> {
+    type Error = Error;
+
+    fn try_from(time: GeneralizedTime) -> Result<chrono::DateTime<Utc>> {
+        time.to_date_time().try_into()
+    }
+}
+
 #[cfg(feature = "time")]
 #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
 impl DecodeValue<'_> for PrimitiveDateTime {
@@ -317,12 +466,55 @@ impl TryFrom<GeneralizedTime> for PrimitiveDateTime {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+impl<'a> arbitrary::Arbitrary<'a> for GeneralizedTime {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from_date_time(u.arbitrary()?))
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl crypto_serde::serde::Serialize for GeneralizedTime {
+    /// Serialize the inner [`DateTime`].
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: crypto_serde::serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> crypto_serde::serde::Deserialize<'de> for GeneralizedTime {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: crypto_serde::serde::Deserializer<'de>,
+    {
+        DateTime::deserialize(deserializer).map(Self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::GeneralizedTime;
-    use crate::{Decode, Encode, Encoder};
+    use crate::{DateTime, Decode, Decoder, Encode, Encoder, Tag};
     use hex_literal::hex;
 
+    /// Leniently decode a `GeneralizedTime` with the given value.
+    fn lenient(value: &[u8]) -> crate::Result<(DateTime, bool)> {
+        let mut buf = [0u8; 64];
+        buf[0] = Tag::GeneralizedTime.octet();
+        buf[1] = value.len() as u8;
+        buf[2..(value.len() + 2)].copy_from_slice(value);
+
+        let mut decoder = Decoder::new(&buf[..(value.len() + 2)])?;
+        let lenient = GeneralizedTime::decode_lenient(&mut decoder)?;
+        decoder.finish((lenient.datetime(), lenient.is_normalized()))
+    }
+
     #[test]
     fn round_trip() {
         let example_bytes = hex!("18 0f 31 39 39 31 30 35 30 36 32 33 34 35 34 30 5a");
@@ -334,4 +526,39 @@ mod tests {
         utc_time.encode(&mut encoder).unwrap();
         assert_eq!(example_bytes, encoder.finish().unwrap());
     }
+
+    #[test]
+    fn decode_lenient() {
+        let datetime = DateTime::new(1991, 5, 6, 23, 45, 40).unwrap();
+
+        // RFC 5280-conformant values aren't normalized
+        assert_eq!(lenient(b"19910506234540Z").unwrap(), (datetime, false));
+
+        // UTC offsets
+        assert_eq!(lenient(b"19910507004540+0100").unwrap(), (datetime, true));
+        assert_eq!(lenient(b"19910506181540-0530").unwrap(), (datetime, true));
+
+        // Fractional seconds are discarded
+        assert_eq!(lenient(b"19910506234540.999Z").unwrap(), (datetime, true));
+        assert_eq!(lenient(b"19910507014540,5+0200").unwrap(), (datetime, true));
+
+        // Omitted seconds
+        assert_eq!(
+            lenient(b"199105062345Z").unwrap(),
+            (DateTime::new(1991, 5, 6, 23, 45, 0).unwrap(), true)
+        );
+
+        // Still invalid
+        assert!(lenient(b"19910506234540").is_err());
+        assert!(lenient(b"19910506234540.Z").is_err());
+        assert!(lenient(b"19910506234540+01").is_err());
+        assert!(lenient(b"19910506234540+2400").is_err());
+        assert!(lenient(b"19700101000000+0100").is_err());
+
+        // Strict decoding is unaffected
+        assert!(GeneralizedTime::from_der(&hex!(
+            "18 13 31 39 39 31 30 35 30 37 30 30 34 35 34 30 2b 30 31 30 30"
+        ))
+        .is_err());
+    }
 }
@@ -1,8 +1,8 @@
 //! ASN.1 `IA5String` support.
 
 use crate::{
-    asn1::Any, ord::OrdIsValueOrd, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error,
-    FixedTag, Header, Length, Result, StrSlice, Tag,
+    asn1::Any, charset, ord::OrdIsValueOrd, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder,
+    Error, FixedTag, Header, Length, Result, StrSlice, Tag,
 };
 use core::{fmt, str};
 
@@ -31,7 +31,7 @@ impl<'a> Ia5String<'a> {
         let input = input.as_ref();
 
         // Validate all characters are within IA5String's allowed set
-        if input.iter().any(|&c| c > 0x7F) {
+        if !charset::is_ia5(input) {
             return Err(Self::TAG.value_error());
         }
 
@@ -133,6 +133,22 @@ impl<'a> fmt::Debug for Ia5String<'a> {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+impl<'a> arbitrary::Arbitrary<'a> for Ia5String<'a> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let bytes = u.arbitrary::<&'a [u8]>()?;
+
+        // Use the longest prefix which contains only valid characters
+        let len = bytes
+            .iter()
+            .position(|&byte| Ia5String::new(&[byte]).is_err())
+            .unwrap_or(bytes.len());
+
+        Self::new(&bytes[..len]).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Ia5String;
@@ -3,8 +3,9 @@
 use super::uint;
 use crate::{
     asn1::Any, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, ErrorKind, FixedTag,
-    Header, Length, Result, Tag,
+    Header, Length, Result, Tag, ValueOrd,
 };
+use core::cmp::Ordering;
 
 /// "Big" unsigned ASN.1 `INTEGER` type.
 ///
@@ -92,6 +93,17 @@ impl<'a> FixedTag for UIntBytes<'a> {
     const TAG: Tag = Tag::Integer;
 }
 
+impl ValueOrd for UIntBytes<'_> {
+    fn value_cmp(&self, other: &Self) -> Result<Ordering> {
+        // Leading zeroes are stripped, so comparing the lengths first orders
+        // the values numerically, which is the same as their DER ordering.
+        Ok(self
+            .len()
+            .cmp(&other.len())
+            .then_with(|| self.as_bytes().cmp(other.as_bytes())))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::UIntBytes;
@@ -5,7 +5,16 @@ use crate::{
     ErrorKind, FixedTag, Header, Length, Result, Tag,
 };
 
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
 /// ASN.1 `OCTET STRING` type.
+///
+/// Only the primitive form is supported: use [`SegmentedString`][`super::SegmentedString`]
+/// to decode BER's constructed (i.e. segmented) form.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub struct OctetString<'a> {
     /// Inner value
@@ -90,3 +99,402 @@ impl<'a> From<OctetString<'a>> for &'a [u8] {
         octet_string.as_bytes()
     }
 }
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> From<OctetString<'a>> for Cow<'a, [u8]> {
+    fn from(octet_string: OctetString<'a>) -> Cow<'a, [u8]> {
+        Cow::Borrowed(octet_string.as_bytes())
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> TryFrom<Any<'a>> for Cow<'a, [u8]> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Cow<'a, [u8]>> {
+        OctetString::try_from(any).map(Into::into)
+    }
+}
+
+/// Decodes as [`Cow::Borrowed`], borrowing from the input.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> DecodeValue<'a> for Cow<'a, [u8]> {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        OctetString::decode_value(decoder, header).map(Into::into)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl EncodeValue for Cow<'_, [u8]> {
+    fn value_len(&self) -> Result<Length> {
+        OctetString::new(self)?.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        OctetString::new(self)?.encode_value(encoder)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl FixedTag for Cow<'_, [u8]> {
+    const TAG: Tag = Tag::OctetString;
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl OrdIsValueOrd for Cow<'_, [u8]> {}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl crypto_serde::serde::Serialize for OctetString<'_> {
+    /// Serialize the octets as hex in human-readable formats, or as bytes
+    /// in binary formats.
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: crypto_serde::serde::Serializer,
+    {
+        crypto_serde::serialize_hex_lower_or_bin(&self.as_bytes(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de: 'a, 'a> crypto_serde::serde::Deserialize<'de> for OctetString<'a> {
+    /// Deserialize octets borrowed from the input.
+    ///
+    /// Only supported by binary formats: use [`OwnedOctetString`] to
+    /// deserialize from human-readable formats.
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: crypto_serde::serde::Deserializer<'de>,
+    {
+        ByteSlice::deserialize_borrowed(deserializer).map(|inner| Self { inner })
+    }
+}
+
+/// Owned counterpart of [`OctetString`] which stores a copy of the value,
+/// e.g. for deserializing from human-readable formats.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct OwnedOctetString {
+    /// Inner value
+    inner: Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl OwnedOctetString {
+    /// Create a new ASN.1 `OCTET STRING` from the given bytes.
+    pub fn new(bytes: Vec<u8>) -> Result<Self> {
+        OctetString::new(&bytes)?;
+        Ok(Self { inner: bytes })
+    }
+
+    /// Borrow this value as an [`OctetString`].
+    pub fn as_octet_string(&self) -> OctetString<'_> {
+        // Length was checked when this `OwnedOctetString` was created
+        OctetString::new(&self.inner).expect("invalid length")
+    }
+
+    /// Borrow the inner byte slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.inner
+    }
+
+    /// Convert this value into its inner bytes.
+    pub fn into_vec(self) -> Vec<u8> {
+        self.inner
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl AsRef<[u8]> for OwnedOctetString {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> DecodeValue<'a> for OwnedOctetString {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        OctetString::decode_value(decoder, header).map(Self::from)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl EncodeValue for OwnedOctetString {
+    fn value_len(&self) -> Result<Length> {
+        self.as_octet_string().value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        encoder.bytes(&self.inner)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl FixedTag for OwnedOctetString {
+    const TAG: Tag = Tag::OctetString;
+}
+
+#[cfg(feature = "alloc")]
+impl OrdIsValueOrd for OwnedOctetString {}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl From<OctetString<'_>> for OwnedOctetString {
+    fn from(octet_string: OctetString<'_>) -> OwnedOctetString {
+        Self {
+            inner: octet_string.as_bytes().to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> From<&'a OwnedOctetString> for OctetString<'a> {
+    fn from(octet_string: &'a OwnedOctetString) -> OctetString<'a> {
+        octet_string.as_octet_string()
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl crypto_serde::serde::Serialize for OwnedOctetString {
+    /// Serialize the octets as hex in human-readable formats, or as bytes
+    /// in binary formats.
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: crypto_serde::serde::Serializer,
+    {
+        crypto_serde::serialize_hex_lower_or_bin(&self.inner, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> crypto_serde::serde::Deserialize<'de> for OwnedOctetString {
+    /// Deserialize octets from hex in human-readable formats, or from bytes
+    /// in binary formats.
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: crypto_serde::serde::Deserializer<'de>,
+    {
+        use crypto_serde::serde::de::Error as _;
+
+        crypto_serde::slice::deserialize_hex_or_bin_vec(deserializer)
+            .and_then(|bytes| Self::new(bytes).map_err(D::Error::custom))
+    }
+}
+
+/// IPv4 addresses are encoded as a 4-byte `OCTET STRING`, e.g. as in the
+/// `iPAddress` form of an X.509 `GeneralName`.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl DecodeValue<'_> for Ipv4Addr {
+    fn decode_value(decoder: &mut Decoder<'_>, header: Header) -> Result<Self> {
+        OctetString::decode_value(decoder, header)?.try_into()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl EncodeValue for Ipv4Addr {
+    fn value_len(&self) -> Result<Length> {
+        Ok(Length::new(4))
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        encoder.bytes(&self.octets())
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl FixedTag for Ipv4Addr {
+    const TAG: Tag = Tag::OctetString;
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl TryFrom<OctetString<'_>> for Ipv4Addr {
+    type Error = Error;
+
+    fn try_from(octet_string: OctetString<'_>) -> Result<Ipv4Addr> {
+        <[u8; 4]>::try_from(octet_string.as_bytes())
+            .map(Into::into)
+            .map_err(|_| OctetString::TAG.length_error())
+    }
+}
+
+/// IPv6 addresses are encoded as a 16-byte `OCTET STRING`, e.g. as in the
+/// `iPAddress` form of an X.509 `GeneralName`.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl DecodeValue<'_> for Ipv6Addr {
+    fn decode_value(decoder: &mut Decoder<'_>, header: Header) -> Result<Self> {
+        OctetString::decode_value(decoder, header)?.try_into()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl EncodeValue for Ipv6Addr {
+    fn value_len(&self) -> Result<Length> {
+        Ok(Length::new(16))
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        encoder.bytes(&self.octets())
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl FixedTag for Ipv6Addr {
+    const TAG: Tag = Tag::OctetString;
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl TryFrom<OctetString<'_>> for Ipv6Addr {
+    type Error = Error;
+
+    fn try_from(octet_string: OctetString<'_>) -> Result<Ipv6Addr> {
+        <[u8; 16]>::try_from(octet_string.as_bytes())
+            .map(Into::into)
+            .map_err(|_| OctetString::TAG.length_error())
+    }
+}
+
+/// IP addresses are encoded as a 4-byte (IPv4) or 16-byte (IPv6)
+/// `OCTET STRING`, with the version determined by the length.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl DecodeValue<'_> for IpAddr {
+    fn decode_value(decoder: &mut Decoder<'_>, header: Header) -> Result<Self> {
+        OctetString::decode_value(decoder, header)?.try_into()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl EncodeValue for IpAddr {
+    fn value_len(&self) -> Result<Length> {
+        match self {
+            IpAddr::V4(addr) => addr.value_len(),
+            IpAddr::V6(addr) => addr.value_len(),
+        }
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        match self {
+            IpAddr::V4(addr) => addr.encode_value(encoder),
+            IpAddr::V6(addr) => addr.encode_value(encoder),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl FixedTag for IpAddr {
+    const TAG: Tag = Tag::OctetString;
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl TryFrom<OctetString<'_>> for IpAddr {
+    type Error = Error;
+
+    fn try_from(octet_string: OctetString<'_>) -> Result<IpAddr> {
+        match octet_string.as_bytes().len() {
+            4 => Ipv4Addr::try_from(octet_string).map(IpAddr::V4),
+            16 => Ipv6Addr::try_from(octet_string).map(IpAddr::V6),
+            _ => Err(OctetString::TAG.length_error()),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::{asn1::OctetString, Decode, Encode, ErrorKind, Tag};
+    use hex_literal::hex;
+    use std::{
+        borrow::Cow,
+        net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    };
+
+    const IPV4_DER: &[u8] = &hex!("0404 c0000201");
+    const IPV6_DER: &[u8] = &hex!("0410 20010db8000000000000000000000001");
+
+    #[test]
+    fn ipv4_round_trip() {
+        let addr = Ipv4Addr::new(192, 0, 2, 1);
+        assert_eq!(Ipv4Addr::from_der(IPV4_DER).unwrap(), addr);
+        assert_eq!(IpAddr::from_der(IPV4_DER).unwrap(), IpAddr::V4(addr));
+
+        let mut buf = [0u8; 32];
+        assert_eq!(addr.encode_to_slice(&mut buf).unwrap(), IPV4_DER);
+        assert_eq!(
+            IpAddr::V4(addr).encode_to_slice(&mut buf).unwrap(),
+            IPV4_DER
+        );
+    }
+
+    #[test]
+    fn ipv6_round_trip() {
+        let addr = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+        assert_eq!(Ipv6Addr::from_der(IPV6_DER).unwrap(), addr);
+        assert_eq!(IpAddr::from_der(IPV6_DER).unwrap(), IpAddr::V6(addr));
+
+        let mut buf = [0u8; 32];
+        assert_eq!(addr.encode_to_slice(&mut buf).unwrap(), IPV6_DER);
+        assert_eq!(
+            IpAddr::V6(addr).encode_to_slice(&mut buf).unwrap(),
+            IPV6_DER
+        );
+    }
+
+    #[test]
+    fn cow_round_trip() {
+        let der = hex!("0403 010203");
+        let bytes = Cow::<'_, [u8]>::from_der(&der).unwrap();
+        assert!(matches!(bytes, Cow::Borrowed(&[1, 2, 3])));
+
+        let owned: Cow<'_, [u8]> = Cow::Owned(vec![1, 2, 3]);
+        assert_eq!(owned.to_vec().unwrap(), der);
+    }
+
+    #[test]
+    fn invalid_length() {
+        let length_error = ErrorKind::Length {
+            tag: Tag::OctetString,
+        };
+
+        // IPv4 address with a CIDR mask, as used in X.509 name constraints
+        let bytes = hex!("0408 c0000200ffffff00");
+        assert_eq!(IpAddr::from_der(&bytes).unwrap_err().kind(), length_error);
+        assert_eq!(Ipv4Addr::from_der(&bytes).unwrap_err().kind(), length_error);
+
+        assert_eq!(
+            Ipv6Addr::from_der(IPV4_DER).unwrap_err().kind(),
+            length_error
+        );
+        assert_eq!(
+            Ipv4Addr::try_from(OctetString::new(&[]).unwrap())
+                .unwrap_err()
+                .kind(),
+            length_error
+        );
+    }
+}
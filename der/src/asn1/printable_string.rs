@@ -1,8 +1,8 @@
 //! ASN.1 `PrintableString` support.
 
 use crate::{
-    asn1::Any, ord::OrdIsValueOrd, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error,
-    FixedTag, Header, Length, Result, StrSlice, Tag,
+    asn1::Any, charset, ord::OrdIsValueOrd, ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder,
+    Error, FixedTag, Header, Length, Result, StrSlice, Tag,
 };
 use core::{fmt, str};
 
@@ -47,25 +47,8 @@ impl<'a> PrintableString<'a> {
         let input = input.as_ref();
 
         // Validate all characters are within PrintedString's allowed set
-        for &c in input.iter() {
-            match c {
-                b'A'..=b'Z'
-                | b'a'..=b'z'
-                | b'0'..=b'9'
-                | b' '
-                | b'\''
-                | b'('
-                | b')'
-                | b'+'
-                | b','
-                | b'-'
-                | b'.'
-                | b'/'
-                | b':'
-                | b'='
-                | b'?' => (),
-                _ => return Err(Self::TAG.value_error()),
-            }
+        if !charset::is_printable(input) {
+            return Err(Self::TAG.value_error());
         }
 
         StrSlice::from_bytes(input)
@@ -166,6 +149,22 @@ impl<'a> fmt::Debug for PrintableString<'a> {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+impl<'a> arbitrary::Arbitrary<'a> for PrintableString<'a> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let bytes = u.arbitrary::<&'a [u8]>()?;
+
+        // Use the longest prefix which contains only valid characters
+        let len = bytes
+            .iter()
+            .position(|&byte| PrintableString::new(&[byte]).is_err())
+            .unwrap_or(bytes.len());
+
+        Self::new(&bytes[..len]).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::PrintableString;
@@ -0,0 +1,359 @@
+//! Support for string types encoded using BER's constructed form.
+
+use super::BitString;
+use crate::{ByteSlice, Decode, Decoder, Error, ErrorKind, Header, Result, Tag};
+use core::iter::FusedIterator;
+
+#[cfg(feature = "alloc")]
+use {super::OwnedAny, alloc::vec::Vec};
+
+/// String value which may be encoded using either the primitive form or, as
+/// BER permits, the constructed form, where the value is split into segments
+/// (e.g. by streaming CMS implementations).
+///
+/// Any `UNIVERSAL` string type can be decoded (see [`Tag::is_segmentable`]),
+/// but the constructed form is only accepted by a [`Decoder`] configured with
+/// [`EncodingRules::Ber`][`crate::EncodingRules::Ber`]. Segments must all be
+/// encoded using the primitive form and the same tag as the string itself.
+///
+/// The segments can be iterated over without copying using
+/// [`SegmentedString::segments`], or reassembled into a contiguous buffer
+/// using [`SegmentedString::to_vec`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SegmentedString<'a> {
+    /// Tag of the string.
+    tag: Tag,
+
+    /// Is the string encoded using the constructed form?
+    constructed: bool,
+
+    /// Unused bits in the final octet of a `BIT STRING`.
+    unused_bits: u8,
+
+    /// Value of the string: the segments' TLVs if constructed.
+    value: ByteSlice<'a>,
+}
+
+impl<'a> SegmentedString<'a> {
+    /// Get the [`Tag`] of this string.
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    /// Was this string encoded using the constructed form?
+    pub fn is_constructed(&self) -> bool {
+        self.constructed
+    }
+
+    /// Get the number of unused bits in the final octet of a `BIT STRING`.
+    ///
+    /// Always `0` for other string types.
+    pub fn unused_bits(&self) -> u8 {
+        self.unused_bits
+    }
+
+    /// Iterate over the contents of each segment of this string.
+    ///
+    /// For a `BIT STRING`, the leading unused bits octet of each segment is
+    /// omitted (see [`SegmentedString::unused_bits`]). A string encoded using
+    /// the primitive form has exactly one segment.
+    pub fn segments(&self) -> Segments<'a> {
+        let state = if self.constructed {
+            // Length was checked when decoding
+            Decoder::new(self.value.as_bytes())
+                .ok()
+                .map(|decoder| State::Constructed {
+                    decoder,
+                    bit_string: self.tag == Tag::BitString,
+                })
+        } else if self.tag == Tag::BitString {
+            self.value
+                .as_bytes()
+                .split_first()
+                .map(|(_, bytes)| State::Primitive(bytes))
+        } else {
+            Some(State::Primitive(self.value.as_bytes()))
+        };
+
+        Segments { state }
+    }
+
+    /// Reassemble the contents of this string into a contiguous buffer.
+    ///
+    /// For a `BIT STRING`, the unused bits octet is omitted (see
+    /// [`SegmentedString::unused_bits`]).
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        for segment in self.segments() {
+            bytes.extend_from_slice(segment);
+        }
+
+        bytes
+    }
+
+    /// Reassemble this string into an [`OwnedAny`] containing the primitive
+    /// (i.e. DER) encoding of its value, which can then be decoded as e.g. an
+    /// [`OctetString`][`super::OctetString`].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_owned_any(&self) -> Result<OwnedAny> {
+        let mut value = Vec::new();
+
+        if self.tag == Tag::BitString {
+            value.push(self.unused_bits);
+        }
+
+        for segment in self.segments() {
+            value.extend_from_slice(segment);
+        }
+
+        OwnedAny::new(self.tag, value)
+    }
+}
+
+impl<'a> Decode<'a> for SegmentedString<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        let header = Header::decode(decoder)?;
+
+        if !header.tag.is_segmentable() {
+            return Err(decoder.error(ErrorKind::TagUnexpected {
+                expected: None,
+                actual: header.tag,
+            }));
+        }
+
+        let value = ByteSlice::new(decoder.bytes(header.length)?)?;
+
+        let unused_bits = if header.constructed {
+            check_segments(header.tag, value.as_bytes())
+        } else if header.tag == Tag::BitString {
+            check_bit_string_segment(value.as_bytes())
+        } else {
+            Ok(0)
+        }
+        .map_err(|e| decoder.error(e.kind()))?;
+
+        Ok(Self {
+            tag: header.tag,
+            constructed: header.constructed,
+            unused_bits,
+            value,
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for SegmentedString<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+/// Check the segments of a constructed string with the given tag, returning
+/// the number of unused bits in the final octet of a `BIT STRING`.
+fn check_segments(tag: Tag, bytes: &[u8]) -> Result<u8> {
+    let mut decoder = Decoder::new(bytes)?;
+    let mut unused_bits = 0;
+
+    while !decoder.is_finished() {
+        let header = Header::decode(&mut decoder)?;
+
+        if header.tag != tag || header.constructed {
+            return Err(ErrorKind::TagForm {
+                tag: header.tag,
+                constructed: header.constructed,
+            }
+            .into());
+        }
+
+        let segment = decoder.bytes(header.length)?;
+
+        if tag == Tag::BitString {
+            // Only the final segment may have unused bits
+            if unused_bits != 0 {
+                return Err(tag.value_error());
+            }
+
+            unused_bits = check_bit_string_segment(segment)?;
+        }
+    }
+
+    decoder.finish(unused_bits)
+}
+
+/// Check the contents of a `BIT STRING` segment, returning the number of
+/// unused bits in its final octet.
+fn check_bit_string_segment(bytes: &[u8]) -> Result<u8> {
+    match *bytes {
+        [] => Err(Tag::BitString.length_error()),
+        [unused_bits, ref rest @ ..] => {
+            if unused_bits > BitString::MAX_UNUSED_BITS || (rest.is_empty() && unused_bits != 0) {
+                Err(Tag::BitString.value_error())
+            } else {
+                Ok(unused_bits)
+            }
+        }
+    }
+}
+
+/// Iterator over the segments of a [`SegmentedString`].
+///
+/// Created by [`SegmentedString::segments`].
+#[derive(Clone, Debug)]
+pub struct Segments<'a> {
+    /// Iteration state, or `None` if finished.
+    state: Option<State<'a>>,
+}
+
+/// Iteration state of [`Segments`].
+#[derive(Clone, Debug)]
+enum State<'a> {
+    /// Single remaining segment of a string encoded using the primitive form.
+    Primitive(&'a [u8]),
+
+    /// Segments of a string encoded using the constructed form.
+    Constructed {
+        /// Decoder for the segments.
+        decoder: Decoder<'a>,
+
+        /// Are the segments `BIT STRING`s?
+        bit_string: bool,
+    },
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        match self.state.as_mut()? {
+            State::Primitive(bytes) => {
+                let bytes = *bytes;
+                self.state = None;
+                Some(bytes)
+            }
+            State::Constructed {
+                decoder,
+                bit_string,
+            } => {
+                let segment = if decoder.is_finished() {
+                    None
+                } else {
+                    // Segments were checked when decoding
+                    Header::decode(decoder)
+                        .and_then(|header| decoder.bytes(header.length))
+                        .ok()
+                };
+
+                match (segment, *bit_string) {
+                    (Some(bytes), false) => Some(bytes),
+                    (Some(bytes), true) => bytes.get(1..),
+                    (None, _) => {
+                        self.state = None;
+                        None
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a> FusedIterator for Segments<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use super::SegmentedString;
+    use crate::{Decode, Decoder, EncodingRules, ErrorKind, Tag};
+    use hex_literal::hex;
+
+    /// Constructed `OCTET STRING` containing "hello" split into two segments.
+    const CONSTRUCTED_OCTET_STRING: &[u8] = &hex!("2409 0403 68656c 0402 6c6f");
+
+    /// Constructed `BIT STRING` with 4 unused bits split into two segments.
+    const CONSTRUCTED_BIT_STRING: &[u8] = &hex!("2309 0302 00ff 0303 04abc0");
+
+    fn decode_ber(bytes: &[u8]) -> crate::Result<SegmentedString<'_>> {
+        let mut decoder = Decoder::new(bytes)?;
+        decoder.set_encoding_rules(EncodingRules::Ber);
+        let result = SegmentedString::decode(&mut decoder)?;
+        decoder.finish(result)
+    }
+
+    #[test]
+    fn constructed_octet_string() {
+        let string = decode_ber(CONSTRUCTED_OCTET_STRING).unwrap();
+        assert_eq!(string.tag(), Tag::OctetString);
+        assert!(string.is_constructed());
+
+        let mut segments = string.segments();
+        assert_eq!(segments.next(), Some(&b"hel"[..]));
+        assert_eq!(segments.next(), Some(&b"lo"[..]));
+        assert_eq!(segments.next(), None);
+
+        // Not permitted by DER
+        let err = SegmentedString::from_der(CONSTRUCTED_OCTET_STRING).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::TagForm { .. }));
+    }
+
+    #[test]
+    fn constructed_bit_string() {
+        let string = decode_ber(CONSTRUCTED_BIT_STRING).unwrap();
+        assert_eq!(string.tag(), Tag::BitString);
+        assert_eq!(string.unused_bits(), 4);
+        assert!(string.segments().eq([&[0xff][..], &[0xab, 0xc0]]));
+    }
+
+    #[test]
+    fn primitive() {
+        let string = SegmentedString::from_der(&hex!("0405 68656c6c6f")).unwrap();
+        assert!(!string.is_constructed());
+        assert!(string.segments().eq([&b"hello"[..]]));
+
+        let string = SegmentedString::from_der(&hex!("0303 04abc0")).unwrap();
+        assert_eq!(string.unused_bits(), 4);
+        assert!(string.segments().eq([&[0xab, 0xc0][..]]));
+
+        // Not a string type
+        assert!(SegmentedString::from_der(&hex!("020101")).is_err());
+    }
+
+    #[test]
+    fn invalid_segments() {
+        for bytes in [
+            // Segment with a different tag
+            &hex!("2407 0403 68656c 0c00")[..],
+            // Nested constructed segment
+            &hex!("2407 2405 0403 68656c"),
+            // Unused bits in a segment other than the last one
+            &hex!("2309 0302 04f0 0303 00abcd"),
+            // Truncated segment
+            &hex!("2405 0404 68656c"),
+        ] {
+            assert!(decode_ber(bytes).is_err(), "{:x?}", bytes);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn reassemble() {
+        use crate::asn1::{BitString, OctetString};
+
+        let string = decode_ber(CONSTRUCTED_OCTET_STRING).unwrap();
+        assert_eq!(string.to_vec(), b"hello");
+
+        let any = string.to_owned_any().unwrap();
+        let octet_string: OctetString<'_> = any.decode_into().unwrap();
+        assert_eq!(octet_string.as_bytes(), b"hello");
+
+        let string = decode_ber(CONSTRUCTED_BIT_STRING).unwrap();
+        assert_eq!(string.to_vec(), [0xff, 0xab, 0xc0]);
+
+        let any = string.to_owned_any().unwrap();
+        let bit_string: BitString<'_> = any.decode_into().unwrap();
+        assert_eq!(bit_string.unused_bits(), 4);
+        assert_eq!(bit_string.raw_bytes(), [0xff, 0xab, 0xc0]);
+    }
+}
@@ -35,13 +35,7 @@ where
     }
 
     fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
-        self.fields(|fields| {
-            for &field in fields {
-                field.encode(encoder)?;
-            }
-
-            Ok(())
-        })
+        self.fields(|fields| encoder.encode_all(fields.iter().copied()))
     }
 }
 
@@ -58,8 +52,9 @@ pub struct SequenceRef<'a> {
     /// Body of the `SEQUENCE`.
     body: ByteSlice<'a>,
 
-    /// Offset location in the outer document where this `SEQUENCE` begins.
-    offset: Length,
+    /// Nested decoder for the body of the `SEQUENCE`, which begins at the
+    /// offset location in the outer document where the body occurs.
+    decoder: Decoder<'a>,
 }
 
 impl<'a> SequenceRef<'a> {
@@ -68,7 +63,7 @@ impl<'a> SequenceRef<'a> {
     where
         F: FnOnce(&mut Decoder<'a>) -> Result<T>,
     {
-        let mut nested_decoder = Decoder::new_with_offset(self.body, self.offset);
+        let mut nested_decoder = self.decoder.clone();
         let result = f(&mut nested_decoder)?;
         nested_decoder.finish(result)
     }
@@ -78,7 +73,8 @@ impl<'a> DecodeValue<'a> for SequenceRef<'a> {
     fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
         let offset = decoder.position();
         let body = ByteSlice::decode_value(decoder, header)?;
-        Ok(Self { body, offset })
+        let decoder = decoder.nested(body, offset)?;
+        Ok(Self { body, decoder })
     }
 }
 
@@ -2,13 +2,16 @@
 
 use crate::{
     arrayvec, ord::iter_cmp, ArrayVec, Decode, DecodeValue, Decoder, DerOrd, Encode, EncodeValue,
-    Encoder, ErrorKind, FixedTag, Header, Length, Result, Tag, ValueOrd,
+    Encoder, FixedTag, Header, Length, Result, Tag, ValueOrd,
 };
 use core::cmp::Ordering;
 
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
+#[cfg(feature = "heapless")]
+use crate::ErrorKind;
+
 /// ASN.1 `SEQUENCE OF` backed by an array.
 ///
 /// This type implements an append-only `SEQUENCE OF` type which is stack-based
@@ -67,17 +70,8 @@ where
     T: Decode<'a>,
 {
     fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
-        let end_pos = (decoder.position() + header.length)?;
         let mut sequence_of = Self::new();
-
-        while decoder.position() < end_pos {
-            sequence_of.add(decoder.decode()?)?;
-        }
-
-        if decoder.position() != end_pos {
-            decoder.error(ErrorKind::Length { tag: Self::TAG });
-        }
-
+        decoder.decode_elements(Self::TAG, header.length, |elem| sequence_of.add(elem))?;
         Ok(sequence_of)
     }
 }
@@ -92,11 +86,7 @@ where
     }
 
     fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
-        for elem in self.iter() {
-            elem.encode(encoder)?;
-        }
-
-        Ok(())
+        encoder.encode_all(self.iter())
     }
 }
 
@@ -128,6 +118,12 @@ impl<'a, T> Iterator for SequenceOfIter<'a, T> {
     }
 }
 
+impl<'a, T> DoubleEndedIterator for SequenceOfIter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.inner.next_back()
+    }
+}
+
 impl<'a, T> ExactSizeIterator for SequenceOfIter<'a, T> {}
 
 impl<'a, T, const N: usize> DecodeValue<'a> for [T; N]
@@ -151,11 +147,7 @@ where
     }
 
     fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
-        for elem in self {
-            elem.encode(encoder)?;
-        }
-
-        Ok(())
+        encoder.encode_all(self.iter())
     }
 }
 
@@ -179,16 +171,12 @@ where
     T: Decode<'a>,
 {
     fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
-        let end_pos = (decoder.position() + header.length)?;
         let mut sequence_of = Self::new();
 
-        while decoder.position() < end_pos {
-            sequence_of.push(decoder.decode()?);
-        }
-
-        if decoder.position() != end_pos {
-            decoder.error(ErrorKind::Length { tag: Self::TAG });
-        }
+        decoder.decode_elements(Self::TAG, header.length, |elem| {
+            sequence_of.push(elem);
+            Ok(())
+        })?;
 
         Ok(sequence_of)
     }
@@ -206,11 +194,7 @@ where
     }
 
     fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
-        for elem in self {
-            elem.encode(encoder)?;
-        }
-
-        Ok(())
+        encoder.encode_all(self.iter())
     }
 }
 
@@ -230,3 +214,55 @@ where
         iter_cmp(self.iter(), other.iter())
     }
 }
+
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+impl<'a, T, const N: usize> DecodeValue<'a> for heapless::Vec<T, N>
+where
+    T: Decode<'a>,
+{
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        let mut sequence_of = Self::new();
+
+        decoder.decode_elements(Self::TAG, header.length, |elem| {
+            sequence_of
+                .push(elem)
+                .map_err(|_| ErrorKind::Overlength.into())
+        })?;
+
+        Ok(sequence_of)
+    }
+}
+
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+impl<T, const N: usize> EncodeValue for heapless::Vec<T, N>
+where
+    T: Encode,
+{
+    fn value_len(&self) -> Result<Length> {
+        self.iter()
+            .try_fold(Length::ZERO, |len, elem| len + elem.encoded_len()?)
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        encoder.encode_all(self.iter())
+    }
+}
+
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+impl<T, const N: usize> FixedTag for heapless::Vec<T, N> {
+    const TAG: Tag = Tag::Sequence;
+}
+
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+impl<T, const N: usize> ValueOrd for heapless::Vec<T, N>
+where
+    T: DerOrd,
+{
+    fn value_cmp(&self, other: &Self) -> Result<Ordering> {
+        iter_cmp(self.iter(), other.iter())
+    }
+}
@@ -86,17 +86,8 @@ where
     T: Decode<'a> + DerOrd,
 {
     fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
-        let end_pos = (decoder.position() + header.length)?;
         let mut result = Self::new();
-
-        while decoder.position() < end_pos {
-            result.add(decoder.decode()?)?;
-        }
-
-        if decoder.position() != end_pos {
-            decoder.error(ErrorKind::Length { tag: Self::TAG });
-        }
-
+        decoder.decode_elements(Self::TAG, header.length, |elem| result.add(elem))?;
         Ok(result)
     }
 }
@@ -111,11 +102,7 @@ where
     }
 
     fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
-        for elem in self.iter() {
-            elem.encode(encoder)?;
-        }
-
-        Ok(())
+        encoder.encode_all(self.iter())
     }
 }
 
@@ -169,6 +156,12 @@ impl<'a, T> Iterator for SetOfIter<'a, T> {
     }
 }
 
+impl<'a, T> DoubleEndedIterator for SetOfIter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.inner.next_back()
+    }
+}
+
 impl<'a, T> ExactSizeIterator for SetOfIter<'a, T> {}
 
 /// ASN.1 `SET OF` backed by a [`Vec`].
@@ -273,17 +266,8 @@ where
     T: Decode<'a> + DerOrd,
 {
     fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
-        let end_pos = (decoder.position() + header.length)?;
         let mut result = Self::new();
-
-        while decoder.position() < end_pos {
-            result.add(decoder.decode()?)?;
-        }
-
-        if decoder.position() != end_pos {
-            decoder.error(ErrorKind::Length { tag: Self::TAG });
-        }
-
+        decoder.decode_elements(Self::TAG, header.length, |elem| result.add(elem))?;
         Ok(result)
     }
 }
@@ -300,11 +284,7 @@ where
     }
 
     fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
-        for elem in self.iter() {
-            elem.encode(encoder)?;
-        }
-
-        Ok(())
+        encoder.encode_all(self.iter())
     }
 }
 
@@ -377,12 +357,17 @@ where
 /// to support heapless `no_std` targets as well as to enable bubbling up
 /// sorting errors.
 fn der_sort<T: DerOrd>(slice: &mut [T]) -> Result<()> {
-    for i in 1..=slice.len() {
-        let mut j = i - 1;
-
-        while j > 0 && slice[j - 1].der_cmp(&slice[j])? == Ordering::Greater {
-            slice.swap(j - 1, j);
-            j -= 1;
+    for i in 0..slice.len() {
+        let mut j = i;
+
+        while let (Some(prev), Some(cur)) = (j.checked_sub(1), slice.get(j)) {
+            match slice.get(prev) {
+                Some(elem) if elem.der_cmp(cur)? == Ordering::Greater => {
+                    slice.swap(prev, j);
+                    j = prev;
+                }
+                _ => break,
+            }
         }
     }
 
@@ -0,0 +1,260 @@
+//! X.509 `Time` support.
+
+use crate::{
+    asn1::{GeneralizedTime, UtcTime},
+    Choice, DateTime, Decode, Decoder, EncodeValue, Encoder, Length, Result, Tag, Tagged,
+};
+use core::{fmt, time::Duration};
+
+#[cfg(feature = "std")]
+use {crate::Error, std::time::SystemTime};
+
+/// X.509 `Time` as defined in [RFC 5280 Section 4.1.2.5].
+///
+/// ```text
+/// Time ::= CHOICE {
+///      utcTime        UTCTime,
+///      generalTime    GeneralizedTime
+/// }
+/// ```
+///
+/// RFC 5280 requires dates through the year 2049 to be encoded as `UTCTime`
+/// and dates in 2050 or later as `GeneralizedTime`. [`Time::from_date_time`]
+/// and the conversions from [`DateTime`] and `SystemTime` pick the encoding
+/// accordingly.
+///
+/// [RFC 5280 Section 4.1.2.5]: https://tools.ietf.org/html/rfc5280#section-4.1.2.5
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Time {
+    /// Legacy UTC time (has 2-digit year, valid only through 2049).
+    UtcTime(UtcTime),
+
+    /// Modern [`GeneralizedTime`] encoding with 4-digit year.
+    GeneralTime(GeneralizedTime),
+}
+
+impl Time {
+    /// `99991231235959Z`: the `GeneralizedTime` value RFC 5280 prescribes for
+    /// the `notAfter` of a certificate with no well-defined expiration date.
+    pub const INFINITY: Self = Time::GeneralTime(GeneralizedTime::INFINITY);
+
+    /// Create a [`Time`] from a [`DateTime`] using the encoding required by
+    /// RFC 5280: `UTCTime` through the year 2049 and `GeneralizedTime` for
+    /// dates in 2050 or later.
+    pub fn from_date_time(datetime: DateTime) -> Self {
+        match UtcTime::from_date_time(datetime) {
+            Ok(t) => Time::UtcTime(t),
+            Err(_) => Time::GeneralTime(GeneralizedTime::from_date_time(datetime)),
+        }
+    }
+
+    /// Create a [`Time`] given a [`Duration`] since `UNIX_EPOCH`
+    /// (a.k.a. "Unix time"), using the encoding required by RFC 5280.
+    pub fn from_unix_duration(unix_duration: Duration) -> Result<Self> {
+        DateTime::from_unix_duration(unix_duration).map(Self::from_date_time)
+    }
+
+    /// Get duration since `UNIX_EPOCH`.
+    pub fn to_unix_duration(self) -> Duration {
+        match self {
+            Time::UtcTime(t) => t.to_unix_duration(),
+            Time::GeneralTime(t) => t.to_unix_duration(),
+        }
+    }
+
+    /// Get Time as DateTime
+    pub fn to_date_time(&self) -> DateTime {
+        match self {
+            Time::UtcTime(t) => t.to_date_time(),
+            Time::GeneralTime(t) => t.to_date_time(),
+        }
+    }
+
+    /// Instantiate from [`SystemTime`], using the encoding required by
+    /// RFC 5280.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn from_system_time(time: SystemTime) -> Result<Self> {
+        DateTime::try_from(time).map(Self::from_date_time)
+    }
+
+    /// Convert to [`SystemTime`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn to_system_time(&self) -> SystemTime {
+        match self {
+            Time::UtcTime(t) => t.to_system_time(),
+            Time::GeneralTime(t) => t.to_system_time(),
+        }
+    }
+
+    /// Convert to [`GeneralizedTime`], which can represent any [`Time`].
+    pub fn to_general_time(&self) -> GeneralizedTime {
+        match self {
+            Time::UtcTime(t) => GeneralizedTime::from_date_time(t.to_date_time()),
+            Time::GeneralTime(t) => *t,
+        }
+    }
+
+    /// Convert to [`UtcTime`].
+    ///
+    /// Returns an error for dates in 2050 or later, which can't be represented
+    /// as a `UTCTime` according to RFC 5280.
+    pub fn to_utc_time(&self) -> Result<UtcTime> {
+        match self {
+            Time::UtcTime(t) => Ok(*t),
+            Time::GeneralTime(t) => UtcTime::from_date_time(t.to_date_time()),
+        }
+    }
+
+    /// Is this the "no well-defined expiration date" value
+    /// (i.e. [`Time::INFINITY`])?
+    ///
+    /// This compares the date and time only, so a non-conforming `UTCTime`
+    /// can never be infinite.
+    pub fn is_infinity(&self) -> bool {
+        self.to_date_time() == DateTime::INFINITY
+    }
+}
+
+impl<'a> Choice<'a> for Time {
+    fn can_decode(tag: Tag) -> bool {
+        matches!(tag, Tag::UtcTime | Tag::GeneralizedTime)
+    }
+}
+
+impl<'a> Decode<'a> for Time {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        match decoder.peek_tag()? {
+            Tag::UtcTime => decoder.decode().map(Time::UtcTime),
+            Tag::GeneralizedTime => decoder.decode().map(Time::GeneralTime),
+            tag => Err(tag.unexpected_error(None)),
+        }
+    }
+}
+
+impl EncodeValue for Time {
+    fn value_len(&self) -> Result<Length> {
+        match self {
+            Time::UtcTime(t) => t.value_len(),
+            Time::GeneralTime(t) => t.value_len(),
+        }
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        match self {
+            Time::UtcTime(t) => t.encode_value(encoder),
+            Time::GeneralTime(t) => t.encode_value(encoder),
+        }
+    }
+}
+
+impl Tagged for Time {
+    fn tag(&self) -> Tag {
+        match self {
+            Time::UtcTime(_) => Tag::UtcTime,
+            Time::GeneralTime(_) => Tag::GeneralizedTime,
+        }
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_date_time())
+    }
+}
+
+impl From<UtcTime> for Time {
+    fn from(time: UtcTime) -> Time {
+        Time::UtcTime(time)
+    }
+}
+
+impl From<GeneralizedTime> for Time {
+    fn from(time: GeneralizedTime) -> Time {
+        Time::GeneralTime(time)
+    }
+}
+
+impl From<DateTime> for Time {
+    fn from(datetime: DateTime) -> Time {
+        Time::from_date_time(datetime)
+    }
+}
+
+impl From<Time> for DateTime {
+    fn from(time: Time) -> DateTime {
+        time.to_date_time()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl From<Time> for SystemTime {
+    fn from(time: Time) -> SystemTime {
+        time.to_system_time()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl From<&Time> for SystemTime {
+    fn from(time: &Time) -> SystemTime {
+        time.to_system_time()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl TryFrom<SystemTime> for Time {
+    type Error = Error;
+
+    fn try_from(time: SystemTime) -> Result<Time> {
+        Time::from_system_time(time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Time;
+    use crate::{asn1::GeneralizedTime, DateTime, Decode, Encode};
+    use hex_literal::hex;
+
+    #[test]
+    fn rfc5280_encoding() {
+        let time = Time::from(DateTime::new(2049, 12, 31, 23, 59, 59).unwrap());
+        assert!(matches!(time, Time::UtcTime(_)));
+        assert_eq!(
+            time.encode_to_slice(&mut [0u8; 32]).unwrap(),
+            hex!("17 0d 34 39 31 32 33 31 32 33 35 39 35 39 5a")
+        );
+
+        let time = Time::from(DateTime::new(2050, 1, 1, 0, 0, 0).unwrap());
+        assert!(matches!(time, Time::GeneralTime(_)));
+        let mut buf = [0u8; 32];
+        let encoded = time.encode_to_slice(&mut buf).unwrap();
+        assert_eq!(
+            encoded,
+            hex!("18 0f 32 30 35 30 30 31 30 31 30 30 30 30 30 30 5a")
+        );
+        assert_eq!(Time::from_der(encoded).unwrap(), time);
+    }
+
+    #[test]
+    fn infinity() {
+        let example_bytes = hex!("18 0f 39 39 39 39 31 32 33 31 32 33 35 39 35 39 5a");
+        assert_eq!(
+            Time::INFINITY.encode_to_slice(&mut [0u8; 32]).unwrap(),
+            example_bytes
+        );
+        assert_eq!(
+            GeneralizedTime::from_der(&example_bytes).unwrap(),
+            GeneralizedTime::INFINITY
+        );
+
+        let time = Time::from_der(&example_bytes).unwrap();
+        assert_eq!(time, Time::INFINITY);
+        assert!(time.is_infinity());
+        assert!(!Time::from(DateTime::new(2049, 12, 31, 23, 59, 59).unwrap()).is_infinity());
+    }
+}
@@ -4,14 +4,17 @@ use crate::{
     asn1::Any,
     datetime::{self, DateTime},
     ord::OrdIsValueOrd,
-    ByteSlice, DecodeValue, Decoder, EncodeValue, Encoder, Error, FixedTag, Header, Length, Result,
-    Tag,
+    ByteSlice, Decode, DecodeValue, Decoder, EncodeValue, Encoder, Error, FixedTag, Header, Length,
+    LenientDateTime, Result, Tag,
 };
 use core::time::Duration;
 
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
+#[cfg(feature = "chrono")]
+use chrono::{NaiveDateTime, Utc};
+
 /// Maximum year that can be represented as a `UTCTime`.
 pub const MAX_YEAR: u16 = 2049;
 
@@ -37,6 +40,10 @@ impl UtcTime {
     /// Length of an RFC 5280-flavored ASN.1 DER-encoded [`UtcTime`].
     pub const LENGTH: Length = Length::new(13);
 
+    /// Century pivot specified in RFC 5280: two-digit years less than this
+    /// value are interpreted as `20YY`, all others as `19YY`.
+    pub const RFC5280_PIVOT: u8 = 50;
+
     /// Create a [`UtcTime`] from a [`DateTime`].
     pub fn from_date_time(datetime: DateTime) -> Result<Self> {
         if datetime.year() <= MAX_YEAR {
@@ -77,33 +84,73 @@ impl UtcTime {
     pub fn to_system_time(&self) -> SystemTime {
         self.0.to_system_time()
     }
+
+    /// Decode a `UTCTime` using a custom century pivot: two-digit years less
+    /// than `pivot` are interpreted as `20YY`, all others as `19YY`.
+    ///
+    /// This is intended for interop with legacy systems which use a different
+    /// window than [`UtcTime::RFC5280_PIVOT`], which is always used when
+    /// decoding a [`UtcTime`]. The resulting year may be outside of the range
+    /// a [`UtcTime`] can represent, so a [`DateTime`] is returned instead.
+    pub fn decode_with_pivot(decoder: &mut Decoder<'_>, pivot: u8) -> Result<DateTime> {
+        let header = Header::decode(decoder)?;
+        header.tag.assert_eq(Self::TAG)?;
+        decode_date_time(decoder, header, pivot)
+    }
+
+    /// Leniently decode a `UTCTime` which may not conform to RFC 5280, e.g.
+    /// legacy BER which includes a UTC offset (`+0100`) or omits the seconds,
+    /// normalizing it to UTC.
+    ///
+    /// Use [`LenientDateTime::is_normalized`] to check whether the original
+    /// value was RFC 5280-conformant: decoding a [`UtcTime`] is always strict.
+    /// Normalizing may move the date outside of the range a [`UtcTime`] can
+    /// represent, so a [`LenientDateTime`] is returned.
+    pub fn decode_lenient(decoder: &mut Decoder<'_>) -> Result<LenientDateTime> {
+        let header = Header::decode(decoder)?;
+        header.tag.assert_eq(Self::TAG)?;
+        let policy = decoder.leap_second_policy();
+        let bytes = ByteSlice::decode_value(decoder, header)?;
+        datetime::decode_lenient(
+            Self::TAG,
+            bytes.as_bytes(),
+            Some(Self::RFC5280_PIVOT),
+            policy,
+        )
+    }
+}
+
+/// Decode the value of a `UTCTime` as a [`DateTime`] using the given pivot.
+fn decode_date_time(decoder: &mut Decoder<'_>, header: Header, pivot: u8) -> Result<DateTime> {
+    let policy = decoder.leap_second_policy();
+
+    match *ByteSlice::decode_value(decoder, header)?.as_bytes() {
+        // RFC 5280 requires mandatory seconds and Z-normalized time zone
+        [year1, year2, mon1, mon2, day1, day2, hour1, hour2, min1, min2, sec1, sec2, b'Z'] => {
+            let year = datetime::decode_decimal(UtcTime::TAG, year1, year2)?;
+            let month = datetime::decode_decimal(UtcTime::TAG, mon1, mon2)?;
+            let day = datetime::decode_decimal(UtcTime::TAG, day1, day2)?;
+            let hour = datetime::decode_decimal(UtcTime::TAG, hour1, hour2)?;
+            let minute = datetime::decode_decimal(UtcTime::TAG, min1, min2)?;
+            let second = datetime::decode_decimal(UtcTime::TAG, sec1, sec2)?;
+
+            let year = if year >= pivot {
+                year as u16 + 1900
+            } else {
+                year as u16 + 2000
+            };
+
+            DateTime::new_with_leap_second_policy(year, month, day, hour, minute, second, policy)
+                .map_err(|_| UtcTime::TAG.value_error())
+        }
+        _ => Err(UtcTime::TAG.value_error()),
+    }
 }
 
 impl DecodeValue<'_> for UtcTime {
     fn decode_value(decoder: &mut Decoder<'_>, header: Header) -> Result<Self> {
-        match *ByteSlice::decode_value(decoder, header)?.as_bytes() {
-            // RFC 5280 requires mandatory seconds and Z-normalized time zone
-            [year1, year2, mon1, mon2, day1, day2, hour1, hour2, min1, min2, sec1, sec2, b'Z'] => {
-                let year = datetime::decode_decimal(Self::TAG, year1, year2)?;
-                let month = datetime::decode_decimal(Self::TAG, mon1, mon2)?;
-                let day = datetime::decode_decimal(Self::TAG, day1, day2)?;
-                let hour = datetime::decode_decimal(Self::TAG, hour1, hour2)?;
-                let minute = datetime::decode_decimal(Self::TAG, min1, min2)?;
-                let second = datetime::decode_decimal(Self::TAG, sec1, sec2)?;
-
-                // RFC 5280 rules for interpreting the year
-                let year = if year >= 50 {
-                    year as u16 + 1900
-                } else {
-                    year as u16 + 2000
-                };
-
-                DateTime::new(year, month, day, hour, minute, second)
-                    .map_err(|_| Self::TAG.value_error())
-                    .and_then(|dt| Self::from_unix_duration(dt.unix_duration()))
-            }
-            _ => Err(Self::TAG.value_error()),
-        }
+        decode_date_time(decoder, header, Self::RFC5280_PIVOT)
+            .and_then(|dt| Self::from_unix_duration(dt.unix_duration()))
     }
 }
 
@@ -177,6 +224,48 @@ impl From<UtcTime> for SystemTime {
     }
 }
 
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl TryFrom<NaiveDateTime> for UtcTime {
+    type Error = Error;
+
+    fn try_from(time: NaiveDateTime) -> Result<UtcTime> {
+        DateTime::try_from(time)
+            .map_err(|_| Self::TAG.value_error())?
+            .try_into()
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl TryFrom<UtcTime> for NaiveDateTime {
+    type Error = Error;
+
+    fn try_from(time: UtcTime) -> Result<NaiveDateTime> {
+        time.to_date_time().try_into()
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl TryFrom<chrono::DateTime<Utc>> for UtcTime {
+    type Error = Error;
+
+    fn try_from(time: chrono::DateTime<Utc>) -> Result<UtcTime> {
+        time.naive_utc().try_into()
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl TryFrom<UtcTime> for chrono::DateTime<Utc> {
+    type Error = Error;
+
+    fn try_from(time: UtcTime) -> Result<chrono::DateTime<Utc>> {
+        time.to_date_time().try_into()
+    }
+}
+
 impl TryFrom<Any<'_>> for UtcTime {
     type Error = Error;
 
@@ -188,7 +277,7 @@ impl TryFrom<Any<'_>> for UtcTime {
 #[cfg(test)]
 mod tests {
     use super::UtcTime;
-    use crate::{Decode, Encode, Encoder};
+    use crate::{DateTime, Decode, Decoder, Encode, Encoder, LeapSecondPolicy};
     use hex_literal::hex;
 
     #[test]
@@ -202,4 +291,80 @@ mod tests {
         utc_time.encode(&mut encoder).unwrap();
         assert_eq!(example_bytes, encoder.finish().unwrap());
     }
+
+    #[test]
+    fn decode_with_pivot() {
+        // 2060-05-06T23:45:40Z or 1960-05-06T23:45:40Z
+        let example_bytes = hex!("17 0d 36 30 30 35 30 36 32 33 34 35 34 30 5a");
+
+        let datetime =
+            UtcTime::decode_with_pivot(&mut Decoder::new(&example_bytes).unwrap(), 70).unwrap();
+        assert_eq!(datetime.year(), 2060);
+        assert_eq!(datetime.unix_duration().as_secs(), 2851112740);
+
+        // Years before 1970 can't be represented as a `DateTime`
+        assert!(UtcTime::decode_with_pivot(
+            &mut Decoder::new(&example_bytes).unwrap(),
+            UtcTime::RFC5280_PIVOT
+        )
+        .is_err());
+
+        // 1991-05-06T23:45:40Z is unaffected by the pivot
+        let example_bytes = hex!("17 0d 39 31 30 35 30 36 32 33 34 35 34 30 5a");
+        let datetime =
+            UtcTime::decode_with_pivot(&mut Decoder::new(&example_bytes).unwrap(), 70).unwrap();
+        assert_eq!(
+            datetime,
+            UtcTime::from_der(&example_bytes).unwrap().to_date_time()
+        );
+    }
+
+    #[test]
+    fn leap_second_policy() {
+        // 2016-12-31T23:59:60Z
+        let example_bytes = hex!("17 0d 31 36 31 32 33 31 32 33 35 39 36 30 5a");
+        assert!(UtcTime::from_der(&example_bytes).is_err());
+
+        let mut decoder = Decoder::new(&example_bytes).unwrap();
+        decoder.set_leap_second_policy(LeapSecondPolicy::Clamp);
+        let utc_time = UtcTime::decode(&mut decoder).unwrap();
+        assert_eq!(
+            utc_time.to_date_time(),
+            DateTime::new(2016, 12, 31, 23, 59, 59).unwrap()
+        );
+
+        let mut decoder = Decoder::new(&example_bytes).unwrap();
+        decoder.set_leap_second_policy(LeapSecondPolicy::Carry);
+        let datetime = UtcTime::decode_with_pivot(&mut decoder, UtcTime::RFC5280_PIVOT).unwrap();
+        assert_eq!(datetime, DateTime::new(2017, 1, 1, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn decode_lenient() {
+        // 2049-12-31T23:30:00-0100, which is 2050-01-01T00:30:00Z
+        let example_bytes = hex!("17 0f 34 39 31 32 33 31 32 33 33 30 2d 30 31 30 30");
+        assert!(UtcTime::from_der(&example_bytes).is_err());
+
+        let mut decoder = Decoder::new(&example_bytes).unwrap();
+        let lenient = UtcTime::decode_lenient(&mut decoder).unwrap();
+        decoder.finish(()).unwrap();
+        assert!(lenient.is_normalized());
+        assert_eq!(
+            lenient.datetime(),
+            DateTime::new(2050, 1, 1, 0, 30, 0).unwrap()
+        );
+
+        // RFC 5280-conformant values aren't normalized
+        let example_bytes = hex!("17 0d 39 31 30 35 30 36 32 33 34 35 34 30 5a");
+        let lenient = UtcTime::decode_lenient(&mut Decoder::new(&example_bytes).unwrap()).unwrap();
+        assert!(!lenient.is_normalized());
+        assert_eq!(
+            lenient.datetime(),
+            UtcTime::from_der(&example_bytes).unwrap().to_date_time()
+        );
+
+        // Fractional seconds aren't permitted in a `UTCTime`
+        let example_bytes = hex!("17 0f 39 31 30 35 30 36 32 33 34 35 34 30 2e 35 5a");
+        assert!(UtcTime::decode_lenient(&mut Decoder::new(&example_bytes).unwrap()).is_err());
+    }
 }
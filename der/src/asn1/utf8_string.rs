@@ -7,7 +7,13 @@ use crate::{
 use core::{fmt, str};
 
 #[cfg(feature = "alloc")]
-use alloc::{borrow::ToOwned, string::String};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    string::String,
+};
+
+#[cfg(feature = "heapless")]
+use crate::ErrorKind;
 
 /// ASN.1 `UTF8String` type.
 ///
@@ -193,6 +199,106 @@ impl FixedTag for String {
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 impl OrdIsValueOrd for String {}
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> From<Utf8String<'a>> for Cow<'a, str> {
+    fn from(s: Utf8String<'a>) -> Cow<'a, str> {
+        Cow::Borrowed(s.as_str())
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> TryFrom<Any<'a>> for Cow<'a, str> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<Cow<'a, str>> {
+        Utf8String::try_from(any).map(Into::into)
+    }
+}
+
+/// Decodes as [`Cow::Borrowed`], borrowing from the input.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<'a> DecodeValue<'a> for Cow<'a, str> {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        Utf8String::decode_value(decoder, header).map(Into::into)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl EncodeValue for Cow<'_, str> {
+    fn value_len(&self) -> Result<Length> {
+        Utf8String::new(self.as_ref())?.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Utf8String::new(self.as_ref())?.encode_value(encoder)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl FixedTag for Cow<'_, str> {
+    const TAG: Tag = Tag::Utf8String;
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl OrdIsValueOrd for Cow<'_, str> {}
+
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+impl<'a, const N: usize> DecodeValue<'a> for heapless::String<N> {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        let mut s = heapless::String::new();
+        s.push_str(Utf8String::decode_value(decoder, header)?.as_str())
+            .map_err(|_| ErrorKind::Overlength)?;
+        Ok(s)
+    }
+}
+
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+impl<'a, const N: usize> TryFrom<Any<'a>> for heapless::String<N> {
+    type Error = Error;
+
+    fn try_from(any: Any<'a>) -> Result<heapless::String<N>> {
+        any.decode_into()
+    }
+}
+
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+impl<const N: usize> EncodeValue for heapless::String<N> {
+    fn value_len(&self) -> Result<Length> {
+        Utf8String::new(self)?.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        Utf8String::new(self)?.encode_value(encoder)
+    }
+}
+
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+impl<const N: usize> FixedTag for heapless::String<N> {
+    const TAG: Tag = Tag::Utf8String;
+}
+
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+impl<const N: usize> OrdIsValueOrd for heapless::String<N> {}
+
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+impl<'a> arbitrary::Arbitrary<'a> for Utf8String<'a> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Self::new(u.arbitrary::<&'a str>()?).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Utf8String;
@@ -214,4 +320,18 @@ mod tests {
         let utf8_string = Utf8String::from_der(example_bytes).unwrap();
         assert_eq!(utf8_string.as_str(), "Helló");
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn cow_round_trip() {
+        use crate::Encode;
+        use alloc::borrow::Cow;
+
+        let example_bytes = &[0x0c, 0x06, 0x48, 0x65, 0x6c, 0x6c, 0xc3, 0xb3];
+        let s = Cow::<'_, str>::from_der(example_bytes).unwrap();
+        assert!(matches!(s, Cow::Borrowed("Helló")));
+
+        let owned: Cow<'_, str> = Cow::Owned("Helló".into());
+        assert_eq!(owned.to_vec().unwrap(), example_bytes);
+    }
 }
@@ -0,0 +1,331 @@
+//! Conversion of BER-encoded messages to DER.
+//!
+//! Formats such as CMS and PKCS#12 are often produced by streaming encoders
+//! which emit BER, e.g. using indefinite lengths. Such messages can be
+//! converted to DER with [`to_der`], then decoded as usual.
+//!
+//! Alternatively, a [`Decoder`] can accept segmented strings (but not
+//! indefinite lengths) when decoding with [`EncodingRules::Ber`].
+//!
+//! [`EncodingRules::Ber`]: crate::EncodingRules::Ber
+
+use crate::{Decoder, ErrorKind, Length, Result, Tag};
+use alloc::vec::Vec;
+
+/// Constructed bit of an identifier octet.
+const CONSTRUCTED_FLAG: u8 = 0b100000;
+
+/// Identifier octet of the end-of-contents marker of an indefinite-length
+/// value.
+const END_OF_CONTENTS: u8 = 0x00;
+
+/// Universal tag number of `BIT STRING`.
+const BIT_STRING: u8 = 0x03;
+
+/// Universal tag number of `SET` and `SET OF`.
+const SET: u8 = 0x31;
+
+/// Convert a BER-encoded value to DER.
+///
+/// This handles the BER encodings produced by streaming CMS implementations:
+///
+/// - indefinite and non-minimal lengths are replaced by minimal definite ones
+/// - constructed (segmented) universal string types are reassembled
+/// - `SET` and `SET OF` elements are sorted
+/// - `TRUE` is encoded as `0xFF`
+///
+/// Constructed strings with an `IMPLICIT` context-specific tag can't be told
+/// apart from `EXPLICIT` tagging without the schema, so they are left as-is.
+///
+/// Like the rest of this crate, this only supports tag numbers which fit in
+/// the identifier octet (i.e. up to 30): values using the high tag number
+/// form are rejected with [`ErrorKind::TagNumberInvalid`].
+///
+/// DER is a subset of BER, so DER-encoded input is returned unchanged.
+///
+/// ```
+/// use der::{asn1::OctetString, Decode};
+/// use hex_literal::hex;
+///
+/// // Indefinite-length `OCTET STRING` in two segments
+/// let der = der::ber::to_der(&hex!("2480 0402ABCD 0401EF 0000"))?;
+/// assert_eq!(der, hex!("0403ABCDEF"));
+/// assert_eq!(OctetString::from_der(&der)?.as_bytes(), &hex!("ABCDEF"));
+/// # Ok::<(), der::Error>(())
+/// ```
+pub fn to_der(ber: &[u8]) -> Result<Vec<u8>> {
+    let mut reader = Reader { bytes: ber, pos: 0 };
+    let der = reader.element(0)?;
+
+    if reader.pos != ber.len() {
+        return Err(ErrorKind::TrailingData {
+            decoded: Length::try_from(reader.pos)?,
+            remaining: Length::try_from(ber.len() - reader.pos)?,
+        }
+        .into());
+    }
+
+    Ok(der)
+}
+
+/// Cursor over BER input.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    /// Read a single element, returning its DER encoding.
+    fn element(&mut self, depth: u32) -> Result<Vec<u8>> {
+        let tag = self.byte()?;
+
+        if tag & 0x1F == 0x1F {
+            return Err(ErrorKind::TagNumberInvalid.into());
+        }
+
+        // End-of-contents markers are consumed along with the indefinite-length
+        // value they terminate, so they can't occur anywhere else
+        if tag == END_OF_CONTENTS {
+            return Err(ErrorKind::TagUnknown { byte: tag }.into());
+        }
+
+        let length = self.length()?;
+
+        if tag & CONSTRUCTED_FLAG == 0 {
+            let len = length.ok_or_else(|| length_error(tag))?;
+            let value = self.bytes(len)?;
+
+            return match (tag, value) {
+                (0x01, [b]) if *b != 0 => Ok(encode(tag, &[0xFF])),
+                _ => Ok(encode(tag, value)),
+            };
+        }
+
+        if depth >= Decoder::DEFAULT_MAX_DEPTH {
+            return Err(ErrorKind::DepthLimit {
+                max_depth: Decoder::DEFAULT_MAX_DEPTH,
+            }
+            .into());
+        }
+
+        let mut children = Vec::new();
+
+        match length {
+            Some(len) => {
+                let mut nested = Reader {
+                    bytes: self.bytes(len)?,
+                    pos: 0,
+                };
+
+                while nested.pos < nested.bytes.len() {
+                    children.push(nested.element(depth + 1)?);
+                }
+            }
+            None => loop {
+                if self.bytes.get(self.pos..self.pos + 2) == Some(&[END_OF_CONTENTS, 0]) {
+                    self.pos += 2;
+                    break;
+                }
+
+                children.push(self.element(depth + 1)?);
+            },
+        }
+
+        if is_string_type(tag & !CONSTRUCTED_FLAG) {
+            return reassemble(tag & !CONSTRUCTED_FLAG, &children);
+        }
+
+        if tag == SET {
+            children.sort();
+        }
+
+        Ok(encode(tag, &children.concat()))
+    }
+
+    /// Read the length octets, returning `None` for the indefinite form.
+    fn length(&mut self) -> Result<Option<usize>> {
+        let first = self.byte()?;
+
+        match first {
+            0x00..=0x7F => Ok(Some(first.into())),
+            0x80 => Ok(None),
+            0x81..=0x84 => {
+                let mut len = 0usize;
+
+                for _ in 0..(first & 0x7F) {
+                    len = (len << 8) | usize::from(self.byte()?);
+                }
+
+                Ok(Some(len))
+            }
+            _ => Err(ErrorKind::Overlength.into()),
+        }
+    }
+
+    /// Read a single byte.
+    fn byte(&mut self) -> Result<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    /// Read `len` bytes.
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        let remaining = self.bytes.len() - self.pos;
+
+        if len > remaining {
+            return Err(ErrorKind::Incomplete {
+                expected_len: Length::try_from(self.pos.saturating_add(len))?,
+                actual_len: Length::try_from(self.bytes.len())?,
+            }
+            .into());
+        }
+
+        let bytes = &self.bytes[self.pos..][..len];
+        self.pos += len;
+        Ok(bytes)
+    }
+}
+
+/// Is the given (primitive) universal tag a string type which BER allows to
+/// be segmented?
+fn is_string_type(tag: u8) -> bool {
+    matches!(
+        tag,
+        0x03 | 0x04 | 0x0C | 0x12 | 0x13 | 0x14 | 0x16 | 0x1A | 0x1C | 0x1E
+    )
+}
+
+/// Reassemble the DER-encoded segments of a constructed string as a single
+/// primitive string with the given tag.
+fn reassemble(tag: u8, segments: &[Vec<u8>]) -> Result<Vec<u8>> {
+    let mut value = Vec::new();
+    let mut unused_bits = 0;
+
+    if tag == BIT_STRING {
+        value.push(0);
+    }
+
+    for segment in segments {
+        let mut reader = Reader {
+            bytes: segment,
+            pos: 0,
+        };
+
+        if reader.byte()? != tag || unused_bits != 0 {
+            return Err(length_error(tag));
+        }
+
+        let len = reader.length()?.ok_or_else(|| length_error(tag))?;
+        let mut bytes = reader.bytes(len)?;
+
+        if tag == BIT_STRING {
+            let (first, rest) = bytes.split_first().ok_or_else(|| length_error(tag))?;
+            unused_bits = *first;
+            bytes = rest;
+        }
+
+        value.extend_from_slice(bytes);
+    }
+
+    if tag == BIT_STRING {
+        value[0] = unused_bits;
+    }
+
+    Ok(encode(tag, &value))
+}
+
+/// Encode a TLV with a minimal definite length.
+fn encode(tag: u8, value: &[u8]) -> Vec<u8> {
+    let len = value.len();
+    let mut der = Vec::with_capacity(len + 6);
+    der.push(tag);
+
+    if len < 0x80 {
+        der.push(len as u8);
+    } else {
+        let octets = len.to_be_bytes();
+        let skip = octets.iter().take_while(|&&b| b == 0).count();
+        der.push(0x80 | (octets.len() - skip) as u8);
+        der.extend_from_slice(&octets[skip..]);
+    }
+
+    der.extend_from_slice(value);
+    der
+}
+
+/// Error for a value which has an invalid length or encoding.
+fn length_error(tag: u8) -> crate::Error {
+    match Tag::try_from(tag) {
+        Ok(tag) => ErrorKind::Length { tag }.into(),
+        Err(err) => err,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_der;
+    use crate::ErrorKind;
+    use hex_literal::hex;
+
+    #[test]
+    fn der_unchanged() {
+        let der = hex!("300A 0101FF 0403ABCDEF 0500");
+        assert_eq!(to_der(&der).unwrap(), der);
+    }
+
+    #[test]
+    fn indefinite_length() {
+        assert_eq!(
+            to_der(&hex!("3080 3080 020101 0000 0000")).unwrap(),
+            hex!("3005 3003 020101")
+        );
+    }
+
+    #[test]
+    fn non_minimal_length() {
+        assert_eq!(to_der(&hex!("048200 02ABCD")).unwrap(), hex!("0402ABCD"));
+    }
+
+    #[test]
+    fn segmented_strings() {
+        assert_eq!(
+            to_der(&hex!("2480 0402ABCD 2406 0401EF 040100 0000")).unwrap(),
+            hex!("0404ABCDEF00")
+        );
+        assert_eq!(
+            to_der(&hex!("2308 030200FF 030204F0")).unwrap(),
+            hex!("030304FFF0")
+        );
+
+        // Only the last segment of a `BIT STRING` may have unused bits
+        assert!(to_der(&hex!("2308 030204F0 030200FF")).is_err());
+    }
+
+    #[test]
+    fn set_sorted_and_true_normalized() {
+        assert_eq!(
+            to_der(&hex!("3106 020102 020101")).unwrap(),
+            hex!("3106 020101 020102")
+        );
+        assert_eq!(to_der(&hex!("010101")).unwrap(), hex!("0101FF"));
+    }
+
+    #[test]
+    fn errors() {
+        // Missing end-of-contents
+        assert!(to_der(&hex!("3080 020101")).is_err());
+
+        // Primitive value with an indefinite length
+        assert!(to_der(&hex!("0480 0000")).is_err());
+
+        // End-of-contents outside of an indefinite-length value
+        assert!(to_der(&hex!("0000")).is_err());
+        assert!(to_der(&hex!("3005 020101 0000")).is_err());
+
+        // High tag number form
+        let err = to_der(&hex!("1F2001 00")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TagNumberInvalid);
+
+        let err = to_der(&hex!("020101 00")).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::TrailingData { .. }));
+    }
+}
@@ -2,8 +2,8 @@
 //! library-level length limitation i.e. `Length::max()`.
 
 use crate::{
-    str_slice::StrSlice, DecodeValue, Decoder, DerOrd, EncodeValue, Encoder, Error, Header, Length,
-    Result,
+    str_slice::StrSlice, DecodeValue, Decoder, DerOrd, EncodeValue, Encoder, Error, ErrorKind,
+    Header, Length, Result,
 };
 use core::cmp::Ordering;
 
@@ -49,6 +49,30 @@ impl<'a> ByteSlice<'a> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'a> ByteSlice<'a> {
+    /// Deserialize a [`ByteSlice`] borrowed from the input of a binary format.
+    ///
+    /// Human-readable formats encode bytes as hex, which can't be borrowed:
+    /// the owned types (e.g. [`OwnedAny`][`crate::asn1::OwnedAny`]) must be
+    /// used to deserialize from them instead.
+    pub fn deserialize_borrowed<'de: 'a, D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: crypto_serde::serde::Deserializer<'de>,
+    {
+        use crypto_serde::serde::{de::Error as _, Deserialize};
+
+        if deserializer.is_human_readable() {
+            return Err(D::Error::custom(
+                "borrowed DER types can only be deserialized from binary formats: use the owned types instead",
+            ));
+        }
+
+        <&'de [u8]>::deserialize(deserializer)
+            .and_then(|bytes| Self::new(bytes).map_err(D::Error::custom))
+    }
+}
+
 impl AsRef<[u8]> for ByteSlice<'_> {
     fn as_ref(&self) -> &[u8] {
         self.as_bytes()
@@ -57,6 +81,14 @@ impl AsRef<[u8]> for ByteSlice<'_> {
 
 impl<'a> DecodeValue<'a> for ByteSlice<'a> {
     fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        // Constructed (i.e. segmented) strings can't be borrowed as-is
+        if header.constructed != header.tag.is_constructed() {
+            return Err(decoder.error(ErrorKind::TagForm {
+                tag: header.tag,
+                constructed: header.constructed,
+            }));
+        }
+
         decoder.bytes(header.length).and_then(Self::new)
     }
 }
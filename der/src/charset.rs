@@ -0,0 +1,95 @@
+//! Character set validation for ASN.1 string types.
+//!
+//! Input is processed in fixed-size chunks using branch-free checks, which
+//! allows the compiler to vectorize the inner loops without resorting to
+//! `unsafe` code or target-specific intrinsics.
+//!
+//! UTF-8 validation is left to [`core::str::from_utf8`], which already
+//! includes a word-at-a-time fast path for ASCII input.
+
+/// Number of bytes checked per iteration of the chunked loops.
+const CHUNK_SIZE: usize = 16;
+
+/// Are all of the given bytes within the IA5 (i.e. 7-bit ASCII) character set?
+pub(crate) fn is_ia5(bytes: &[u8]) -> bool {
+    // `<[u8]>::is_ascii` already operates a word at a time
+    bytes.is_ascii()
+}
+
+/// Are all of the given bytes within the `PrintableString` character set?
+pub(crate) fn is_printable(bytes: &[u8]) -> bool {
+    let mut chunks = bytes.chunks_exact(CHUNK_SIZE);
+
+    for chunk in chunks.by_ref() {
+        if !chunk
+            .iter()
+            .fold(true, |acc, &c| acc & is_printable_char(c))
+        {
+            return false;
+        }
+    }
+
+    chunks.remainder().iter().all(|&c| is_printable_char(c))
+}
+
+/// Branch-free check of whether a byte is in the `PrintableString` set.
+///
+/// Uses only comparisons and bitwise operations so it can be evaluated on
+/// many bytes at once.
+#[inline(always)]
+fn is_printable_char(c: u8) -> bool {
+    let alpha = (c | 0x20).wrapping_sub(b'a') < 26;
+    let digit = c.wrapping_sub(b'0') < 10;
+    let quote_parens = c.wrapping_sub(b'\'') < 3; // `'`, `(`, `)`
+    let plus_to_slash = c.wrapping_sub(b'+') < 5; // `+`, `,`, `-`, `.`, `/`
+
+    alpha
+        | digit
+        | quote_parens
+        | plus_to_slash
+        | (c == b' ')
+        | (c == b':')
+        | (c == b'=')
+        | (c == b'?')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_ia5, is_printable, CHUNK_SIZE};
+
+    /// Reference implementation of the `PrintableString` character set.
+    fn reference(c: u8) -> bool {
+        c.is_ascii_alphanumeric() || b" '()+,-./:=?".contains(&c)
+    }
+
+    #[test]
+    fn printable_matches_reference() {
+        for c in 0..=u8::MAX {
+            assert_eq!(is_printable(&[c]), reference(c), "byte {:#04x}", c);
+        }
+    }
+
+    #[test]
+    fn printable_chunked() {
+        let mut input = [b'A'; CHUNK_SIZE * 4 + 3];
+        assert!(is_printable(&input));
+
+        // Invalid byte at each position, including in the remainder
+        for i in 0..input.len() {
+            input[i] = b'*';
+            assert!(!is_printable(&input));
+            input[i] = 0xC1;
+            assert!(!is_printable(&input));
+            input[i] = b'A';
+        }
+    }
+
+    #[test]
+    fn ia5() {
+        assert!(is_ia5(b"user@example.com\x00\x7f"));
+        let mut input = [b'a'; 100];
+        assert!(is_ia5(&input));
+        input[99] = 0x80;
+        assert!(!is_ia5(&input));
+    }
+}
@@ -8,9 +8,15 @@
 use crate::{Encoder, Error, ErrorKind, Result, Tag};
 use core::{fmt, str::FromStr, time::Duration};
 
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
 #[cfg(feature = "std")]
 use std::time::{SystemTime, UNIX_EPOCH};
 
+#[cfg(feature = "chrono")]
+use chrono::{Datelike, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc};
+
 #[cfg(feature = "time")]
 use time::PrimitiveDateTime;
 
@@ -23,6 +29,50 @@ const MIN_YEAR: u16 = 1970;
 /// This corresponds to: 9999-12-31T23:59:59Z
 const MAX_UNIX_DURATION: Duration = Duration::from_secs(253_402_300_799);
 
+/// Policy for handling leap seconds, i.e. a seconds value of `60`, when
+/// constructing a [`DateTime`] or decoding an ASN.1 time value.
+///
+/// [`DateTime`] can't represent leap seconds, so by default they're rejected.
+/// Tooling which emits timestamps like `...235960Z` can be accommodated by
+/// opting into a lenient policy, e.g. on a [`Decoder`][`crate::Decoder`]
+/// used to decode an entire certificate:
+///
+/// ```
+/// use der::{asn1::GeneralizedTime, Decode, Decoder, LeapSecondPolicy};
+///
+/// // GeneralizedTime: 2016-12-31T23:59:60Z
+/// let bytes = b"\x18\x0f20161231235960Z";
+/// assert!(GeneralizedTime::from_der(bytes).is_err());
+///
+/// let mut decoder = Decoder::new(bytes).unwrap();
+/// decoder.set_leap_second_policy(LeapSecondPolicy::Carry);
+/// let time = GeneralizedTime::decode(&mut decoder).unwrap();
+/// decoder.finish(()).unwrap();
+///
+/// assert_eq!(time.to_date_time().to_string(), "2017-01-01T00:00:00Z");
+/// ```
+///
+/// Note that either lenient policy changes the value, so re-encoding it won't
+/// reproduce the original encoding.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum LeapSecondPolicy {
+    /// Reject leap seconds as an invalid date/time (default).
+    Reject,
+
+    /// Clamp leap seconds to `59`, i.e. `23:59:60` becomes `23:59:59`.
+    Clamp,
+
+    /// Carry leap seconds into the next minute, i.e. `23:59:60` becomes
+    /// `00:00:00` of the following day.
+    Carry,
+}
+
+impl Default for LeapSecondPolicy {
+    fn default() -> Self {
+        Self::Reject
+    }
+}
+
 /// Date-and-time type shared by multiple ASN.1 types
 /// (e.g. `GeneralizedTime`, `UTCTime`).
 ///
@@ -56,6 +106,20 @@ pub struct DateTime {
 }
 
 impl DateTime {
+    /// Latest [`DateTime`] which can be represented: 9999-12-31T23:59:59Z.
+    ///
+    /// RFC 5280 uses this value to indicate that a certificate has no
+    /// well-defined expiration date.
+    pub const INFINITY: Self = Self {
+        year: 9999,
+        month: 12,
+        day: 31,
+        hour: 23,
+        minutes: 59,
+        seconds: 59,
+        unix_duration: MAX_UNIX_DURATION,
+    };
+
     /// Create a new [`DateTime`] from the given UTC time components.
     pub fn new(year: u16, month: u8, day: u8, hour: u8, minutes: u8, seconds: u8) -> Result<Self> {
         // Basic validation of the components.
@@ -120,6 +184,32 @@ impl DateTime {
         })
     }
 
+    /// Create a new [`DateTime`] from the given UTC time components, handling
+    /// a `seconds` value of `60` according to the given [`LeapSecondPolicy`].
+    pub fn new_with_leap_second_policy(
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minutes: u8,
+        seconds: u8,
+        policy: LeapSecondPolicy,
+    ) -> Result<Self> {
+        match (seconds, policy) {
+            (60, LeapSecondPolicy::Clamp) => Self::new(year, month, day, hour, minutes, 59),
+            (60, LeapSecondPolicy::Carry) => {
+                let datetime = Self::new(year, month, day, hour, minutes, 59)?;
+                let unix_duration = datetime
+                    .unix_duration
+                    .checked_add(Duration::from_secs(1))
+                    .ok_or(ErrorKind::DateTime)?;
+
+                Self::from_unix_duration(unix_duration)
+            }
+            _ => Self::new(year, month, day, hour, minutes, seconds),
+        }
+    }
+
     /// Compute a [`DateTime`] from the given [`Duration`] since the `UNIX_EPOCH`.
     ///
     /// Returns `None` if the value is outside the supported date range.
@@ -234,6 +324,121 @@ impl DateTime {
         self.unix_duration
     }
 
+    /// Add the given [`Duration`] to this [`DateTime`], discarding any
+    /// fractional seconds.
+    ///
+    /// Returns `None` if the result is outside the supported date range.
+    pub fn checked_add(&self, duration: Duration) -> Option<Self> {
+        let secs = self
+            .unix_duration
+            .as_secs()
+            .checked_add(duration.as_secs())?;
+        Self::from_unix_duration(Duration::from_secs(secs)).ok()
+    }
+
+    /// Subtract the given [`Duration`] from this [`DateTime`], rounding any
+    /// fractional seconds up (i.e. towards this [`DateTime`]).
+    ///
+    /// Returns `None` if the result is outside the supported date range.
+    pub fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        let mut secs = duration.as_secs();
+
+        if duration.subsec_nanos() != 0 {
+            secs = secs.checked_add(1)?;
+        }
+
+        let secs = self.unix_duration.as_secs().checked_sub(secs)?;
+        Self::from_unix_duration(Duration::from_secs(secs)).ok()
+    }
+
+    /// Compute the [`Duration`] elapsed from an `earlier` [`DateTime`] to
+    /// this one.
+    ///
+    /// Returns an error if `earlier` is later than this [`DateTime`].
+    pub fn duration_since(&self, earlier: &DateTime) -> Result<Duration> {
+        self.unix_duration
+            .checked_sub(earlier.unix_duration)
+            .ok_or_else(|| ErrorKind::DateTime.into())
+    }
+
+    /// Parse an [RFC 3339] timestamp, e.g. `2001-01-02T12:13:14Z` or
+    /// `2001-01-02T13:13:14.5+01:00`, normalizing it to UTC.
+    ///
+    /// Fractional seconds are discarded, and leap seconds are rejected.
+    /// [`FromStr`] only accepts the Z-normalized form without fractional
+    /// seconds produced by [`DateTime::to_rfc3339`].
+    ///
+    /// [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339#section-5.6
+    pub fn from_rfc3339(s: &str) -> Result<Self> {
+        let mut rest = s.as_bytes();
+
+        let year = rfc3339_decimal(&mut rest)? as u16 * 100 + rfc3339_decimal(&mut rest)? as u16;
+        rfc3339_separator(&mut rest, b"-")?;
+        let month = rfc3339_decimal(&mut rest)?;
+        rfc3339_separator(&mut rest, b"-")?;
+        let day = rfc3339_decimal(&mut rest)?;
+        rfc3339_separator(&mut rest, b"Tt ")?;
+        let hour = rfc3339_decimal(&mut rest)?;
+        rfc3339_separator(&mut rest, b":")?;
+        let minutes = rfc3339_decimal(&mut rest)?;
+        rfc3339_separator(&mut rest, b":")?;
+        let seconds = rfc3339_decimal(&mut rest)?;
+
+        if let [b'.', remaining @ ..] = rest {
+            let digits = remaining.iter().take_while(|b| b.is_ascii_digit()).count();
+
+            if digits == 0 {
+                return Err(ErrorKind::DateTime.into());
+            }
+
+            rest = remaining.get(digits..).unwrap_or_default();
+        }
+
+        let datetime = Self::new(year, month, day, hour, minutes, seconds)?;
+
+        let (sign, offset) = match *rest {
+            [b'Z' | b'z'] => return Ok(datetime),
+            [sign @ (b'+' | b'-'), ref remaining @ ..] => {
+                rest = remaining;
+                let offset_hours = rfc3339_decimal(&mut rest)?;
+                rfc3339_separator(&mut rest, b":")?;
+                let offset_minutes = rfc3339_decimal(&mut rest)?;
+
+                if !rest.is_empty() || offset_hours > 23 || offset_minutes > 59 {
+                    return Err(ErrorKind::DateTime.into());
+                }
+
+                (
+                    sign,
+                    Duration::from_secs(
+                        u64::from(offset_hours) * 3600 + u64::from(offset_minutes) * 60,
+                    ),
+                )
+            }
+            _ => return Err(ErrorKind::DateTime.into()),
+        };
+
+        // Local time is ahead of UTC for positive offsets
+        if sign == b'+' {
+            datetime.checked_sub(offset)
+        } else {
+            datetime.checked_add(offset)
+        }
+        .ok_or_else(|| ErrorKind::DateTime.into())
+    }
+
+    /// Format this [`DateTime`] as a Z-normalized [RFC 3339] timestamp,
+    /// e.g. `2001-01-02T12:13:14Z`.
+    ///
+    /// This is the same format as the [`fmt::Display`] impl.
+    ///
+    /// [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339#section-5.6
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_rfc3339(&self) -> String {
+        self.to_string()
+    }
+
     /// Instantiate from [`SystemTime`].
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -352,6 +557,214 @@ impl TryFrom<PrimitiveDateTime> for DateTime {
     }
 }
 
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl TryFrom<DateTime> for NaiveDateTime {
+    type Error = Error;
+
+    fn try_from(time: DateTime) -> Result<NaiveDateTime> {
+        NaiveDate::from_ymd_opt(time.year() as i32, time.month() as u32, time.day() as u32)
+            .and_then(|date| {
+                date.and_hms_opt(
+                    time.hour() as u32,
+                    time.minutes() as u32,
+                    time.seconds() as u32,
+                )
+            })
+            .ok_or_else(|| ErrorKind::DateTime.into())
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl TryFrom<NaiveDateTime> for DateTime {
+    type Error = Error;
+
+    /// Convert a [`NaiveDateTime`] in UTC, discarding fractional seconds.
+    fn try_from(time: NaiveDateTime) -> Result<DateTime> {
+        DateTime::new(
+            u16::try_from(time.year()).map_err(|_| ErrorKind::DateTime)?,
+            time.month() as u8,
+            time.day() as u8,
+            time.hour() as u8,
+            time.minute() as u8,
+            time.second() as u8,
+        )
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl TryFrom<DateTime> for chrono::DateTime<Utc> {
+    type Error = Error;
+
+    fn try_from(time: DateTime) -> Result<chrono::DateTime<Utc>> {
+        NaiveDateTime::try_from(time).map(|time| Utc.from_utc_datetime(&time))
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl TryFrom<chrono::DateTime<Utc>> for DateTime {
+    type Error = Error;
+
+    /// Convert a [`chrono::DateTime`], discarding fractional seconds.
+    fn try_from(time: chrono::DateTime<Utc>) -> Result<DateTime> {
+        time.naive_utc().try_into()
+    }
+}
+
+/// [`DateTime`] decoded from a time value which may not conform to DER, e.g.
+/// a BER-encoded `GeneralizedTime` with a UTC offset or fractional seconds.
+///
+/// Returned by `GeneralizedTime::decode_lenient` and `UtcTime::decode_lenient`.
+/// Strict consumers can check [`LenientDateTime::is_normalized`] and reject
+/// any value which wasn't already in the form required by RFC 5280.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct LenientDateTime {
+    /// Decoded date/time, normalized to UTC.
+    datetime: DateTime,
+
+    /// Was the original value normalized to obtain `datetime`?
+    normalized: bool,
+}
+
+impl LenientDateTime {
+    /// Get the decoded [`DateTime`], normalized to UTC.
+    pub fn datetime(&self) -> DateTime {
+        self.datetime
+    }
+
+    /// Did the original value need to be normalized, i.e. did it include a
+    /// UTC offset, fractional seconds, or omit the seconds?
+    ///
+    /// Values for which this returns `false` are valid under RFC 5280.
+    pub fn is_normalized(&self) -> bool {
+        self.normalized
+    }
+}
+
+impl From<LenientDateTime> for DateTime {
+    fn from(lenient: LenientDateTime) -> DateTime {
+        lenient.datetime
+    }
+}
+
+/// Leniently decode the value of a `GeneralizedTime` (if `pivot` is `None`)
+/// or `UTCTime` (if `pivot` is `Some`), in any of the following forms:
+///
+/// - `GeneralizedTime`: `YYYYMMDDHHMM[SS[(.|,)F+]](Z|(+|-)HHMM)`
+/// - `UTCTime`: `YYMMDDHHMM[SS](Z|(+|-)HHMM)`
+pub(crate) fn decode_lenient(
+    tag: Tag,
+    bytes: &[u8],
+    pivot: Option<u8>,
+    policy: LeapSecondPolicy,
+) -> Result<LenientDateTime> {
+    let mut normalized = false;
+    let mut rest = bytes;
+
+    let decimal = |rest: &mut &[u8]| match **rest {
+        [hi, lo, ref remaining @ ..] => {
+            *rest = remaining;
+            decode_decimal(tag, hi, lo)
+        }
+        _ => Err(tag.value_error()),
+    };
+
+    let year = match pivot {
+        Some(pivot) => match decimal(&mut rest)? {
+            year if year >= pivot => year as u16 + 1900,
+            year => year as u16 + 2000,
+        },
+        None => decimal(&mut rest)? as u16 * 100 + decimal(&mut rest)? as u16,
+    };
+
+    let month = decimal(&mut rest)?;
+    let day = decimal(&mut rest)?;
+    let hour = decimal(&mut rest)?;
+    let minute = decimal(&mut rest)?;
+
+    let second = match rest.first() {
+        Some(b'0'..=b'9') => decimal(&mut rest)?,
+        _ => {
+            normalized = true;
+            0
+        }
+    };
+
+    // Fractional seconds (`GeneralizedTime` only) are discarded
+    if let (None, [b'.' | b',', remaining @ ..]) = (pivot, rest) {
+        let digits = remaining.iter().take_while(|b| b.is_ascii_digit()).count();
+
+        if digits == 0 {
+            return Err(tag.value_error());
+        }
+
+        normalized = true;
+        rest = remaining.get(digits..).unwrap_or_default();
+    }
+
+    let datetime =
+        DateTime::new_with_leap_second_policy(year, month, day, hour, minute, second, policy)
+            .map_err(|_| tag.value_error())?;
+
+    let datetime = match *rest {
+        [b'Z'] => datetime,
+        [sign @ (b'+' | b'-'), h1, h2, m1, m2] => {
+            let offset_hours = decode_decimal(tag, h1, h2)?;
+            let offset_minutes = decode_decimal(tag, m1, m2)?;
+
+            if offset_hours > 23 || offset_minutes > 59 {
+                return Err(tag.value_error());
+            }
+
+            // Local time is ahead of UTC for positive offsets
+            let offset = Duration::from_secs(
+                u64::from(offset_hours) * 3600 + u64::from(offset_minutes) * 60,
+            );
+            let unix_duration = if sign == b'+' {
+                datetime.unix_duration().checked_sub(offset)
+            } else {
+                datetime.unix_duration().checked_add(offset)
+            };
+
+            normalized = true;
+            unix_duration
+                .ok_or_else(|| tag.value_error())
+                .and_then(|d| DateTime::from_unix_duration(d).map_err(|_| tag.value_error()))?
+        }
+        _ => return Err(tag.value_error()),
+    };
+
+    Ok(LenientDateTime {
+        datetime,
+        normalized,
+    })
+}
+
+/// Parse a 2-digit decimal value from the start of an RFC 3339 timestamp.
+fn rfc3339_decimal(rest: &mut &[u8]) -> Result<u8> {
+    match **rest {
+        [hi, lo, ref remaining @ ..] => {
+            *rest = remaining;
+            decode_decimal(Tag::GeneralizedTime, hi, lo).map_err(|_| ErrorKind::DateTime.into())
+        }
+        _ => Err(ErrorKind::DateTime.into()),
+    }
+}
+
+/// Parse one of the given separators from the start of an RFC 3339 timestamp.
+fn rfc3339_separator(rest: &mut &[u8], separators: &[u8]) -> Result<()> {
+    match **rest {
+        [byte, ref remaining @ ..] if separators.contains(&byte) => {
+            *rest = remaining;
+            Ok(())
+        }
+        _ => Err(ErrorKind::DateTime.into()),
+    }
+}
+
 /// Decode 2-digit decimal value
 pub(crate) fn decode_decimal(tag: Tag, hi: u8, lo: u8) -> Result<u8> {
     if (b'0'..=b'9').contains(&hi) && (b'0'..=b'9').contains(&lo) {
@@ -373,9 +786,74 @@ pub(crate) fn encode_decimal(encoder: &mut Encoder<'_>, tag: Tag, value: u8) ->
     encoder.byte((value % 10) + b'0')
 }
 
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+impl<'a> arbitrary::Arbitrary<'a> for DateTime {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let secs = u.int_in_range(0..=MAX_UNIX_DURATION.as_secs())?;
+        Self::from_unix_duration(Duration::from_secs(secs))
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl crypto_serde::serde::Serialize for DateTime {
+    /// Serialize as an RFC 3339 string (e.g. `2001-01-02T12:13:14Z`) in
+    /// human-readable formats, or as seconds since the Unix epoch in binary
+    /// formats.
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: crypto_serde::serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_u64(self.unix_duration.as_secs())
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> crypto_serde::serde::Deserialize<'de> for DateTime {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: crypto_serde::serde::Deserializer<'de>,
+    {
+        use crypto_serde::serde::de;
+
+        /// Visitor for [`DateTime`] in either representation.
+        struct DateTimeVisitor;
+
+        impl de::Visitor<'_> for DateTimeVisitor {
+            type Value = DateTime;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an RFC 3339 date-time or seconds since the Unix epoch")
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> core::result::Result<DateTime, E> {
+                s.parse().map_err(E::custom)
+            }
+
+            fn visit_u64<E: de::Error>(self, secs: u64) -> core::result::Result<DateTime, E> {
+                DateTime::from_unix_duration(Duration::from_secs(secs)).map_err(E::custom)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(DateTimeVisitor)
+        } else {
+            deserializer.deserialize_u64(DateTimeVisitor)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::DateTime;
+    use super::{DateTime, LeapSecondPolicy};
+    use core::time::Duration;
 
     /// Ensure a day is OK
     fn is_date_valid(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> bool {
@@ -400,6 +878,115 @@ mod tests {
         assert_eq!(datetime.seconds(), 14);
     }
 
+    #[test]
+    fn arithmetic() {
+        let not_before = DateTime::new(2020, 2, 28, 12, 0, 0).unwrap();
+        let not_after = DateTime::new(2021, 3, 1, 12, 0, 0).unwrap();
+        let validity = not_after.duration_since(&not_before).unwrap();
+        assert_eq!(validity, Duration::from_secs(367 * 86400));
+        assert!(not_before.duration_since(&not_after).is_err());
+
+        assert_eq!(not_before.checked_add(validity), Some(not_after));
+        assert_eq!(not_after.checked_sub(validity), Some(not_before));
+        assert_eq!(
+            not_before.checked_add(Duration::from_millis(1999)),
+            DateTime::new(2020, 2, 28, 12, 0, 1).ok()
+        );
+        assert_eq!(
+            not_before.checked_sub(Duration::from_millis(1)),
+            DateTime::new(2020, 2, 28, 11, 59, 59).ok()
+        );
+
+        // Out of range
+        assert_eq!(DateTime::INFINITY.checked_add(Duration::from_secs(1)), None);
+        assert_eq!(not_before.checked_add(Duration::MAX), None);
+        assert_eq!(
+            not_before.checked_sub(not_before.unix_duration() + Duration::from_secs(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn leap_second_policy() {
+        let new = |policy| DateTime::new_with_leap_second_policy(2016, 12, 31, 23, 59, 60, policy);
+
+        assert!(new(LeapSecondPolicy::Reject).is_err());
+        assert!(new(LeapSecondPolicy::default()).is_err());
+        assert_eq!(
+            new(LeapSecondPolicy::Clamp).unwrap(),
+            DateTime::new(2016, 12, 31, 23, 59, 59).unwrap()
+        );
+        assert_eq!(
+            new(LeapSecondPolicy::Carry).unwrap(),
+            DateTime::new(2017, 1, 1, 0, 0, 0).unwrap()
+        );
+
+        // Other invalid seconds values are always rejected
+        assert!(DateTime::new_with_leap_second_policy(
+            2016,
+            12,
+            31,
+            23,
+            59,
+            61,
+            LeapSecondPolicy::Carry
+        )
+        .is_err());
+
+        // Carrying past the maximum representable date is an error
+        assert!(DateTime::new_with_leap_second_policy(
+            9999,
+            12,
+            31,
+            23,
+            59,
+            60,
+            LeapSecondPolicy::Carry
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn from_rfc3339() {
+        let datetime = DateTime::new(2001, 1, 2, 12, 13, 14).unwrap();
+
+        for s in [
+            "2001-01-02T12:13:14Z",
+            "2001-01-02t12:13:14z",
+            "2001-01-02 12:13:14Z",
+            "2001-01-02T12:13:14.999Z",
+            "2001-01-02T13:13:14+01:00",
+            "2001-01-02T06:43:14.5-05:30",
+            "2001-01-03T00:13:14+12:00",
+        ] {
+            assert_eq!(DateTime::from_rfc3339(s).unwrap(), datetime, "{}", s);
+        }
+
+        for s in [
+            "2001-01-02T12:13:14",
+            "2001-01-02T12:13Z",
+            "2001-01-02T12:13:14.Z",
+            "2001-01-02T12:13:14+0100",
+            "2001-01-02T12:13:14+24:00",
+            "2001-01-02T12:13:14Z ",
+            "2001-01-02T23:59:60Z",
+            "1970-01-01T00:00:00+00:01",
+        ] {
+            assert!(DateTime::from_rfc3339(s).is_err(), "{}", s);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_rfc3339() {
+        let datetime = DateTime::new(2001, 1, 2, 12, 13, 14).unwrap();
+        assert_eq!(datetime.to_rfc3339(), "2001-01-02T12:13:14Z");
+        assert_eq!(
+            DateTime::from_rfc3339(&datetime.to_rfc3339()).unwrap(),
+            datetime
+        );
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn display() {
@@ -0,0 +1,426 @@
+//! Debugging utilities for DER-encoded messages.
+
+use crate::{ByteSlice, Decoder, Error, Length, Result, Tag, Tagged, Tlv};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Maximum number of bytes of each value included in a
+/// [`DifferenceKind::Value`] excerpt.
+const EXCERPT_LEN: usize = 16;
+
+/// Compare two DER-encoded messages structurally, returning the first
+/// [`Difference`] found between them, or `None` if they're identical.
+///
+/// Both messages are walked as trees of TLV productions in parallel,
+/// descending into constructed values whose encodings differ, so the
+/// difference reported is the innermost value at which they diverge.
+///
+/// ```
+/// use der::debug::{diff, DifferenceKind};
+///
+/// // SEQUENCE { INTEGER 1, OCTET STRING 'AB'H } vs SEQUENCE { INTEGER 1, OCTET STRING 'AC'H }
+/// let a = [0x30, 0x06, 0x02, 0x01, 0x01, 0x04, 0x01, 0xAB];
+/// let b = [0x30, 0x06, 0x02, 0x01, 0x01, 0x04, 0x01, 0xAC];
+///
+/// let difference = diff(&a, &b).unwrap();
+/// assert_eq!(difference.path, &[0, 1]);
+/// assert!(matches!(difference.kind, DifferenceKind::Value { .. }));
+/// println!("{}", difference);
+///
+/// assert_eq!(diff(&a, &a), None);
+/// ```
+pub fn diff(left: &[u8], right: &[u8]) -> Option<Difference> {
+    let mut left = match Decoder::new(left) {
+        Ok(decoder) => decoder,
+        Err(error) => return Some(Difference::malformed(Vec::new(), Side::Left, error)),
+    };
+
+    let mut right = match Decoder::new(right) {
+        Ok(decoder) => decoder,
+        Err(error) => return Some(Difference::malformed(Vec::new(), Side::Right, error)),
+    };
+
+    diff_elements(&mut Vec::new(), &mut left, &mut right)
+}
+
+/// Difference between two DER-encoded messages, as found by [`diff`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Difference {
+    /// Path to the differing value, i.e. the index of the value within each
+    /// enclosing constructed value, starting with the index of the
+    /// outermost value within the message.
+    pub path: Vec<usize>,
+
+    /// Offsets of the differing value within the left and right messages.
+    pub offsets: (Length, Length),
+
+    /// Nature of the difference.
+    pub kind: DifferenceKind,
+}
+
+/// Nature of a [`Difference`] between two DER-encoded messages.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum DifferenceKind {
+    /// Values have different tags.
+    Tag {
+        /// Tag of the value in the left message.
+        left: Tag,
+
+        /// Tag of the value in the right message.
+        right: Tag,
+    },
+
+    /// Primitive values with the same tag have different contents.
+    Value {
+        /// Tag of both values.
+        tag: Tag,
+
+        /// Lengths of the left and right values.
+        lengths: (Length, Length),
+
+        /// Offset of the first differing byte within the values.
+        offset: Length,
+
+        /// Excerpt of the left value, starting at `offset`.
+        left: Vec<u8>,
+
+        /// Excerpt of the right value, starting at `offset`.
+        right: Vec<u8>,
+    },
+
+    /// One message contains a value where the other has none.
+    Extra {
+        /// Message containing the extra value.
+        side: Side,
+
+        /// Tag of the extra value.
+        tag: Tag,
+    },
+
+    /// One message could not be decoded.
+    Malformed {
+        /// Message which is malformed.
+        side: Side,
+
+        /// Error encountered when decoding it.
+        error: Error,
+    },
+}
+
+/// Which of the two messages compared by [`diff`] something applies to.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Side {
+    /// First (left) message.
+    Left,
+
+    /// Second (right) message.
+    Right,
+}
+
+impl Difference {
+    /// Create a [`Difference`] for a malformed message.
+    fn malformed(path: Vec<usize>, side: Side, error: Error) -> Self {
+        let offset = error.position().unwrap_or_default();
+
+        Self {
+            path,
+            offsets: (offset, offset),
+            kind: DifferenceKind::Malformed { side, error },
+        }
+    }
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("at ")?;
+
+        if self.path.is_empty() {
+            f.write_str("toplevel")?;
+        } else {
+            for (i, index) in self.path.iter().enumerate() {
+                write!(f, "{}{}", if i == 0 { "" } else { "." }, index)?;
+            }
+        }
+
+        write!(
+            f,
+            " (offsets {} / {}): {}",
+            self.offsets.0, self.offsets.1, self.kind
+        )
+    }
+}
+
+impl fmt::Display for DifferenceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DifferenceKind::Tag { left, right } => {
+                write!(f, "tag mismatch: {} vs {}", left, right)
+            }
+            DifferenceKind::Value {
+                tag,
+                lengths,
+                offset,
+                left,
+                right,
+            } => {
+                write!(
+                    f,
+                    "{} value mismatch (lengths {} / {}) at value byte {}: ",
+                    tag, lengths.0, lengths.1, offset
+                )?;
+                write_hex(f, left)?;
+                f.write_str(" vs ")?;
+                write_hex(f, right)
+            }
+            DifferenceKind::Extra { side, tag } => write!(f, "extra {} value in {}", tag, side),
+            DifferenceKind::Malformed { side, error } => {
+                write!(f, "{} message is malformed: {}", side, error)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Side {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Side::Left => "left",
+            Side::Right => "right",
+        })
+    }
+}
+
+/// Write an excerpt of a value as hex, or `(end)` if it's empty.
+fn write_hex(f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+    if bytes.is_empty() {
+        return f.write_str("(end)");
+    }
+
+    for byte in bytes {
+        write!(f, "{:02X}", byte)?;
+    }
+
+    Ok(())
+}
+
+/// Compare the remaining elements of two decoders, whose paths are both
+/// `path`.
+fn diff_elements<'a>(
+    path: &mut Vec<usize>,
+    left: &mut Decoder<'a>,
+    right: &mut Decoder<'a>,
+) -> Option<Difference> {
+    let mut index = 0;
+
+    loop {
+        let offsets = (left.position(), right.position());
+
+        let (left_tlv, right_tlv) = match (left.is_finished(), right.is_finished()) {
+            (true, true) => return None,
+            (false, true) => return Some(extra(path, index, offsets, Side::Left, left)),
+            (true, false) => return Some(extra(path, index, offsets, Side::Right, right)),
+            (false, false) => match (left.tlv(), right.tlv()) {
+                (Ok(l), Ok(r)) => (l, r),
+                (Err(error), _) => {
+                    return Some(Difference::malformed(path.clone(), Side::Left, error))
+                }
+                (_, Err(error)) => {
+                    return Some(Difference::malformed(path.clone(), Side::Right, error))
+                }
+            },
+        };
+
+        if left_tlv.raw() != right_tlv.raw() {
+            path.push(index);
+
+            return Some(
+                match diff_tlvs(path, offsets, left, &left_tlv, right, &right_tlv) {
+                    Ok(difference) => difference,
+                    Err((side, error)) => Difference::malformed(path.clone(), side, error),
+                },
+            );
+        }
+
+        index += 1;
+    }
+}
+
+/// Compare two TLV productions with differing encodings, found at `path`.
+fn diff_tlvs<'a>(
+    path: &mut Vec<usize>,
+    offsets: (Length, Length),
+    left: &mut Decoder<'a>,
+    left_tlv: &Tlv<'a>,
+    right: &mut Decoder<'a>,
+    right_tlv: &Tlv<'a>,
+) -> core::result::Result<Difference, (Side, Error)> {
+    let tag = left_tlv.tag();
+
+    if tag != right_tlv.tag() {
+        return Ok(Difference {
+            path: path.clone(),
+            offsets,
+            kind: DifferenceKind::Tag {
+                left: tag,
+                right: right_tlv.tag(),
+            },
+        });
+    }
+
+    if tag.is_constructed() {
+        let mut left = nested(left, left_tlv).map_err(|e| (Side::Left, e))?;
+        let mut right = nested(right, right_tlv).map_err(|e| (Side::Right, e))?;
+
+        if let Some(difference) = diff_elements(path, &mut left, &mut right) {
+            return Ok(difference);
+        }
+    }
+
+    let (left_value, right_value) = (left_tlv.value(), right_tlv.value());
+    let offset = left_value
+        .iter()
+        .zip(right_value)
+        .position(|(l, r)| l != r)
+        .unwrap_or_else(|| left_value.len().min(right_value.len()));
+
+    let excerpt = |value: &[u8]| {
+        value
+            .get(offset..)
+            .unwrap_or_default()
+            .iter()
+            .copied()
+            .take(EXCERPT_LEN)
+            .collect()
+    };
+
+    Ok(Difference {
+        path: path.clone(),
+        offsets,
+        kind: DifferenceKind::Value {
+            tag,
+            lengths: (left_tlv.header().length, right_tlv.header().length),
+            offset: Length::try_from(offset).map_err(|e| (Side::Left, e))?,
+            left: excerpt(left_value),
+            right: excerpt(right_value),
+        },
+    })
+}
+
+/// Create a [`Difference`] for an extra value at the given index.
+fn extra(
+    path: &[usize],
+    index: usize,
+    offsets: (Length, Length),
+    side: Side,
+    decoder: &Decoder<'_>,
+) -> Difference {
+    let mut path = path.to_vec();
+
+    match decoder.peek_tag() {
+        Ok(tag) => {
+            path.push(index);
+
+            Difference {
+                path,
+                offsets,
+                kind: DifferenceKind::Extra { side, tag },
+            }
+        }
+        Err(error) => Difference::malformed(path, side, error),
+    }
+}
+
+/// Create a nested decoder for the value of a constructed TLV production.
+fn nested<'a>(decoder: &mut Decoder<'a>, tlv: &Tlv<'a>) -> Result<Decoder<'a>> {
+    let offset = (tlv.end_offset()? - tlv.header().length)?;
+    decoder.nested(ByteSlice::new(tlv.value())?, offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff, DifferenceKind, Side};
+    use crate::{ErrorKind, Length, Tag};
+    use alloc::{string::ToString, vec};
+    use hex_literal::hex;
+
+    #[test]
+    fn identical() {
+        assert_eq!(diff(&hex!("3003 020101"), &hex!("3003 020101")), None);
+        assert_eq!(diff(&[], &[]), None);
+    }
+
+    #[test]
+    fn nested_value() {
+        let difference = diff(
+            &hex!("3009 0101FF 3004 0402ABCD"),
+            &hex!("300A 0101FF 3005 0403ABCE01"),
+        )
+        .unwrap();
+
+        assert_eq!(difference.path, &[0, 1, 0]);
+        assert_eq!(difference.offsets, (Length::new(7), Length::new(7)));
+        assert_eq!(
+            difference.kind,
+            DifferenceKind::Value {
+                tag: Tag::OctetString,
+                lengths: (Length::new(2), Length::new(3)),
+                offset: Length::ONE,
+                left: vec![0xCD],
+                right: vec![0xCE, 0x01],
+            }
+        );
+        assert_eq!(
+            difference.to_string(),
+            "at 0.1.0 (offsets 7 / 7): OCTET STRING value mismatch (lengths 2 / 3) \
+             at value byte 1: CD vs CE01"
+        );
+    }
+
+    #[test]
+    fn tag_mismatch() {
+        let difference = diff(&hex!("3003 020101"), &hex!("3003 0A0101")).unwrap();
+        assert_eq!(difference.path, &[0, 0]);
+        assert_eq!(
+            difference.kind,
+            DifferenceKind::Tag {
+                left: Tag::Integer,
+                right: Tag::Enumerated
+            }
+        );
+    }
+
+    #[test]
+    fn extra_element() {
+        let difference = diff(&hex!("3003 020101"), &hex!("3005 020101 0500")).unwrap();
+        assert_eq!(difference.path, &[0, 1]);
+        assert_eq!(difference.offsets, (Length::new(5), Length::new(5)));
+        assert_eq!(
+            difference.kind,
+            DifferenceKind::Extra {
+                side: Side::Right,
+                tag: Tag::Null
+            }
+        );
+        assert_eq!(
+            difference.to_string(),
+            "at 0.1 (offsets 5 / 5): extra NULL value in right"
+        );
+
+        let difference = diff(&hex!("020101 0500"), &hex!("020101")).unwrap();
+        assert_eq!(difference.path, &[1]);
+    }
+
+    #[test]
+    fn malformed() {
+        let difference = diff(&hex!("3003 020101"), &hex!("3004 020101")).unwrap();
+        assert!(difference.path.is_empty());
+
+        match difference.kind {
+            DifferenceKind::Malformed { side, error } => {
+                assert_eq!(side, Side::Right);
+                assert!(matches!(error.kind(), ErrorKind::Incomplete { .. }));
+            }
+            other => panic!("unexpected difference: {:?}", other),
+        }
+    }
+}
@@ -2,6 +2,9 @@
 
 use crate::{Decoder, FixedTag, Header, Result};
 
+#[cfg(feature = "pem")]
+use crate::pem::{self, PemLabel};
+
 #[cfg(doc)]
 use crate::{Length, Tag};
 
@@ -50,6 +53,28 @@ pub trait DecodeOwned: for<'a> Decode<'a> {}
 
 impl<T> DecodeOwned for T where T: for<'a> Decode<'a> {}
 
+/// PEM decoding trait.
+///
+/// This trait is automatically impl'd for any type which impls both
+/// [`DecodeOwned`] and [`PemLabel`].
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+pub trait DecodePem: DecodeOwned + PemLabel {
+    /// Try to decode this type from PEM, ensuring the PEM type label
+    /// matches [`PemLabel::TYPE_LABEL`].
+    fn from_pem(pem: impl AsRef<[u8]>) -> Result<Self>;
+}
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl<T: DecodeOwned + PemLabel> DecodePem for T {
+    fn from_pem(pem: impl AsRef<[u8]>) -> Result<Self> {
+        let (label, der_bytes) = pem::decode_vec(pem.as_ref())?;
+        Self::validate_pem_label(label)?;
+        Self::from_der(&der_bytes)
+    }
+}
+
 /// Decode the value part of a Tag-Length-Value encoded field, sans the [`Tag`]
 /// and [`Length`].
 pub trait DecodeValue<'a>: Sized {
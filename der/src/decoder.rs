@@ -1,11 +1,22 @@
 //! DER decoder.
 
+mod progress;
+mod tlv_iter;
+
+pub use self::{progress::Progress, tlv_iter::TlvIter};
+
+use self::progress::ProgressCallback;
 use crate::{
-    asn1::*, ByteSlice, Choice, Decode, DecodeValue, Encode, Error, ErrorKind, FixedTag, Header,
-    Length, Result, Tag, TagMode, TagNumber,
+    asn1::*, ByteSlice, Choice, Decode, DecodeValue, Encode, EncodingRules, Error, ErrorKind,
+    FixedTag, Header, LeapSecondPolicy, Length, Result, Tag, TagMode, TagNumber, Tlv,
 };
 
+#[cfg(doc)]
+use crate::Reader;
+
 /// DER decoder.
+///
+/// Decodes from a byte slice. To decode from other sources, see [`Reader`].
 #[derive(Clone, Debug)]
 pub struct Decoder<'a> {
     /// Byte slice being decoded.
@@ -21,28 +32,164 @@ pub struct Decoder<'a> {
     ///
     /// Used for nested decoding.
     offset: Length,
+
+    /// Total length of the original ASN.1 DER document.
+    total_len: Length,
+
+    /// Nesting depth of this decoder relative to the toplevel decoder.
+    depth: u32,
+
+    /// Maximum nesting depth permitted before decoding is aborted.
+    max_depth: u32,
+
+    /// Maximum number of elements permitted in a `SEQUENCE OF`/`SET OF`.
+    max_elements: Option<usize>,
+
+    /// Maximum encoded length of a `SEQUENCE OF`/`SET OF` body.
+    max_collection_len: Option<Length>,
+
+    /// Index of the element being decoded within the currently active
+    /// `SEQUENCE OF`/`SET OF` collection, if any.
+    element_index: Option<usize>,
+
+    /// Optional callback used to report decoding progress.
+    progress: Option<ProgressCallback<'a>>,
+
+    /// Handling of leap seconds when decoding time values.
+    leap_second_policy: LeapSecondPolicy,
+
+    /// Encoding rules enforced when decoding.
+    encoding_rules: EncodingRules,
+
+    /// Reject fields explicitly encoded with their `DEFAULT` value.
+    reject_encoded_defaults: bool,
 }
 
 impl<'a> Decoder<'a> {
+    /// Default maximum nesting depth of constructed values (e.g. `SEQUENCE`).
+    pub const DEFAULT_MAX_DEPTH: u32 = 128;
+
     /// Create a new decoder for the given byte slice.
     pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        let bytes = ByteSlice::new(bytes)?;
+
         Ok(Self {
-            bytes: Some(ByteSlice::new(bytes)?),
+            bytes: Some(bytes),
             position: Length::ZERO,
             offset: Length::ZERO,
+            total_len: bytes.len(),
+            depth: 0,
+            max_depth: Self::DEFAULT_MAX_DEPTH,
+            max_elements: None,
+            max_collection_len: None,
+            element_index: None,
+            progress: None,
+            leap_second_policy: LeapSecondPolicy::default(),
+            encoding_rules: EncodingRules::default(),
+            reject_encoded_defaults: false,
         })
     }
 
-    /// Create a new decoder where `bytes` begins at a specified offset within
-    /// an original ASN.1 DER document.
+    /// Create a new decoder for the given byte slice which invokes the
+    /// provided callback with the decoder's [`Progress`] each time a value
+    /// has been decoded, including values within nested `SEQUENCE`s.
+    ///
+    /// If the callback returns an error, decoding is aborted and the error
+    /// is returned to the caller.
+    pub fn new_with_progress(
+        bytes: &'a [u8],
+        callback: &'a dyn Fn(Progress) -> Result<()>,
+    ) -> Result<Self> {
+        let mut decoder = Self::new(bytes)?;
+        decoder.progress = Some(ProgressCallback(callback));
+        Ok(decoder)
+    }
+
+    /// Create a new nested decoder where `bytes` begins at a specified offset
+    /// within the original ASN.1 DER document being decoded by `self`.
     ///
     /// This is used for calculating positions when decoding nested documents.
-    pub(crate) fn new_with_offset(bytes: ByteSlice<'a>, offset: Length) -> Self {
-        Self {
+    ///
+    /// Returns an error if the maximum nesting depth would be exceeded.
+    pub(crate) fn nested(&mut self, bytes: ByteSlice<'a>, offset: Length) -> Result<Self> {
+        if self.depth >= self.max_depth {
+            return Err(self.error(ErrorKind::DepthLimit {
+                max_depth: self.max_depth,
+            }));
+        }
+
+        Ok(Self {
             bytes: Some(bytes),
             position: Length::ZERO,
             offset,
-        }
+            total_len: self.total_len,
+            depth: self.depth.saturating_add(1),
+            max_depth: self.max_depth,
+            max_elements: self.max_elements,
+            max_collection_len: self.max_collection_len,
+            element_index: None,
+            progress: self.progress,
+            leap_second_policy: self.leap_second_policy,
+            encoding_rules: self.encoding_rules,
+            reject_encoded_defaults: self.reject_encoded_defaults,
+        })
+    }
+
+    /// Set the maximum nesting depth of constructed values (e.g. `SEQUENCE`)
+    /// this decoder will accept, which defaults to [`Self::DEFAULT_MAX_DEPTH`].
+    ///
+    /// This bounds the stack usage of decoding recursive types, such as
+    /// those which contain a `Box<Self>` field.
+    pub fn set_max_depth(&mut self, max_depth: u32) {
+        self.max_depth = max_depth;
+    }
+
+    /// Set the maximum number of elements this decoder will accept in any
+    /// single `SEQUENCE OF` or `SET OF`, e.g. when decoding a `Vec<T>`.
+    ///
+    /// By default the number of elements is unbounded.
+    pub fn set_max_elements(&mut self, max_elements: usize) {
+        self.max_elements = Some(max_elements);
+    }
+
+    /// Set the maximum encoded length of the body of any single
+    /// `SEQUENCE OF` or `SET OF` this decoder will accept.
+    ///
+    /// By default the length is bounded only by the input.
+    pub fn set_max_collection_len(&mut self, max_len: Length) {
+        self.max_collection_len = Some(max_len);
+    }
+
+    /// Set the [`LeapSecondPolicy`] used when decoding `GeneralizedTime` and
+    /// `UTCTime` values, which defaults to [`LeapSecondPolicy::Reject`].
+    pub fn set_leap_second_policy(&mut self, policy: LeapSecondPolicy) {
+        self.leap_second_policy = policy;
+    }
+
+    /// Get the [`LeapSecondPolicy`] used when decoding time values.
+    pub fn leap_second_policy(&self) -> LeapSecondPolicy {
+        self.leap_second_policy
+    }
+
+    /// Set the [`EncodingRules`] enforced by this decoder, which defaults to
+    /// [`EncodingRules::Der`].
+    pub fn set_encoding_rules(&mut self, encoding_rules: EncodingRules) {
+        self.encoding_rules = encoding_rules;
+    }
+
+    /// Set whether fields with a `DEFAULT` which are explicitly encoded with
+    /// that value are rejected when decoding with [`EncodingRules::Der`].
+    ///
+    /// DER requires such values to be omitted, however many encoders in the
+    /// wild (e.g. of X.509 certificates) include them regardless, so they're
+    /// accepted by default.
+    pub fn set_reject_encoded_defaults(&mut self, reject: bool) {
+        self.reject_encoded_defaults = reject;
+    }
+
+    /// Get the [`EncodingRules`] enforced by this decoder.
+    pub fn encoding_rules(&self) -> EncodingRules {
+        self.encoding_rules
     }
 
     /// Decode a value which impls the [`Decode`] trait.
@@ -51,10 +198,73 @@ impl<'a> Decoder<'a> {
             return Err(self.error(ErrorKind::Failed));
         }
 
-        T::decode(self).map_err(|e| {
+        let result = T::decode(self).map_err(|e| {
             self.bytes.take();
             e.nested(self.position)
-        })
+        })?;
+
+        self.report_progress()?;
+        Ok(result)
+    }
+
+    /// Decode an ASN.1 field with a `DEFAULT` value, returning the result of
+    /// `default` if the field is absent.
+    ///
+    /// DER requires a value equal to its `DEFAULT` to be omitted, so such a
+    /// value can be rejected using [`Decoder::set_reject_encoded_defaults`].
+    pub fn decode_default<T, F>(&mut self, default: F) -> Result<T>
+    where
+        T: Choice<'a> + PartialEq,
+        F: FnOnce() -> T,
+    {
+        let tag = self.peek_tag();
+
+        match self.decode::<Option<T>>()? {
+            Some(value) => self.resolve_default(tag?, Some(value), default),
+            None => Ok(default()),
+        }
+    }
+
+    /// Resolve the value of an ASN.1 field with a `DEFAULT` which has already
+    /// been decoded as an `OPTIONAL` field with the given [`Tag`] (e.g. a
+    /// `CONTEXT-SPECIFIC` field).
+    ///
+    /// Behaves the same as [`Decoder::decode_default`].
+    pub fn resolve_default<T, F>(&mut self, tag: Tag, value: Option<T>, default: F) -> Result<T>
+    where
+        T: PartialEq,
+        F: FnOnce() -> T,
+    {
+        let default = default();
+
+        match value {
+            Some(value)
+                if value == default
+                    && self.reject_encoded_defaults
+                    && self.encoding_rules == EncodingRules::Der =>
+            {
+                Err(self.error(ErrorKind::Noncanonical { tag }))
+            }
+            Some(value) => Ok(value),
+            None => Ok(default),
+        }
+    }
+
+    /// Decode successive values of type `T` until the input is exhausted.
+    ///
+    /// Returns a [`TlvIter`] which yields each decoded value in turn.
+    pub fn decode_all<T: Decode<'a>>(&mut self) -> TlvIter<'_, 'a, T> {
+        TlvIter::new(self)
+    }
+
+    /// Get the current [`Progress`] of this decoder.
+    pub fn progress(&self) -> Progress {
+        Progress {
+            position: self.position(),
+            total_len: self.total_len,
+            depth: self.depth,
+            element_index: self.element_index,
+        }
     }
 
     /// Return an error with the given [`ErrorKind`], annotating it with
@@ -75,9 +285,13 @@ impl<'a> Decoder<'a> {
     }
 
     /// Get the position within the buffer.
+    ///
+    /// For nested decoders this is relative to the start of the outermost
+    /// message, which makes it suitable for use in error reporting.
     pub fn position(&self) -> Length {
-        // TODO(tarcieri): avoid potential panic here
-        (self.position + self.offset).expect("overflow")
+        // Can't overflow: both are bounded by the length of the outermost
+        // message, which is itself a valid `Length`
+        self.position.saturating_add(self.offset)
     }
 
     /// Peek at the next byte in the decoder without modifying the cursor.
@@ -93,7 +307,7 @@ impl<'a> Decoder<'a> {
     /// Does not modify the decoder's state.
     pub fn peek_tag(&self) -> Result<Tag> {
         match self.peek_byte() {
-            Some(byte) => byte.try_into(),
+            Some(byte) => Tag::from_identifier(byte).map(|(tag, _)| tag),
             None => {
                 let actual_len = self.input_len()?;
                 let expected_len = (actual_len + Length::ONE)?;
@@ -148,6 +362,12 @@ impl<'a> Decoder<'a> {
         self.decode()
     }
 
+    /// Decode the next complete TLV production, retaining its original
+    /// encoding and its position within the message.
+    pub fn tlv(&mut self) -> Result<Tlv<'a>> {
+        self.decode()
+    }
+
     /// Attempt to decode ASN.1 `INTEGER` as `i8`
     pub fn int8(&mut self) -> Result<i8> {
         self.decode()
@@ -253,6 +473,78 @@ impl<'a> Decoder<'a> {
         SequenceRef::decode(self)?.decode_body(f)
     }
 
+    /// Decode the elements of a `SEQUENCE OF` or `SET OF` whose body has the
+    /// given length, calling the provided function on each decoded element.
+    ///
+    /// Tracks the index of the current element for the purposes of progress
+    /// reporting, and enforces the configured collection limits and maximum
+    /// nesting depth.
+    pub(crate) fn decode_elements<T, F>(&mut self, tag: Tag, length: Length, f: F) -> Result<()>
+    where
+        T: Decode<'a>,
+        F: FnMut(T) -> Result<()>,
+    {
+        if self.max_collection_len.map_or(false, |max| length > max) {
+            return Err(self.error(ErrorKind::CollectionLimit { tag }));
+        }
+
+        if self.depth >= self.max_depth {
+            return Err(self.error(ErrorKind::DepthLimit {
+                max_depth: self.max_depth,
+            }));
+        }
+
+        let end_pos = (self.position() + length)?;
+        let parent_index = self.element_index.replace(0);
+        self.depth = self.depth.saturating_add(1);
+
+        // Restore the parent's state even if decoding an element fails
+        let result = self.decode_each(tag, end_pos, f);
+        self.depth = self.depth.saturating_sub(1);
+        self.element_index = parent_index;
+        result?;
+
+        if self.position() != end_pos {
+            return Err(self.error(ErrorKind::Length { tag }));
+        }
+
+        Ok(())
+    }
+
+    /// Decode elements until `end_pos` is reached, calling the provided
+    /// function on each decoded element.
+    fn decode_each<T, F>(&mut self, tag: Tag, end_pos: Length, mut f: F) -> Result<()>
+    where
+        T: Decode<'a>,
+        F: FnMut(T) -> Result<()>,
+    {
+        let mut count = 0usize;
+
+        while self.position() < end_pos {
+            if self.max_elements.map_or(false, |max| count >= max) {
+                return Err(self.error(ErrorKind::CollectionLimit { tag }));
+            }
+
+            f(self.decode()?)?;
+            count = count.saturating_add(1);
+            self.element_index = self.element_index.map(|index| index.saturating_add(1));
+        }
+
+        Ok(())
+    }
+
+    /// Invoke the progress callback (if any) with the current [`Progress`].
+    fn report_progress(&mut self) -> Result<()> {
+        if let Some(ProgressCallback(callback)) = self.progress {
+            callback(self.progress()).map_err(|e| {
+                self.bytes.take();
+                e
+            })?;
+        }
+
+        Ok(())
+    }
+
     /// Decode a single byte, updating the internal cursor.
     pub(crate) fn byte(&mut self) -> Result<u8> {
         match self.bytes(1u8)? {
@@ -308,7 +600,15 @@ impl<'a> Decoder<'a> {
     }
 
     /// Get the number of bytes still remaining in the buffer.
-    pub(crate) fn remaining_len(&self) -> Result<Length> {
+    ///
+    /// When called on the nested [`Decoder`] passed to
+    /// [`Decoder::sequence`], this is the number of bytes remaining in the
+    /// value of the enclosing `SEQUENCE`, which allows reading elements
+    /// until the end of a containing value is reached (see also
+    /// [`Decoder::is_finished`]).
+    ///
+    /// Returns an error if a fatal error has occurred.
+    pub fn remaining_len(&self) -> Result<Length> {
         self.remaining()?.len().try_into()
     }
 
@@ -334,8 +634,9 @@ impl<'a> Decoder<'a> {
 
 #[cfg(test)]
 mod tests {
-    use super::Decoder;
-    use crate::{Decode, ErrorKind, Length, Tag};
+    use super::{Decoder, Progress};
+    use crate::{asn1::OctetString, Decode, EncodingRules, ErrorKind, Length, Tag};
+    use core::cell::Cell;
     use hex_literal::hex;
 
     // INTEGER: 42
@@ -359,6 +660,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn decode_elements_restores_state_on_error() {
+        // SEQUENCE OF INTEGER body containing a truncated second element
+        let mut decoder = Decoder::new(&hex!("02012A0201")).unwrap();
+        let result = decoder.decode_elements(Tag::Sequence, Length::new(5), |_: u8| Ok(()));
+        assert!(result.is_err());
+        assert_eq!(decoder.depth, 0);
+        assert_eq!(decoder.element_index, None);
+    }
+
     #[test]
     fn invalid_field_length() {
         let mut decoder = Decoder::new(&EXAMPLE_MSG[..2]).unwrap();
@@ -380,7 +691,7 @@ mod tests {
     #[test]
     fn trailing_data() {
         let mut decoder = Decoder::new(EXAMPLE_MSG).unwrap();
-        let x = decoder.decode().unwrap();
+        let x: i8 = decoder.decode().unwrap();
         assert_eq!(42i8, x);
 
         let err = decoder.finish(x).err().unwrap();
@@ -403,6 +714,45 @@ mod tests {
         assert_eq!(decoder.position(), Length::ZERO); // Position unchanged
     }
 
+    #[test]
+    fn constructed_form() {
+        // Constructed OCTET STRING containing a single primitive segment
+        const CONSTRUCTED_OCTET_STRING: &[u8] = &hex!("2403040100");
+
+        let err = Decoder::new(CONSTRUCTED_OCTET_STRING)
+            .unwrap()
+            .peek_header()
+            .unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::TagForm {
+                tag: Tag::OctetString,
+                constructed: true
+            }
+        );
+
+        let mut decoder = Decoder::new(CONSTRUCTED_OCTET_STRING).unwrap();
+        decoder.set_encoding_rules(EncodingRules::Ber);
+        let header = decoder.peek_header().unwrap();
+        assert_eq!(header.tag, Tag::OctetString);
+        assert!(header.constructed);
+        assert_eq!(header.identifier_octet(), 0x24);
+
+        // Borrowed string types can't reassemble segments
+        let err = OctetString::decode(&mut decoder).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::TagForm { .. }));
+
+        // BER only permits the constructed form for string types
+        for bytes in [&hex!("2101ff")[..], &hex!("1000")] {
+            let mut decoder = Decoder::new(bytes).unwrap();
+            decoder.set_encoding_rules(EncodingRules::Ber);
+            assert!(matches!(
+                decoder.peek_header().unwrap_err().kind(),
+                ErrorKind::TagForm { .. }
+            ));
+        }
+    }
+
     #[test]
     fn peek_header() {
         let decoder = Decoder::new(EXAMPLE_MSG).unwrap();
@@ -413,4 +763,92 @@ mod tests {
         assert_eq!(header.length, Length::ONE);
         assert_eq!(decoder.position(), Length::ZERO); // Position unchanged
     }
+
+    #[test]
+    fn progress_callback() {
+        // SEQUENCE OF INTEGER: [1, 2, 3]
+        const SEQUENCE_OF: &[u8] = &hex!("3009020101020102020103");
+
+        let reports = Cell::new(0);
+        let last_index = Cell::new(None);
+        let callback = |progress: Progress| {
+            reports.set(reports.get() + 1);
+
+            if progress.depth == 1 {
+                last_index.set(progress.element_index);
+            }
+
+            Ok(())
+        };
+
+        let mut decoder = Decoder::new_with_progress(SEQUENCE_OF, &callback).unwrap();
+        let ints: [u8; 3] = decoder.decode().unwrap();
+        assert_eq!(ints, [1, 2, 3]);
+        assert_eq!(reports.get(), 4);
+        assert_eq!(last_index.get(), Some(2));
+        assert_eq!(decoder.progress().percent(), 100);
+    }
+
+    #[test]
+    fn progress_callback_abort() {
+        let callback = |_| Err(ErrorKind::Failed.into());
+        let mut decoder = Decoder::new_with_progress(EXAMPLE_MSG, &callback).unwrap();
+        assert!(decoder.decode::<i8>().is_err());
+        assert!(decoder.is_failed());
+    }
+
+    #[test]
+    fn collection_limits() {
+        // SEQUENCE OF INTEGER: [1, 2, 3]
+        const SEQUENCE_OF: &[u8] = &hex!("3009020101020102020103");
+
+        let mut decoder = Decoder::new(SEQUENCE_OF).unwrap();
+        decoder.set_max_elements(3);
+        decoder.set_max_collection_len(Length::new(9));
+        assert_eq!(decoder.decode::<[u8; 3]>().unwrap(), [1, 2, 3]);
+
+        let mut decoder = Decoder::new(SEQUENCE_OF).unwrap();
+        decoder.set_max_elements(2);
+        let err = decoder.decode::<[u8; 3]>().err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::CollectionLimit { tag: Tag::Sequence }
+        );
+
+        let mut decoder = Decoder::new(SEQUENCE_OF).unwrap();
+        decoder.set_max_collection_len(Length::new(8));
+        let err = decoder.decode::<[u8; 3]>().err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::CollectionLimit { tag: Tag::Sequence }
+        );
+    }
+
+    #[test]
+    fn read_until_end() {
+        // SEQUENCE OF INTEGER: [1, 2, 3] followed by trailing data
+        let mut decoder = Decoder::new(&hex!("300902010102010202010300")).unwrap();
+
+        let ints = decoder
+            .sequence(|nested| {
+                let mut ints = [0u8; 3];
+                let mut n = 0;
+
+                assert_eq!(nested.remaining_len().unwrap(), Length::new(9));
+
+                while !nested.is_finished() {
+                    ints[n] = nested.decode()?;
+                    n += 1;
+                }
+
+                assert_eq!(nested.remaining_len().unwrap(), Length::ZERO);
+                assert_eq!(nested.position(), Length::new(11));
+                Ok(ints)
+            })
+            .unwrap();
+
+        assert_eq!(ints, [1, 2, 3]);
+        assert!(!decoder.is_finished());
+        assert_eq!(decoder.remaining_len().unwrap(), Length::ONE);
+    }
 }
@@ -0,0 +1,79 @@
+//! Decoder progress reporting.
+
+use crate::{Length, Result};
+use core::fmt;
+
+/// Snapshot of a [`Decoder`][`crate::Decoder`]'s progress through its input.
+///
+/// This is intended for driving progress bars and enforcing timeouts when
+/// decoding very large documents, e.g. CRLs with millions of entries or
+/// CMS messages with large embedded content.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Progress {
+    /// Number of bytes of the original document consumed so far.
+    pub position: Length,
+
+    /// Total length of the original document.
+    ///
+    /// This can be used along with `position` to estimate the fraction of
+    /// the document which has been decoded.
+    pub total_len: Length,
+
+    /// Nesting depth of the value currently being decoded, where `0` is the
+    /// toplevel.
+    pub depth: u32,
+
+    /// Index of the element currently being decoded within the innermost
+    /// active `SEQUENCE OF`/`SET OF` collection, if any.
+    pub element_index: Option<usize>,
+}
+
+impl Progress {
+    /// Estimate the completion of the decoding operation in the range
+    /// `0..=100` percent.
+    pub fn percent(&self) -> u8 {
+        let total = u64::from(u32::from(self.total_len));
+
+        if total == 0 {
+            return 100;
+        }
+
+        let position = u64::from(u32::from(self.position));
+        u8::try_from(position.saturating_mul(100) / total).unwrap_or(100)
+    }
+}
+
+/// Callback which is invoked with the current [`Progress`] each time a
+/// value has been decoded.
+///
+/// Returning an error from the callback aborts decoding, which can be used
+/// to implement timeouts or cancellation.
+#[derive(Copy, Clone)]
+pub(crate) struct ProgressCallback<'a>(pub(crate) &'a dyn Fn(Progress) -> Result<()>);
+
+impl fmt::Debug for ProgressCallback<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ProgressCallback(..)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Progress;
+    use crate::Length;
+
+    #[test]
+    fn percent() {
+        let mut progress = Progress {
+            position: Length::from(25u8),
+            total_len: Length::from(100u8),
+            depth: 0,
+            element_index: None,
+        };
+
+        assert_eq!(progress.percent(), 25);
+
+        progress.total_len = Length::ZERO;
+        assert_eq!(progress.percent(), 100);
+    }
+}
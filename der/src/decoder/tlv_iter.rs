@@ -0,0 +1,84 @@
+//! Iterator over concatenated TLV-encoded values.
+
+use crate::{Decode, Decoder, Result};
+use core::{iter::FusedIterator, marker::PhantomData};
+
+/// Iterator which decodes successive TLV-encoded values of type `T` from a
+/// [`Decoder`] until its input is exhausted.
+///
+/// This is useful for formats which concatenate multiple toplevel DER
+/// values, e.g. certificate bundles.
+///
+/// Created by [`Decoder::decode_all`]. If an error occurs, it is yielded
+/// once (with the position at which it occurred) and iteration stops.
+#[derive(Debug)]
+pub struct TlvIter<'d, 'a, T> {
+    /// Decoder for the concatenated values.
+    decoder: &'d mut Decoder<'a>,
+
+    /// Type of the values being decoded.
+    value: PhantomData<fn() -> T>,
+}
+
+impl<'d, 'a, T> TlvIter<'d, 'a, T> {
+    /// Create a new [`TlvIter`].
+    pub(crate) fn new(decoder: &'d mut Decoder<'a>) -> Self {
+        Self {
+            decoder,
+            value: PhantomData,
+        }
+    }
+}
+
+impl<'d, 'a, T> Iterator for TlvIter<'d, 'a, T>
+where
+    T: Decode<'a>,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Result<T>> {
+        if self.decoder.is_failed() || self.decoder.is_finished() {
+            None
+        } else {
+            Some(self.decoder.decode())
+        }
+    }
+}
+
+impl<'d, 'a, T> FusedIterator for TlvIter<'d, 'a, T> where T: Decode<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Decoder, ErrorKind, Length};
+    use hex_literal::hex;
+
+    #[test]
+    fn decode_all() {
+        let mut decoder = Decoder::new(&hex!("020101 020102 020103")).unwrap();
+        let mut iter = decoder.decode_all::<u8>();
+
+        for expected in 1..=3 {
+            assert_eq!(iter.next().unwrap().unwrap(), expected);
+        }
+
+        assert!(iter.next().is_none());
+        assert!(decoder.is_finished());
+    }
+
+    #[test]
+    fn decode_all_error_position() {
+        let mut decoder = Decoder::new(&hex!("020101 020102 0102")).unwrap();
+        let mut iter = decoder.decode_all::<u8>();
+
+        assert_eq!(iter.next().unwrap().unwrap(), 1);
+        assert_eq!(iter.next().unwrap().unwrap(), 2);
+
+        let err = iter.next().unwrap().unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::TagUnexpected { .. }));
+        // Position is relative to the start of the input, after the header
+        // of the third value has been consumed
+        assert_eq!(err.position(), Some(Length::new(8)));
+
+        assert!(iter.next().is_none());
+    }
+}
@@ -1,14 +1,469 @@
 //! ASN.1 DER-encoded documents stored on the heap.
 
-use crate::{Decode, Encode, Error, Result};
+use crate::{asn1::Null, Decode, Encode, Error, Length, Result};
 use alloc::{boxed::Box, vec::Vec};
+use core::{fmt, marker::PhantomData};
 
 #[cfg(feature = "pem")]
-use {crate::pem, alloc::string::String};
+use {crate::pem, alloc::string::String, core::str::FromStr};
 
 #[cfg(feature = "std")]
 use std::{fs, path::Path};
 
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroizing;
+
+#[cfg(feature = "mlock")]
+use zeroize::Zeroize;
+
+/// Message type which can be contained in an [`OwnedDocument`] or a
+/// [`SecretDocument`].
+///
+/// Messages are decoded by borrowing from the document, so this trait maps
+/// the message type to its form borrowing for the lifetime `'a`. Types which
+/// don't borrow from their encoding map to themselves, whereas e.g. a
+/// `PrivateKeyInfo<'static>` document contains `PrivateKeyInfo<'a>`:
+///
+/// ```ignore
+/// impl<'a> DocumentMessage<'a> for PrivateKeyInfo<'static> {
+///     type Message = PrivateKeyInfo<'a>;
+/// }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub trait DocumentMessage<'a> {
+    /// Message type borrowing from a document for the lifetime `'a`.
+    type Message: Decode<'a> + Encode;
+}
+
+macro_rules! impl_document_message {
+    ($($ty:ty),+) => {
+        $(
+            impl<'a> DocumentMessage<'a> for $ty {
+                type Message = $ty;
+            }
+        )+
+    };
+}
+
+impl_document_message!(bool, i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, Null);
+
+/// Owned ASN.1 DER document which is guaranteed to be the valid encoding of
+/// the message type `T`.
+///
+/// The DER serialization is validated when the document is constructed,
+/// which allows it to be passed around and persisted as bytes while retaining
+/// the type of message it contains.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct OwnedDocument<T> {
+    /// ASN.1 DER encoded bytes.
+    der_bytes: Vec<u8>,
+
+    /// Message type encoded by this document.
+    msg: PhantomData<fn() -> T>,
+}
+
+impl<T> OwnedDocument<T> {
+    /// Borrow the inner serialized bytes of this document.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.der_bytes.as_slice()
+    }
+
+    /// Convert this document into its inner serialized bytes.
+    pub fn into_vec(self) -> Vec<u8> {
+        self.der_bytes
+    }
+
+    /// Get the length of the encoded ASN.1 DER in bytes.
+    pub fn len(&self) -> Length {
+        // Documents always contain valid DER, whose length fits in `Length`
+        self.der_bytes.len().try_into().unwrap_or(Length::MAX)
+    }
+
+    /// Encode ASN.1 DER document as a PEM string.
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    pub fn to_pem(&self, line_ending: pem::LineEnding) -> Result<String>
+    where
+        T: pem::PemLabel,
+    {
+        Ok(pem::encode_string(
+            T::TYPE_LABEL,
+            line_ending,
+            self.as_bytes(),
+        )?)
+    }
+
+    /// Write ASN.1 DER document to a file.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write_der_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        Ok(fs::write(path, self.as_bytes())?)
+    }
+
+    /// Write PEM-encoded ASN.1 DER document to a file.
+    #[cfg(all(feature = "pem", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "pem", feature = "std"))))]
+    pub fn write_pem_file(&self, path: impl AsRef<Path>, line_ending: pem::LineEnding) -> Result<()>
+    where
+        T: pem::PemLabel,
+    {
+        Ok(fs::write(path, self.to_pem(line_ending)?.as_bytes())?)
+    }
+}
+
+impl<T> OwnedDocument<T>
+where
+    T: for<'a> DocumentMessage<'a>,
+{
+    /// Create a new document from the provided ASN.1 DER bytes, ensuring
+    /// they decode as the message type `T`.
+    pub fn from_der(bytes: &[u8]) -> Result<Self> {
+        bytes.to_vec().try_into()
+    }
+
+    /// Decode the message contained in this document.
+    pub fn decode_msg(&self) -> Result<<T as DocumentMessage<'_>>::Message> {
+        <T as DocumentMessage<'_>>::Message::from_der(self.as_bytes())
+    }
+
+    /// Decode document from PEM, ensuring its type label matches the
+    /// expected label for `T`.
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    pub fn from_pem(s: &str) -> Result<Self>
+    where
+        T: pem::PemLabel,
+    {
+        let (label, der_bytes) = pem::decode_vec(s.as_bytes())?;
+        T::validate_pem_label(label)?;
+        der_bytes.try_into()
+    }
+
+    /// Read ASN.1 DER document from a file.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read_der_file(path: impl AsRef<Path>) -> Result<Self> {
+        fs::read(path)?.try_into()
+    }
+
+    /// Read PEM-encoded ASN.1 DER document from a file.
+    #[cfg(all(feature = "pem", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "pem", feature = "std"))))]
+    pub fn read_pem_file(path: impl AsRef<Path>) -> Result<Self>
+    where
+        T: pem::PemLabel,
+    {
+        Self::from_pem(&fs::read_to_string(path)?)
+    }
+
+    /// Encode the provided message as ASN.1 DER.
+    pub fn encode_msg(msg: &<T as DocumentMessage<'_>>::Message) -> Result<Self> {
+        Ok(Self {
+            der_bytes: msg.to_vec()?,
+            msg: PhantomData,
+        })
+    }
+}
+
+impl<T> AsRef<[u8]> for OwnedDocument<T> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<T> Clone for OwnedDocument<T> {
+    fn clone(&self) -> Self {
+        Self {
+            der_bytes: self.der_bytes.clone(),
+            msg: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for OwnedDocument<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("OwnedDocument")
+            .field(&self.der_bytes)
+            .finish()
+    }
+}
+
+impl<T> Eq for OwnedDocument<T> {}
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl<T> FromStr for OwnedDocument<T>
+where
+    T: for<'a> DocumentMessage<'a> + pem::PemLabel,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_pem(s)
+    }
+}
+
+impl<T> PartialEq for OwnedDocument<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.der_bytes == other.der_bytes
+    }
+}
+
+impl<T> TryFrom<&[u8]> for OwnedDocument<T>
+where
+    T: for<'a> DocumentMessage<'a>,
+{
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+impl<T> TryFrom<Vec<u8>> for OwnedDocument<T>
+where
+    T: for<'a> DocumentMessage<'a>,
+{
+    type Error = Error;
+
+    fn try_from(der_bytes: Vec<u8>) -> Result<Self> {
+        <T as DocumentMessage<'_>>::Message::from_der(&der_bytes)?;
+
+        Ok(Self {
+            der_bytes,
+            msg: PhantomData,
+        })
+    }
+}
+
+/// Owned ASN.1 DER document containing secret data, e.g. a private key,
+/// which is guaranteed to be the valid encoding of the message type `T`.
+///
+/// Like [`OwnedDocument`], but the DER serialization is zeroized when the
+/// document is dropped, as are any intermediate buffers (e.g. decoded PEM)
+/// used when constructing it. Its contents are omitted from [`fmt::Debug`]
+/// output, and files are written with permissions restricted to the owner.
+///
+/// When the `mlock` feature is enabled, the document is also locked into
+/// memory so it isn't swapped to disk. Locking is best effort, since the
+/// amount of memory a process may lock is usually limited: use
+/// [`SecretDocument::is_locked`] to check whether it succeeded.
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "zeroize"))))]
+pub struct SecretDocument<T> {
+    /// ASN.1 DER encoded bytes.
+    der_bytes: Zeroizing<Vec<u8>>,
+
+    /// Lock which keeps `der_bytes` resident in memory.
+    #[cfg(feature = "mlock")]
+    lock: Option<region::LockGuard>,
+
+    /// Message type encoded by this document.
+    msg: PhantomData<fn() -> T>,
+}
+
+#[cfg(feature = "zeroize")]
+impl<T> SecretDocument<T> {
+    /// Create a new document from bytes which are known to be well-formed.
+    fn new(der_bytes: Zeroizing<Vec<u8>>) -> Self {
+        #[cfg(feature = "mlock")]
+        let lock = if der_bytes.is_empty() {
+            None
+        } else {
+            region::lock(der_bytes.as_ptr(), der_bytes.len()).ok()
+        };
+
+        Self {
+            der_bytes,
+            #[cfg(feature = "mlock")]
+            lock,
+            msg: PhantomData,
+        }
+    }
+
+    /// Borrow the inner serialized bytes of this document.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.der_bytes.as_slice()
+    }
+
+    /// Get the length of the encoded ASN.1 DER in bytes.
+    pub fn len(&self) -> Length {
+        // Documents always contain valid DER, whose length fits in `Length`
+        self.der_bytes.len().try_into().unwrap_or(Length::MAX)
+    }
+
+    /// Is the buffer containing the document locked into memory?
+    #[cfg(feature = "mlock")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mlock")))]
+    pub fn is_locked(&self) -> bool {
+        self.lock.is_some()
+    }
+
+    /// Encode ASN.1 DER document as a PEM string.
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    pub fn to_pem(&self, line_ending: pem::LineEnding) -> Result<Zeroizing<String>>
+    where
+        T: pem::PemLabel,
+    {
+        Ok(Zeroizing::new(pem::encode_string(
+            T::TYPE_LABEL,
+            line_ending,
+            self.as_bytes(),
+        )?))
+    }
+
+    /// Write ASN.1 DER document to a file which is only readable by its
+    /// owner.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write_der_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        write_secret_file(path, self.as_bytes())
+    }
+
+    /// Write PEM-encoded ASN.1 DER document to a file which is only
+    /// readable by its owner.
+    #[cfg(all(feature = "pem", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "pem", feature = "std"))))]
+    pub fn write_pem_file(&self, path: impl AsRef<Path>, line_ending: pem::LineEnding) -> Result<()>
+    where
+        T: pem::PemLabel,
+    {
+        write_secret_file(path, self.to_pem(line_ending)?.as_bytes())
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T> SecretDocument<T>
+where
+    T: for<'a> DocumentMessage<'a>,
+{
+    /// Create a new document from the provided ASN.1 DER bytes, ensuring
+    /// they decode as the message type `T`.
+    pub fn from_der(bytes: &[u8]) -> Result<Self> {
+        Zeroizing::new(bytes.to_vec()).try_into()
+    }
+
+    /// Decode the message contained in this document.
+    ///
+    /// Note that the returned message is not zeroized on drop unless it
+    /// provides that guarantee itself.
+    pub fn decode_msg(&self) -> Result<<T as DocumentMessage<'_>>::Message> {
+        <T as DocumentMessage<'_>>::Message::from_der(self.as_bytes())
+    }
+
+    /// Encode the provided message as ASN.1 DER.
+    pub fn encode_msg(msg: &<T as DocumentMessage<'_>>::Message) -> Result<Self> {
+        Ok(Self::new(Zeroizing::new(msg.to_vec()?)))
+    }
+
+    /// Decode document from PEM, ensuring its type label matches the
+    /// expected label for `T`.
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    pub fn from_pem(s: &str) -> Result<Self>
+    where
+        T: pem::PemLabel,
+    {
+        let (label, der_bytes) = pem::decode_vec(s.as_bytes())?;
+        let der_bytes = Zeroizing::new(der_bytes);
+        T::validate_pem_label(label)?;
+        der_bytes.try_into()
+    }
+
+    /// Read ASN.1 DER document from a file.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read_der_file(path: impl AsRef<Path>) -> Result<Self> {
+        Zeroizing::new(fs::read(path)?).try_into()
+    }
+
+    /// Read PEM-encoded ASN.1 DER document from a file.
+    #[cfg(all(feature = "pem", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "pem", feature = "std"))))]
+    pub fn read_pem_file(path: impl AsRef<Path>) -> Result<Self>
+    where
+        T: pem::PemLabel,
+    {
+        Self::from_pem(&Zeroizing::new(fs::read_to_string(path)?))
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T> AsRef<[u8]> for SecretDocument<T> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T> Clone for SecretDocument<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.der_bytes.clone())
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T> fmt::Debug for SecretDocument<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretDocument").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "mlock")]
+impl<T> Drop for SecretDocument<T> {
+    fn drop(&mut self) {
+        // Zeroize the document before its pages are unlocked
+        self.der_bytes.zeroize();
+        self.lock = None;
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T> From<OwnedDocument<T>> for SecretDocument<T> {
+    fn from(doc: OwnedDocument<T>) -> SecretDocument<T> {
+        Self::new(Zeroizing::new(doc.der_bytes))
+    }
+}
+
+#[cfg(all(feature = "pem", feature = "zeroize"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl<T> FromStr for SecretDocument<T>
+where
+    T: for<'a> DocumentMessage<'a> + pem::PemLabel,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_pem(s)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T> TryFrom<&[u8]> for SecretDocument<T>
+where
+    T: for<'a> DocumentMessage<'a>,
+{
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T> TryFrom<Zeroizing<Vec<u8>>> for SecretDocument<T>
+where
+    T: for<'a> DocumentMessage<'a>,
+{
+    type Error = Error;
+
+    fn try_from(der_bytes: Zeroizing<Vec<u8>>) -> Result<Self> {
+        <T as DocumentMessage<'_>>::Message::from_der(&der_bytes)?;
+        Ok(Self::new(der_bytes))
+    }
+}
+
 /// ASN.1 DER-encoded document.
 ///
 /// This trait is intended to impl on types which contain an ASN.1 DER-encoded
@@ -129,30 +584,152 @@ fn write_file(path: impl AsRef<Path>, data: &[u8], sensitive: bool) -> Result<()
 }
 
 /// Write a file containing secret data to the filesystem, restricting the
-/// file permissions so it's only readable by the owner
-#[cfg(all(unix, feature = "std"))]
+/// file permissions so it's only readable by the owner.
+///
+/// The data is written to a new temporary file in the same directory, which
+/// is then renamed over `path`. This ensures the file is never readable with
+/// broader permissions (e.g. those of an existing file at `path`), and that
+/// `path` never contains partially written data.
+#[cfg(feature = "std")]
 fn write_secret_file(path: impl AsRef<Path>, data: &[u8]) -> Result<()> {
-    use std::{io::Write, os::unix::fs::OpenOptionsExt};
+    use std::{
+        io::{self, Write},
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    /// Counter which distinguishes temporary files created by this process.
+    static TEMP_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let path = path.as_ref();
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "path has no file name"))?;
+
+    let mut temp_name = std::ffi::OsString::from(".");
+    temp_name.push(file_name);
+    temp_name.push(std::format!(
+        ".{}.{}.tmp",
+        std::process::id(),
+        TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let temp_path = path.with_file_name(temp_name);
+
+    let result = secret_file_options(&mut fs::OpenOptions::new())
+        .open(&temp_path)
+        .and_then(|mut file| {
+            file.write_all(data)?;
+            file.sync_all()
+        })
+        .and_then(|()| fs::rename(&temp_path, path));
+
+    if result.is_err() {
+        // Best effort: the temporary file may not have been created
+        let _ = fs::remove_file(&temp_path);
+    }
+
+    Ok(result?)
+}
+
+/// Options for creating a new file containing secret data, restricting the
+/// file permissions so it's only readable by the owner.
+#[cfg(all(unix, feature = "std"))]
+fn secret_file_options(options: &mut fs::OpenOptions) -> &mut fs::OpenOptions {
+    use std::os::unix::fs::OpenOptionsExt;
 
     /// File permissions for secret data
-    #[cfg(unix)]
     const SECRET_FILE_PERMS: u32 = 0o600;
 
-    fs::OpenOptions::new()
-        .create(true)
-        .write(true)
-        .truncate(true)
-        .mode(SECRET_FILE_PERMS)
-        .open(path)
-        .and_then(|mut file| file.write_all(data))?;
-
-    Ok(())
+    options.write(true).create_new(true).mode(SECRET_FILE_PERMS)
 }
 
-/// Write a file containing secret data to the filesystem
+/// Options for creating a new file containing secret data.
 // TODO(tarcieri): permissions hardening on Windows
 #[cfg(all(not(unix), feature = "std"))]
-fn write_secret_file(path: impl AsRef<Path>, data: &[u8]) -> Result<()> {
-    fs::write(path, data)?;
-    Ok(())
+fn secret_file_options(options: &mut fs::OpenOptions) -> &mut fs::OpenOptions {
+    options.write(true).create_new(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OwnedDocument;
+
+    #[cfg(feature = "zeroize")]
+    use super::SecretDocument;
+    use crate::{ErrorKind, Tag};
+    use hex_literal::hex;
+
+    #[test]
+    fn round_trip() {
+        let doc = OwnedDocument::<u16>::encode_msg(&0x1234).unwrap();
+        assert_eq!(doc.as_bytes(), &hex!("02021234"));
+        assert_eq!(doc.decode_msg().unwrap(), 0x1234);
+        assert_eq!(OwnedDocument::<u16>::from_der(doc.as_bytes()).unwrap(), doc);
+    }
+
+    #[test]
+    fn reject_invalid() {
+        let err = OwnedDocument::<bool>::from_der(&hex!("02021234")).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::TagUnexpected {
+                expected: Some(Tag::Boolean),
+                actual: Tag::Integer
+            }
+        );
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn secret_round_trip() {
+        let doc = SecretDocument::<u16>::encode_msg(&0x1234).unwrap();
+        assert_eq!(doc.as_bytes(), &hex!("02021234"));
+        assert_eq!(doc.decode_msg().unwrap(), 0x1234);
+        assert_eq!(
+            SecretDocument::<u16>::from(OwnedDocument::from_der(doc.as_bytes()).unwrap())
+                .as_bytes(),
+            doc.as_bytes()
+        );
+        assert_eq!(format!("{:?}", doc), "SecretDocument { .. }");
+        assert!(SecretDocument::<bool>::from_der(doc.as_bytes()).is_err());
+    }
+
+    #[cfg(feature = "mlock")]
+    #[test]
+    fn secret_lock_is_best_effort() {
+        // Locking fails when the process may not lock any more memory (e.g.
+        // `RLIMIT_MEMLOCK` is zero), which mustn't prevent using the document,
+        // so whether or not it succeeded isn't asserted here
+        let doc = SecretDocument::<u16>::encode_msg(&0x1234).unwrap();
+        let clone = doc.clone();
+        drop(doc);
+
+        // Each copy has its own lock, so zeroizing and unlocking the original
+        // when it's dropped doesn't affect the clone
+        assert_eq!(clone.as_bytes(), [0x02, 0x02, 0x12, 0x34]);
+        assert_eq!(clone.decode_msg().unwrap(), 0x1234);
+    }
+
+    #[cfg(all(unix, feature = "std"))]
+    #[test]
+    fn write_secret_file_replaces_permissions() {
+        use std::{fs, os::unix::fs::PermissionsExt};
+
+        let dir = std::env::temp_dir().join(format!("der-secret-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("secret.der");
+
+        fs::write(&path, b"public").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+        super::write_secret_file(&path, b"secret").unwrap();
+
+        assert_eq!(fs::read(&path).unwrap(), b"secret");
+        assert_eq!(
+            fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+            0o600
+        );
+
+        // No temporary files are left behind
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
 }
@@ -0,0 +1,425 @@
+//! Dynamic value model for DER messages whose schema isn't known at compile
+//! time.
+//!
+//! [`Value`] represents an arbitrary TLV-encoded value as a tree, which can
+//! be decoded from any DER message and encoded back to it without defining
+//! Rust types for it, e.g. for ASN.1 inspectors or protocol bridges.
+//!
+//! ```
+//! use der::{dynamic::Value, Decode, Encode};
+//!
+//! let der = [0x30, 0x06, 0x02, 0x01, 0x2A, 0x04, 0x01, 0xFF];
+//! let value = Value::from_der(&der)?;
+//! assert_eq!(
+//!     value,
+//!     Value::Sequence(vec![Value::Integer(vec![0x2A]), Value::OctetString(vec![0xFF])])
+//! );
+//! assert_eq!(value.to_vec()?, der);
+//! # Ok::<(), der::Error>(())
+//! ```
+
+use crate::{
+    asn1::{
+        BitString, GeneralizedTime, Ia5String, OctetString, OwnedAny, PrintableString, UtcTime,
+        Utf8String,
+    },
+    ByteSlice, Class, Decode, DecodeValue, Decoder, Encode, EncodeValue, Encoder, EncodingRules,
+    ErrorKind, Header, Length, Result, Tag, Tagged,
+};
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "oid")]
+use crate::asn1::ObjectIdentifier;
+
+/// Dynamically typed ASN.1 value.
+///
+/// Values of the universal types listed below are decoded into the
+/// corresponding variant, and constructed values of other classes (e.g.
+/// `EXPLICIT`ly tagged fields) into [`Value::Constructed`]. All other
+/// values are decoded as [`Value::Other`] with their contents left as-is.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Value {
+    /// ASN.1 `BOOLEAN`.
+    Boolean(bool),
+
+    /// ASN.1 `INTEGER`, as its minimal big endian two's complement contents.
+    Integer(Vec<u8>),
+
+    /// ASN.1 `BIT STRING`.
+    BitString {
+        /// Number of unused bits in the final octet.
+        unused_bits: u8,
+
+        /// Bit string contents, including any unused bits.
+        bytes: Vec<u8>,
+    },
+
+    /// ASN.1 `OCTET STRING`.
+    OctetString(Vec<u8>),
+
+    /// ASN.1 `NULL`.
+    Null,
+
+    /// ASN.1 `OBJECT IDENTIFIER`.
+    #[cfg(feature = "oid")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
+    ObjectIdentifier(ObjectIdentifier),
+
+    /// ASN.1 `UTF8String`.
+    Utf8String(String),
+
+    /// ASN.1 `PrintableString`.
+    PrintableString(String),
+
+    /// ASN.1 `IA5String`.
+    Ia5String(String),
+
+    /// ASN.1 `UTCTime`.
+    UtcTime(UtcTime),
+
+    /// ASN.1 `GeneralizedTime`.
+    GeneralizedTime(GeneralizedTime),
+
+    /// ASN.1 `SEQUENCE` (or `SEQUENCE OF`).
+    Sequence(Vec<Value>),
+
+    /// ASN.1 `SET` (or `SET OF`).
+    ///
+    /// Its elements are sorted into DER order when encoded.
+    Set(Vec<Value>),
+
+    /// Constructed value of any class other than universal, e.g. an
+    /// `EXPLICIT`ly tagged field.
+    Constructed {
+        /// Tag of the value.
+        tag: Tag,
+
+        /// Values it contains.
+        values: Vec<Value>,
+    },
+
+    /// Any other value.
+    Other(OwnedAny),
+}
+
+impl Value {
+    /// Get the values contained in this value, if it's constructed.
+    pub fn values(&self) -> Option<&[Value]> {
+        match self {
+            Value::Sequence(values) | Value::Set(values) | Value::Constructed { values, .. } => {
+                Some(values)
+            }
+            _ => None,
+        }
+    }
+
+    /// Decode the values contained in a constructed value.
+    fn decode_values(decoder: &mut Decoder<'_>, header: Header) -> Result<Vec<Value>> {
+        let mut values = Vec::new();
+
+        decoder.decode_elements(header.tag, header.length, |value| {
+            values.push(value);
+            Ok(())
+        })?;
+
+        Ok(values)
+    }
+
+    /// Encode the elements of a `SET` in DER order.
+    fn encode_set(values: &[Value]) -> Result<Vec<u8>> {
+        let mut elements = values
+            .iter()
+            .map(Encode::to_vec)
+            .collect::<Result<Vec<_>>>()?;
+
+        elements.sort_unstable();
+        Ok(elements.concat())
+    }
+}
+
+impl<'a> Decode<'a> for Value {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        let header = Header::decode(decoder)?;
+
+        Ok(match header.tag {
+            Tag::Boolean => Value::Boolean(bool::decode_value(decoder, header)?),
+            Tag::Integer => {
+                let bytes = ByteSlice::decode_value(decoder, header)?.as_bytes();
+                check_integer(bytes)?;
+                Value::Integer(bytes.to_vec())
+            }
+            Tag::BitString => {
+                let bit_string = BitString::decode_value(decoder, header)?;
+                Value::BitString {
+                    unused_bits: bit_string.unused_bits(),
+                    bytes: bit_string.raw_bytes().to_vec(),
+                }
+            }
+            Tag::OctetString => Value::OctetString(
+                OctetString::decode_value(decoder, header)?
+                    .as_bytes()
+                    .to_vec(),
+            ),
+            Tag::Null => {
+                <()>::decode_value(decoder, header)?;
+                Value::Null
+            }
+            #[cfg(feature = "oid")]
+            Tag::ObjectIdentifier => {
+                Value::ObjectIdentifier(ObjectIdentifier::decode_value(decoder, header)?)
+            }
+            Tag::Utf8String => {
+                Value::Utf8String(Utf8String::decode_value(decoder, header)?.as_str().into())
+            }
+            Tag::PrintableString => Value::PrintableString(
+                PrintableString::decode_value(decoder, header)?
+                    .as_str()
+                    .into(),
+            ),
+            Tag::Ia5String => {
+                Value::Ia5String(Ia5String::decode_value(decoder, header)?.as_str().into())
+            }
+            Tag::UtcTime => Value::UtcTime(UtcTime::decode_value(decoder, header)?),
+            Tag::GeneralizedTime => {
+                Value::GeneralizedTime(GeneralizedTime::decode_value(decoder, header)?)
+            }
+            Tag::Sequence => Value::Sequence(Self::decode_values(decoder, header)?),
+            Tag::Set => {
+                let values = Self::decode_values(decoder, header)?;
+
+                if decoder.encoding_rules() == EncodingRules::Der {
+                    let mut prev: Option<Vec<u8>> = None;
+
+                    for value in &values {
+                        let der = value.to_vec()?;
+
+                        if prev.as_ref().map_or(false, |prev| *prev > der) {
+                            return Err(decoder.error(ErrorKind::SetOrdering));
+                        }
+
+                        prev = Some(der);
+                    }
+                }
+
+                Value::Set(values)
+            }
+            tag if tag.is_constructed() && tag.class() != Class::Universal => Value::Constructed {
+                tag,
+                values: Self::decode_values(decoder, header)?,
+            },
+            tag => Value::Other(OwnedAny::new(
+                tag,
+                ByteSlice::decode_value(decoder, header)?
+                    .as_bytes()
+                    .to_vec(),
+            )?),
+        })
+    }
+}
+
+impl EncodeValue for Value {
+    fn value_len(&self) -> Result<Length> {
+        match self {
+            Value::Boolean(value) => value.value_len(),
+            Value::Integer(bytes) | Value::OctetString(bytes) => bytes.len().try_into(),
+            Value::BitString { unused_bits, bytes } => {
+                BitString::new(*unused_bits, bytes)?.value_len()
+            }
+            Value::Null => Ok(Length::ZERO),
+            #[cfg(feature = "oid")]
+            Value::ObjectIdentifier(oid) => oid.value_len(),
+            Value::Utf8String(s) | Value::PrintableString(s) | Value::Ia5String(s) => {
+                s.len().try_into()
+            }
+            Value::UtcTime(time) => time.value_len(),
+            Value::GeneralizedTime(time) => time.value_len(),
+            Value::Sequence(values) | Value::Set(values) | Value::Constructed { values, .. } => {
+                values
+                    .iter()
+                    .try_fold(Length::ZERO, |len, value| len + value.encoded_len()?)
+            }
+            Value::Other(any) => any.value_len(),
+        }
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        match self {
+            Value::Boolean(value) => value.encode_value(encoder),
+            Value::Integer(bytes) => {
+                check_integer(bytes).or_else(|e| encoder.error(e.kind()))?;
+                encoder.bytes(bytes)
+            }
+            Value::BitString { unused_bits, bytes } => {
+                BitString::new(*unused_bits, bytes)?.encode_value(encoder)
+            }
+            Value::OctetString(bytes) => encoder.bytes(bytes),
+            Value::Null => Ok(()),
+            #[cfg(feature = "oid")]
+            Value::ObjectIdentifier(oid) => oid.encode_value(encoder),
+            Value::Utf8String(s) => Utf8String::new(s)?.encode_value(encoder),
+            Value::PrintableString(s) => PrintableString::new(s)?.encode_value(encoder),
+            Value::Ia5String(s) => Ia5String::new(s)?.encode_value(encoder),
+            Value::UtcTime(time) => time.encode_value(encoder),
+            Value::GeneralizedTime(time) => time.encode_value(encoder),
+            Value::Sequence(values) | Value::Constructed { values, .. } => {
+                encoder.encode_all(values.iter())
+            }
+            Value::Set(values) => encoder.bytes(&Self::encode_set(values)?),
+            Value::Other(any) => any.encode_value(encoder),
+        }
+    }
+}
+
+impl Tagged for Value {
+    fn tag(&self) -> Tag {
+        match self {
+            Value::Boolean(_) => Tag::Boolean,
+            Value::Integer(_) => Tag::Integer,
+            Value::BitString { .. } => Tag::BitString,
+            Value::OctetString(_) => Tag::OctetString,
+            Value::Null => Tag::Null,
+            #[cfg(feature = "oid")]
+            Value::ObjectIdentifier(_) => Tag::ObjectIdentifier,
+            Value::Utf8String(_) => Tag::Utf8String,
+            Value::PrintableString(_) => Tag::PrintableString,
+            Value::Ia5String(_) => Tag::Ia5String,
+            Value::UtcTime(_) => Tag::UtcTime,
+            Value::GeneralizedTime(_) => Tag::GeneralizedTime,
+            Value::Sequence(_) => Tag::Sequence,
+            Value::Set(_) => Tag::Set,
+            Value::Constructed { tag, .. } => *tag,
+            Value::Other(any) => any.tag(),
+        }
+    }
+}
+
+/// Check that the given `INTEGER` contents are minimally encoded.
+fn check_integer(bytes: &[u8]) -> Result<()> {
+    match bytes {
+        [] => Err(Tag::Integer.length_error()),
+        [0x00, byte, ..] if *byte < 0x80 => Err(Tag::Integer.non_canonical_error()),
+        [0xFF, byte, ..] if *byte >= 0x80 => Err(Tag::Integer.non_canonical_error()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Value;
+    use crate::{
+        asn1::{GeneralizedTime, OwnedAny},
+        Decode, Decoder, Encode, EncodingRules, ErrorKind, Tag, TagNumber, Tagged,
+    };
+    use alloc::vec::Vec;
+    use hex_literal::hex;
+
+    #[test]
+    fn round_trip() {
+        let der = hex!(
+            "3039"
+            "0101FF"
+            "0202FF7F"
+            "03020780"
+            "0400"
+            "0500"
+            "0C0161"
+            "130162"
+            "160163"
+            "180F32303031303930393031343634305A"
+            "3100"
+            "A0053003020100"
+            "8001FF"
+            "1E020064"
+        );
+
+        let value = Value::from_der(&der).unwrap();
+        let values = value.values().unwrap();
+        assert_eq!(values[0], Value::Boolean(true));
+        assert_eq!(values[1], Value::Integer(vec![0xFF, 0x7F]));
+        assert_eq!(
+            values[2],
+            Value::BitString {
+                unused_bits: 7,
+                bytes: vec![0x80]
+            }
+        );
+        assert_eq!(values[5], Value::Utf8String("a".into()));
+        assert_eq!(
+            values[8],
+            Value::GeneralizedTime(
+                GeneralizedTime::from_unix_duration(core::time::Duration::from_secs(1_000_000_000))
+                    .unwrap()
+            )
+        );
+        assert_eq!(
+            values[10],
+            Value::Constructed {
+                tag: Tag::ContextSpecific {
+                    constructed: true,
+                    number: TagNumber::N0
+                },
+                values: vec![Value::Sequence(vec![Value::Integer(vec![0])])]
+            }
+        );
+        assert_eq!(
+            values[11],
+            Value::Other(
+                OwnedAny::new(
+                    Tag::ContextSpecific {
+                        constructed: false,
+                        number: TagNumber::N0
+                    },
+                    vec![0xFF]
+                )
+                .unwrap()
+            )
+        );
+        assert_eq!(values[12].tag(), Tag::BmpString);
+
+        assert_eq!(value.to_vec().unwrap(), der);
+
+        let mut buf = [0u8; 128];
+        assert_eq!(value.encode_to_slice_reverse(&mut buf).unwrap(), der);
+    }
+
+    #[test]
+    fn noncanonical_integer() {
+        for der in [&hex!("0200")[..], &hex!("0202007F"), &hex!("0202FF80")] {
+            assert!(Value::from_der(der).is_err());
+        }
+
+        assert!(Value::Integer(vec![0x00, 0x01]).to_vec().is_err());
+    }
+
+    #[test]
+    fn set_ordering() {
+        let der = hex!("3106 020102 020101");
+        let err = Value::from_der(&der).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::SetOrdering);
+
+        let mut decoder = Decoder::new(&der).unwrap();
+        decoder.set_encoding_rules(EncodingRules::Ber);
+        let value = decoder.decode::<Value>().unwrap();
+
+        // Elements are sorted when encoded
+        assert_eq!(value.to_vec().unwrap(), hex!("3106 020101 020102"));
+    }
+
+    #[test]
+    fn depth_limit() {
+        let mut der = Vec::new();
+
+        for depth in 0..8u8 {
+            der.splice(0..0, [0x30, depth * 2]);
+        }
+
+        let mut decoder = Decoder::new(&der).unwrap();
+        decoder.set_max_depth(8);
+        assert!(decoder.decode::<Value>().is_ok());
+
+        let mut decoder = Decoder::new(&der).unwrap();
+        decoder.set_max_depth(7);
+        let err = decoder.decode::<Value>().err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::DepthLimit { max_depth: 7 });
+    }
+}
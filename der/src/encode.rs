@@ -1,10 +1,16 @@
 //! Trait definition for [`Encode`].
 
-use crate::{Encoder, Header, Length, Result, Tagged};
+use crate::{Encoder, Header, Length, Result, Tagged, Writer};
 
 #[cfg(feature = "alloc")]
 use {crate::ErrorKind, alloc::vec::Vec, core::iter};
 
+#[cfg(feature = "pem")]
+use {
+    crate::pem::{self, LineEnding, PemLabel},
+    alloc::string::String,
+};
+
 #[cfg(doc)]
 use crate::Tag;
 
@@ -24,16 +30,41 @@ pub trait Encode {
         encoder.finish()
     }
 
+    /// Encode this value back-to-front into the end of the provided byte
+    /// slice, returning a sub-slice containing the encoded message.
+    ///
+    /// See [`Encoder::new_reverse`].
+    fn encode_to_slice_reverse<'a>(&self, buf: &'a mut [u8]) -> Result<&'a [u8]> {
+        let mut encoder = Encoder::new_reverse(buf);
+        self.encode(&mut encoder)?;
+        encoder.finish()
+    }
+
+    /// Encode this value as ASN.1 DER through the provided [`Writer`],
+    /// returning the length of the encoded message.
+    fn encode_to_writer(&self, writer: &mut dyn Writer) -> Result<Length> {
+        let mut encoder = Encoder::from_writer(writer);
+        self.encode(&mut encoder)?;
+        Ok(encoder.position())
+    }
+
     /// Encode this message as ASN.1 DER, appending it to the provided
     /// byte vector.
+    ///
+    /// The lengths of the values comprising this message are computed by a
+    /// single counting pass over it, rather than using [`Encode::encoded_len`]
+    /// and [`EncodeValue::value_len`], which compute the lengths of deeply
+    /// nested values repeatedly.
     #[cfg(feature = "alloc")]
     #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
     fn encode_to_vec(&self, buf: &mut Vec<u8>) -> Result<Length> {
-        let expected_len = usize::try_from(self.encoded_len()?)?;
+        let (expected_len, lengths) = Encoder::count(self)?;
+        let expected_len = usize::try_from(expected_len)?;
+        let start = buf.len();
         buf.reserve(expected_len);
         buf.extend(iter::repeat(0).take(expected_len));
 
-        let mut encoder = Encoder::new(buf);
+        let mut encoder = Encoder::with_lengths(&mut buf[start..], lengths);
         self.encode(&mut encoder)?;
         let actual_len = encoder.finish()?.len();
 
@@ -69,8 +100,32 @@ where
 
     /// Encode this value as ASN.1 DER using the provided [`Encoder`].
     fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
-        self.header()?.encode(encoder)?;
-        self.encode_value(encoder)
+        encoder.tlv(self)
+    }
+}
+
+/// PEM encoding trait.
+///
+/// This trait is automatically impl'd for any type which impls both
+/// [`Encode`] and [`PemLabel`].
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+pub trait EncodePem: Encode + PemLabel {
+    /// Try to encode this type as PEM, using [`PemLabel::TYPE_LABEL`] as the
+    /// PEM type label.
+    fn to_pem(&self, line_ending: LineEnding) -> Result<String>;
+}
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl<T: Encode + PemLabel> EncodePem for T {
+    fn to_pem(&self, line_ending: LineEnding) -> Result<String> {
+        let der_bytes = self.to_vec()?;
+        Ok(pem::encode_string(
+            Self::TYPE_LABEL,
+            line_ending,
+            &der_bytes,
+        )?)
     }
 }
 
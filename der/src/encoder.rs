@@ -1,59 +1,401 @@
 //! DER encoder.
 
+use crate::writer::UninitWriter;
 use crate::{
     asn1::*, Encode, EncodeValue, Error, ErrorKind, Header, Length, Result, Tag, TagMode,
-    TagNumber, Tagged,
+    TagNumber, Tagged, Writer,
 };
+use core::{fmt, mem::MaybeUninit};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 /// DER encoder.
+///
+/// Encodes into a byte slice by default, or through a [`Writer`] (see
+/// [`Encode::encode_to_writer`]). Can also encode into an uninitialized
+/// buffer (see [`Encoder::new_uninit`]), or back-to-front from the end of a
+/// byte slice (see [`Encoder::new_reverse`]).
 #[derive(Debug)]
 pub struct Encoder<'a> {
-    /// Buffer into which DER-encoded message is written
-    bytes: Option<&'a mut [u8]>,
+    /// Output to which the DER-encoded message is written.
+    ///
+    /// In the event an error was previously encountered this will be set to
+    /// `None` to prevent further encoding while in a bad state.
+    output: Option<Output<'a>>,
 
-    /// Total number of bytes written to buffer so far
+    /// Total number of bytes written to the output so far
     position: Length,
+
+    /// Number of values prepended to the output of a reverse encoder at the
+    /// current level of nesting.
+    units: usize,
+
+    /// Lengths of the values being encoded, if recorded by a counting pass.
+    #[cfg(feature = "alloc")]
+    lengths: Option<LengthCache>,
+}
+
+/// Lengths of each TLV-encoded value of a message in the order they're
+/// encoded, recorded by a counting pass over the message and replayed when
+/// encoding it, rather than computing them using [`EncodeValue::value_len`].
+///
+/// Computing a value's length requires computing the lengths of all of the
+/// values nested within it, so when encoding nested values this way each
+/// length would be computed once for every level of nesting above it.
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub(crate) enum LengthCache {
+    /// Lengths being recorded by a counting pass.
+    Record(Vec<Length>),
+
+    /// Lengths being replayed, along with the index of the next one.
+    Replay(Vec<Length>, usize),
+}
+
+/// Output of an [`Encoder`].
+enum Output<'a> {
+    /// Buffer into which DER-encoded message is written
+    Slice(&'a mut [u8]),
+
+    /// Writer to which the DER-encoded message is written incrementally
+    Writer(&'a mut dyn Writer),
+
+    /// Buffer into which DER-encoded message is written back-to-front
+    Reverse(&'a mut [u8]),
+
+    /// Uninitialized buffer into which DER-encoded message is written
+    Uninit(UninitWriter<'a>),
+
+    /// No output: only the length of the message is counted
+    #[cfg(feature = "alloc")]
+    Count,
+}
+
+impl fmt::Debug for Output<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Output::Slice(bytes) => f.debug_tuple("Slice").field(bytes).finish(),
+            Output::Writer(_) => f.debug_tuple("Writer").finish(),
+            Output::Reverse(bytes) => f.debug_tuple("Reverse").field(bytes).finish(),
+            Output::Uninit(writer) => f.debug_tuple("Uninit").field(&writer.len()).finish(),
+            #[cfg(feature = "alloc")]
+            Output::Count => f.debug_tuple("Count").finish(),
+        }
+    }
 }
 
 impl<'a> Encoder<'a> {
     /// Create a new encoder with the given byte slice as a backing buffer.
+    ///
+    /// The buffer's existing contents are ignored and don't need to be
+    /// zeroed: only the slice returned by [`Encoder::finish`] holds
+    /// meaningful data, so a scratch buffer can be reused across encoding
+    /// operations without clearing it in between. To avoid initializing the
+    /// buffer at all, see [`Encoder::new_uninit`].
     pub fn new(bytes: &'a mut [u8]) -> Self {
         Self {
-            bytes: Some(bytes),
+            output: Some(Output::Slice(bytes)),
+            position: Length::ZERO,
+            units: 0,
+            #[cfg(feature = "alloc")]
+            lengths: None,
+        }
+    }
+
+    /// Create a new encoder with the given uninitialized buffer as a backing
+    /// buffer.
+    ///
+    /// The encoder keeps track of how much of the buffer it has written, and
+    /// [`Encoder::finish_uninit`] returns that portion of it.
+    ///
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use der::{Encode, Encoder};
+    ///
+    /// let mut buf = [MaybeUninit::<u8>::uninit(); 16];
+    /// let mut encoder = Encoder::new_uninit(&mut buf);
+    /// 0x1234u16.encode(&mut encoder)?;
+    /// let written = encoder.finish_uninit()?;
+    ///
+    /// // SAFETY: every byte returned by `finish_uninit` has been written
+    /// let der = unsafe { &*(written as *const [MaybeUninit<u8>] as *const [u8]) };
+    /// assert_eq!(der, &[0x02, 0x02, 0x12, 0x34]);
+    /// # Ok::<(), der::Error>(())
+    /// ```
+    pub fn new_uninit(bytes: &'a mut [MaybeUninit<u8>]) -> Self {
+        Self {
+            output: Some(Output::Uninit(UninitWriter::new(bytes))),
             position: Length::ZERO,
+            units: 0,
+            #[cfg(feature = "alloc")]
+            lengths: None,
+        }
+    }
+
+    /// Create a new encoder which writes through the given [`Writer`].
+    pub(crate) fn from_writer(writer: &'a mut dyn Writer) -> Self {
+        Self {
+            output: Some(Output::Writer(writer)),
+            position: Length::ZERO,
+            units: 0,
+            #[cfg(feature = "alloc")]
+            lengths: None,
+        }
+    }
+
+    /// Create a new encoder which writes back-to-front from the end of the
+    /// given byte slice.
+    ///
+    /// Values are prepended to the output, and the header of each value is
+    /// prepended once its contents have been written and their length is
+    /// known. This avoids computing the lengths of nested values in advance
+    /// (see [`EncodeValue::value_len`]) without the allocation needed by
+    /// [`Encode::encode_to_vec`], which makes it well suited to
+    /// memory-constrained targets.
+    ///
+    /// The message returned by [`Encoder::finish`] ends at the end of the
+    /// buffer. Note that values encoded one after another using the encoder
+    /// itself are also prepended, and so appear in the reverse order.
+    ///
+    /// ```
+    /// use der::{asn1::SequenceOf, Encode, Encoder};
+    ///
+    /// let mut seq = SequenceOf::<u8, 2>::new();
+    /// seq.add(1)?;
+    /// seq.add(2)?;
+    ///
+    /// let mut buf = [0u8; 16];
+    /// let mut encoder = Encoder::new_reverse(&mut buf);
+    /// encoder.encode(&seq)?;
+    /// assert_eq!(encoder.finish()?, &[0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02]);
+    /// # Ok::<(), der::Error>(())
+    /// ```
+    pub fn new_reverse(bytes: &'a mut [u8]) -> Self {
+        Self {
+            output: Some(Output::Reverse(bytes)),
+            position: Length::ZERO,
+            units: 0,
+            #[cfg(feature = "alloc")]
+            lengths: None,
+        }
+    }
+
+    /// Is this encoder writing back-to-front? (see [`Encoder::new_reverse`])
+    pub fn is_reverse(&self) -> bool {
+        matches!(self.output, Some(Output::Reverse(_)))
+    }
+
+    /// Create a new encoder with the given byte slice as a backing buffer,
+    /// which uses lengths recorded by [`Encoder::count`].
+    #[cfg(feature = "alloc")]
+    pub(crate) fn with_lengths(bytes: &'a mut [u8], lengths: Vec<Length>) -> Self {
+        let mut encoder = Self::new(bytes);
+        encoder.lengths = Some(LengthCache::Replay(lengths, 0));
+        encoder
+    }
+
+    /// Perform a counting pass over the given value, returning its encoded
+    /// length along with the lengths of each of the values it contains.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn count<T: Encode + ?Sized>(value: &T) -> Result<(Length, Vec<Length>)> {
+        let mut encoder = Encoder {
+            output: Some(Output::Count),
+            position: Length::ZERO,
+            units: 0,
+            lengths: Some(LengthCache::Record(Vec::new())),
+        };
+        encoder.encode(value)?;
+
+        match encoder.lengths {
+            Some(LengthCache::Record(lengths)) => Ok((encoder.position, lengths)),
+            _ => Err(ErrorKind::Failed.at(encoder.position)),
         }
     }
 
     /// Encode a value which impls the [`Encode`] trait.
-    pub fn encode<T: Encode>(&mut self, encodable: &T) -> Result<()> {
+    pub fn encode<T: Encode + ?Sized>(&mut self, encodable: &T) -> Result<()> {
         if self.is_failed() {
             self.error(ErrorKind::Failed)?;
         }
 
         encodable.encode(self).map_err(|e| {
-            self.bytes.take();
+            self.output.take();
             e.nested(self.position)
         })
     }
 
+    /// Encode each of the given values in order.
+    ///
+    /// Reverse encoders (see [`Encoder::new_reverse`]) prepend the values
+    /// last to first, so the [`EncodeValue`] impls of constructed types
+    /// should use this method to encode the values they contain.
+    pub fn encode_all<'v, T, I>(&mut self, values: I) -> Result<()>
+    where
+        T: Encode + ?Sized + 'v,
+        I: IntoIterator<Item = &'v T>,
+        I::IntoIter: DoubleEndedIterator,
+    {
+        if !self.is_reverse() {
+            for value in values {
+                self.encode(value)?;
+            }
+
+            return Ok(());
+        }
+
+        let units = self.units;
+
+        for value in values.into_iter().rev() {
+            self.encode(value)?;
+        }
+
+        self.units = units + 1;
+        Ok(())
+    }
+
+    /// Encode a value along with its [`Header`], using its recorded length
+    /// if available.
+    pub(crate) fn tlv<T: EncodeValue + Tagged + ?Sized>(&mut self, value: &T) -> Result<()> {
+        if self.is_reverse() {
+            return self.tlv_reverse(value);
+        }
+
+        #[cfg(feature = "alloc")]
+        match &mut self.lengths {
+            Some(LengthCache::Record(lengths)) => {
+                // Record the length actually encoded, then count the header
+                let index = lengths.len();
+                lengths.push(Length::ZERO);
+
+                let start = self.position;
+                value.encode_value(self)?;
+                let length = (self.position - start)?;
+
+                if let Some(LengthCache::Record(lengths)) = &mut self.lengths {
+                    if let Some(slot) = lengths.get_mut(index) {
+                        *slot = length;
+                    }
+                }
+
+                return Header::new(value.tag(), length)?.encode(self);
+            }
+            Some(LengthCache::Replay(lengths, index)) => {
+                let length = match lengths.get(*index) {
+                    Some(&length) => length,
+                    None => return self.error(ErrorKind::Length { tag: value.tag() }),
+                };
+                *index += 1;
+
+                Header::new(value.tag(), length)?.encode(self)?;
+                let start = self.position;
+                value.encode_value(self)?;
+
+                return if (self.position - start)? == length {
+                    Ok(())
+                } else {
+                    self.error(ErrorKind::Length { tag: value.tag() })
+                };
+            }
+            None => (),
+        }
+
+        value.header()?.encode(self)?;
+        value.encode_value(self)
+    }
+
+    /// Prepend a value along with its [`Header`] to the output of a reverse
+    /// encoder.
+    ///
+    /// The contents of constructed values are prepended before their header.
+    /// Should a value's [`EncodeValue`] impl prepend more than one unit of
+    /// output (i.e. not use [`Encoder::encode_all`]), its contents would be
+    /// out of order, so it's instead encoded front-to-back into space
+    /// reserved using its computed length, as are primitive values.
+    fn tlv_reverse<T: EncodeValue + Tagged + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let units = self.units;
+        let tag = value.tag();
+        let start = self.position;
+
+        if tag.is_constructed() {
+            self.units = 0;
+            value.encode_value(self)?;
+
+            if self.units <= 1 {
+                let length = (self.position - start)?;
+                Header::new(tag, length)?.encode(self)?;
+                self.units = units + 1;
+                return Ok(());
+            }
+
+            self.position = start;
+        }
+
+        let header = value.header()?;
+        let len = (header.encoded_len()? + header.length)?;
+
+        if self.contiguous(len, |encoder| {
+            header.encode(encoder)?;
+            value.encode_value(encoder)
+        })? != len
+        {
+            return self.error(ErrorKind::Length { tag });
+        }
+
+        self.units = units + 1;
+        Ok(())
+    }
+
+    /// Encode output of the given length front-to-back using `f`, returning
+    /// the length actually encoded.
+    ///
+    /// Reverse encoders reserve space for the output at the front of what's
+    /// been written so far, which is then written using a nested encoder.
+    pub(crate) fn contiguous<F>(&mut self, len: Length, f: F) -> Result<Length>
+    where
+        F: FnOnce(&mut Encoder<'_>) -> Result<()>,
+    {
+        if !self.is_reverse() {
+            let start = self.position;
+            f(self)?;
+            return self.position - start;
+        }
+
+        let result = {
+            let mut encoder = Encoder::new(self.reserve(len)?);
+            f(&mut encoder).map(|_| encoder.position)
+        };
+
+        self.units += 1;
+        result.map_err(|e| {
+            self.output.take();
+            e
+        })
+    }
+
     /// Return an error with the given [`ErrorKind`], annotating it with
     /// context about where the error occurred.
     // TODO(tarcieri): change return type to `Error`
     pub fn error<T>(&mut self, kind: ErrorKind) -> Result<T> {
-        self.bytes.take();
+        self.output.take();
         Err(kind.at(self.position))
     }
 
     /// Return an error for an invalid value with the given tag.
     // TODO(tarcieri): compose this with `Encoder::error` after changing its return type
     pub fn value_error(&mut self, tag: Tag) -> Error {
-        self.bytes.take();
+        self.output.take();
         tag.value_error().kind().at(self.position)
     }
 
     /// Did the decoding operation fail due to an error?
     pub fn is_failed(&self) -> bool {
-        self.bytes.is_none()
+        self.output.is_none()
+    }
+
+    /// Get the number of bytes written so far.
+    pub fn position(&self) -> Length {
+        self.position
     }
 
     /// Finish encoding to the buffer, returning a slice containing the data
@@ -62,11 +404,38 @@ impl<'a> Encoder<'a> {
         let pos = self.position;
         let range = ..usize::try_from(self.position)?;
 
-        match self.bytes {
-            Some(bytes) => bytes
+        match self.output {
+            Some(Output::Slice(bytes)) => bytes
                 .get(range)
                 .ok_or_else(|| ErrorKind::Overlength.at(pos)),
-            None => Err(ErrorKind::Failed.at(pos)),
+            Some(Output::Reverse(bytes)) => bytes
+                .len()
+                .checked_sub(range.end)
+                .and_then(|start| bytes.get(start..))
+                .ok_or_else(|| ErrorKind::Overlength.at(pos)),
+            // Encoders which don't write to a slice are either constructed
+            // internally and never finished, or use `finish_uninit`
+            _ => Err(ErrorKind::Failed.at(pos)),
+        }
+    }
+
+    /// Finish encoding to the uninitialized buffer given to
+    /// [`Encoder::new_uninit`], returning the portion of it which has been
+    /// written.
+    ///
+    /// Every element of the returned slice is initialized. This crate is
+    /// `#![forbid(unsafe_code)]`, so converting it to a `&[u8]` is left to
+    /// the caller.
+    pub fn finish_uninit(self) -> Result<&'a [MaybeUninit<u8>]> {
+        let pos = self.position;
+
+        match self.output {
+            // The position is advanced before nested values are written, so
+            // it only matches what's been written if they were all completed
+            Some(Output::Uninit(writer)) if Length::try_from(writer.len())? == pos => {
+                Ok(writer.into_written())
+            }
+            _ => Err(ErrorKind::Failed.at(pos)),
         }
     }
 
@@ -151,12 +520,24 @@ impl<'a> Encoder<'a> {
     where
         F: FnOnce(&mut Encoder<'_>) -> Result<()>,
     {
+        if self.is_reverse() {
+            let units = self.units;
+
+            if self.contiguous(length, f)? != length {
+                return self.error(ErrorKind::Length { tag: Tag::Sequence });
+            }
+
+            Header::new(Tag::Sequence, length)?.encode(self)?;
+            self.units = units + 1;
+            return Ok(());
+        }
+
         Header::new(Tag::Sequence, length).and_then(|header| header.encode(self))?;
 
-        let mut nested_encoder = Encoder::new(self.reserve(length)?);
+        let mut nested_encoder = self.nested(length)?;
         f(&mut nested_encoder)?;
 
-        if nested_encoder.finish()?.len() == usize::try_from(length)? {
+        if nested_encoder.position == length {
             Ok(())
         } else {
             self.error(ErrorKind::Length { tag: Tag::Sequence })
@@ -179,6 +560,30 @@ impl<'a> Encoder<'a> {
             .and_then(|value| self.encode(&value))
     }
 
+    /// Create a nested encoder for a value of the given length, advancing
+    /// the internal cursor position past it.
+    fn nested(&mut self, len: Length) -> Result<Encoder<'_>> {
+        if let Some(Output::Slice(_)) = self.output {
+            return self.reserve(len).map(Encoder::new);
+        }
+
+        let end = (self.position + len).or_else(|e| self.error(e.kind()))?;
+        self.position = end;
+
+        match self.output.as_mut() {
+            Some(Output::Writer(writer)) => Ok(Encoder::from_writer(&mut **writer)),
+            Some(Output::Uninit(writer)) => Ok(Encoder::from_writer(writer)),
+            #[cfg(feature = "alloc")]
+            Some(Output::Count) => Ok(Encoder {
+                output: Some(Output::Count),
+                position: Length::ZERO,
+                units: 0,
+                lengths: None,
+            }),
+            _ => Err(ErrorKind::Failed.at(end)),
+        }
+    }
+
     /// Reserve a portion of the internal buffer, updating the internal cursor
     /// position and returning a mutable slice.
     fn reserve(&mut self, len: impl TryInto<Length>) -> Result<&mut [u8]> {
@@ -191,47 +596,84 @@ impl<'a> Encoder<'a> {
         }
 
         let end = (self.position + len).or_else(|e| self.error(e.kind()))?;
-        let range = self.position.try_into()?..end.try_into()?;
+        let start = usize::try_from(self.position)?;
+        let range = start..end.try_into()?;
         let position = &mut self.position;
 
-        // TODO(tarcieri): non-panicking version of this code
         // We ensure above that the buffer is untainted and there is sufficient
-        // space to perform this slicing operation, however it would be nice to
-        // have fully panic-free code.
+        // space for this range, so the error case below is unreachable.
         //
-        // Unfortunately tainting the buffer on error is tricky to do when
-        // potentially holding a reference to the buffer, and failure to taint
-        // it would not uphold the invariant that any errors should taint it.
-        let slice = &mut self.bytes.as_mut().expect("DER encoder tainted")[range];
-        *position = end;
+        // Tainting the buffer on error is tricky to do when potentially holding
+        // a reference to the buffer, so it isn't tainted in that case.
+        let slice = match self.output.as_mut() {
+            Some(Output::Slice(bytes)) => bytes.get_mut(range),
+            // Reverse encoders fill the buffer from the end
+            Some(Output::Reverse(bytes)) => {
+                let buffer_len = bytes.len();
+                buffer_len
+                    .checked_sub(range.end)
+                    .zip(buffer_len.checked_sub(range.start))
+                    .and_then(move |(start, end)| bytes.get_mut(start..end))
+            }
+            _ => None,
+        };
 
-        Ok(slice)
+        match slice {
+            Some(slice) => {
+                *position = end;
+                Ok(slice)
+            }
+            None => Err(ErrorKind::Overlength.at(*position)),
+        }
     }
 
     /// Encode a single byte into the backing buffer.
     pub(crate) fn byte(&mut self, byte: u8) -> Result<()> {
-        match self.reserve(1u8)?.first_mut() {
-            Some(b) => {
-                *b = byte;
-                Ok(())
-            }
-            None => self.error(ErrorKind::Overlength),
-        }
+        self.bytes(&[byte])
     }
 
     /// Encode the provided byte slice into the backing buffer.
     pub(crate) fn bytes(&mut self, slice: &[u8]) -> Result<()> {
-        self.reserve(slice.len())?.copy_from_slice(slice);
-        Ok(())
+        let writer: Option<&mut dyn Writer> = match self.output.as_mut() {
+            Some(Output::Writer(writer)) => Some(&mut **writer),
+            Some(Output::Uninit(writer)) => Some(writer),
+            #[cfg(feature = "alloc")]
+            Some(Output::Count) => None,
+            Some(Output::Reverse(_)) => {
+                let buf = self.reserve(slice.len())?;
+                buf.iter_mut().zip(slice).for_each(|(dst, src)| *dst = *src);
+                self.units += 1;
+                return Ok(());
+            }
+            _ => {
+                let buf = self.reserve(slice.len())?;
+                buf.iter_mut().zip(slice).for_each(|(dst, src)| *dst = *src);
+                return Ok(());
+            }
+        };
+
+        let result = Length::try_from(slice.len())
+            .and_then(|len| self.position + len)
+            .and_then(|end| match writer {
+                Some(writer) => writer.write(slice).map(|_| end),
+                None => Ok(end),
+            });
+
+        match result {
+            Ok(end) => {
+                self.position = end;
+                Ok(())
+            }
+            Err(e) => self.error(e.kind()),
+        }
     }
 
     /// Get the size of the buffer in bytes.
     fn buffer_len(&self) -> Result<Length> {
-        self.bytes
-            .as_ref()
-            .map(|bytes| bytes.len())
-            .ok_or_else(|| ErrorKind::Failed.at(self.position))
-            .and_then(TryInto::try_into)
+        match &self.output {
+            Some(Output::Slice(bytes) | Output::Reverse(bytes)) => bytes.len().try_into(),
+            _ => Err(ErrorKind::Failed.at(self.position)),
+        }
     }
 
     /// Get the number of bytes still remaining in the buffer.
@@ -247,9 +689,13 @@ impl<'a> Encoder<'a> {
 
 #[cfg(test)]
 mod tests {
+    use core::mem::MaybeUninit;
     use hex_literal::hex;
 
-    use crate::{asn1::BitString, Encode, ErrorKind, Length, TagMode, TagNumber};
+    use crate::{
+        asn1::{Any, BitString, ContextSpecific, GeneralizedTime, OctetString, SequenceOf, SetOf},
+        Encode, ErrorKind, Length, Tag, TagMode, TagNumber,
+    };
 
     use super::Encoder;
 
@@ -262,6 +708,49 @@ mod tests {
         assert_eq!(err.position(), Some(Length::ZERO));
     }
 
+    #[test]
+    fn encode_to_uninit_buffer() {
+        let mut buf = [MaybeUninit::uninit(); 16];
+        let mut encoder = Encoder::new_uninit(&mut buf);
+        encoder
+            .sequence(Length::new(6), |nested| {
+                nested.encode(&1u8)?;
+                nested.encode(&2u8)
+            })
+            .unwrap();
+        encoder.encode(&true).unwrap();
+        assert_eq!(encoder.position(), Length::new(11));
+        assert_eq!(encoder.finish_uninit().unwrap().len(), 11);
+    }
+
+    #[test]
+    fn overlength_uninit_buffer() {
+        let mut buf = [MaybeUninit::uninit(); 2];
+        let mut encoder = Encoder::new_uninit(&mut buf);
+        let err = 0x1234u16.encode(&mut encoder).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Overlength);
+        assert!(encoder.finish_uninit().is_err());
+    }
+
+    #[test]
+    fn incomplete_nested_uninit_buffer() {
+        // A nested value which fails partway through leaves part of the space
+        // reserved for it unwritten, so it must not be returned by
+        // `finish_uninit`
+        let mut buf = [MaybeUninit::uninit(); 16];
+        let mut encoder = Encoder::new_uninit(&mut buf);
+        let result = encoder.sequence(Length::new(3), |nested| {
+            nested.null()?;
+            Err(ErrorKind::Failed.into())
+        });
+        assert!(result.is_err());
+        assert_eq!(encoder.position(), Length::new(5));
+        assert_eq!(
+            encoder.finish_uninit().err().unwrap().kind(),
+            ErrorKind::Failed
+        );
+    }
+
     #[test]
     fn context_specific_with_implicit_field() {
         // From RFC8410 Section 10.3:
@@ -284,4 +773,267 @@ mod tests {
 
         assert_eq!(EXPECTED_BYTES, encoder.finish().unwrap());
     }
+
+    /// `SEQUENCE`s nested `depth` levels deep, which count the number of
+    /// times their lengths are computed using `value_len`.
+    struct Nested<'a> {
+        depth: u8,
+        calls: &'a core::cell::Cell<usize>,
+    }
+
+    impl Nested<'_> {
+        fn child(&self) -> Option<Self> {
+            self.depth.checked_sub(1).map(|depth| Nested {
+                depth,
+                calls: self.calls,
+            })
+        }
+    }
+
+    impl crate::EncodeValue for Nested<'_> {
+        fn value_len(&self) -> crate::Result<Length> {
+            self.calls.set(self.calls.get() + 1);
+            self.child()
+                .map_or(true.encoded_len(), |child| child.encoded_len())
+        }
+
+        fn encode_value(&self, encoder: &mut Encoder<'_>) -> crate::Result<()> {
+            match self.child() {
+                Some(child) => encoder.encode(&child),
+                None => encoder.encode(&true),
+            }
+        }
+    }
+
+    impl crate::FixedTag for Nested<'_> {
+        const TAG: Tag = Tag::Sequence;
+    }
+
+    #[test]
+    fn encode_nested_to_slice() {
+        let calls = core::cell::Cell::new(0);
+        let mut buf = [0u8; 64];
+        let der = Nested {
+            depth: 15,
+            calls: &calls,
+        }
+        .encode_to_slice(&mut buf)
+        .unwrap();
+        assert_eq!(der.len(), 16 * 2 + 3);
+
+        // The length of each `SEQUENCE` is computed by each of its ancestors
+        assert_eq!(calls.get(), (1..=16).sum::<usize>());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn encode_nested_to_vec() {
+        let calls = core::cell::Cell::new(0);
+        let nested = Nested {
+            depth: 15,
+            calls: &calls,
+        };
+        let der = nested.to_vec().unwrap();
+
+        // Lengths are recorded by a counting pass instead
+        assert_eq!(calls.get(), 0);
+
+        let mut buf = [0u8; 64];
+        assert_eq!(der, nested.encode_to_slice(&mut buf).unwrap());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn encode_to_vec_appends() {
+        let mut buf = alloc::vec![0xAA];
+        assert_eq!(true.encode_to_vec(&mut buf).unwrap(), Length::new(3));
+        assert_eq!(buf, hex!("AA 0101FF"));
+    }
+
+    /// Writer which records each write it receives.
+    #[cfg(feature = "alloc")]
+    #[derive(Default)]
+    struct RecordingWriter(alloc::vec::Vec<alloc::vec::Vec<u8>>);
+
+    #[cfg(feature = "alloc")]
+    impl crate::Writer for RecordingWriter {
+        fn write(&mut self, slice: &[u8]) -> crate::Result<()> {
+            self.0.push(slice.to_vec());
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn encode_to_writer() {
+        use crate::asn1::{OctetString, SequenceOf};
+
+        let mut seq = SequenceOf::<OctetString<'_>, 2>::new();
+        seq.add(OctetString::new(&[1, 2]).unwrap()).unwrap();
+        seq.add(OctetString::new(&[3]).unwrap()).unwrap();
+
+        let mut writer = RecordingWriter::default();
+        let len = seq.encode_to_writer(&mut writer).unwrap();
+        assert_eq!(len, seq.encoded_len().unwrap());
+        assert_eq!(writer.0.concat(), seq.to_vec().unwrap());
+
+        // Contents are written as they're encoded rather than buffered
+        assert!(writer.0.len() > 1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn encode_sequence_to_writer() {
+        let mut writer = alloc::vec::Vec::new();
+        let mut encoder = Encoder::from_writer(&mut writer);
+        encoder
+            .sequence(6u8.into(), |encoder| {
+                encoder.encode(&1u8)?;
+                encoder.encode(&true)
+            })
+            .unwrap();
+        assert_eq!(encoder.position(), Length::new(8));
+        assert_eq!(writer, hex!("3006 020101 0101FF"));
+
+        // Sequence contents shorter than the given length
+        let mut writer = alloc::vec::Vec::new();
+        let mut encoder = Encoder::from_writer(&mut writer);
+        let err = encoder
+            .sequence(7u8.into(), |encoder| encoder.encode(&1u8))
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::Length { tag: Tag::Sequence });
+        assert!(encoder.is_failed());
+    }
+
+    /// Assert that encoding the given value back-to-front produces the same
+    /// output as encoding it front-to-back.
+    fn assert_reverse_eq<T: Encode + ?Sized>(value: &T) {
+        let mut forward = [0u8; 1024];
+        let expected = value.encode_to_slice(&mut forward).unwrap();
+
+        // Fill the buffer to check that no stale bytes are left in the output
+        let mut reverse = [0xAAu8; 1024];
+        let mut encoder = Encoder::new_reverse(&mut reverse);
+        encoder.encode(value).unwrap();
+        assert_eq!(
+            encoder.position(),
+            Length::try_from(expected.len()).unwrap()
+        );
+        assert_eq!(encoder.finish().unwrap(), expected);
+    }
+
+    #[test]
+    fn reverse_encode_nested() {
+        let calls = core::cell::Cell::new(0);
+        let value = Nested {
+            depth: 16,
+            calls: &calls,
+        };
+
+        let mut buf = [0u8; 64];
+        let actual = value.encode_to_slice_reverse(&mut buf).unwrap();
+        assert_eq!(actual.len(), 37);
+        assert_eq!(calls.get(), 0);
+
+        assert_reverse_eq(&value);
+    }
+
+    #[test]
+    fn reverse_encode_equivalence() {
+        let mut inner = SequenceOf::<u8, 3>::new();
+        for n in [1, 0x80, 0xFF] {
+            inner.add(n).unwrap();
+        }
+
+        let mut outer = SequenceOf::<SequenceOf<u8, 3>, 2>::new();
+        outer.add(inner.clone()).unwrap();
+        outer.add(SequenceOf::new()).unwrap();
+
+        assert_reverse_eq(&inner);
+        assert_reverse_eq(&outer);
+        assert_reverse_eq(&[inner.clone(), inner.clone()]);
+        assert_reverse_eq(&SetOf::try_from([3i32, -1, 0x1234]).unwrap());
+        assert_reverse_eq(&BitString::new(4, &[0xF0, 0x0F, 0xF0]).unwrap());
+        assert_reverse_eq(&OctetString::new(&[0xAB; 300]).unwrap());
+        assert_reverse_eq(&-129i32);
+        assert_reverse_eq(&Any::new(Tag::Sequence, &hex!("020101 0101FF")).unwrap());
+        assert_reverse_eq(
+            &GeneralizedTime::from_unix_duration(core::time::Duration::from_secs(1_000_000_000))
+                .unwrap(),
+        );
+
+        for tag_mode in [TagMode::Explicit, TagMode::Implicit] {
+            assert_reverse_eq(&ContextSpecific {
+                tag_number: TagNumber::new(2),
+                tag_mode,
+                value: outer.clone(),
+            });
+            assert_reverse_eq(&ContextSpecific {
+                tag_number: TagNumber::new(0),
+                tag_mode,
+                value: true,
+            });
+        }
+    }
+
+    /// `SEQUENCE` which encodes its fields one after the other, rather than
+    /// using [`Encoder::encode_all`].
+    struct Pair(u8, bool);
+
+    impl crate::EncodeValue for Pair {
+        fn value_len(&self) -> crate::Result<Length> {
+            self.0.encoded_len()? + self.1.encoded_len()?
+        }
+
+        fn encode_value(&self, encoder: &mut Encoder<'_>) -> crate::Result<()> {
+            encoder.encode(&self.0)?;
+            encoder.encode(&self.1)
+        }
+    }
+
+    impl crate::FixedTag for Pair {
+        const TAG: Tag = Tag::Sequence;
+    }
+
+    #[test]
+    fn reverse_encode_out_of_order() {
+        let value = Pair(1, true);
+        let mut buf = [0u8; 16];
+        assert_eq!(
+            value.encode_to_slice_reverse(&mut buf).unwrap(),
+            hex!("3006 020101 0101FF")
+        );
+
+        assert_reverse_eq(&[Pair(1, true), Pair(2, false)]);
+    }
+
+    #[test]
+    fn reverse_encode_sequence() {
+        let mut buf = [0u8; 16];
+        let mut encoder = Encoder::new_reverse(&mut buf);
+        encoder
+            .sequence(6u8.into(), |encoder| {
+                encoder.encode(&1u8)?;
+                encoder.encode(&true)
+            })
+            .unwrap();
+        assert_eq!(encoder.finish().unwrap(), hex!("3006 020101 0101FF"));
+    }
+
+    #[test]
+    fn reverse_encode_prepends() {
+        let mut buf = [0u8; 16];
+        let mut encoder = Encoder::new_reverse(&mut buf);
+        encoder.encode(&1u8).unwrap();
+        encoder.encode(&true).unwrap();
+        assert_eq!(encoder.finish().unwrap(), hex!("0101FF 020101"));
+    }
+
+    #[test]
+    fn reverse_overlength_message() {
+        let mut buf = [0u8; 4];
+        let err = [1u8, 2].encode_to_slice_reverse(&mut buf).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Overlength);
+    }
 }
@@ -0,0 +1,26 @@
+//! ASN.1 encoding rules.
+
+/// ASN.1 encoding rules enforced by a [`Decoder`][`crate::Decoder`].
+///
+/// This library always encodes DER, which is a restricted subset of BER.
+/// When decoding, some BER-only encodings can be tolerated by selecting
+/// [`EncodingRules::Ber`]. Other BER-encoded messages (e.g. those using
+/// indefinite lengths) can be converted to DER using
+/// [`ber::to_der`][`crate::ber::to_der`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum EncodingRules {
+    /// Basic Encoding Rules.
+    ///
+    /// Permits the constructed form of string types (e.g. a segmented
+    /// `OCTET STRING`) in addition to everything allowed by DER.
+    Ber,
+
+    /// Distinguished Encoding Rules (default).
+    Der,
+}
+
+impl Default for EncodingRules {
+    fn default() -> Self {
+        Self::Der
+    }
+}
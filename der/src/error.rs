@@ -76,6 +76,18 @@ impl fmt::Display for Error {
     }
 }
 
+#[cfg(feature = "defmt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
+impl defmt::Format for Error {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{}", self.kind);
+
+        if let Some(pos) = self.position {
+            defmt::write!(f, " at DER byte {}", pos);
+        }
+    }
+}
+
 impl From<ErrorKind> for Error {
     fn from(kind: ErrorKind) -> Error {
         Error {
@@ -86,8 +98,8 @@ impl From<ErrorKind> for Error {
 }
 
 impl From<Infallible> for Error {
-    fn from(_: Infallible) -> Error {
-        unreachable!()
+    fn from(never: Infallible) -> Error {
+        match never {}
     }
 }
 
@@ -136,9 +148,26 @@ impl From<time::error::ComponentRange> for Error {
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum ErrorKind {
+    /// Size limit of a `SEQUENCE OF` or `SET OF` exceeded.
+    ///
+    /// See [`Decoder::set_max_elements`][`crate::Decoder::set_max_elements`]
+    /// and [`Decoder::set_max_collection_len`][`crate::Decoder::set_max_collection_len`].
+    CollectionLimit {
+        /// Tag of the collection which exceeded the limit.
+        tag: Tag,
+    },
+
     /// Date-and-time related errors.
     DateTime,
 
+    /// Maximum nesting depth of the decoder exceeded.
+    ///
+    /// See [`Decoder::set_max_depth`][`crate::Decoder::set_max_depth`].
+    DepthLimit {
+        /// Maximum nesting depth which was exceeded.
+        max_depth: u32,
+    },
+
     /// This error indicates a previous DER parsing operation resulted in
     /// an error and tainted the state of a `Decoder` or `Encoder`.
     ///
@@ -220,6 +249,16 @@ pub enum ErrorKind {
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     PermissionDenied,
 
+    /// Value uses the primitive or constructed form when the other one is
+    /// required, e.g. a constructed `OCTET STRING` in DER.
+    TagForm {
+        /// Tag of the value.
+        tag: Tag,
+
+        /// Does the value use the constructed form?
+        constructed: bool,
+    },
+
     /// Unknown tag mode.
     TagModeUnknown,
 
@@ -286,7 +325,11 @@ impl ErrorKind {
 impl fmt::Display for ErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            ErrorKind::CollectionLimit { tag } => write!(f, "{} exceeds size limit", tag),
             ErrorKind::DateTime => write!(f, "date/time error"),
+            ErrorKind::DepthLimit { max_depth } => {
+                write!(f, "maximum nesting depth exceeded: {}", max_depth)
+            }
             ErrorKind::Failed => write!(f, "operation failed"),
             #[cfg(feature = "std")]
             ErrorKind::FileNotFound => f.write_str("file not found"),
@@ -307,7 +350,14 @@ impl fmt::Display for ErrorKind {
             ErrorKind::OidMalformed => write!(f, "malformed OID"),
             #[cfg(feature = "oid")]
             ErrorKind::OidUnknown { oid } => {
-                write!(f, "unknown/unsupported OID: {}", oid)
+                write!(f, "unknown/unsupported OID: {}", oid)?;
+
+                #[cfg(feature = "oid-db")]
+                if let Some(name) = oid.name() {
+                    write!(f, " ({})", name)?;
+                }
+
+                Ok(())
             }
             ErrorKind::SetOrdering => write!(f, "ordering error"),
             ErrorKind::Overflow => write!(f, "integer overflow"),
@@ -316,6 +366,16 @@ impl fmt::Display for ErrorKind {
             ErrorKind::Pem(e) => write!(f, "PEM error: {}", e),
             #[cfg(feature = "std")]
             ErrorKind::PermissionDenied => f.write_str("permission denied"),
+            ErrorKind::TagForm { tag, constructed } => write!(
+                f,
+                "{} form not permitted for {}",
+                if *constructed {
+                    "constructed"
+                } else {
+                    "primitive"
+                },
+                tag
+            ),
             ErrorKind::TagModeUnknown => write!(f, "unknown tag mode"),
             ErrorKind::TagNumberInvalid => write!(f, "invalid tag number"),
             ErrorKind::TagUnexpected { expected, actual } => {
@@ -342,3 +402,101 @@ impl fmt::Display for ErrorKind {
         }
     }
 }
+
+#[cfg(feature = "defmt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
+impl defmt::Format for ErrorKind {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self {
+            ErrorKind::CollectionLimit { tag } => defmt::write!(f, "{} exceeds size limit", tag),
+            ErrorKind::DateTime => defmt::write!(f, "date/time error"),
+            ErrorKind::DepthLimit { max_depth } => {
+                defmt::write!(f, "maximum nesting depth exceeded: {=u32}", max_depth)
+            }
+            ErrorKind::Failed => defmt::write!(f, "operation failed"),
+            #[cfg(feature = "std")]
+            ErrorKind::FileNotFound => defmt::write!(f, "file not found"),
+            ErrorKind::Incomplete {
+                expected_len,
+                actual_len,
+            } => defmt::write!(
+                f,
+                "ASN.1 DER message is incomplete: expected {}, actual {}",
+                expected_len,
+                actual_len
+            ),
+            #[cfg(feature = "std")]
+            ErrorKind::Io(_) => defmt::write!(f, "I/O error"),
+            ErrorKind::Length { tag } => defmt::write!(f, "incorrect length for {}", tag),
+            ErrorKind::Noncanonical { tag } => {
+                defmt::write!(f, "ASN.1 {} not canonically encoded as DER", tag)
+            }
+            ErrorKind::OidMalformed => defmt::write!(f, "malformed OID"),
+            #[cfg(feature = "oid")]
+            ErrorKind::OidUnknown { oid } => {
+                defmt::write!(f, "unknown/unsupported OID: {=[u8]:02x}", oid.as_bytes())
+            }
+            ErrorKind::SetOrdering => defmt::write!(f, "ordering error"),
+            ErrorKind::Overflow => defmt::write!(f, "integer overflow"),
+            ErrorKind::Overlength => defmt::write!(f, "ASN.1 DER message is too long"),
+            #[cfg(feature = "pem")]
+            ErrorKind::Pem(_) => defmt::write!(f, "PEM error"),
+            #[cfg(feature = "std")]
+            ErrorKind::PermissionDenied => defmt::write!(f, "permission denied"),
+            ErrorKind::TagForm { tag, constructed } => defmt::write!(
+                f,
+                "{=str} form not permitted for {}",
+                if *constructed {
+                    "constructed"
+                } else {
+                    "primitive"
+                },
+                tag
+            ),
+            ErrorKind::TagModeUnknown => defmt::write!(f, "unknown tag mode"),
+            ErrorKind::TagNumberInvalid => defmt::write!(f, "invalid tag number"),
+            ErrorKind::TagUnexpected { expected, actual } => {
+                defmt::write!(f, "unexpected ASN.1 DER tag: ");
+
+                if let Some(tag) = expected {
+                    defmt::write!(f, "expected {}, ", tag);
+                }
+
+                defmt::write!(f, "got {}", actual)
+            }
+            ErrorKind::TagUnknown { byte } => {
+                defmt::write!(f, "unknown/unsupported ASN.1 DER tag: {=u8:#04x}", byte)
+            }
+            ErrorKind::TrailingData { decoded, remaining } => defmt::write!(
+                f,
+                "trailing data at end of DER message: decoded {} bytes, {} bytes remaining",
+                decoded,
+                remaining
+            ),
+            ErrorKind::Utf8(e) => {
+                defmt::write!(f, "invalid UTF-8 after {=usize} bytes", e.valid_up_to())
+            }
+            ErrorKind::Value { tag } => defmt::write!(f, "malformed ASN.1 DER value for {}", tag),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(all(feature = "alloc", feature = "oid-db"))]
+    #[test]
+    fn oid_unknown_display_name() {
+        use super::ErrorKind;
+        use crate::oid_db::rfc5912::RSA_ENCRYPTION;
+        use alloc::string::ToString;
+
+        let err = ErrorKind::OidUnknown {
+            oid: RSA_ENCRYPTION,
+        };
+
+        assert_eq!(
+            err.to_string(),
+            "unknown/unsupported OID: 1.2.840.113549.1.1.1 (rsaEncryption)"
+        );
+    }
+}
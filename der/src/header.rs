@@ -1,16 +1,28 @@
 //! ASN.1 DER headers.
 
-use crate::{Decode, Decoder, DerOrd, Encode, Encoder, ErrorKind, Length, Result, Tag};
+use crate::tag::CONSTRUCTED_FLAG;
+use crate::{
+    Decode, Decoder, DerOrd, Encode, Encoder, EncodingRules, ErrorKind, Length, Result, Tag,
+};
 use core::cmp::Ordering;
 
 /// ASN.1 DER headers: tag + length component of TLV-encoded values
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Header {
     /// Tag representing the type of the encoded value
     pub tag: Tag,
 
     /// Length of the encoded value
     pub length: Length,
+
+    /// Is the value encoded using the constructed (as opposed to primitive)
+    /// form?
+    ///
+    /// This always matches [`Tag::is_constructed`] except when decoding BER,
+    /// which permits the constructed form for `UNIVERSAL` string types (see
+    /// [`Tag::is_segmentable`]).
+    pub constructed: bool,
 }
 
 impl Header {
@@ -19,13 +31,36 @@ impl Header {
     /// Returns an error if the length exceeds the limits of [`Length`].
     pub fn new(tag: Tag, length: impl TryInto<Length>) -> Result<Self> {
         let length = length.try_into().map_err(|_| ErrorKind::Overflow)?;
-        Ok(Self { tag, length })
+
+        Ok(Self {
+            tag,
+            length,
+            constructed: tag.is_constructed(),
+        })
+    }
+
+    /// Get the identifier octet for this header, i.e. the octet encoding of
+    /// its [`Tag`] using the form indicated by [`Header::constructed`].
+    pub fn identifier_octet(&self) -> u8 {
+        if self.constructed {
+            self.tag.octet() | CONSTRUCTED_FLAG
+        } else {
+            self.tag.octet() & !CONSTRUCTED_FLAG
+        }
     }
 }
 
 impl Decode<'_> for Header {
     fn decode(decoder: &mut Decoder<'_>) -> Result<Header> {
-        let tag = Tag::decode(decoder)?;
+        let (tag, constructed) = Tag::from_identifier(decoder.byte()?)?;
+
+        if constructed != tag.is_constructed()
+            && !(constructed
+                && tag.is_segmentable()
+                && decoder.encoding_rules() == EncodingRules::Ber)
+        {
+            return Err(decoder.error(ErrorKind::TagForm { tag, constructed }));
+        }
 
         let length = Length::decode(decoder).map_err(|e| {
             if e.kind() == ErrorKind::Overlength {
@@ -35,7 +70,11 @@ impl Decode<'_> for Header {
             }
         })?;
 
-        Ok(Self { tag, length })
+        Ok(Self {
+            tag,
+            length,
+            constructed,
+        })
     }
 }
 
@@ -45,16 +84,34 @@ impl Encode for Header {
     }
 
     fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
-        self.tag.encode(encoder)?;
-        self.length.encode(encoder)
+        let len = self.encoded_len()?;
+        encoder.contiguous(len, |encoder| {
+            encoder.byte(self.identifier_octet())?;
+            self.length.encode(encoder)
+        })?;
+        Ok(())
     }
 }
 
 impl DerOrd for Header {
     fn der_cmp(&self, other: &Self) -> Result<Ordering> {
-        match self.tag.der_cmp(&other.tag)? {
+        match self.identifier_octet().cmp(&other.identifier_octet()) {
             Ordering::Equal => self.length.der_cmp(&other.length),
             ordering => Ok(ordering),
         }
     }
 }
+
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+impl<'a> arbitrary::Arbitrary<'a> for Header {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let tag: Tag = u.arbitrary()?;
+
+        Ok(Self {
+            tag,
+            length: u.arbitrary()?,
+            constructed: tag.is_constructed(),
+        })
+    }
+}
@@ -4,6 +4,7 @@ use crate::{Decode, Decoder, DerOrd, Encode, Encoder, Error, ErrorKind, Result};
 use core::{
     cmp::Ordering,
     fmt,
+    iter::Sum,
     ops::{Add, Sub},
 };
 
@@ -38,6 +39,50 @@ impl Length {
         self == Length::ZERO
     }
 
+    /// Add two lengths, returning `None` if the result would exceed
+    /// [`Length::MAX`].
+    pub fn checked_add(self, rhs: Length) -> Option<Self> {
+        (self + rhs).ok()
+    }
+
+    /// Subtract `rhs` from this length, returning `None` if the result would
+    /// be negative.
+    pub fn checked_sub(self, rhs: Length) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Length)
+    }
+
+    /// Add two lengths, saturating at [`Length::MAX`].
+    pub fn saturating_add(self, rhs: Length) -> Self {
+        self.checked_add(rhs).unwrap_or(Length::MAX)
+    }
+
+    /// Subtract `rhs` from this length, saturating at [`Length::ZERO`].
+    pub fn saturating_sub(self, rhs: Length) -> Self {
+        self.checked_sub(rhs).unwrap_or(Length::ZERO)
+    }
+
+    /// Sum the given lengths, each of which may be an error (e.g. the result
+    /// of [`Encode::encoded_len`]), returning the first error encountered or
+    /// an [`ErrorKind::Overflow`] error if the sum exceeds [`Length::MAX`].
+    ///
+    /// This is useful for computing the length of values with many fields:
+    ///
+    /// ```
+    /// use der::{Encode, Length};
+    ///
+    /// let len = Length::try_sum([true.encoded_len(), 42u8.encoded_len(), Ok(Length::ONE)])?;
+    /// assert_eq!(len, Length::new(7));
+    /// # Ok::<(), der::Error>(())
+    /// ```
+    pub fn try_sum<I>(lengths: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = Result<Length>>,
+    {
+        lengths
+            .into_iter()
+            .try_fold(Length::ZERO, |sum, len| sum + len?)
+    }
+
     /// Get the length of DER Tag-Length-Value (TLV) encoded data if `self`
     /// is the length of the inner "value" portion of the message.
     pub fn for_tlv(self) -> Result<Self> {
@@ -143,6 +188,22 @@ impl Sub<Length> for Result<Length> {
     }
 }
 
+/// Sum lengths, returning an [`ErrorKind::Overflow`] error if the sum exceeds
+/// [`Length::MAX`].
+///
+/// To sum lengths which may themselves be errors, see [`Length::try_sum`].
+impl Sum<Length> for Result<Length> {
+    fn sum<I: Iterator<Item = Length>>(iter: I) -> Self {
+        Length::try_sum(iter.map(Ok))
+    }
+}
+
+impl<'a> Sum<&'a Length> for Result<Length> {
+    fn sum<I: Iterator<Item = &'a Length>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 impl From<u8> for Length {
     fn from(len: u8) -> Length {
         Length(len as u32)
@@ -238,19 +299,23 @@ impl Encode for Length {
     }
 
     fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
-        if let Some(tag_byte) = self.initial_octet() {
-            encoder.byte(tag_byte)?;
-
-            // Strip leading zeroes
-            match self.0.to_be_bytes() {
-                [0, 0, 0, byte] => encoder.byte(byte),
-                [0, 0, bytes @ ..] => encoder.bytes(&bytes),
-                [0, bytes @ ..] => encoder.bytes(&bytes),
-                bytes => encoder.bytes(&bytes),
+        let len = self.encoded_len()?;
+        encoder.contiguous(len, |encoder| {
+            if let Some(tag_byte) = self.initial_octet() {
+                encoder.byte(tag_byte)?;
+
+                // Strip leading zeroes
+                match self.0.to_be_bytes() {
+                    [0, 0, 0, byte] => encoder.byte(byte),
+                    [0, 0, bytes @ ..] => encoder.bytes(&bytes),
+                    [0, bytes @ ..] => encoder.bytes(&bytes),
+                    bytes => encoder.bytes(&bytes),
+                }
+            } else {
+                encoder.byte(self.0 as u8)
             }
-        } else {
-            encoder.byte(self.0 as u8)
-        }
+        })?;
+        Ok(())
     }
 }
 
@@ -275,10 +340,26 @@ impl fmt::Display for Length {
     }
 }
 
+#[cfg(feature = "defmt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
+impl defmt::Format for Length {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{=u32}", self.0)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+impl<'a> arbitrary::Arbitrary<'a> for Length {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self(u.int_in_range(0..=MAX_U32)?))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Length;
-    use crate::{Decode, DerOrd, Encode, ErrorKind};
+    use crate::{Decode, DerOrd, Encode, ErrorKind, Result};
     use core::cmp::Ordering;
 
     #[test]
@@ -357,6 +438,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn checked_and_saturating() {
+        let two = Length::new(2);
+        assert_eq!(Length::ONE.checked_add(Length::ONE), Some(two));
+        assert_eq!(Length::MAX.checked_add(Length::ONE), None);
+        assert_eq!(two.checked_sub(Length::ONE), Some(Length::ONE));
+        assert_eq!(Length::ONE.checked_sub(two), None);
+
+        assert_eq!(Length::MAX.saturating_add(two), Length::MAX);
+        assert_eq!(Length::ONE.saturating_add(Length::ONE), two);
+        assert_eq!(Length::ONE.saturating_sub(two), Length::ZERO);
+        assert_eq!(two.saturating_sub(Length::ONE), Length::ONE);
+    }
+
+    #[test]
+    fn sum() {
+        let lengths = [Length::ONE, Length::new(2), Length::new(3)];
+        assert_eq!(lengths.iter().sum::<Result<Length>>(), Ok(Length::new(6)));
+        assert_eq!(
+            lengths.iter().copied().sum::<Result<Length>>(),
+            Ok(Length::new(6))
+        );
+        assert_eq!(
+            [Length::MAX, Length::ONE]
+                .iter()
+                .sum::<Result<Length>>()
+                .unwrap_err()
+                .kind(),
+            ErrorKind::Overflow
+        );
+        assert_eq!(
+            core::iter::empty::<Length>().sum::<Result<Length>>(),
+            Ok(Length::ZERO)
+        );
+    }
+
+    #[test]
+    fn try_sum() {
+        assert_eq!(
+            Length::try_sum([Ok(Length::ONE), Ok(Length::new(2))]),
+            Ok(Length::new(3))
+        );
+        assert_eq!(
+            Length::try_sum([Ok(Length::ONE), Err(ErrorKind::Failed.into())])
+                .unwrap_err()
+                .kind(),
+            ErrorKind::Failed
+        );
+        assert_eq!(
+            Length::try_sum([Ok(Length::MAX), Ok(Length::ONE)])
+                .unwrap_err()
+                .kind(),
+            ErrorKind::Overflow
+        );
+    }
+
     #[test]
     fn der_ord() {
         assert_eq!(Length::ONE.der_cmp(&Length::MAX).unwrap(), Ordering::Less);
@@ -30,6 +30,8 @@
 //!   Requires `alloc` feature. See also [`SetOf`].
 //! - [`Option`]: ASN.1 `OPTIONAL`.
 //! - [`SystemTime`][`std::time::SystemTime`]: ASN.1 `GeneralizedTime`. Requires `std` feature.
+//! - [`IpAddr`][`std::net::IpAddr`], [`Ipv4Addr`][`std::net::Ipv4Addr`],
+//!   [`Ipv6Addr`][`std::net::Ipv6Addr`]: ASN.1 `OCTET STRING`. Requires `std` feature.
 //! - [`Vec`][`alloc::vec::Vec`]: ASN.1 `SEQUENCE OF`. Requires `alloc` feature.
 //! - `[T; N]`: ASN.1 `SEQUENCE OF`. See also [`SequenceOf`].
 //!
@@ -220,6 +222,10 @@
 //! - [`Choice`]: derive for `CHOICE` enum (see [`der_derive::Choice`])
 //! - [`Enumerated`]: derive for `ENUMERATED` enum (see [`der_derive::Enumerated`])
 //! - [`Sequence`]: derive for `SEQUENCE` struct (see [`der_derive::Sequence`])
+
+//!
+//! The [`asn1!`] macro can also be used to generate types using these
+//! derives from ASN.1 module notation (see [`der_derive::asn1`]).
 //!
 //! ### Derive [`Sequence`] for struct
 //! The following is a code example of how to use the [`Sequence`] custom derive:
@@ -332,40 +338,72 @@ extern crate alloc;
 extern crate std;
 
 pub mod asn1;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod ber;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod debug;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod dynamic;
+#[cfg(all(feature = "alloc", feature = "proptest"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "proptest"))))]
+pub mod strategy;
 
 pub(crate) mod arrayvec;
 mod byte_slice;
+mod charset;
 mod datetime;
 mod decode;
 mod decoder;
 mod encode;
 mod encoder;
+mod encoding_rules;
 mod error;
 mod header;
 mod length;
 mod ord;
+mod reader;
 mod str_slice;
 mod tag;
+mod tlv;
+mod writer;
 
 #[cfg(feature = "alloc")]
 mod document;
+#[cfg(feature = "alloc")]
+mod tlv_builder;
 
 pub use crate::{
     asn1::{Any, Choice, Sequence},
-    datetime::DateTime,
+    datetime::{DateTime, LeapSecondPolicy, LenientDateTime},
     decode::{Decode, DecodeOwned, DecodeValue},
-    decoder::Decoder,
+    decoder::{Decoder, Progress, TlvIter},
     encode::{Encode, EncodeValue},
     encoder::Encoder,
+    encoding_rules::EncodingRules,
     error::{Error, ErrorKind, Result},
     header::Header,
     length::Length,
     ord::{DerOrd, ValueOrd},
+    reader::Reader,
     tag::{Class, FixedTag, Tag, TagMode, TagNumber, Tagged},
+    tlv::{RawDerOf, Tlv},
+    writer::Writer,
 };
 
+#[cfg(feature = "std")]
+pub use crate::{reader::IoReader, writer::IoWriter};
+
+#[cfg(feature = "alloc")]
+pub use document::{Document, DocumentMessage, OwnedDocument};
+
 #[cfg(feature = "alloc")]
-pub use document::Document;
+pub use tlv_builder::TlvBuilder;
+
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+pub use document::SecretDocument;
 
 #[cfg(feature = "bigint")]
 #[cfg_attr(docsrs, doc(cfg(feature = "bigint")))]
@@ -373,11 +411,22 @@ pub use crypto_bigint as bigint;
 
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
-pub use der_derive::{Choice, Enumerated, Newtype, Sequence, ValueOrd};
+pub use der_derive::{asn1, Choice, Enumerated, Newtype, Sequence, ValueOrd};
+
+#[cfg(feature = "oid-db")]
+#[cfg_attr(docsrs, doc(cfg(feature = "oid-db")))]
+pub use const_oid::db as oid_db;
 
 #[cfg(feature = "pem")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
-pub use pem_rfc7468 as pem;
+pub use {
+    crate::{decode::DecodePem, encode::EncodePem},
+    pem_rfc7468 as pem,
+};
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+pub use chrono;
 
 #[cfg(feature = "time")]
 #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
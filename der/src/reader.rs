@@ -0,0 +1,169 @@
+//! Reader trait.
+
+use crate::{ErrorKind, Result};
+
+#[cfg(feature = "alloc")]
+use {
+    crate::{Decode, DecodeOwned, Decoder, Header, Length},
+    alloc::vec::Vec,
+};
+
+/// Maximum number of bytes of a value's contents read into a buffer at once.
+///
+/// Bounds the memory allocated in advance of the data actually being read,
+/// which would otherwise be controlled by the length of the value.
+#[cfg(feature = "alloc")]
+const CHUNK_SIZE: usize = 4096;
+
+/// Input from which DER is read incrementally.
+///
+/// Unlike [`Encoder`][`crate::Encoder`], which can write through a
+/// [`Writer`][`crate::Writer`], [`Decoder`][`crate::Decoder`] always decodes
+/// from a byte slice: it's zero-copy, and types such as
+/// [`OctetString`][`crate::asn1::OctetString`] borrow their contents from
+/// the input, which therefore has to be contiguous and outlive them.
+///
+/// A [`Reader`] instead allows DER to be read from e.g. a socket or file one
+/// complete TLV-encoded value at a time (see [`Reader::read_tlv`]). Each
+/// value is buffered before being decoded, either using a
+/// [`Decoder`][`crate::Decoder`] or, for types which don't borrow from their
+/// input, using [`Reader::decode`].
+pub trait Reader {
+    /// Fill the provided buffer with bytes read from the input, returning an
+    /// error if the input ends first.
+    fn read_into(&mut self, buf: &mut [u8]) -> Result<()>;
+
+    /// Read a single byte.
+    fn read_byte(&mut self) -> Result<u8> {
+        let mut byte = [0];
+        self.read_into(&mut byte)?;
+        let [byte] = byte;
+        Ok(byte)
+    }
+
+    /// Read the next TLV-encoded value from the input, appending it to the
+    /// provided buffer and returning its length.
+    ///
+    /// Only the header of the value is checked to be valid DER.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn read_tlv(&mut self, buf: &mut Vec<u8>) -> Result<Length> {
+        let start = buf.len();
+        let identifier = self.read_byte()?;
+        let initial_octet = self.read_byte()?;
+        buf.extend_from_slice(&[identifier, initial_octet]);
+
+        // Long form lengths of up to 4 bytes (see `Length`'s `Decode` impl)
+        if let 0x81..=0x84 = initial_octet {
+            for _ in 0..(initial_octet & 0x7F) {
+                buf.push(self.read_byte()?);
+            }
+        }
+
+        let header = buf
+            .get(start..)
+            .ok_or_else(|| ErrorKind::Failed.into())
+            .and_then(Decoder::new)
+            .and_then(|mut decoder| Header::decode(&mut decoder))?;
+
+        let header_len = Length::try_from(buf.len() - start)?;
+        let mut remaining = usize::try_from(header.length)?;
+
+        while remaining > 0 {
+            let chunk_len = remaining.min(CHUNK_SIZE);
+            let pos = buf.len();
+            buf.resize(pos + chunk_len, 0);
+            self.read_into(&mut buf[pos..])?;
+            remaining -= chunk_len;
+        }
+
+        header_len + header.length
+    }
+
+    /// Read the next TLV-encoded value from the input and decode it as `T`.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn decode<T: DecodeOwned>(&mut self) -> Result<T>
+    where
+        Self: Sized,
+    {
+        let mut buf = Vec::new();
+        self.read_tlv(&mut buf)?;
+        T::from_der(&buf)
+    }
+}
+
+impl Reader for &[u8] {
+    fn read_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        if buf.len() > self.len() {
+            return Err(ErrorKind::Incomplete {
+                expected_len: buf.len().try_into()?,
+                actual_len: self.len().try_into()?,
+            }
+            .into());
+        }
+
+        let (bytes, rest) = self.split_at(buf.len());
+        buf.copy_from_slice(bytes);
+        *self = rest;
+        Ok(())
+    }
+}
+
+/// [`Reader`] which reads from a [`std::io::Read`] implementation.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug)]
+pub struct IoReader<R: std::io::Read>(pub R);
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<R: std::io::Read> Reader for IoReader<R> {
+    fn read_into(&mut self, buf: &mut [u8]) -> Result<()> {
+        Ok(self.0.read_exact(buf)?)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::Reader;
+    use crate::{asn1::OctetString, Encode, ErrorKind};
+    use alloc::vec::Vec;
+    use hex_literal::hex;
+
+    #[test]
+    fn read_tlv() {
+        let mut input = &hex!("0403010203 020101 05")[..];
+        let mut buf = Vec::new();
+        assert_eq!(input.read_tlv(&mut buf).unwrap(), 5u8.into());
+        assert_eq!(input.read_tlv(&mut buf).unwrap(), 3u8.into());
+        assert_eq!(buf, hex!("0403010203 020101"));
+
+        // Truncated header
+        let err = input.read_tlv(&mut buf).err().unwrap();
+        assert!(matches!(err.kind(), ErrorKind::Incomplete { .. }));
+    }
+
+    #[test]
+    fn read_tlv_long_form() {
+        let value = [0xAB; 300];
+        let der = OctetString::new(&value).unwrap().to_vec().unwrap();
+        let mut input = der.as_slice();
+        let mut buf = Vec::new();
+        assert_eq!(input.read_tlv(&mut buf).unwrap(), 304u16.into());
+        assert_eq!(buf, der);
+        assert!(input.is_empty());
+
+        // Contents shorter than the encoded length
+        let mut input = &der[..100];
+        assert!(input.read_tlv(&mut Vec::new()).is_err());
+    }
+
+    #[test]
+    fn decode() {
+        let mut input = &hex!("020101 020102")[..];
+        assert_eq!(input.decode::<u8>().unwrap(), 1);
+        assert_eq!(input.decode::<u8>().unwrap(), 2);
+        assert!(input.decode::<u8>().is_err());
+    }
+}
@@ -0,0 +1,290 @@
+//! [`proptest`] strategies for generating ASN.1 values.
+//!
+//! These can be used to write property tests for types which are built from
+//! the ASN.1 types in this crate, e.g. to check that they round-trip:
+//!
+//! ```
+//! use der::{dynamic::Value, strategy, Decode, Encode};
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn value_round_trip(value in strategy::value(4)) {
+//!         let der = value.to_vec().unwrap();
+//!         prop_assert_eq!(Value::from_der(&der).unwrap(), value);
+//!     }
+//! }
+//! # value_round_trip();
+//! ```
+//!
+//! Strategies for ASN.1 string types generate [`String`]s containing only
+//! characters which are valid for that type, since the string types in this
+//! crate borrow their contents.
+//!
+//! [`proptest`]: https://docs.rs/proptest/
+
+use crate::{
+    asn1::{GeneralizedTime, OwnedAny, UtcTime},
+    dynamic::Value,
+    Encode, Tag, TagNumber,
+};
+use alloc::{string::String, vec::Vec};
+use core::time::Duration;
+use proptest::{collection::vec, prelude::*, sample::select};
+
+#[cfg(feature = "oid")]
+use crate::asn1::ObjectIdentifier;
+
+/// Maximum length of generated strings and byte strings.
+const MAX_LEN: usize = 64;
+
+/// Maximum number of elements in generated constructed values.
+const MAX_ELEMENTS: usize = 8;
+
+/// Characters in the `PrintableString` character set.
+const PRINTABLE_CHARS: &str =
+    "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789 '()+,-./:=?";
+
+/// Latest time representable as `UTCTime`: 2049-12-31T23:59:59Z.
+const UTC_TIME_MAX_SECS: u64 = 2_524_607_999;
+
+/// Latest time representable as `GeneralizedTime`: 9999-12-31T23:59:59Z.
+const GENERALIZED_TIME_MAX_SECS: u64 = 253_402_300_799;
+
+/// `UNIVERSAL` class tags supported by this crate.
+const UNIVERSAL_TAGS: &[Tag] = &[
+    Tag::Boolean,
+    Tag::Integer,
+    Tag::BitString,
+    Tag::OctetString,
+    Tag::Null,
+    Tag::ObjectIdentifier,
+    Tag::Enumerated,
+    Tag::Utf8String,
+    Tag::Sequence,
+    Tag::Set,
+    Tag::NumericString,
+    Tag::PrintableString,
+    Tag::TeletexString,
+    Tag::Ia5String,
+    Tag::UtcTime,
+    Tag::GeneralizedTime,
+    Tag::VisibleString,
+    Tag::UniversalString,
+    Tag::BmpString,
+];
+
+/// Strategy for generating a [`TagNumber`].
+pub fn tag_number() -> impl Strategy<Value = TagNumber> {
+    (0..=30u8).prop_map(TagNumber::new)
+}
+
+/// Strategy for generating an arbitrary [`Tag`] of any class.
+pub fn tag() -> impl Strategy<Value = Tag> {
+    prop_oneof![select(UNIVERSAL_TAGS), non_universal_tag(any::<bool>()),]
+}
+
+/// Strategy for generating an `APPLICATION`, `CONTEXT-SPECIFIC`, or `PRIVATE`
+/// tag whose form is generated by `constructed`.
+fn non_universal_tag(constructed: impl Strategy<Value = bool>) -> impl Strategy<Value = Tag> {
+    (0..3u8, tag_number(), constructed).prop_map(|(class, number, constructed)| match class {
+        0 => number.application(constructed),
+        1 => number.context_specific(constructed),
+        _ => number.private(constructed),
+    })
+}
+
+/// Strategy for generating an [`ObjectIdentifier`].
+#[cfg(feature = "oid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "oid")))]
+pub fn oid() -> impl Strategy<Value = ObjectIdentifier> {
+    // Arcs which encode as more than 4 bytes of base 128 are rejected by the
+    // decoder
+    (0..=2u32, 0..=39u32, vec(0..=0x0FFF_FFFFu32, 1..=6)).prop_filter_map(
+        "OID exceeds maximum size",
+        |(first, second, rest)| {
+            ObjectIdentifier::from_arcs([first, second].iter().copied().chain(rest)).ok()
+        },
+    )
+}
+
+/// Strategy for generating an ASN.1 `ANY` value with an arbitrary tag and
+/// contents.
+///
+/// The contents of generated values aren't necessarily valid for their tag.
+pub fn any_value() -> impl Strategy<Value = OwnedAny> {
+    (tag(), vec(any::<u8>(), 0..=MAX_LEN)).prop_filter_map("invalid ANY value", |(tag, value)| {
+        OwnedAny::new(tag, value).ok()
+    })
+}
+
+/// Strategy for generating a string which is a valid `UTF8String`.
+pub fn utf8_string() -> impl Strategy<Value = String> {
+    vec(any::<char>(), 0..=MAX_LEN).prop_map(|chars| chars.into_iter().collect())
+}
+
+/// Strategy for generating a string which is a valid `PrintableString`.
+pub fn printable_string() -> impl Strategy<Value = String> {
+    let chars = PRINTABLE_CHARS.chars().collect::<Vec<_>>();
+    vec(select(chars), 0..=MAX_LEN).prop_map(|chars| chars.into_iter().collect())
+}
+
+/// Strategy for generating a string which is a valid `IA5String`.
+pub fn ia5_string() -> impl Strategy<Value = String> {
+    vec(0..=0x7Fu8, 0..=MAX_LEN).prop_map(|bytes| bytes.into_iter().map(char::from).collect())
+}
+
+/// Strategy for generating a [`UtcTime`].
+pub fn utc_time() -> impl Strategy<Value = UtcTime> {
+    (0..=UTC_TIME_MAX_SECS).prop_filter_map("invalid UTCTime", |secs| {
+        UtcTime::from_unix_duration(Duration::from_secs(secs)).ok()
+    })
+}
+
+/// Strategy for generating a [`GeneralizedTime`].
+pub fn generalized_time() -> impl Strategy<Value = GeneralizedTime> {
+    (0..=GENERALIZED_TIME_MAX_SECS).prop_filter_map("invalid GeneralizedTime", |secs| {
+        GeneralizedTime::from_unix_duration(Duration::from_secs(secs)).ok()
+    })
+}
+
+/// Strategy for generating trees of dynamically typed [`Value`]s, with
+/// constructed values nested at most `max_depth` levels deep.
+///
+/// Generated values are valid DER, and are decoded by [`Value`] as the
+/// value they were generated as, i.e. they round-trip. In particular the
+/// elements of generated `SET`s are in DER order.
+pub fn value(max_depth: u32) -> impl Strategy<Value = Value> {
+    primitive_value().prop_recursive(
+        max_depth,
+        (MAX_ELEMENTS as u32).saturating_pow(max_depth.min(2)),
+        MAX_ELEMENTS as u32,
+        |inner| {
+            let elements = vec(inner, 0..=MAX_ELEMENTS);
+
+            prop_oneof![
+                elements.clone().prop_map(Value::Sequence),
+                elements.clone().prop_map(|mut values| {
+                    values.sort_by_cached_key(|value| value.to_vec().unwrap_or_default());
+                    Value::Set(values)
+                }),
+                (non_universal_tag(Just(true)), elements)
+                    .prop_map(|(tag, values)| Value::Constructed { tag, values }),
+            ]
+        },
+    )
+}
+
+/// Strategy for generating primitive [`Value`]s.
+fn primitive_value() -> impl Strategy<Value = Value> {
+    let bytes = || vec(any::<u8>(), 0..=MAX_LEN);
+
+    let bit_string = (0..=7u8, bytes()).prop_map(|(unused_bits, mut bytes)| {
+        let unused_bits = match bytes.last_mut() {
+            Some(last) => {
+                *last &= 0xFF << unused_bits;
+                unused_bits
+            }
+            None => 0,
+        };
+
+        Value::BitString { unused_bits, bytes }
+    });
+
+    let other = (non_universal_tag(Just(false)), bytes())
+        .prop_filter_map("invalid primitive value", |(tag, value)| {
+            OwnedAny::new(tag, value).ok().map(Value::Other)
+        });
+
+    let values = prop_oneof![
+        any::<bool>().prop_map(Value::Boolean),
+        any::<i64>().prop_map(|n| Value::Integer(integer_bytes(n))),
+        bit_string,
+        bytes().prop_map(Value::OctetString),
+        Just(Value::Null),
+        other,
+    ];
+
+    let values = prop_oneof![
+        6 => values,
+        1 => utf8_string().prop_map(Value::Utf8String),
+        1 => printable_string().prop_map(Value::PrintableString),
+        1 => ia5_string().prop_map(Value::Ia5String),
+        1 => utc_time().prop_map(Value::UtcTime),
+        1 => generalized_time().prop_map(Value::GeneralizedTime),
+    ];
+
+    #[cfg(feature = "oid")]
+    let values = prop_oneof![11 => values, 1 => oid().prop_map(Value::ObjectIdentifier)];
+
+    values
+}
+
+/// Get the minimal big endian two's complement encoding of an integer.
+fn integer_bytes(n: i64) -> Vec<u8> {
+    let bytes = n.to_be_bytes();
+    let mut bytes = bytes.as_slice();
+
+    // Strip leading bytes which are redundant with the sign of the next byte
+    while let [first, ref rest @ ..] = *bytes {
+        match (first, rest.first().map(|byte| byte & 0x80)) {
+            (0x00, Some(0)) | (0xFF, Some(0x80)) => bytes = rest,
+            _ => break,
+        }
+    }
+
+    bytes.to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::integer_bytes;
+    use crate::{
+        asn1::{Any, Ia5String, OwnedAny, PrintableString, Utf8String},
+        dynamic::Value,
+        Decode, Encode,
+    };
+    use proptest::prelude::*;
+
+    #[test]
+    fn minimal_integers() {
+        assert_eq!(integer_bytes(0), [0x00]);
+        assert_eq!(integer_bytes(127), [0x7F]);
+        assert_eq!(integer_bytes(128), [0x00, 0x80]);
+        assert_eq!(integer_bytes(-1), [0xFF]);
+        assert_eq!(integer_bytes(-128), [0x80]);
+        assert_eq!(integer_bytes(-129), [0xFF, 0x7F]);
+        assert_eq!(integer_bytes(i64::MIN).len(), 8);
+    }
+
+    proptest! {
+        #[test]
+        fn value_round_trip(value in super::value(3)) {
+            let der = value.to_vec().unwrap();
+            prop_assert_eq!(Value::from_der(&der).unwrap(), value);
+        }
+
+        #[test]
+        fn any_round_trip(any in super::any_value()) {
+            let der = any.to_vec().unwrap();
+            prop_assert_eq!(OwnedAny::from(Any::from_der(&der).unwrap()), any);
+        }
+
+        #[test]
+        fn strings_valid(
+            utf8 in super::utf8_string(),
+            printable in super::printable_string(),
+            ia5 in super::ia5_string(),
+        ) {
+            prop_assert!(Utf8String::new(&utf8).is_ok());
+            prop_assert!(PrintableString::new(&printable).is_ok());
+            prop_assert!(Ia5String::new(&ia5).is_ok());
+        }
+
+        #[cfg(feature = "oid")]
+        #[test]
+        fn oid_round_trip(oid in super::oid()) {
+            let der = oid.to_vec().unwrap();
+            prop_assert_eq!(crate::asn1::ObjectIdentifier::from_der(&der).unwrap(), oid);
+        }
+    }
+}
@@ -10,7 +10,7 @@ use crate::{Decode, Decoder, DerOrd, Encode, Encoder, Error, ErrorKind, Length,
 use core::{cmp::Ordering, fmt};
 
 /// Indicator bit for constructed form encoding (i.e. vs primitive form)
-const CONSTRUCTED_FLAG: u8 = 0b100000;
+pub(crate) const CONSTRUCTED_FLAG: u8 = 0b100000;
 
 /// Types which have a constant ASN.1 [`Tag`].
 pub trait FixedTag {
@@ -85,6 +85,9 @@ pub enum Tag {
     /// `PrintableString` tag: `19`.
     PrintableString,
 
+    /// `TeletexString` tag: `20`.
+    TeletexString,
+
     /// `IA5String` tag: `22`.
     Ia5String,
 
@@ -97,6 +100,9 @@ pub enum Tag {
     /// `VisibleString` tag: `26`.
     VisibleString,
 
+    /// `UniversalString` tag: `28`.
+    UniversalString,
+
     /// `BMPString` tag: `30`.
     BmpString,
 
@@ -129,6 +135,31 @@ pub enum Tag {
 }
 
 impl Tag {
+    /// Create a [`Tag`] from its [`Class`], [`TagNumber`], and whether or not
+    /// it's constructed.
+    ///
+    /// `UNIVERSAL` tags must be ones supported by this crate, using the form
+    /// required by DER (i.e. constructed for `SEQUENCE` and `SET`, primitive
+    /// otherwise), or else an [`ErrorKind::TagUnknown`] error is returned.
+    ///
+    /// ```
+    /// use der::{Class, Tag, TagNumber};
+    ///
+    /// let tag = Tag::new(Class::ContextSpecific, TagNumber::N2, true)?;
+    /// assert_eq!(tag, TagNumber::N2.context_specific(true));
+    /// assert_eq!(Tag::new(Class::Universal, TagNumber::N16, true)?, Tag::Sequence);
+    /// assert!(Tag::new(Class::Universal, TagNumber::N16, false).is_err());
+    /// # Ok::<(), der::Error>(())
+    /// ```
+    pub fn new(class: Class, number: TagNumber, constructed: bool) -> Result<Tag> {
+        match class {
+            Class::Universal => Tag::try_from(class.octet(constructed, number)),
+            Class::Application => Ok(number.application(constructed)),
+            Class::ContextSpecific => Ok(number.context_specific(constructed)),
+            Class::Private => Ok(number.private(constructed)),
+        }
+    }
+
     /// Assert that this [`Tag`] matches the provided expected tag.
     ///
     /// On mismatch, returns an [`Error`] with [`ErrorKind::TagUnexpected`].
@@ -140,6 +171,16 @@ impl Tag {
         }
     }
 
+    /// Peek at the next byte in the decoder and attempt to decode it as a
+    /// [`Tag`] value, without modifying the decoder's state.
+    ///
+    /// This is intended for implementing [`Decode`] for types which can be
+    /// encoded with more than one tag, i.e. a [`Choice`][`crate::Choice`],
+    /// which can't impl [`FixedTag`].
+    pub fn peek(decoder: &Decoder<'_>) -> Result<Self> {
+        decoder.peek_tag()
+    }
+
     /// Get the [`Class`] that corresponds to this [`Tag`].
     pub fn class(self) -> Class {
         match self {
@@ -150,7 +191,7 @@ impl Tag {
         }
     }
 
-    /// Get the [`TagNumber`] (lower 6-bits) for this tag.
+    /// Get the [`TagNumber`] (lower 5 bits) for this tag.
     pub fn number(self) -> TagNumber {
         TagNumber(self.octet() & TagNumber::MASK)
     }
@@ -180,6 +221,42 @@ impl Tag {
         self.class() == Class::Universal
     }
 
+    /// Is this a `UNIVERSAL` string type which BER permits to be encoded in
+    /// the constructed (i.e. segmented) form, unlike DER?
+    pub fn is_segmentable(self) -> bool {
+        matches!(
+            self,
+            Tag::BitString
+                | Tag::OctetString
+                | Tag::Utf8String
+                | Tag::NumericString
+                | Tag::PrintableString
+                | Tag::TeletexString
+                | Tag::Ia5String
+                | Tag::VisibleString
+                | Tag::UniversalString
+                | Tag::BmpString
+        )
+    }
+
+    /// Parse an identifier octet, returning the [`Tag`] along with whether
+    /// or not it uses the constructed form.
+    ///
+    /// Unlike [`Tag::try_from`], this accepts `UNIVERSAL` tags in either form,
+    /// e.g. a constructed `OCTET STRING` (`0x24`), for which the returned
+    /// [`Tag`] is always the form used by DER.
+    pub(crate) fn from_identifier(byte: u8) -> Result<(Tag, bool)> {
+        let constructed = byte & CONSTRUCTED_FLAG != 0;
+
+        match Tag::try_from(byte) {
+            Ok(tag) => Ok((tag, constructed)),
+            Err(err) => match Tag::try_from(byte ^ CONSTRUCTED_FLAG) {
+                Ok(tag) if tag.is_universal() => Ok((tag, constructed)),
+                _ => Err(err),
+            },
+        }
+    }
+
     /// Get the octet encoding for this [`Tag`].
     pub fn octet(self) -> u8 {
         match self {
@@ -195,11 +272,13 @@ impl Tag {
             Tag::Set => 0x11 | CONSTRUCTED_FLAG,
             Tag::NumericString => 0x12,
             Tag::PrintableString => 0x13,
+            Tag::TeletexString => 0x14,
             Tag::Ia5String => 0x16,
             Tag::UtcTime => 0x17,
             Tag::GeneralizedTime => 0x18,
             Tag::VisibleString => 0x1A,
-            Tag::BmpString => 0x1D,
+            Tag::UniversalString => 0x1C,
+            Tag::BmpString => 0x1E,
             Tag::Application {
                 constructed,
                 number,
@@ -261,11 +340,13 @@ impl TryFrom<u8> for Tag {
             0x0C => Ok(Tag::Utf8String),
             0x12 => Ok(Tag::NumericString),
             0x13 => Ok(Tag::PrintableString),
+            0x14 => Ok(Tag::TeletexString),
             0x16 => Ok(Tag::Ia5String),
             0x17 => Ok(Tag::UtcTime),
             0x18 => Ok(Tag::GeneralizedTime),
             0x1A => Ok(Tag::VisibleString),
-            0x1d => Ok(Tag::BmpString),
+            0x1C => Ok(Tag::UniversalString),
+            0x1E => Ok(Tag::BmpString),
             0x30 => Ok(Tag::Sequence), // constructed
             0x31 => Ok(Tag::Set),      // constructed
             0x40..=0x7E => Ok(Tag::Application {
@@ -335,10 +416,12 @@ impl fmt::Display for Tag {
             Tag::Set => f.write_str("SET"),
             Tag::NumericString => f.write_str("NumericString"),
             Tag::PrintableString => f.write_str("PrintableString"),
+            Tag::TeletexString => f.write_str("TeletexString"),
             Tag::Ia5String => f.write_str("IA5String"),
             Tag::UtcTime => f.write_str("UTCTime"),
             Tag::GeneralizedTime => f.write_str("GeneralizedTime"),
             Tag::VisibleString => f.write_str("VisibleString"),
+            Tag::UniversalString => f.write_str("UniversalString"),
             Tag::BmpString => f.write_str("BMPString"),
             Tag::Sequence => f.write_str("SEQUENCE"),
             Tag::Application {
@@ -369,17 +452,189 @@ impl fmt::Display for Tag {
     }
 }
 
+#[cfg(feature = "defmt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
+impl defmt::Format for Tag {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        const FIELD_TYPE: [&str; 2] = ["primitive", "constructed"];
+
+        match *self {
+            Tag::Boolean => defmt::write!(f, "BOOLEAN"),
+            Tag::Integer => defmt::write!(f, "INTEGER"),
+            Tag::BitString => defmt::write!(f, "BIT STRING"),
+            Tag::OctetString => defmt::write!(f, "OCTET STRING"),
+            Tag::Null => defmt::write!(f, "NULL"),
+            Tag::ObjectIdentifier => defmt::write!(f, "OBJECT IDENTIFIER"),
+            Tag::Enumerated => defmt::write!(f, "ENUMERATED"),
+            Tag::Utf8String => defmt::write!(f, "UTF8String"),
+            Tag::Set => defmt::write!(f, "SET"),
+            Tag::NumericString => defmt::write!(f, "NumericString"),
+            Tag::PrintableString => defmt::write!(f, "PrintableString"),
+            Tag::TeletexString => defmt::write!(f, "TeletexString"),
+            Tag::Ia5String => defmt::write!(f, "IA5String"),
+            Tag::UtcTime => defmt::write!(f, "UTCTime"),
+            Tag::GeneralizedTime => defmt::write!(f, "GeneralizedTime"),
+            Tag::VisibleString => defmt::write!(f, "VisibleString"),
+            Tag::UniversalString => defmt::write!(f, "UniversalString"),
+            Tag::BmpString => defmt::write!(f, "BMPString"),
+            Tag::Sequence => defmt::write!(f, "SEQUENCE"),
+            Tag::Application {
+                constructed,
+                number,
+            } => defmt::write!(
+                f,
+                "APPLICATION [{=u8}] ({=str})",
+                number.value(),
+                FIELD_TYPE[constructed as usize]
+            ),
+            Tag::ContextSpecific {
+                constructed,
+                number,
+            } => defmt::write!(
+                f,
+                "CONTEXT-SPECIFIC [{=u8}] ({=str})",
+                number.value(),
+                FIELD_TYPE[constructed as usize]
+            ),
+            Tag::Private {
+                constructed,
+                number,
+            } => defmt::write!(
+                f,
+                "PRIVATE [{=u8}] ({=str})",
+                number.value(),
+                FIELD_TYPE[constructed as usize]
+            ),
+        }
+    }
+}
+
 impl fmt::Debug for Tag {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Tag(0x{:02x}: {})", u8::from(*self), self)
     }
 }
 
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+impl<'a> arbitrary::Arbitrary<'a> for Tag {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        /// `UNIVERSAL` class tags supported by this crate.
+        const UNIVERSAL_TAGS: &[Tag] = &[
+            Tag::Boolean,
+            Tag::Integer,
+            Tag::BitString,
+            Tag::OctetString,
+            Tag::Null,
+            Tag::ObjectIdentifier,
+            Tag::Enumerated,
+            Tag::Utf8String,
+            Tag::Sequence,
+            Tag::Set,
+            Tag::NumericString,
+            Tag::PrintableString,
+            Tag::TeletexString,
+            Tag::Ia5String,
+            Tag::UtcTime,
+            Tag::GeneralizedTime,
+            Tag::VisibleString,
+            Tag::UniversalString,
+            Tag::BmpString,
+        ];
+
+        let constructed = u.arbitrary()?;
+        let number = u.arbitrary()?;
+
+        Ok(match u.int_in_range(0..=3)? {
+            0 => *u.choose(UNIVERSAL_TAGS)?,
+            1 => Tag::Application {
+                constructed,
+                number,
+            },
+            2 => Tag::ContextSpecific {
+                constructed,
+                number,
+            },
+            _ => Tag::Private {
+                constructed,
+                number,
+            },
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::TagNumber;
     use super::{Class, Tag};
 
+    #[test]
+    fn from_identifier() {
+        assert_eq!(
+            Tag::from_identifier(0x04).unwrap(),
+            (Tag::OctetString, false)
+        );
+        assert_eq!(
+            Tag::from_identifier(0x24).unwrap(),
+            (Tag::OctetString, true)
+        );
+        assert_eq!(Tag::from_identifier(0x30).unwrap(), (Tag::Sequence, true));
+        assert_eq!(Tag::from_identifier(0x10).unwrap(), (Tag::Sequence, false));
+        assert_eq!(
+            Tag::from_identifier(0xA0).unwrap(),
+            (
+                Tag::ContextSpecific {
+                    constructed: true,
+                    number: TagNumber::N0
+                },
+                true
+            )
+        );
+        assert!(Tag::from_identifier(0x07).is_err());
+        assert!(Tag::from_identifier(0x27).is_err());
+        assert!(Tag::try_from(0x24).is_err());
+    }
+
+    #[test]
+    fn string_tag_octets() {
+        for (tag, octet) in [
+            (Tag::Utf8String, 0x0C),
+            (Tag::NumericString, 0x12),
+            (Tag::PrintableString, 0x13),
+            (Tag::TeletexString, 0x14),
+            (Tag::Ia5String, 0x16),
+            (Tag::VisibleString, 0x1A),
+            (Tag::UniversalString, 0x1C),
+            (Tag::BmpString, 0x1E),
+        ] {
+            assert_eq!(tag.octet(), octet);
+            assert_eq!(Tag::try_from(octet).unwrap(), tag);
+            assert_eq!(tag.class(), Class::Universal);
+        }
+    }
+
+    #[test]
+    fn new() {
+        for byte in 0..=u8::MAX {
+            if let Ok(tag) = Tag::try_from(byte) {
+                assert_eq!(
+                    Tag::new(tag.class(), tag.number(), tag.is_constructed()).unwrap(),
+                    tag
+                );
+            }
+        }
+
+        assert_eq!(
+            Tag::new(Class::Private, TagNumber::N30, false).unwrap(),
+            Tag::Private {
+                constructed: false,
+                number: TagNumber::N30
+            }
+        );
+        assert!(Tag::new(Class::Universal, TagNumber::N4, true).is_err());
+        assert!(Tag::new(Class::Universal, TagNumber::N7, false).is_err());
+    }
+
     #[test]
     fn tag_class() {
         assert_eq!(Tag::Boolean.class(), Class::Universal);
@@ -0,0 +1,290 @@
+//! Decoded Tag-Length-Value (TLV) productions with positional metadata.
+
+use crate::{
+    Decode, Decoder, Encode, Encoder, ErrorKind, FixedTag, Header, Length, Result, Tag, Tagged,
+};
+
+/// Complete Tag-Length-Value (TLV) production decoded from a message, along
+/// with the exact bytes it was decoded from and its position in the message.
+///
+/// This is useful when the original encoding of a value needs to be
+/// retained, e.g. to verify a signature over a `TBSCertificate` without
+/// re-encoding it, or to report the location of a value which fails
+/// validation after decoding.
+///
+/// When encoded, the original bytes are written out unmodified.
+///
+/// ```
+/// use der::{asn1::UIntBytes, Decoder, Length, Tlv};
+///
+/// // SEQUENCE { INTEGER 1, INTEGER 2 }
+/// let der = [0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02];
+/// let mut decoder = Decoder::new(&der)?;
+///
+/// let second = decoder.sequence(|decoder| {
+///     decoder.decode::<UIntBytes<'_>>()?;
+///     decoder.tlv()
+/// })?;
+///
+/// assert_eq!(second.offset(), Length::new(5));
+/// assert_eq!(second.raw(), &[0x02, 0x01, 0x02]);
+/// assert_eq!(second.value(), &[0x02]);
+/// assert_eq!(second.decode_as::<u8>()?, 2);
+/// # Ok::<(), der::Error>(())
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Tlv<'a> {
+    /// Decoded header of the TLV production.
+    header: Header,
+
+    /// Offset of the first byte of the TLV production within the message.
+    offset: Length,
+
+    /// Complete TLV production, including the header.
+    raw: &'a [u8],
+
+    /// Value portion of the TLV production.
+    value: &'a [u8],
+}
+
+impl<'a> Tlv<'a> {
+    /// Get the [`Header`] of this TLV production.
+    pub fn header(&self) -> Header {
+        self.header
+    }
+
+    /// Get the offset of the first byte of this TLV production within the
+    /// outermost message it was decoded from.
+    pub fn offset(&self) -> Length {
+        self.offset
+    }
+
+    /// Get the offset just past the last byte of this TLV production within
+    /// the outermost message it was decoded from.
+    pub fn end_offset(&self) -> Result<Length> {
+        self.offset + self.header.length.for_tlv()?
+    }
+
+    /// Get the complete TLV production exactly as it occurred in the input,
+    /// including its header.
+    pub fn raw(&self) -> &'a [u8] {
+        self.raw
+    }
+
+    /// Get the value portion of this TLV production, i.e. excluding its
+    /// header.
+    pub fn value(&self) -> &'a [u8] {
+        self.value
+    }
+
+    /// Decode this TLV production as the given type.
+    ///
+    /// Positions in any errors are relative to the start of this TLV
+    /// production, rather than the message it was decoded from.
+    pub fn decode_as<T: Decode<'a>>(&self) -> Result<T> {
+        T::from_der(self.raw)
+    }
+}
+
+impl<'a> Decode<'a> for Tlv<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Tlv<'a>> {
+        let offset = decoder.position();
+        let header = decoder.peek_header()?;
+        let header_len = usize::try_from(header.encoded_len()?)?;
+        let raw = decoder.tlv_bytes()?;
+
+        Ok(Self {
+            header,
+            offset,
+            raw,
+            value: raw.get(header_len..).unwrap_or_default(),
+        })
+    }
+}
+
+impl Encode for Tlv<'_> {
+    fn encoded_len(&self) -> Result<Length> {
+        self.raw.len().try_into()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        encoder.bytes(self.raw)
+    }
+}
+
+impl Tagged for Tlv<'_> {
+    fn tag(&self) -> Tag {
+        self.header.tag
+    }
+}
+
+/// Value decoded as `T` along with the exact bytes it was decoded from.
+///
+/// This can be used as the type of a field of a struct deriving
+/// [`Sequence`][`crate::Sequence`] in order to retain the original encoding
+/// of a nested value, e.g. to verify a signature over a `TBSCertificate`
+/// byte-exactly rather than over a re-encoding of it:
+///
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use der::{asn1::BitString, Decode, RawDerOf, Sequence};
+///
+/// #[derive(Sequence)]
+/// struct Signed<'a> {
+///     tbs: RawDerOf<'a, bool>,
+///     signature: BitString<'a>,
+/// }
+///
+/// // SEQUENCE { BOOLEAN TRUE, BIT STRING '00'H }
+/// let der = [0x30, 0x07, 0x01, 0x01, 0xFF, 0x03, 0x02, 0x00, 0x00];
+/// let signed = Signed::from_der(&der)?;
+///
+/// assert!(*signed.tbs.value());
+/// assert_eq!(signed.tbs.raw(), &der[2..5]);
+/// # }
+/// # Ok::<(), der::Error>(())
+/// ```
+///
+/// When encoded, the original bytes are written out unmodified.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RawDerOf<'a, T> {
+    /// Decoded value.
+    value: T,
+
+    /// Complete TLV production the value was decoded from.
+    raw: &'a [u8],
+}
+
+impl<'a, T> RawDerOf<'a, T> {
+    /// Get the decoded value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Get the decoded value, discarding the raw bytes.
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// Get the complete TLV production the value was decoded from, exactly
+    /// as it occurred in the input.
+    pub fn raw(&self) -> &'a [u8] {
+        self.raw
+    }
+}
+
+impl<'a, T> Decode<'a> for RawDerOf<'a, T>
+where
+    T: Decode<'a>,
+{
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        let header = decoder.peek_header()?;
+        let raw = decoder.clone().tlv_bytes()?;
+        let start = decoder.position();
+        let value = decoder.decode()?;
+
+        // `T` must be decoded from exactly one TLV production
+        if (decoder.position() - start)? != raw.len().try_into()? {
+            return Err(decoder.error(ErrorKind::Length { tag: header.tag }));
+        }
+
+        Ok(Self { value, raw })
+    }
+}
+
+impl<T> Encode for RawDerOf<'_, T> {
+    fn encoded_len(&self) -> Result<Length> {
+        self.raw.len().try_into()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        encoder.bytes(self.raw)
+    }
+}
+
+impl<T: FixedTag> FixedTag for RawDerOf<'_, T> {
+    const TAG: Tag = T::TAG;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RawDerOf, Tlv};
+    use crate::{asn1::OctetString, Decode, Decoder, Encode, ErrorKind, Length, Tag, Tagged};
+    use hex_literal::hex;
+
+    /// `SEQUENCE { BOOLEAN TRUE, SEQUENCE { OCTET STRING 'ABCD'H } }`
+    const EXAMPLE_MSG: &[u8] = &hex!("300B 0101FF 3006 0404ABCD0000");
+
+    #[test]
+    fn nested_offsets() {
+        let mut decoder = Decoder::new(EXAMPLE_MSG).unwrap();
+
+        let (outer, inner, octets) = decoder
+            .sequence(|decoder| {
+                let boolean = decoder.tlv()?;
+                assert_eq!(boolean.offset(), Length::new(2));
+                assert_eq!(boolean.raw(), &hex!("0101FF"));
+
+                let inner = decoder.tlv()?;
+                let octets = Tlv::from_der(inner.value())?;
+                Ok((boolean, inner, octets))
+            })
+            .unwrap();
+
+        assert_eq!(outer.tag(), Tag::Boolean);
+        assert_eq!(inner.offset(), Length::new(5));
+        assert_eq!(inner.end_offset().unwrap(), Length::new(13));
+        assert_eq!(inner.header().length, Length::new(6));
+        assert_eq!(inner.raw(), &EXAMPLE_MSG[5..]);
+        assert_eq!(inner.value(), &EXAMPLE_MSG[7..]);
+
+        // Offsets of values decoded separately are relative to their input
+        assert_eq!(octets.offset(), Length::ZERO);
+        assert_eq!(
+            octets.decode_as::<OctetString<'_>>().unwrap().as_bytes(),
+            &hex!("ABCD0000")
+        );
+    }
+
+    #[test]
+    fn reencode_raw() {
+        let tlv = Tlv::from_der(EXAMPLE_MSG).unwrap();
+        assert_eq!(tlv.offset(), Length::ZERO);
+        assert_eq!(tlv.encoded_len().unwrap(), Length::new(13));
+
+        let mut buf = [0u8; 13];
+        assert_eq!(tlv.encode_to_slice(&mut buf).unwrap(), EXAMPLE_MSG);
+    }
+
+    #[test]
+    fn truncated() {
+        assert!(Tlv::from_der(&EXAMPLE_MSG[..12]).is_err());
+    }
+
+    #[test]
+    fn raw_der_of() {
+        let mut decoder = Decoder::new(EXAMPLE_MSG).unwrap();
+        let (boolean, missing) = decoder
+            .sequence(|decoder| {
+                let boolean = decoder.decode::<RawDerOf<'_, bool>>()?;
+                let missing = decoder.decode::<Option<RawDerOf<'_, bool>>>()?;
+                decoder.tlv()?;
+                Ok((boolean, missing))
+            })
+            .unwrap();
+
+        assert!(boolean.value());
+        assert_eq!(boolean.raw(), &hex!("0101FF"));
+        let mut buf = [0u8; 3];
+        assert_eq!(boolean.encode_to_slice(&mut buf).unwrap(), hex!("0101FF"));
+        assert_eq!(missing, None);
+    }
+
+    #[test]
+    fn raw_der_of_partial_decode() {
+        // `Option<bool>` decodes nothing when the next value isn't a `BOOLEAN`
+        let err = RawDerOf::<'_, Option<bool>>::from_der(EXAMPLE_MSG).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Length { tag: Tag::Sequence });
+    }
+}
@@ -0,0 +1,294 @@
+//! Runtime builder for TLV-encoded values.
+
+use crate::{asn1::Any, Decoder, Encode, ErrorKind, Header, Length, Result, Tag, TagNumber};
+use alloc::vec::Vec;
+
+/// Builder for DER messages whose structure is only known at runtime.
+///
+/// Values are appended one after another, and constructed values (e.g.
+/// `SEQUENCE`s) are begun and ended around the values they contain, with
+/// their lengths computed as they're ended. This allows DER to be produced
+/// from dynamic input, e.g. a schema description, without defining Rust
+/// types for it.
+///
+/// ```
+/// use der::{asn1::Utf8String, Tag, TlvBuilder};
+///
+/// let mut builder = TlvBuilder::new();
+/// builder
+///     .begin_sequence()?
+///     .encode(&42u8)?
+///     .begin_set()?
+///     .encode(&Utf8String::new("b")?)?
+///     .encode(&true)?
+///     .end()?
+///     .primitive(Tag::OctetString, &[1, 2, 3])?
+///     .end()?;
+///
+/// assert_eq!(
+///     builder.finish()?,
+///     &[
+///         0x30, 0x10, 0x02, 0x01, 0x2A, 0x31, 0x06, 0x01, 0x01, 0xFF, 0x0C, 0x01, 0x62,
+///         0x04, 0x03, 0x01, 0x02, 0x03
+///     ]
+/// );
+/// # Ok::<(), der::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TlvBuilder {
+    /// Message encoded so far, excluding the headers of open values.
+    bytes: Vec<u8>,
+
+    /// Constructed values which have been begun but not yet ended, along
+    /// with the offset of their contents within `bytes`.
+    open: Vec<(Tag, usize)>,
+}
+
+impl TlvBuilder {
+    /// Create a new, empty [`TlvBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Begin a constructed value with the given tag.
+    ///
+    /// Values appended until the matching call to [`TlvBuilder::end`] are
+    /// encoded as its contents.
+    pub fn begin(&mut self, tag: Tag) -> Result<&mut Self> {
+        if !tag.is_constructed() {
+            return Err(ErrorKind::TagForm {
+                tag,
+                constructed: false,
+            }
+            .into());
+        }
+
+        self.open.push((tag, self.bytes.len()));
+        Ok(self)
+    }
+
+    /// Begin an ASN.1 `SEQUENCE`.
+    pub fn begin_sequence(&mut self) -> Result<&mut Self> {
+        self.begin(Tag::Sequence)
+    }
+
+    /// Begin an ASN.1 `SET` (or `SET OF`).
+    ///
+    /// Its elements are sorted into DER order when it's ended.
+    pub fn begin_set(&mut self) -> Result<&mut Self> {
+        self.begin(Tag::Set)
+    }
+
+    /// Begin a constructed `CONTEXT-SPECIFIC` value with the given tag
+    /// number, i.e. an `EXPLICIT`ly tagged field, or an `IMPLICIT`ly tagged
+    /// constructed field.
+    pub fn begin_context_specific(&mut self, number: TagNumber) -> Result<&mut Self> {
+        self.begin(Tag::ContextSpecific {
+            constructed: true,
+            number,
+        })
+    }
+
+    /// End the innermost constructed value which has been begun, encoding
+    /// its header.
+    ///
+    /// Returns an error if there are no open values.
+    pub fn end(&mut self) -> Result<&mut Self> {
+        let (tag, start) = self.open.pop().ok_or(ErrorKind::Failed)?;
+
+        if tag == Tag::Set {
+            let contents = {
+                let mut decoder = Decoder::new(&self.bytes[start..])?;
+                let mut elements = Vec::new();
+
+                while !decoder.is_finished() {
+                    elements.push(decoder.tlv_bytes()?);
+                }
+
+                elements.sort_unstable();
+                elements.concat()
+            };
+
+            self.bytes.truncate(start);
+            self.bytes.extend_from_slice(&contents);
+        }
+
+        let length = Length::try_from(self.bytes.len() - start)?;
+        let mut header = [0u8; 8];
+        let header = Header::new(tag, length)?.encode_to_slice(&mut header)?;
+        self.bytes.splice(start..start, header.iter().copied());
+        Ok(self)
+    }
+
+    /// Append a value which impls the [`Encode`] trait.
+    pub fn encode<T: Encode + ?Sized>(&mut self, value: &T) -> Result<&mut Self> {
+        value.encode_to_vec(&mut self.bytes)?;
+        Ok(self)
+    }
+
+    /// Append a primitive value with the given tag and contents.
+    pub fn primitive(&mut self, tag: Tag, value: &[u8]) -> Result<&mut Self> {
+        if tag.is_constructed() {
+            return Err(ErrorKind::TagForm {
+                tag,
+                constructed: true,
+            }
+            .into());
+        }
+
+        self.encode(&Any::new(tag, value)?)
+    }
+
+    /// Append a single previously encoded TLV value.
+    pub fn raw(&mut self, der: &[u8]) -> Result<&mut Self> {
+        let mut decoder = Decoder::new(der)?;
+        let tlv = decoder.tlv_bytes()?;
+        decoder.finish(())?;
+        self.bytes.extend_from_slice(tlv);
+        Ok(self)
+    }
+
+    /// Get the number of constructed values which have been begun but not
+    /// yet ended.
+    pub fn depth(&self) -> usize {
+        self.open.len()
+    }
+
+    /// Finish building, returning the encoded message.
+    ///
+    /// Returns an error if any constructed values haven't been ended.
+    pub fn finish(self) -> Result<Vec<u8>> {
+        match self.open.last() {
+            Some(&(tag, _)) => Err(ErrorKind::Length { tag }.into()),
+            None => Ok(self.bytes),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TlvBuilder;
+    use crate::{
+        asn1::{ContextSpecific, SequenceOf, SetOfVec},
+        Decode, Encode, ErrorKind, Tag, TagMode, TagNumber,
+    };
+    use hex_literal::hex;
+
+    #[test]
+    fn nested() {
+        let mut builder = TlvBuilder::new();
+        builder
+            .begin_sequence()
+            .unwrap()
+            .begin_sequence()
+            .unwrap()
+            .encode(&1u8)
+            .unwrap()
+            .end()
+            .unwrap()
+            .begin_sequence()
+            .unwrap()
+            .end()
+            .unwrap();
+        assert_eq!(builder.depth(), 1);
+        builder.end().unwrap();
+        assert_eq!(builder.depth(), 0);
+
+        let der = builder.finish().unwrap();
+        assert_eq!(der, hex!("3007 3003020101 3000"));
+
+        let mut inner = SequenceOf::<u8, 1>::new();
+        inner.add(1).unwrap();
+        let mut outer = SequenceOf::<SequenceOf<u8, 1>, 2>::new();
+        outer.add(inner).unwrap();
+        outer.add(SequenceOf::new()).unwrap();
+        assert_eq!(outer.to_vec().unwrap(), der);
+    }
+
+    #[test]
+    fn set_sorted() {
+        let mut builder = TlvBuilder::new();
+        builder.begin_set().unwrap();
+        for n in [0x300u16, 2, 0x10] {
+            builder.encode(&n).unwrap();
+        }
+        builder.end().unwrap();
+
+        let der = builder.finish().unwrap();
+        let set = SetOfVec::<u16>::from_der(&der).unwrap();
+        assert_eq!(set.as_slice(), &[2, 0x10, 0x300]);
+        assert_eq!(set.to_vec().unwrap(), der);
+    }
+
+    #[test]
+    fn context_specific() {
+        let mut builder = TlvBuilder::new();
+        builder
+            .begin_context_specific(TagNumber::N2)
+            .unwrap()
+            .encode(&true)
+            .unwrap()
+            .end()
+            .unwrap();
+
+        let expected = ContextSpecific {
+            tag_number: TagNumber::N2,
+            tag_mode: TagMode::Explicit,
+            value: true,
+        };
+        assert_eq!(builder.finish().unwrap(), expected.to_vec().unwrap());
+    }
+
+    #[test]
+    fn long_form_length() {
+        let mut builder = TlvBuilder::new();
+        builder.begin_sequence().unwrap();
+        builder.primitive(Tag::OctetString, &[0xAB; 300]).unwrap();
+        builder.end().unwrap();
+
+        let der = builder.finish().unwrap();
+        assert_eq!(der.len(), 308);
+        assert_eq!(&der[..9], hex!("308201300482012CAB"));
+    }
+
+    #[test]
+    fn raw() {
+        let mut builder = TlvBuilder::new();
+        builder.raw(&hex!("020101")).unwrap();
+        assert!(builder.raw(&hex!("020101 020102")).is_err());
+        assert!(builder.raw(&hex!("0201")).is_err());
+        assert_eq!(builder.finish().unwrap(), hex!("020101"));
+    }
+
+    #[test]
+    fn unbalanced() {
+        let mut builder = TlvBuilder::new();
+        assert_eq!(builder.end().unwrap_err().kind(), ErrorKind::Failed);
+
+        builder.begin_sequence().unwrap();
+        assert_eq!(
+            builder.finish().unwrap_err().kind(),
+            ErrorKind::Length { tag: Tag::Sequence }
+        );
+    }
+
+    #[test]
+    fn tag_form() {
+        let mut builder = TlvBuilder::new();
+        assert_eq!(
+            builder.begin(Tag::Integer).unwrap_err().kind(),
+            ErrorKind::TagForm {
+                tag: Tag::Integer,
+                constructed: false
+            }
+        );
+        assert_eq!(
+            builder.primitive(Tag::Sequence, &[]).unwrap_err().kind(),
+            ErrorKind::TagForm {
+                tag: Tag::Sequence,
+                constructed: true
+            }
+        );
+    }
+}
@@ -0,0 +1,126 @@
+//! Writer trait.
+
+use crate::{ErrorKind, Length, Result};
+use core::mem::MaybeUninit;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Output to which DER is written incrementally.
+///
+/// Values can be encoded through a [`Writer`] using
+/// [`Encode::encode_to_writer`][`crate::Encode::encode_to_writer`], which
+/// allows the encoding to be consumed as it's produced rather than first
+/// being written to a buffer, e.g. to compute a digest over it:
+///
+/// ```
+/// use core::hash::Hasher;
+/// use der::{Encode, Writer};
+/// use std::collections::hash_map::DefaultHasher;
+///
+/// /// Writer which hashes the DER it's given.
+/// struct HashingWriter<H: Hasher>(H);
+///
+/// impl<H: Hasher> Writer for HashingWriter<H> {
+///     fn write(&mut self, slice: &[u8]) -> der::Result<()> {
+///         self.0.write(slice);
+///         Ok(())
+///     }
+/// }
+///
+/// let mut writer = HashingWriter(DefaultHasher::new());
+/// let len = 0x1234u16.encode_to_writer(&mut writer)?;
+/// assert_eq!(len, 4u8.into());
+///
+/// let mut expected = DefaultHasher::new();
+/// expected.write(&[0x02, 0x02, 0x12, 0x34]);
+/// assert_eq!(writer.0.finish(), expected.finish());
+/// # Ok::<(), der::Error>(())
+/// ```
+pub trait Writer {
+    /// Write the given DER-encoded bytes as output.
+    fn write(&mut self, slice: &[u8]) -> Result<()>;
+
+    /// Write a single byte.
+    fn write_byte(&mut self, byte: u8) -> Result<()> {
+        self.write(&[byte])
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl Writer for Vec<u8> {
+    fn write(&mut self, slice: &[u8]) -> Result<()> {
+        self.extend_from_slice(slice);
+        Ok(())
+    }
+}
+
+/// [`Writer`] which writes to a possibly uninitialized buffer, keeping track
+/// of how much of it has been initialized.
+#[derive(Debug)]
+pub(crate) struct UninitWriter<'a> {
+    /// Buffer being written to.
+    buffer: &'a mut [MaybeUninit<u8>],
+
+    /// Number of bytes at the start of the buffer which have been written,
+    /// and are therefore initialized.
+    initialized: usize,
+}
+
+impl<'a> UninitWriter<'a> {
+    /// Create a new writer which writes to the start of the given buffer.
+    pub(crate) fn new(buffer: &'a mut [MaybeUninit<u8>]) -> Self {
+        Self {
+            buffer,
+            initialized: 0,
+        }
+    }
+
+    /// Get the number of bytes written so far.
+    pub(crate) fn len(&self) -> usize {
+        self.initialized
+    }
+
+    /// Get the portion of the buffer which has been written so far.
+    pub(crate) fn into_written(self) -> &'a [MaybeUninit<u8>] {
+        let buffer: &'a [MaybeUninit<u8>] = self.buffer;
+        buffer.get(..self.initialized).unwrap_or_default()
+    }
+}
+
+impl Writer for UninitWriter<'_> {
+    fn write(&mut self, slice: &[u8]) -> Result<()> {
+        let end = self
+            .initialized
+            .checked_add(slice.len())
+            .ok_or(ErrorKind::Overflow)?;
+
+        let position = Length::try_from(self.initialized)?;
+        let dst = self
+            .buffer
+            .get_mut(self.initialized..end)
+            .ok_or_else(|| ErrorKind::Overlength.at(position))?;
+
+        for (dst, src) in dst.iter_mut().zip(slice) {
+            *dst = MaybeUninit::new(*src);
+        }
+
+        self.initialized = end;
+        Ok(())
+    }
+}
+
+/// [`Writer`] which writes to a [`std::io::Write`] implementation.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug)]
+pub struct IoWriter<W: std::io::Write>(pub W);
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<W: std::io::Write> Writer for IoWriter<W> {
+    fn write(&mut self, slice: &[u8]) -> Result<()> {
+        Ok(self.0.write_all(slice)?)
+    }
+}
@@ -0,0 +1,65 @@
+//! Tests for `arbitrary` support.
+
+#![cfg(all(feature = "arbitrary", feature = "alloc"))]
+
+use arbitrary::{Arbitrary, Unstructured};
+use der::{
+    asn1::{Any, GeneralizedTime, Ia5String, PrintableString, Utf8String},
+    Decode, Encode, Header,
+};
+
+/// Generate values of type `T` from a range of inputs, ensuring they
+/// round trip through DER.
+fn round_trip<T>()
+where
+    T: for<'a> Arbitrary<'a> + for<'a> Decode<'a> + Encode + Eq + core::fmt::Debug,
+{
+    let data: Vec<u8> = (0..=255).cycle().take(4096).collect();
+
+    for chunk in data.chunks(61) {
+        let mut u = Unstructured::new(chunk);
+
+        if let Ok(value) = T::arbitrary(&mut u) {
+            let der = value.to_vec().unwrap();
+            assert_eq!(T::from_der(&der).unwrap(), value);
+        }
+    }
+}
+
+/// Borrowing variant of [`round_trip`].
+macro_rules! round_trip_borrowed {
+    ($ty:ident) => {
+        let data: Vec<u8> = (0..=255).cycle().take(4096).collect();
+
+        for chunk in data.chunks(61) {
+            let mut u = Unstructured::new(chunk);
+
+            if let Ok(value) = $ty::arbitrary(&mut u) {
+                let der = value.to_vec().unwrap();
+                assert_eq!($ty::from_der(&der).unwrap(), value);
+            }
+        }
+    };
+}
+
+#[test]
+fn header() {
+    round_trip::<Header>();
+}
+
+#[test]
+fn generalized_time() {
+    round_trip::<GeneralizedTime>();
+}
+
+#[test]
+fn any() {
+    round_trip_borrowed!(Any);
+}
+
+#[test]
+fn strings() {
+    round_trip_borrowed!(Ia5String);
+    round_trip_borrowed!(PrintableString);
+    round_trip_borrowed!(Utf8String);
+}
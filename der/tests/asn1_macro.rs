@@ -0,0 +1,191 @@
+//! Tests for the `asn1!` macro.
+//!
+//! To expand the Rust code generated by the macro when debugging issues
+//! related to these tests, run:
+//!
+//! $ cargo expand --test asn1_macro --all-features
+
+#![cfg(all(feature = "derive", feature = "alloc", feature = "oid"))]
+
+/// Types defined without a module header, i.e. with `EXPLICIT` tagging.
+mod explicit {
+    use der::{
+        asn1::{ObjectIdentifier, OctetString, SetOfVec, UIntBytes, Utf8String},
+        Decode, Decoder, Encode,
+    };
+    use hex_literal::hex;
+
+    der::asn1! {
+        id-ce-basicConstraints OBJECT IDENTIFIER ::= { 2 5 29 19 }
+
+        Extension ::= SEQUENCE {
+            extnID      OBJECT IDENTIFIER,
+            critical    BOOLEAN DEFAULT FALSE,
+            extnValue   OCTET STRING
+        }
+
+        Extensions ::= SEQUENCE SIZE (1..MAX) OF Extension
+
+        Version ::= INTEGER { v1(0), v2(1), v3(2) }
+
+        Color ::= ENUMERATED { red, green(5), blue, ... }
+
+        Label ::= SET OF Attribute
+
+        Attribute ::= SEQUENCE {
+            type    OBJECT IDENTIFIER,
+            value   UTF8String (SIZE (1..64))
+        }
+
+        Record ::= SEQUENCE {
+            version     [0] Version DEFAULT v1,
+            serial      INTEGER,
+            color       [1] IMPLICIT Color OPTIONAL,
+            label       Label,
+            extensions  [3] Extensions OPTIONAL
+        }
+    }
+
+    const BASIC_CONSTRAINTS: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.29.19");
+
+    #[test]
+    fn extension_default() {
+        let extension = Extension {
+            extn_id: BASIC_CONSTRAINTS,
+            critical: false,
+            extn_value: OctetString::new(&[]).unwrap(),
+        };
+
+        let der = extension.to_vec().unwrap();
+        assert_eq!(der, hex!("3007 0603551D13 0400"));
+        assert_eq!(Extension::from_der(&der).unwrap(), extension);
+
+        let critical = Extension {
+            critical: true,
+            ..extension
+        };
+        let der = critical.to_vec().unwrap();
+        assert_eq!(der, hex!("300A 0603551D13 0101FF 0400"));
+        assert_eq!(Extension::from_der(&der).unwrap(), critical);
+
+        // `DEFAULT` values must be omitted under DER, which is only enforced
+        // on request
+        let der = hex!("300A 0603551D13 010100 0400");
+        assert_eq!(Extension::from_der(&der).unwrap(), extension);
+
+        let mut decoder = Decoder::new(&der).unwrap();
+        decoder.set_reject_encoded_defaults(true);
+        assert!(decoder.decode::<Extension<'_>>().is_err());
+    }
+
+    #[test]
+    fn named_integer() {
+        assert_eq!(VERSION_V1, 0);
+        assert_eq!(VERSION_V3, 2);
+        let version: Version = VERSION_V2;
+        assert_eq!(version, 1i64);
+    }
+
+    #[test]
+    fn enumerated() {
+        assert_eq!(Color::Red as u32, 0);
+        assert_eq!(Color::Green as u32, 5);
+        assert_eq!(Color::Blue as u32, 6);
+        assert_eq!(Color::from_der(&hex!("0A0106")).unwrap(), Color::Blue);
+    }
+
+    #[test]
+    fn record_round_trip() {
+        let mut label = Label::new();
+        label
+            .add(Attribute {
+                r#type: BASIC_CONSTRAINTS,
+                value: Utf8String::new("b").unwrap(),
+            })
+            .unwrap();
+
+        let extensions: Extensions = vec![Extension {
+            extn_id: BASIC_CONSTRAINTS,
+            critical: true,
+            extn_value: OctetString::new(&[0x30, 0x00]).unwrap(),
+        }];
+
+        let record = Record {
+            version: VERSION_V3,
+            serial: UIntBytes::new(&[0x01]).unwrap(),
+            color: Some(Color::Green),
+            label,
+            extensions: Some(extensions),
+        };
+
+        let der = record.to_vec().unwrap();
+        assert_eq!(
+            der,
+            hex!(
+                "3029
+                 A003 020102
+                 020101
+                 810105
+                 310A 3008 0603551D13 0C0162
+                 A310 300E 300C 0603551D13 0101FF 04023000"
+            )
+        );
+        assert_eq!(Record::from_der(&der).unwrap(), record);
+
+        let minimal = Record {
+            version: VERSION_V1,
+            color: None,
+            label: SetOfVec::new(),
+            extensions: None,
+            ..record
+        };
+
+        let der = minimal.to_vec().unwrap();
+        assert_eq!(der, hex!("3005 020101 3100"));
+        assert_eq!(Record::from_der(&der).unwrap(), minimal);
+    }
+}
+
+/// Types defined within an `IMPLICIT TAGS` module.
+mod implicit {
+    use der::{
+        asn1::{Ia5String, Null},
+        Decode, Encode,
+    };
+    use hex_literal::hex;
+
+    der::asn1! {
+        Example { iso(1) 2 3 } DEFINITIONS IMPLICIT TAGS ::=
+        BEGIN
+
+        IMPORTS Name FROM Other { iso(1) 4 };
+
+        GeneralName ::= CHOICE {
+            rfc822Name  [1] IA5String,
+            dNSName     [2] IA5String,
+            nothing     NULL,
+            other       [3] EXPLICIT Name
+        }
+
+        Names ::= SEQUENCE OF GeneralName
+
+        END
+    }
+
+    /// Type imported by the module above.
+    pub type Name = bool;
+
+    #[test]
+    fn choice_round_trip() {
+        let names: Names = vec![
+            GeneralName::Rfc822Name(Ia5String::new("a@b").unwrap()),
+            GeneralName::DNSName(Ia5String::new("b").unwrap()),
+            GeneralName::Nothing(Null),
+            GeneralName::Other(true),
+        ];
+
+        let der = names.to_vec().unwrap();
+        assert_eq!(der, hex!("300F 8103614062 820162 0500 A3030101FF"));
+        assert_eq!(Names::from_der(&der).unwrap(), names);
+    }
+}
@@ -0,0 +1,122 @@
+//! Tests for the `chrono` integration.
+
+#![cfg(feature = "chrono")]
+
+use der::{
+    asn1::{GeneralizedTime, UtcTime},
+    chrono::{NaiveDate, NaiveDateTime, TimeZone, Utc},
+    DateTime, Decode, Encode,
+};
+use hex_literal::hex;
+
+/// GeneralizedTime: 2020-02-29T12:34:56Z
+const GENERALIZED_TIME_DER: &[u8] = &hex!("180f32303230303232393132333435365a");
+
+/// UTCTime: 2020-02-29T12:34:56Z
+const UTC_TIME_DER: &[u8] = &hex!("170d3230303232393132333435365a");
+
+fn naive_date_time() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2020, 2, 29)
+        .unwrap()
+        .and_hms_opt(12, 34, 56)
+        .unwrap()
+}
+
+#[test]
+fn date_time_conversions() {
+    let datetime = DateTime::new(2020, 2, 29, 12, 34, 56).unwrap();
+    let naive = naive_date_time();
+    let utc = Utc.from_utc_datetime(&naive);
+
+    assert_eq!(NaiveDateTime::try_from(datetime).unwrap(), naive);
+    assert_eq!(DateTime::try_from(naive).unwrap(), datetime);
+    assert_eq!(
+        der::chrono::DateTime::<Utc>::try_from(datetime).unwrap(),
+        utc
+    );
+    assert_eq!(DateTime::try_from(utc).unwrap(), datetime);
+}
+
+#[test]
+fn fractional_seconds_are_discarded() {
+    let naive = naive_date_time()
+        .date()
+        .and_hms_milli_opt(12, 34, 56, 789)
+        .unwrap();
+
+    assert_eq!(
+        DateTime::try_from(naive).unwrap(),
+        DateTime::new(2020, 2, 29, 12, 34, 56).unwrap()
+    );
+}
+
+#[test]
+fn out_of_range() {
+    let naive = NaiveDate::from_ymd_opt(1969, 12, 31)
+        .unwrap()
+        .and_hms_opt(23, 59, 59)
+        .unwrap();
+
+    assert!(DateTime::try_from(naive).is_err());
+    assert!(GeneralizedTime::try_from(naive).is_err());
+
+    let naive = NaiveDate::from_ymd_opt(2050, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+
+    assert!(GeneralizedTime::try_from(naive).is_ok());
+    assert!(UtcTime::try_from(naive).is_err());
+}
+
+#[test]
+fn generalized_time() {
+    let naive = naive_date_time();
+    let utc = Utc.from_utc_datetime(&naive);
+
+    let generalized_time = GeneralizedTime::from_der(GENERALIZED_TIME_DER).unwrap();
+    assert_eq!(GeneralizedTime::try_from(naive).unwrap(), generalized_time);
+    assert_eq!(GeneralizedTime::try_from(utc).unwrap(), generalized_time);
+    assert_eq!(NaiveDateTime::try_from(generalized_time).unwrap(), naive);
+    assert_eq!(
+        der::chrono::DateTime::<Utc>::try_from(generalized_time).unwrap(),
+        utc
+    );
+}
+
+#[test]
+fn utc_time() {
+    let naive = naive_date_time();
+    let utc = Utc.from_utc_datetime(&naive);
+
+    let utc_time = UtcTime::from_der(UTC_TIME_DER).unwrap();
+    assert_eq!(UtcTime::try_from(naive).unwrap(), utc_time);
+    assert_eq!(UtcTime::try_from(utc).unwrap(), utc_time);
+    assert_eq!(NaiveDateTime::try_from(utc_time).unwrap(), naive);
+    assert_eq!(
+        der::chrono::DateTime::<Utc>::try_from(utc_time).unwrap(),
+        utc
+    );
+}
+
+#[test]
+fn decode_encode() {
+    let naive = naive_date_time();
+    let utc = Utc.from_utc_datetime(&naive);
+
+    assert_eq!(
+        NaiveDateTime::from_der(GENERALIZED_TIME_DER).unwrap(),
+        naive
+    );
+    assert_eq!(
+        der::chrono::DateTime::<Utc>::from_der(GENERALIZED_TIME_DER).unwrap(),
+        utc
+    );
+
+    let mut buf = [0u8; 32];
+    assert_eq!(
+        naive.encode_to_slice(&mut buf).unwrap(),
+        GENERALIZED_TIME_DER
+    );
+    assert_eq!(utc.encode_to_slice(&mut buf).unwrap(), GENERALIZED_TIME_DER);
+}
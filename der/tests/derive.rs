@@ -202,8 +202,8 @@ mod enumerated {
 #[cfg(feature = "oid")]
 mod sequence {
     use der::{
-        asn1::{Any, ObjectIdentifier, SetOf},
-        Decode, Encode, Sequence, ValueOrd,
+        asn1::{Any, ContextSpecific, ObjectIdentifier, OctetString, SetOf, UIntBytes},
+        Decode, Decoder, DerOrd, Encode, EncodingRules, ErrorKind, Sequence, ValueOrd,
     };
     use hex_literal::hex;
 
@@ -333,7 +333,6 @@ mod sequence {
     }
 
     /// X.509 extension
-    // TODO(tarcieri): tests for code derived with the `default` attribute
     #[derive(Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
     pub struct Extension<'a> {
         extn_id: ObjectIdentifier,
@@ -378,6 +377,86 @@ mod sequence {
         pub typed_context_specific_optional: Option<&'a [u8]>,
     }
 
+    /// Generic default values must still be comparable when other
+    /// `PartialEq` impls exist for the field type, e.g. `serde_json`'s
+    /// `impl PartialEq<Value> for bool`.
+    #[derive(Sequence)]
+    pub struct TypeCheckGenericDefault {
+        #[asn1(default = "Default::default")]
+        pub flag: bool,
+        #[asn1(context_specific = "0", default = "Default::default")]
+        pub context_specific_flag: bool,
+    }
+
+    #[test]
+    fn generic_default_with_other_partial_eq_impls() {
+        assert_eq!(serde_json::Value::Bool(false), false);
+
+        let value = TypeCheckGenericDefault {
+            flag: false,
+            context_specific_flag: true,
+        };
+        assert_eq!(value.to_vec().unwrap(), hex!("3005A0030101FF"));
+    }
+
+    // Authority key identifier as defined in [RFC 5280 Section 4.2.1.1], using
+    // implicitly tagged optional fields.
+    //
+    // ```text
+    // AuthorityKeyIdentifier ::= SEQUENCE {
+    //     keyIdentifier             [0] KeyIdentifier           OPTIONAL,
+    //     authorityCertIssuer       [1] GeneralNames            OPTIONAL,
+    //     authorityCertSerialNumber [2] CertificateSerialNumber OPTIONAL  }
+    // ```
+    //
+    // [RFC 5280 Section 4.2.1.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.1
+    #[derive(Sequence, Debug, Eq, PartialEq, ValueOrd)]
+    pub struct AuthorityKeyIdentifierExample<'a> {
+        #[asn1(context_specific = "0", tag_mode = "IMPLICIT", optional)]
+        pub key_identifier: Option<OctetString<'a>>,
+
+        // Stand-in for `GeneralNames`, which is constructed
+        #[asn1(context_specific = "1", tag_mode = "IMPLICIT", optional)]
+        pub authority_cert_issuer: Option<IssuerExample>,
+
+        #[asn1(context_specific = "2", tag_mode = "IMPLICIT", optional)]
+        pub authority_cert_serial_number: Option<UIntBytes<'a>>,
+    }
+
+    #[derive(Sequence, Debug, Eq, PartialEq, ValueOrd)]
+    pub struct IssuerExample {
+        pub id: u8,
+    }
+
+    /// Recursive type which uses `Box` for indirection.
+    #[derive(Sequence, Debug, Eq, PartialEq)]
+    pub struct Node {
+        pub value: u8,
+        pub next: Option<Box<Node>>,
+        #[asn1(context_specific = "0", optional = "true")]
+        pub child: Option<Box<Self>>,
+    }
+
+    // Boxed fields without recursion.
+    #[derive(Sequence, Debug, Eq, PartialEq)]
+    pub struct TypeCheckBoxedFields {
+        pub boxed: Box<bool>,
+        #[asn1(context_specific = "0", tag_mode = "IMPLICIT")]
+        pub context_specific_boxed: Box<bool>,
+    }
+
+    impl Node {
+        fn chain(len: u8) -> Option<Box<Self>> {
+            (0..len).fold(None, |next, value| {
+                Some(Box::new(Node {
+                    value,
+                    next,
+                    child: None,
+                }))
+            })
+        }
+    }
+
     #[test]
     fn idp_test() {
         let idp = IssuingDistributionPointExample::from_der(&hex!("30038101FF")).unwrap();
@@ -430,6 +509,60 @@ mod sequence {
         assert_eq!(ext2.critical, false);
     }
 
+    #[test]
+    fn default_omitted() {
+        let ext = ExtensionExample {
+            extn_id: ObjectIdentifier::new_unwrap("2.5.29.19"),
+            critical: false,
+            extn_value: &[],
+        };
+        let der = hex!("3007 0603551D13 0400");
+        assert_eq!(ext.to_vec().unwrap(), der);
+        assert_eq!(ExtensionExample::from_der(&der).unwrap(), ext);
+
+        let idp = IssuingDistributionPointExample::from_der(&hex!("3000")).unwrap();
+        assert_eq!(idp.only_contains_user_certs, false);
+        assert_eq!(idp.to_vec().unwrap(), hex!("3000"));
+    }
+
+    #[test]
+    fn default_encoded() {
+        // `critical` explicitly encoded as its `DEFAULT` of `FALSE`
+        let der = hex!("300A 0603551D13 010100 0400");
+        let ext = ExtensionExample::from_der(&der).unwrap();
+        assert_eq!(ext.critical, false);
+        assert_eq!(ext.to_vec().unwrap(), hex!("3007 0603551D13 0400"));
+
+        let mut decoder = Decoder::new(&der).unwrap();
+        decoder.set_reject_encoded_defaults(true);
+        let err = decoder.decode::<ExtensionExample<'_>>().err().unwrap();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Noncanonical {
+                tag: der::Tag::Boolean
+            }
+        );
+
+        let mut decoder = Decoder::new(&der).unwrap();
+        decoder.set_reject_encoded_defaults(true);
+        decoder.set_encoding_rules(EncodingRules::Ber);
+        let ext = decoder.decode::<ExtensionExample<'_>>().unwrap();
+        assert_eq!(ext.critical, false);
+
+        // `onlyContainsUserCerts` explicitly encoded as `FALSE`
+        let der = hex!("3003 810100");
+        let idp = IssuingDistributionPointExample::from_der(&der).unwrap();
+        assert_eq!(idp.only_contains_user_certs, false);
+
+        let mut decoder = Decoder::new(&der).unwrap();
+        decoder.set_reject_encoded_defaults(true);
+        let err = decoder
+            .decode::<IssuingDistributionPointExample>()
+            .err()
+            .unwrap();
+        assert!(matches!(err.kind(), ErrorKind::Noncanonical { .. }));
+    }
+
     #[test]
     fn decode() {
         let algorithm_identifier =
@@ -456,6 +589,151 @@ mod sequence {
             algorithm_identifier.to_vec().unwrap()
         );
     }
+
+    #[test]
+    fn implicit_optional() {
+        let der = hex!("300C80020102A103020101820105");
+        let aki = AuthorityKeyIdentifierExample::from_der(&der).unwrap();
+        assert_eq!(aki.key_identifier.unwrap().as_bytes(), &[1, 2]);
+        assert_eq!(aki.authority_cert_issuer, Some(IssuerExample { id: 1 }));
+        assert_eq!(aki.authority_cert_serial_number.unwrap().as_bytes(), &[5]);
+        assert_eq!(aki.to_vec().unwrap(), der);
+
+        let der = hex!("3003820105");
+        let aki = AuthorityKeyIdentifierExample::from_der(&der).unwrap();
+        assert_eq!(aki.key_identifier, None);
+        assert_eq!(aki.authority_cert_issuer, None);
+        assert_eq!(aki.to_vec().unwrap(), der);
+    }
+
+    #[test]
+    fn value_ord_matches_encoding() {
+        let key_identifier = AuthorityKeyIdentifierExample {
+            key_identifier: Some(OctetString::new(&[1, 2, 3]).unwrap()),
+            authority_cert_issuer: None,
+            authority_cert_serial_number: None,
+        };
+
+        let issuer = AuthorityKeyIdentifierExample {
+            key_identifier: None,
+            authority_cert_issuer: Some(IssuerExample { id: 1 }),
+            authority_cert_serial_number: None,
+        };
+
+        let serial_number = AuthorityKeyIdentifierExample {
+            key_identifier: None,
+            authority_cert_issuer: None,
+            authority_cert_serial_number: Some(UIntBytes::new(&[1, 2, 3]).unwrap()),
+        };
+
+        let examples = [&key_identifier, &issuer, &serial_number];
+
+        for a in examples {
+            for b in examples {
+                let expected = a.to_vec().unwrap().cmp(&b.to_vec().unwrap());
+                assert_eq!(a.der_cmp(b).unwrap(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn recursive() {
+        let node = Node {
+            value: 1,
+            next: Node::chain(2),
+            child: Node::chain(1),
+        };
+
+        let der = node.to_vec().unwrap();
+        assert_eq!(der, hex!("301402010130080201013003020100A0053003020100"));
+        assert_eq!(Node::from_der(&der).unwrap(), node);
+    }
+
+    #[test]
+    fn boxed_fields() {
+        let value = TypeCheckBoxedFields {
+            boxed: Box::new(true),
+            context_specific_boxed: Box::new(false),
+        };
+
+        let der = value.to_vec().unwrap();
+        assert_eq!(der, hex!("30060101FF800100"));
+        assert_eq!(TypeCheckBoxedFields::from_der(&der).unwrap(), value);
+    }
+
+    #[test]
+    fn encode_reverse() {
+        let mut buf = [0u8; 64];
+
+        for der in [
+            &hex!("300C80020102A103020101820105")[..],
+            &hex!("3003820105"),
+        ] {
+            let aki = AuthorityKeyIdentifierExample::from_der(der).unwrap();
+            assert_eq!(aki.encode_to_slice_reverse(&mut buf).unwrap(), der);
+        }
+
+        let der = hex!("301402010130080201013003020100A0053003020100");
+        let node = Node::from_der(&der).unwrap();
+        assert_eq!(node.encode_to_slice_reverse(&mut buf).unwrap(), der);
+    }
+
+    #[test]
+    fn recursive_depth_limit() {
+        let der = Node::chain(3).unwrap().to_vec().unwrap();
+
+        let mut decoder = Decoder::new(&der).unwrap();
+        decoder.set_max_depth(3);
+        assert!(decoder.decode::<Node>().is_ok());
+
+        let mut decoder = Decoder::new(&der).unwrap();
+        decoder.set_max_depth(2);
+        let err = decoder.decode::<Node>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DepthLimit { max_depth: 2 });
+    }
+
+    #[test]
+    fn recursive_explicit_depth_limit() {
+        let node = (0..10).fold(None, |child, value| {
+            Some(Box::new(Node {
+                value,
+                next: None,
+                child,
+            }))
+        });
+        let der = node.unwrap().to_vec().unwrap();
+        assert!(Node::from_der(&der).is_ok());
+
+        // Each level nests both a `SEQUENCE` and an `EXPLICIT` tag
+        let mut decoder = Decoder::new(&der).unwrap();
+        decoder.set_max_depth(20);
+        assert!(decoder.decode::<Node>().is_ok());
+
+        let mut decoder = Decoder::new(&der).unwrap();
+        decoder.set_max_depth(2);
+        let err = decoder.decode::<Node>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DepthLimit { max_depth: 2 });
+    }
+
+    #[test]
+    fn explicit_inherits_settings() {
+        // `critical` explicitly encoded as its `DEFAULT` within `[0] EXPLICIT`
+        let der = hex!("A00C 300A 0603551D13 010100 0400");
+        let field = ContextSpecific::<ExtensionExample<'_>>::from_der(&der).unwrap();
+        assert_eq!(field.value.critical, false);
+
+        let mut decoder = Decoder::new(&der).unwrap();
+        decoder.set_reject_encoded_defaults(true);
+        let err = decoder
+            .decode::<ContextSpecific<ExtensionExample<'_>>>()
+            .unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Noncanonical {
+                tag: der::Tag::Boolean
+            }
+        );
+    }
 }
 
 mod newtype {
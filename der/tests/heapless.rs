@@ -0,0 +1,42 @@
+//! Tests for `heapless` support.
+
+#![cfg(feature = "heapless")]
+
+use der::{Decode, Encode, ErrorKind};
+use hex_literal::hex;
+
+/// `SEQUENCE OF INTEGER` containing 1, 2, 3.
+const SEQUENCE_OF_INTS: &[u8] = &hex!("3009020101020102020103");
+
+/// `UTF8String` containing "Helló".
+const UTF8_STRING: &[u8] = &hex!("0c0648656c6cc3b3");
+
+#[test]
+fn vec_round_trip() {
+    let vec = heapless::Vec::<u8, 3>::from_der(SEQUENCE_OF_INTS).unwrap();
+    assert_eq!(vec.as_slice(), &[1, 2, 3]);
+
+    let mut buf = [0u8; 16];
+    assert_eq!(vec.encode_to_slice(&mut buf).unwrap(), SEQUENCE_OF_INTS);
+}
+
+#[test]
+fn vec_over_capacity() {
+    let err = heapless::Vec::<u8, 2>::from_der(SEQUENCE_OF_INTS).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Overlength);
+}
+
+#[test]
+fn string_round_trip() {
+    let s = heapless::String::<8>::from_der(UTF8_STRING).unwrap();
+    assert_eq!(s.as_str(), "Helló");
+
+    let mut buf = [0u8; 16];
+    assert_eq!(s.encode_to_slice(&mut buf).unwrap(), UTF8_STRING);
+}
+
+#[test]
+fn string_over_capacity() {
+    let err = heapless::String::<5>::from_der(UTF8_STRING).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Overlength);
+}
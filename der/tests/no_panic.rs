@@ -0,0 +1,184 @@
+//! Link-time checks that decoding can't panic.
+//!
+//! Each function below is wrapped in a guard which references an undefined
+//! symbol if it's dropped during unwinding. If the optimizer can't prove a
+//! function never panics, the guard is retained and linking fails with an
+//! error naming the symbol.
+//!
+//! This relies on optimizations, so it's only checked in release builds
+//! with LTO enabled:
+//!
+//! $ CARGO_PROFILE_RELEASE_LTO=fat cargo test --release --features no-panic,oid --test no_panic
+//!
+//! Two groups of entry points can't be checked this way, as they make calls
+//! through trait objects which the optimizer has to assume may unwind:
+//!
+//! - Decoding the contents of constructed values (e.g. [`Decoder::sequence`]
+//!   or [`SequenceOf`]) reports progress to the optional callback given to
+//!   [`Decoder::new_with_progress`], which is a `&dyn Fn`.
+//! - Encoding (e.g. [`Encode::encode_to_slice`]) writes to the encoder's
+//!   output, which may be a `&mut dyn Writer`.
+//!
+//! These calls aren't inlined, so the guard is retained even when the
+//! callback is absent or the output is a slice. Panics in user-supplied
+//! callbacks and writers are out of scope regardless, and the rest of these
+//! paths are instead exercised with arbitrary input in `no_panic_proptest.rs`.
+//! Computing the encoded length doesn't write anything, and is covered here.
+//!
+//! [`Decoder::sequence`]: der::Decoder::sequence
+//! [`Decoder::new_with_progress`]: der::Decoder::new_with_progress
+//! [`Encode::encode_to_slice`]: der::Encode::encode_to_slice
+//! [`SequenceOf`]: der::asn1::SequenceOf
+
+#![cfg(all(feature = "no-panic", not(debug_assertions)))]
+
+use der::{
+    asn1::{
+        Any, BitString, GeneralizedTime, OctetString, PrintableString, SequenceRef, UIntBytes,
+        UtcTime, Utf8String,
+    },
+    Decode, Decoder, Encode, Header, Length, Result, Tlv,
+};
+
+#[cfg(feature = "oid")]
+use der::asn1::ObjectIdentifier;
+
+/// Guard which fails to link unless it's provably never dropped.
+struct Guard;
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        extern "C" {
+            #[link_name = "\n\nERROR: der function may panic\n\n"]
+            fn trigger() -> !;
+        }
+
+        unsafe { trigger() }
+    }
+}
+
+macro_rules! no_panic {
+    ($($(#[$attr:meta])* fn $name:ident($($arg:ident: $ty:ty),*) -> $ret:ty $body:block)*) => {
+        $(
+            $(#[$attr])*
+            #[inline(never)]
+            fn $name($($arg: $ty),*) -> $ret {
+                let guard = Guard;
+                let ret = (|| $body)();
+                core::mem::forget(guard);
+                ret
+            }
+        )*
+    };
+}
+
+no_panic! {
+    fn decode_bool(bytes: &[u8]) -> Result<bool> {
+        bool::from_der(bytes)
+    }
+
+    fn decode_u64(bytes: &[u8]) -> Result<u64> {
+        u64::from_der(bytes)
+    }
+
+    fn decode_i32(bytes: &[u8]) -> Result<i32> {
+        i32::from_der(bytes)
+    }
+
+    fn decode_uint_bytes(bytes: &[u8]) -> Result<UIntBytes<'_>> {
+        UIntBytes::from_der(bytes)
+    }
+
+    fn decode_header(bytes: &[u8]) -> Result<Header> {
+        Header::decode(&mut Decoder::new(bytes)?)
+    }
+
+    fn decode_length(bytes: &[u8]) -> Result<Length> {
+        Length::from_der(bytes)
+    }
+
+    fn decode_bit_string(bytes: &[u8]) -> Result<BitString<'_>> {
+        BitString::from_der(bytes)
+    }
+
+    fn decode_octet_string(bytes: &[u8]) -> Result<OctetString<'_>> {
+        OctetString::from_der(bytes)
+    }
+
+    fn decode_printable_string(bytes: &[u8]) -> Result<PrintableString<'_>> {
+        PrintableString::from_der(bytes)
+    }
+
+    fn decode_utf8_string(bytes: &[u8]) -> Result<Utf8String<'_>> {
+        Utf8String::from_der(bytes)
+    }
+
+    fn decode_utc_time(bytes: &[u8]) -> Result<UtcTime> {
+        UtcTime::from_der(bytes)
+    }
+
+    fn decode_generalized_time(bytes: &[u8]) -> Result<GeneralizedTime> {
+        GeneralizedTime::from_der(bytes)
+    }
+
+    #[cfg(feature = "oid")]
+    fn decode_oid(bytes: &[u8]) -> Result<ObjectIdentifier> {
+        ObjectIdentifier::from_der(bytes)
+    }
+
+    fn decode_any(bytes: &[u8]) -> Result<Any<'_>> {
+        Any::from_der(bytes)
+    }
+
+    fn decode_tlv(bytes: &[u8]) -> Result<Tlv<'_>> {
+        Decoder::new(bytes)?.tlv()
+    }
+
+    fn decode_sequence_ref(bytes: &[u8]) -> Result<SequenceRef<'_>> {
+        SequenceRef::from_der(bytes)
+    }
+
+    fn encoded_len_u64(value: u64) -> Result<Length> {
+        value.encoded_len()
+    }
+
+    fn encoded_len_octet_string(value: &OctetString<'_>) -> Result<Length> {
+        value.encoded_len()
+    }
+
+    fn encoded_len_any(value: &Any<'_>) -> Result<Length> {
+        value.encoded_len()
+    }
+}
+
+#[test]
+fn run() {
+    assert!(decode_bool(&[0x01, 0x01, 0xFF]).unwrap());
+    assert_eq!(decode_u64(&[0x02, 0x01, 0x2A]).unwrap(), 42);
+    assert_eq!(decode_i32(&[0x02, 0x01, 0xFF]).unwrap(), -1);
+    assert!(decode_uint_bytes(&[0x02, 0x01, 0x80]).is_err());
+    assert_eq!(decode_header(&[0x04, 0x00]).unwrap().length, Length::ZERO);
+    assert!(decode_length(&[0x81, 0x05]).is_err());
+    assert!(decode_bit_string(&[0x03, 0x01, 0x08]).is_err());
+    assert!(decode_octet_string(&[0x04, 0x02, 0x00]).is_err());
+    assert!(decode_printable_string(&[0x13, 0x01, b'*']).is_err());
+    assert!(decode_utf8_string(&[0x0C, 0x01, 0xFF]).is_err());
+    assert!(decode_utc_time(&[0x17, 0x00]).is_err());
+    assert!(decode_generalized_time(&[0x18, 0x01, b'1']).is_err());
+    assert!(decode_any(&[]).is_err());
+    assert!(decode_tlv(&[0x30, 0x80]).is_err());
+    assert!(decode_sequence_ref(&[0x30, 0x01]).is_err());
+    assert_eq!(encoded_len_u64(u64::MAX).unwrap(), Length::new(11));
+
+    let octet_string = decode_octet_string(&[0x04, 0x01, 0x2A]).unwrap();
+    assert_eq!(
+        encoded_len_octet_string(&octet_string).unwrap(),
+        Length::new(3)
+    );
+
+    let any = decode_any(&[0x30, 0x03, 0x02, 0x01, 0x2A]).unwrap();
+    assert_eq!(encoded_len_any(&any).unwrap(), Length::new(5));
+
+    #[cfg(feature = "oid")]
+    assert!(decode_oid(&[0x06, 0x01, 0x80]).is_err());
+}
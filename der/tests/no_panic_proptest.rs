@@ -0,0 +1,82 @@
+//! Checks that the entry points which can't be covered by the link-time
+//! checks in `no_panic.rs` don't panic on arbitrary input.
+
+use der::{
+    asn1::{Any, OctetString, SequenceOf},
+    Decode, Decoder, Encode, Encoder, Length, Result,
+};
+use hex_literal::hex;
+use proptest::prelude::*;
+
+/// `SEQUENCE { INTEGER, SEQUENCE { BOOLEAN, ANY }, OCTET STRING }`
+const NESTED_SEQUENCE: &[u8] = &hex!("3012 02012A 3008 0101FF 3003 020101 0403 010203");
+
+/// Decode [`NESTED_SEQUENCE`] (or whatever's left of it).
+fn decode_nested(bytes: &[u8]) -> Result<(u8, bool, Any<'_>, OctetString<'_>)> {
+    let mut decoder = Decoder::new(bytes)?;
+    let value = decoder.sequence(|seq| {
+        let int = seq.decode()?;
+        let (flag, any) = seq.sequence(|inner| Ok((inner.decode()?, inner.decode()?)))?;
+        Ok((int, flag, any, seq.decode()?))
+    })?;
+    decoder.finish(value)
+}
+
+/// Encode the decoded [`NESTED_SEQUENCE`] into `buf`.
+fn encode_nested<'a>(
+    value: &(u8, bool, Any<'_>, OctetString<'_>),
+    buf: &'a mut [u8],
+) -> Result<&'a [u8]> {
+    let inner_len = (value.1.encoded_len()? + value.2.encoded_len()?)?;
+    let len = (value.0.encoded_len()? + inner_len.for_tlv()? + value.3.encoded_len()?)?;
+
+    let mut encoder = Encoder::new(buf);
+    encoder.sequence(len, |seq| {
+        seq.encode(&value.0)?;
+        seq.sequence(inner_len, |inner| {
+            inner.encode(&value.1)?;
+            inner.encode(&value.2)
+        })?;
+        seq.encode(&value.3)
+    })?;
+    encoder.finish()
+}
+
+proptest! {
+    #[test]
+    fn decode_mutated_sequence(
+        index in 0..NESTED_SEQUENCE.len(),
+        byte in any::<u8>(),
+        len in 0..=NESTED_SEQUENCE.len(),
+    ) {
+        let mut bytes = NESTED_SEQUENCE.to_vec();
+        bytes[index] = byte;
+        bytes.truncate(len);
+
+        let _ = decode_nested(&bytes);
+        let _ = SequenceOf::<Any<'_>, 4>::from_der(&bytes);
+        let _ = SequenceOf::<u8, 2>::from_der(&bytes);
+    }
+
+    #[test]
+    fn decode_arbitrary_sequence(bytes in proptest::collection::vec(any::<u8>(), 0..64)) {
+        let _ = decode_nested(&bytes);
+        let _ = SequenceOf::<Any<'_>, 4>::from_der(&bytes);
+        let _ = SequenceOf::<u8, 2>::from_der(&bytes);
+    }
+
+    #[test]
+    fn encode_to_short_buffer(len in 0..=NESTED_SEQUENCE.len()) {
+        let value = decode_nested(NESTED_SEQUENCE).unwrap();
+        let mut buf = vec![0u8; len];
+
+        match encode_nested(&value, &mut buf) {
+            Ok(der) => prop_assert_eq!(der, NESTED_SEQUENCE),
+            Err(_) => prop_assert!(len < NESTED_SEQUENCE.len()),
+        }
+
+        let any = Any::from_der(NESTED_SEQUENCE).unwrap();
+        prop_assert_eq!(any.encoded_len().unwrap(), Length::new(NESTED_SEQUENCE.len() as u16));
+        prop_assert_eq!(any.encode_to_slice(&mut buf).is_ok(), len == NESTED_SEQUENCE.len());
+    }
+}
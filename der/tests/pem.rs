@@ -0,0 +1,45 @@
+//! PEM decoding and encoding tests.
+
+#![cfg(all(feature = "derive", feature = "pem"))]
+
+use der::{
+    pem::{LineEnding, PemLabel},
+    Decode, DecodePem, EncodePem, ErrorKind, Sequence,
+};
+
+/// Example type with a PEM label.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Sequence)]
+pub struct Point {
+    pub x: u8,
+    pub y: u8,
+}
+
+impl PemLabel for Point {
+    const TYPE_LABEL: &'static str = "POINT";
+}
+
+const POINT_PEM: &str = "-----BEGIN POINT-----\nMAYCAQECAQI=\n-----END POINT-----\n";
+
+#[test]
+fn from_pem() {
+    let point = Point::from_pem(POINT_PEM).unwrap();
+    assert_eq!(point, Point { x: 1, y: 2 });
+}
+
+#[test]
+fn from_pem_wrong_label() {
+    let pem = POINT_PEM.replace("POINT", "VECTOR");
+    let err = Point::from_pem(pem).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::Pem(_)));
+}
+
+#[test]
+fn to_pem() {
+    let point = Point { x: 1, y: 2 };
+    let pem = point.to_pem(LineEnding::LF).unwrap();
+    assert_eq!(pem, POINT_PEM);
+    assert_eq!(
+        Point::from_der(&[0x30, 6, 2, 1, 1, 2, 1, 2]).unwrap(),
+        point
+    );
+}
@@ -0,0 +1,131 @@
+//! Tests for `serde` support.
+
+#![cfg(all(feature = "oid", feature = "serde"))]
+
+use der::{
+    asn1::{
+        Any, BitString, GeneralizedTime, ObjectIdentifier, OctetString, OwnedAny, OwnedBitString,
+        OwnedOctetString,
+    },
+    DateTime, Tag,
+};
+use hex_literal::hex;
+
+const OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+
+#[test]
+fn object_identifier() {
+    let json = serde_json::to_string(&OID).unwrap();
+    assert_eq!(json, "\"1.2.840.10045.2.1\"");
+    assert_eq!(
+        serde_json::from_str::<ObjectIdentifier>(&json).unwrap(),
+        OID
+    );
+
+    let bin = bincode::serialize(&OID).unwrap();
+    assert_eq!(bincode::deserialize::<ObjectIdentifier>(&bin).unwrap(), OID);
+}
+
+#[test]
+fn any() {
+    let any = Any::new(Tag::Integer, &[0x2a]).unwrap();
+    assert_eq!(serde_json::to_string(&any).unwrap(), "\"02012a\"");
+
+    let bin = bincode::serialize(&any).unwrap();
+    assert_eq!(&bin[8..], hex!("02012a"));
+    assert_eq!(bincode::deserialize::<Any<'_>>(&bin).unwrap(), any);
+}
+
+#[test]
+fn bit_string() {
+    let bits = BitString::new(6, &[0x80]).unwrap();
+    assert_eq!(serde_json::to_string(&bits).unwrap(), "\"0680\"");
+
+    let bin = bincode::serialize(&bits).unwrap();
+    assert_eq!(bincode::deserialize::<BitString<'_>>(&bin).unwrap(), bits);
+
+    // Unused bits on an empty `BIT STRING`
+    let bin = bincode::serialize(&[0x01u8][..]).unwrap();
+    assert!(bincode::deserialize::<BitString<'_>>(&bin).is_err());
+}
+
+#[test]
+fn octet_string() {
+    let octets = OctetString::new(&[0xde, 0xad, 0xbe, 0xef]).unwrap();
+    assert_eq!(serde_json::to_string(&octets).unwrap(), "\"deadbeef\"");
+
+    let bin = bincode::serialize(&octets).unwrap();
+    assert_eq!(
+        bincode::deserialize::<OctetString<'_>>(&bin).unwrap(),
+        octets
+    );
+}
+
+#[test]
+fn borrowed_types_reject_human_readable() {
+    assert!(serde_json::from_str::<OctetString<'_>>("\"deadbeef\"").is_err());
+}
+
+#[test]
+fn owned_any_json_round_trip() {
+    let any = Any::new(Tag::Integer, &[0x2a]).unwrap();
+    let json = serde_json::to_string(&any).unwrap();
+
+    let owned = serde_json::from_str::<OwnedAny>(&json).unwrap();
+    assert_eq!(owned.as_any(), any);
+    assert_eq!(serde_json::to_string(&owned).unwrap(), json);
+
+    let bin = bincode::serialize(&owned).unwrap();
+    assert_eq!(bin, bincode::serialize(&any).unwrap());
+    assert_eq!(bincode::deserialize::<OwnedAny>(&bin).unwrap(), owned);
+
+    assert!(serde_json::from_str::<OwnedAny>("\"0201\"").is_err());
+}
+
+#[test]
+fn owned_bit_string_json_round_trip() {
+    let bits = BitString::new(6, &[0x80]).unwrap();
+    let json = serde_json::to_string(&bits).unwrap();
+
+    let owned = serde_json::from_str::<OwnedBitString>(&json).unwrap();
+    assert_eq!(owned.as_bit_string(), bits);
+    assert_eq!(serde_json::to_string(&owned).unwrap(), json);
+
+    let bin = bincode::serialize(&owned).unwrap();
+    assert_eq!(bincode::deserialize::<OwnedBitString>(&bin).unwrap(), owned);
+
+    assert!(serde_json::from_str::<OwnedBitString>("\"\"").is_err());
+    assert!(serde_json::from_str::<OwnedBitString>("\"01\"").is_err());
+}
+
+#[test]
+fn owned_octet_string_json_round_trip() {
+    let octets = OctetString::new(&[0xde, 0xad, 0xbe, 0xef]).unwrap();
+    let json = serde_json::to_string(&octets).unwrap();
+
+    let owned = serde_json::from_str::<OwnedOctetString>(&json).unwrap();
+    assert_eq!(owned.as_octet_string(), octets);
+    assert_eq!(serde_json::to_string(&owned).unwrap(), json);
+
+    let bin = bincode::serialize(&owned).unwrap();
+    assert_eq!(
+        bincode::deserialize::<OwnedOctetString>(&bin).unwrap(),
+        owned
+    );
+}
+
+#[test]
+fn generalized_time() {
+    let time = GeneralizedTime::from_date_time(DateTime::new(2001, 1, 2, 12, 13, 14).unwrap());
+
+    let json = serde_json::to_string(&time).unwrap();
+    assert_eq!(json, "\"2001-01-02T12:13:14Z\"");
+    assert_eq!(
+        serde_json::from_str::<GeneralizedTime>(&json).unwrap(),
+        time
+    );
+
+    let bin = bincode::serialize(&time).unwrap();
+    assert_eq!(bin, 978437594u64.to_le_bytes());
+    assert_eq!(bincode::deserialize::<GeneralizedTime>(&bin).unwrap(), time);
+}
@@ -11,9 +11,12 @@
 //! [RFC 4648]: https://datatracker.ietf.org/doc/html/rfc4648
 
 use crate::{
-    grammar, Base64Decoder, Error, Result, BASE64_WRAP_WIDTH, POST_ENCAPSULATION_BOUNDARY,
+    grammar, Base64Decoder, Error, Headers, Result, BASE64_WRAP_WIDTH, POST_ENCAPSULATION_BOUNDARY,
     PRE_ENCAPSULATION_BOUNDARY,
 };
+
+#[cfg(feature = "alloc")]
+use crate::{PositionedError, ENCAPSULATION_BOUNDARY_DELIMITER};
 use core::str;
 
 #[cfg(feature = "alloc")]
@@ -56,6 +59,26 @@ pub fn decode_vec(pem: &[u8]) -> Result<(&str, Vec<u8>)> {
     Ok((type_label, buf))
 }
 
+/// Decode a PEM document according to RFC 7468's "Strict" grammar, ensuring
+/// its type label matches `expected` before decoding the encapsulated text.
+///
+/// Returns [`Error::UnexpectedTypeLabel`] if the label does not match.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn decode_vec_with_label(expected: &'static str, pem: &[u8]) -> Result<Vec<u8>> {
+    let mut decoder = Decoder::new(pem).map_err(|e| check_for_headers(pem, e))?;
+
+    if decoder.type_label() != expected {
+        return Err(Error::UnexpectedTypeLabel { expected });
+    }
+
+    let mut buf = Vec::new();
+    decoder
+        .decode_to_end(&mut buf)
+        .map_err(|e| check_for_headers(pem, e))?;
+    Ok(buf)
+}
+
 /// Decode the encapsulation boundaries of a PEM document according to RFC 7468's "Strict" grammar.
 ///
 /// On success, returning the decoded label.
@@ -74,9 +97,33 @@ pub struct Decoder<'i> {
 
     /// Buffered Base64 decoder.
     base64: Base64Decoder<'i>,
+
+    /// RFC 1421 headers (only parsed in lenient mode).
+    headers: Option<Headers<'i>>,
 }
 
 impl<'i> Decoder<'i> {
+    /// Decode every PEM document contained in `pem`, e.g. a certificate
+    /// chain, returning an iterator over `(label, der_bytes)` pairs.
+    ///
+    /// Any text before, between, or after the documents is ignored. Errors
+    /// are reported along with the byte offset of the offending document,
+    /// after which decoding resumes with the next document, if any. A
+    /// document missing its post-encapsulation boundary ends at the start of
+    /// the next one.
+    ///
+    /// Unlike [`Decoder::new`], this scans the encapsulated text in order to
+    /// locate the document boundaries, and is therefore intended for inputs
+    /// such as certificate bundles rather than secret key material.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn decode_all(pem: &'i [u8]) -> DecodeAll<'i> {
+        DecodeAll {
+            remaining: pem,
+            position: 0,
+        }
+    }
+
     /// Create a new PEM [`Decoder`] with the default options.
     ///
     /// Uses the default 64-character line wrapping.
@@ -89,7 +136,37 @@ impl<'i> Decoder<'i> {
         let encapsulation = Encapsulation::try_from(pem)?;
         let type_label = encapsulation.label();
         let base64 = Base64Decoder::new_wrapped(encapsulation.encapsulated_text, line_width)?;
-        Ok(Self { type_label, base64 })
+        Ok(Self {
+            type_label,
+            base64,
+            headers: None,
+        })
+    }
+
+    /// Create a new PEM [`Decoder`] which tolerates RFC 1421 headers at the
+    /// start of the encapsulated text, as found in "traditional" encrypted
+    /// private keys produced by OpenSSL (see [`Headers`]).
+    ///
+    /// Such headers are disallowed by RFC 7468. When present, they're
+    /// available via [`Decoder::headers`], which higher layers can use to
+    /// detect and decrypt such keys.
+    ///
+    /// Uses the default 64-character line wrapping.
+    pub fn new_lenient(pem: &'i [u8]) -> Result<Self> {
+        let encapsulation = Encapsulation::try_from(pem)?;
+        let type_label = encapsulation.label();
+
+        let (headers, encapsulated_text) = match Headers::parse(encapsulation.encapsulated_text)? {
+            Some((headers, text)) => (Some(headers), text),
+            None => (None, encapsulation.encapsulated_text),
+        };
+
+        let base64 = Base64Decoder::new_wrapped(encapsulated_text, BASE64_WRAP_WIDTH)?;
+        Ok(Self {
+            type_label,
+            base64,
+            headers,
+        })
     }
 
     /// Get the PEM type label for the input document.
@@ -97,6 +174,12 @@ impl<'i> Decoder<'i> {
         self.type_label
     }
 
+    /// Get the RFC 1421 headers of the input document, if it has any and
+    /// this decoder was created with [`Decoder::new_lenient`].
+    pub fn headers(&self) -> Option<Headers<'i>> {
+        self.headers
+    }
+
     /// Decode data into the provided output buffer.
     ///
     /// There must be at least as much remaining Base64 input to be decoded
@@ -145,6 +228,95 @@ impl<'i> io::Read for Decoder<'i> {
     }
 }
 
+/// Iterator over the documents in a multi-document PEM input.
+///
+/// Created by [`Decoder::decode_all`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug)]
+pub struct DecodeAll<'i> {
+    /// Input which has yet to be decoded.
+    remaining: &'i [u8],
+
+    /// Byte offset of `remaining` within the original input.
+    position: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<'i> DecodeAll<'i> {
+    /// Split the next document off of the remaining input, returning its
+    /// byte offset along with the document including its trailing newline.
+    fn next_document(
+        &mut self,
+    ) -> Option<core::result::Result<(usize, &'i [u8]), PositionedError>> {
+        let start = find(self.remaining, PRE_ENCAPSULATION_BOUNDARY)?;
+        let position = self.position + start;
+        let document = &self.remaining[start..];
+        let body = &document[PRE_ENCAPSULATION_BOUNDARY.len()..];
+
+        // A truncated document must not swallow the one following it, so the
+        // search for its end stops at the next pre-encapsulation boundary
+        let next_start =
+            find(body, PRE_ENCAPSULATION_BOUNDARY).map(|n| n + PRE_ENCAPSULATION_BOUNDARY.len());
+
+        let end = find(body, POST_ENCAPSULATION_BOUNDARY)
+            .map(|n| n + PRE_ENCAPSULATION_BOUNDARY.len())
+            .filter(|&n| next_start.map_or(true, |next_start| n < next_start))
+            .map(|n| n + POST_ENCAPSULATION_BOUNDARY.len())
+            .and_then(|n| {
+                find(&document[n..], ENCAPSULATION_BOUNDARY_DELIMITER)
+                    .map(|m| n + m + ENCAPSULATION_BOUNDARY_DELIMITER.len())
+            });
+
+        let mut end = match end {
+            Some(end) => end,
+            None => {
+                match next_start {
+                    Some(next_start) => {
+                        self.remaining = &document[next_start..];
+                        self.position = position + next_start;
+                    }
+                    None => self.remaining = &[],
+                }
+
+                return Some(Err(PositionedError {
+                    position,
+                    error: Error::PostEncapsulationBoundary,
+                }));
+            }
+        };
+
+        end += match &document[end..] {
+            [grammar::CHAR_CR, grammar::CHAR_LF, ..] => 2,
+            [grammar::CHAR_CR, ..] | [grammar::CHAR_LF, ..] => 1,
+            _ => 0,
+        };
+
+        self.remaining = &document[end..];
+        self.position = position + end;
+        Some(Ok((position, &document[..end])))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'i> Iterator for DecodeAll<'i> {
+    type Item = core::result::Result<(&'i str, Vec<u8>), PositionedError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_document()?.and_then(|(position, document)| {
+            decode_vec(document).map_err(|error| PositionedError { position, error })
+        }))
+    }
+}
+
+/// Find the offset of the first occurrence of `needle` in `haystack`.
+#[cfg(feature = "alloc")]
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
 /// PEM encapsulation parser.
 ///
 /// This parser performs an initial pass over the data, locating the
@@ -153,9 +325,8 @@ impl<'i> io::Read for Decoder<'i> {
 /// on the potentially secret Base64-encoded data encapsulated between
 /// the two boundaries.
 ///
-/// It only supports a single encapsulated message at present. Future work
-/// could potentially include extending it provide an iterator over a series
-/// of encapsulated messages.
+/// It only supports a single encapsulated message. See [`DecodeAll`] for
+/// inputs containing a series of encapsulated messages.
 #[derive(Copy, Clone, Debug)]
 struct Encapsulation<'a> {
     /// Type label extracted from the pre/post-encapsulation boundaries.
@@ -69,6 +69,29 @@ impl fmt::Display for Error {
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
+/// Error decoding a document contained in a multi-document PEM input,
+/// along with the position of that document.
+///
+/// Returned by the [`DecodeAll`][`crate::DecodeAll`] iterator.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PositionedError {
+    /// Byte offset of the pre-encapsulation boundary of the document
+    /// which failed to decode.
+    pub position: usize,
+
+    /// Error which occurred decoding the document.
+    pub error: Error,
+}
+
+impl fmt::Display for PositionedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (document at byte {})", self.error, self.position)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PositionedError {}
+
 impl From<base64ct::Error> for Error {
     fn from(err: base64ct::Error) -> Error {
         Error::Base64(err)
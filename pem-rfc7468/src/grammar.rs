@@ -45,6 +45,19 @@ pub(crate) fn is_wsp(char: u8) -> bool {
     matches!(char, CHAR_HT | CHAR_SP)
 }
 
+/// Does the provided line begin an RFC 1421 header field, i.e. `name: value`?
+///
+/// Field names are one or more printable characters other than the colon.
+pub(crate) fn is_header_line(line: &[u8]) -> bool {
+    match line.iter().position(|&c| c == CHAR_COLON) {
+        Some(n) if n > 0 => line
+            .iter()
+            .take(n)
+            .all(|&c| matches!(c, 0x21..=0x39 | 0x3B..=0x7E)),
+        _ => false,
+    }
+}
+
 /// Strip the "preamble", i.e. data that appears before the PEM
 /// pre-encapsulation boundary.
 ///
@@ -52,16 +52,27 @@ mod decoder;
 mod encoder;
 mod error;
 mod grammar;
+mod headers;
+
+#[cfg(feature = "std")]
+mod stream;
 
 pub use crate::{
     decoder::{decode, decode_label, Decoder},
     encoder::{encapsulated_len, encode, encoded_len, Encoder},
-    error::{Error, Result},
+    error::{Error, PositionedError, Result},
+    headers::{DekInfo, HeaderFields, Headers},
 };
 pub use base64ct::LineEnding;
 
 #[cfg(feature = "alloc")]
-pub use crate::{decoder::decode_vec, encoder::encode_string};
+pub use crate::{
+    decoder::{decode_vec, decode_vec_with_label, DecodeAll},
+    encoder::encode_string,
+};
+
+#[cfg(feature = "std")]
+pub use crate::stream::{StreamDecoder, StreamEncoder};
 
 /// The pre-encapsulation boundary appears before the encapsulated text.
 ///
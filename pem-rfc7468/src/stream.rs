@@ -0,0 +1,313 @@
+//! Streaming PEM decoder and encoder.
+//!
+//! These types process PEM documents in fixed-size chunks using
+//! [`io::BufRead`] and [`io::Write`], and are intended for very large
+//! documents (e.g. CRLs or CT bundles) which would otherwise need to be
+//! materialized in memory in their entirety.
+
+use crate::{
+    grammar, Error, LineEnding, BASE64_WRAP_WIDTH, ENCAPSULATION_BOUNDARY_DELIMITER,
+    POST_ENCAPSULATION_BOUNDARY, PRE_ENCAPSULATION_BOUNDARY,
+};
+use alloc::{string::String, vec::Vec};
+use base64ct::{Base64, Encoding};
+use std::io::{self, BufRead, Read};
+
+/// Maximum length of a line within the encapsulated text, including the
+/// encapsulation boundaries.
+const MAX_LINE_LEN: usize = 1024;
+
+/// Number of input bytes encoded per line of Base64.
+const BYTES_PER_LINE: usize = BASE64_WRAP_WIDTH / 4 * 3;
+
+/// Streaming PEM decoder.
+///
+/// Reads a PEM document from the provided [`io::BufRead`] line-by-line,
+/// decoding the encapsulated text as it is read via [`io::Read`].
+///
+/// Lines must be terminated with either LF or CRLF, and may be at most
+/// 1024 bytes long. Any text preceding the pre-encapsulation boundary is
+/// ignored. Once the post-encapsulation boundary has been read, the inner
+/// reader can be obtained using [`StreamDecoder::into_inner`], e.g. in order
+/// to decode subsequent documents.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct StreamDecoder<R: BufRead> {
+    /// Inner reader.
+    reader: R,
+
+    /// PEM type label.
+    type_label: String,
+
+    /// Buffer for the current line.
+    line: Vec<u8>,
+
+    /// Base64 which has been read but not yet decoded.
+    base64: Vec<u8>,
+
+    /// Decoded data which has not yet been read.
+    decoded: Vec<u8>,
+
+    /// Position within `decoded`.
+    position: usize,
+
+    /// Has Base64 padding been decoded?
+    padded: bool,
+
+    /// Has the post-encapsulation boundary been read?
+    finished: bool,
+}
+
+impl<R: BufRead> StreamDecoder<R> {
+    /// Create a new [`StreamDecoder`], reading from `reader` up to and
+    /// including the pre-encapsulation boundary.
+    pub fn new(reader: R) -> io::Result<Self> {
+        let mut decoder = Self {
+            reader,
+            type_label: String::new(),
+            line: Vec::new(),
+            base64: Vec::new(),
+            decoded: Vec::new(),
+            position: 0,
+            padded: false,
+            finished: false,
+        };
+
+        let mut line_start = true;
+
+        loop {
+            if decoder.read_line()? == 0 {
+                return Err(Error::Preamble.into());
+            }
+
+            if line_start && decoder.line.starts_with(PRE_ENCAPSULATION_BOUNDARY) {
+                let (label, rest) =
+                    grammar::split_label(&decoder.line[PRE_ENCAPSULATION_BOUNDARY.len()..])
+                        .ok_or(Error::PreEncapsulationBoundary)?;
+
+                if !rest.is_empty() {
+                    return Err(Error::PreEncapsulationBoundary.into());
+                }
+
+                decoder.type_label = label.into();
+                return Ok(decoder);
+            }
+
+            if decoder.line.contains(&grammar::CHAR_NUL) {
+                return Err(Error::Preamble.into());
+            }
+
+            line_start = decoder.line.ends_with(&[grammar::CHAR_LF]);
+        }
+    }
+
+    /// Get the PEM type label for the document being decoded.
+    pub fn type_label(&self) -> &str {
+        &self.type_label
+    }
+
+    /// Have the encapsulated text and post-encapsulation boundary been read
+    /// in their entirety?
+    pub fn is_finished(&self) -> bool {
+        self.finished && self.position == self.decoded.len()
+    }
+
+    /// Get the inner reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Read a line of at most [`MAX_LINE_LEN`] bytes into `self.line`,
+    /// returning the number of bytes read.
+    fn read_line(&mut self) -> io::Result<usize> {
+        self.line.clear();
+        (&mut self.reader)
+            .take(MAX_LINE_LEN as u64)
+            .read_until(grammar::CHAR_LF, &mut self.line)
+    }
+
+    /// Read and decode the next line of the encapsulated text.
+    fn decode_line(&mut self) -> io::Result<()> {
+        self.read_line()?;
+
+        let line = match grammar::strip_trailing_eol(&self.line) {
+            Some(line) => line,
+            None if self.line.len() == MAX_LINE_LEN => {
+                return Err(Error::EncapsulatedText.into());
+            }
+            None => return Err(Error::PostEncapsulationBoundary.into()),
+        };
+
+        if let Some(rest) = line.strip_prefix(POST_ENCAPSULATION_BOUNDARY) {
+            if rest.strip_suffix(ENCAPSULATION_BOUNDARY_DELIMITER)
+                != Some(self.type_label.as_bytes())
+            {
+                return Err(Error::PostEncapsulationBoundary.into());
+            }
+
+            if !self.base64.is_empty() {
+                return Err(Error::Base64(base64ct::Error::InvalidLength).into());
+            }
+
+            self.finished = true;
+            return Ok(());
+        }
+
+        if self.padded {
+            return Err(Error::EncapsulatedText.into());
+        }
+
+        if line.contains(&b':') {
+            return Err(Error::HeaderDisallowed.into());
+        }
+
+        self.base64.extend_from_slice(line);
+        let base64_len = self.base64.len() / 4 * 4;
+        let decoded_len = base64_len / 4 * 3;
+
+        self.decoded.resize(decoded_len, 0);
+        self.position = 0;
+
+        let len = Base64::decode(&self.base64[..base64_len], &mut self.decoded)
+            .map_err(Error::from)?
+            .len();
+
+        self.padded = len < decoded_len;
+        self.decoded.truncate(len);
+        self.base64.drain(..base64_len);
+        Ok(())
+    }
+}
+
+impl<R: BufRead> Read for StreamDecoder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position == self.decoded.len() {
+            if self.finished {
+                return Ok(0);
+            }
+
+            self.decode_line()?;
+        }
+
+        let len = buf.len().min(self.decoded.len() - self.position);
+        buf[..len].copy_from_slice(&self.decoded[self.position..][..len]);
+        self.position += len;
+        Ok(len)
+    }
+}
+
+/// Streaming PEM encoder.
+///
+/// Writes a PEM document to the provided [`io::Write`], encoding data
+/// written via [`io::Write`] one line at a time using the default
+/// 64-character line wrapping.
+///
+/// The document must be completed using [`StreamEncoder::finish`], which
+/// writes the post-encapsulation boundary.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct StreamEncoder<W: io::Write> {
+    /// Inner writer.
+    writer: W,
+
+    /// PEM type label.
+    type_label: String,
+
+    /// Line ending used to wrap Base64.
+    line_ending: LineEnding,
+
+    /// Data which has yet to be encoded.
+    block: [u8; BYTES_PER_LINE],
+
+    /// Length of the data in `block`.
+    block_len: usize,
+}
+
+impl<W: io::Write> StreamEncoder<W> {
+    /// Create a new [`StreamEncoder`], writing the pre-encapsulation
+    /// boundary to `writer`.
+    pub fn new(type_label: &str, line_ending: LineEnding, mut writer: W) -> io::Result<Self> {
+        grammar::validate_label(type_label.as_bytes())?;
+
+        for boundary_part in [
+            PRE_ENCAPSULATION_BOUNDARY,
+            type_label.as_bytes(),
+            ENCAPSULATION_BOUNDARY_DELIMITER,
+            line_ending.as_bytes(),
+        ] {
+            writer.write_all(boundary_part)?;
+        }
+
+        Ok(Self {
+            writer,
+            type_label: type_label.into(),
+            line_ending,
+            block: [0u8; BYTES_PER_LINE],
+            block_len: 0,
+        })
+    }
+
+    /// Get the PEM type label used for this document.
+    pub fn type_label(&self) -> &str {
+        &self.type_label
+    }
+
+    /// Finish encoding PEM, writing the final line of Base64 followed by the
+    /// post-encapsulation boundary.
+    ///
+    /// On success, returns the inner writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.encode_line()?;
+
+        for boundary_part in [
+            POST_ENCAPSULATION_BOUNDARY,
+            self.type_label.as_bytes(),
+            ENCAPSULATION_BOUNDARY_DELIMITER,
+            self.line_ending.as_bytes(),
+        ] {
+            self.writer.write_all(boundary_part)?;
+        }
+
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    /// Encode the buffered data as a line of Base64.
+    fn encode_line(&mut self) -> io::Result<()> {
+        let mut buf = [0u8; BASE64_WRAP_WIDTH];
+        let encoded =
+            Base64::encode(&self.block[..self.block_len], &mut buf).map_err(Error::from)?;
+        self.writer.write_all(encoded.as_bytes())?;
+        self.writer.write_all(self.line_ending.as_bytes())?;
+        self.block_len = 0;
+        Ok(())
+    }
+}
+
+impl<W: io::Write> io::Write for StreamEncoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut input = buf;
+
+        while !input.is_empty() {
+            // Lines are only written once more input is available, so the
+            // final line is always written by `finish`.
+            if self.block_len == BYTES_PER_LINE {
+                self.encode_line()?;
+            }
+
+            let len = input.len().min(BYTES_PER_LINE - self.block_len);
+            self.block[self.block_len..][..len].copy_from_slice(&input[..len]);
+            self.block_len += len;
+            input = &input[len..];
+        }
+
+        Ok(buf.len())
+    }
+
+    /// Flush the inner writer.
+    ///
+    /// Data which does not yet make up a complete line of Base64 remains
+    /// buffered until more data is written or [`StreamEncoder::finish`] is
+    /// called.
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
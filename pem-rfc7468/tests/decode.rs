@@ -58,6 +58,53 @@ fn header_of_length_64() {
     assert_eq!(label, "RSA PRIVATE KEY");
 }
 
+#[test]
+fn pkcs1_enc_example_lenient() {
+    let pem = include_bytes!("examples/ssh_rsa_pem_password.pem");
+    let mut decoder = pem_rfc7468::Decoder::new_lenient(pem).unwrap();
+    assert_eq!(decoder.type_label(), "RSA PRIVATE KEY");
+
+    let headers = decoder.headers().unwrap();
+    assert!(headers.is_encrypted());
+
+    let dek_info = headers.dek_info().unwrap().unwrap();
+    assert_eq!(dek_info.algorithm(), "AES-128-CBC");
+    assert_eq!(dek_info.iv_hex(), "15670D76FD184D46C40C971733E0543F");
+
+    let mut buf = [0u8; 2048];
+    let len = decoder.remaining_len();
+    assert!(len > 0);
+    assert_eq!(decoder.decode(&mut buf[..len]).unwrap().len(), len);
+    assert_eq!(decoder.remaining_len(), 0);
+}
+
+#[test]
+fn header_of_length_64_lenient() {
+    let pem = include_bytes!("examples/chosen_header.pem");
+    let decoder = pem_rfc7468::Decoder::new_lenient(pem).unwrap();
+    let headers = decoder.headers().unwrap();
+    assert_eq!(
+        headers.get("A-Header-That-Happens-To-Be-Exactly"),
+        Some("64 characters long.........")
+    );
+    assert!(headers.is_encrypted());
+}
+
+#[test]
+fn pkcs8_example_lenient() {
+    let pem = include_bytes!("examples/pkcs8.pem");
+    let mut decoder = pem_rfc7468::Decoder::new_lenient(pem).unwrap();
+    assert_eq!(decoder.type_label(), "PRIVATE KEY");
+    assert!(decoder.headers().is_none());
+
+    let mut buf = [0u8; 2048];
+    let len = decoder.remaining_len();
+    assert_eq!(
+        decoder.decode(&mut buf[..len]).unwrap(),
+        include_bytes!("examples/pkcs8.der")
+    );
+}
+
 #[test]
 #[cfg(feature = "alloc")]
 fn header_of_length_64_with_vec() {
@@ -104,6 +151,89 @@ fn pkcs8_enc_example_with_vec() {
     assert_eq!(decoded, include_bytes!("examples/pkcs8-enc.der"));
 }
 
+#[test]
+#[cfg(feature = "alloc")]
+fn pkcs1_example_with_label() {
+    let pem = include_bytes!("examples/pkcs1.pem");
+    let decoded = pem_rfc7468::decode_vec_with_label("RSA PRIVATE KEY", pem).unwrap();
+    assert_eq!(decoded, include_bytes!("examples/pkcs1.der"));
+
+    assert_eq!(
+        pem_rfc7468::decode_vec_with_label("PRIVATE KEY", pem),
+        Err(pem_rfc7468::Error::UnexpectedTypeLabel {
+            expected: "PRIVATE KEY"
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn decode_all_example() {
+    let mut pem = b"chain:\n".to_vec();
+    pem.extend_from_slice(include_bytes!("examples/pkcs1.pem"));
+    pem.extend_from_slice(b"\n# second document\n");
+    pem.extend_from_slice(include_bytes!("examples/pkcs8.pem"));
+
+    let documents = pem_rfc7468::Decoder::decode_all(&pem)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(documents.len(), 2);
+    assert_eq!(documents[0].0, "RSA PRIVATE KEY");
+    assert_eq!(documents[0].1, include_bytes!("examples/pkcs1.der"));
+    assert_eq!(documents[1].0, "PRIVATE KEY");
+    assert_eq!(documents[1].1, include_bytes!("examples/pkcs8.der"));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn decode_all_errors() {
+    let pkcs1 = include_bytes!("examples/pkcs1.pem");
+    let pkcs8 = include_bytes!("examples/pkcs8.pem");
+
+    // Corrupt the Base64 of the second document
+    let mut pem = pkcs1.to_vec();
+    pem.extend_from_slice(pkcs8);
+    let offset = pkcs1.len() + 40;
+    pem[offset] = b'%';
+
+    let mut documents = pem_rfc7468::Decoder::decode_all(&pem);
+    assert_eq!(documents.next().unwrap().unwrap().0, "RSA PRIVATE KEY");
+
+    let err = documents.next().unwrap().unwrap_err();
+    assert_eq!(err.position, pkcs1.len());
+    assert!(matches!(err.error, pem_rfc7468::Error::Base64(_)));
+    assert!(documents.next().is_none());
+
+    // Truncated final document
+    let pem = [&pkcs1[..], &pkcs8[..100]].concat();
+    let mut documents = pem_rfc7468::Decoder::decode_all(&pem);
+    assert!(documents.next().unwrap().is_ok());
+    assert_eq!(
+        documents.next().unwrap(),
+        Err(pem_rfc7468::PositionedError {
+            position: pkcs1.len(),
+            error: pem_rfc7468::Error::PostEncapsulationBoundary
+        })
+    );
+    assert!(documents.next().is_none());
+
+    // Truncated document followed by a valid one
+    let pem = [&pkcs1[..100], b"\n", &pkcs8[..]].concat();
+    let mut documents = pem_rfc7468::Decoder::decode_all(&pem);
+    assert_eq!(
+        documents.next().unwrap(),
+        Err(pem_rfc7468::PositionedError {
+            position: 0,
+            error: pem_rfc7468::Error::PostEncapsulationBoundary
+        })
+    );
+    let (label, der) = documents.next().unwrap().unwrap();
+    assert_eq!(label, "PRIVATE KEY");
+    assert_eq!(der, include_bytes!("examples/pkcs8.der"));
+    assert!(documents.next().is_none());
+}
+
 #[test]
 fn ed25519_example() {
     let pem = include_bytes!("examples/ed25519_id.pem");
@@ -20,6 +20,12 @@ pub enum Error {
     /// a number expected to be a prime was not a prime.
     Crypto,
 
+    /// Errors decrypting legacy encrypted PEM private keys, e.g. because the
+    /// key isn't encrypted, the cipher is unsupported, or the password is
+    /// incorrect.
+    #[cfg(feature = "legacy-encryption")]
+    Decrypt,
+
     /// PKCS#8 errors.
     #[cfg(feature = "pkcs8")]
     Pkcs8(pkcs8::Error),
@@ -33,6 +39,8 @@ impl fmt::Display for Error {
         match self {
             Error::Asn1(err) => write!(f, "PKCS#1 ASN.1 error: {}", err),
             Error::Crypto => f.write_str("PKCS#1 cryptographic error"),
+            #[cfg(feature = "legacy-encryption")]
+            Error::Decrypt => f.write_str("PKCS#1 legacy PEM decryption error"),
             #[cfg(feature = "pkcs8")]
             Error::Pkcs8(err) => write!(f, "{}", err),
             Error::Version => f.write_str("PKCS#1 version error"),
@@ -46,12 +54,21 @@ impl From<der::Error> for Error {
     }
 }
 
+#[cfg(feature = "pem")]
+impl From<der::pem::Error> for Error {
+    fn from(err: der::pem::Error) -> Error {
+        der::Error::from(err).into()
+    }
+}
+
 #[cfg(feature = "pkcs8")]
 impl From<Error> for pkcs8::Error {
     fn from(err: Error) -> pkcs8::Error {
         match err {
             Error::Asn1(e) => pkcs8::Error::Asn1(e),
             Error::Crypto | Error::Version => pkcs8::Error::KeyMalformed,
+            #[cfg(feature = "legacy-encryption")]
+            Error::Decrypt => pkcs8::Error::KeyMalformed,
             Error::Pkcs8(e) => e,
         }
     }
@@ -65,18 +82,18 @@ impl From<pkcs8::Error> for Error {
 }
 
 #[cfg(feature = "pkcs8")]
-impl From<Error> for pkcs8::spki::Error {
-    fn from(err: Error) -> pkcs8::spki::Error {
+impl From<Error> for spki::Error {
+    fn from(err: Error) -> spki::Error {
         match err {
-            Error::Asn1(e) => pkcs8::spki::Error::Asn1(e),
-            _ => pkcs8::spki::Error::KeyMalformed,
+            Error::Asn1(e) => spki::Error::Asn1(e),
+            _ => spki::Error::KeyMalformed,
         }
     }
 }
 
 #[cfg(feature = "pkcs8")]
-impl From<pkcs8::spki::Error> for Error {
-    fn from(err: pkcs8::spki::Error) -> Error {
+impl From<spki::Error> for Error {
+    fn from(err: spki::Error) -> Error {
         Error::Pkcs8(pkcs8::Error::PublicKey(err))
     }
 }
@@ -15,6 +15,7 @@ extern crate alloc;
 extern crate std;
 
 mod error;
+mod params;
 mod private_key;
 mod public_key;
 mod traits;
@@ -24,9 +25,14 @@ pub use der::{
     self,
     asn1::{ObjectIdentifier, UIntBytes},
 };
+pub use spki::{self, AlgorithmIdentifier};
 
 pub use self::{
     error::{Error, Result},
+    params::{
+        RsaOaepParams, RsaPssParams, TrailerField, MGF_1_OID, P_SPECIFIED_OID, RSAES_OAEP_OID,
+        RSASSA_PSS_OID, SHA_1_AI, SHA_1_OID,
+    },
     private_key::RsaPrivateKey,
     public_key::RsaPublicKey,
     traits::{DecodeRsaPrivateKey, DecodeRsaPublicKey},
@@ -54,7 +60,7 @@ pub const ALGORITHM_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.84
 /// `AlgorithmIdentifier` for RSA.
 #[cfg(feature = "pkcs8")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
-pub const ALGORITHM_ID: pkcs8::AlgorithmIdentifier<'static> = pkcs8::AlgorithmIdentifier {
+pub const ALGORITHM_ID: AlgorithmIdentifier<'static> = AlgorithmIdentifier {
     oid: ALGORITHM_OID,
     parameters: Some(der::asn1::Any::NULL),
 };
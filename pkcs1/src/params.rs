@@ -0,0 +1,312 @@
+//! PKCS#1 RSA parameters.
+
+use crate::{Error, Result};
+use der::{
+    asn1::{Any, ContextSpecific, ObjectIdentifier, OctetString},
+    Decode, DecodeValue, Decoder, Encode, EncodeValue, Encoder, ErrorKind, FixedTag, Header,
+    Length, Sequence, Tag, TagMode, TagNumber,
+};
+use spki::AlgorithmIdentifier;
+
+/// `id-sha1` Object Identifier (OID)
+pub const SHA_1_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.14.3.2.26");
+
+/// `id-mgf1` Object Identifier (OID)
+pub const MGF_1_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.8");
+
+/// `id-pSpecified` Object Identifier (OID)
+pub const P_SPECIFIED_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.9");
+
+/// `id-RSAES-OAEP` Object Identifier (OID)
+pub const RSAES_OAEP_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.7");
+
+/// `id-RSASSA-PSS` Object Identifier (OID)
+pub const RSASSA_PSS_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.10");
+
+/// `AlgorithmIdentifier` for SHA-1, the default hash function.
+pub const SHA_1_AI: AlgorithmIdentifier<'static> = AlgorithmIdentifier {
+    oid: SHA_1_OID,
+    parameters: Some(Any::NULL),
+};
+
+/// Default salt length in octets.
+const DEFAULT_SALT_LEN: u32 = 20;
+
+/// `TrailerField` as defined in [RFC 8017 Appendix 2.3].
+///
+/// ```text
+/// TrailerField ::= INTEGER { trailerFieldBC(1) }
+/// ```
+///
+/// [RFC 8017 Appendix 2.3]: https://datatracker.ietf.org/doc/html/rfc8017#appendix-A.2.3
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum TrailerField {
+    /// The only value supported by PKCS#1: the trailer field is `0xbc`.
+    BC = 1,
+}
+
+impl Default for TrailerField {
+    fn default() -> Self {
+        Self::BC
+    }
+}
+
+impl<'a> DecodeValue<'a> for TrailerField {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> der::Result<Self> {
+        match u8::decode_value(decoder, header)? {
+            1 => Ok(TrailerField::BC),
+            _ => Err(Self::TAG.value_error()),
+        }
+    }
+}
+
+impl EncodeValue for TrailerField {
+    fn value_len(&self) -> der::Result<Length> {
+        Ok(Length::ONE)
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> der::Result<()> {
+        (*self as u8).encode_value(encoder)
+    }
+}
+
+impl FixedTag for TrailerField {
+    const TAG: Tag = Tag::Integer;
+}
+
+/// PKCS#1 RSASSA-PSS parameters as defined in [RFC 8017 Appendix 2.3].
+///
+/// ASN.1 structure containing the parameters of a RSASSA-PSS signature:
+///
+/// ```text
+/// RSASSA-PSS-params ::= SEQUENCE {
+///     hashAlgorithm      [0] HashAlgorithm      DEFAULT sha1,
+///     maskGenAlgorithm   [1] MaskGenAlgorithm   DEFAULT mgf1SHA1,
+///     saltLength         [2] INTEGER            DEFAULT 20,
+///     trailerField       [3] TrailerField       DEFAULT trailerFieldBC
+/// }
+/// ```
+///
+/// Fields which are equal to their default value are omitted when encoding.
+///
+/// [RFC 8017 Appendix 2.3]: https://datatracker.ietf.org/doc/html/rfc8017#appendix-A.2.3
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RsaPssParams<'a> {
+    /// Hash algorithm used when computing the message digest.
+    pub hash: AlgorithmIdentifier<'a>,
+
+    /// Hash algorithm used by the MGF1 mask generation function.
+    pub mask_gen_hash: AlgorithmIdentifier<'a>,
+
+    /// Length of the salt in octets.
+    pub salt_len: u32,
+
+    /// Trailer field.
+    pub trailer_field: TrailerField,
+}
+
+impl<'a> Default for RsaPssParams<'a> {
+    fn default() -> Self {
+        Self {
+            hash: SHA_1_AI,
+            mask_gen_hash: SHA_1_AI,
+            salt_len: DEFAULT_SALT_LEN,
+            trailer_field: TrailerField::BC,
+        }
+    }
+}
+
+impl<'a> Decode<'a> for RsaPssParams<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.sequence(|decoder| {
+            Ok(Self {
+                hash: decode_explicit(decoder, TagNumber::N0)?.unwrap_or(SHA_1_AI),
+                mask_gen_hash: decode_explicit::<Mgf1<'_>>(decoder, TagNumber::N1)?
+                    .map(|mgf| mgf.0)
+                    .unwrap_or(SHA_1_AI),
+                salt_len: decode_explicit(decoder, TagNumber::N2)?.unwrap_or(DEFAULT_SALT_LEN),
+                trailer_field: decode_explicit(decoder, TagNumber::N3)?.unwrap_or_default(),
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for RsaPssParams<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[
+            &encode_explicit(TagNumber::N0, self.hash, SHA_1_AI),
+            &encode_explicit(TagNumber::N1, Mgf1(self.mask_gen_hash), Mgf1(SHA_1_AI)),
+            &encode_explicit(TagNumber::N2, self.salt_len, DEFAULT_SALT_LEN),
+            &encode_explicit(TagNumber::N3, self.trailer_field, TrailerField::BC),
+        ])
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for RsaPssParams<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Ok(Self::from_der(bytes)?)
+    }
+}
+
+/// PKCS#1 RSAES-OAEP parameters as defined in [RFC 8017 Appendix 2.1].
+///
+/// ASN.1 structure containing the parameters of RSAES-OAEP encryption:
+///
+/// ```text
+/// RSAES-OAEP-params ::= SEQUENCE {
+///     hashAlgorithm      [0] HashAlgorithm     DEFAULT sha1,
+///     maskGenAlgorithm   [1] MaskGenAlgorithm  DEFAULT mgf1SHA1,
+///     pSourceAlgorithm   [2] PSourceAlgorithm  DEFAULT pSpecifiedEmpty
+/// }
+/// ```
+///
+/// Fields which are equal to their default value are omitted when encoding.
+///
+/// [RFC 8017 Appendix 2.1]: https://datatracker.ietf.org/doc/html/rfc8017#appendix-A.2.1
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RsaOaepParams<'a> {
+    /// Hash algorithm used when hashing the label.
+    pub hash: AlgorithmIdentifier<'a>,
+
+    /// Hash algorithm used by the MGF1 mask generation function.
+    pub mask_gen_hash: AlgorithmIdentifier<'a>,
+
+    /// Label associated with the message, given by the `id-pSpecified`
+    /// source algorithm.
+    pub label: &'a [u8],
+}
+
+impl<'a> Default for RsaOaepParams<'a> {
+    fn default() -> Self {
+        Self {
+            hash: SHA_1_AI,
+            mask_gen_hash: SHA_1_AI,
+            label: &[],
+        }
+    }
+}
+
+impl<'a> Decode<'a> for RsaOaepParams<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.sequence(|decoder| {
+            Ok(Self {
+                hash: decode_explicit(decoder, TagNumber::N0)?.unwrap_or(SHA_1_AI),
+                mask_gen_hash: decode_explicit::<Mgf1<'_>>(decoder, TagNumber::N1)?
+                    .map(|mgf| mgf.0)
+                    .unwrap_or(SHA_1_AI),
+                label: decode_explicit::<PSpecified<'_>>(decoder, TagNumber::N2)?
+                    .map(|source| source.0)
+                    .unwrap_or(&[]),
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for RsaOaepParams<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[
+            &encode_explicit(TagNumber::N0, self.hash, SHA_1_AI),
+            &encode_explicit(TagNumber::N1, Mgf1(self.mask_gen_hash), Mgf1(SHA_1_AI)),
+            &encode_explicit(TagNumber::N2, PSpecified(self.label), PSpecified(&[])),
+        ])
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for RsaOaepParams<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Ok(Self::from_der(bytes)?)
+    }
+}
+
+/// `MaskGenAlgorithm` using MGF1 with the given hash function.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct Mgf1<'a>(AlgorithmIdentifier<'a>);
+
+impl<'a> Decode<'a> for Mgf1<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.sequence(|decoder| {
+            let oid = decoder.decode()?;
+
+            if oid != MGF_1_OID {
+                return Err(ErrorKind::OidUnknown { oid }.into());
+            }
+
+            decoder.decode().map(Self)
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for Mgf1<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[&MGF_1_OID, &self.0])
+    }
+}
+
+/// `PSourceAlgorithm` using `id-pSpecified` with the given label.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct PSpecified<'a>(&'a [u8]);
+
+impl<'a> Decode<'a> for PSpecified<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.sequence(|decoder| {
+            let oid = decoder.decode()?;
+
+            if oid != P_SPECIFIED_OID {
+                return Err(ErrorKind::OidUnknown { oid }.into());
+            }
+
+            decoder
+                .decode()
+                .map(|label: OctetString<'a>| Self(label.as_bytes()))
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for PSpecified<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[&P_SPECIFIED_OID, &OctetString::new(self.0)?])
+    }
+}
+
+/// Decode an optional `EXPLICIT` context-specific field.
+fn decode_explicit<'a, T: Decode<'a>>(
+    decoder: &mut Decoder<'a>,
+    tag_number: TagNumber,
+) -> der::Result<Option<T>> {
+    Ok(ContextSpecific::<T>::decode_explicit(decoder, tag_number)?.map(|field| field.value))
+}
+
+/// Encode an `EXPLICIT` context-specific field, omitting it if it's equal
+/// to the `DEFAULT` value.
+fn encode_explicit<T: PartialEq>(
+    tag_number: TagNumber,
+    value: T,
+    default: T,
+) -> Option<ContextSpecific<T>> {
+    if value == default {
+        None
+    } else {
+        Some(ContextSpecific {
+            tag_number,
+            tag_mode: TagMode::Explicit,
+            value,
+        })
+    }
+}
@@ -2,6 +2,8 @@
 
 #[cfg(feature = "alloc")]
 pub(crate) mod document;
+#[cfg(feature = "legacy-encryption")]
+pub(crate) mod legacy;
 #[cfg(feature = "alloc")]
 pub(crate) mod other_prime_info;
 
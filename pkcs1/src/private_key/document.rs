@@ -41,6 +41,12 @@ impl DecodeRsaPrivateKey for RsaPrivateKeyDocument {
         Ok(Self::from_pem(s)?)
     }
 
+    #[cfg(feature = "legacy-encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "legacy-encryption")))]
+    fn from_pkcs1_encrypted_pem(s: &str, password: impl AsRef<[u8]>) -> Result<Self> {
+        super::legacy::decrypt_pem(s, password.as_ref())
+    }
+
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     fn read_pkcs1_der_file(path: impl AsRef<Path>) -> Result<Self> {
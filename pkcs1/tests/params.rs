@@ -0,0 +1,165 @@
+//! PKCS#1 algorithm params tests
+
+use der::{
+    asn1::{Any, ObjectIdentifier},
+    Encode,
+};
+use hex_literal::hex;
+use pkcs1::{AlgorithmIdentifier, RsaOaepParams, RsaPssParams, TrailerField, SHA_1_AI};
+
+/// `id-sha256` Object Identifier (OID)
+const SHA_256_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.1");
+
+/// RSASSA-PSS parameters using SHA-256 and a 32-byte salt.
+///
+/// Extracted from a certificate generated using:
+/// $ openssl req -new -x509 -key key.pem -sha256 -sigopt rsa_padding_mode:pss -sigopt rsa_pss_saltlen:32
+const RSA_PSS_PARAMETERS_SHA256: &[u8] = &hex!("3034a00f300d06096086480165030402010500a11c301a06092a864886f70d010108300d06096086480165030402010500a203020120");
+
+/// RSASSA-PSS parameters using SHA-256 and a 478-byte salt, i.e. the maximum
+/// salt length for a 4096-bit key, as used by
+/// `openssl ... -sigopt rsa_pss_saltlen:max`.
+const RSA_PSS_PARAMETERS_SHA256_MAX_SALT: &[u8] = &hex!("3035a00f300d06096086480165030402010500a11c301a06092a864886f70d010108300d06096086480165030402010500a204020201de");
+
+/// RSASSA-PSS parameters using the default values.
+const RSA_PSS_PARAMETERS_DEFAULTS: &[u8] = &hex!("3000");
+
+/// RSAES-OAEP parameters using SHA-256 and the label `0102`.
+///
+/// Extracted from a CMS message generated using:
+/// $ openssl cms -encrypt -keyopt rsa_padding_mode:oaep -keyopt rsa_oaep_md:sha256 -keyopt rsa_mgf1_md:sha256 -keyopt rsa_oaep_label:0102
+const RSA_OAEP_PARAMETERS_SHA256_LABEL: &[u8] = &hex!("303ea00d300b0609608648016503040201a11a301806092a864886f70d010108300b0609608648016503040201a211300f06092a864886f70d01010904020102");
+
+/// RSAES-OAEP parameters using the default values.
+const RSA_OAEP_PARAMETERS_DEFAULTS: &[u8] = &hex!("3000");
+
+#[test]
+fn decode_pss_param() {
+    let param = RsaPssParams::try_from(RSA_PSS_PARAMETERS_SHA256).unwrap();
+
+    assert_eq!(param.hash.oid, SHA_256_OID);
+    assert_eq!(param.hash.parameters, Some(Any::NULL));
+    assert_eq!(param.mask_gen_hash.oid, SHA_256_OID);
+    assert_eq!(param.mask_gen_hash.parameters, Some(Any::NULL));
+    assert_eq!(param.salt_len, 32);
+    assert_eq!(param.trailer_field, TrailerField::BC);
+}
+
+#[test]
+fn encode_pss_param() {
+    let mut buf = [0u8; 256];
+    let param = RsaPssParams::try_from(RSA_PSS_PARAMETERS_SHA256).unwrap();
+    assert_eq!(
+        param.encode_to_slice(&mut buf).unwrap(),
+        RSA_PSS_PARAMETERS_SHA256
+    );
+}
+
+#[test]
+fn decode_pss_param_long_salt() {
+    let param = RsaPssParams::try_from(RSA_PSS_PARAMETERS_SHA256_MAX_SALT).unwrap();
+
+    assert_eq!(param.hash.oid, SHA_256_OID);
+    assert_eq!(param.mask_gen_hash.oid, SHA_256_OID);
+    assert_eq!(param.salt_len, 478);
+    assert_eq!(param.trailer_field, TrailerField::BC);
+
+    let mut buf = [0u8; 256];
+    assert_eq!(
+        param.encode_to_slice(&mut buf).unwrap(),
+        RSA_PSS_PARAMETERS_SHA256_MAX_SALT
+    );
+}
+
+#[test]
+fn decode_pss_param_default() {
+    let param = RsaPssParams::try_from(RSA_PSS_PARAMETERS_DEFAULTS).unwrap();
+
+    assert_eq!(param.hash, SHA_1_AI);
+    assert_eq!(param.mask_gen_hash, SHA_1_AI);
+    assert_eq!(param.salt_len, 20);
+    assert_eq!(param.trailer_field, TrailerField::BC);
+    assert_eq!(param, RsaPssParams::default());
+}
+
+#[test]
+fn encode_pss_param_default() {
+    let mut buf = [0u8; 256];
+    assert_eq!(
+        RsaPssParams::default().encode_to_slice(&mut buf).unwrap(),
+        RSA_PSS_PARAMETERS_DEFAULTS
+    );
+}
+
+#[test]
+fn decode_pss_param_bad_trailer_field() {
+    // trailerField [3] INTEGER 2
+    assert!(RsaPssParams::try_from(&hex!("3005a303020102")[..]).is_err());
+}
+
+#[test]
+fn decode_pss_param_bad_mask_gen() {
+    // maskGenAlgorithm [1] using id-pSpecified rather than id-mgf1
+    let bytes = hex!("3012a110300e06092a864886f70d0101093001");
+    assert!(RsaPssParams::try_from(&bytes[..]).is_err());
+}
+
+#[test]
+fn decode_oaep_param() {
+    let param = RsaOaepParams::try_from(RSA_OAEP_PARAMETERS_SHA256_LABEL).unwrap();
+
+    assert_eq!(param.hash.oid, SHA_256_OID);
+    assert_eq!(param.hash.parameters, None);
+    assert_eq!(param.mask_gen_hash.oid, SHA_256_OID);
+    assert_eq!(param.mask_gen_hash.parameters, None);
+    assert_eq!(param.label, hex!("0102"));
+}
+
+#[test]
+fn encode_oaep_param() {
+    let mut buf = [0u8; 256];
+    let param = RsaOaepParams::try_from(RSA_OAEP_PARAMETERS_SHA256_LABEL).unwrap();
+    assert_eq!(
+        param.encode_to_slice(&mut buf).unwrap(),
+        RSA_OAEP_PARAMETERS_SHA256_LABEL
+    );
+}
+
+#[test]
+fn decode_oaep_param_default() {
+    let param = RsaOaepParams::try_from(RSA_OAEP_PARAMETERS_DEFAULTS).unwrap();
+
+    assert_eq!(param.hash, SHA_1_AI);
+    assert_eq!(param.mask_gen_hash, SHA_1_AI);
+    assert!(param.label.is_empty());
+    assert_eq!(param, RsaOaepParams::default());
+}
+
+#[test]
+fn encode_oaep_param_default() {
+    let mut buf = [0u8; 256];
+    assert_eq!(
+        RsaOaepParams::default().encode_to_slice(&mut buf).unwrap(),
+        RSA_OAEP_PARAMETERS_DEFAULTS
+    );
+}
+
+#[test]
+fn encode_oaep_param_sha256() {
+    let sha256 = AlgorithmIdentifier {
+        oid: SHA_256_OID,
+        parameters: None,
+    };
+
+    let param = RsaOaepParams {
+        hash: sha256,
+        mask_gen_hash: sha256,
+        label: &hex!("0102"),
+    };
+
+    let mut buf = [0u8; 256];
+    assert_eq!(
+        param.encode_to_slice(&mut buf).unwrap(),
+        RSA_OAEP_PARAMETERS_SHA256_LABEL
+    );
+}
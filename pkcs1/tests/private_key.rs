@@ -6,6 +6,9 @@ use pkcs1::{RsaPrivateKey, Version};
 #[cfg(feature = "pem")]
 use pkcs1::{der::Document, RsaPrivateKeyDocument};
 
+#[cfg(feature = "legacy-encryption")]
+use pkcs1::{DecodeRsaPrivateKey, Error};
+
 /// RSA-2048 PKCS#1 private key encoded as ASN.1 DER.
 ///
 /// Note: this key is extracted from the corresponding `rsa2048-priv.der`
@@ -26,6 +29,23 @@ const RSA_2048_PEM_EXAMPLE: &str = include_str!("examples/rsa2048-priv.pem");
 #[cfg(feature = "pem")]
 const RSA_4096_PEM_EXAMPLE: &str = include_str!("examples/rsa4096-priv.pem");
 
+/// RSA-2048 PKCS#1 private key encrypted with OpenSSL's legacy AES-128-CBC
+/// PEM encryption.
+///
+/// Generated using:
+/// $ openssl rsa -in rsa2048-priv.pem -traditional -aes128 -passout pass:hunter42
+#[cfg(feature = "legacy-encryption")]
+const RSA_2048_AES128_PEM_EXAMPLE: &str = include_str!("examples/rsa2048-priv-aes128.pem");
+
+/// RSA-2048 PKCS#1 private key encrypted with OpenSSL's legacy AES-256-CBC
+/// PEM encryption.
+#[cfg(feature = "legacy-encryption")]
+const RSA_2048_AES256_PEM_EXAMPLE: &str = include_str!("examples/rsa2048-priv-aes256.pem");
+
+/// Password for the legacy encrypted examples.
+#[cfg(feature = "legacy-encryption")]
+const PASSWORD: &[u8] = b"hunter42"; // Bad password; don't actually use outside tests!
+
 #[test]
 fn decode_rsa2048_der() {
     let key = RsaPrivateKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
@@ -115,6 +135,37 @@ fn decode_rsa_4096_pem() {
     assert_eq!(pkcs1_doc.decode().modulus.as_bytes(), pk.modulus.as_bytes());
 }
 
+#[cfg(feature = "legacy-encryption")]
+#[test]
+fn decrypt_rsa_2048_legacy_pem() {
+    for pem in [RSA_2048_AES128_PEM_EXAMPLE, RSA_2048_AES256_PEM_EXAMPLE] {
+        let pkcs1_doc = RsaPrivateKeyDocument::from_pkcs1_encrypted_pem(pem, PASSWORD).unwrap();
+        assert_eq!(pkcs1_doc.as_ref(), RSA_2048_DER_EXAMPLE);
+    }
+}
+
+#[cfg(feature = "legacy-encryption")]
+#[test]
+fn decrypt_rsa_2048_legacy_pem_wrong_password() {
+    // An incorrect password usually results in invalid padding, but may
+    // instead produce garbage which fails to parse
+    assert!(RsaPrivateKeyDocument::from_pkcs1_encrypted_pem(
+        RSA_2048_AES128_PEM_EXAMPLE,
+        b"hunter2"
+    )
+    .is_err());
+}
+
+#[cfg(feature = "legacy-encryption")]
+#[test]
+fn decrypt_unencrypted_pem() {
+    assert_eq!(
+        RsaPrivateKeyDocument::from_pkcs1_encrypted_pem(RSA_2048_PEM_EXAMPLE, PASSWORD)
+            .unwrap_err(),
+        Error::Decrypt
+    );
+}
+
 #[test]
 fn private_key_to_public_key() {
     let private_key = RsaPrivateKey::try_from(RSA_2048_DER_EXAMPLE).unwrap();
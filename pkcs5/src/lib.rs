@@ -137,7 +137,16 @@ impl<'a> EncryptionScheme<'a> {
 
 impl<'a> Decode<'a> for EncryptionScheme<'a> {
     fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
-        AlgorithmIdentifier::decode(decoder)?.try_into()
+        decoder.sequence(|alg| {
+            let oid = alg.decode()?;
+
+            if oid == pbes2::PBES2_OID {
+                alg.decode::<pbes2::Parameters<'a>>().map(Into::into)
+            } else {
+                let parameters = alg.decode()?;
+                pbes1::Parameters::try_from(AlgorithmIdentifier { oid, parameters }).map(Into::into)
+            }
+        })
     }
 }
 
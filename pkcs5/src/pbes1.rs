@@ -114,8 +114,7 @@ impl<'a> TryFrom<AlgorithmIdentifier<'a>> for Parameters {
 
     fn try_from(alg: AlgorithmIdentifier<'a>) -> der::Result<Self> {
         // Ensure that we have a supported PBES1 algorithm identifier
-        let encryption = EncryptionScheme::try_from(alg.oid)
-            .map_err(|_| der::Tag::ObjectIdentifier.value_error())?;
+        let encryption = EncryptionScheme::try_from(alg.oid)?;
 
         if let Some(any) = alg.parameters {
             any.sequence(|params| {
@@ -36,6 +36,21 @@ pub const AES_192_CBC_OID: ObjectIdentifier =
 pub const AES_256_CBC_OID: ObjectIdentifier =
     ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.1.42");
 
+/// 128-bit Advanced Encryption Standard (AES) algorithm with Galois/Counter
+/// Mode (GCM) of operation.
+pub const AES_128_GCM_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.1.6");
+
+/// 192-bit Advanced Encryption Standard (AES) algorithm with Galois/Counter
+/// Mode (GCM) of operation.
+pub const AES_192_GCM_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.1.26");
+
+/// 256-bit Advanced Encryption Standard (AES) algorithm with Galois/Counter
+/// Mode (GCM) of operation.
+pub const AES_256_GCM_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.1.46");
+
 /// DES operating in CBC mode
 #[cfg(feature = "des-insecure")]
 #[cfg_attr(docsrs, doc(cfg(feature = "des-insecure")))]
@@ -54,6 +69,15 @@ pub const PBES2_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.11
 /// AES cipher block size
 const AES_BLOCK_SIZE: usize = 16;
 
+/// AES-GCM nonce size
+const GCM_NONCE_SIZE: usize = 12;
+
+/// Default AES-GCM integrity check value (i.e. authentication tag) length
+const GCM_DEFAULT_ICV_LEN: u8 = 12;
+
+/// AES-GCM integrity check value length used when encrypting
+const GCM_ICV_LEN: u8 = 16;
+
 /// DES / Triple DES block size
 #[cfg(any(feature = "3des", feature = "des-insecure"))]
 const DES_BLOCK_SIZE: usize = 8;
@@ -138,6 +162,45 @@ impl<'a> Parameters<'a> {
         Ok(Self { kdf, encryption })
     }
 
+    /// Initialize PBES2 parameters using PBKDF2-SHA256 as the password-based
+    /// key derivation function and AES-256-GCM as the symmetric cipher.
+    ///
+    /// The ciphertext is followed by a 16-byte authentication tag.
+    pub fn pbkdf2_sha256_aes256gcm(
+        pbkdf2_iterations: u32,
+        pbkdf2_salt: &'a [u8],
+        aes_nonce: &'a [u8; GCM_NONCE_SIZE],
+    ) -> Result<Self> {
+        let kdf = Pbkdf2Params::hmac_with_sha256(pbkdf2_iterations, pbkdf2_salt)?.into();
+        let encryption = EncryptionScheme::Aes256Gcm {
+            nonce: aes_nonce,
+            icv_len: GCM_ICV_LEN,
+        };
+        Ok(Self { kdf, encryption })
+    }
+
+    /// Initialize PBES2 parameters using scrypt as the password-based
+    /// key derivation function and AES-256-GCM as the symmetric cipher.
+    ///
+    /// The ciphertext is followed by a 16-byte authentication tag.
+    ///
+    /// For more information on scrypt parameters, see documentation for the
+    /// [`scrypt::Params`] struct.
+    #[cfg(feature = "scrypt")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "scrypt")))]
+    pub fn scrypt_aes256gcm(
+        params: scrypt::Params,
+        salt: &'a [u8],
+        aes_nonce: &'a [u8; GCM_NONCE_SIZE],
+    ) -> Result<Self> {
+        let kdf = ScryptParams::from_params_and_salt(params, salt)?.into();
+        let encryption = EncryptionScheme::Aes256Gcm {
+            nonce: aes_nonce,
+            icv_len: GCM_ICV_LEN,
+        };
+        Ok(Self { kdf, encryption })
+    }
+
     /// Attempt to decrypt the given ciphertext, allocating and returning a
     /// byte vector containing the plaintext.
     #[cfg(all(feature = "alloc", feature = "pbes2"))]
@@ -155,7 +218,7 @@ impl<'a> Parameters<'a> {
     ///
     /// Returns an error if the algorithm specified in this scheme's parameters
     /// is unsupported, or if the ciphertext is malformed (e.g. not a multiple
-    /// of a block mode's padding)
+    /// of a block mode's padding, or failing authentication for AES-GCM)
     #[cfg(feature = "pbes2")]
     #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
     pub fn decrypt_in_place<'b>(
@@ -188,6 +251,9 @@ impl<'a> Parameters<'a> {
     /// Encrypt the given plaintext in-place using a key derived from the
     /// provided password and this scheme's parameters, writing the ciphertext
     /// into the same buffer.
+    ///
+    /// The plaintext is `buffer[..pos]`, and the remainder of `buffer` must
+    /// have room for the block mode's padding or AES-GCM's authentication tag.
     #[cfg(feature = "pbes2")]
     #[cfg_attr(docsrs, doc(cfg(feature = "pbes2")))]
     pub fn encrypt_in_place<'b>(
@@ -202,7 +268,14 @@ impl<'a> Parameters<'a> {
 
 impl<'a> Decode<'a> for Parameters<'a> {
     fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
-        decoder.any()?.try_into()
+        // Decode the parameters using the given decoder rather than via
+        // `Any`, so they're decoded with its configuration
+        decoder.sequence(|params| {
+            Ok(Self {
+                kdf: params.decode()?,
+                encryption: params.decode()?,
+            })
+        })
     }
 }
 
@@ -253,6 +326,33 @@ pub enum EncryptionScheme<'a> {
         iv: &'a [u8; AES_BLOCK_SIZE],
     },
 
+    /// AES-128 in GCM mode
+    Aes128Gcm {
+        /// Nonce
+        nonce: &'a [u8; GCM_NONCE_SIZE],
+
+        /// Length of the integrity check value (i.e. authentication tag)
+        icv_len: u8,
+    },
+
+    /// AES-192 in GCM mode
+    Aes192Gcm {
+        /// Nonce
+        nonce: &'a [u8; GCM_NONCE_SIZE],
+
+        /// Length of the integrity check value (i.e. authentication tag)
+        icv_len: u8,
+    },
+
+    /// AES-256 in GCM mode
+    Aes256Gcm {
+        /// Nonce
+        nonce: &'a [u8; GCM_NONCE_SIZE],
+
+        /// Length of the integrity check value (i.e. authentication tag)
+        icv_len: u8,
+    },
+
     /// 3-Key Triple DES in CBC mode
     #[cfg(feature = "3des")]
     DesEde3Cbc {
@@ -275,6 +375,9 @@ impl<'a> EncryptionScheme<'a> {
             Self::Aes128Cbc { .. } => 16,
             Self::Aes192Cbc { .. } => 24,
             Self::Aes256Cbc { .. } => 32,
+            Self::Aes128Gcm { .. } => 16,
+            Self::Aes192Gcm { .. } => 24,
+            Self::Aes256Gcm { .. } => 32,
             #[cfg(feature = "des-insecure")]
             Self::DesCbc { .. } => 8,
             #[cfg(feature = "3des")]
@@ -288,6 +391,9 @@ impl<'a> EncryptionScheme<'a> {
             Self::Aes128Cbc { .. } => AES_128_CBC_OID,
             Self::Aes192Cbc { .. } => AES_192_CBC_OID,
             Self::Aes256Cbc { .. } => AES_256_CBC_OID,
+            Self::Aes128Gcm { .. } => AES_128_GCM_OID,
+            Self::Aes192Gcm { .. } => AES_192_GCM_OID,
+            Self::Aes256Gcm { .. } => AES_256_GCM_OID,
             #[cfg(feature = "des-insecure")]
             Self::DesCbc { .. } => DES_CBC_OID,
             #[cfg(feature = "3des")]
@@ -305,7 +411,20 @@ impl<'a> EncryptionScheme<'a> {
 
 impl<'a> Decode<'a> for EncryptionScheme<'a> {
     fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
-        AlgorithmIdentifier::decode(decoder).and_then(TryInto::try_into)
+        decoder.sequence(|alg| {
+            let oid = alg.decode()?;
+
+            match oid {
+                AES_128_GCM_OID | AES_192_GCM_OID | AES_256_GCM_OID => {
+                    Ok(alg.decode::<GcmParameters<'a>>()?.into_scheme(oid))
+                }
+                _ => AlgorithmIdentifier {
+                    oid,
+                    parameters: alg.decode()?,
+                }
+                .try_into(),
+            }
+        })
     }
 }
 
@@ -313,6 +432,16 @@ impl<'a> TryFrom<AlgorithmIdentifier<'a>> for EncryptionScheme<'a> {
     type Error = der::Error;
 
     fn try_from(alg: AlgorithmIdentifier<'a>) -> der::Result<Self> {
+        match alg.oid {
+            AES_128_GCM_OID | AES_192_GCM_OID | AES_256_GCM_OID => {
+                return match alg.parameters {
+                    Some(params) => Ok(GcmParameters::try_from(params)?.into_scheme(alg.oid)),
+                    None => Err(Tag::Sequence.value_error()),
+                };
+            }
+            _ => (),
+        }
+
         // TODO(tarcieri): support for non-AES algorithms?
         let iv = match alg.parameters {
             Some(params) => params.octet_string()?.as_bytes(),
@@ -352,6 +481,11 @@ impl<'a> TryFrom<AlgorithmIdentifier<'a>> for EncryptionScheme<'a> {
     }
 }
 
+/// Converts CBC mode schemes into an [`AlgorithmIdentifier`].
+///
+/// AES-GCM parameters are a `SEQUENCE` which cannot be borrowed from the
+/// scheme, so this conversion fails for AES-GCM. Use the [`Encode`] impl
+/// on [`EncryptionScheme`] instead.
 impl<'a> TryFrom<EncryptionScheme<'a>> for AlgorithmIdentifier<'a> {
     type Error = der::Error;
 
@@ -360,6 +494,9 @@ impl<'a> TryFrom<EncryptionScheme<'a>> for AlgorithmIdentifier<'a> {
             EncryptionScheme::Aes128Cbc { iv } => iv,
             EncryptionScheme::Aes192Cbc { iv } => iv,
             EncryptionScheme::Aes256Cbc { iv } => iv,
+            EncryptionScheme::Aes128Gcm { .. }
+            | EncryptionScheme::Aes192Gcm { .. }
+            | EncryptionScheme::Aes256Gcm { .. } => return Err(Tag::Sequence.value_error()),
             #[cfg(feature = "des-insecure")]
             EncryptionScheme::DesCbc { iv } => iv,
             #[cfg(feature = "3des")]
@@ -375,10 +512,112 @@ impl<'a> TryFrom<EncryptionScheme<'a>> for AlgorithmIdentifier<'a> {
 
 impl<'a> Encode for EncryptionScheme<'a> {
     fn encoded_len(&self) -> der::Result<Length> {
-        AlgorithmIdentifier::try_from(*self)?.encoded_len()
+        match GcmParameters::from_scheme(self) {
+            Some(params) => (self.oid().encoded_len()? + params.encoded_len()?)?.for_tlv(),
+            None => AlgorithmIdentifier::try_from(*self)?.encoded_len(),
+        }
     }
 
     fn encode(&self, encoder: &mut Encoder<'_>) -> der::Result<()> {
-        AlgorithmIdentifier::try_from(*self)?.encode(encoder)
+        match GcmParameters::from_scheme(self) {
+            Some(params) => {
+                let seq_len = (self.oid().encoded_len()? + params.encoded_len()?)?;
+                encoder.sequence(seq_len, |seq| {
+                    seq.encode(&self.oid())?;
+                    seq.encode(&params)
+                })
+            }
+            None => AlgorithmIdentifier::try_from(*self)?.encode(encoder),
+        }
+    }
+}
+
+/// AES-GCM parameters as defined in [RFC 5084 Section 3.2].
+///
+/// ```text
+/// GCMParameters ::= SEQUENCE {
+///   aes-nonce        OCTET STRING, -- recommended size is 12 octets
+///   aes-ICVlen       AES-GCM-ICVlen DEFAULT 12 }
+///
+/// AES-GCM-ICVlen ::= INTEGER (12 | 13 | 14 | 15 | 16)
+/// ```
+///
+/// Only 12-byte nonces are supported.
+///
+/// [RFC 5084 Section 3.2]: https://datatracker.ietf.org/doc/html/rfc5084#section-3.2
+struct GcmParameters<'a> {
+    /// Nonce
+    nonce: &'a [u8; GCM_NONCE_SIZE],
+
+    /// Integrity check value length
+    icv_len: u8,
+}
+
+impl<'a> GcmParameters<'a> {
+    /// Get the [`GcmParameters`] for the given scheme, if it uses AES-GCM.
+    fn from_scheme(scheme: &EncryptionScheme<'a>) -> Option<Self> {
+        match *scheme {
+            EncryptionScheme::Aes128Gcm { nonce, icv_len }
+            | EncryptionScheme::Aes192Gcm { nonce, icv_len }
+            | EncryptionScheme::Aes256Gcm { nonce, icv_len } => Some(Self { nonce, icv_len }),
+            _ => None,
+        }
+    }
+
+    /// Get the AES-GCM [`EncryptionScheme`] with the given OID which uses
+    /// these parameters.
+    fn into_scheme(self, oid: ObjectIdentifier) -> EncryptionScheme<'a> {
+        let Self { nonce, icv_len } = self;
+
+        match oid {
+            AES_128_GCM_OID => EncryptionScheme::Aes128Gcm { nonce, icv_len },
+            AES_192_GCM_OID => EncryptionScheme::Aes192Gcm { nonce, icv_len },
+            _ => EncryptionScheme::Aes256Gcm { nonce, icv_len },
+        }
+    }
+
+    /// Decode the fields of [`GcmParameters`].
+    ///
+    /// DER requires the default ICV length to be omitted, but it's only
+    /// rejected if explicitly encoded when the decoder is configured to with
+    /// [`Decoder::set_reject_encoded_defaults`].
+    fn decode_fields(params: &mut Decoder<'a>) -> der::Result<Self> {
+        let nonce = params
+            .octet_string()?
+            .as_bytes()
+            .try_into()
+            .map_err(|_| Tag::OctetString.value_error())?;
+
+        let icv_len = match params.decode_default(|| GCM_DEFAULT_ICV_LEN)? {
+            len @ 12..=16 => len,
+            _ => return Err(Tag::Integer.value_error()),
+        };
+
+        Ok(Self { nonce, icv_len })
+    }
+}
+
+impl<'a> Decode<'a> for GcmParameters<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.sequence(Self::decode_fields)
+    }
+}
+
+impl<'a> Sequence<'a> for GcmParameters<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        // The default ICV length must be omitted when encoding as DER
+        let icv_len = Some(self.icv_len).filter(|&len| len != GCM_DEFAULT_ICV_LEN);
+        f(&[&OctetString::new(self.nonce.as_slice())?, &icv_len])
+    }
+}
+
+impl<'a> TryFrom<Any<'a>> for GcmParameters<'a> {
+    type Error = der::Error;
+
+    fn try_from(any: Any<'a>) -> der::Result<Self> {
+        any.sequence(Self::decode_fields)
     }
 }
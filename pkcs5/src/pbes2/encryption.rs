@@ -1,9 +1,19 @@
 //! PBES2 encryption.
 
-use super::{EncryptionScheme, Kdf, Parameters, Pbkdf2Params, Pbkdf2Prf, ScryptParams};
+use super::{
+    EncryptionScheme, Kdf, Parameters, Pbkdf2Params, Pbkdf2Prf, ScryptParams, GCM_NONCE_SIZE,
+};
 use crate::{Error, Result};
+use aes_gcm::{
+    aead::{
+        consts::{U12, U13, U14, U15, U16},
+        AeadInPlace,
+    },
+    AesGcm, TagSize,
+};
 use cbc::cipher::{
-    block_padding::Pkcs7, BlockCipher, BlockDecryptMut, BlockEncryptMut, KeyInit, KeyIvInit,
+    block_padding::Pkcs7, BlockCipher, BlockDecryptMut, BlockEncrypt, BlockEncryptMut, KeyInit,
+    KeyIvInit,
 };
 use hmac::{
     digest::{
@@ -45,6 +55,69 @@ fn cbc_decrypt<'a, C: BlockDecryptMut + BlockCipher + KeyInit>(
         .map_err(|_| Error::EncryptFailed)
 }
 
+fn gcm_encrypt<'a, C, T>(
+    es: EncryptionScheme<'_>,
+    key: EncryptionKey,
+    nonce: &[u8; GCM_NONCE_SIZE],
+    buffer: &'a mut [u8],
+    pos: usize,
+) -> Result<&'a [u8]>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + KeyInit,
+    T: TagSize,
+{
+    let len = pos.checked_add(T::USIZE).ok_or(Error::EncryptFailed)?;
+    let buffer = buffer.get_mut(..len).ok_or(Error::EncryptFailed)?;
+    let (msg, tag) = buffer.split_at_mut(pos);
+
+    tag.copy_from_slice(
+        &AesGcm::<C, U12, T>::new_from_slice(key.as_slice())
+            .map_err(|_| es.to_alg_params_invalid())?
+            .encrypt_in_place_detached(nonce.into(), &[], msg)
+            .map_err(|_| Error::EncryptFailed)?,
+    );
+
+    Ok(buffer)
+}
+
+fn gcm_decrypt<'a, C, T>(
+    es: EncryptionScheme<'_>,
+    key: EncryptionKey,
+    nonce: &[u8; GCM_NONCE_SIZE],
+    buffer: &'a mut [u8],
+) -> Result<&'a [u8]>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + KeyInit,
+    T: TagSize,
+{
+    let pos = buffer
+        .len()
+        .checked_sub(T::USIZE)
+        .ok_or(Error::DecryptFailed)?;
+    let (msg, tag) = buffer.split_at_mut(pos);
+
+    AesGcm::<C, U12, T>::new_from_slice(key.as_slice())
+        .map_err(|_| es.to_alg_params_invalid())?
+        .decrypt_in_place_detached(nonce.into(), &[], msg, (&*tag).into())
+        .map_err(|_| Error::DecryptFailed)?;
+
+    Ok(msg)
+}
+
+/// Dispatch an AES-GCM operation on the integrity check value length.
+macro_rules! gcm {
+    ($f:ident::<$cipher:ty>($es:expr, $icv_len:expr, $($arg:expr),+)) => {
+        match $icv_len {
+            12 => $f::<$cipher, U12>($es, $($arg),+),
+            13 => $f::<$cipher, U13>($es, $($arg),+),
+            14 => $f::<$cipher, U14>($es, $($arg),+),
+            15 => $f::<$cipher, U15>($es, $($arg),+),
+            16 => $f::<$cipher, U16>($es, $($arg),+),
+            _ => Err($es.to_alg_params_invalid()),
+        }
+    };
+}
+
 pub fn encrypt_in_place<'b>(
     params: &Parameters<'_>,
     password: impl AsRef<[u8]>,
@@ -62,6 +135,21 @@ pub fn encrypt_in_place<'b>(
         EncryptionScheme::Aes128Cbc { iv } => cbc_encrypt::<aes::Aes128Enc>(es, key, iv, buf, pos),
         EncryptionScheme::Aes192Cbc { iv } => cbc_encrypt::<aes::Aes192Enc>(es, key, iv, buf, pos),
         EncryptionScheme::Aes256Cbc { iv } => cbc_encrypt::<aes::Aes256Enc>(es, key, iv, buf, pos),
+        EncryptionScheme::Aes128Gcm { nonce, icv_len } => {
+            gcm!(gcm_encrypt::<aes::Aes128>(
+                es, icv_len, key, nonce, buf, pos
+            ))
+        }
+        EncryptionScheme::Aes192Gcm { nonce, icv_len } => {
+            gcm!(gcm_encrypt::<aes::Aes192>(
+                es, icv_len, key, nonce, buf, pos
+            ))
+        }
+        EncryptionScheme::Aes256Gcm { nonce, icv_len } => {
+            gcm!(gcm_encrypt::<aes::Aes256>(
+                es, icv_len, key, nonce, buf, pos
+            ))
+        }
         #[cfg(feature = "3des")]
         EncryptionScheme::DesEde3Cbc { iv } => cbc_encrypt::<des::TdesEde3>(es, key, iv, buf, pos),
         #[cfg(feature = "des-insecure")]
@@ -84,6 +172,15 @@ pub fn decrypt_in_place<'a>(
         EncryptionScheme::Aes128Cbc { iv } => cbc_decrypt::<aes::Aes128Dec>(es, key, iv, buf),
         EncryptionScheme::Aes192Cbc { iv } => cbc_decrypt::<aes::Aes192Dec>(es, key, iv, buf),
         EncryptionScheme::Aes256Cbc { iv } => cbc_decrypt::<aes::Aes256Dec>(es, key, iv, buf),
+        EncryptionScheme::Aes128Gcm { nonce, icv_len } => {
+            gcm!(gcm_decrypt::<aes::Aes128>(es, icv_len, key, nonce, buf))
+        }
+        EncryptionScheme::Aes192Gcm { nonce, icv_len } => {
+            gcm!(gcm_decrypt::<aes::Aes192>(es, icv_len, key, nonce, buf))
+        }
+        EncryptionScheme::Aes256Gcm { nonce, icv_len } => {
+            gcm!(gcm_decrypt::<aes::Aes256>(es, icv_len, key, nonce, buf))
+        }
         #[cfg(feature = "3des")]
         EncryptionScheme::DesEde3Cbc { iv } => cbc_decrypt::<des::TdesEde3>(es, key, iv, buf),
         #[cfg(feature = "des-insecure")]
@@ -103,7 +200,7 @@ impl EncryptionKey {
     pub fn derive_from_password(password: &[u8], kdf: &Kdf<'_>, key_size: usize) -> Result<Self> {
         // if the kdf params defined a key length, ensure it matches the required key size
         if let Some(len) = kdf.key_length() {
-            if key_size != len.into() {
+            if key_size != usize::from(len) {
                 return Err(kdf.to_alg_params_invalid());
             }
         }
@@ -24,6 +24,22 @@ const PBES2_SCRYPT_AES256CBC_ALG_ID: &[u8] = &hex!(
     09bd0a6251f2254f9fd5963887c27cf01"
 );
 
+/// PBES2 + PBKDF2-SHA256 + AES-256-GCM `AlgorithmIdentifier` example with a
+/// 16-byte ICV.
+const PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID: &[u8] = &hex!(
+    "305806092a864886f70d01050d304b302906092a864886f70d01050c301c0408
+     79d982e70df91a8802020800300c06082a864886f70d02090500301e06096086
+     4801650304012e3011040cb2d02d78b2efd9dff694cf8e020110"
+);
+
+/// PBES2 + PBKDF2-SHA256 + AES-256-GCM `AlgorithmIdentifier` example with
+/// the default 12-byte ICV.
+const PBES2_PBKDF2_SHA256_AES256GCM_DEFAULT_ICV_ALG_ID: &[u8] = &hex!(
+    "305506092a864886f70d01050d3048302906092a864886f70d01050c301c0408
+     79d982e70df91a8802020800300c06082a864886f70d02090500301b06096086
+     4801650304012e300e040cb2d02d78b2efd9dff694cf8e"
+);
+
 /// Plaintext of Ed25519 PKCS#8 private key.
 ///
 /// This is the hex-encoded contents of `ed25519-priv.der` from
@@ -53,6 +69,15 @@ const ED25519_PKCS8_KEY_CIPHERTEXT_SCRYPT: &[u8] = &hex!(
      065EEB366D6E6C98CC3B0E7E69BDC861C88AFEB8F03DBA1E2C6D99D06D17360C"
 );
 
+/// Ciphertext and 16-byte tag of Ed25519 PKCS#8 private key when encrypted
+/// using PBKDF2-SHA256 and AES-256-GCM.
+///
+/// Generated with the Python `cryptography` package.
+const ED25519_PKCS8_KEY_CIPHERTEXT_AES256GCM: &[u8] = &hex!(
+    "dc95a61bc03e10e1865d750036dcf2d287eb0390e5bd234979fcbb01271830c0
+     585bc98d993c20957687ae7e8a267f94aff98338af30f4908b0f714a94e09a11"
+);
+
 /// PBES2 + DES-EDE3-CBC + PBKDF-SHA2 `AlgorithmIdentifier` example.
 ///
 /// Generated by OpenSSL and extracted from the `pkcs8` crate's
@@ -110,6 +135,76 @@ fn decrypt_pbes2_scrypt_aes256cbc() {
     assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
 }
 
+#[test]
+fn decrypt_pbes2_pbkdf2_sha256_aes256gcm() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID).unwrap();
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_AES256GCM);
+    let plaintext = scheme.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
+    assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+
+    // A 12-byte tag is a truncation of the 16-byte tag
+    let scheme =
+        pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES256GCM_DEFAULT_ICV_ALG_ID)
+            .unwrap();
+    let ciphertext = &ED25519_PKCS8_KEY_CIPHERTEXT_AES256GCM;
+    let mut buffer = Vec::from(&ciphertext[..(ciphertext.len() - 4)]);
+    let plaintext = scheme.decrypt_in_place(PASSWORD, &mut buffer).unwrap();
+    assert_eq!(plaintext, ED25519_PKCS8_KEY_PLAINTEXT);
+}
+
+#[test]
+fn decrypt_pbes2_pbkdf2_sha256_aes256gcm_tampered() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID).unwrap();
+    let mut buffer = Vec::from(ED25519_PKCS8_KEY_CIPHERTEXT_AES256GCM);
+    buffer[0] ^= 1;
+
+    assert_eq!(
+        scheme.decrypt_in_place(PASSWORD, &mut buffer),
+        Err(pkcs5::Error::DecryptFailed)
+    );
+    assert_eq!(
+        scheme.decrypt_in_place(PASSWORD, &mut [0u8; 15]),
+        Err(pkcs5::Error::DecryptFailed)
+    );
+}
+
+#[test]
+fn encrypt_pbes2_pbkdf2_sha256_aes256gcm() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID).unwrap();
+    let mut buffer = [0u8; 64];
+    buffer[..ED25519_PKCS8_KEY_PLAINTEXT.len()].copy_from_slice(ED25519_PKCS8_KEY_PLAINTEXT);
+
+    let ciphertext = scheme
+        .encrypt_in_place(PASSWORD, &mut buffer, ED25519_PKCS8_KEY_PLAINTEXT.len())
+        .unwrap();
+    assert_eq!(ciphertext, ED25519_PKCS8_KEY_CIPHERTEXT_AES256GCM);
+
+    // No room for the tag
+    let mut buffer = [0u8; 63];
+    assert_eq!(
+        scheme.encrypt_in_place(PASSWORD, &mut buffer, ED25519_PKCS8_KEY_PLAINTEXT.len()),
+        Err(pkcs5::Error::EncryptFailed)
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn encrypt_pbes2_pbkdf2_sha256_aes256gcm_params() {
+    let nonce = hex!("b2d02d78b2efd9dff694cf8e");
+    let params =
+        pkcs5::pbes2::Parameters::pbkdf2_sha256_aes256gcm(2048, &hex!("79d982e70df91a88"), &nonce)
+            .unwrap();
+
+    let ciphertext = params
+        .encrypt(PASSWORD, ED25519_PKCS8_KEY_PLAINTEXT)
+        .unwrap();
+    assert_eq!(ciphertext, ED25519_PKCS8_KEY_CIPHERTEXT_AES256GCM);
+    assert_eq!(
+        params.decrypt(PASSWORD, &ciphertext).unwrap(),
+        ED25519_PKCS8_KEY_PLAINTEXT
+    );
+}
+
 #[test]
 #[cfg(feature = "3des")]
 fn decrypt_pbes2_pbkdf2_sha256_desede3cbc() {
@@ -0,0 +1,98 @@
+//! Password-Based Encryption Scheme 1 tests
+
+use der::{Decode, Encode, ErrorKind};
+use hex_literal::hex;
+use pkcs5::pbes1;
+
+/// PBES1 + MD5 + DES-CBC `AlgorithmIdentifier` example.
+///
+/// Generated by OpenSSL using:
+///
+/// ```text
+/// $ openssl pkcs8 -topk8 -v1 PBE-MD5-DES -provider legacy -provider default -outform der
+/// ```
+const PBES1_MD5_DES_CBC_ALG_ID: &[u8] =
+    &hex!("301b06092a864886f70d010503300e0408716e1ff008c1317302020800");
+
+/// PBES1 + SHA-1 + DES-CBC `AlgorithmIdentifier` example.
+///
+/// Generated by OpenSSL using:
+///
+/// ```text
+/// $ openssl pkcs8 -topk8 -v1 PBE-SHA1-DES -provider legacy -provider default -outform der
+/// ```
+const PBES1_SHA1_DES_CBC_ALG_ID: &[u8] =
+    &hex!("301b06092a864886f70d01050a300e04087c7d5cc5b6b8b8f602020800");
+
+/// Decoding test for PBES1 + MD5 + DES-CBC `AlgorithmIdentifier`
+#[test]
+fn decode_pbes1_md5_des_cbc() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES1_MD5_DES_CBC_ALG_ID).unwrap();
+    assert_eq!(scheme.oid(), pbes1::PBE_WITH_MD5_AND_DES_CBC_OID);
+    assert!(scheme.pbes2().is_none());
+
+    let params = scheme.pbes1().unwrap();
+    assert_eq!(
+        params.encryption,
+        pbes1::EncryptionScheme::PbeWithMd5AndDesCbc
+    );
+    assert_eq!(params.encryption.cipher(), pbes1::SymmetricCipher::DesCbc);
+    assert_eq!(params.salt, hex!("716e1ff008c13173"));
+    assert_eq!(params.iteration_count, 2048);
+}
+
+/// Decoding test for PBES1 + SHA-1 + DES-CBC `AlgorithmIdentifier`
+#[test]
+fn decode_pbes1_sha1_des_cbc() {
+    let params = pbes1::Parameters::from_der(PBES1_SHA1_DES_CBC_ALG_ID).unwrap();
+    assert_eq!(params.oid(), pbes1::PBE_WITH_SHA1_AND_DES_CBC_OID);
+    assert_eq!(
+        params.encryption,
+        pbes1::EncryptionScheme::PbeWithSha1AndDesCbc
+    );
+    assert_eq!(params.salt, hex!("7c7d5cc5b6b8b8f6"));
+    assert_eq!(params.iteration_count, 2048);
+}
+
+/// Unknown algorithms are reported as such
+#[test]
+fn decode_unknown_oid() {
+    // `pbeWithSHA1AndDES-CBC` with the final arc changed to 99
+    let mut alg_id = PBES1_SHA1_DES_CBC_ALG_ID.to_vec();
+    alg_id[12] = 99;
+
+    let err = pkcs5::EncryptionScheme::try_from(alg_id.as_slice()).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::OidUnknown { .. }));
+}
+
+/// Encoding test for PBES1 `AlgorithmIdentifier`s
+#[test]
+fn encode_pbes1() {
+    for alg_id in [PBES1_MD5_DES_CBC_ALG_ID, PBES1_SHA1_DES_CBC_ALG_ID] {
+        let mut buffer = [0u8; 1024];
+
+        let scheme = pkcs5::EncryptionScheme::try_from(alg_id).unwrap();
+        let mut encoder = der::Encoder::new(&mut buffer);
+        scheme.encode(&mut encoder).unwrap();
+
+        let encoded_der = encoder.finish().unwrap();
+        assert_eq!(encoded_der, alg_id);
+    }
+}
+
+/// PBES1 is only supported for parsing
+#[test]
+#[cfg(feature = "pbes2")]
+fn pbes1_crypt_unsupported() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES1_MD5_DES_CBC_ALG_ID).unwrap();
+    let mut buffer = [0u8; 16];
+
+    assert_eq!(
+        scheme.decrypt_in_place("hunter42", &mut buffer),
+        Err(pkcs5::Error::NoPbes1CryptSupport)
+    );
+    assert_eq!(
+        scheme.encrypt_in_place("hunter42", &mut buffer, 0),
+        Err(pkcs5::Error::NoPbes1CryptSupport)
+    );
+}
@@ -1,6 +1,6 @@
 //! Password-Based Encryption Scheme 2 tests
 
-use der::Encode;
+use der::{Decode, Encode};
 use hex_literal::hex;
 use pkcs5::pbes2;
 
@@ -34,6 +34,25 @@ const PBES2_SCRYPT_AES256CBC_ALG_ID: &[u8] = &hex!(
     09bd0a6251f2254f9fd5963887c27cf01"
 );
 
+/// PBES2 + PBKDF2-SHA256 + AES-256-GCM `AlgorithmIdentifier` example with a
+/// 16-byte ICV.
+///
+/// Uses the same salt as the AES-256-CBC example and a 12-byte prefix of its
+/// IV as the nonce.
+const PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID: &[u8] = &hex!(
+    "305806092a864886f70d01050d304b302906092a864886f70d01050c301c0408
+     79d982e70df91a8802020800300c06082a864886f70d02090500301e06096086
+     4801650304012e3011040cb2d02d78b2efd9dff694cf8e020110"
+);
+
+/// PBES2 + PBKDF2-SHA256 + AES-256-GCM `AlgorithmIdentifier` example with
+/// the default 12-byte ICV.
+const PBES2_PBKDF2_SHA256_AES256GCM_DEFAULT_ICV_ALG_ID: &[u8] = &hex!(
+    "305506092a864886f70d01050d3048302906092a864886f70d01050c301c0408
+     79d982e70df91a8802020800300c06082a864886f70d02090500301b06096086
+     4801650304012e300e040cb2d02d78b2efd9dff694cf8e"
+);
+
 /// PBES2 + DES-EDE3-CBC + PBKDF-SHA2 `AlgorithmIdentifier` example.
 ///
 /// Generated by OpenSSL and extracted from the `pkcs8` crate's
@@ -96,6 +115,63 @@ fn decode_pbes2_pbkdf2_sha256_aes256cbc() {
     }
 }
 
+/// Decoding test for PBES2 + PBKDF2-SHA256 + AES-256-GCM `AlgorithmIdentifier`
+#[test]
+fn decode_pbes2_pbkdf2_sha256_aes256gcm() {
+    let scheme = pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID).unwrap();
+    let params = scheme.pbes2().unwrap();
+
+    let pbkdf2_params = params.kdf.pbkdf2().unwrap();
+    assert_eq!(pbkdf2_params.salt, &hex!("79d982e70df91a88"));
+    assert_eq!(pbkdf2_params.iteration_count, 2048);
+    assert_eq!(pbkdf2_params.prf, pbes2::Pbkdf2Prf::HmacWithSha256);
+
+    match params.encryption {
+        pbes2::EncryptionScheme::Aes256Gcm { nonce, icv_len } => {
+            assert_eq!(nonce, &hex!("b2d02d78b2efd9dff694cf8e"));
+            assert_eq!(icv_len, 16);
+        }
+        other => panic!("unexpected encryption scheme: {:?}", other),
+    }
+
+    let scheme =
+        pkcs5::EncryptionScheme::try_from(PBES2_PBKDF2_SHA256_AES256GCM_DEFAULT_ICV_ALG_ID)
+            .unwrap();
+
+    match scheme.pbes2().unwrap().encryption {
+        pbes2::EncryptionScheme::Aes256Gcm { icv_len, .. } => assert_eq!(icv_len, 12),
+        other => panic!("unexpected encryption scheme: {:?}", other),
+    }
+}
+
+/// AES-GCM parameters with an explicitly encoded default ICV length aren't
+/// valid DER, but are only rejected when decoding them strictly
+#[test]
+fn decode_pbes2_aes256gcm_explicit_default_icv() {
+    let params = hex!("3011040cb2d02d78b2efd9dff694cf8e02010c");
+    let alg_id = pkcs5::AlgorithmIdentifier {
+        oid: pbes2::AES_256_GCM_OID,
+        parameters: Some(der::asn1::Any::from_der(&params).unwrap()),
+    };
+
+    match pbes2::EncryptionScheme::try_from(alg_id).unwrap() {
+        pbes2::EncryptionScheme::Aes256Gcm { icv_len, .. } => assert_eq!(icv_len, 12),
+        other => panic!("unexpected encryption scheme: {:?}", other),
+    }
+
+    let mut buf = [0u8; 64];
+    let der = alg_id.encode_to_slice(&mut buf).unwrap();
+    let mut decoder = der::Decoder::new(der).unwrap();
+    decoder.set_reject_encoded_defaults(true);
+    let err = decoder.decode::<pbes2::EncryptionScheme<'_>>().unwrap_err();
+    assert_eq!(
+        err.kind(),
+        der::ErrorKind::Noncanonical {
+            tag: der::Tag::Integer
+        }
+    );
+}
+
 /// Decoding test for PBES2 + scrypt + AES-256-CBC `AlgorithmIdentifier`
 #[test]
 fn decode_pbes2_scrypt_aes256cbc() {
@@ -197,3 +273,25 @@ fn encode_pbes2_scrypt_aes256cbc() {
     let encoded_der = encoder.finish().unwrap();
     assert_eq!(encoded_der, PBES2_SCRYPT_AES256CBC_ALG_ID);
 }
+
+/// Encoding test for PBES2 + PBKDF2-SHA256 + AES-256-GCM `AlgorithmIdentifier`
+#[test]
+fn encode_pbes2_pbkdf2_sha256_aes256gcm() {
+    for alg_id in [
+        PBES2_PBKDF2_SHA256_AES256GCM_ALG_ID,
+        PBES2_PBKDF2_SHA256_AES256GCM_DEFAULT_ICV_ALG_ID,
+    ] {
+        let mut buffer = [0u8; 1024];
+
+        let scheme = pkcs5::EncryptionScheme::try_from(alg_id).unwrap();
+        let mut encoder = der::Encoder::new(&mut buffer);
+        scheme.encode(&mut encoder).unwrap();
+
+        let encoded_der = encoder.finish().unwrap();
+        assert_eq!(encoded_der, alg_id);
+        assert_eq!(
+            scheme.encoded_len().unwrap(),
+            alg_id.len().try_into().unwrap()
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use der::{DecodeValue, Decoder, EncodeValue, Encoder, FixedTag, Header, Length, Tag};
+
+/// Syntax version of the CMS content types [RFC 5652 § 10.2.5](https://datatracker.ietf.org/doc/html/rfc5652#section-10.2.5)
+///
+/// ```text
+/// CMSVersion ::= INTEGER  { v0(0), v1(1), v2(2), v3(3), v4(4), v5(5) }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum CmsVersion {
+    /// syntax version 0
+    V0 = 0,
+    /// syntax version 1
+    V1 = 1,
+    /// syntax version 2
+    V2 = 2,
+    /// syntax version 3
+    V3 = 3,
+    /// syntax version 4
+    V4 = 4,
+    /// syntax version 5
+    V5 = 5,
+}
+
+impl FixedTag for CmsVersion {
+    const TAG: Tag = Tag::Integer;
+}
+
+impl From<CmsVersion> for u8 {
+    fn from(version: CmsVersion) -> Self {
+        version as u8
+    }
+}
+
+impl TryFrom<u8> for CmsVersion {
+    type Error = der::Error;
+    fn try_from(byte: u8) -> der::Result<CmsVersion> {
+        match byte {
+            0 => Ok(CmsVersion::V0),
+            1 => Ok(CmsVersion::V1),
+            2 => Ok(CmsVersion::V2),
+            3 => Ok(CmsVersion::V3),
+            4 => Ok(CmsVersion::V4),
+            5 => Ok(CmsVersion::V5),
+            _ => Err(Self::TAG.value_error()),
+        }
+    }
+}
+
+impl<'a> DecodeValue<'a> for CmsVersion {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> der::Result<CmsVersion> {
+        CmsVersion::try_from(u8::decode_value(decoder, header)?)
+    }
+}
+
+impl EncodeValue for CmsVersion {
+    fn value_len(&self) -> der::Result<Length> {
+        u8::from(*self).value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> der::Result<()> {
+        u8::from(*self).encode_value(encoder)
+    }
+}
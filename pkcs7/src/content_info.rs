@@ -5,6 +5,12 @@ use der::{
     Decode, Decoder, Encode, Sequence, TagMode, TagNumber,
 };
 
+#[cfg(feature = "alloc")]
+use {
+    crate::{enveloped_data_content::EnvelopedDataContent, signed_data_content::SignedDataContent},
+    der::asn1::ContextSpecificRef,
+};
+
 const CONTENT_TAG: TagNumber = TagNumber::new(0);
 
 /// Content exchanged between entities [RFC 5652 § 3](https://datatracker.ietf.org/doc/html/rfc5652#section-3)
@@ -15,6 +21,7 @@ const CONTENT_TAG: TagNumber = TagNumber::new(0);
 ///   content
 ///     [0] EXPLICIT ANY DEFINED BY contentType OPTIONAL }
 /// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ContentInfo<'a> {
     /// Content type `data`
     Data(Option<DataContent<'a>>),
@@ -22,9 +29,18 @@ pub enum ContentInfo<'a> {
     /// Content type `encrypted-data`
     EncryptedData(Option<EncryptedDataContent<'a>>),
 
+    /// Content type `signed-data`
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    SignedData(Option<SignedDataContent<'a>>),
+
+    /// Content type `enveloped-data`
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    EnvelopedData(Option<EnvelopedDataContent<'a>>),
+
     /// Catch-all case for content types that are not explicitly supported
-    ///   - signed-data
-    ///   - enveloped-data
+    ///   - signed-data and enveloped-data, without the `alloc` feature
     ///   - signed-and-enveloped-data
     ///   - digested-data
     Other((ContentType, Option<OctetString<'a>>)),
@@ -36,6 +52,10 @@ impl<'a> ContentInfo<'a> {
         match self {
             Self::Data(_) => ContentType::Data,
             Self::EncryptedData(_) => ContentType::EncryptedData,
+            #[cfg(feature = "alloc")]
+            Self::SignedData(_) => ContentType::SignedData,
+            #[cfg(feature = "alloc")]
+            Self::EnvelopedData(_) => ContentType::EnvelopedData,
             Self::Other((content_type, _)) => *content_type,
         }
     }
@@ -52,6 +72,10 @@ impl<'a> ContentInfo<'a> {
         match content_type {
             ContentType::Data => ContentInfo::Data(None),
             ContentType::EncryptedData => ContentInfo::EncryptedData(None),
+            #[cfg(feature = "alloc")]
+            ContentType::SignedData => ContentInfo::SignedData(None),
+            #[cfg(feature = "alloc")]
+            ContentType::EnvelopedData => ContentInfo::EnvelopedData(None),
             _ => ContentInfo::Other((content_type, None)),
         }
     }
@@ -77,6 +101,16 @@ impl<'a> Decode<'a> for ContentInfo<'a> {
                     ContextSpecific::decode_explicit(decoder, CONTENT_TAG)?
                         .map(|field| field.value),
                 )),
+                #[cfg(feature = "alloc")]
+                ContentType::SignedData => Ok(ContentInfo::SignedData(
+                    ContextSpecific::decode_explicit(decoder, CONTENT_TAG)?
+                        .map(|field| field.value),
+                )),
+                #[cfg(feature = "alloc")]
+                ContentType::EnvelopedData => Ok(ContentInfo::EnvelopedData(
+                    ContextSpecific::decode_explicit(decoder, CONTENT_TAG)?
+                        .map(|field| field.value),
+                )),
                 _ => Ok(ContentInfo::Other((
                     content_type,
                     decoder.context_specific::<OctetString<'_>>(CONTENT_TAG, TagMode::Explicit)?,
@@ -108,6 +142,24 @@ impl<'a> Sequence<'a> for ContentInfo<'a> {
                     value: *d,
                 }),
             ]),
+            #[cfg(feature = "alloc")]
+            Self::SignedData(data) => f(&[
+                &self.content_type(),
+                &data.as_ref().map(|value| ContextSpecificRef {
+                    tag_number: CONTENT_TAG,
+                    tag_mode: TagMode::Explicit,
+                    value,
+                }),
+            ]),
+            #[cfg(feature = "alloc")]
+            Self::EnvelopedData(data) => f(&[
+                &self.content_type(),
+                &data.as_ref().map(|value| ContextSpecificRef {
+                    tag_number: CONTENT_TAG,
+                    tag_mode: TagMode::Explicit,
+                    value,
+                }),
+            ]),
             Self::Other((content_type, opt_oct_str)) => f(&[
                 content_type,
                 &opt_oct_str.as_ref().map(|d| ContextSpecific {
@@ -8,10 +8,33 @@ use der::{
 };
 use spki::AlgorithmIdentifier;
 
+#[cfg(feature = "alloc")]
+use {
+    crate::{
+        signed_data_content::{
+            Attributes, CertificateSet, RevocationInfoChoices, SignerIdentifier,
+        },
+        CmsVersion, UnorderedSetOf,
+    },
+    der::{
+        asn1::{Any, ContextSpecificRef, SequenceRef},
+        DecodeValue, Encoder, Header, Length, Tag,
+    },
+};
+
 type ContentEncryptionAlgorithmIdentifier<'a> = AlgorithmIdentifier<'a>;
 
 const ENCRYPTED_CONTENT_TAG: TagNumber = TagNumber::new(0);
 
+#[cfg(feature = "alloc")]
+const ORIGINATOR_INFO_TAG: TagNumber = TagNumber::new(0);
+#[cfg(feature = "alloc")]
+const UNPROTECTED_ATTRS_TAG: TagNumber = TagNumber::new(1);
+#[cfg(feature = "alloc")]
+const CERTS_TAG: TagNumber = TagNumber::new(0);
+#[cfg(feature = "alloc")]
+const CRLS_TAG: TagNumber = TagNumber::new(1);
+
 /// Encrypted content information [RFC 5652 § 6](https://datatracker.ietf.org/doc/html/rfc5652#section-6)
 ///
 /// ```text
@@ -80,3 +103,255 @@ impl<'a> Sequence<'a> for EncryptedContentInfo<'a> {
         ])
     }
 }
+
+/// Enveloped-data content type [RFC 5652 § 6.1](https://datatracker.ietf.org/doc/html/rfc5652#section-6.1)
+///
+/// ```text
+/// EnvelopedData ::= SEQUENCE {
+///   version CMSVersion,
+///   originatorInfo [0] IMPLICIT OriginatorInfo OPTIONAL,
+///   recipientInfos RecipientInfos,
+///   encryptedContentInfo EncryptedContentInfo,
+///   unprotectedAttrs [1] IMPLICIT UnprotectedAttributes OPTIONAL }
+///
+/// RecipientInfos ::= SET SIZE (1..MAX) OF RecipientInfo
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EnvelopedDataContent<'a> {
+    /// the syntax version number.
+    pub version: CmsVersion,
+
+    /// certificates and CRLs of the originator.
+    pub originator_info: Option<OriginatorInfo<'a>>,
+
+    /// the per-recipient information.
+    pub recipient_infos: UnorderedSetOf<RecipientInfo<'a>>,
+
+    /// the encrypted content information.
+    pub encrypted_content_info: EncryptedContentInfo<'a>,
+
+    /// the attributes which are not encrypted.
+    pub unprotected_attrs: Option<Attributes<'a>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Decode<'a> for EnvelopedDataContent<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<EnvelopedDataContent<'a>> {
+        decoder.sequence(|decoder| {
+            Ok(EnvelopedDataContent {
+                version: decoder.decode()?,
+                originator_info: decoder
+                    .context_specific(ORIGINATOR_INFO_TAG, TagMode::Implicit)?,
+                recipient_infos: decoder.decode()?,
+                encrypted_content_info: decoder.decode()?,
+                unprotected_attrs: decoder
+                    .context_specific(UNPROTECTED_ATTRS_TAG, TagMode::Implicit)?,
+            })
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Sequence<'a> for EnvelopedDataContent<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[
+            &self.version,
+            &self
+                .originator_info
+                .as_ref()
+                .map(|value| ContextSpecificRef {
+                    tag_number: ORIGINATOR_INFO_TAG,
+                    tag_mode: TagMode::Implicit,
+                    value,
+                }),
+            &self.recipient_infos,
+            &self.encrypted_content_info,
+            &self
+                .unprotected_attrs
+                .as_ref()
+                .map(|value| ContextSpecificRef {
+                    tag_number: UNPROTECTED_ATTRS_TAG,
+                    tag_mode: TagMode::Implicit,
+                    value,
+                }),
+        ])
+    }
+}
+
+/// Originator information [RFC 5652 § 6.1](https://datatracker.ietf.org/doc/html/rfc5652#section-6.1)
+///
+/// ```text
+/// OriginatorInfo ::= SEQUENCE {
+///   certs [0] IMPLICIT CertificateSet OPTIONAL,
+///   crls [1] IMPLICIT RevocationInfoChoices OPTIONAL }
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct OriginatorInfo<'a> {
+    /// the certificates of the originator.
+    pub certs: Option<CertificateSet<'a>>,
+
+    /// the revocation information of the originator.
+    pub crls: Option<RevocationInfoChoices<'a>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> DecodeValue<'a> for OriginatorInfo<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> der::Result<OriginatorInfo<'a>> {
+        SequenceRef::decode_value(decoder, header)?.decode_body(|decoder| {
+            Ok(OriginatorInfo {
+                certs: decoder.context_specific(CERTS_TAG, TagMode::Implicit)?,
+                crls: decoder.context_specific(CRLS_TAG, TagMode::Implicit)?,
+            })
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Sequence<'a> for OriginatorInfo<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[
+            &self.certs.as_ref().map(|value| ContextSpecificRef {
+                tag_number: CERTS_TAG,
+                tag_mode: TagMode::Implicit,
+                value,
+            }),
+            &self.crls.as_ref().map(|value| ContextSpecificRef {
+                tag_number: CRLS_TAG,
+                tag_mode: TagMode::Implicit,
+                value,
+            }),
+        ])
+    }
+}
+
+/// Per-recipient information [RFC 5652 § 6.2](https://datatracker.ietf.org/doc/html/rfc5652#section-6.2)
+///
+/// ```text
+/// RecipientInfo ::= CHOICE {
+///   ktri KeyTransRecipientInfo,
+///   kari [1] KeyAgreeRecipientInfo,
+///   kekri [2] KEKRecipientInfo,
+///   pwri [3] PasswordRecipientinfo,
+///   ori [4] OtherRecipientInfo }
+/// ```
+///
+/// Only `KeyTransRecipientInfo` is decoded: the other alternatives are
+/// kept as [`Any`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RecipientInfo<'a> {
+    /// Key transport recipient, e.g. RSA.
+    Ktri(KeyTransRecipientInfo<'a>),
+
+    /// Any other kind of recipient.
+    Other(Any<'a>),
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Decode<'a> for RecipientInfo<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<RecipientInfo<'a>> {
+        match Tag::peek(decoder)? {
+            Tag::Sequence => decoder.decode().map(Self::Ktri),
+            Tag::ContextSpecific {
+                constructed: true, ..
+            } => decoder.decode().map(Self::Other),
+            tag => Err(tag.unexpected_error(Some(Tag::Sequence))),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Encode for RecipientInfo<'a> {
+    fn encoded_len(&self) -> der::Result<Length> {
+        match self {
+            Self::Ktri(ktri) => ktri.encoded_len(),
+            Self::Other(other) => other.encoded_len(),
+        }
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> der::Result<()> {
+        match self {
+            Self::Ktri(ktri) => ktri.encode(encoder),
+            Self::Other(other) => other.encode(encoder),
+        }
+    }
+}
+
+/// Key transport recipient information [RFC 5652 § 6.2.1](https://datatracker.ietf.org/doc/html/rfc5652#section-6.2.1)
+///
+/// ```text
+/// KeyTransRecipientInfo ::= SEQUENCE {
+///   version CMSVersion,  -- always set to 0 or 2
+///   rid RecipientIdentifier,
+///   keyEncryptionAlgorithm KeyEncryptionAlgorithmIdentifier,
+///   encryptedKey EncryptedKey }
+///
+/// EncryptedKey ::= OCTET STRING
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeyTransRecipientInfo<'a> {
+    /// the syntax version number.
+    pub version: CmsVersion,
+
+    /// identifies the recipient's certificate.
+    pub rid: RecipientIdentifier<'a>,
+
+    /// the algorithm used to encrypt the content-encryption key.
+    pub key_encryption_algorithm: AlgorithmIdentifier<'a>,
+
+    /// the encrypted content-encryption key.
+    pub encrypted_key: OctetString<'a>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Decode<'a> for KeyTransRecipientInfo<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<KeyTransRecipientInfo<'a>> {
+        decoder.sequence(|decoder| {
+            Ok(KeyTransRecipientInfo {
+                version: decoder.decode()?,
+                rid: decoder.decode()?,
+                key_encryption_algorithm: decoder.decode()?,
+                encrypted_key: decoder.decode()?,
+            })
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Sequence<'a> for KeyTransRecipientInfo<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[
+            &self.version,
+            &self.rid,
+            &self.key_encryption_algorithm,
+            &self.encrypted_key,
+        ])
+    }
+}
+
+/// Recipient identifier [RFC 5652 § 6.2.1](https://datatracker.ietf.org/doc/html/rfc5652#section-6.2.1)
+///
+/// ```text
+/// RecipientIdentifier ::= CHOICE {
+///   issuerAndSerialNumber IssuerAndSerialNumber,
+///   subjectKeyIdentifier [0] SubjectKeyIdentifier }
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub type RecipientIdentifier<'a> = SignerIdentifier<'a>;
@@ -10,15 +10,29 @@
 #![forbid(unsafe_code, clippy::unwrap_used)]
 #![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod cms_version;
 mod content_info;
 mod content_type;
 
-pub use crate::{content_info::ContentInfo, content_type::ContentType};
+#[cfg(feature = "alloc")]
+mod unordered_set_of;
+
+pub use crate::{cms_version::CmsVersion, content_info::ContentInfo, content_type::ContentType};
+
+#[cfg(feature = "alloc")]
+pub use crate::unordered_set_of::UnorderedSetOf;
 
 pub mod data_content;
 pub mod encrypted_data_content;
 pub mod enveloped_data_content;
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod signed_data_content;
+
 use der::asn1::ObjectIdentifier;
 
 /// `pkcs-7` Object Identifier (OID).
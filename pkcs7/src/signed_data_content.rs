@@ -0,0 +1,461 @@
+//! `signed-data` content type [RFC 5652 § 5](https://datatracker.ietf.org/doc/html/rfc5652#section-5)
+//!
+//! Types defined by X.509 (certificates, CRLs, names and attributes) are
+//! kept as [`Any`]: they can be decoded further using e.g. the
+//! [`x509-cert`](https://docs.rs/x509-cert) crate.
+
+use crate::{CmsVersion, UnorderedSetOf};
+
+use der::{
+    asn1::{Any, ContextSpecificRef, ObjectIdentifier, OctetString, SetOfVec, UIntBytes},
+    Choice, Decode, Decoder, Encode, Encoder, Length, Sequence, Tag, TagMode, TagNumber, Tagged,
+};
+use spki::AlgorithmIdentifier;
+
+const CERTIFICATES_TAG: TagNumber = TagNumber::new(0);
+const CRLS_TAG: TagNumber = TagNumber::new(1);
+const E_CONTENT_TAG: TagNumber = TagNumber::new(0);
+const SIGNED_ATTRS_TAG: TagNumber = TagNumber::new(0);
+const UNSIGNED_ATTRS_TAG: TagNumber = TagNumber::new(1);
+const SUBJECT_KEY_IDENTIFIER_TAG: TagNumber = TagNumber::new(0);
+
+/// Signed-data content type [RFC 5652 § 5.1](https://datatracker.ietf.org/doc/html/rfc5652#section-5.1)
+///
+/// ```text
+/// SignedData ::= SEQUENCE {
+///   version CMSVersion,
+///   digestAlgorithms DigestAlgorithmIdentifiers,
+///   encapContentInfo EncapsulatedContentInfo,
+///   certificates [0] IMPLICIT CertificateSet OPTIONAL,
+///   crls [1] IMPLICIT RevocationInfoChoices OPTIONAL,
+///   signerInfos SignerInfos }
+///
+/// DigestAlgorithmIdentifiers ::= SET OF DigestAlgorithmIdentifier
+/// ```
+///
+/// Degenerate signed-data with no content and no signers is commonly used
+/// to exchange bundles of certificates (`.p7b` files).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedDataContent<'a> {
+    /// the syntax version number.
+    pub version: CmsVersion,
+
+    /// the message digest algorithms used by the signers.
+    pub digest_algorithms: SetOfVec<AlgorithmIdentifier<'a>>,
+
+    /// the signed content.
+    pub encap_content_info: EncapsulatedContentInfo<'a>,
+
+    /// the certificates needed to validate the signatures.
+    pub certificates: Option<CertificateSet<'a>>,
+
+    /// the revocation information needed to validate the signatures.
+    pub crls: Option<RevocationInfoChoices<'a>>,
+
+    /// the per-signer information.
+    pub signer_infos: SignerInfos<'a>,
+}
+
+impl<'a> Decode<'a> for SignedDataContent<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<SignedDataContent<'a>> {
+        decoder.sequence(|decoder| {
+            Ok(SignedDataContent {
+                version: decoder.decode()?,
+                digest_algorithms: decoder.decode()?,
+                encap_content_info: decoder.decode()?,
+                certificates: decoder.context_specific(CERTIFICATES_TAG, TagMode::Implicit)?,
+                crls: decoder.context_specific(CRLS_TAG, TagMode::Implicit)?,
+                signer_infos: decoder.decode()?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for SignedDataContent<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[
+            &self.version,
+            &self.digest_algorithms,
+            &self.encap_content_info,
+            &self.certificates.as_ref().map(|value| ContextSpecificRef {
+                tag_number: CERTIFICATES_TAG,
+                tag_mode: TagMode::Implicit,
+                value,
+            }),
+            &self.crls.as_ref().map(|value| ContextSpecificRef {
+                tag_number: CRLS_TAG,
+                tag_mode: TagMode::Implicit,
+                value,
+            }),
+            &self.signer_infos,
+        ])
+    }
+}
+
+/// Encapsulated content information [RFC 5652 § 5.2](https://datatracker.ietf.org/doc/html/rfc5652#section-5.2)
+///
+/// ```text
+/// EncapsulatedContentInfo ::= SEQUENCE {
+///   eContentType ContentType,
+///   eContent [0] EXPLICIT OCTET STRING OPTIONAL }
+/// ```
+///
+/// The content type isn't limited to the PKCS#7 ones, e.g. time-stamp
+/// tokens contain `id-ct-TSTInfo`. The `eContent` is absent for detached
+/// signatures.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EncapsulatedContentInfo<'a> {
+    /// indicates the type of content.
+    pub e_content_type: ObjectIdentifier,
+
+    /// the signed content, if not detached.
+    pub e_content: Option<OctetString<'a>>,
+}
+
+impl<'a> Decode<'a> for EncapsulatedContentInfo<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<EncapsulatedContentInfo<'a>> {
+        decoder.sequence(|decoder| {
+            Ok(EncapsulatedContentInfo {
+                e_content_type: decoder.decode()?,
+                e_content: decoder.context_specific(E_CONTENT_TAG, TagMode::Explicit)?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for EncapsulatedContentInfo<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[
+            &self.e_content_type,
+            &self.e_content.as_ref().map(|value| ContextSpecificRef {
+                tag_number: E_CONTENT_TAG,
+                tag_mode: TagMode::Explicit,
+                value,
+            }),
+        ])
+    }
+}
+
+/// Per-signer information [RFC 5652 § 5.3](https://datatracker.ietf.org/doc/html/rfc5652#section-5.3)
+///
+/// ```text
+/// SignerInfo ::= SEQUENCE {
+///   version CMSVersion,
+///   sid SignerIdentifier,
+///   digestAlgorithm DigestAlgorithmIdentifier,
+///   signedAttrs [0] IMPLICIT SignedAttributes OPTIONAL,
+///   signatureAlgorithm SignatureAlgorithmIdentifier,
+///   signature SignatureValue,
+///   unsignedAttrs [1] IMPLICIT UnsignedAttributes OPTIONAL }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerInfo<'a> {
+    /// the syntax version number.
+    pub version: CmsVersion,
+
+    /// identifies the signer's certificate.
+    pub sid: SignerIdentifier<'a>,
+
+    /// the message digest algorithm used by the signer.
+    pub digest_algorithm: AlgorithmIdentifier<'a>,
+
+    /// the attributes covered by the signature.
+    pub signed_attrs: Option<Attributes<'a>>,
+
+    /// the signature algorithm used by the signer.
+    pub signature_algorithm: AlgorithmIdentifier<'a>,
+
+    /// the signature value.
+    pub signature: OctetString<'a>,
+
+    /// the attributes not covered by the signature.
+    pub unsigned_attrs: Option<Attributes<'a>>,
+}
+
+impl<'a> Decode<'a> for SignerInfo<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<SignerInfo<'a>> {
+        decoder.sequence(|decoder| {
+            Ok(SignerInfo {
+                version: decoder.decode()?,
+                sid: decoder.decode()?,
+                digest_algorithm: decoder.decode()?,
+                signed_attrs: decoder.context_specific(SIGNED_ATTRS_TAG, TagMode::Implicit)?,
+                signature_algorithm: decoder.decode()?,
+                signature: decoder.decode()?,
+                unsigned_attrs: decoder.context_specific(UNSIGNED_ATTRS_TAG, TagMode::Implicit)?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for SignerInfo<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[
+            &self.version,
+            &self.sid,
+            &self.digest_algorithm,
+            &self.signed_attrs.as_ref().map(|value| ContextSpecificRef {
+                tag_number: SIGNED_ATTRS_TAG,
+                tag_mode: TagMode::Implicit,
+                value,
+            }),
+            &self.signature_algorithm,
+            &self.signature,
+            &self
+                .unsigned_attrs
+                .as_ref()
+                .map(|value| ContextSpecificRef {
+                    tag_number: UNSIGNED_ATTRS_TAG,
+                    tag_mode: TagMode::Implicit,
+                    value,
+                }),
+        ])
+    }
+}
+
+/// Signer identifier [RFC 5652 § 5.3](https://datatracker.ietf.org/doc/html/rfc5652#section-5.3)
+///
+/// ```text
+/// SignerIdentifier ::= CHOICE {
+///   issuerAndSerialNumber IssuerAndSerialNumber,
+///   subjectKeyIdentifier [0] SubjectKeyIdentifier }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SignerIdentifier<'a> {
+    /// issuer name and serial number of the certificate
+    IssuerAndSerialNumber(IssuerAndSerialNumber<'a>),
+
+    /// subject key identifier of the certificate
+    SubjectKeyIdentifier(OctetString<'a>),
+}
+
+impl<'a> Choice<'a> for SignerIdentifier<'a> {
+    fn can_decode(tag: Tag) -> bool {
+        tag == Tag::Sequence || tag == Self::SUBJECT_KEY_IDENTIFIER
+    }
+}
+
+impl<'a> SignerIdentifier<'a> {
+    const SUBJECT_KEY_IDENTIFIER: Tag = Tag::ContextSpecific {
+        constructed: false,
+        number: SUBJECT_KEY_IDENTIFIER_TAG,
+    };
+}
+
+impl<'a> Decode<'a> for SignerIdentifier<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<SignerIdentifier<'a>> {
+        match Tag::peek(decoder)? {
+            Tag::Sequence => decoder.decode().map(Self::IssuerAndSerialNumber),
+            Self::SUBJECT_KEY_IDENTIFIER => decoder
+                .context_specific(SUBJECT_KEY_IDENTIFIER_TAG, TagMode::Implicit)?
+                .map(Self::SubjectKeyIdentifier)
+                .ok_or_else(|| Self::SUBJECT_KEY_IDENTIFIER.value_error()),
+            tag => Err(tag.unexpected_error(None)),
+        }
+    }
+}
+
+impl<'a> Encode for SignerIdentifier<'a> {
+    fn encoded_len(&self) -> der::Result<Length> {
+        match self {
+            Self::IssuerAndSerialNumber(sid) => sid.encoded_len(),
+            Self::SubjectKeyIdentifier(ski) => ContextSpecificRef {
+                tag_number: SUBJECT_KEY_IDENTIFIER_TAG,
+                tag_mode: TagMode::Implicit,
+                value: ski,
+            }
+            .encoded_len(),
+        }
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> der::Result<()> {
+        match self {
+            Self::IssuerAndSerialNumber(sid) => sid.encode(encoder),
+            Self::SubjectKeyIdentifier(ski) => ContextSpecificRef {
+                tag_number: SUBJECT_KEY_IDENTIFIER_TAG,
+                tag_mode: TagMode::Implicit,
+                value: ski,
+            }
+            .encode(encoder),
+        }
+    }
+}
+
+impl<'a> Tagged for SignerIdentifier<'a> {
+    fn tag(&self) -> Tag {
+        match self {
+            Self::IssuerAndSerialNumber(_) => Tag::Sequence,
+            Self::SubjectKeyIdentifier(_) => Self::SUBJECT_KEY_IDENTIFIER,
+        }
+    }
+}
+
+/// Issuer name and serial number of a certificate [RFC 5652 § 10.2.4](https://datatracker.ietf.org/doc/html/rfc5652#section-10.2.4)
+///
+/// ```text
+/// IssuerAndSerialNumber ::= SEQUENCE {
+///   issuer Name,
+///   serialNumber CertificateSerialNumber }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IssuerAndSerialNumber<'a> {
+    /// the issuer `Name` of the certificate.
+    pub issuer: Any<'a>,
+
+    /// the serial number of the certificate.
+    pub serial_number: UIntBytes<'a>,
+}
+
+impl<'a> Decode<'a> for IssuerAndSerialNumber<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<IssuerAndSerialNumber<'a>> {
+        decoder.sequence(|decoder| {
+            Ok(IssuerAndSerialNumber {
+                issuer: decoder.decode()?,
+                serial_number: decoder.decode()?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for IssuerAndSerialNumber<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[&self.issuer, &self.serial_number])
+    }
+}
+
+/// Certificate of any format [RFC 5652 § 10.2.2](https://datatracker.ietf.org/doc/html/rfc5652#section-10.2.2)
+///
+/// ```text
+/// CertificateChoices ::= CHOICE {
+///   certificate Certificate,
+///   extendedCertificate [0] IMPLICIT ExtendedCertificate,  -- Obsolete
+///   v1AttrCert [1] IMPLICIT AttributeCertificateV1,        -- Obsolete
+///   v2AttrCert [2] IMPLICIT AttributeCertificateV2,
+///   other [3] IMPLICIT OtherCertificateFormat }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CertificateChoices<'a> {
+    /// X.509 `Certificate`.
+    Certificate(Any<'a>),
+
+    /// Any other kind of certificate.
+    Other(Any<'a>),
+}
+
+impl<'a> Decode<'a> for CertificateChoices<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<CertificateChoices<'a>> {
+        decode_choice(decoder, Self::Certificate, Self::Other)
+    }
+}
+
+impl<'a> Encode for CertificateChoices<'a> {
+    fn encoded_len(&self) -> der::Result<Length> {
+        match self {
+            Self::Certificate(any) | Self::Other(any) => any.encoded_len(),
+        }
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> der::Result<()> {
+        match self {
+            Self::Certificate(any) | Self::Other(any) => any.encode(encoder),
+        }
+    }
+}
+
+/// Revocation information of any format [RFC 5652 § 10.2.1](https://datatracker.ietf.org/doc/html/rfc5652#section-10.2.1)
+///
+/// ```text
+/// RevocationInfoChoice ::= CHOICE {
+///   crl CertificateList,
+///   other [1] IMPLICIT OtherRevocationInfoFormat }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RevocationInfoChoice<'a> {
+    /// X.509 `CertificateList`.
+    Crl(Any<'a>),
+
+    /// Any other revocation information format, e.g. an OCSP response.
+    Other(Any<'a>),
+}
+
+impl<'a> Decode<'a> for RevocationInfoChoice<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<RevocationInfoChoice<'a>> {
+        decode_choice(decoder, Self::Crl, Self::Other)
+    }
+}
+
+impl<'a> Encode for RevocationInfoChoice<'a> {
+    fn encoded_len(&self) -> der::Result<Length> {
+        match self {
+            Self::Crl(any) | Self::Other(any) => any.encoded_len(),
+        }
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> der::Result<()> {
+        match self {
+            Self::Crl(any) | Self::Other(any) => any.encode(encoder),
+        }
+    }
+}
+
+/// Decode a CHOICE between a `SEQUENCE` and other formats which are
+/// tagged with a constructed context-specific tag.
+fn decode_choice<'a, T>(
+    decoder: &mut Decoder<'a>,
+    sequence: fn(Any<'a>) -> T,
+    other: fn(Any<'a>) -> T,
+) -> der::Result<T> {
+    match Tag::peek(decoder)? {
+        Tag::Sequence => decoder.decode().map(sequence),
+        Tag::ContextSpecific {
+            constructed: true, ..
+        } => decoder.decode().map(other),
+        tag => Err(tag.unexpected_error(Some(Tag::Sequence))),
+    }
+}
+
+/// Set of attributes, e.g. the signed attributes of a [`SignerInfo`].
+///
+/// ```text
+/// SignedAttributes ::= SET SIZE (1..MAX) OF Attribute
+///
+/// Attribute ::= SEQUENCE {
+///   attrType OBJECT IDENTIFIER,
+///   attrValues SET OF AttributeValue }
+/// ```
+///
+/// Each attribute is kept as [`Any`].
+pub type Attributes<'a> = SetOfVec<Any<'a>>;
+
+/// Set of certificates [RFC 5652 § 10.2.3](https://datatracker.ietf.org/doc/html/rfc5652#section-10.2.3)
+///
+/// ```text
+/// CertificateSet ::= SET OF CertificateChoices
+/// ```
+pub type CertificateSet<'a> = UnorderedSetOf<CertificateChoices<'a>>;
+
+/// Set of revocation information [RFC 5652 § 10.2.1](https://datatracker.ietf.org/doc/html/rfc5652#section-10.2.1)
+///
+/// ```text
+/// RevocationInfoChoices ::= SET OF RevocationInfoChoice
+/// ```
+pub type RevocationInfoChoices<'a> = UnorderedSetOf<RevocationInfoChoice<'a>>;
+
+/// Set of per-signer information [RFC 5652 § 5.1](https://datatracker.ietf.org/doc/html/rfc5652#section-5.1)
+///
+/// ```text
+/// SignerInfos ::= SET OF SignerInfo
+/// ```
+pub type SignerInfos<'a> = UnorderedSetOf<SignerInfo<'a>>;
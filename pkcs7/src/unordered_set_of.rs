@@ -0,0 +1,50 @@
+use alloc::vec::Vec;
+use der::{DecodeValue, Decoder, EncodeValue, Encoder, FixedTag, Header, Length, Tag};
+
+/// ASN.1 `SET OF` whose elements are kept in their encoded order.
+///
+/// Unlike [`SetOfVec`](der::asn1::SetOfVec), the DER ordering of the
+/// elements isn't enforced when decoding: many encoders don't sort the
+/// certificates and signers of CMS messages, and sorting them requires
+/// comparing entire certificates.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnorderedSetOf<T>(pub Vec<T>);
+
+impl<T> Default for UnorderedSetOf<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<'a, T> DecodeValue<'a> for UnorderedSetOf<T>
+where
+    T: der::Decode<'a>,
+{
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> der::Result<Self> {
+        Vec::decode_value(decoder, header).map(Self)
+    }
+}
+
+impl<T> EncodeValue for UnorderedSetOf<T>
+where
+    T: der::Encode,
+{
+    fn value_len(&self) -> der::Result<Length> {
+        self.0.value_len()
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> der::Result<()> {
+        self.0.encode_value(encoder)
+    }
+}
+
+impl<T> FixedTag for UnorderedSetOf<T> {
+    const TAG: Tag = Tag::Set;
+}
+
+impl<T> From<Vec<T>> for UnorderedSetOf<T> {
+    fn from(elements: Vec<T>) -> Self {
+        Self(elements)
+    }
+}
@@ -78,3 +78,102 @@ fn decode_encrypted_key_example() {
 
     assert_eq!(encoded_content, bytes)
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decode_signed_data_example() {
+    use pkcs7::signed_data_content::{CertificateChoices, SignerIdentifier};
+    use pkcs7::CmsVersion;
+
+    let bytes = fs::read("./tests/examples/cms-signed.der").expect("Failed to read cms-signed.der");
+
+    let content = ContentInfo::from_der(&bytes).expect("expected valid data");
+    assert_eq!(content.content_type(), ContentType::SignedData);
+
+    match &content {
+        ContentInfo::SignedData(Some(signed_data)) => {
+            assert_eq!(signed_data.version, CmsVersion::V1);
+            assert_eq!(signed_data.digest_algorithms.len(), 1);
+            assert_eq!(
+                signed_data.encap_content_info.e_content_type,
+                pkcs7::PKCS_7_DATA_OID
+            );
+            assert_eq!(
+                signed_data.encap_content_info.e_content.unwrap().as_bytes(),
+                b"Hello, world!\n"
+            );
+            assert!(signed_data.crls.is_none());
+
+            let certificates = &signed_data.certificates.as_ref().unwrap().0;
+            assert!(matches!(
+                certificates[..],
+                [CertificateChoices::Certificate(_)]
+            ));
+
+            let signer_infos = &signed_data.signer_infos.0;
+            assert_eq!(signer_infos.len(), 1);
+            assert!(signer_infos[0].signed_attrs.is_none());
+            assert_eq!(signer_infos[0].signature.as_bytes().len(), 256);
+            match &signer_infos[0].sid {
+                SignerIdentifier::IssuerAndSerialNumber(sid) => {
+                    assert_eq!(sid.serial_number.as_bytes(), &[0x02])
+                }
+                _ => panic!("expected SignerIdentifier::IssuerAndSerialNumber(_)"),
+            }
+        }
+        _ => panic!("expected ContentInfo::SignedData(Some(_))"),
+    }
+
+    let mut buf = vec![0u8; bytes.len()];
+    let encoded_content = encode_content_info(&content, &mut buf);
+
+    assert_eq!(encoded_content, bytes);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decode_enveloped_data_example() {
+    use der::{Tag, TagNumber, Tagged};
+    use pkcs7::enveloped_data_content::RecipientInfo;
+    use pkcs7::CmsVersion;
+
+    let bytes =
+        fs::read("./tests/examples/cms-enveloped.der").expect("Failed to read cms-enveloped.der");
+
+    let content = ContentInfo::from_der(&bytes).expect("expected valid data");
+    assert_eq!(content.content_type(), ContentType::EnvelopedData);
+
+    match &content {
+        ContentInfo::EnvelopedData(Some(enveloped_data)) => {
+            assert_eq!(enveloped_data.version, CmsVersion::V2);
+            assert!(enveloped_data.originator_info.is_none());
+            assert!(enveloped_data.unprotected_attrs.is_none());
+
+            // RSA recipient followed by an ECDH one
+            match &enveloped_data.recipient_infos.0[..] {
+                [RecipientInfo::Ktri(ktri), RecipientInfo::Other(kari)] => {
+                    assert_eq!(ktri.version, CmsVersion::V0);
+                    assert_eq!(ktri.encrypted_key.as_bytes().len(), 256);
+                    assert_eq!(
+                        kari.tag(),
+                        Tag::ContextSpecific {
+                            constructed: true,
+                            number: TagNumber::N1,
+                        }
+                    );
+                }
+                _ => panic!("expected a key transport and another recipient"),
+            }
+
+            let eci = &enveloped_data.encrypted_content_info;
+            assert_eq!(eci.content_type, ContentType::Data);
+            assert_eq!(eci.encrypted_content.unwrap().len(), 16);
+        }
+        _ => panic!("expected ContentInfo::EnvelopedData(Some(_))"),
+    }
+
+    let mut buf = vec![0u8; bytes.len()];
+    let encoded_content = encode_content_info(&content, &mut buf);
+
+    assert_eq!(encoded_content, bytes);
+}
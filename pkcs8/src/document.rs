@@ -4,3 +4,4 @@
 #[cfg(feature = "pkcs5")]
 pub(crate) mod encrypted_private_key;
 pub(crate) mod private_key;
+pub(crate) mod secret;
@@ -0,0 +1,100 @@
+//! Hardened PKCS#8 private key document.
+
+use crate::{
+    DecodePrivateKey, EncodePrivateKey, Error, PrivateKeyDocument, PrivateKeyInfo, Result,
+};
+use der::DocumentMessage;
+
+#[cfg(feature = "pem")]
+use {alloc::string::String, der::pem::LineEnding, zeroize::Zeroizing};
+
+#[cfg(feature = "std")]
+use std::path::Path;
+
+/// PKCS#8 private key document which is hardened for storing secrets.
+///
+/// Like [`PrivateKeyDocument`], this type stores a [`PrivateKeyInfo`]
+/// encoded as ASN.1 DER which is guaranteed to be well-formed. It is a
+/// [`der::SecretDocument`], so the key is zeroized when dropped, omitted from
+/// [`core::fmt::Debug`] output, and only readable by its owner when written
+/// to a file. When the `mlock` feature is enabled, it is also locked into
+/// memory so that it isn't swapped to disk.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub type SecretDocument = der::SecretDocument<PrivateKeyInfo<'static>>;
+
+impl<'a> DocumentMessage<'a> for PrivateKeyInfo<'static> {
+    type Message = PrivateKeyInfo<'a>;
+}
+
+impl DecodePrivateKey for SecretDocument {
+    fn from_pkcs8_der(bytes: &[u8]) -> Result<Self> {
+        Ok(Self::from_der(bytes)?)
+    }
+
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    fn from_pkcs8_pem(s: &str) -> Result<Self> {
+        Ok(Self::from_pem(s)?)
+    }
+
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn read_pkcs8_der_file(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self::read_der_file(path)?)
+    }
+
+    #[cfg(all(feature = "pem", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn read_pkcs8_pem_file(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self::read_pem_file(path)?)
+    }
+}
+
+impl EncodePrivateKey for SecretDocument {
+    fn to_pkcs8_der(&self) -> Result<PrivateKeyDocument> {
+        PrivateKeyDocument::from_pkcs8_der(self.as_bytes())
+    }
+
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    fn to_pkcs8_pem(&self, line_ending: LineEnding) -> Result<Zeroizing<String>> {
+        Ok(self.to_pem(line_ending)?)
+    }
+
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn write_pkcs8_der_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        Ok(self.write_der_file(path)?)
+    }
+
+    #[cfg(all(feature = "pem", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn write_pkcs8_pem_file(&self, path: impl AsRef<Path>, line_ending: LineEnding) -> Result<()> {
+        Ok(self.write_pem_file(path, line_ending)?)
+    }
+}
+
+impl From<PrivateKeyDocument> for SecretDocument {
+    fn from(doc: PrivateKeyDocument) -> SecretDocument {
+        // `doc` is zeroized when dropped
+        Self::try_from(doc.as_ref()).expect("PrivateKeyDocument is well-formed")
+    }
+}
+
+impl TryFrom<PrivateKeyInfo<'_>> for SecretDocument {
+    type Error = Error;
+
+    fn try_from(private_key_info: PrivateKeyInfo<'_>) -> Result<SecretDocument> {
+        Self::try_from(&private_key_info)
+    }
+}
+
+impl TryFrom<&PrivateKeyInfo<'_>> for SecretDocument {
+    type Error = Error;
+
+    fn try_from(private_key_info: &PrivateKeyInfo<'_>) -> Result<SecretDocument> {
+        Ok(Self::encode_msg(private_key_info)?)
+    }
+}
@@ -18,7 +18,8 @@
 //!
 //! - [`EncryptedPrivateKeyInfo`]: (with `pkcs5` feature) encrypted key.
 //! - [`PrivateKeyInfo`]: algorithm identifier and data representing a private key.
-//!   Optionally also includes public key data for asymmetric keys.
+//!   Optionally also includes attributes and public key data for asymmetric
+//!   keys (i.e. [RFC 5958] `OneAsymmetricKey`, see [`OneAsymmetricKey`]).
 //! - [`SubjectPublicKeyInfo`]: algorithm identifier and data representing a public key
 //!   (re-exported from the [`spki`] crate)
 //!
@@ -50,7 +51,8 @@
 //!       - SHA-2 based PRF with HMAC-SHA224, HMAC-SHA256, HMAC-SHA384, or HMAC-SHA512
 //!       - SHA-1 based PRF with HMAC-SHA1, when the `sha1` feature of this crate is enabled.
 //!   - Symmetric encryption: AES-128-CBC, AES-192-CBC, or AES-256-CBC
-//!     (best available options for PKCS#5v2)
+//!     (best available options for PKCS#5v2), or AES-128-GCM, AES-192-GCM,
+//!     or AES-256-GCM ([RFC 5084])
 //!  
 //! ## Legacy DES-CBC and DES-EDE3-CBC (3DES) support (optional)
 //! When the `des-insecure` and/or `3des` features are enabled this crate provides support for
@@ -69,6 +71,7 @@
 //!
 //! [RFC 5208]: https://tools.ietf.org/html/rfc5208
 //! [RFC 5958]: https://tools.ietf.org/html/rfc5958
+//! [RFC 5084]: https://datatracker.ietf.org/doc/html/rfc5084
 //! [RFC 7914]: https://datatracker.ietf.org/doc/html/rfc7914
 //! [PKCS#5v2 Password Based Encryption Scheme 2 (RFC 8018)]: https://tools.ietf.org/html/rfc8018#section-6.2
 //! [scrypt]: https://en.wikipedia.org/wiki/Scrypt
@@ -91,7 +94,7 @@ pub(crate) mod encrypted_private_key_info;
 
 pub use crate::{
     error::{Error, Result},
-    private_key_info::PrivateKeyInfo,
+    private_key_info::{OneAsymmetricKey, PrivateKeyInfo},
     traits::DecodePrivateKey,
     version::Version,
 };
@@ -100,7 +103,10 @@ pub use spki::{self, AlgorithmIdentifier, DecodePublicKey, SubjectPublicKeyInfo}
 
 #[cfg(feature = "alloc")]
 pub use {
-    crate::{document::private_key::PrivateKeyDocument, traits::EncodePrivateKey},
+    crate::{
+        document::{private_key::PrivateKeyDocument, secret::SecretDocument},
+        traits::EncodePrivateKey,
+    },
     spki::{EncodePublicKey, PublicKeyDocument},
 };
 
@@ -4,7 +4,7 @@ use crate::{AlgorithmIdentifier, Error, Result, Version};
 use core::fmt;
 use der::{
     asn1::{Any, BitString, ContextSpecific, OctetString},
-    Decode, Decoder, Encode, Sequence, TagMode, TagNumber,
+    Decode, Decoder, Encode, Sequence, Tag, TagMode, TagNumber,
 };
 
 #[cfg(feature = "alloc")]
@@ -26,6 +26,9 @@ use {
 #[cfg(feature = "subtle")]
 use subtle::{Choice, ConstantTimeEq};
 
+/// Context-specific tag number for the attributes.
+const ATTRIBUTES_TAG: TagNumber = TagNumber::new(0);
+
 /// Context-specific tag number for the public key.
 const PUBLIC_KEY_TAG: TagNumber = TagNumber::new(1);
 
@@ -98,10 +101,22 @@ pub struct PrivateKeyInfo<'a> {
     /// Private key data.
     pub private_key: &'a [u8],
 
+    /// DER-encoded contents of the `attributes` field (i.e. the elements of
+    /// the `SET OF Attribute`), if present.
+    pub attributes: Option<&'a [u8]>,
+
     /// Public key data, optionally available if version is V2.
     pub public_key: Option<&'a [u8]>,
 }
 
+/// PKCS#8 v2 `OneAsymmetricKey` as defined in [RFC 5958 Section 2].
+///
+/// This is the name RFC 5958 gives to [`PrivateKeyInfo`], which is a
+/// backwards-compatible extension of the structure from RFC 5208.
+///
+/// [RFC 5958 Section 2]: https://datatracker.ietf.org/doc/html/rfc5958#section-2
+pub type OneAsymmetricKey<'a> = PrivateKeyInfo<'a>;
+
 impl<'a> PrivateKeyInfo<'a> {
     /// Create a new PKCS#8 [`PrivateKeyInfo`] message.
     ///
@@ -111,6 +126,7 @@ impl<'a> PrivateKeyInfo<'a> {
         Self {
             algorithm,
             private_key,
+            attributes: None,
             public_key: None,
         }
     }
@@ -156,6 +172,14 @@ impl<'a> PrivateKeyInfo<'a> {
     }
 }
 
+/// Tag of the `attributes` field: `[0] IMPLICIT SET OF Attribute`.
+const fn attributes_tag() -> Tag {
+    Tag::ContextSpecific {
+        constructed: true,
+        number: ATTRIBUTES_TAG,
+    }
+}
+
 impl<'a> Decode<'a> for PrivateKeyInfo<'a> {
     fn decode(decoder: &mut Decoder<'a>) -> der::Result<PrivateKeyInfo<'a>> {
         decoder.sequence(|decoder| {
@@ -163,16 +187,26 @@ impl<'a> Decode<'a> for PrivateKeyInfo<'a> {
             let version = Version::decode(decoder)?;
             let algorithm = decoder.decode()?;
             let private_key = decoder.octet_string()?.into();
+
+            let attributes = match decoder.peek_tag().ok() {
+                Some(tag) if tag == attributes_tag() => Some(decoder.decode::<Any<'_>>()?.value()),
+                // `Attributes` is a `SET OF` and therefore must be constructed
+                Some(
+                    tag @ Tag::ContextSpecific {
+                        number: ATTRIBUTES_TAG,
+                        ..
+                    },
+                ) => return Err(tag.non_canonical_error()),
+                _ => None,
+            };
+
             let public_key = decoder
                 .context_specific::<BitString<'_>>(PUBLIC_KEY_TAG, TagMode::Implicit)?
-                .map(|bs| {
-                    bs.as_bytes()
-                        .ok_or_else(|| der::Tag::BitString.value_error())
-                })
+                .map(|bs| bs.as_bytes().ok_or_else(|| Tag::BitString.value_error()))
                 .transpose()?;
 
             if version.has_public_key() != public_key.is_some() {
-                return Err(decoder.value_error(der::Tag::ContextSpecific {
+                return Err(decoder.value_error(Tag::ContextSpecific {
                     constructed: true,
                     number: PUBLIC_KEY_TAG,
                 }));
@@ -186,6 +220,7 @@ impl<'a> Decode<'a> for PrivateKeyInfo<'a> {
             Ok(Self {
                 algorithm,
                 private_key,
+                attributes,
                 public_key,
             })
         })
@@ -201,6 +236,10 @@ impl<'a> Sequence<'a> for PrivateKeyInfo<'a> {
             &u8::from(self.version()),
             &self.algorithm,
             &OctetString::new(self.private_key)?,
+            &self
+                .attributes
+                .map(|attrs| Any::new(attributes_tag(), attrs))
+                .transpose()?,
             &self
                 .public_key
                 .map(|pk| {
@@ -223,11 +262,18 @@ impl<'a> TryFrom<&'a [u8]> for PrivateKeyInfo<'a> {
     }
 }
 
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl der::pem::PemLabel for PrivateKeyInfo<'_> {
+    const TYPE_LABEL: &'static str = "PRIVATE KEY";
+}
+
 impl<'a> fmt::Debug for PrivateKeyInfo<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("PrivateKeyInfo")
             .field("version", &self.version())
             .field("algorithm", &self.algorithm)
+            .field("attributes", &self.attributes)
             .field("public_key", &self.public_key)
             .finish_non_exhaustive()
     }
@@ -238,8 +284,9 @@ impl<'a> fmt::Debug for PrivateKeyInfo<'a> {
 impl<'a> ConstantTimeEq for PrivateKeyInfo<'a> {
     fn ct_eq(&self, other: &Self) -> Choice {
         // NOTE: public fields are not compared in constant time
-        let public_fields_eq =
-            self.algorithm == other.algorithm && self.public_key == other.public_key;
+        let public_fields_eq = self.algorithm == other.algorithm
+            && self.attributes == other.attributes
+            && self.public_key == other.public_key;
 
         self.private_key.ct_eq(other.private_key) & Choice::from(public_fields_eq as u8)
     }
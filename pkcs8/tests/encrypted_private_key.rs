@@ -215,6 +215,33 @@ fn encrypt_ed25519_der_encpriv_aes256_scrypt() {
     assert_eq!(pk_encrypted.as_ref(), ED25519_DER_AES256_SCRYPT_EXAMPLE);
 }
 
+#[cfg(feature = "encryption")]
+#[test]
+fn encrypt_and_decrypt_ed25519_der_encpriv_aes256gcm_pbkdf2_sha256() {
+    let pbes2_params = pkcs5::pbes2::Parameters::pbkdf2_sha256_aes256gcm(
+        2048,
+        &hex!("79d982e70df91a88"),
+        &hex!("b2d02d78b2efd9dff694cf8e"),
+    )
+    .unwrap();
+
+    let pk_plaintext = PrivateKeyDocument::try_from(ED25519_DER_PLAINTEXT_EXAMPLE).unwrap();
+    let pk_encrypted = pk_plaintext
+        .encrypt_with_params(pbes2_params.clone(), PASSWORD)
+        .unwrap();
+
+    let enc_pk = pk_encrypted.decode();
+    assert_eq!(
+        enc_pk.encryption_algorithm.pbes2().unwrap().encryption,
+        pbes2_params.encryption
+    );
+    assert_eq!(
+        enc_pk.decrypt(PASSWORD).unwrap().as_ref(),
+        ED25519_DER_PLAINTEXT_EXAMPLE
+    );
+    assert!(enc_pk.decrypt(b"hunter2").is_err());
+}
+
 #[test]
 #[cfg(feature = "alloc")]
 fn encode_ed25519_encpriv_aes256_pbkdf2_sha256_der() {
@@ -1,7 +1,7 @@
 //! PKCS#8 private key tests
 
 use hex_literal::hex;
-use pkcs8::{PrivateKeyInfo, Version};
+use pkcs8::{OneAsymmetricKey, PrivateKeyInfo, Version};
 
 #[cfg(feature = "pem")]
 use der::Document;
@@ -12,6 +12,9 @@ use pkcs8::PrivateKeyDocument;
 #[cfg(feature = "std")]
 use pkcs8::DecodePrivateKey;
 
+#[cfg(feature = "pem")]
+use pkcs8::{EncodePrivateKey, SecretDocument};
+
 /// Elliptic Curve (P-256) PKCS#8 private key encoded as ASN.1 DER
 const EC_P256_DER_EXAMPLE: &[u8] = include_bytes!("examples/p256-priv.der");
 
@@ -35,6 +38,10 @@ const EC_P256_PEM_EXAMPLE: &str = include_str!("examples/p256-priv.pem");
 #[cfg(feature = "pem")]
 const ED25519_PEM_V1_EXAMPLE: &str = include_str!("examples/ed25519-priv-pkcs8v1.pem");
 
+/// Ed25519 PKCS#8 v2 private key + public key encoded as PEM
+#[cfg(feature = "pem")]
+const ED25519_PEM_V2_EXAMPLE: &str = include_str!("examples/ed25519-priv-pkcs8v2.pem");
+
 /// RSA-2048 PKCS#8 private key encoded as PEM
 #[cfg(feature = "pem")]
 const RSA_2048_PEM_EXAMPLE: &str = include_str!("examples/rsa2048-priv.pem");
@@ -87,15 +94,39 @@ fn decode_ed25519_der_v2() {
         hex!("0420D4EE72DBF913584AD5B6D8F1F769F8AD3AFE7C28CBF1D4FBE097A88F44755842");
     const PUB_KEY: [u8; 32] =
         hex!("19BF44096984CDFE8541BAC167DC3B96C85086AA30B6B6CB0C5C38AD703166E1");
+    const ATTRIBUTES: [u8; 31] =
+        hex!("301D060A2A864886F70D01090914310F0C0D437572646C6520436861697273");
 
     let pk = PrivateKeyInfo::try_from(ED25519_DER_V2_EXAMPLE).unwrap();
     assert_eq!(pk.version(), Version::V2);
     assert_eq!(pk.algorithm.oid, "1.3.101.112".parse().unwrap());
     assert_eq!(pk.algorithm.parameters, None);
     assert_eq!(pk.private_key, PRIV_KEY);
+    assert_eq!(pk.attributes, Some(&ATTRIBUTES[..]));
     assert_eq!(pk.public_key, Some(&PUB_KEY[..]));
 }
 
+#[test]
+fn decode_ed25519_der_v1_with_attributes() {
+    // Attributes are permitted in v1 documents, which lack a public key
+    const ATTRIBUTES: [u8; 31] =
+        hex!("301D060A2A864886F70D01090914310F0C0D437572646C6520436861697273");
+
+    let mut der = hex!(
+        "304F020100300506032B657004220420D4EE72DBF913584AD5B6D8F1F769F8AD3AFE7C28CBF1D4FBE097A88F44755842"
+        "A01F301D060A2A864886F70D01090914310F0C0D437572646C6520436861697273"
+    );
+
+    let pk = OneAsymmetricKey::try_from(&der[..]).unwrap();
+    assert_eq!(pk.version(), Version::V1);
+    assert_eq!(pk.attributes, Some(&ATTRIBUTES[..]));
+    assert_eq!(pk.public_key, None);
+
+    // A primitive `[0]` tag is not a valid `SET OF Attribute`
+    der[48] = 0x80;
+    assert!(OneAsymmetricKey::try_from(&der[..]).is_err());
+}
+
 #[test]
 fn decode_rsa_2048_der() {
     let pk = PrivateKeyInfo::try_from(RSA_2048_DER_EXAMPLE).unwrap();
@@ -187,6 +218,20 @@ fn encode_ed25519_der_v1() {
 fn encode_ed25519_der_v2() {
     let pk = PrivateKeyInfo::try_from(ED25519_DER_V2_EXAMPLE).unwrap();
     assert_eq!(pk.to_der().unwrap().decode(), pk);
+    assert_eq!(ED25519_DER_V2_EXAMPLE, pk.to_der().unwrap().as_ref());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn encode_ed25519_der_v2_from_parts() {
+    let v2 = PrivateKeyInfo::try_from(ED25519_DER_V2_EXAMPLE).unwrap();
+
+    let mut pk = OneAsymmetricKey::new(v2.algorithm, v2.private_key);
+    pk.attributes = v2.attributes;
+    pk.public_key = v2.public_key;
+
+    assert_eq!(pk.version(), Version::V2);
+    assert_eq!(ED25519_DER_V2_EXAMPLE, pk.to_der().unwrap().as_ref());
 }
 
 #[test]
@@ -216,6 +261,16 @@ fn encode_ed25519_pem() {
     );
 }
 
+#[test]
+#[cfg(feature = "pem")]
+fn encode_ed25519_pem_v2() {
+    let pk = PrivateKeyInfo::try_from(ED25519_DER_V2_EXAMPLE).unwrap();
+    assert_eq!(
+        ED25519_PEM_V2_EXAMPLE,
+        &*pk.to_pem(Default::default()).unwrap()
+    );
+}
+
 #[test]
 #[cfg(feature = "pem")]
 fn encode_rsa_2048_pem() {
@@ -241,3 +296,40 @@ fn read_pem_file() {
         PrivateKeyDocument::read_pkcs8_pem_file("tests/examples/p256-priv.pem").unwrap();
     assert_eq!(pkcs8_doc.as_ref(), EC_P256_DER_EXAMPLE);
 }
+
+#[test]
+#[cfg(feature = "pem")]
+fn secret_document_round_trip() {
+    let doc: SecretDocument = RSA_2048_PEM_EXAMPLE.parse().unwrap();
+    assert_eq!(doc.as_ref(), RSA_2048_DER_EXAMPLE);
+    assert_eq!(doc.clone().as_bytes(), RSA_2048_DER_EXAMPLE);
+    assert_eq!(doc.decode_msg().unwrap().private_key.len(), 1191);
+    assert_eq!(format!("{:?}", doc), "SecretDocument { .. }");
+    assert_eq!(
+        RSA_2048_PEM_EXAMPLE,
+        &*doc.to_pkcs8_pem(Default::default()).unwrap()
+    );
+
+    let pkcs8_doc: PrivateKeyDocument = RSA_2048_PEM_EXAMPLE.parse().unwrap();
+    assert_eq!(
+        SecretDocument::from(pkcs8_doc).as_ref(),
+        RSA_2048_DER_EXAMPLE
+    );
+}
+
+#[test]
+#[cfg(all(unix, feature = "pem", feature = "std"))]
+fn secret_document_write_pem_file() {
+    use std::{fs, os::unix::fs::PermissionsExt};
+
+    let doc = SecretDocument::from_pkcs8_der(EC_P256_DER_EXAMPLE).unwrap();
+    let path = std::env::temp_dir().join(format!("pkcs8-secret-{}.pem", std::process::id()));
+    doc.write_pkcs8_pem_file(&path, Default::default()).unwrap();
+
+    let mode = fs::metadata(&path).unwrap().permissions().mode();
+    let written = SecretDocument::read_pkcs8_pem_file(&path);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(mode & 0o777, 0o600);
+    assert_eq!(written.unwrap().as_ref(), EC_P256_DER_EXAMPLE);
+}
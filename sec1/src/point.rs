@@ -124,11 +124,11 @@ where
         let tag = if compress {
             Tag::compress_y(y.as_slice())
         } else {
-            Tag::Uncompressed
+            Tag::Uncompressed.into()
         };
 
         let mut bytes = GenericArray::default();
-        bytes[0] = tag.into();
+        bytes[0] = tag;
         bytes[1..(Size::to_usize() + 1)].copy_from_slice(x);
 
         if !compress {
@@ -587,14 +587,15 @@ impl Tag {
         }
     }
 
-    /// Compress the given y-coordinate, returning a `Tag::Compressed*` value
-    fn compress_y(y: &[u8]) -> Self {
-        // Is the y-coordinate odd in the SEC1 sense: `self mod 2 == 1`?
-        if y.as_ref().last().expect("empty y-coordinate") & 1 == 1 {
-            Tag::CompressedOddY
-        } else {
-            Tag::CompressedEvenY
-        }
+    /// Compress the given y-coordinate, returning the byte value of the
+    /// corresponding `Tag::Compressed*` variant.
+    ///
+    /// This is computed without branching on the value of the y-coordinate.
+    fn compress_y(y: &[u8]) -> u8 {
+        // The y-coordinate is odd in the SEC1 sense if `y mod 2 == 1`, in which
+        // case this selects `Tag::CompressedOddY` (`0x03`) over
+        // `Tag::CompressedEvenY` (`0x02`)
+        u8::from(Tag::CompressedEvenY) | (y.last().expect("empty y-coordinate") & 1)
     }
 }
 
@@ -785,6 +786,16 @@ mod tests {
         assert_eq!(compressed_point.as_bytes(), &COMPRESSED_BYTES[..]);
     }
 
+    #[test]
+    fn compress_odd_y() {
+        let mut bytes = UNCOMPRESSED_BYTES;
+        bytes[64] = 0x23;
+
+        let compressed_point = EncodedPoint::from_bytes(&bytes[..]).unwrap().compress();
+        assert_eq!(compressed_point.tag(), Tag::CompressedOddY);
+        assert_eq!(&compressed_point.as_bytes()[1..], &COMPRESSED_BYTES[1..]);
+    }
+
     #[cfg(feature = "subtle")]
     #[test]
     fn conditional_select() {
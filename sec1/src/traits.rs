@@ -110,6 +110,7 @@ impl<T: pkcs8::DecodePrivateKey> DecodeEcPrivateKey for T {
         Ok(Self::try_from(pkcs8::PrivateKeyInfo {
             algorithm,
             private_key,
+            attributes: None,
             public_key: None,
         })?)
     }
@@ -2,7 +2,7 @@
 
 #![cfg(feature = "der")]
 
-use der::asn1::ObjectIdentifier;
+use der::{asn1::ObjectIdentifier, Encode};
 use hex_literal::hex;
 use sec1::{EcParameters, EcPrivateKey};
 
@@ -48,3 +48,28 @@ fn decode_p256_pem() {
     let pk = EcPrivateKey::try_from(P256_DER_EXAMPLE).unwrap();
     assert_eq!(sec1_doc.decode().private_key, pk.private_key);
 }
+
+#[test]
+fn encode_p256_der() {
+    let mut buf = [0u8; 256];
+    let key = EcPrivateKey::try_from(P256_DER_EXAMPLE).unwrap();
+    assert_eq!(key.encode_to_slice(&mut buf).unwrap(), P256_DER_EXAMPLE);
+}
+
+#[test]
+fn decode_p256_der_without_optional_fields() {
+    // `ECPrivateKey` containing only the `version` and `privateKey` fields
+    let bytes =
+        hex!("3025020101042069624171561A63340DE0E7D869F2A05492558E1A04868B6A9F854A866788188D");
+    let key = EcPrivateKey::try_from(&bytes[..]).unwrap();
+
+    assert_eq!(
+        key.private_key,
+        hex!("69624171561A63340DE0E7D869F2A05492558E1A04868B6A9F854A866788188D")
+    );
+    assert_eq!(key.parameters, None);
+    assert_eq!(key.public_key, None);
+
+    let mut buf = [0u8; 64];
+    assert_eq!(key.encode_to_slice(&mut buf).unwrap(), &bytes[..]);
+}
@@ -26,15 +26,21 @@ pub struct AlgorithmIdentifier<'a> {
 
 impl<'a> AlgorithmIdentifier<'a> {
     /// Assert the `algorithm` OID is an expected value.
+    ///
+    /// Returns [`Error::OidUnknown`] containing the actual `algorithm` OID
+    /// if it doesn't match.
     pub fn assert_algorithm_oid(&self, expected_oid: ObjectIdentifier) -> Result<ObjectIdentifier> {
         if self.oid == expected_oid {
             Ok(expected_oid)
         } else {
-            Err(Error::OidUnknown { oid: expected_oid })
+            Err(Error::OidUnknown { oid: self.oid })
         }
     }
 
     /// Assert `parameters` is an OID and has the expected value.
+    ///
+    /// Returns [`Error::OidUnknown`] containing the actual `parameters` OID
+    /// if it doesn't match.
     pub fn assert_parameters_oid(
         &self,
         expected_oid: ObjectIdentifier,
@@ -44,7 +50,7 @@ impl<'a> AlgorithmIdentifier<'a> {
         if actual_oid == expected_oid {
             Ok(actual_oid)
         } else {
-            Err(Error::OidUnknown { oid: expected_oid })
+            Err(Error::OidUnknown { oid: actual_oid })
         }
     }
 
@@ -5,12 +5,20 @@ use alloc::vec::Vec;
 use core::fmt;
 use der::{Decode, Document};
 
+#[cfg(any(feature = "fingerprint", feature = "pem"))]
+use alloc::string::String;
+
+#[cfg(feature = "fingerprint")]
+use {
+    base64ct::{Base64, Encoding},
+    sha2::{digest, Digest, Sha256},
+};
+
 #[cfg(feature = "std")]
 use std::path::Path;
 
 #[cfg(feature = "pem")]
 use {
-    alloc::string::String,
     core::str::FromStr,
     der::pem::{self, LineEnding},
 };
@@ -24,6 +32,28 @@ use {
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 pub struct PublicKeyDocument(Vec<u8>);
 
+impl PublicKeyDocument {
+    /// Calculate the SHA-256 fingerprint of this document.
+    ///
+    /// Unlike [`SubjectPublicKeyInfo::fingerprint`], this hashes the
+    /// stored DER directly rather than re-encoding it.
+    #[cfg(feature = "fingerprint")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fingerprint")))]
+    pub fn fingerprint(&self) -> digest::Output<Sha256> {
+        Sha256::digest(self.as_ref())
+    }
+
+    /// Calculate the SHA-256 fingerprint of this document and encode it as a
+    /// Base64 string, e.g. for use as an [RFC 7469] `pin-sha256` directive.
+    ///
+    /// [RFC 7469]: https://datatracker.ietf.org/doc/html/rfc7469#section-2.4
+    #[cfg(feature = "fingerprint")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fingerprint")))]
+    pub fn fingerprint_base64(&self) -> String {
+        Base64::encode_string(self.fingerprint().as_slice())
+    }
+}
+
 impl<'a> Document<'a> for PublicKeyDocument {
     type Message = SubjectPublicKeyInfo<'a>;
     const SENSITIVE: bool = false;
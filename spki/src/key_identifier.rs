@@ -0,0 +1,95 @@
+//! Key identifiers as defined in RFC 5280.
+
+use core::fmt;
+use sha1::{Digest, Sha1};
+
+/// Size of a SHA-1 digest.
+const SHA1_LEN: usize = 20;
+
+/// Size of a key identifier computed using the truncated method.
+const TRUNCATED_LEN: usize = 8;
+
+/// Key identifier computed from a public key, for use in the
+/// `SubjectKeyIdentifier` and `AuthorityKeyIdentifier` certificate
+/// extensions.
+///
+/// [RFC 5280 Section 4.2.1.2] describes two common methods for computing
+/// these from a [`SubjectPublicKeyInfo`][`crate::SubjectPublicKeyInfo`]:
+///
+/// 1. The 160-bit SHA-1 hash of the value of the `subjectPublicKey`
+///    BIT STRING (excluding the tag, length, and number of unused bits).
+///    See [`SubjectPublicKeyInfo::key_identifier`][`crate::SubjectPublicKeyInfo::key_identifier`].
+/// 2. A four-bit type field with the value `0100` followed by the least
+///    significant 60 bits of that hash. See
+///    [`SubjectPublicKeyInfo::key_identifier_truncated`][`crate::SubjectPublicKeyInfo::key_identifier_truncated`].
+///
+/// Key identifiers compare equal only if they were computed using the same
+/// method, so when matching identifiers found in certificates compare
+/// against [`KeyIdentifier::as_bytes`].
+///
+/// [RFC 5280 Section 4.2.1.2]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.2
+#[derive(Copy, Clone, Eq, Hash, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "fingerprint")))]
+pub struct KeyIdentifier {
+    /// Identifier bytes, followed by zeroes.
+    bytes: [u8; SHA1_LEN],
+
+    /// Length of the identifier.
+    length: usize,
+}
+
+impl KeyIdentifier {
+    /// Compute a key identifier using method 1: the SHA-1 hash of the given
+    /// public key bytes.
+    pub(crate) fn sha1(public_key: &[u8]) -> Self {
+        Self {
+            bytes: Sha1::digest(public_key).into(),
+            length: SHA1_LEN,
+        }
+    }
+
+    /// Compute a key identifier using method 2: the type field `0100`
+    /// followed by the least significant 60 bits of the SHA-1 hash of the
+    /// given public key bytes.
+    pub(crate) fn truncated(public_key: &[u8]) -> Self {
+        let digest = Sha1::digest(public_key);
+        let mut bytes = [0u8; SHA1_LEN];
+
+        for (byte, digest_byte) in bytes
+            .iter_mut()
+            .zip(digest.iter().skip(SHA1_LEN - TRUNCATED_LEN))
+        {
+            *byte = *digest_byte;
+        }
+
+        bytes[0] = 0x40 | (bytes[0] & 0x0F);
+
+        Self {
+            bytes,
+            length: TRUNCATED_LEN,
+        }
+    }
+
+    /// Borrow the bytes of this key identifier.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.length]
+    }
+}
+
+impl AsRef<[u8]> for KeyIdentifier {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl fmt::Debug for KeyIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("KeyIdentifier(")?;
+
+        for byte in self.as_bytes() {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        f.write_str(")")
+    }
+}
@@ -40,6 +40,9 @@ mod traits;
 #[cfg(feature = "alloc")]
 mod document;
 
+#[cfg(feature = "fingerprint")]
+mod key_identifier;
+
 pub use crate::{
     algorithm::AlgorithmIdentifier,
     error::{Error, Result},
@@ -50,3 +53,6 @@ pub use der::{self, asn1::ObjectIdentifier};
 
 #[cfg(feature = "alloc")]
 pub use crate::{document::PublicKeyDocument, traits::EncodePublicKey};
+
+#[cfg(feature = "fingerprint")]
+pub use crate::key_identifier::KeyIdentifier;
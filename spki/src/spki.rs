@@ -5,7 +5,10 @@ use core::cmp::Ordering;
 use der::{asn1::BitString, Decode, Decoder, DerOrd, Encode, Sequence, ValueOrd};
 
 #[cfg(feature = "fingerprint")]
-use sha2::{digest, Digest, Sha256};
+use {
+    crate::KeyIdentifier,
+    sha2::{digest, Digest, Sha256},
+};
 
 #[cfg(all(feature = "alloc", feature = "fingerprint"))]
 use {
@@ -51,6 +54,31 @@ impl<'a> SubjectPublicKeyInfo<'a> {
         Ok(Base64::encode_string(self.fingerprint()?.as_slice()))
     }
 
+    /// Compute the [`KeyIdentifier`] of this [`SubjectPublicKeyInfo`] using
+    /// [RFC 5280 Section 4.2.1.2] method 1, i.e. the SHA-1 hash of the
+    /// `subject_public_key`.
+    ///
+    /// This is the method most commonly used by certificate authorities.
+    ///
+    /// [RFC 5280 Section 4.2.1.2]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.2
+    #[cfg(feature = "fingerprint")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fingerprint")))]
+    pub fn key_identifier(&self) -> KeyIdentifier {
+        KeyIdentifier::sha1(self.subject_public_key)
+    }
+
+    /// Compute the [`KeyIdentifier`] of this [`SubjectPublicKeyInfo`] using
+    /// [RFC 5280 Section 4.2.1.2] method 2, i.e. the type field `0100`
+    /// followed by the least significant 60 bits of the SHA-1 hash of the
+    /// `subject_public_key`.
+    ///
+    /// [RFC 5280 Section 4.2.1.2]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.2
+    #[cfg(feature = "fingerprint")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fingerprint")))]
+    pub fn key_identifier_truncated(&self) -> KeyIdentifier {
+        KeyIdentifier::truncated(self.subject_public_key)
+    }
+
     /// Get a [`BitString`] representing the `subject_public_key`
     fn subject_public_key_bitstring(&self) -> der::Result<BitString<'a>> {
         BitString::from_bytes(self.subject_public_key)
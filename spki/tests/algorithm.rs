@@ -0,0 +1,85 @@
+//! `AlgorithmIdentifier` tests.
+
+use spki::{der::asn1::Any, AlgorithmIdentifier, Error, ObjectIdentifier};
+
+/// `id-ecPublicKey` Object Identifier (OID)
+const EC_PUBLIC_KEY_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+
+/// `prime256v1` Object Identifier (OID)
+const P256_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+
+/// `secp384r1` Object Identifier (OID)
+const P384_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.34");
+
+/// `rsaEncryption` Object Identifier (OID)
+const RSA_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+
+fn p256_algorithm_id() -> AlgorithmIdentifier<'static> {
+    AlgorithmIdentifier {
+        oid: EC_PUBLIC_KEY_OID,
+        parameters: Some(Any::from(&P256_OID)),
+    }
+}
+
+#[test]
+fn assert_algorithm_oid() {
+    let alg_id = p256_algorithm_id();
+    assert_eq!(
+        alg_id.assert_algorithm_oid(EC_PUBLIC_KEY_OID),
+        Ok(EC_PUBLIC_KEY_OID)
+    );
+    assert_eq!(
+        alg_id.assert_algorithm_oid(RSA_OID),
+        Err(Error::OidUnknown {
+            oid: EC_PUBLIC_KEY_OID
+        })
+    );
+}
+
+#[test]
+fn assert_parameters_oid() {
+    let alg_id = p256_algorithm_id();
+    assert_eq!(alg_id.assert_parameters_oid(P256_OID), Ok(P256_OID));
+    assert_eq!(
+        alg_id.assert_parameters_oid(P384_OID),
+        Err(Error::OidUnknown { oid: P256_OID })
+    );
+}
+
+#[test]
+fn assert_oids() {
+    let alg_id = p256_algorithm_id();
+    assert!(alg_id.assert_oids(EC_PUBLIC_KEY_OID, P256_OID).is_ok());
+    assert!(alg_id.assert_oids(RSA_OID, P256_OID).is_err());
+    assert!(alg_id.assert_oids(EC_PUBLIC_KEY_OID, P384_OID).is_err());
+}
+
+#[test]
+fn parameters_missing() {
+    let alg_id = AlgorithmIdentifier {
+        oid: EC_PUBLIC_KEY_OID,
+        parameters: None,
+    };
+
+    assert_eq!(
+        alg_id.parameters_any(),
+        Err(Error::AlgorithmParametersMissing)
+    );
+    assert_eq!(
+        alg_id.parameters_oid(),
+        Err(Error::AlgorithmParametersMissing)
+    );
+    assert_eq!(alg_id.oids().unwrap(), (EC_PUBLIC_KEY_OID, None));
+}
+
+#[test]
+fn parameters_not_oid() {
+    let alg_id = AlgorithmIdentifier {
+        oid: RSA_OID,
+        parameters: Some(Any::NULL),
+    };
+
+    assert!(alg_id.parameters_any().unwrap().is_null());
+    assert!(matches!(alg_id.parameters_oid(), Err(Error::Asn1(_))));
+    assert_eq!(alg_id.oids().unwrap(), (RSA_OID, None));
+}
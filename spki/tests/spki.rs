@@ -6,8 +6,11 @@ use spki::der::Encode;
 #[cfg(feature = "fingerprint")]
 use {hex_literal::hex, spki::SubjectPublicKeyInfo};
 
+#[cfg(all(feature = "alloc", feature = "fingerprint"))]
+use spki::PublicKeyDocument;
+
 #[cfg(all(feature = "pem", feature = "fingerprint"))]
-use spki::{der::Document, EncodePublicKey, PublicKeyDocument};
+use spki::{der::Document, EncodePublicKey};
 
 #[cfg(feature = "fingerprint")]
 // Taken from pkcs8/tests/public_key.rs
@@ -71,6 +74,36 @@ fn decode_and_fingerprint_spki() {
     );
 }
 
+#[test]
+#[cfg(feature = "fingerprint")]
+fn key_identifiers() {
+    let spki = SubjectPublicKeyInfo::try_from(ED25519_DER_EXAMPLE).unwrap();
+
+    // Generated using `tail -c 32 ed25519-pub.der | openssl dgst -sha1`
+    let key_id = spki.key_identifier();
+    assert_eq!(
+        key_id.as_bytes(),
+        hex!("4A245D980086AC2E62F9F6412928FFEC5895FF09")
+    );
+    assert_eq!(
+        format!("{:?}", key_id),
+        "KeyIdentifier(4A245D980086AC2E62F9F6412928FFEC5895FF09)"
+    );
+
+    // Least significant 60 bits of the above, prefixed by `0100`
+    let key_id = spki.key_identifier_truncated();
+    assert_eq!(key_id.as_bytes(), hex!("4928FFEC5895FF09"));
+    assert_ne!(key_id, spki.key_identifier());
+}
+
+#[test]
+#[cfg(all(feature = "alloc", feature = "fingerprint"))]
+fn fingerprint_public_key_document() {
+    let doc = PublicKeyDocument::try_from(ED25519_DER_EXAMPLE).unwrap();
+    assert_eq!(doc.fingerprint().as_slice(), ED25519_SPKI_FINGERPRINT);
+    assert_eq!(doc.fingerprint_base64(), ED25519_SPKI_FINGERPRINT_BASE64);
+}
+
 #[test]
 #[cfg(all(feature = "pem", feature = "fingerprint"))]
 fn decode_ed25519_pem() {
@@ -25,6 +25,24 @@ const ECDSA_SHA2_P521: &str = "ecdsa-sha2-nistp521";
 /// None
 const NONE: &str = "none";
 
+/// OpenSSH certificate for DSA public key
+const CERT_DSA: &str = "ssh-dss-cert-v01@openssh.com";
+
+/// OpenSSH certificate for ECDSA (NIST P-256) public key
+const CERT_ECDSA_SHA2_P256: &str = "ecdsa-sha2-nistp256-cert-v01@openssh.com";
+
+/// OpenSSH certificate for ECDSA (NIST P-384) public key
+const CERT_ECDSA_SHA2_P384: &str = "ecdsa-sha2-nistp384-cert-v01@openssh.com";
+
+/// OpenSSH certificate for ECDSA (NIST P-521) public key
+const CERT_ECDSA_SHA2_P521: &str = "ecdsa-sha2-nistp521-cert-v01@openssh.com";
+
+/// OpenSSH certificate for Ed25519 public key
+const CERT_ED25519: &str = "ssh-ed25519-cert-v01@openssh.com";
+
+/// OpenSSH certificate for RSA public key
+const CERT_RSA: &str = "ssh-rsa-cert-v01@openssh.com";
+
 /// SHA-256 hash function.
 const SHA256: &str = "SHA256";
 
@@ -89,6 +107,11 @@ pub enum Algorithm {
 }
 
 impl Algorithm {
+    /// Maximum length of a certificate algorithm string:
+    /// `ecdsa-sha2-nistpXXX-cert-v01@openssh.com` (40 chars)
+    #[cfg(feature = "alloc")]
+    pub(crate) const MAX_CERT_SIZE: usize = 40;
+
     /// Decode algorithm from the given string identifier.
     ///
     /// # Supported algorithms
@@ -122,6 +145,41 @@ impl Algorithm {
         }
     }
 
+    /// Decode algorithm from the given OpenSSH certificate algorithm
+    /// identifier.
+    ///
+    /// # Supported algorithms
+    /// - `ecdsa-sha2-nistp256-cert-v01@openssh.com`
+    /// - `ecdsa-sha2-nistp384-cert-v01@openssh.com`
+    /// - `ecdsa-sha2-nistp521-cert-v01@openssh.com`
+    /// - `ssh-dss-cert-v01@openssh.com`
+    /// - `ssh-ed25519-cert-v01@openssh.com`
+    /// - `ssh-rsa-cert-v01@openssh.com`
+    pub fn new_certificate(id: &str) -> Result<Self> {
+        match id {
+            CERT_DSA => Ok(Algorithm::Dsa),
+            CERT_ECDSA_SHA2_P256 => Ok(Algorithm::Ecdsa(EcdsaCurve::NistP256)),
+            CERT_ECDSA_SHA2_P384 => Ok(Algorithm::Ecdsa(EcdsaCurve::NistP384)),
+            CERT_ECDSA_SHA2_P521 => Ok(Algorithm::Ecdsa(EcdsaCurve::NistP521)),
+            CERT_ED25519 => Ok(Algorithm::Ed25519),
+            CERT_RSA => Ok(Algorithm::Rsa),
+            _ => Err(Error::Algorithm),
+        }
+    }
+
+    /// Get the OpenSSH certificate algorithm identifier which corresponds to
+    /// this algorithm.
+    pub fn as_certificate_str(self) -> &'static str {
+        match self {
+            Algorithm::Dsa => CERT_DSA,
+            Algorithm::Ecdsa(EcdsaCurve::NistP256) => CERT_ECDSA_SHA2_P256,
+            Algorithm::Ecdsa(EcdsaCurve::NistP384) => CERT_ECDSA_SHA2_P384,
+            Algorithm::Ecdsa(EcdsaCurve::NistP521) => CERT_ECDSA_SHA2_P521,
+            Algorithm::Ed25519 => CERT_ED25519,
+            Algorithm::Rsa => CERT_RSA,
+        }
+    }
+
     /// Is the algorithm DSA?
     pub fn is_dsa(self) -> bool {
         self == Algorithm::Dsa
@@ -203,6 +261,15 @@ impl CipherAlg {
         }
     }
 
+    /// Get the block size used by this algorithm, which private keys are
+    /// padded to a multiple of.
+    pub fn block_size(self) -> usize {
+        match self {
+            Self::None => 8,
+            Self::Aes256Ctr => 16,
+        }
+    }
+
     /// Is the cipher algorithm "none"?
     pub fn is_none(self) -> bool {
         self == Self::None
@@ -0,0 +1,577 @@
+//! OpenSSH certificate support.
+//!
+//! Support for decoding and encoding OpenSSH certificates as described in
+//! [PROTOCOL.certkeys]:
+//!
+//! <https://github.com/openssh/openssh-portable/blob/master/PROTOCOL.certkeys>
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use crate::{
+    decoder::{Base64Decoder, Decode, Decoder},
+    encoder::{encoded_len, Encode, Encoder},
+    public::{openssh::Encapsulation, KeyData},
+    Algorithm, Error, Result,
+};
+use alloc::{borrow::ToOwned, collections::BTreeMap, string::String, vec::Vec};
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "std")]
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Key/value map type used for certificate's critical options and extensions.
+///
+/// Flag options, which have no data, map to `None`. This is distinct from an
+/// option whose data is an empty `string`, which maps to `Some("")`.
+pub type OptionsMap = BTreeMap<String, Option<String>>;
+
+/// OpenSSH certificate as specified in [PROTOCOL.certkeys].
+///
+/// OpenSSH supports X.509-like certificate authorities, but using a custom
+/// encoding format.
+///
+/// [PROTOCOL.certkeys]: https://github.com/openssh/openssh-portable/blob/master/PROTOCOL.certkeys
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Certificate {
+    /// CA-provided random bitstring of arbitrary length
+    /// (but typically 16 or 32 bytes).
+    nonce: Vec<u8>,
+
+    /// Public key data.
+    public_key: KeyData,
+
+    /// Serial number.
+    serial: u64,
+
+    /// Certificate type.
+    cert_type: CertType,
+
+    /// Key ID.
+    key_id: String,
+
+    /// Valid principals.
+    valid_principals: Vec<String>,
+
+    /// Valid after (Unix time).
+    valid_after: u64,
+
+    /// Valid before (Unix time).
+    valid_before: u64,
+
+    /// Critical options.
+    critical_options: OptionsMap,
+
+    /// Extensions.
+    extensions: OptionsMap,
+
+    /// Reserved field.
+    reserved: Vec<u8>,
+
+    /// Signature key of signing CA.
+    signature_key: KeyData,
+
+    /// Signature over the certificate.
+    signature: Signature,
+
+    /// Comment on the certificate.
+    comment: String,
+}
+
+impl Certificate {
+    /// Parse an OpenSSH-formatted certificate.
+    ///
+    /// OpenSSH-formatted certificates look like the following
+    /// (i.e. similar to OpenSSH public keys with `-cert-v01@openssh.com`
+    /// added to the algorithm identifier):
+    ///
+    /// ```text
+    /// ssh-ed25519-cert-v01@openssh.com AAAAIHNzaC1lZDI1NTE5LWNlc...8REbCaAw== user@example.com
+    /// ```
+    pub fn from_openssh(input: impl AsRef<[u8]>) -> Result<Self> {
+        let encapsulation = Encapsulation::decode(input.as_ref())?;
+        let mut decoder = Base64Decoder::new(encapsulation.base64_data)?;
+        let mut certificate = Self::decode(&mut decoder)?;
+
+        if !decoder.is_finished() {
+            return Err(Error::Length);
+        }
+
+        // Verify that the algorithm in the Base64-encoded data matches the text
+        if encapsulation.algorithm_id != certificate.algorithm().as_certificate_str() {
+            return Err(Error::Algorithm);
+        }
+
+        certificate.comment = encapsulation.comment.to_owned();
+        Ok(certificate)
+    }
+
+    /// Encode OpenSSH-formatted certificate.
+    pub fn encode_openssh<'o>(&self, out: &'o mut [u8]) -> Result<&'o str> {
+        Encapsulation::encode(
+            out,
+            self.algorithm().as_certificate_str(),
+            self.comment(),
+            |encoder| self.encode(encoder),
+        )
+    }
+
+    /// Encode an OpenSSH-formatted certificate, allocating a [`String`] for
+    /// the result.
+    pub fn to_openssh(&self) -> Result<String> {
+        let alg_len = self.algorithm().as_certificate_str().len();
+        let cert_data_len = encoded_len(self.encoded_len()?);
+        let comment_len = self.comment.len();
+        let encoded_len = 2 + alg_len + cert_data_len + comment_len;
+
+        let mut buf = vec![0u8; encoded_len];
+        let actual_len = self.encode_openssh(&mut buf)?.len();
+        buf.truncate(actual_len);
+        Ok(String::from_utf8(buf)?)
+    }
+
+    /// Read certificate from an OpenSSH-formatted file.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read_openssh_file(path: &Path) -> Result<Self> {
+        let input = fs::read_to_string(path)?;
+        Self::from_openssh(&*input)
+    }
+
+    /// Write certificate as an OpenSSH-formatted file.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write_openssh_file(&self, path: &Path) -> Result<()> {
+        let encoded = self.to_openssh()?;
+        fs::write(path, encoded.as_bytes())?;
+        Ok(())
+    }
+
+    /// Get the digital signature [`Algorithm`] of the certified public key.
+    pub fn algorithm(&self) -> Algorithm {
+        self.public_key.algorithm()
+    }
+
+    /// Comment on the certificate (e.g. email address).
+    pub fn comment(&self) -> &str {
+        &self.comment
+    }
+
+    /// CA-provided random bitstring of arbitrary length
+    /// (but typically 16 or 32 bytes).
+    ///
+    /// This prevents hash collision attacks against the signature.
+    pub fn nonce(&self) -> &[u8] {
+        &self.nonce
+    }
+
+    /// Get the public key data of the certified key.
+    pub fn public_key(&self) -> &KeyData {
+        &self.public_key
+    }
+
+    /// Optional certificate serial number set by the CA to provide an
+    /// abbreviated way to refer to certificates from that CA.
+    ///
+    /// If a CA does not wish to number its certificates, it must set this
+    /// field to zero.
+    pub fn serial(&self) -> u64 {
+        self.serial
+    }
+
+    /// Specifies whether this certificate is for identification of a user
+    /// or a host.
+    pub fn cert_type(&self) -> CertType {
+        self.cert_type
+    }
+
+    /// Key ID: free-form text field that is filled in by the CA at the time
+    /// of signing, which is intended to be used for identifying the
+    /// identity in log messages.
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+
+    /// List of zero or more principals which this certificate is valid for.
+    ///
+    /// Principals are hostnames for host certificates and usernames for user
+    /// certificates. As a special case, a zero-length "valid principals"
+    /// field means the certificate is valid for any principal of the
+    /// specified type.
+    pub fn valid_principals(&self) -> &[String] {
+        &self.valid_principals
+    }
+
+    /// Valid after (Unix time), i.e. the start of the validity window.
+    pub fn valid_after(&self) -> u64 {
+        self.valid_after
+    }
+
+    /// Valid before (Unix time), i.e. the end of the validity window.
+    pub fn valid_before(&self) -> u64 {
+        self.valid_before
+    }
+
+    /// Valid after (system time).
+    ///
+    /// Returns `None` if the time is not representable as a [`SystemTime`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn valid_after_time(&self) -> Option<SystemTime> {
+        UNIX_EPOCH.checked_add(Duration::from_secs(self.valid_after))
+    }
+
+    /// Valid before (system time).
+    ///
+    /// Returns `None` if the time is not representable as a [`SystemTime`],
+    /// e.g. for certificates which are valid "forever" (`u64::MAX`).
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn valid_before_time(&self) -> Option<SystemTime> {
+        UNIX_EPOCH.checked_add(Duration::from_secs(self.valid_before))
+    }
+
+    /// Is this certificate valid at the given Unix time?
+    ///
+    /// This only checks the validity window and does NOT verify the
+    /// signature or any other properties of the certificate.
+    pub fn is_valid_at(&self, unix_secs: u64) -> bool {
+        self.valid_after <= unix_secs && unix_secs < self.valid_before
+    }
+
+    /// Critical options: zero or more options which are checked by the
+    /// implementation, which must refuse to authorize a certificate
+    /// containing an option it does not recognize.
+    pub fn critical_options(&self) -> &OptionsMap {
+        &self.critical_options
+    }
+
+    /// Extensions: zero or more optional extensions, which implementations
+    /// should ignore if they do not recognize them.
+    pub fn extensions(&self) -> &OptionsMap {
+        &self.extensions
+    }
+
+    /// Signature key of signing CA.
+    pub fn signature_key(&self) -> &KeyData {
+        &self.signature_key
+    }
+
+    /// Signature computed over all preceding fields of the certificate.
+    pub fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    /// Get the signing input of this certificate: the encoding of all fields
+    /// from the algorithm identifier up to and including the signature key.
+    ///
+    /// This is the message which [`Certificate::signature`] is computed over.
+    pub fn signed_data(&self) -> Result<Vec<u8>> {
+        let mut signed_data = Vec::new();
+        self.encode_signed_data(&mut signed_data)?;
+        Ok(signed_data)
+    }
+
+    /// Encode the fields of the certificate which are covered by the
+    /// signature.
+    fn encode_signed_data(&self, encoder: &mut impl Encoder) -> Result<()> {
+        encoder.encode_str(self.algorithm().as_certificate_str())?;
+        encoder.encode_byte_slice(&self.nonce)?;
+        self.public_key.encode_key_data(encoder)?;
+        encoder.encode_u64(self.serial)?;
+        encoder.encode_u32(self.cert_type.into())?;
+        encoder.encode_str(&self.key_id)?;
+        encoder.encode_nested(|nested| {
+            for principal in &self.valid_principals {
+                nested.encode_str(principal)?;
+            }
+            Ok(())
+        })?;
+        encoder.encode_u64(self.valid_after)?;
+        encoder.encode_u64(self.valid_before)?;
+        encode_options(encoder, &self.critical_options)?;
+        encode_options(encoder, &self.extensions)?;
+        encoder.encode_byte_slice(&self.reserved)?;
+        encoder.encode_nested(|nested| self.signature_key.encode(nested))
+    }
+}
+
+impl Decode for Certificate {
+    fn decode(decoder: &mut impl Decoder) -> Result<Self> {
+        let mut buf = [0u8; Algorithm::MAX_CERT_SIZE];
+        let algorithm = decoder
+            .decode_str(&mut buf)
+            .map_err(|_| Error::Algorithm)
+            .and_then(Algorithm::new_certificate)?;
+
+        let nonce = decoder.decode_byte_vec()?;
+        let public_key = KeyData::decode_as(decoder, algorithm)?;
+        let serial = decoder.decode_u64()?;
+        let cert_type = CertType::try_from(decoder.decode_u32()?)?;
+        let key_id = decoder.decode_string()?;
+        let valid_principals = decoder.decode_nested(|nested| {
+            let mut principals = Vec::new();
+
+            while !nested.is_finished() {
+                principals.push(nested.decode_string()?);
+            }
+
+            Ok(principals)
+        })?;
+        let valid_after = decoder.decode_u64()?;
+        let valid_before = decoder.decode_u64()?;
+        let critical_options = decode_options(decoder)?;
+        let extensions = decode_options(decoder)?;
+        let reserved = decoder.decode_byte_vec()?;
+        let signature_key = decoder.decode_nested(|nested| KeyData::decode(nested))?;
+        let signature = decoder.decode_nested(|nested| Signature::decode(nested))?;
+
+        Ok(Self {
+            nonce,
+            public_key,
+            serial,
+            cert_type,
+            key_id,
+            valid_principals,
+            valid_after,
+            valid_before,
+            critical_options,
+            extensions,
+            reserved,
+            signature_key,
+            signature,
+            comment: String::new(),
+        })
+    }
+}
+
+impl Encode for Certificate {
+    fn encoded_len(&self) -> Result<usize> {
+        let principals_len = self
+            .valid_principals
+            .iter()
+            .map(|principal| 4 + principal.len())
+            .sum::<usize>();
+
+        Ok(4 + self.algorithm().as_certificate_str().len()
+            + 4
+            + self.nonce.len()
+            + self.public_key.encoded_key_data_len()?
+            + 8 // serial
+            + 4 // cert type
+            + 4
+            + self.key_id.len()
+            + 4
+            + principals_len
+            + 8 // valid after
+            + 8 // valid before
+            + options_len(&self.critical_options)
+            + options_len(&self.extensions)
+            + 4
+            + self.reserved.len()
+            + 4
+            + self.signature_key.encoded_len()?
+            + 4
+            + self.signature.encoded_len()?)
+    }
+
+    fn encode(&self, encoder: &mut impl Encoder) -> Result<()> {
+        self.encode_signed_data(encoder)?;
+        encoder.encode_nested(|nested| self.signature.encode(nested))
+    }
+}
+
+impl FromStr for Certificate {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_openssh(s)
+    }
+}
+
+impl fmt::Display for Certificate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_openssh().map_err(|_| fmt::Error)?)
+    }
+}
+
+/// Types of OpenSSH certificates: user or host.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum CertType {
+    /// User certificate
+    User = 1,
+
+    /// Host certificate
+    Host = 2,
+}
+
+impl CertType {
+    /// Is this a host certificate?
+    pub fn is_host(self) -> bool {
+        self == CertType::Host
+    }
+
+    /// Is this a user certificate?
+    pub fn is_user(self) -> bool {
+        self == CertType::User
+    }
+}
+
+impl Default for CertType {
+    fn default() -> Self {
+        CertType::User
+    }
+}
+
+impl TryFrom<u32> for CertType {
+    type Error = Error;
+
+    fn try_from(n: u32) -> Result<CertType> {
+        match n {
+            1 => Ok(CertType::User),
+            2 => Ok(CertType::Host),
+            _ => Err(Error::FormatEncoding),
+        }
+    }
+}
+
+impl From<CertType> for u32 {
+    fn from(cert_type: CertType) -> u32 {
+        cert_type as u32
+    }
+}
+
+/// Signature over an OpenSSH certificate.
+///
+/// Contains a signature algorithm identifier (e.g. `ssh-ed25519` or
+/// `rsa-sha2-512`) along with the algorithm-specific signature blob.
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Signature {
+    /// Signature algorithm identifier.
+    algorithm: String,
+
+    /// Signature data.
+    data: Vec<u8>,
+}
+
+impl Signature {
+    /// Create a new signature with the given algorithm identifier and
+    /// algorithm-specific signature blob.
+    pub fn new(algorithm: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        Self {
+            algorithm: algorithm.into(),
+            data: data.into(),
+        }
+    }
+
+    /// Get the signature algorithm identifier.
+    pub fn algorithm(&self) -> &str {
+        &self.algorithm
+    }
+
+    /// Get the algorithm-specific signature blob.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl AsRef<[u8]> for Signature {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Decode for Signature {
+    fn decode(decoder: &mut impl Decoder) -> Result<Self> {
+        let algorithm = decoder.decode_string()?;
+        let data = decoder.decode_byte_vec()?;
+        Ok(Self { algorithm, data })
+    }
+}
+
+impl Encode for Signature {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(4 + self.algorithm.len() + 4 + self.data.len())
+    }
+
+    fn encode(&self, encoder: &mut impl Encoder) -> Result<()> {
+        encoder.encode_str(&self.algorithm)?;
+        encoder.encode_byte_slice(&self.data)
+    }
+}
+
+/// Decode critical options or extensions.
+///
+/// Names must be unique and appear in lexical order. Flag options have
+/// empty data, whereas the data of other options is itself a `string`.
+fn decode_options(decoder: &mut impl Decoder) -> Result<OptionsMap> {
+    decoder.decode_nested(|nested| {
+        let mut options = OptionsMap::new();
+
+        while !nested.is_finished() {
+            let name = nested.decode_string()?;
+            let data = nested.decode_nested(|data| {
+                if data.is_finished() {
+                    Ok(None)
+                } else {
+                    data.decode_string().map(Some)
+                }
+            })?;
+
+            match options.keys().next_back() {
+                Some(last) if *last >= name => return Err(Error::FormatEncoding),
+                _ => options.insert(name, data),
+            };
+        }
+
+        Ok(options)
+    })
+}
+
+/// Encode critical options or extensions.
+fn encode_options(encoder: &mut impl Encoder, options: &OptionsMap) -> Result<()> {
+    encoder.encode_nested(|nested| {
+        for (name, data) in options {
+            nested.encode_str(name)?;
+            nested.encode_nested(|nested| match data {
+                Some(data) => nested.encode_str(data),
+                None => Ok(()),
+            })?;
+        }
+
+        Ok(())
+    })
+}
+
+/// Get the encoded length of critical options or extensions.
+fn options_len(options: &OptionsMap) -> usize {
+    let options_len = options
+        .iter()
+        .map(|(name, data)| 4 + name.len() + 4 + data.as_ref().map_or(0, |data| 4 + data.len()))
+        .sum::<usize>();
+
+    4 + options_len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Certificate;
+    use crate::encoder::Encode;
+    use alloc::vec::Vec;
+
+    /// Ed25519 OpenSSH user certificate
+    const OSSH_ED25519_CERT_EXAMPLE: &str = include_str!("../tests/examples/id_ed25519-cert.pub");
+
+    #[test]
+    fn encoded_len() {
+        let cert = Certificate::from_openssh(OSSH_ED25519_CERT_EXAMPLE).unwrap();
+        let mut encoded = Vec::new();
+        cert.encode(&mut encoded).unwrap();
+        assert_eq!(cert.encoded_len().unwrap(), encoded.len());
+    }
+}
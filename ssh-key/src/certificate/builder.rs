@@ -0,0 +1,159 @@
+//! OpenSSH certificate builder.
+
+use super::{CertType, Certificate, OptionsMap, Signature};
+use crate::{public::KeyData, Result};
+use alloc::{string::String, vec::Vec};
+
+/// OpenSSH certificate builder.
+///
+/// This type provides the core functionality for building OpenSSH
+/// certificates, computing the signing input and attaching the signature
+/// produced by a certificate authority.
+///
+/// Producing the signature itself is left to the caller (see
+/// [`Builder::sign_with`]), which allows it to be computed using any signing
+/// key, including one held in a hardware device or SSH agent.
+#[derive(Clone, Debug)]
+pub struct Builder {
+    nonce: Vec<u8>,
+    public_key: KeyData,
+    serial: u64,
+    cert_type: CertType,
+    key_id: String,
+    valid_principals: Vec<String>,
+    valid_after: u64,
+    valid_before: u64,
+    critical_options: OptionsMap,
+    extensions: OptionsMap,
+    comment: String,
+}
+
+impl Builder {
+    /// Create a new certificate builder for the given public key.
+    ///
+    /// # Arguments
+    /// - `nonce`: random value of arbitrary length, typically 16 or 32 bytes,
+    ///   which prevents hash collision attacks against the signature. It
+    ///   MUST be generated using a cryptographically secure RNG.
+    /// - `public_key`: key to be certified.
+    /// - `valid_after`: Unix time at which the certificate becomes valid.
+    /// - `valid_before`: Unix time at which the certificate expires.
+    pub fn new(
+        nonce: impl Into<Vec<u8>>,
+        public_key: KeyData,
+        valid_after: u64,
+        valid_before: u64,
+    ) -> Self {
+        Self {
+            nonce: nonce.into(),
+            public_key,
+            serial: 0,
+            cert_type: CertType::default(),
+            key_id: String::new(),
+            valid_principals: Vec::new(),
+            valid_after,
+            valid_before,
+            critical_options: OptionsMap::new(),
+            extensions: OptionsMap::new(),
+            comment: String::new(),
+        }
+    }
+
+    /// Set certificate serial number.
+    ///
+    /// Default: `0`.
+    pub fn serial(mut self, serial: u64) -> Self {
+        self.serial = serial;
+        self
+    }
+
+    /// Set certificate type: user or host.
+    ///
+    /// Default: [`CertType::User`].
+    pub fn cert_type(mut self, cert_type: CertType) -> Self {
+        self.cert_type = cert_type;
+        self
+    }
+
+    /// Set key ID: label to identify this particular certificate.
+    ///
+    /// Default: `""`.
+    pub fn key_id(mut self, key_id: impl Into<String>) -> Self {
+        self.key_id = key_id.into();
+        self
+    }
+
+    /// Add a principal (i.e. username or hostname) to `valid_principals`.
+    ///
+    /// If no principals are added the certificate is valid for any
+    /// principal of the specified type.
+    pub fn valid_principal(mut self, principal: impl Into<String>) -> Self {
+        self.valid_principals.push(principal.into());
+        self
+    }
+
+    /// Add a critical option with the given data to this certificate, e.g.
+    /// `force-command`.
+    pub fn critical_option(mut self, name: impl Into<String>, data: impl Into<String>) -> Self {
+        self.critical_options.insert(name.into(), Some(data.into()));
+        self
+    }
+
+    /// Add a flag critical option, which has no data, to this certificate,
+    /// e.g. `verify-required`.
+    pub fn critical_option_flag(mut self, name: impl Into<String>) -> Self {
+        self.critical_options.insert(name.into(), None);
+        self
+    }
+
+    /// Add an extension with the given data to this certificate.
+    pub fn extension(mut self, name: impl Into<String>, data: impl Into<String>) -> Self {
+        self.extensions.insert(name.into(), Some(data.into()));
+        self
+    }
+
+    /// Add a flag extension, which has no data, to this certificate, e.g.
+    /// `permit-pty`.
+    pub fn extension_flag(mut self, name: impl Into<String>) -> Self {
+        self.extensions.insert(name.into(), None);
+        self
+    }
+
+    /// Set the comment on the certificate.
+    ///
+    /// Default: `""`.
+    pub fn comment(mut self, comment: impl Into<String>) -> Self {
+        self.comment = comment.into();
+        self
+    }
+
+    /// Build the certificate, signing it using the provided function.
+    ///
+    /// The function receives the signing input of the certificate (see
+    /// [`Certificate::signed_data`]) and returns a [`Signature`] over it
+    /// computed with the private key corresponding to `signature_key`.
+    pub fn sign_with<F>(self, signature_key: KeyData, f: F) -> Result<Certificate>
+    where
+        F: FnOnce(&[u8]) -> Result<Signature>,
+    {
+        let mut certificate = Certificate {
+            nonce: self.nonce,
+            public_key: self.public_key,
+            serial: self.serial,
+            cert_type: self.cert_type,
+            key_id: self.key_id,
+            valid_principals: self.valid_principals,
+            valid_after: self.valid_after,
+            valid_before: self.valid_before,
+            critical_options: self.critical_options,
+            extensions: self.extensions,
+            reserved: Vec::new(),
+            signature_key,
+            signature: Signature::new(String::new(), Vec::new()),
+            comment: self.comment,
+        };
+
+        certificate.signature = f(&certificate.signed_data()?)?;
+        Ok(certificate)
+    }
+}
@@ -60,6 +60,19 @@ pub(crate) trait Decoder {
         Ok(u32::from_be_bytes(bytes))
     }
 
+    /// Decode a `uint64` as described in [RFC4251 § 5]:
+    ///
+    /// > Represents a 64-bit unsigned integer.  Stored as eight bytes in
+    /// > the order of decreasing significance (network byte order).
+    ///
+    /// [RFC4251 § 5]: https://datatracker.ietf.org/doc/html/rfc4251#section-5
+    #[cfg(feature = "alloc")]
+    fn decode_u64(&mut self) -> Result<u64> {
+        let mut bytes = [0u8; 8];
+        self.decode_raw(&mut bytes)?;
+        Ok(u64::from_be_bytes(bytes))
+    }
+
     /// Decode a `usize`.
     ///
     /// Uses [`Decoder::decode_u32`] and then converts to a `usize`, handling
@@ -130,6 +143,24 @@ pub(crate) trait Decoder {
         String::from_utf8(self.decode_byte_vec()?).map_err(|_| Error::CharacterEncoding)
     }
 
+    /// Decode a `string` containing a nested encoding using the provided
+    /// function, ensuring that all of its contents have been consumed.
+    #[cfg(feature = "alloc")]
+    fn decode_nested<T, F>(&mut self, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut &[u8]) -> Result<T>,
+    {
+        let bytes = self.decode_byte_vec()?;
+        let mut nested = bytes.as_slice();
+        let result = f(&mut nested)?;
+
+        if nested.is_finished() {
+            Ok(result)
+        } else {
+            Err(Error::Length)
+        }
+    }
+
     /// Drain the given number of bytes from the decoder, discarding them.
     fn drain(&mut self, n_bytes: usize) -> Result<()> {
         let mut byte = [0];
@@ -160,6 +191,27 @@ impl Decoder for Base64Decoder<'_> {
     }
 }
 
+impl Decoder for &[u8] {
+    fn decode_raw<'o>(&mut self, out: &'o mut [u8]) -> Result<&'o [u8]> {
+        if out.len() > self.len() {
+            return Err(Error::Length);
+        }
+
+        let (bytes, remaining) = self.split_at(out.len());
+        out.copy_from_slice(bytes);
+        *self = remaining;
+        Ok(out)
+    }
+
+    fn decoded_len(&self) -> usize {
+        self.len()
+    }
+
+    fn is_finished(&self) -> bool {
+        self.is_empty()
+    }
+}
+
 impl Decoder for pem::Decoder<'_> {
     fn decode_raw<'o>(&mut self, out: &'o mut [u8]) -> Result<&'o [u8]> {
         Ok(self.decode(out)?)
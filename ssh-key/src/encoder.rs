@@ -6,6 +6,9 @@ use crate::Result;
 use core::str;
 use pem_rfc7468 as pem;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 #[cfg(feature = "fingerprint")]
 use sha2::{Digest, Sha256};
 
@@ -48,6 +51,17 @@ pub(crate) trait Encoder {
         self.encode_raw(&num.to_be_bytes())
     }
 
+    /// Encode a `uint64` as described in [RFC4251 § 5]:
+    ///
+    /// > Represents a 64-bit unsigned integer.  Stored as eight bytes in
+    /// > the order of decreasing significance (network byte order).
+    ///
+    /// [RFC4251 § 5]: https://datatracker.ietf.org/doc/html/rfc4251#section-5
+    #[cfg(feature = "alloc")]
+    fn encode_u64(&mut self, num: u64) -> Result<()> {
+        self.encode_raw(&num.to_be_bytes())
+    }
+
     /// Encode a `usize` as a `uint32` as described in [RFC4251 § 5].
     ///
     /// Uses [`Encoder::encode_u32`] after converting from a `usize`, handling
@@ -90,6 +104,18 @@ pub(crate) trait Encoder {
     fn encode_str(&mut self, s: &str) -> Result<()> {
         self.encode_byte_slice(s.as_bytes())
     }
+
+    /// Encode a `string` containing a nested encoding produced by the
+    /// provided function.
+    #[cfg(feature = "alloc")]
+    fn encode_nested<F>(&mut self, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut Vec<u8>) -> Result<()>,
+    {
+        let mut nested = Vec::new();
+        f(&mut nested)?;
+        self.encode_byte_slice(&nested)
+    }
 }
 
 impl Encoder for Base64Encoder<'_> {
@@ -98,6 +124,14 @@ impl Encoder for Base64Encoder<'_> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl Encoder for Vec<u8> {
+    fn encode_raw(&mut self, bytes: &[u8]) -> Result<()> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
 impl Encoder for pem::Encoder<'_, '_> {
     fn encode_raw(&mut self, bytes: &[u8]) -> Result<()> {
         Ok(self.encode(bytes)?)
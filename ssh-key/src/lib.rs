@@ -36,11 +36,11 @@
 //! if let Some(ed25519_public_key) = public_key.key_data().ed25519() {
 //!     assert_eq!(
 //!         ed25519_public_key.as_ref(),
-//!         [
+//!         &[
 //!             0xb3, 0x3e, 0xae, 0xf3, 0x7e, 0xa2, 0xdf, 0x7c, 0xaa, 0x1, 0xd, 0xef, 0xde, 0xa3,
 //!             0x4e, 0x24, 0x1f, 0x65, 0xf1, 0xb5, 0x29, 0xa4, 0xf4, 0x3e, 0xd1, 0x43, 0x27, 0xf5,
 //!             0xc5, 0x4a, 0xab, 0x62
-//!         ].as_ref()
+//!         ]
 //!     );
 //! }
 //! # Ok(())
@@ -83,20 +83,20 @@
 //! if let Some(ed25519_keypair) = private_key.key_data().ed25519() {
 //!     assert_eq!(
 //!         ed25519_keypair.public.as_ref(),
-//!         [
+//!         &[
 //!             0xb3, 0x3e, 0xae, 0xf3, 0x7e, 0xa2, 0xdf, 0x7c, 0xaa, 0x1, 0xd, 0xef, 0xde, 0xa3,
 //!             0x4e, 0x24, 0x1f, 0x65, 0xf1, 0xb5, 0x29, 0xa4, 0xf4, 0x3e, 0xd1, 0x43, 0x27, 0xf5,
 //!             0xc5, 0x4a, 0xab, 0x62
-//!         ].as_ref()
+//!         ]
 //!     );
 //!
 //!     assert_eq!(
 //!         ed25519_keypair.private.as_ref(),
-//!         [
+//!         &[
 //!             0xb6, 0x6, 0xc2, 0x22, 0xd1, 0xc, 0x16, 0xda, 0xe1, 0x6c, 0x70, 0xa4, 0xd4, 0x51,
 //!             0x73, 0x47, 0x2e, 0xc6, 0x17, 0xe0, 0x5c, 0x65, 0x69, 0x20, 0xd2, 0x6e, 0x56, 0xc0,
 //!             0x8f, 0xb5, 0x91, 0xed
-//!         ].as_ref()
+//!         ]
 //!     )
 //! }
 //! # Ok(())
@@ -119,6 +119,10 @@ mod encoder;
 mod error;
 mod kdf;
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod certificate;
+
 #[cfg(feature = "fingerprint")]
 mod fingerprint;
 #[cfg(feature = "alloc")]
@@ -135,7 +139,7 @@ pub use crate::{
 pub use base64ct::LineEnding;
 
 #[cfg(feature = "alloc")]
-pub use crate::mpint::MPInt;
+pub use crate::{certificate::Certificate, mpint::MPInt};
 
 #[cfg(feature = "ecdsa")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
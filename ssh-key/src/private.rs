@@ -42,11 +42,26 @@ use std::{fs, io::Write, path::Path};
 #[cfg(all(unix, feature = "std"))]
 use std::os::unix::fs::OpenOptionsExt;
 
+#[cfg(feature = "encryption")]
+use ctr::cipher::{KeyIvInit, StreamCipher};
+
 #[cfg(feature = "subtle")]
 use subtle::{Choice, ConstantTimeEq};
 
+/// AES-256 in counter (CTR) mode.
+#[cfg(feature = "encryption")]
+type Aes256Ctr = ctr::Ctr128BE<aes::Aes256>;
+
+/// AES block size.
+#[cfg(feature = "encryption")]
+const AES_BLOCK_SIZE: usize = 16;
+
+/// AES-256 key size.
+#[cfg(feature = "encryption")]
+const AES256_KEY_SIZE: usize = 32;
+
 /// Padding bytes to use.
-const PADDING_BYTES: [u8; 7] = [1, 2, 3, 4, 5, 6, 7];
+const PADDING_BYTES: [u8; 15] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
 
 /// Line width used by the PEM encoding of OpenSSH private keys.
 const PEM_LINE_WIDTH: usize = 70;
@@ -149,59 +164,71 @@ impl PrivateKey {
         // See OpenSSH PROTOCOL.key § 3
         let private_key_len = pem_decoder.decode_usize()?;
 
-        // TODO(tarcieri): support for encrypted private keys
-        let block_size = DEFAULT_BLOCK_SIZE;
-
-        if (private_key_len % block_size != 0) || (private_key_len != pem_decoder.remaining_len()) {
+        if private_key_len != pem_decoder.remaining_len() {
             return Err(Error::Length);
         }
 
-        let checkint1 = pem_decoder.decode_u32()?;
-        let checkint2 = pem_decoder.decode_u32()?;
+        let key_data = Self::decode_privatekey_comment_pair(
+            &mut pem_decoder,
+            &mut public_key,
+            DEFAULT_BLOCK_SIZE,
+        )?;
 
-        if checkint1 != checkint2 {
-            // TODO(tarcieri): treat this as a cryptographic error?
-            return Err(Error::FormatEncoding);
-        }
+        Ok(Self {
+            cipher_alg,
+            kdf_opts,
+            public_key,
+            key_data,
+        })
+    }
 
-        let key_data = KeypairData::decode(&mut pem_decoder)?;
+    /// Decrypt this private key using the provided password, returning a
+    /// new unencrypted [`PrivateKey`].
+    ///
+    /// Presently only keys encrypted with `aes256-ctr` using a key derived
+    /// with `bcrypt-pbkdf` (the OpenSSH defaults) are supported.
+    #[cfg(feature = "encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+    pub fn decrypt(&self, password: impl AsRef<[u8]>) -> Result<Self> {
+        let ciphertext = match &self.key_data {
+            KeypairData::Encrypted(ciphertext) => ciphertext,
+            _ => return Err(Error::Decrypted),
+        };
 
-        // Ensure public key matches private key
-        if public_key.key_data() != &public::KeyData::try_from(&key_data)? {
-            return Err(Error::PublicKey);
-        }
+        let (salt, rounds) = match &self.kdf_opts {
+            KdfOpts::Bcrypt { salt, rounds } => (salt, *rounds),
+            _ => return Err(Error::Algorithm),
+        };
 
-        // Decode comment (e.g. email address)
-        #[cfg(not(feature = "alloc"))]
-        pem_decoder.drain_prefixed()?;
-        #[cfg(feature = "alloc")]
-        {
-            public_key.comment = pem_decoder.decode_string()?;
+        if self.cipher_alg != CipherAlg::Aes256Ctr {
+            return Err(Error::Algorithm);
         }
 
-        let padding_len = pem_decoder.remaining_len();
+        let mut key_and_iv = Zeroizing::new([0u8; AES256_KEY_SIZE + AES_BLOCK_SIZE]);
+        bcrypt_pbkdf::bcrypt_pbkdf(password, salt, rounds, key_and_iv.as_mut())
+            .map_err(|_| Error::Crypto)?;
 
-        if padding_len >= block_size {
-            return Err(Error::Length);
-        }
+        let (key, iv) = key_and_iv.split_at(AES256_KEY_SIZE);
+        let mut plaintext = Zeroizing::new(ciphertext.clone());
+        Aes256Ctr::new_from_slices(key, iv)
+            .map_err(|_| Error::Crypto)?
+            .apply_keystream(&mut plaintext);
 
-        if padding_len != 0 {
-            // TODO(tarcieri): support for encrypted private keys
-            let mut padding = [0u8; DEFAULT_BLOCK_SIZE];
-            pem_decoder.decode(&mut padding[..padding_len])?;
-
-            if PADDING_BYTES[..padding_len] != padding[..padding_len] {
-                return Err(Error::FormatEncoding);
-            }
+        // Mismatched check integers indicate an incorrect password
+        if plaintext.len() < 8 || plaintext[..4] != plaintext[4..8] {
+            return Err(Error::Crypto);
         }
 
-        if !pem_decoder.is_finished() {
-            return Err(Error::Length);
-        }
+        let mut public_key = self.public_key.clone();
+        let key_data = Self::decode_privatekey_comment_pair(
+            &mut plaintext.as_slice(),
+            &mut public_key,
+            self.cipher_alg.block_size(),
+        )?;
 
         Ok(Self {
-            cipher_alg,
-            kdf_opts,
+            cipher_alg: CipherAlg::None,
+            kdf_opts: KdfOpts::Empty,
             public_key,
             key_data,
         })
@@ -364,6 +391,62 @@ impl PrivateKey {
         ))
     }
 
+    /// Decode the private key and comment which follow the length prefix of
+    /// the (decrypted) list of private keys, ensuring they match the given
+    /// public key.
+    fn decode_privatekey_comment_pair(
+        decoder: &mut impl Decoder,
+        public_key: &mut PublicKey,
+        block_size: usize,
+    ) -> Result<KeypairData> {
+        if decoder.decoded_len() % block_size != 0 {
+            return Err(Error::Length);
+        }
+
+        let checkint1 = decoder.decode_u32()?;
+        let checkint2 = decoder.decode_u32()?;
+
+        if checkint1 != checkint2 {
+            return Err(Error::FormatEncoding);
+        }
+
+        let key_data = KeypairData::decode(decoder)?;
+
+        // Ensure public key matches private key
+        if public_key.key_data() != &public::KeyData::try_from(&key_data)? {
+            return Err(Error::PublicKey);
+        }
+
+        // Decode comment (e.g. email address)
+        #[cfg(not(feature = "alloc"))]
+        decoder.drain_prefixed()?;
+        #[cfg(feature = "alloc")]
+        {
+            public_key.comment = decoder.decode_string()?;
+        }
+
+        let padding_len = decoder.decoded_len();
+
+        if padding_len >= block_size {
+            return Err(Error::Length);
+        }
+
+        if padding_len != 0 {
+            let mut padding = [0u8; PADDING_BYTES.len()];
+            decoder.decode_raw(&mut padding[..padding_len])?;
+
+            if PADDING_BYTES[..padding_len] != padding[..padding_len] {
+                return Err(Error::FormatEncoding);
+            }
+        }
+
+        if !decoder.is_finished() {
+            return Err(Error::Length);
+        }
+
+        Ok(key_data)
+    }
+
     /// Get the length of the private key data in bytes (not including padding).
     fn private_key_len(&self) -> Result<usize> {
         if self.is_encrypted() {
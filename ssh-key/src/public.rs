@@ -7,7 +7,7 @@ mod dsa;
 #[cfg(feature = "ecdsa")]
 mod ecdsa;
 mod ed25519;
-mod openssh;
+pub(crate) mod openssh;
 #[cfg(feature = "alloc")]
 mod rsa;
 
@@ -316,11 +316,11 @@ impl KeyData {
 
         n
     }
-}
 
-impl Decode for KeyData {
-    fn decode(decoder: &mut impl Decoder) -> Result<Self> {
-        match Algorithm::decode(decoder)? {
+    /// Decode [`KeyData`] for the specified algorithm, i.e. without a
+    /// leading algorithm identifier.
+    pub(crate) fn decode_as(decoder: &mut impl Decoder, algorithm: Algorithm) -> Result<Self> {
+        match algorithm {
             #[cfg(feature = "alloc")]
             Algorithm::Dsa => DsaPublicKey::decode(decoder).map(Self::Dsa),
             #[cfg(feature = "ecdsa")]
@@ -335,28 +335,23 @@ impl Decode for KeyData {
             _ => Err(Error::Algorithm),
         }
     }
-}
-
-impl Encode for KeyData {
-    fn encoded_len(&self) -> Result<usize> {
-        let alg_len = self.algorithm().encoded_len()?;
 
-        let key_len = match self {
+    /// Get the length of this key data when encoded without a leading
+    /// algorithm identifier.
+    pub(crate) fn encoded_key_data_len(&self) -> Result<usize> {
+        match self {
             #[cfg(feature = "alloc")]
-            Self::Dsa(key) => key.encoded_len()?,
+            Self::Dsa(key) => key.encoded_len(),
             #[cfg(feature = "ecdsa")]
-            Self::Ecdsa(key) => key.encoded_len()?,
-            Self::Ed25519(key) => key.encoded_len()?,
+            Self::Ecdsa(key) => key.encoded_len(),
+            Self::Ed25519(key) => key.encoded_len(),
             #[cfg(feature = "alloc")]
-            Self::Rsa(key) => key.encoded_len()?,
-        };
-
-        Ok(alg_len + key_len)
+            Self::Rsa(key) => key.encoded_len(),
+        }
     }
 
-    fn encode(&self, encoder: &mut impl Encoder) -> Result<()> {
-        self.algorithm().encode(encoder)?;
-
+    /// Encode this key data without a leading algorithm identifier.
+    pub(crate) fn encode_key_data(&self, encoder: &mut impl Encoder) -> Result<()> {
         match self {
             #[cfg(feature = "alloc")]
             Self::Dsa(key) => key.encode(encoder),
@@ -368,3 +363,21 @@ impl Encode for KeyData {
         }
     }
 }
+
+impl Decode for KeyData {
+    fn decode(decoder: &mut impl Decoder) -> Result<Self> {
+        let algorithm = Algorithm::decode(decoder)?;
+        Self::decode_as(decoder, algorithm)
+    }
+}
+
+impl Encode for KeyData {
+    fn encoded_len(&self) -> Result<usize> {
+        Ok(self.algorithm().encoded_len()? + self.encoded_key_data_len()?)
+    }
+
+    fn encode(&self, encoder: &mut impl Encoder) -> Result<()> {
+        self.algorithm().encode(encoder)?;
+        self.encode_key_data(encoder)
+    }
+}
@@ -0,0 +1,169 @@
+//! OpenSSH certificate tests.
+
+#![cfg(feature = "alloc")]
+
+use hex_literal::hex;
+use ssh_key::{
+    certificate::{Builder, CertType, Signature},
+    Algorithm, Certificate, PublicKey,
+};
+
+/// Ed25519 OpenSSH user certificate
+const OSSH_ED25519_CERT_EXAMPLE: &str = include_str!("examples/id_ed25519-cert.pub");
+
+/// Ed25519 OpenSSH public key of the certified key
+const OSSH_ED25519_EXAMPLE: &str = include_str!("examples/id_ed25519.pub");
+
+/// Ed25519 OpenSSH public key of the CA
+const OSSH_CA_ED25519_EXAMPLE: &str = include_str!("examples/ca_ed25519.pub");
+
+/// 2026-01-01T00:00:00Z
+const VALID_AFTER: u64 = 1767225600;
+
+/// 2027-01-01T00:00:00Z
+const VALID_BEFORE: u64 = 1798761600;
+
+#[test]
+fn decode_ed25519_cert_openssh() {
+    let cert = Certificate::from_openssh(OSSH_ED25519_CERT_EXAMPLE).unwrap();
+    let public_key = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    let ca_key = PublicKey::from_openssh(OSSH_CA_ED25519_EXAMPLE).unwrap();
+
+    assert_eq!(Algorithm::Ed25519, cert.algorithm());
+    assert_eq!(
+        &hex!("1a895ebe88958b07c0577e85eac5b513992818cb9c0909d0d7533d27b6103ca3"),
+        cert.nonce()
+    );
+    assert_eq!(public_key.key_data(), cert.public_key());
+    assert_eq!(42, cert.serial());
+    assert_eq!(CertType::User, cert.cert_type());
+    assert_eq!("user@example.com", cert.key_id());
+    assert_eq!(&["user", "admin"], cert.valid_principals());
+    assert_eq!(VALID_AFTER, cert.valid_after());
+    assert_eq!(VALID_BEFORE, cert.valid_before());
+
+    let critical_options = cert.critical_options();
+    assert_eq!(2, critical_options.len());
+    assert_eq!(
+        Some("/usr/bin/true"),
+        critical_options["force-command"].as_deref()
+    );
+    assert_eq!(
+        Some("10.0.0.0/8"),
+        critical_options["source-address"].as_deref()
+    );
+
+    let extensions = cert.extensions().keys().collect::<Vec<_>>();
+    assert_eq!(
+        &[
+            "permit-X11-forwarding",
+            "permit-agent-forwarding",
+            "permit-pty",
+            "permit-user-rc"
+        ],
+        extensions.as_slice()
+    );
+    assert!(cert.extensions().values().all(Option::is_none));
+
+    assert_eq!(ca_key.key_data(), cert.signature_key());
+    assert_eq!("ssh-ed25519", cert.signature().algorithm());
+    assert_eq!(64, cert.signature().as_bytes().len());
+    assert_eq!("user@example.com", cert.comment());
+}
+
+#[test]
+fn encode_ed25519_cert_openssh() {
+    let cert = Certificate::from_openssh(OSSH_ED25519_CERT_EXAMPLE).unwrap();
+    assert_eq!(OSSH_ED25519_CERT_EXAMPLE.trim_end(), cert.to_string());
+}
+
+#[test]
+fn validity_window() {
+    let cert = Certificate::from_openssh(OSSH_ED25519_CERT_EXAMPLE).unwrap();
+    assert!(!cert.is_valid_at(VALID_AFTER - 1));
+    assert!(cert.is_valid_at(VALID_AFTER));
+    assert!(cert.is_valid_at(VALID_BEFORE - 1));
+    assert!(!cert.is_valid_at(VALID_BEFORE));
+}
+
+#[test]
+fn build_ed25519_cert() {
+    let cert = Certificate::from_openssh(OSSH_ED25519_CERT_EXAMPLE).unwrap();
+    let public_key = PublicKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    let ca_key = PublicKey::from_openssh(OSSH_CA_ED25519_EXAMPLE).unwrap();
+
+    // Options are added out of order to ensure they're sorted when encoded
+    let builder = Builder::new(
+        cert.nonce(),
+        public_key.key_data().clone(),
+        VALID_AFTER,
+        VALID_BEFORE,
+    )
+    .serial(42)
+    .cert_type(CertType::User)
+    .key_id("user@example.com")
+    .valid_principal("user")
+    .valid_principal("admin")
+    .critical_option("source-address", "10.0.0.0/8")
+    .critical_option("force-command", "/usr/bin/true")
+    .extension_flag("permit-user-rc")
+    .extension_flag("permit-pty")
+    .extension_flag("permit-agent-forwarding")
+    .extension_flag("permit-X11-forwarding")
+    .comment("user@example.com");
+
+    // Ed25519 signatures are deterministic, so reusing the signature of the
+    // example certificate must produce an identical certificate
+    let built = builder
+        .sign_with(ca_key.key_data().clone(), |signed_data| {
+            assert_eq!(cert.signed_data().unwrap(), signed_data);
+            Ok(cert.signature().clone())
+        })
+        .unwrap();
+
+    assert_eq!(cert, built);
+    assert_eq!(
+        OSSH_ED25519_CERT_EXAMPLE.trim_end(),
+        built.to_openssh().unwrap()
+    );
+}
+
+#[test]
+fn empty_option_data() {
+    let cert = Certificate::from_openssh(OSSH_ED25519_CERT_EXAMPLE).unwrap();
+    let ca_key = PublicKey::from_openssh(OSSH_CA_ED25519_EXAMPLE).unwrap();
+
+    // Flag options and options whose data is an empty `string` are encoded
+    // differently, so both must survive being re-encoded
+    let built = Builder::new(
+        cert.nonce(),
+        cert.public_key().clone(),
+        VALID_AFTER,
+        VALID_BEFORE,
+    )
+    .critical_option("force-command", "")
+    .critical_option_flag("verify-required")
+    .extension_flag("permit-pty")
+    .extension("permit-user-rc", "")
+    .comment("user@example.com")
+    .sign_with(ca_key.key_data().clone(), |_| Ok(cert.signature().clone()))
+    .unwrap();
+
+    let decoded = Certificate::from_openssh(built.to_openssh().unwrap()).unwrap();
+    assert_eq!(built, decoded);
+    assert_eq!(built.signed_data().unwrap(), decoded.signed_data().unwrap());
+    assert_eq!(
+        Some(""),
+        decoded.critical_options()["force-command"].as_deref()
+    );
+    assert_eq!(None, decoded.critical_options()["verify-required"]);
+    assert_eq!(None, decoded.extensions()["permit-pty"]);
+    assert_eq!(Some(""), decoded.extensions()["permit-user-rc"].as_deref());
+}
+
+#[test]
+fn signature_new() {
+    let signature = Signature::new("ssh-ed25519", [0u8; 64]);
+    assert_eq!("ssh-ed25519", signature.algorithm());
+    assert_eq!(&[0u8; 64], signature.as_bytes());
+}
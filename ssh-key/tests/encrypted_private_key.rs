@@ -5,9 +5,20 @@
 use hex_literal::hex;
 use ssh_key::{Algorithm, KdfAlg, KdfOpts, PrivateKey};
 
+#[cfg(feature = "encryption")]
+use ssh_key::Error;
+
+/// Unencrypted Ed25519 OpenSSH-formatted private key.
+#[cfg(feature = "encryption")]
+const OSSH_ED25519_EXAMPLE: &str = include_str!("examples/id_ed25519");
+
 /// Encrypted Ed25519 OpenSSH-formatted private key.
 const OSSH_ED25519_ENC_EXAMPLE: &str = include_str!("examples/id_ed25519.enc");
 
+/// Password for [`OSSH_ED25519_ENC_EXAMPLE`]. Don't use outside of tests!
+#[cfg(feature = "encryption")]
+const PASSWORD: &str = "hunter42";
+
 #[test]
 fn decode_ed25519_enc_openssh() {
     let ossh_key = PrivateKey::from_openssh(OSSH_ED25519_ENC_EXAMPLE).unwrap();
@@ -36,3 +47,34 @@ fn encode_ed25519_enc_openssh() {
         ossh_key.to_openssh(Default::default()).unwrap().trim_end()
     );
 }
+
+#[cfg(feature = "encryption")]
+#[test]
+fn decrypt_ed25519_enc_openssh() {
+    let ossh_key = PrivateKey::from_openssh(OSSH_ED25519_ENC_EXAMPLE).unwrap();
+    assert!(ossh_key.is_encrypted());
+
+    let decrypted = ossh_key.decrypt(PASSWORD).unwrap();
+    assert!(!decrypted.is_encrypted());
+    assert_eq!(KdfAlg::None, decrypted.kdf_alg());
+    assert_eq!("user@example.com", decrypted.comment());
+
+    let expected = PrivateKey::from_openssh(OSSH_ED25519_EXAMPLE).unwrap();
+    assert_eq!(expected.public_key(), decrypted.public_key());
+    assert_eq!(
+        expected.key_data().ed25519().unwrap().private.as_ref(),
+        decrypted.key_data().ed25519().unwrap().private.as_ref()
+    );
+
+    assert_eq!(
+        Err(Error::Decrypted),
+        decrypted.decrypt(PASSWORD).map(|_| ())
+    );
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn decrypt_ed25519_enc_openssh_wrong_password() {
+    let ossh_key = PrivateKey::from_openssh(OSSH_ED25519_ENC_EXAMPLE).unwrap();
+    assert_eq!(Err(Error::Crypto), ossh_key.decrypt("hunter2").map(|_| ()));
+}
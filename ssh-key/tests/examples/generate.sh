@@ -10,5 +10,14 @@ ssh-keygen -t ecdsa -b 256 -f id_ecdsa_p256 -C user@example.com
 ssh-keygen -t ecdsa -b 384 -f id_ecdsa_p384 -C user@example.com
 ssh-keygen -t ecdsa -b 521 -f id_ecdsa_p521 -C user@example.com
 ssh-keygen -t ed25519 -f id_ed25519 -C user@example.com
+cp id_ed25519 id_ed25519.enc
+ssh-keygen -p -f id_ed25519.enc -P "" -N hunter42
 ssh-keygen -t rsa -b 3072 -f id_rsa_3072 -C user@example.com
 ssh-keygen -t rsa -b 4096 -f id_rsa_4096 -C user@example.com
+
+# Certificates
+ssh-keygen -t ed25519 -f ca_ed25519 -C ca@example.com
+TZ=UTC ssh-keygen -s ca_ed25519 -I user@example.com -n user,admin -z 42 \
+    -V 20260101000000:20270101000000 -O force-command=/usr/bin/true \
+    -O source-address=10.0.0.0/8 -O no-port-forwarding id_ed25519.pub
+rm ca_ed25519
@@ -7,7 +7,7 @@
 #[cfg(feature = "std")]
 extern crate std;
 
-use core::{fmt, ops, time::Duration};
+use core::{fmt, ops, str::FromStr, time::Duration};
 
 #[cfg(feature = "serde")]
 use serde::{de, ser, Deserialize, Serialize};
@@ -21,6 +21,9 @@ use zeroize::Zeroize;
 /// Number of nanoseconds in a second
 const NANOS_PER_SECOND: u32 = 1_000_000_000;
 
+/// Prefix of external `TAI64` and `TAI64N` labels in their textual form.
+const LABEL_PREFIX: char = '@';
+
 /// A `TAI64` label.
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub struct Tai64(pub u64);
@@ -89,6 +92,28 @@ impl From<Tai64> for [u8; 8] {
     }
 }
 
+impl fmt::Display for Tai64 {
+    /// Format as an external `TAI64` label, e.g. `@4000000037c219bf`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{:016x}", LABEL_PREFIX, self.0)
+    }
+}
+
+impl FromStr for Tai64 {
+    type Err = Error;
+
+    /// Parse an external `TAI64` label, i.e. `@` followed by 16 hex digits.
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let hex = s.strip_prefix(LABEL_PREFIX).ok_or(Error::LabelInvalid)?;
+
+        if hex.len() != Tai64::BYTE_SIZE * 2 {
+            return Err(Error::LengthInvalid);
+        }
+
+        parse_hex_u64(hex).map(Tai64)
+    }
+}
+
 impl ops::Add<u64> for Tai64 {
     type Output = Self;
 
@@ -105,17 +130,27 @@ impl ops::Sub<u64> for Tai64 {
     }
 }
 
+/// Serialized as an external label (e.g. `"@4000000037c219bf"`) when using
+/// human-readable formats or as bytes if the format is binary.
 #[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for Tai64 {
     fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        Ok(<[u8; Tai64::BYTE_SIZE]>::deserialize(deserializer)?.into())
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(LabelVisitor::new("a TAI64 label"))
+        } else {
+            Ok(<[u8; Tai64::BYTE_SIZE]>::deserialize(deserializer)?.into())
+        }
     }
 }
 
 #[cfg(feature = "serde")]
 impl Serialize for Tai64 {
     fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        self.to_bytes().serialize(serializer)
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            self.to_bytes().serialize(serializer)
+        }
     }
 }
 
@@ -254,6 +289,59 @@ impl From<SystemTime> for Tai64N {
     }
 }
 
+#[cfg(feature = "der")]
+impl From<der::DateTime> for Tai64N {
+    fn from(t: der::DateTime) -> Self {
+        Self::UNIX_EPOCH + t.unix_duration()
+    }
+}
+
+#[cfg(feature = "der")]
+impl TryFrom<Tai64N> for der::DateTime {
+    type Error = der::Error;
+
+    /// Convert `TAI64N` to [`der::DateTime`], which only supports timestamps
+    /// from 1970 through 9999.
+    fn try_from(t: Tai64N) -> der::Result<Self> {
+        let d = t
+            .duration_since(&Tai64N::UNIX_EPOCH)
+            .map_err(|_| der::ErrorKind::DateTime)?;
+
+        der::DateTime::from_unix_duration(d)
+    }
+}
+
+impl fmt::Display for Tai64N {
+    /// Format as an external `TAI64N` label, e.g.
+    /// `@4000000037c219bf2ef02e94`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{:08x}", self.0, self.1)
+    }
+}
+
+impl FromStr for Tai64N {
+    type Err = Error;
+
+    /// Parse an external `TAI64N` label, i.e. `@` followed by 24 hex digits,
+    /// as used by e.g. daemontools' `multilog`.
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let hex = s.strip_prefix(LABEL_PREFIX).ok_or(Error::LabelInvalid)?;
+
+        if hex.len() != Tai64N::BYTE_SIZE * 2 {
+            return Err(Error::LengthInvalid);
+        }
+
+        let (secs, nanos) = hex.split_at(Tai64::BYTE_SIZE * 2);
+        let nanos = parse_hex_u64(nanos)? as u32;
+
+        if nanos < NANOS_PER_SECOND {
+            Ok(Tai64N(Tai64(parse_hex_u64(secs)?), nanos))
+        } else {
+            Err(Error::NanosInvalid)
+        }
+    }
+}
+
 #[allow(clippy::suspicious_arithmetic_impl)]
 impl ops::Add<Duration> for Tai64N {
     type Output = Self;
@@ -284,20 +372,71 @@ impl ops::Sub<Duration> for Tai64N {
     }
 }
 
+/// Serialized as an external label (e.g. `"@4000000037c219bf2ef02e94"`)
+/// when using human-readable formats or as bytes if the format is binary.
 #[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for Tai64N {
     fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         use de::Error;
-        <[u8; Tai64N::BYTE_SIZE]>::deserialize(deserializer)?
-            .try_into()
-            .map_err(D::Error::custom)
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(LabelVisitor::new("a TAI64N label"))
+        } else {
+            <[u8; Tai64N::BYTE_SIZE]>::deserialize(deserializer)?
+                .try_into()
+                .map_err(D::Error::custom)
+        }
     }
 }
 
 #[cfg(feature = "serde")]
 impl Serialize for Tai64N {
     fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        self.to_bytes().serialize(serializer)
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            self.to_bytes().serialize(serializer)
+        }
+    }
+}
+
+/// Parse hex digits (upper or lower case) into a `u64`.
+fn parse_hex_u64(hex: &str) -> Result<u64, Error> {
+    // `from_str_radix` also accepts a leading `+`
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(Error::LabelInvalid);
+    }
+
+    u64::from_str_radix(hex, 16).map_err(|_| Error::LabelInvalid)
+}
+
+/// Visitor for parsing external labels from strings.
+#[cfg(feature = "serde")]
+struct LabelVisitor<T> {
+    expecting: &'static str,
+    label: core::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> LabelVisitor<T> {
+    fn new(expecting: &'static str) -> Self {
+        Self {
+            expecting,
+            label: core::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: FromStr<Err = Error>> de::Visitor<'de> for LabelVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<T, E> {
+        s.parse().map_err(E::custom)
     }
 }
 
@@ -309,6 +448,9 @@ pub enum Error {
 
     /// Nanosecond part must be <= 999999999.
     NanosInvalid,
+
+    /// Malformed external label, i.e. missing `@` prefix or non-hex digits.
+    LabelInvalid,
 }
 
 impl fmt::Display for Error {
@@ -316,6 +458,7 @@ impl fmt::Display for Error {
         let description = match self {
             Error::LengthInvalid => "length invalid",
             Error::NanosInvalid => "invalid number of nanoseconds",
+            Error::LabelInvalid => "invalid label",
         };
 
         write!(f, "{}", description)
@@ -328,6 +471,7 @@ impl std::error::Error for Error {}
 #[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
+    use std::string::ToString;
 
     #[test]
     fn before_epoch() {
@@ -349,4 +493,80 @@ mod tests {
 
         assert_eq!(t, t1);
     }
+
+    /// Example label from the daemontools `tai64n` documentation.
+    const LABEL: &str = "@4000000037c219bf2ef02e94";
+
+    const TAI64N: Tai64N = Tai64N(Tai64(0x4000000037c219bf), 0x2ef02e94);
+
+    #[test]
+    fn label_round_trip() {
+        assert_eq!(LABEL.parse::<Tai64N>().unwrap(), TAI64N);
+        assert_eq!(TAI64N.to_string(), LABEL);
+
+        assert_eq!("@4000000037c219bf".parse::<Tai64>().unwrap(), TAI64N.0);
+        assert_eq!(TAI64N.0.to_string(), "@4000000037c219bf");
+
+        // Upper case hex digits are accepted
+        assert_eq!(
+            "@4000000037C219BF2EF02E94".parse::<Tai64N>().unwrap(),
+            TAI64N
+        );
+    }
+
+    #[test]
+    fn label_invalid() {
+        assert_eq!(
+            "4000000037c219bf2ef02e94".parse::<Tai64N>(),
+            Err(Error::LabelInvalid)
+        );
+        assert_eq!(
+            "@4000000037c219bf2ef02e9".parse::<Tai64N>(),
+            Err(Error::LengthInvalid)
+        );
+        assert_eq!(
+            "@+000000037c219bf2ef02e94".parse::<Tai64N>(),
+            Err(Error::LabelInvalid)
+        );
+        assert_eq!(
+            "@4000000037c219bf3b9aca00".parse::<Tai64N>(),
+            Err(Error::NanosInvalid)
+        );
+        assert_eq!(
+            "@4000000037c219bf2ef02e94".parse::<Tai64>(),
+            Err(Error::LengthInvalid)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_human_readable() {
+        let json = serde_json::to_string(&TAI64N).unwrap();
+        assert_eq!(json, std::format!("\"{}\"", LABEL));
+        assert_eq!(serde_json::from_str::<Tai64N>(&json).unwrap(), TAI64N);
+
+        let json = serde_json::to_string(&TAI64N.0).unwrap();
+        assert_eq!(json, "\"@4000000037c219bf\"");
+        assert_eq!(serde_json::from_str::<Tai64>(&json).unwrap(), TAI64N.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_binary() {
+        let bytes = bincode::serialize(&TAI64N).unwrap();
+        assert_eq!(bytes, TAI64N.to_bytes());
+        assert_eq!(bincode::deserialize::<Tai64N>(&bytes).unwrap(), TAI64N);
+    }
+
+    #[cfg(feature = "der")]
+    #[test]
+    fn der_date_time() {
+        // 0x37c219bf - 10 seconds after the Unix epoch
+        let date_time = der::DateTime::new(1999, 8, 24, 4, 4, 5).unwrap();
+        assert_eq!(Tai64N::from(date_time), Tai64N(TAI64N.0, 0));
+        assert_eq!(der::DateTime::try_from(TAI64N).unwrap(), date_time);
+
+        let before_epoch = Tai64N::UNIX_EPOCH - Duration::new(1, 0);
+        assert!(der::DateTime::try_from(before_epoch).is_err());
+    }
 }
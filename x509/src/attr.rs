@@ -187,6 +187,33 @@ impl AttributeTypeAndValue<'_> {
         atv.to_vec()
     }
 
+    /// Compare this AttributeTypeAndValue with another using the name
+    /// matching rules in [RFC 5280 Section 7.1].
+    ///
+    /// `PrintableString` values are compared using `caseIgnoreMatch`, which
+    /// ignores case as well as leading, trailing and repeated spaces. All
+    /// other values must be identical.
+    ///
+    /// [RFC 5280 Section 7.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-7.1
+    pub fn matches(&self, other: &AttributeTypeAndValue<'_>) -> bool {
+        if self.oid != other.oid {
+            return false;
+        }
+
+        match (
+            self.value.printable_string(),
+            other.value.printable_string(),
+        ) {
+            (Ok(a), Ok(b)) => {
+                let a = a.as_str().split(' ').filter(|s| !s.is_empty());
+                let b = b.as_str().split(' ').filter(|s| !s.is_empty());
+                a.clone().count() == b.clone().count()
+                    && a.zip(b).all(|(a, b)| a.eq_ignore_ascii_case(b))
+            }
+            _ => self.value == other.value,
+        }
+    }
+
     /// Converts an AttributeTypeAndValue string into an encoded AttributeTypeAndValue
     ///
     /// This function follows the rules in [RFC 4514].
@@ -0,0 +1,376 @@
+//! Certificate and certification request builders
+
+use crate::{
+    attr::Attribute,
+    certificate::{Certificate, TbsCertificate, Version},
+    ext::Extension,
+    name::Name,
+    request::{self, document::CertReqDocument, CertReq, CertReqInfo, ExtensionReq},
+    time::Validity,
+};
+
+use alloc::vec::Vec;
+
+use const_oid::AssociatedOid;
+use der::asn1::{Any, BitString, SetOfVec, UIntBytes};
+use der::{Decode, Encode, ErrorKind, Result};
+use spki::{AlgorithmIdentifier, SubjectPublicKeyInfo};
+
+#[cfg(feature = "signature")]
+use {
+    crate::certificate::document::CertificateDocument,
+    der::Error,
+    signature::{Signature, Signer},
+};
+
+#[cfg(feature = "digest")]
+use {
+    crate::ext::AsExtension,
+    crate::ocsp::{self, CertId, OcspNonce, OcspRequest, TbsRequest},
+    der::asn1::OctetString,
+    digest::Digest,
+};
+
+/// X.509 v3 certificate builder.
+///
+/// Assembles a [`TbsCertificate`] which can be signed by the issuer,
+/// either externally using the bytes returned by
+/// [`CertificateBuilder::signing_input`], or using a [`Signer`] via
+/// [`CertificateBuilder::build_and_sign`] when the `signature` feature is
+/// enabled.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CertificateBuilder<'a> {
+    tbs_certificate: TbsCertificate<'a>,
+}
+
+impl<'a> CertificateBuilder<'a> {
+    /// Create a new [`CertificateBuilder`].
+    ///
+    /// The `signature` argument identifies the algorithm the issuer will
+    /// use to sign the certificate.
+    pub fn new(
+        serial_number: UIntBytes<'a>,
+        signature: AlgorithmIdentifier<'a>,
+        issuer: Name<'a>,
+        validity: Validity,
+        subject: Name<'a>,
+        subject_public_key_info: SubjectPublicKeyInfo<'a>,
+    ) -> Self {
+        Self {
+            tbs_certificate: TbsCertificate {
+                version: Version::V3,
+                serial_number,
+                signature,
+                issuer,
+                validity,
+                subject,
+                subject_public_key_info,
+                issuer_unique_id: None,
+                subject_unique_id: None,
+                extensions: None,
+            },
+        }
+    }
+
+    /// Add an [`Extension`] to the certificate.
+    ///
+    /// Returns an error if an extension with the same OID has already been
+    /// added, as a certificate must not contain more than one instance of a
+    /// particular extension.
+    pub fn extension(mut self, extension: Extension<'a>) -> Result<Self> {
+        let extensions = self.tbs_certificate.extensions.get_or_insert_with(Vec::new);
+
+        if extensions.iter().any(|e| e.extn_id == extension.extn_id) {
+            return Err(ErrorKind::Failed.into());
+        }
+
+        extensions.push(extension);
+        Ok(self)
+    }
+
+    /// Get the [`TbsCertificate`] assembled by this builder.
+    pub fn tbs_certificate(&self) -> &TbsCertificate<'a> {
+        &self.tbs_certificate
+    }
+
+    /// Get the DER encoding of the [`TbsCertificate`], which is the input
+    /// to be signed by the issuer.
+    pub fn signing_input(&self) -> Result<Vec<u8>> {
+        self.tbs_certificate.to_vec()
+    }
+
+    /// Build a [`Certificate`] using a signature over the
+    /// [`CertificateBuilder::signing_input`] computed by the issuer.
+    pub fn build(self, signature: BitString<'a>) -> Certificate<'a> {
+        Certificate {
+            signature_algorithm: self.tbs_certificate.signature,
+            tbs_certificate: self.tbs_certificate,
+            signature,
+        }
+    }
+
+    /// Sign the certificate using the provided [`Signer`], returning the
+    /// DER-encoded certificate.
+    ///
+    /// The bytes of the signature are placed in the certificate as-is, so
+    /// the signature type must use the encoding expected by the algorithm
+    /// identified by the `signature` passed to [`CertificateBuilder::new`]
+    /// (e.g. ASN.1 DER for ECDSA).
+    #[cfg(feature = "signature")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "signature")))]
+    pub fn build_and_sign<S, Sig>(self, signer: &S) -> Result<CertificateDocument>
+    where
+        S: Signer<Sig>,
+        Sig: Signature,
+    {
+        let signature = signer
+            .try_sign(&self.signing_input()?)
+            .map_err(|_| Error::from(ErrorKind::Failed))?;
+
+        let cert = self.build(BitString::from_bytes(signature.as_bytes())?);
+        CertificateDocument::try_from(&cert)
+    }
+}
+
+/// PKCS#10 certification request builder.
+///
+/// Assembles a [`CertReqInfo`] which can be signed by the subject, either
+/// externally using the bytes returned by [`RequestBuilder::signing_input`],
+/// or using a [`Signer`] via [`RequestBuilder::build_and_sign`] when the
+/// `signature` feature is enabled.
+///
+/// Extensions added using [`RequestBuilder::extension`] are requested using
+/// the `extensionRequest` attribute.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestBuilder<'a> {
+    subject: Name<'a>,
+    public_key: SubjectPublicKeyInfo<'a>,
+    algorithm: AlgorithmIdentifier<'a>,
+    attributes: Vec<Attribute<'a>>,
+    extensions: Vec<Extension<'a>>,
+}
+
+impl<'a> RequestBuilder<'a> {
+    /// Create a new [`RequestBuilder`].
+    ///
+    /// The `algorithm` argument identifies the algorithm the subject will
+    /// use to sign the request.
+    pub fn new(
+        subject: Name<'a>,
+        public_key: SubjectPublicKeyInfo<'a>,
+        algorithm: AlgorithmIdentifier<'a>,
+    ) -> Self {
+        Self {
+            subject,
+            public_key,
+            algorithm,
+            attributes: Vec::new(),
+            extensions: Vec::new(),
+        }
+    }
+
+    /// Add an [`Attribute`] to the request.
+    ///
+    /// Returns an error if an attribute with the same OID has already been
+    /// added, or if the attribute is an `extensionRequest`: use
+    /// [`RequestBuilder::extension`] to request extensions instead.
+    pub fn attribute(mut self, attribute: Attribute<'a>) -> Result<Self> {
+        if attribute.oid == ExtensionReq::OID
+            || self.attributes.iter().any(|a| a.oid == attribute.oid)
+        {
+            return Err(ErrorKind::Failed.into());
+        }
+
+        self.attributes.push(attribute);
+        Ok(self)
+    }
+
+    /// Request an [`Extension`] be included in the certificate.
+    ///
+    /// Returns an error if an extension with the same OID has already been
+    /// added.
+    pub fn extension(mut self, extension: Extension<'a>) -> Result<Self> {
+        if self
+            .extensions
+            .iter()
+            .any(|e| e.extn_id == extension.extn_id)
+        {
+            return Err(ErrorKind::Failed.into());
+        }
+
+        self.extensions.push(extension);
+        Ok(self)
+    }
+
+    /// Get the DER encoding of the [`CertReqInfo`], which is the input to
+    /// be signed by the subject.
+    pub fn signing_input(&self) -> Result<Vec<u8>> {
+        self.with_info(|info| info.to_vec())
+    }
+
+    /// Build a [`CertReq`] using a signature over the
+    /// [`RequestBuilder::signing_input`] computed by the subject, returning
+    /// the DER-encoded request.
+    pub fn build(self, signature: BitString<'_>) -> Result<CertReqDocument> {
+        self.with_info(|info| {
+            CertReqDocument::try_from(CertReq {
+                info: info.clone(),
+                algorithm: self.algorithm,
+                signature,
+            })
+        })
+    }
+
+    /// Sign the request using the provided [`Signer`], returning the
+    /// DER-encoded request.
+    ///
+    /// The bytes of the signature are placed in the request as-is, so the
+    /// signature type must use the encoding expected by the algorithm
+    /// identified by the `algorithm` passed to [`RequestBuilder::new`]
+    /// (e.g. ASN.1 DER for ECDSA).
+    #[cfg(feature = "signature")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "signature")))]
+    pub fn build_and_sign<S, Sig>(self, signer: &S) -> Result<CertReqDocument>
+    where
+        S: Signer<Sig>,
+        Sig: Signature,
+    {
+        let signature = signer
+            .try_sign(&self.signing_input()?)
+            .map_err(|_| Error::from(ErrorKind::Failed))?;
+
+        self.build(BitString::from_bytes(signature.as_bytes())?)
+    }
+
+    /// Assemble the [`CertReqInfo`], encoding the `extensionRequest`
+    /// attribute if any extensions have been added.
+    fn with_info<T>(&self, f: impl FnOnce(&CertReqInfo<'_>) -> Result<T>) -> Result<T> {
+        let mut attributes = self.attributes.clone();
+        let extension_req;
+
+        if !self.extensions.is_empty() {
+            extension_req = ExtensionReq(self.extensions.clone()).to_vec()?;
+            attributes.push(Attribute {
+                oid: ExtensionReq::OID,
+                values: SetOfVec::try_from([Any::from_der(&extension_req)?])?,
+            });
+        }
+
+        f(&CertReqInfo {
+            version: request::Version::V1,
+            subject: self.subject.clone(),
+            public_key: self.public_key,
+            attributes: attributes.try_into()?,
+        })
+    }
+}
+
+/// OCSP request builder.
+///
+/// Assembles an unsigned [`OcspRequest`] asking for the status of one or
+/// more certificates, optionally including a nonce extension.
+#[cfg(feature = "digest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct OcspRequestBuilder<'a> {
+    requests: Vec<PendingRequest<'a>>,
+    nonce: Option<&'a [u8]>,
+}
+
+/// [`CertId`] whose hashes are owned by the [`OcspRequestBuilder`].
+#[cfg(feature = "digest")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct PendingRequest<'a> {
+    hash_algorithm: AlgorithmIdentifier<'a>,
+    issuer_name_hash: Vec<u8>,
+    issuer_key_hash: Vec<u8>,
+    serial_number: UIntBytes<'a>,
+}
+
+#[cfg(feature = "digest")]
+impl<'a> OcspRequestBuilder<'a> {
+    /// Create a new [`OcspRequestBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request the status of `cert`, which was issued by `issuer`.
+    ///
+    /// The issuer name and key hashes are computed using the digest `D`,
+    /// which must be the algorithm identified by `hash_algorithm`.
+    ///
+    /// Returns an error if the issuer of `cert` does not match the subject
+    /// of `issuer`.
+    pub fn add_cert<D: Digest>(
+        mut self,
+        hash_algorithm: AlgorithmIdentifier<'a>,
+        cert: &Certificate<'a>,
+        issuer: &Certificate<'_>,
+    ) -> Result<Self> {
+        if !cert
+            .tbs_certificate
+            .issuer
+            .matches(&issuer.tbs_certificate.subject)
+        {
+            return Err(ErrorKind::Failed.into());
+        }
+
+        let issuer_key = issuer
+            .tbs_certificate
+            .subject_public_key_info
+            .subject_public_key;
+
+        self.requests.push(PendingRequest {
+            hash_algorithm,
+            issuer_name_hash: D::digest(cert.tbs_certificate.issuer.to_vec()?).to_vec(),
+            issuer_key_hash: D::digest(issuer_key).to_vec(),
+            serial_number: cert.tbs_certificate.serial_number,
+        });
+
+        Ok(self)
+    }
+
+    /// Include an [`OcspNonce`] extension containing the given bytes.
+    ///
+    /// The responder is expected to echo the nonce in its response.
+    pub fn nonce(mut self, nonce: &'a [u8]) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    /// Build the DER-encoded [`OcspRequest`].
+    pub fn build(&self) -> Result<Vec<u8>> {
+        let mut request_list = Vec::with_capacity(self.requests.len());
+
+        for request in &self.requests {
+            request_list.push(ocsp::Request {
+                req_cert: CertId {
+                    hash_algorithm: request.hash_algorithm,
+                    issuer_name_hash: OctetString::new(&request.issuer_name_hash)?,
+                    issuer_key_hash: OctetString::new(&request.issuer_key_hash)?,
+                    serial_number: request.serial_number,
+                },
+                single_request_extensions: None,
+            });
+        }
+
+        let mut buf = Vec::new();
+        let request_extensions = match self.nonce {
+            Some(nonce) => Some(alloc::vec![
+                OcspNonce(OctetString::new(nonce)?).to_extension(&mut buf)?
+            ]),
+            None => None,
+        };
+
+        OcspRequest {
+            tbs_request: TbsRequest {
+                version: ocsp::Version::V1,
+                requestor_name: None,
+                request_list,
+                request_extensions,
+            },
+            optional_signature: None,
+        }
+        .to_vec()
+    }
+}
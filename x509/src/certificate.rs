@@ -5,8 +5,8 @@ use crate::{name::Name, time::Validity};
 use alloc::vec::Vec;
 
 use const_oid::AssociatedOid;
-use der::asn1::{BitString, UIntBytes};
-use der::{Decode, Enumerated, Error, ErrorKind, Newtype, Sequence};
+use der::asn1::{Any, BitString, UIntBytes};
+use der::{Decode, Enumerated, Error, ErrorKind, Newtype, Sequence, Tag, Tagged};
 use spki::{AlgorithmIdentifier, SubjectPublicKeyInfo};
 
 pub mod document;
@@ -38,6 +38,46 @@ impl Default for Version {
     }
 }
 
+/// Certificate profile, which determines the ASN.1 string types permitted
+/// in the attribute values of the issuer and subject [`Name`]s.
+///
+/// Certificates are always decoded according to the Distinguished Encoding
+/// Rules, however many certificates issued prior to [RFC 5280] use string
+/// types it deprecates.
+///
+/// [RFC 5280]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.1.2.4
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+pub enum Profile {
+    /// Strict [RFC 5280] profile (default), which rejects attribute values
+    /// encoded as `TeletexString`, `UniversalString` or `BMPString`.
+    ///
+    /// [RFC 5280]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.1.2.4
+    Rfc5280,
+
+    /// Lenient profile, which accepts attribute values encoded using any
+    /// ASN.1 type, including the legacy string types.
+    Lenient,
+}
+
+impl Profile {
+    /// Check that an attribute value is permitted by this profile.
+    pub fn check_attribute_value(self, value: &Any<'_>) -> der::Result<()> {
+        match (self, value.tag()) {
+            (
+                Profile::Rfc5280,
+                tag @ (Tag::TeletexString | Tag::UniversalString | Tag::BmpString),
+            ) => Err(tag.unexpected_error(None)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self::Rfc5280
+    }
+}
+
 /// X.509 `TbsCertificate` as defined in [RFC 5280 Section 4.1]
 ///
 /// ASN.1 structure containing the names of the subject and issuer, a public
@@ -145,6 +185,31 @@ pub struct Certificate<'a> {
     pub signature: BitString<'a>,
 }
 
+impl<'a> Certificate<'a> {
+    /// Decode a DER-encoded certificate, checking that the names it
+    /// contains conform to the given [`Profile`].
+    pub fn from_der_with_profile(bytes: &'a [u8], profile: Profile) -> Result<Self, Error> {
+        let cert = Self::from_der(bytes)?;
+        cert.check_profile(profile)?;
+        Ok(cert)
+    }
+
+    /// Check that the issuer and subject names of this certificate conform
+    /// to the given [`Profile`].
+    pub fn check_profile(&self, profile: Profile) -> Result<(), Error> {
+        self.tbs_certificate.issuer.check_profile(profile)?;
+        self.tbs_certificate.subject.check_profile(profile)
+    }
+
+    /// Check this certificate against the RFC 5280 profile and the CA/Browser
+    /// Forum Baseline Requirements.
+    ///
+    /// See [`lint::lint`][`crate::lint::lint`] for details.
+    pub fn lint(&self) -> Vec<crate::lint::Finding> {
+        crate::lint::lint(self)
+    }
+}
+
 /// `PkiPath` as defined by X.509 and referenced by [RFC 6066].
 ///
 /// This contains a series of certificates in validation order from the
@@ -0,0 +1,32 @@
+//! Cryptographic Message Syntax (CMS) types as defined in [RFC 5652].
+//!
+//! The CMS types themselves are provided by the [`pkcs7`] crate and
+//! re-exported here. Types defined by X.509 (certificates, CRLs and names)
+//! are kept as [`Any`](der::asn1::Any) by that crate, and can be decoded
+//! using e.g. [`Certificate`](crate::Certificate).
+//!
+//! Messages produced by streaming implementations (e.g. S/MIME) are often
+//! BER-encoded with indefinite lengths. Such messages can be decoded using
+//! [`ContentInfoDocument::from_ber`], which converts them to DER first.
+//!
+//! [RFC 5652]: https://datatracker.ietf.org/doc/html/rfc5652
+
+pub mod bundle;
+pub mod document;
+
+pub use bundle::CertificateBundle;
+pub use document::ContentInfoDocument;
+
+pub use pkcs7::{
+    encrypted_data_content::EncryptedDataContent,
+    enveloped_data_content::{
+        EncryptedContentInfo, EnvelopedDataContent, KeyTransRecipientInfo, OriginatorInfo,
+        RecipientIdentifier, RecipientInfo,
+    },
+    signed_data_content::{
+        CertificateChoices, CertificateSet, EncapsulatedContentInfo, IssuerAndSerialNumber,
+        RevocationInfoChoice, RevocationInfoChoices, SignedDataContent, SignerIdentifier,
+        SignerInfo, SignerInfos,
+    },
+    CmsVersion, ContentInfo, ContentType, UnorderedSetOf,
+};
@@ -0,0 +1,138 @@
+//! Certificate bundles ("certs-only" PKCS#7 messages)
+
+use super::{
+    CertificateChoices, CmsVersion, ContentInfo, ContentInfoDocument, EncapsulatedContentInfo,
+    SignedDataContent, UnorderedSetOf,
+};
+use crate::certificate::document::CertificateDocument;
+
+use alloc::vec::{self, Vec};
+use core::slice;
+
+use const_oid::db::rfc5911::ID_DATA;
+use der::asn1::{Any, SetOfVec};
+use der::{Decode, Document, Encode, ErrorKind, Result};
+
+/// Bundle of X.509 certificates, such as a CA chain.
+///
+/// Bundles are commonly exchanged as degenerate "certs-only" CMS
+/// [`SignedDataContent`] messages (`.p7b` files), which have no content and no
+/// signers. The order of the certificates is preserved.
+#[derive(Clone, Debug, Default)]
+pub struct CertificateBundle(Vec<CertificateDocument>);
+
+impl CertificateBundle {
+    /// Create a new [`CertificateBundle`].
+    pub fn new(certificates: Vec<CertificateDocument>) -> Self {
+        Self(certificates)
+    }
+
+    /// Extract the certificates from a DER or BER-encoded CMS `ContentInfo`
+    /// containing [`SignedDataContent`].
+    ///
+    /// Certificates in other formats than X.509 (e.g. attribute
+    /// certificates) are skipped. Any content and signers are ignored.
+    pub fn from_pkcs7(bytes: &[u8]) -> Result<Self> {
+        let doc = ContentInfoDocument::from_ber(bytes)?;
+        let signed_data = match doc.decode() {
+            ContentInfo::SignedData(Some(signed_data)) => signed_data,
+            content_info => {
+                return Err(ErrorKind::OidUnknown {
+                    oid: content_info.content_type().to_oid(),
+                }
+                .into())
+            }
+        };
+        let mut certificates = Vec::new();
+
+        for choice in signed_data.certificates.iter().flat_map(|set| &set.0) {
+            if let CertificateChoices::Certificate(cert) = choice {
+                certificates.push(CertificateDocument::try_from(cert.to_vec()?)?);
+            }
+        }
+
+        Ok(Self(certificates))
+    }
+
+    /// Encode the certificates as a certs-only CMS `ContentInfo`.
+    pub fn to_pkcs7(&self) -> Result<ContentInfoDocument> {
+        let certificates = self
+            .0
+            .iter()
+            .map(|cert| Any::from_der(cert.as_ref()).map(CertificateChoices::Certificate))
+            .collect::<Result<_>>()?;
+
+        ContentInfoDocument::try_from(ContentInfo::SignedData(Some(SignedDataContent {
+            version: CmsVersion::V1,
+            digest_algorithms: SetOfVec::new(),
+            encap_content_info: EncapsulatedContentInfo {
+                e_content_type: ID_DATA,
+                e_content: None,
+            },
+            certificates: Some(UnorderedSetOf(certificates)),
+            crls: None,
+            signer_infos: UnorderedSetOf::default(),
+        })))
+    }
+
+    /// Add a certificate to the bundle.
+    pub fn push(&mut self, certificate: CertificateDocument) {
+        self.0.push(certificate);
+    }
+
+    /// Get the certificates in the bundle.
+    pub fn as_slice(&self) -> &[CertificateDocument] {
+        &self.0
+    }
+
+    /// Iterate over the certificates in the bundle.
+    pub fn iter(&self) -> slice::Iter<'_, CertificateDocument> {
+        self.0.iter()
+    }
+
+    /// Get the number of certificates in the bundle.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Is the bundle empty?
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<Vec<CertificateDocument>> for CertificateBundle {
+    fn from(certificates: Vec<CertificateDocument>) -> Self {
+        Self(certificates)
+    }
+}
+
+impl From<CertificateBundle> for Vec<CertificateDocument> {
+    fn from(bundle: CertificateBundle) -> Self {
+        bundle.0
+    }
+}
+
+impl FromIterator<CertificateDocument> for CertificateBundle {
+    fn from_iter<I: IntoIterator<Item = CertificateDocument>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for CertificateBundle {
+    type Item = CertificateDocument;
+    type IntoIter = vec::IntoIter<CertificateDocument>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a CertificateBundle {
+    type Item = &'a CertificateDocument;
+    type IntoIter = slice::Iter<'a, CertificateDocument>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
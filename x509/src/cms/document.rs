@@ -0,0 +1,98 @@
+//! ContentInfoDocument implementation
+
+use super::ContentInfo;
+use der::{ber, Error, Result};
+
+use alloc::vec::Vec;
+use core::fmt;
+use der::{Decode, Document};
+
+#[cfg(feature = "pem")]
+use {core::str::FromStr, der::pem};
+
+/// CMS `ContentInfo` document.
+///
+/// This type provides storage for [`ContentInfo`] encoded as ASN.1
+/// DER with the invariant that the contained-document is "well-formed", i.e.
+/// it will parse successfully according to this crate's parsing rules.
+#[derive(Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct ContentInfoDocument(Vec<u8>);
+
+impl ContentInfoDocument {
+    /// Parse a BER-encoded [`ContentInfo`], converting it to DER.
+    ///
+    /// DER is a subset of BER, so this accepts DER-encoded input as well.
+    pub fn from_ber(bytes: &[u8]) -> Result<Self> {
+        Self::try_from(ber::to_der(bytes)?)
+    }
+}
+
+impl<'a> Document<'a> for ContentInfoDocument {
+    type Message = ContentInfo<'a>;
+    const SENSITIVE: bool = false;
+}
+
+impl AsRef<[u8]> for ContentInfoDocument {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl TryFrom<&[u8]> for ContentInfoDocument {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+impl TryFrom<ContentInfo<'_>> for ContentInfoDocument {
+    type Error = Error;
+
+    fn try_from(content_info: ContentInfo<'_>) -> Result<ContentInfoDocument> {
+        Self::try_from(&content_info)
+    }
+}
+
+impl TryFrom<&ContentInfo<'_>> for ContentInfoDocument {
+    type Error = Error;
+
+    fn try_from(content_info: &ContentInfo<'_>) -> Result<ContentInfoDocument> {
+        Self::from_msg(content_info)
+    }
+}
+
+impl TryFrom<Vec<u8>> for ContentInfoDocument {
+    type Error = Error;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self> {
+        // Ensure document is well-formed
+        ContentInfo::from_der(bytes.as_slice())?;
+        Ok(Self(bytes))
+    }
+}
+
+impl fmt::Debug for ContentInfoDocument {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple("ContentInfoDocument")
+            .field(&self.decode())
+            .finish()
+    }
+}
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl FromStr for ContentInfoDocument {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_pem(s)
+    }
+}
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl pem::PemLabel for ContentInfoDocument {
+    const TYPE_LABEL: &'static str = "CMS";
+}
@@ -5,10 +5,10 @@ use crate::name::Name;
 use crate::time::Time;
 use crate::Version;
 
-use alloc::vec::Vec;
+use core::iter::FusedIterator;
 
-use der::asn1::{BitString, UIntBytes};
-use der::Sequence;
+use der::asn1::{Any, BitString, UIntBytes};
+use der::{Choice, Decode, Decoder, Encode, Encoder, Length, Result, Sequence, Tag, Tagged};
 use spki::AlgorithmIdentifier;
 
 /// `CertificateList` as defined in [RFC 5280 Section 5.1].
@@ -32,8 +32,8 @@ pub struct CertificateList<'a> {
 
 /// Implicit intermediate structure from the ASN.1 definition of `TBSCertList`.
 ///
-/// This type is used for the `revoked_certificates` field of `TbsCertList`.
-/// See [RFC 5280 Section 5.1].
+/// This type is yielded by the iterator over the `revoked_certificates`
+/// field of `TbsCertList`. See [RFC 5280 Section 5.1].
 ///
 ///```text
 /// RevokedCert ::= SEQUENCE {
@@ -79,8 +79,136 @@ pub struct TbsCertList<'a> {
     pub issuer: Name<'a>,
     pub this_update: Time,
     pub next_update: Option<Time>,
-    pub revoked_certificates: Option<Vec<RevokedCert<'a>>>,
+    pub revoked_certificates: Option<RevokedCertificates<'a>>,
 
     #[asn1(context_specific = "0", tag_mode = "EXPLICIT", optional = "true")]
     pub crl_extensions: Option<Extensions<'a>>,
 }
+
+/// `revokedCertificates` field of `TbsCertList` as defined in
+/// [RFC 5280 Section 5.1].
+///
+/// ```text
+/// revokedCertificates     SEQUENCE OF RevokedCert
+/// ```
+///
+/// CRLs may contain a very large number of entries, so rather than decoding
+/// them all up front, this type borrows the encoded entries and decodes them
+/// on demand using [`RevokedCertificates::iter`].
+///
+/// [RFC 5280 Section 5.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-5.1
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RevokedCertificates<'a> {
+    /// Encoded `SEQUENCE OF` the entries.
+    entries: Any<'a>,
+}
+
+impl<'a> RevokedCertificates<'a> {
+    /// Create a new [`RevokedCertificates`] from the concatenated DER
+    /// encodings of its [`RevokedCert`] entries.
+    ///
+    /// The entries are not validated until they are decoded by
+    /// [`RevokedCertificates::iter`].
+    pub fn new(entries: &'a [u8]) -> Result<Self> {
+        Ok(Self {
+            entries: Any::new(Tag::Sequence, entries)?,
+        })
+    }
+
+    /// Borrow the concatenated DER encodings of the entries.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.entries.value()
+    }
+
+    /// Is this list of entries empty?
+    pub fn is_empty(&self) -> bool {
+        self.as_bytes().is_empty()
+    }
+
+    /// Iterate over the entries, decoding each one as it's reached.
+    pub fn iter(&self) -> RevokedCertificatesIter<'a> {
+        RevokedCertificatesIter {
+            decoder: Decoder::new(self.as_bytes()).ok(),
+        }
+    }
+}
+
+impl<'a> Choice<'a> for RevokedCertificates<'a> {
+    fn can_decode(tag: Tag) -> bool {
+        tag == Tag::Sequence
+    }
+}
+
+impl<'a> Decode<'a> for RevokedCertificates<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> Result<Self> {
+        let entries = Any::decode(decoder)?;
+        entries.tag().assert_eq(Tag::Sequence)?;
+        Ok(Self { entries })
+    }
+}
+
+impl Tagged for RevokedCertificates<'_> {
+    fn tag(&self) -> Tag {
+        Tag::Sequence
+    }
+}
+
+impl Encode for RevokedCertificates<'_> {
+    fn encoded_len(&self) -> Result<Length> {
+        self.entries.encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        self.entries.encode(encoder)
+    }
+}
+
+impl<'a> IntoIterator for RevokedCertificates<'a> {
+    type Item = Result<RevokedCert<'a>>;
+    type IntoIter = RevokedCertificatesIter<'a>;
+
+    fn into_iter(self) -> RevokedCertificatesIter<'a> {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &RevokedCertificates<'a> {
+    type Item = Result<RevokedCert<'a>>;
+    type IntoIter = RevokedCertificatesIter<'a>;
+
+    fn into_iter(self) -> RevokedCertificatesIter<'a> {
+        self.iter()
+    }
+}
+
+/// Iterator over the entries of [`RevokedCertificates`].
+///
+/// Each entry is decoded when it is reached. If an error occurs, it is
+/// yielded once and iteration stops.
+#[derive(Clone, Debug)]
+pub struct RevokedCertificatesIter<'a> {
+    /// Decoder for the remaining entries, or `None` after an error.
+    decoder: Option<Decoder<'a>>,
+}
+
+impl<'a> Iterator for RevokedCertificatesIter<'a> {
+    type Item = Result<RevokedCert<'a>>;
+
+    fn next(&mut self) -> Option<Result<RevokedCert<'a>>> {
+        let decoder = self.decoder.as_mut()?;
+
+        if decoder.is_finished() {
+            return None;
+        }
+
+        let result = decoder.decode();
+
+        if result.is_err() {
+            self.decoder = None;
+        }
+
+        Some(result)
+    }
+}
+
+impl<'a> FusedIterator for RevokedCertificatesIter<'a> {}
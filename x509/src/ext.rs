@@ -1,6 +1,9 @@
 //! Standardized X.509 Certificate Extensions
 
-use der::Sequence;
+use alloc::vec::Vec;
+
+use const_oid::AssociatedOid;
+use der::{Encode, Result, Sequence};
 use spki::ObjectIdentifier;
 
 pub mod pkix;
@@ -41,4 +44,29 @@ pub struct Extension<'a> {
 /// ```
 ///
 /// [RFC 5280 Section 4.1.2.9]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.1.2.9
-pub type Extensions<'a> = alloc::vec::Vec<Extension<'a>>;
+pub type Extensions<'a> = Vec<Extension<'a>>;
+
+/// Typed extension which can be converted into an [`Extension`].
+///
+/// Typed extensions can be located and decoded from a certificate using
+/// [`TbsCertificate::get`][`crate::TbsCertificate::get`].
+pub trait AsExtension: AssociatedOid + Encode {
+    /// Whether the extension is marked as critical by default, as
+    /// recommended by [RFC 5280 Section 4.2].
+    ///
+    /// [RFC 5280 Section 4.2]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2
+    const CRITICAL: bool;
+
+    /// Encode this extension into `buf`, returning an [`Extension`] which
+    /// references the encoded value and uses the default criticality.
+    fn to_extension<'b>(&self, buf: &'b mut Vec<u8>) -> Result<Extension<'b>> {
+        buf.clear();
+        self.encode_to_vec(buf)?;
+
+        Ok(Extension {
+            extn_id: Self::OID,
+            critical: Self::CRITICAL,
+            extn_value: buf.as_slice(),
+        })
+    }
+}
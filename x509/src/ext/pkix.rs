@@ -4,13 +4,14 @@ pub mod certpolicy;
 pub mod constraints;
 pub mod crl;
 pub mod name;
+pub mod sct;
 
 mod access;
 mod authkeyid;
 mod keyusage;
 mod policymap;
 
-use crate::attr::AttributeTypeAndValue;
+use crate::{attr::AttributeTypeAndValue, ext::AsExtension};
 
 pub use access::{AccessDescription, AuthorityInfoAccessSyntax, SubjectInfoAccessSyntax};
 pub use authkeyid::AuthorityKeyIdentifier;
@@ -23,6 +24,7 @@ pub use crl::{
 };
 pub use keyusage::{ExtendedKeyUsage, KeyUsage, KeyUsages, PrivateKeyUsagePeriod};
 pub use policymap::{PolicyMapping, PolicyMappings};
+pub use sct::SignedCertificateTimestampList;
 
 pub use const_oid::db::rfc5280::{
     ID_CE_INHIBIT_ANY_POLICY, ID_CE_ISSUER_ALT_NAME, ID_CE_SUBJECT_ALT_NAME,
@@ -47,6 +49,10 @@ impl<'a> AssociatedOid for SubjectKeyIdentifier<'a> {
     const OID: ObjectIdentifier = ID_CE_SUBJECT_KEY_IDENTIFIER;
 }
 
+impl<'a> AsExtension for SubjectKeyIdentifier<'a> {
+    const CRITICAL: bool = false;
+}
+
 /// SubjectAltName as defined in [RFC 5280 Section 4.2.1.6].
 ///
 /// ```text
@@ -61,6 +67,12 @@ impl<'a> AssociatedOid for SubjectAltName<'a> {
     const OID: ObjectIdentifier = ID_CE_SUBJECT_ALT_NAME;
 }
 
+/// Note that [RFC 5280 Section 4.2.1.6] requires this extension to be
+/// marked critical when the subject name is empty.
+impl<'a> AsExtension for SubjectAltName<'a> {
+    const CRITICAL: bool = false;
+}
+
 /// IssuerAltName as defined in [RFC 5280 Section 4.2.1.7].
 ///
 /// ```text
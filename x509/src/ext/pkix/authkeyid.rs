@@ -1,4 +1,5 @@
 use super::name::GeneralNames;
+use crate::ext::AsExtension;
 
 use const_oid::db::rfc5280::ID_CE_AUTHORITY_KEY_IDENTIFIER;
 use const_oid::{AssociatedOid, ObjectIdentifier};
@@ -34,3 +35,7 @@ pub struct AuthorityKeyIdentifier<'a> {
 impl<'a> AssociatedOid for AuthorityKeyIdentifier<'a> {
     const OID: ObjectIdentifier = ID_CE_AUTHORITY_KEY_IDENTIFIER;
 }
+
+impl<'a> AsExtension for AuthorityKeyIdentifier<'a> {
+    const CRITICAL: bool = false;
+}
@@ -1,3 +1,5 @@
+use crate::ext::AsExtension;
+
 use const_oid::{db::rfc5280::ID_CE_BASIC_CONSTRAINTS, AssociatedOid, ObjectIdentifier};
 use der::Sequence;
 
@@ -22,3 +24,7 @@ pub struct BasicConstraints {
 impl AssociatedOid for BasicConstraints {
     const OID: ObjectIdentifier = ID_CE_BASIC_CONSTRAINTS;
 }
+
+impl AsExtension for BasicConstraints {
+    const CRITICAL: bool = true;
+}
@@ -1,5 +1,7 @@
 use alloc::vec::Vec;
 
+use crate::ext::AsExtension;
+
 use const_oid::db::rfc5280::{
     ID_CE_EXT_KEY_USAGE, ID_CE_KEY_USAGE, ID_CE_PRIVATE_KEY_USAGE_PERIOD,
 };
@@ -51,6 +53,10 @@ impl AssociatedOid for KeyUsage {
     const OID: ObjectIdentifier = ID_CE_KEY_USAGE;
 }
 
+impl AsExtension for KeyUsage {
+    const CRITICAL: bool = true;
+}
+
 /// ExtKeyUsageSyntax as defined in [RFC 5280 Section 4.2.1.12].
 ///
 /// Many extended key usage values include:
@@ -74,6 +80,10 @@ impl AssociatedOid for ExtendedKeyUsage {
     const OID: ObjectIdentifier = ID_CE_EXT_KEY_USAGE;
 }
 
+impl AsExtension for ExtendedKeyUsage {
+    const CRITICAL: bool = false;
+}
+
 /// PrivateKeyUsagePeriod as defined in [RFC 3280 Section 4.2.1.4].
 ///
 /// RFC 5280 states "use of this ISO standard extension is neither deprecated nor recommended for use in the Internet PKI."
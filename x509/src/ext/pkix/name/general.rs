@@ -6,6 +6,12 @@ use crate::name::Name;
 use der::asn1::{Ia5String, ObjectIdentifier, OctetString};
 use der::Choice;
 
+#[cfg(feature = "std")]
+use {
+    der::{ErrorKind, Result, Tag},
+    std::net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
 /// GeneralNames as defined in [RFC 5280 Section 4.2.1.6].
 ///
 /// ```text
@@ -61,3 +67,31 @@ pub enum GeneralName<'a> {
     #[asn1(context_specific = "8", tag_mode = "IMPLICIT")]
     RegisteredId(ObjectIdentifier),
 }
+
+impl GeneralName<'_> {
+    /// Get the IP address of an `iPAddress` name.
+    ///
+    /// Returns `Ok(None)` if this is not an `iPAddress` name, and an error if
+    /// the octets are not a 4-byte IPv4 or 16-byte IPv6 address. Note that
+    /// `iPAddress` names in the name constraints extension also include a
+    /// subnet mask, and are therefore rejected by this method.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn ip_addr(&self) -> Result<Option<IpAddr>> {
+        let octets = match self {
+            GeneralName::IpAddress(octets) => octets.as_bytes(),
+            _ => return Ok(None),
+        };
+
+        if let Ok(octets) = <[u8; 4]>::try_from(octets) {
+            Ok(Some(Ipv4Addr::from(octets).into()))
+        } else if let Ok(octets) = <[u8; 16]>::try_from(octets) {
+            Ok(Some(Ipv6Addr::from(octets).into()))
+        } else {
+            Err(ErrorKind::Length {
+                tag: Tag::OctetString,
+            }
+            .into())
+        }
+    }
+}
@@ -0,0 +1,441 @@
+//! Signed Certificate Timestamp (SCT) list extension as defined in [RFC 6962].
+//!
+//! The value of the extension is an `OCTET STRING` containing a
+//! `SignedCertificateTimestampList`, which is encoded using the TLS
+//! presentation language ([RFC 5246 Section 4]) rather than DER.
+//!
+//! [RFC 6962]: https://datatracker.ietf.org/doc/html/rfc6962
+//! [RFC 5246 Section 4]: https://datatracker.ietf.org/doc/html/rfc5246#section-4
+
+use crate::ext::AsExtension;
+
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use const_oid::{AssociatedOid, ObjectIdentifier};
+use der::asn1::OctetString;
+use der::{DecodeValue, Decoder, EncodeValue, Encoder, FixedTag, Header, Length, Result, Tag};
+
+/// `id-ce-embeddedSCTList` Object Identifier (OID).
+///
+/// Also used for the OCSP response extension of the same format.
+pub const CT_PRECERT_SCTS: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.3.6.1.4.1.11129.2.4.2");
+
+/// Length of a [`SignedCertificateTimestamp::log_id`].
+pub const LOG_ID_LENGTH: usize = 32;
+
+/// `SignedCertificateTimestampList` as defined in [RFC 6962 Section 3.3].
+///
+/// ```text
+/// opaque SerializedSCT<1..2^16-1>;
+///
+/// struct {
+///     SerializedSCT sct_list <1..2^16-1>;
+/// } SignedCertificateTimestampList;
+/// ```
+///
+/// [RFC 6962 Section 3.3]: https://datatracker.ietf.org/doc/html/rfc6962#section-3.3
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SignedCertificateTimestampList<'a>(pub Vec<SerializedSct<'a>>);
+
+impl<'a> SignedCertificateTimestampList<'a> {
+    /// Parse a TLS-encoded `SignedCertificateTimestampList`.
+    ///
+    /// SCTs of unknown versions are kept as [`SerializedSct::Unknown`].
+    pub fn from_tls(bytes: &'a [u8]) -> Result<Self> {
+        let mut reader = TlsReader(bytes);
+        let mut list = TlsReader(reader.vector()?);
+        reader.finish()?;
+
+        let mut scts = Vec::new();
+
+        while !list.0.is_empty() {
+            scts.push(SerializedSct::from_tls(list.vector()?)?);
+        }
+
+        if scts.is_empty() {
+            return Err(Self::TAG.value_error());
+        }
+
+        Ok(Self(scts))
+    }
+
+    /// Encode this list using the TLS presentation language.
+    pub fn to_tls(&self) -> Result<Vec<u8>> {
+        let len = self.tls_len()?;
+        let mut bytes = Vec::with_capacity(len);
+
+        // `tls_len` checked that the list fits in a 16-bit length prefix
+        bytes.extend_from_slice(&((len - 2) as u16).to_be_bytes());
+
+        for sct in &self.0 {
+            write_vector(&mut bytes, &sct.to_tls()?)?;
+        }
+
+        debug_assert_eq!(bytes.len(), len);
+        Ok(bytes)
+    }
+
+    /// Iterate over the SCTs in this list, skipping those of unknown
+    /// versions.
+    pub fn scts(&self) -> impl Iterator<Item = &SignedCertificateTimestamp<'a>> {
+        self.0.iter().filter_map(|sct| match sct {
+            SerializedSct::Parsed(sct) => Some(sct),
+            SerializedSct::Unknown(_) => None,
+        })
+    }
+
+    /// Get the length of the TLS encoding of this list.
+    fn tls_len(&self) -> Result<usize> {
+        let mut len = 0;
+
+        for sct in &self.0 {
+            len += vector_len(sct.tls_len()?)?;
+        }
+
+        if len == 0 {
+            return Err(Self::TAG.value_error());
+        }
+
+        vector_len(len)
+    }
+}
+
+impl<'a> DecodeValue<'a> for SignedCertificateTimestampList<'a> {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        Self::from_tls(OctetString::decode_value(decoder, header)?.as_bytes())
+    }
+}
+
+impl EncodeValue for SignedCertificateTimestampList<'_> {
+    fn value_len(&self) -> Result<Length> {
+        Length::try_from(self.tls_len()?)
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        OctetString::new(self.to_tls()?.as_slice())?.encode_value(encoder)
+    }
+}
+
+impl FixedTag for SignedCertificateTimestampList<'_> {
+    const TAG: Tag = Tag::OctetString;
+}
+
+impl<'a> AssociatedOid for SignedCertificateTimestampList<'a> {
+    const OID: ObjectIdentifier = CT_PRECERT_SCTS;
+}
+
+impl<'a> AsExtension for SignedCertificateTimestampList<'a> {
+    const CRITICAL: bool = false;
+}
+
+/// `SerializedSCT` in a [`SignedCertificateTimestampList`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SerializedSct<'a> {
+    /// SCT of a supported version.
+    Parsed(SignedCertificateTimestamp<'a>),
+
+    /// SCT of an unknown version, which is kept as-is so the rest of the
+    /// list can still be used.
+    Unknown(&'a [u8]),
+}
+
+impl<'a> SerializedSct<'a> {
+    /// Parse the contents of a TLS-encoded `SerializedSCT`.
+    pub fn from_tls(bytes: &'a [u8]) -> Result<Self> {
+        match bytes.first() {
+            Some(&version) if version == SctVersion::V1 as u8 => {
+                let mut reader = TlsReader(bytes);
+                let sct = SignedCertificateTimestamp::read(&mut reader)?;
+                reader.finish()?;
+                Ok(Self::Parsed(sct))
+            }
+            Some(_) => Ok(Self::Unknown(bytes)),
+            None => Err(SignedCertificateTimestampList::TAG.value_error()),
+        }
+    }
+
+    /// Encode this SCT using the TLS presentation language.
+    pub fn to_tls(&self) -> Result<Vec<u8>> {
+        match self {
+            Self::Parsed(sct) => sct.to_tls(),
+            Self::Unknown(bytes) => Ok(bytes.to_vec()),
+        }
+    }
+
+    /// Get the length of the TLS encoding of this SCT.
+    fn tls_len(&self) -> Result<usize> {
+        match self {
+            Self::Parsed(sct) => sct.tls_len(),
+            Self::Unknown(bytes) => Ok(bytes.len()),
+        }
+    }
+}
+
+/// `SignedCertificateTimestamp` as defined in [RFC 6962 Section 3.2].
+///
+/// ```text
+/// struct {
+///     Version sct_version;
+///     LogID id;
+///     uint64 timestamp;
+///     CtExtensions extensions;
+///     digitally-signed struct { ... };
+/// } SignedCertificateTimestamp;
+///
+/// enum { v1(0), (255) } Version;
+/// struct { opaque key_id[32]; } LogID;
+/// opaque CtExtensions<0..2^16-1>;
+/// ```
+///
+/// [RFC 6962 Section 3.2]: https://datatracker.ietf.org/doc/html/rfc6962#section-3.2
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SignedCertificateTimestamp<'a> {
+    /// Version of the SCT.
+    pub version: SctVersion,
+
+    /// SHA-256 hash of the log's public key.
+    pub log_id: [u8; LOG_ID_LENGTH],
+
+    /// Time at which the SCT was issued, in milliseconds since `UNIX_EPOCH`.
+    pub timestamp: u64,
+
+    /// Opaque extensions of the SCT.
+    pub extensions: &'a [u8],
+
+    /// Signature of the log over the SCT.
+    pub signature: DigitallySigned<'a>,
+}
+
+impl<'a> SignedCertificateTimestamp<'a> {
+    /// Get the duration of [`SignedCertificateTimestamp::timestamp`] since
+    /// `UNIX_EPOCH`.
+    pub fn unix_duration(&self) -> Duration {
+        Duration::from_millis(self.timestamp)
+    }
+
+    /// Read a version 1 SCT from the given reader.
+    fn read(reader: &mut TlsReader<'a>) -> Result<Self> {
+        let version = match reader.u8()? {
+            0 => SctVersion::V1,
+            _ => return Err(SignedCertificateTimestampList::TAG.value_error()),
+        };
+
+        let mut log_id = [0u8; LOG_ID_LENGTH];
+        log_id.copy_from_slice(reader.bytes(LOG_ID_LENGTH)?);
+
+        let mut timestamp = [0u8; 8];
+        timestamp.copy_from_slice(reader.bytes(8)?);
+
+        Ok(Self {
+            version,
+            log_id,
+            timestamp: u64::from_be_bytes(timestamp),
+            extensions: reader.vector()?,
+            signature: DigitallySigned {
+                hash_algorithm: reader.u8()?.into(),
+                signature_algorithm: reader.u8()?.into(),
+                signature: reader.vector()?,
+            },
+        })
+    }
+
+    /// Encode this SCT using the TLS presentation language.
+    pub fn to_tls(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        bytes.push(self.version as u8);
+        bytes.extend_from_slice(&self.log_id);
+        bytes.extend_from_slice(&self.timestamp.to_be_bytes());
+        write_vector(&mut bytes, self.extensions)?;
+        bytes.push(self.signature.hash_algorithm.into());
+        bytes.push(self.signature.signature_algorithm.into());
+        write_vector(&mut bytes, self.signature.signature)?;
+        Ok(bytes)
+    }
+
+    /// Get the length of the TLS encoding of this SCT.
+    fn tls_len(&self) -> Result<usize> {
+        Ok(1 + LOG_ID_LENGTH
+            + 8
+            + vector_len(self.extensions.len())?
+            + 2
+            + vector_len(self.signature.signature.len())?)
+    }
+}
+
+/// SCT `Version` as defined in [RFC 6962 Section 3.2].
+///
+/// [RFC 6962 Section 3.2]: https://datatracker.ietf.org/doc/html/rfc6962#section-3.2
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SctVersion {
+    /// Version 1
+    V1 = 0,
+}
+
+/// `DigitallySigned` as defined in [RFC 5246 Section 4.7].
+///
+/// ```text
+/// struct {
+///     SignatureAndHashAlgorithm algorithm;
+///     opaque signature<0..2^16-1>;
+/// } DigitallySigned;
+///
+/// struct {
+///     HashAlgorithm hash;
+///     SignatureAlgorithm signature;
+/// } SignatureAndHashAlgorithm;
+/// ```
+///
+/// [RFC 5246 Section 4.7]: https://datatracker.ietf.org/doc/html/rfc5246#section-4.7
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct DigitallySigned<'a> {
+    /// Hash algorithm.
+    pub hash_algorithm: HashAlgorithm,
+
+    /// Signature algorithm.
+    pub signature_algorithm: SignatureAlgorithm,
+
+    /// Signature, e.g. a DER-encoded ECDSA signature.
+    pub signature: &'a [u8],
+}
+
+/// TLS `HashAlgorithm` as defined in [RFC 5246 Section 7.4.1.4.1].
+///
+/// [RFC 5246 Section 7.4.1.4.1]: https://datatracker.ietf.org/doc/html/rfc5246#section-7.4.1.4.1
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum HashAlgorithm {
+    None,
+    Md5,
+    Sha1,
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+    Unknown(u8),
+}
+
+impl From<u8> for HashAlgorithm {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::None,
+            1 => Self::Md5,
+            2 => Self::Sha1,
+            3 => Self::Sha224,
+            4 => Self::Sha256,
+            5 => Self::Sha384,
+            6 => Self::Sha512,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<HashAlgorithm> for u8 {
+    fn from(algorithm: HashAlgorithm) -> u8 {
+        match algorithm {
+            HashAlgorithm::None => 0,
+            HashAlgorithm::Md5 => 1,
+            HashAlgorithm::Sha1 => 2,
+            HashAlgorithm::Sha224 => 3,
+            HashAlgorithm::Sha256 => 4,
+            HashAlgorithm::Sha384 => 5,
+            HashAlgorithm::Sha512 => 6,
+            HashAlgorithm::Unknown(other) => other,
+        }
+    }
+}
+
+/// TLS `SignatureAlgorithm` as defined in [RFC 5246 Section 7.4.1.4.1].
+///
+/// [RFC 5246 Section 7.4.1.4.1]: https://datatracker.ietf.org/doc/html/rfc5246#section-7.4.1.4.1
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum SignatureAlgorithm {
+    Anonymous,
+    Rsa,
+    Dsa,
+    Ecdsa,
+    Unknown(u8),
+}
+
+impl From<u8> for SignatureAlgorithm {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Anonymous,
+            1 => Self::Rsa,
+            2 => Self::Dsa,
+            3 => Self::Ecdsa,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<SignatureAlgorithm> for u8 {
+    fn from(algorithm: SignatureAlgorithm) -> u8 {
+        match algorithm {
+            SignatureAlgorithm::Anonymous => 0,
+            SignatureAlgorithm::Rsa => 1,
+            SignatureAlgorithm::Dsa => 2,
+            SignatureAlgorithm::Ecdsa => 3,
+            SignatureAlgorithm::Unknown(other) => other,
+        }
+    }
+}
+
+/// Reader for TLS-encoded data.
+struct TlsReader<'a>(&'a [u8]);
+
+impl<'a> TlsReader<'a> {
+    /// Read `len` bytes.
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if len > self.0.len() {
+            return Err(SignedCertificateTimestampList::TAG.length_error());
+        }
+
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(bytes)
+    }
+
+    /// Read a single byte.
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    /// Read a vector with a 16-bit length prefix.
+    fn vector(&mut self) -> Result<&'a [u8]> {
+        let len = self.bytes(2)?;
+        self.bytes(usize::from(u16::from_be_bytes([len[0], len[1]])))
+    }
+
+    /// Ensure all data has been read.
+    fn finish(self) -> Result<()> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err(SignedCertificateTimestampList::TAG.value_error())
+        }
+    }
+}
+
+/// Write a vector with a 16-bit length prefix.
+fn write_vector(buf: &mut Vec<u8>, bytes: &[u8]) -> Result<()> {
+    let len = u16::try_from(bytes.len())
+        .map_err(|_| SignedCertificateTimestampList::TAG.length_error())?;
+
+    buf.extend_from_slice(&len.to_be_bytes());
+    buf.extend_from_slice(bytes);
+    Ok(())
+}
+
+/// Get the length of a vector with a 16-bit length prefix containing `len`
+/// bytes.
+fn vector_len(len: usize) -> Result<usize> {
+    if len > usize::from(u16::MAX) {
+        return Err(SignedCertificateTimestampList::TAG.length_error());
+    }
+
+    Ok(2 + len)
+}
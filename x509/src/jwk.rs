@@ -0,0 +1,721 @@
+//! JSON Web Key (JWK) support as defined in [RFC 7517].
+//!
+//! [`Jwk`] can be (de)serialized using [`serde`] and converted to and from
+//! DER-encoded X.509 `SubjectPublicKeyInfo` (SPKI), PKCS#8 and SEC1 keys.
+//!
+//! The following key types are supported:
+//!
+//! - `RSA` keys as defined in [RFC 7518 Section 6.3]
+//! - `EC` keys on the P-256, P-384 and P-521 curves as defined in
+//!   [RFC 7518 Section 6.2]
+//! - `OKP` keys using Ed25519 or X25519 as defined in [RFC 8037]
+//!
+//! Deriving a public key from a private key is out of scope for this module,
+//! so converting a PKCS#8 or SEC1 private key requires it to include the
+//! corresponding public key.
+//!
+//! [RFC 7517]: https://datatracker.ietf.org/doc/html/rfc7517
+//! [RFC 7518 Section 6.2]: https://datatracker.ietf.org/doc/html/rfc7518#section-6.2
+//! [RFC 7518 Section 6.3]: https://datatracker.ietf.org/doc/html/rfc7518#section-6.3
+//! [RFC 8037]: https://datatracker.ietf.org/doc/html/rfc8037
+
+mod base64url;
+mod error;
+
+pub use error::{Error, Result};
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+
+use const_oid::db::rfc5912::{
+    ID_EC_PUBLIC_KEY, RSA_ENCRYPTION, SECP_256_R_1, SECP_384_R_1, SECP_521_R_1,
+};
+use const_oid::ObjectIdentifier;
+use der::asn1::{OctetString, UIntBytes};
+use der::{Decode, Encode};
+use pkcs1::{RsaPrivateKey, RsaPublicKey};
+use pkcs8::PrivateKeyInfo;
+use sec1::{EcParameters, EcPrivateKey};
+use serde::{Deserialize, Serialize};
+use spki::{AlgorithmIdentifier, SubjectPublicKeyInfo};
+
+/// `id-X25519` Object Identifier (OID) as defined in [RFC 8410].
+///
+/// [RFC 8410]: https://datatracker.ietf.org/doc/html/rfc8410#section-3
+pub const ID_X_25519: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.110");
+
+/// `id-Ed25519` Object Identifier (OID) as defined in [RFC 8410].
+///
+/// [RFC 8410]: https://datatracker.ietf.org/doc/html/rfc8410#section-3
+pub const ID_ED_25519: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+
+/// Tag prefixing uncompressed elliptic curve points as defined in SEC1.
+const UNCOMPRESSED_POINT_TAG: u8 = 0x04;
+
+/// JSON Web Key (JWK) as defined in [RFC 7517 Section 4].
+///
+/// Parameters not listed here, e.g. `key_ops` or `x5c`, are ignored when
+/// deserializing.
+///
+/// [RFC 7517 Section 4]: https://datatracker.ietf.org/doc/html/rfc7517#section-4
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct Jwk {
+    /// `kty` (key type) along with the parameters specific to it.
+    #[serde(flatten)]
+    pub params: KeyParams,
+
+    /// `use` (public key use), e.g. `sig` or `enc`.
+    #[serde(rename = "use", default, skip_serializing_if = "Option::is_none")]
+    pub key_use: Option<String>,
+
+    /// `alg` (algorithm) intended for use with the key, e.g. `ES256`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alg: Option<String>,
+
+    /// `kid` (key ID).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+}
+
+impl Jwk {
+    /// Parse a [`Jwk`] from a DER-encoded `SubjectPublicKeyInfo`.
+    pub fn from_public_key_der(bytes: &[u8]) -> Result<Self> {
+        Self::try_from(SubjectPublicKeyInfo::from_der(bytes)?)
+    }
+
+    /// Parse a [`Jwk`] from a DER-encoded PKCS#8 private key.
+    pub fn from_pkcs8_der(bytes: &[u8]) -> Result<Self> {
+        Self::try_from(PrivateKeyInfo::from_der(bytes)?)
+    }
+
+    /// Parse a [`Jwk`] from a DER-encoded SEC1 elliptic curve private key.
+    ///
+    /// The key must include its curve parameters.
+    pub fn from_sec1_der(bytes: &[u8]) -> Result<Self> {
+        Self::try_from(EcPrivateKey::from_der(bytes)?)
+    }
+
+    /// Serialize the public key as a DER-encoded `SubjectPublicKeyInfo`.
+    ///
+    /// This works for both public and private keys.
+    pub fn to_public_key_der(&self) -> Result<Vec<u8>> {
+        let (algorithm, subject_public_key) = match &self.params {
+            KeyParams::Ec(params) => (params.crv.algorithm(), params.to_point()?),
+            KeyParams::Okp(params) => (params.crv.algorithm(), params.public_key()?.to_vec()),
+            KeyParams::Rsa(params) => (pkcs1::ALGORITHM_ID, params.public_key()?.to_vec()?),
+        };
+
+        Ok(SubjectPublicKeyInfo {
+            algorithm,
+            subject_public_key: &subject_public_key,
+        }
+        .to_vec()?)
+    }
+
+    /// Serialize the private key as a DER-encoded PKCS#8 private key.
+    ///
+    /// `OKP` keys are serialized as PKCS#8 v2 including the public key, `EC`
+    /// keys include the public key in the inner SEC1 structure.
+    pub fn to_pkcs8_der(&self) -> Result<Vec<u8>> {
+        match &self.params {
+            KeyParams::Ec(params) => {
+                let point = params.to_point()?;
+                let private_key = params.to_sec1_der(&point, None)?;
+                Ok(PrivateKeyInfo::new(params.crv.algorithm(), &private_key).to_vec()?)
+            }
+            KeyParams::Okp(params) => {
+                let private_key = OctetString::new(params.private_key()?)?.to_vec()?;
+                Ok(PrivateKeyInfo {
+                    algorithm: params.crv.algorithm(),
+                    private_key: &private_key,
+                    attributes: None,
+                    public_key: Some(params.public_key()?),
+                }
+                .to_vec()?)
+            }
+            KeyParams::Rsa(params) => {
+                let private_key = params.private_key()?.to_vec()?;
+                Ok(PrivateKeyInfo::new(pkcs1::ALGORITHM_ID, &private_key).to_vec()?)
+            }
+        }
+    }
+
+    /// Serialize the private key as a DER-encoded SEC1 elliptic curve private
+    /// key including its curve parameters and public key.
+    ///
+    /// Returns [`Error::UnsupportedKeyType`] for keys other than `EC`.
+    pub fn to_sec1_der(&self) -> Result<Vec<u8>> {
+        match &self.params {
+            KeyParams::Ec(params) => {
+                let point = params.to_point()?;
+                params.to_sec1_der(&point, Some(params.crv.oid().into()))
+            }
+            _ => Err(Error::UnsupportedKeyType),
+        }
+    }
+
+    /// Does this JWK contain a private key?
+    pub fn is_private(&self) -> bool {
+        match &self.params {
+            KeyParams::Ec(params) => params.d.is_some(),
+            KeyParams::Okp(params) => params.d.is_some(),
+            KeyParams::Rsa(params) => params.d.is_some(),
+        }
+    }
+
+    /// Get a copy of this JWK with all private key parameters removed.
+    pub fn to_public(&self) -> Self {
+        let params = match &self.params {
+            KeyParams::Ec(params) => KeyParams::Ec(EcParams {
+                d: None,
+                ..params.clone()
+            }),
+            KeyParams::Okp(params) => KeyParams::Okp(OkpParams {
+                d: None,
+                ..params.clone()
+            }),
+            KeyParams::Rsa(params) => KeyParams::Rsa(RsaParams {
+                n: params.n.clone(),
+                e: params.e.clone(),
+                d: None,
+                p: None,
+                q: None,
+                dp: None,
+                dq: None,
+                qi: None,
+            }),
+        };
+
+        Self {
+            params,
+            key_use: self.key_use.clone(),
+            alg: self.alg.clone(),
+            kid: self.kid.clone(),
+        }
+    }
+}
+
+impl From<KeyParams> for Jwk {
+    fn from(params: KeyParams) -> Jwk {
+        Jwk {
+            params,
+            key_use: None,
+            alg: None,
+            kid: None,
+        }
+    }
+}
+
+impl TryFrom<SubjectPublicKeyInfo<'_>> for Jwk {
+    type Error = Error;
+
+    fn try_from(spki: SubjectPublicKeyInfo<'_>) -> Result<Jwk> {
+        let params =
+            match spki.algorithm.oids()? {
+                (ID_EC_PUBLIC_KEY, Some(curve)) => KeyParams::Ec(EcParams::from_point(
+                    EcCurve::from_oid(curve)?,
+                    spki.subject_public_key,
+                    None,
+                )?),
+                (RSA_ENCRYPTION, None) => KeyParams::Rsa(RsaParams::from_public_key(
+                    &RsaPublicKey::from_der(spki.subject_public_key)?,
+                )),
+                (oid, None) if spki.algorithm.parameters.is_none() => KeyParams::Okp(
+                    OkpParams::new(OkpCurve::from_oid(oid)?, spki.subject_public_key, None)?,
+                ),
+                (oid, _) => return Err(Error::UnsupportedAlgorithm { oid }),
+            };
+
+        Ok(params.into())
+    }
+}
+
+impl TryFrom<PrivateKeyInfo<'_>> for Jwk {
+    type Error = Error;
+
+    fn try_from(pki: PrivateKeyInfo<'_>) -> Result<Jwk> {
+        let params = match pki.algorithm.oids()? {
+            (ID_EC_PUBLIC_KEY, Some(curve)) => {
+                let private_key = EcPrivateKey::from_der(pki.private_key)?;
+                let public_key = private_key.public_key.or(pki.public_key);
+                KeyParams::Ec(EcParams::from_sec1(
+                    Some(EcCurve::from_oid(curve)?),
+                    &private_key,
+                    public_key,
+                )?)
+            }
+            (RSA_ENCRYPTION, None) => KeyParams::Rsa(RsaParams::from_private_key(
+                &RsaPrivateKey::from_der(pki.private_key)?,
+            )?),
+            (oid, None) if pki.algorithm.parameters.is_none() => {
+                let private_key = OctetString::from_der(pki.private_key)?;
+                let public_key = pki.public_key.ok_or(Error::MissingPublicKey)?;
+                KeyParams::Okp(OkpParams::new(
+                    OkpCurve::from_oid(oid)?,
+                    public_key,
+                    Some(private_key.as_bytes()),
+                )?)
+            }
+            (oid, _) => return Err(Error::UnsupportedAlgorithm { oid }),
+        };
+
+        Ok(params.into())
+    }
+}
+
+impl TryFrom<EcPrivateKey<'_>> for Jwk {
+    type Error = Error;
+
+    fn try_from(private_key: EcPrivateKey<'_>) -> Result<Jwk> {
+        let params = EcParams::from_sec1(None, &private_key, private_key.public_key)?;
+        Ok(KeyParams::Ec(params).into())
+    }
+}
+
+/// Key type (`kty`) specific JWK parameters.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "kty")]
+pub enum KeyParams {
+    /// Elliptic curve keys (`"kty": "EC"`).
+    #[serde(rename = "EC")]
+    Ec(EcParams),
+
+    /// Octet key pairs (`"kty": "OKP"`).
+    #[serde(rename = "OKP")]
+    Okp(OkpParams),
+
+    /// RSA keys (`"kty": "RSA"`).
+    #[serde(rename = "RSA")]
+    Rsa(RsaParams),
+}
+
+/// Elliptic curves supported for `EC` keys.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub enum EcCurve {
+    /// NIST P-256 (a.k.a. secp256r1).
+    #[serde(rename = "P-256")]
+    P256,
+
+    /// NIST P-384 (a.k.a. secp384r1).
+    #[serde(rename = "P-384")]
+    P384,
+
+    /// NIST P-521 (a.k.a. secp521r1).
+    #[serde(rename = "P-521")]
+    P521,
+}
+
+impl EcCurve {
+    /// Get the [`EcCurve`] identified by the given `namedCurve` OID.
+    pub fn from_oid(oid: ObjectIdentifier) -> Result<Self> {
+        match oid {
+            SECP_256_R_1 => Ok(EcCurve::P256),
+            SECP_384_R_1 => Ok(EcCurve::P384),
+            SECP_521_R_1 => Ok(EcCurve::P521),
+            _ => Err(Error::UnsupportedAlgorithm { oid }),
+        }
+    }
+
+    /// Get the `namedCurve` OID of this curve.
+    pub fn oid(self) -> ObjectIdentifier {
+        match self {
+            EcCurve::P256 => SECP_256_R_1,
+            EcCurve::P384 => SECP_384_R_1,
+            EcCurve::P521 => SECP_521_R_1,
+        }
+    }
+
+    /// Size of field elements, i.e. of the `x`, `y` and `d` parameters, in
+    /// bytes.
+    pub fn field_size(self) -> usize {
+        match self {
+            EcCurve::P256 => 32,
+            EcCurve::P384 => 48,
+            EcCurve::P521 => 66,
+        }
+    }
+
+    fn algorithm(self) -> AlgorithmIdentifier<'static> {
+        AlgorithmIdentifier {
+            oid: ID_EC_PUBLIC_KEY,
+            parameters: Some(self.oid_ref().into()),
+        }
+    }
+
+    fn oid_ref(self) -> &'static ObjectIdentifier {
+        match self {
+            EcCurve::P256 => &SECP_256_R_1,
+            EcCurve::P384 => &SECP_384_R_1,
+            EcCurve::P521 => &SECP_521_R_1,
+        }
+    }
+}
+
+/// `EC` key parameters as defined in [RFC 7518 Section 6.2].
+///
+/// All parameters are big endian and exactly [`EcCurve::field_size`] bytes
+/// long.
+///
+/// [RFC 7518 Section 6.2]: https://datatracker.ietf.org/doc/html/rfc7518#section-6.2
+#[derive(Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct EcParams {
+    /// `crv` (curve).
+    pub crv: EcCurve,
+
+    /// `x` coordinate.
+    #[serde(with = "base64url")]
+    pub x: Vec<u8>,
+
+    /// `y` coordinate.
+    #[serde(with = "base64url")]
+    pub y: Vec<u8>,
+
+    /// `d` (private key).
+    #[serde(
+        with = "base64url::option",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub d: Option<Vec<u8>>,
+}
+
+impl EcParams {
+    /// Create parameters from an uncompressed SEC1 point and an optional
+    /// big endian private scalar, which is left-padded as needed.
+    fn from_point(crv: EcCurve, point: &[u8], d: Option<&[u8]>) -> Result<Self> {
+        let field_size = crv.field_size();
+
+        let coordinates = match point.split_first() {
+            Some((&UNCOMPRESSED_POINT_TAG, coordinates)) if coordinates.len() == field_size * 2 => {
+                coordinates
+            }
+            _ => return Err(Error::KeyMalformed),
+        };
+
+        let d = match d {
+            Some(d) if d.len() > field_size => return Err(Error::KeyMalformed),
+            Some(d) => {
+                let mut padded = vec![0u8; field_size];
+                padded[(field_size - d.len())..].copy_from_slice(d);
+                Some(padded)
+            }
+            None => None,
+        };
+
+        let (x, y) = coordinates.split_at(field_size);
+
+        Ok(Self {
+            crv,
+            x: x.to_vec(),
+            y: y.to_vec(),
+            d,
+        })
+    }
+
+    /// Create parameters from a SEC1 private key, checking its curve
+    /// parameters (if any) against the expected curve.
+    fn from_sec1(
+        crv: Option<EcCurve>,
+        private_key: &EcPrivateKey<'_>,
+        public_key: Option<&[u8]>,
+    ) -> Result<Self> {
+        let crv = match (crv, private_key.parameters.and_then(|p| p.named_curve())) {
+            (Some(crv), None) => crv,
+            (None, Some(oid)) => EcCurve::from_oid(oid)?,
+            (Some(crv), Some(oid)) if crv.oid() == oid => crv,
+            _ => return Err(Error::KeyMalformed),
+        };
+
+        let public_key = public_key.ok_or(Error::MissingPublicKey)?;
+        Self::from_point(crv, public_key, Some(private_key.private_key))
+    }
+
+    /// Serialize the public key as an uncompressed SEC1 point.
+    fn to_point(&self) -> Result<Vec<u8>> {
+        let field_size = self.crv.field_size();
+
+        if self.x.len() != field_size || self.y.len() != field_size {
+            return Err(Error::KeyMalformed);
+        }
+
+        let mut point = Vec::with_capacity(1 + field_size * 2);
+        point.push(UNCOMPRESSED_POINT_TAG);
+        point.extend_from_slice(&self.x);
+        point.extend_from_slice(&self.y);
+        Ok(point)
+    }
+
+    fn to_sec1_der(&self, point: &[u8], parameters: Option<EcParameters>) -> Result<Vec<u8>> {
+        let private_key = self.d.as_ref().ok_or(Error::MissingPrivateKey)?;
+
+        if private_key.len() != self.crv.field_size() {
+            return Err(Error::KeyMalformed);
+        }
+
+        Ok(EcPrivateKey {
+            private_key,
+            parameters,
+            public_key: Some(point),
+        }
+        .to_vec()?)
+    }
+}
+
+impl fmt::Debug for EcParams {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EcParams")
+            .field("crv", &self.crv)
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .finish()
+    }
+}
+
+/// Curves supported for `OKP` (octet key pair) keys.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub enum OkpCurve {
+    /// Ed25519 signature algorithm key pairs.
+    Ed25519,
+
+    /// X25519 function key pairs.
+    X25519,
+}
+
+impl OkpCurve {
+    /// Get the [`OkpCurve`] identified by the given algorithm OID.
+    pub fn from_oid(oid: ObjectIdentifier) -> Result<Self> {
+        match oid {
+            ID_ED_25519 => Ok(OkpCurve::Ed25519),
+            ID_X_25519 => Ok(OkpCurve::X25519),
+            _ => Err(Error::UnsupportedAlgorithm { oid }),
+        }
+    }
+
+    /// Get the algorithm OID of this curve.
+    pub fn oid(self) -> ObjectIdentifier {
+        match self {
+            OkpCurve::Ed25519 => ID_ED_25519,
+            OkpCurve::X25519 => ID_X_25519,
+        }
+    }
+
+    /// Size of the `x` and `d` parameters in bytes.
+    pub fn key_size(self) -> usize {
+        32
+    }
+
+    fn algorithm(self) -> AlgorithmIdentifier<'static> {
+        AlgorithmIdentifier {
+            oid: self.oid(),
+            parameters: None,
+        }
+    }
+}
+
+/// `OKP` key parameters as defined in [RFC 8037 Section 2].
+///
+/// [RFC 8037 Section 2]: https://datatracker.ietf.org/doc/html/rfc8037#section-2
+#[derive(Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct OkpParams {
+    /// `crv` (subtype of key pair).
+    pub crv: OkpCurve,
+
+    /// `x` (public key).
+    #[serde(with = "base64url")]
+    pub x: Vec<u8>,
+
+    /// `d` (private key).
+    #[serde(
+        with = "base64url::option",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub d: Option<Vec<u8>>,
+}
+
+impl OkpParams {
+    fn new(crv: OkpCurve, x: &[u8], d: Option<&[u8]>) -> Result<Self> {
+        let params = Self {
+            crv,
+            x: x.to_vec(),
+            d: d.map(<[u8]>::to_vec),
+        };
+
+        params.public_key()?;
+
+        if params.d.is_some() {
+            params.private_key()?;
+        }
+
+        Ok(params)
+    }
+
+    fn public_key(&self) -> Result<&[u8]> {
+        if self.x.len() == self.crv.key_size() {
+            Ok(&self.x)
+        } else {
+            Err(Error::KeyMalformed)
+        }
+    }
+
+    fn private_key(&self) -> Result<&[u8]> {
+        match &self.d {
+            Some(d) if d.len() == self.crv.key_size() => Ok(d),
+            Some(_) => Err(Error::KeyMalformed),
+            None => Err(Error::MissingPrivateKey),
+        }
+    }
+}
+
+impl fmt::Debug for OkpParams {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OkpParams")
+            .field("crv", &self.crv)
+            .field("x", &self.x)
+            .finish()
+    }
+}
+
+/// `RSA` key parameters as defined in [RFC 7518 Section 6.3].
+///
+/// All parameters are unsigned big endian integers. Private keys must
+/// include all of `d`, `p`, `q`, `dp`, `dq` and `qi`; multi-prime keys
+/// (the `oth` parameter) are not supported.
+///
+/// [RFC 7518 Section 6.3]: https://datatracker.ietf.org/doc/html/rfc7518#section-6.3
+#[derive(Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct RsaParams {
+    /// `n` (modulus).
+    #[serde(with = "base64url")]
+    pub n: Vec<u8>,
+
+    /// `e` (public exponent).
+    #[serde(with = "base64url")]
+    pub e: Vec<u8>,
+
+    /// `d` (private exponent).
+    #[serde(
+        with = "base64url::option",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub d: Option<Vec<u8>>,
+
+    /// `p` (first prime factor).
+    #[serde(
+        with = "base64url::option",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub p: Option<Vec<u8>>,
+
+    /// `q` (second prime factor).
+    #[serde(
+        with = "base64url::option",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub q: Option<Vec<u8>>,
+
+    /// `dp` (first factor CRT exponent).
+    #[serde(
+        with = "base64url::option",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub dp: Option<Vec<u8>>,
+
+    /// `dq` (second factor CRT exponent).
+    #[serde(
+        with = "base64url::option",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub dq: Option<Vec<u8>>,
+
+    /// `qi` (first CRT coefficient).
+    #[serde(
+        with = "base64url::option",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub qi: Option<Vec<u8>>,
+}
+
+impl RsaParams {
+    fn from_public_key(public_key: &RsaPublicKey<'_>) -> Self {
+        Self {
+            n: public_key.modulus.as_bytes().to_vec(),
+            e: public_key.public_exponent.as_bytes().to_vec(),
+            d: None,
+            p: None,
+            q: None,
+            dp: None,
+            dq: None,
+            qi: None,
+        }
+    }
+
+    fn from_private_key(private_key: &RsaPrivateKey<'_>) -> Result<Self> {
+        if private_key.other_prime_infos.is_some() {
+            return Err(Error::UnsupportedKeyType);
+        }
+
+        let param = |uint: UIntBytes<'_>| Some(uint.as_bytes().to_vec());
+
+        Ok(Self {
+            d: param(private_key.private_exponent),
+            p: param(private_key.prime1),
+            q: param(private_key.prime2),
+            dp: param(private_key.exponent1),
+            dq: param(private_key.exponent2),
+            qi: param(private_key.coefficient),
+            ..Self::from_public_key(&private_key.public_key())
+        })
+    }
+
+    fn public_key(&self) -> Result<RsaPublicKey<'_>> {
+        Ok(RsaPublicKey {
+            modulus: UIntBytes::new(&self.n)?,
+            public_exponent: UIntBytes::new(&self.e)?,
+        })
+    }
+
+    fn private_key(&self) -> Result<RsaPrivateKey<'_>> {
+        let private_exponent = self.d.as_ref().ok_or(Error::MissingPrivateKey)?;
+
+        let public_key = self.public_key()?;
+
+        Ok(RsaPrivateKey {
+            modulus: public_key.modulus,
+            public_exponent: public_key.public_exponent,
+            private_exponent: UIntBytes::new(private_exponent)?,
+            prime1: private_param(&self.p)?,
+            prime2: private_param(&self.q)?,
+            exponent1: private_param(&self.dp)?,
+            exponent2: private_param(&self.dq)?,
+            coefficient: private_param(&self.qi)?,
+            other_prime_infos: None,
+        })
+    }
+}
+
+/// Get a private RSA key parameter, which must be present if `d` is.
+fn private_param(param: &Option<Vec<u8>>) -> Result<UIntBytes<'_>> {
+    match param {
+        Some(bytes) => Ok(UIntBytes::new(bytes)?),
+        None => Err(Error::KeyMalformed),
+    }
+}
+
+impl fmt::Debug for RsaParams {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RsaParams")
+            .field("n", &self.n)
+            .field("e", &self.e)
+            .finish()
+    }
+}
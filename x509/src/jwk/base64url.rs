@@ -0,0 +1,43 @@
+//! Serde helpers for Base64url-encoded JWK parameters.
+//!
+//! [RFC 7518 Section 2] requires unpadded Base64url without line breaks or
+//! other additional characters, which is exactly what [`Base64UrlUnpadded`]
+//! accepts.
+//!
+//! [RFC 7518 Section 2]: https://datatracker.ietf.org/doc/html/rfc7518#section-2
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use base64ct::{Base64UrlUnpadded, Encoding};
+use serde::{de, Deserialize, Deserializer, Serializer};
+
+pub(super) fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&Base64UrlUnpadded::encode_string(bytes))
+}
+
+pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    let encoded = String::deserialize(deserializer)?;
+    Base64UrlUnpadded::decode_vec(&encoded).map_err(de::Error::custom)
+}
+
+/// Helpers for optional parameters, i.e. private key parameters.
+pub(super) mod option {
+    use super::*;
+
+    pub(in crate::jwk) fn serialize<S: Serializer>(
+        bytes: &Option<Vec<u8>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match bytes {
+            Some(bytes) => super::serialize(bytes, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(in crate::jwk) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Vec<u8>>, D::Error> {
+        super::deserialize(deserializer).map(Some)
+    }
+}
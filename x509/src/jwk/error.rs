@@ -0,0 +1,66 @@
+//! Error types
+
+use const_oid::ObjectIdentifier;
+use core::fmt;
+
+/// Result type
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Error type
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// ASN.1 DER-related errors.
+    Asn1(der::Error),
+
+    /// Key parameters are malformed or inconsistent, e.g. coordinates of the
+    /// wrong length or incomplete RSA private key parameters.
+    KeyMalformed,
+
+    /// Operation requires a private key, but the JWK only contains a public
+    /// key.
+    MissingPrivateKey,
+
+    /// Private key does not contain the public key, which can't be computed
+    /// by this module.
+    MissingPublicKey,
+
+    /// Algorithm or elliptic curve is not supported.
+    UnsupportedAlgorithm {
+        /// OID of the unsupported algorithm or curve.
+        oid: ObjectIdentifier,
+    },
+
+    /// Operation is not supported for this key type.
+    UnsupportedKeyType,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Asn1(err) => write!(f, "JWK ASN.1 error: {}", err),
+            Error::KeyMalformed => f.write_str("JWK key parameters malformed"),
+            Error::MissingPrivateKey => f.write_str("JWK does not contain a private key"),
+            Error::MissingPublicKey => f.write_str("private key does not contain a public key"),
+            Error::UnsupportedAlgorithm { oid } => {
+                write!(f, "JWK algorithm {} is unsupported", oid)
+            }
+            Error::UnsupportedKeyType => f.write_str("operation unsupported for JWK key type"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<der::Error> for Error {
+    fn from(err: der::Error) -> Error {
+        Error::Asn1(err)
+    }
+}
+
+impl From<der::ErrorKind> for Error {
+    fn from(err: der::ErrorKind) -> Error {
+        Error::Asn1(err.into())
+    }
+}
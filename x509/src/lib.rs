@@ -23,11 +23,26 @@ pub use der;
 
 pub mod anchor;
 pub mod attr;
+pub mod builder;
 pub mod certificate;
+pub mod cms;
 pub mod crl;
 pub mod ext;
+#[cfg(feature = "jwk")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jwk")))]
+pub mod jwk;
+pub mod lint;
 pub mod name;
+pub mod ocsp;
+pub mod path;
+#[cfg(feature = "pkcs12")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs12")))]
+pub mod pkcs12;
 pub mod request;
 pub mod time;
+pub mod tsp;
 
-pub use certificate::{Certificate, PkiPath, TbsCertificate, Version};
+#[cfg(feature = "digest")]
+pub use builder::OcspRequestBuilder;
+pub use builder::{CertificateBuilder, RequestBuilder};
+pub use certificate::{Certificate, PkiPath, Profile, TbsCertificate, Version};
@@ -0,0 +1,520 @@
+//! Certificate linting against the [RFC 5280] profile and the CA/Browser
+//! Forum [Baseline Requirements].
+//!
+//! [`lint`] checks the structure of a certificate against the rules these
+//! documents place on certificate issuers, and returns a [`Finding`] for
+//! each rule which is violated. Certificates with findings can still be
+//! decoded and used: linting is intended for CAs checking certificates
+//! before issuing them, and for tools which audit issued certificates.
+//!
+//! Only rules which can be checked using the certificate alone are
+//! implemented. Some rules are enforced when decoding instead, e.g. negative
+//! serial numbers and time values which aren't encoded as described in
+//! [RFC 5280 Section 4.1.2.5] are rejected by [`der`].
+//!
+//! [RFC 5280]: https://datatracker.ietf.org/doc/html/rfc5280
+//! [Baseline Requirements]: https://cabforum.org/baseline-requirements-documents/
+//! [RFC 5280 Section 4.1.2.5]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.1.2.5
+
+use crate::{
+    certificate::{Certificate, TbsCertificate, Version},
+    ext::{pkix::BasicConstraints, Extension},
+    time::Time,
+};
+
+use alloc::vec::Vec;
+use const_oid::{
+    db::{
+        rfc5280::{
+            ID_CE_AUTHORITY_KEY_IDENTIFIER, ID_CE_BASIC_CONSTRAINTS, ID_CE_INHIBIT_ANY_POLICY,
+            ID_CE_KEY_USAGE, ID_CE_NAME_CONSTRAINTS, ID_CE_POLICY_CONSTRAINTS,
+            ID_CE_SUBJECT_ALT_NAME, ID_CE_SUBJECT_KEY_IDENTIFIER, ID_PE_AUTHORITY_INFO_ACCESS,
+            ID_PE_SUBJECT_INFO_ACCESS,
+        },
+        rfc5912::{
+            DSA_WITH_SHA_1, MD_2_WITH_RSA_ENCRYPTION, MD_5_WITH_RSA_ENCRYPTION,
+            SHA_1_WITH_RSA_ENCRYPTION,
+        },
+    },
+    ObjectIdentifier,
+};
+use core::fmt;
+use der::{Decode, EncodeValue};
+
+/// `ecdsa-with-SHA1` Object Identifier (OID).
+const ECDSA_WITH_SHA_1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.1");
+
+/// Signature algorithms which use a hash function that is no longer
+/// considered collision resistant.
+const DEPRECATED_SIGNATURE_ALGORITHMS: &[ObjectIdentifier] = &[
+    MD_2_WITH_RSA_ENCRYPTION,
+    MD_5_WITH_RSA_ENCRYPTION,
+    SHA_1_WITH_RSA_ENCRYPTION,
+    DSA_WITH_SHA_1,
+    ECDSA_WITH_SHA_1,
+];
+
+/// Extensions which must (or must not) be marked critical, along with the
+/// lint which is violated otherwise.
+const EXTENSION_CRITICALITY: &[(ObjectIdentifier, bool, Lint)] = &[
+    (
+        ID_CE_AUTHORITY_KEY_IDENTIFIER,
+        false,
+        Lint::AuthorityKeyIdCritical,
+    ),
+    (
+        ID_CE_SUBJECT_KEY_IDENTIFIER,
+        false,
+        Lint::SubjectKeyIdCritical,
+    ),
+    (ID_CE_KEY_USAGE, true, Lint::KeyUsageNotCritical),
+    (
+        ID_CE_NAME_CONSTRAINTS,
+        true,
+        Lint::NameConstraintsNotCritical,
+    ),
+    (
+        ID_CE_POLICY_CONSTRAINTS,
+        true,
+        Lint::PolicyConstraintsNotCritical,
+    ),
+    (
+        ID_CE_INHIBIT_ANY_POLICY,
+        true,
+        Lint::InhibitAnyPolicyNotCritical,
+    ),
+    (
+        ID_PE_AUTHORITY_INFO_ACCESS,
+        false,
+        Lint::AuthorityInfoAccessCritical,
+    ),
+    (
+        ID_PE_SUBJECT_INFO_ACCESS,
+        false,
+        Lint::SubjectInfoAccessCritical,
+    ),
+];
+
+/// Maximum length of an encoded serial number in bytes ([RFC 5280 Section 4.1.2.2]).
+///
+/// [RFC 5280 Section 4.1.2.2]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.1.2.2
+const MAX_SERIAL_NUMBER_LEN: usize = 20;
+
+/// Minimum length of a serial number in bytes for it to contain 64 bits of
+/// CSPRNG output ([Baseline Requirements] Section 7.1).
+///
+/// [Baseline Requirements]: https://cabforum.org/baseline-requirements-documents/
+const MIN_SERIAL_NUMBER_LEN: usize = 8;
+
+/// Maximum validity period of a subscriber certificate in seconds, i.e. 398
+/// days ([Baseline Requirements] Section 6.3.2).
+///
+/// [Baseline Requirements]: https://cabforum.org/baseline-requirements-documents/
+const MAX_SUBSCRIBER_VALIDITY_SECS: u64 = 398 * 86400;
+
+/// First year which must be encoded as `GeneralizedTime`.
+const GENERALIZED_TIME_MIN_YEAR: u16 = 2050;
+
+/// Check a certificate against the [RFC 5280] profile and the CA/Browser
+/// Forum [Baseline Requirements], returning the rules it violates.
+///
+/// Findings are returned in the order the corresponding fields appear in the
+/// certificate. An empty result means no violations were found.
+///
+/// [RFC 5280]: https://datatracker.ietf.org/doc/html/rfc5280
+/// [Baseline Requirements]: https://cabforum.org/baseline-requirements-documents/
+pub fn lint(certificate: &Certificate<'_>) -> Vec<Finding> {
+    let tbs = &certificate.tbs_certificate;
+    let mut findings = Vec::new();
+
+    lint_version(tbs, &mut findings);
+    lint_serial_number(tbs, &mut findings);
+
+    if tbs.signature != certificate.signature_algorithm {
+        findings.push(Lint::SignatureAlgorithmMismatch.into());
+    }
+
+    if DEPRECATED_SIGNATURE_ALGORITHMS.contains(&certificate.signature_algorithm.oid) {
+        findings.push(Finding::with_oid(
+            Lint::DeprecatedSignatureAlgorithm,
+            certificate.signature_algorithm.oid,
+        ));
+    }
+
+    lint_validity(tbs, &mut findings);
+    lint_extensions(tbs, &mut findings);
+    findings
+}
+
+/// Check the version and the fields which depend on it.
+fn lint_version(tbs: &TbsCertificate<'_>, findings: &mut Vec<Finding>) {
+    if tbs.version != Version::V3 {
+        findings.push(Lint::VersionNotV3.into());
+    }
+
+    if tbs.issuer_unique_id.is_some() || tbs.subject_unique_id.is_some() {
+        if tbs.version == Version::V1 {
+            findings.push(Lint::UniqueIdRequiresV2.into());
+        }
+
+        findings.push(Lint::UniqueIdPresent.into());
+    }
+
+    if tbs.extensions.is_some() && tbs.version != Version::V3 {
+        findings.push(Lint::ExtensionsRequireV3.into());
+    }
+}
+
+/// Check the serial number.
+fn lint_serial_number(tbs: &TbsCertificate<'_>, findings: &mut Vec<Finding>) {
+    let serial_number = tbs.serial_number.as_bytes();
+
+    if serial_number.iter().all(|&byte| byte == 0) {
+        findings.push(Lint::SerialNumberNotPositive.into());
+    }
+
+    // The limit applies to the encoded `INTEGER`, which includes a leading
+    // zero byte when the most significant bit is set
+    let encoded_len = tbs
+        .serial_number
+        .value_len()
+        .and_then(usize::try_from)
+        .unwrap_or(usize::MAX);
+
+    if encoded_len > MAX_SERIAL_NUMBER_LEN {
+        findings.push(Lint::SerialNumberTooLong.into());
+    }
+
+    if serial_number.len() < MIN_SERIAL_NUMBER_LEN {
+        findings.push(Lint::SerialNumberLowEntropy.into());
+    }
+}
+
+/// Check the validity period.
+fn lint_validity(tbs: &TbsCertificate<'_>, findings: &mut Vec<Finding>) {
+    let validity = &tbs.validity;
+
+    if [validity.not_before, validity.not_after]
+        .iter()
+        .any(|time| match time {
+            Time::GeneralTime(time) => time.to_date_time().year() < GENERALIZED_TIME_MIN_YEAR,
+            Time::UtcTime(_) => false,
+        })
+    {
+        findings.push(Lint::GeneralizedTimeBefore2050.into());
+    }
+
+    // The validity period includes both `notBefore` and `notAfter`, so it
+    // lasts one second longer than their difference
+    let not_before = validity.not_before.to_unix_duration().as_secs();
+    let not_after = validity.not_after.to_unix_duration().as_secs();
+
+    if !is_ca(tbs) && not_after.saturating_sub(not_before) >= MAX_SUBSCRIBER_VALIDITY_SECS {
+        findings.push(Lint::ValidityPeriodTooLong.into());
+    }
+}
+
+/// Check the extensions.
+fn lint_extensions(tbs: &TbsCertificate<'_>, findings: &mut Vec<Finding>) {
+    let extensions = match tbs.extensions.as_deref() {
+        Some([]) => {
+            findings.push(Lint::ExtensionsEmpty.into());
+            &[]
+        }
+        Some(extensions) => extensions,
+        None => &[],
+    };
+
+    for (i, extension) in extensions.iter().enumerate() {
+        let previous = extensions[..i]
+            .iter()
+            .filter(|e| e.extn_id == extension.extn_id)
+            .count();
+
+        // Only report the first duplicate of each extension
+        if previous == 1 {
+            findings.push(Finding::with_oid(
+                Lint::DuplicateExtension,
+                extension.extn_id,
+            ));
+        }
+    }
+
+    let is_ca = is_ca(tbs);
+
+    if let Some(extension) = find_extension(extensions, ID_CE_BASIC_CONSTRAINTS) {
+        if is_ca && !extension.critical {
+            findings.push(Finding::with_oid(
+                Lint::BasicConstraintsNotCritical,
+                extension.extn_id,
+            ));
+        }
+    }
+
+    for &(oid, critical, lint) in EXTENSION_CRITICALITY {
+        if let Some(extension) = find_extension(extensions, oid) {
+            if extension.critical != critical {
+                findings.push(Finding::with_oid(lint, oid));
+            }
+        }
+    }
+
+    if find_extension(extensions, ID_CE_AUTHORITY_KEY_IDENTIFIER).is_none()
+        && !tbs.issuer.matches(&tbs.subject)
+    {
+        findings.push(Lint::AuthorityKeyIdMissing.into());
+    }
+
+    if is_ca && find_extension(extensions, ID_CE_SUBJECT_KEY_IDENTIFIER).is_none() {
+        findings.push(Lint::SubjectKeyIdMissing.into());
+    }
+
+    if tbs.subject.0.is_empty() {
+        match find_extension(extensions, ID_CE_SUBJECT_ALT_NAME) {
+            Some(extension) if !extension.critical => findings.push(Finding::with_oid(
+                Lint::SubjectAltNameNotCritical,
+                extension.extn_id,
+            )),
+            Some(_) => (),
+            None => findings.push(Lint::SubjectMissing.into()),
+        }
+    }
+}
+
+/// Find the first extension with the given OID.
+fn find_extension<'e, 'a>(
+    extensions: &'e [Extension<'a>],
+    oid: ObjectIdentifier,
+) -> Option<&'e Extension<'a>> {
+    extensions.iter().find(|extension| extension.extn_id == oid)
+}
+
+/// Is the certificate a CA certificate, i.e. does it have a
+/// `BasicConstraints` extension with `cA` set?
+fn is_ca(tbs: &TbsCertificate<'_>) -> bool {
+    tbs.extensions
+        .as_deref()
+        .and_then(|extensions| find_extension(extensions, ID_CE_BASIC_CONSTRAINTS))
+        .and_then(|extension| BasicConstraints::from_der(extension.extn_value).ok())
+        .map_or(false, |basic_constraints| basic_constraints.ca)
+}
+
+/// Document which defines the rule checked by a [`Lint`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Source {
+    /// [RFC 5280]: Internet X.509 Public Key Infrastructure Certificate and
+    /// CRL Profile.
+    ///
+    /// [RFC 5280]: https://datatracker.ietf.org/doc/html/rfc5280
+    Rfc5280,
+
+    /// CA/Browser Forum [Baseline Requirements] for the issuance of publicly
+    /// trusted TLS server certificates.
+    ///
+    /// [Baseline Requirements]: https://cabforum.org/baseline-requirements-documents/
+    CabfBaselineRequirements,
+}
+
+/// Severity of a [`Lint`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The rule is a recommendation ("SHOULD").
+    Warning,
+
+    /// The rule is a requirement ("MUST").
+    Error,
+}
+
+/// Rule checked by [`lint`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Lint {
+    /// Certificate isn't version 3.
+    VersionNotV3,
+
+    /// Certificate contains a unique identifier, but is version 1.
+    UniqueIdRequiresV2,
+
+    /// Certificate contains a unique identifier, which conforming CAs must
+    /// not generate.
+    UniqueIdPresent,
+
+    /// Certificate contains extensions, but isn't version 3.
+    ExtensionsRequireV3,
+
+    /// Serial number is zero.
+    SerialNumberNotPositive,
+
+    /// Serial number is longer than 20 bytes.
+    SerialNumberTooLong,
+
+    /// Serial number is too short to contain 64 bits of CSPRNG output.
+    SerialNumberLowEntropy,
+
+    /// `signature` field of the `TBSCertificate` differs from the
+    /// certificate's `signatureAlgorithm`.
+    SignatureAlgorithmMismatch,
+
+    /// Signature algorithm uses MD2, MD5 or SHA-1.
+    DeprecatedSignatureAlgorithm,
+
+    /// Validity period contains a date before 2050 which is encoded as
+    /// `GeneralizedTime` rather than `UTCTime`.
+    GeneralizedTimeBefore2050,
+
+    /// Validity period of a subscriber certificate is longer than 398 days.
+    ValidityPeriodTooLong,
+
+    /// `extensions` field is present, but empty.
+    ExtensionsEmpty,
+
+    /// Certificate contains more than one instance of an extension.
+    DuplicateExtension,
+
+    /// `BasicConstraints` of a CA certificate isn't marked critical.
+    BasicConstraintsNotCritical,
+
+    /// `KeyUsage` isn't marked critical.
+    KeyUsageNotCritical,
+
+    /// `NameConstraints` isn't marked critical.
+    NameConstraintsNotCritical,
+
+    /// `PolicyConstraints` isn't marked critical.
+    PolicyConstraintsNotCritical,
+
+    /// `InhibitAnyPolicy` isn't marked critical.
+    InhibitAnyPolicyNotCritical,
+
+    /// `AuthorityKeyIdentifier` is marked critical.
+    AuthorityKeyIdCritical,
+
+    /// `SubjectKeyIdentifier` is marked critical.
+    SubjectKeyIdCritical,
+
+    /// `AuthorityInfoAccess` is marked critical.
+    AuthorityInfoAccessCritical,
+
+    /// `SubjectInfoAccess` is marked critical.
+    SubjectInfoAccessCritical,
+
+    /// Certificate which isn't self-issued has no `AuthorityKeyIdentifier`.
+    AuthorityKeyIdMissing,
+
+    /// CA certificate has no `SubjectKeyIdentifier`.
+    SubjectKeyIdMissing,
+
+    /// Subject is empty and there is no `SubjectAltName`.
+    SubjectMissing,
+
+    /// Subject is empty, but `SubjectAltName` isn't marked critical.
+    SubjectAltNameNotCritical,
+}
+
+impl Lint {
+    /// Get the document which defines the rule.
+    pub fn source(self) -> Source {
+        match self {
+            Lint::VersionNotV3
+            | Lint::SerialNumberLowEntropy
+            | Lint::DeprecatedSignatureAlgorithm
+            | Lint::ValidityPeriodTooLong => Source::CabfBaselineRequirements,
+            _ => Source::Rfc5280,
+        }
+    }
+
+    /// Get the severity of violating the rule.
+    pub fn severity(self) -> Severity {
+        match self {
+            Lint::KeyUsageNotCritical | Lint::SerialNumberLowEntropy => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    /// Get a description of the violation.
+    pub fn description(self) -> &'static str {
+        match self {
+            Lint::VersionNotV3 => "certificate is not version 3",
+            Lint::UniqueIdRequiresV2 => "unique identifiers require version 2 or 3",
+            Lint::UniqueIdPresent => "certificate contains a unique identifier",
+            Lint::ExtensionsRequireV3 => "extensions require version 3",
+            Lint::SerialNumberNotPositive => "serial number is not positive",
+            Lint::SerialNumberTooLong => "serial number is longer than 20 octets",
+            Lint::SerialNumberLowEntropy => "serial number is shorter than 64 bits",
+            Lint::SignatureAlgorithmMismatch => {
+                "signature algorithm differs from TBSCertificate signature"
+            }
+            Lint::DeprecatedSignatureAlgorithm => "signature algorithm is deprecated",
+            Lint::GeneralizedTimeBefore2050 => "validity date before 2050 is not UTCTime",
+            Lint::ValidityPeriodTooLong => "validity period is longer than 398 days",
+            Lint::ExtensionsEmpty => "extensions are present but empty",
+            Lint::DuplicateExtension => "extension appears more than once",
+            Lint::BasicConstraintsNotCritical => "CA basic constraints are not critical",
+            Lint::KeyUsageNotCritical => "key usage is not critical",
+            Lint::NameConstraintsNotCritical => "name constraints are not critical",
+            Lint::PolicyConstraintsNotCritical => "policy constraints are not critical",
+            Lint::InhibitAnyPolicyNotCritical => "inhibit any policy is not critical",
+            Lint::AuthorityKeyIdCritical => "authority key identifier is critical",
+            Lint::SubjectKeyIdCritical => "subject key identifier is critical",
+            Lint::AuthorityInfoAccessCritical => "authority information access is critical",
+            Lint::SubjectInfoAccessCritical => "subject information access is critical",
+            Lint::AuthorityKeyIdMissing => "authority key identifier is missing",
+            Lint::SubjectKeyIdMissing => "CA subject key identifier is missing",
+            Lint::SubjectMissing => "subject and subject alternative name are missing",
+            Lint::SubjectAltNameNotCritical => {
+                "subject alternative name with empty subject is not critical"
+            }
+        }
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+/// Violation of a [`Lint`] found in a certificate.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Finding {
+    /// Rule which is violated.
+    pub lint: Lint,
+
+    /// OID of the extension or algorithm which violates the rule, if any.
+    pub oid: Option<ObjectIdentifier>,
+}
+
+impl Finding {
+    /// Create a finding for the given extension or algorithm.
+    fn with_oid(lint: Lint, oid: ObjectIdentifier) -> Self {
+        Self {
+            lint,
+            oid: Some(oid),
+        }
+    }
+
+    /// Get the document which defines the violated rule.
+    pub fn source(&self) -> Source {
+        self.lint.source()
+    }
+
+    /// Get the severity of the violation.
+    pub fn severity(&self) -> Severity {
+        self.lint.severity()
+    }
+}
+
+impl From<Lint> for Finding {
+    fn from(lint: Lint) -> Finding {
+        Self { lint, oid: None }
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.oid {
+            Some(oid) => write!(f, "{} ({})", self.lint, oid),
+            None => write!(f, "{}", self.lint),
+        }
+    }
+}
@@ -1,8 +1,10 @@
 //! Name-related definitions as defined in X.501 (and updated by RFC 5280).
 
+pub mod document;
+
 use alloc::vec::Vec;
 
-use crate::attr::AttributeTypeAndValue;
+use crate::{attr::AttributeTypeAndValue, certificate::Profile};
 
 use der::{asn1::SetOfVec, Decode, Encode, Newtype};
 
@@ -57,7 +59,9 @@ fn split(s: &str, b: u8) -> impl '_ + Iterator<Item = &'_ str> {
 impl RdnSequence<'_> {
     /// Converts an RDNSequence string into an encoded RDNSequence
     ///
-    /// This function follows the rules in [RFC 4514].
+    /// This function follows the rules in [RFC 4514]. Note that the string
+    /// representation lists the RDNs in reverse order, i.e. the last RDN of
+    /// the encoded sequence comes first.
     ///
     /// [RFC 4514]: https://datatracker.ietf.org/doc/html/rfc4514
     pub fn encode_from_string(s: &str) -> Result<Vec<u8>, der::Error> {
@@ -66,20 +70,45 @@ impl RdnSequence<'_> {
             .collect::<Result<Vec<_>, der::Error>>()?;
 
         let mut out = Vec::new();
-        for der in ders.iter() {
+        for der in ders.iter().rev() {
             out.push(RelativeDistinguishedName::from_der(der)?);
         }
 
         RdnSequence(out).to_vec()
     }
+
+    /// Compare this RDNSequence with another using the name matching rules
+    /// in [RFC 5280 Section 7.1].
+    ///
+    /// Both sequences must contain the same number of RDNs, and each pair of
+    /// RDNs must contain matching attributes. `PrintableString` values are
+    /// compared using `caseIgnoreMatch`, all other values must be identical.
+    ///
+    /// [RFC 5280 Section 7.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-7.1
+    pub fn matches(&self, other: &RdnSequence<'_>) -> bool {
+        self.0.len() == other.0.len()
+            && self.0.iter().zip(other.0.iter()).all(|(a, b)| a.matches(b))
+    }
+
+    /// Check that all attribute values in this RDNSequence are permitted by
+    /// the given [`Profile`].
+    pub fn check_profile(&self, profile: Profile) -> Result<(), der::Error> {
+        self.0
+            .iter()
+            .flat_map(|rdn| rdn.0.iter())
+            .try_for_each(|atv| profile.check_attribute_value(&atv.value))
+    }
 }
 
 /// Serializes the structure according to the rules in [RFC 4514].
 ///
+/// The RDNs are output in reverse order, starting with the last RDN of the
+/// sequence.
+///
 /// [RFC 4514]: https://datatracker.ietf.org/doc/html/rfc4514
 impl core::fmt::Display for RdnSequence<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        for (i, atv) in self.0.iter().enumerate() {
+        for (i, atv) in self.0.iter().rev().enumerate() {
             match i {
                 0 => write!(f, "{}", atv)?,
                 _ => write!(f, ",{}", atv)?,
@@ -144,6 +173,17 @@ impl RelativeDistinguishedName<'_> {
 
         RelativeDistinguishedName(atvs.try_into()?).to_vec()
     }
+
+    /// Compare this RelativeDistinguishedName with another using the name
+    /// matching rules in [RFC 5280 Section 7.1].
+    ///
+    /// Each attribute in one RDN must match an attribute in the other,
+    /// regardless of their order.
+    ///
+    /// [RFC 5280 Section 7.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-7.1
+    pub fn matches(&self, other: &RelativeDistinguishedName<'_>) -> bool {
+        self.0.len() == other.0.len() && self.0.iter().all(|a| other.0.iter().any(|b| a.matches(b)))
+    }
 }
 
 /// Serializes the structure according to the rules in [RFC 4514].
@@ -0,0 +1,100 @@
+//! NameDocument implementation
+
+use super::Name;
+use der::{Error, Result};
+
+use alloc::vec::Vec;
+use core::{fmt, str::FromStr};
+use der::{Decode, Document};
+
+/// Name document.
+///
+/// This type provides storage for [`Name`] encoded as ASN.1 DER with the
+/// invariant that the contained-document is "well-formed", i.e. it will
+/// parse successfully according to this crate's parsing rules.
+///
+/// It can be parsed from an [RFC 4514] string using [`FromStr`], and its
+/// [`Display`][`fmt::Display`] impl produces an [RFC 4514] string.
+///
+/// [RFC 4514]: https://datatracker.ietf.org/doc/html/rfc4514
+#[derive(Clone, Eq, PartialEq)]
+pub struct NameDocument(Vec<u8>);
+
+impl NameDocument {
+    /// Compare this name with another using the name matching rules in
+    /// [RFC 5280 Section 7.1].
+    ///
+    /// See [`RdnSequence::matches`][`super::RdnSequence::matches`].
+    ///
+    /// [RFC 5280 Section 7.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-7.1
+    pub fn matches(&self, other: &Name<'_>) -> bool {
+        self.decode().matches(other)
+    }
+}
+
+impl<'a> Document<'a> for NameDocument {
+    type Message = Name<'a>;
+    const SENSITIVE: bool = false;
+}
+
+impl AsRef<[u8]> for NameDocument {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl TryFrom<&[u8]> for NameDocument {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+impl TryFrom<Name<'_>> for NameDocument {
+    type Error = Error;
+
+    fn try_from(name: Name<'_>) -> Result<NameDocument> {
+        Self::try_from(&name)
+    }
+}
+
+impl TryFrom<&Name<'_>> for NameDocument {
+    type Error = Error;
+
+    fn try_from(name: &Name<'_>) -> Result<NameDocument> {
+        Self::from_msg(name)
+    }
+}
+
+impl TryFrom<Vec<u8>> for NameDocument {
+    type Error = Error;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self> {
+        // Ensure document is well-formed
+        Name::from_der(bytes.as_slice())?;
+        Ok(Self(bytes))
+    }
+}
+
+impl fmt::Debug for NameDocument {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple("NameDocument")
+            .field(&self.decode())
+            .finish()
+    }
+}
+
+impl fmt::Display for NameDocument {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}", self.decode())
+    }
+}
+
+impl FromStr for NameDocument {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Name::encode_from_string(s)?.try_into()
+    }
+}
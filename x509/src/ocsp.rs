@@ -0,0 +1,446 @@
+//! Online Certificate Status Protocol (OCSP) types as defined in [RFC 6960].
+//!
+//! [RFC 6960]: https://datatracker.ietf.org/doc/html/rfc6960
+
+use crate::ext::pkix::{name::GeneralName, CrlReason};
+use crate::ext::{AsExtension, Extensions};
+use crate::{name::Name, Certificate};
+
+use alloc::vec::Vec;
+
+use const_oid::db::rfc6960::{ID_PKIX_OCSP_BASIC, ID_PKIX_OCSP_NONCE};
+use const_oid::{AssociatedOid, ObjectIdentifier};
+use der::asn1::{BitString, GeneralizedTime, Null, OctetString, UIntBytes};
+use der::{Choice, Decode, Enumerated, ErrorKind, Newtype, Result, Sequence};
+use spki::AlgorithmIdentifier;
+
+/// OCSP `Version` as defined in [RFC 6960 Section 4.1.1].
+///
+/// ```text
+/// Version ::= INTEGER { v1(0) }
+/// ```
+///
+/// [RFC 6960 Section 4.1.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.1.1
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Enumerated)]
+#[asn1(type = "INTEGER")]
+#[repr(u8)]
+pub enum Version {
+    /// Version 1 (default)
+    V1 = 0,
+}
+
+impl Default for Version {
+    fn default() -> Self {
+        Self::V1
+    }
+}
+
+/// `OCSPRequest` as defined in [RFC 6960 Section 4.1.1].
+///
+/// ```text
+/// OCSPRequest ::= SEQUENCE {
+///     tbsRequest                  TBSRequest,
+///     optionalSignature   [0]     EXPLICIT Signature OPTIONAL
+/// }
+/// ```
+///
+/// [RFC 6960 Section 4.1.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.1.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct OcspRequest<'a> {
+    pub tbs_request: TbsRequest<'a>,
+
+    #[asn1(context_specific = "0", optional = "true")]
+    pub optional_signature: Option<Signature<'a>>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for OcspRequest<'a> {
+    type Error = der::Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+/// `TBSRequest` as defined in [RFC 6960 Section 4.1.1].
+///
+/// ```text
+/// TBSRequest ::= SEQUENCE {
+///     version             [0]     EXPLICIT Version DEFAULT v1,
+///     requestorName       [1]     EXPLICIT GeneralName OPTIONAL,
+///     requestList                 SEQUENCE OF Request,
+///     requestExtensions   [2]     EXPLICIT Extensions OPTIONAL
+/// }
+/// ```
+///
+/// [RFC 6960 Section 4.1.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.1.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct TbsRequest<'a> {
+    #[asn1(context_specific = "0", default = "Default::default")]
+    pub version: Version,
+
+    #[asn1(context_specific = "1", optional = "true")]
+    pub requestor_name: Option<GeneralName<'a>>,
+
+    pub request_list: Vec<Request<'a>>,
+
+    #[asn1(context_specific = "2", optional = "true")]
+    pub request_extensions: Option<Extensions<'a>>,
+}
+
+impl<'a> TbsRequest<'a> {
+    /// Get the [`OcspNonce`] from the request extensions, if present.
+    pub fn nonce(&self) -> Result<Option<OcspNonce<'a>>> {
+        find_extension(self.request_extensions.as_deref())
+    }
+}
+
+/// `Signature` as defined in [RFC 6960 Section 4.1.1].
+///
+/// ```text
+/// Signature ::= SEQUENCE {
+///     signatureAlgorithm      AlgorithmIdentifier,
+///     signature               BIT STRING,
+///     certs               [0] EXPLICIT SEQUENCE OF Certificate OPTIONAL
+/// }
+/// ```
+///
+/// [RFC 6960 Section 4.1.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.1.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct Signature<'a> {
+    pub signature_algorithm: AlgorithmIdentifier<'a>,
+    pub signature: BitString<'a>,
+
+    #[asn1(context_specific = "0", optional = "true")]
+    pub certs: Option<Vec<Certificate<'a>>>,
+}
+
+/// `Request` as defined in [RFC 6960 Section 4.1.1].
+///
+/// ```text
+/// Request ::= SEQUENCE {
+///     reqCert                     CertID,
+///     singleRequestExtensions [0] EXPLICIT Extensions OPTIONAL
+/// }
+/// ```
+///
+/// [RFC 6960 Section 4.1.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.1.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct Request<'a> {
+    pub req_cert: CertId<'a>,
+
+    #[asn1(context_specific = "0", optional = "true")]
+    pub single_request_extensions: Option<Extensions<'a>>,
+}
+
+/// `CertID` as defined in [RFC 6960 Section 4.1.1].
+///
+/// ```text
+/// CertID ::= SEQUENCE {
+///     hashAlgorithm       AlgorithmIdentifier,
+///     issuerNameHash      OCTET STRING, -- Hash of issuer's DN
+///     issuerKeyHash       OCTET STRING, -- Hash of issuer's public key
+///     serialNumber        CertificateSerialNumber
+/// }
+/// ```
+///
+/// [RFC 6960 Section 4.1.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.1.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct CertId<'a> {
+    pub hash_algorithm: AlgorithmIdentifier<'a>,
+    pub issuer_name_hash: OctetString<'a>,
+    pub issuer_key_hash: OctetString<'a>,
+    pub serial_number: UIntBytes<'a>,
+}
+
+/// `OCSPResponse` as defined in [RFC 6960 Section 4.2.1].
+///
+/// ```text
+/// OCSPResponse ::= SEQUENCE {
+///     responseStatus         OCSPResponseStatus,
+///     responseBytes          [0] EXPLICIT ResponseBytes OPTIONAL
+/// }
+/// ```
+///
+/// [RFC 6960 Section 4.2.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.2.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct OcspResponse<'a> {
+    pub response_status: OcspResponseStatus,
+
+    #[asn1(context_specific = "0", optional = "true")]
+    pub response_bytes: Option<ResponseBytes<'a>>,
+}
+
+impl<'a> OcspResponse<'a> {
+    /// Decode the [`BasicOcspResponse`] contained in this response.
+    ///
+    /// Returns an error if the response was not successful, or if it
+    /// contains a response of another type.
+    pub fn basic_response(&self) -> Result<BasicOcspResponse<'a>> {
+        match (self.response_status, &self.response_bytes) {
+            (OcspResponseStatus::Successful, Some(bytes)) => bytes.basic_response(),
+            _ => Err(ErrorKind::Failed.into()),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for OcspResponse<'a> {
+    type Error = der::Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+/// `OCSPResponseStatus` as defined in [RFC 6960 Section 4.2.1].
+///
+/// ```text
+/// OCSPResponseStatus ::= ENUMERATED {
+///     successful            (0),  -- Response has valid confirmations
+///     malformedRequest      (1),  -- Illegal confirmation request
+///     internalError         (2),  -- Internal error in issuer
+///     tryLater              (3),  -- Try again later
+///                                 -- (4) is not used
+///     sigRequired           (5),  -- Must sign the request
+///     unauthorized          (6)   -- Request unauthorized
+/// }
+/// ```
+///
+/// [RFC 6960 Section 4.2.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.2.1
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Enumerated)]
+#[allow(missing_docs)]
+#[repr(u32)]
+pub enum OcspResponseStatus {
+    Successful = 0,
+    MalformedRequest = 1,
+    InternalError = 2,
+    TryLater = 3,
+    SigRequired = 5,
+    Unauthorized = 6,
+}
+
+/// `ResponseBytes` as defined in [RFC 6960 Section 4.2.1].
+///
+/// ```text
+/// ResponseBytes ::= SEQUENCE {
+///     responseType   OBJECT IDENTIFIER,
+///     response       OCTET STRING
+/// }
+/// ```
+///
+/// [RFC 6960 Section 4.2.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.2.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct ResponseBytes<'a> {
+    pub response_type: ObjectIdentifier,
+    pub response: OctetString<'a>,
+}
+
+impl<'a> ResponseBytes<'a> {
+    /// Decode the response as a [`BasicOcspResponse`].
+    ///
+    /// Returns an error if the response type is not `id-pkix-ocsp-basic`.
+    pub fn basic_response(&self) -> Result<BasicOcspResponse<'a>> {
+        if self.response_type != ID_PKIX_OCSP_BASIC {
+            return Err(ErrorKind::OidUnknown {
+                oid: self.response_type,
+            }
+            .into());
+        }
+
+        BasicOcspResponse::from_der(self.response.as_bytes())
+    }
+}
+
+/// `BasicOCSPResponse` as defined in [RFC 6960 Section 4.2.1].
+///
+/// ```text
+/// BasicOCSPResponse ::= SEQUENCE {
+///     tbsResponseData      ResponseData,
+///     signatureAlgorithm   AlgorithmIdentifier,
+///     signature            BIT STRING,
+///     certs            [0] EXPLICIT SEQUENCE OF Certificate OPTIONAL
+/// }
+/// ```
+///
+/// [RFC 6960 Section 4.2.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.2.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct BasicOcspResponse<'a> {
+    pub tbs_response_data: ResponseData<'a>,
+    pub signature_algorithm: AlgorithmIdentifier<'a>,
+    pub signature: BitString<'a>,
+
+    #[asn1(context_specific = "0", optional = "true")]
+    pub certs: Option<Vec<Certificate<'a>>>,
+}
+
+/// `ResponseData` as defined in [RFC 6960 Section 4.2.1].
+///
+/// ```text
+/// ResponseData ::= SEQUENCE {
+///     version              [0] EXPLICIT Version DEFAULT v1,
+///     responderID              ResponderID,
+///     producedAt               GeneralizedTime,
+///     responses                SEQUENCE OF SingleResponse,
+///     responseExtensions   [1] EXPLICIT Extensions OPTIONAL
+/// }
+/// ```
+///
+/// [RFC 6960 Section 4.2.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.2.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct ResponseData<'a> {
+    #[asn1(context_specific = "0", default = "Default::default")]
+    pub version: Version,
+
+    pub responder_id: ResponderId<'a>,
+    pub produced_at: GeneralizedTime,
+    pub responses: Vec<SingleResponse<'a>>,
+
+    #[asn1(context_specific = "1", optional = "true")]
+    pub response_extensions: Option<Extensions<'a>>,
+}
+
+impl<'a> ResponseData<'a> {
+    /// Get the [`OcspNonce`] from the response extensions, if present.
+    ///
+    /// This should match the nonce in the corresponding request.
+    pub fn nonce(&self) -> Result<Option<OcspNonce<'a>>> {
+        find_extension(self.response_extensions.as_deref())
+    }
+}
+
+/// `ResponderID` as defined in [RFC 6960 Section 4.2.1].
+///
+/// ```text
+/// ResponderID ::= CHOICE {
+///     byName   [1] Name,
+///     byKey    [2] KeyHash
+/// }
+///
+/// KeyHash ::= OCTET STRING -- SHA-1 hash of responder's public key
+/// ```
+///
+/// [RFC 6960 Section 4.2.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.2.1
+#[derive(Clone, Debug, Eq, PartialEq, Choice)]
+#[allow(missing_docs)]
+pub enum ResponderId<'a> {
+    #[asn1(context_specific = "1", tag_mode = "EXPLICIT", constructed = "true")]
+    ByName(Name<'a>),
+
+    #[asn1(context_specific = "2", tag_mode = "EXPLICIT", constructed = "true")]
+    ByKey(OctetString<'a>),
+}
+
+/// `SingleResponse` as defined in [RFC 6960 Section 4.2.1].
+///
+/// ```text
+/// SingleResponse ::= SEQUENCE {
+///     certID                       CertID,
+///     certStatus                   CertStatus,
+///     thisUpdate                   GeneralizedTime,
+///     nextUpdate         [0]       EXPLICIT GeneralizedTime OPTIONAL,
+///     singleExtensions   [1]       EXPLICIT Extensions OPTIONAL
+/// }
+/// ```
+///
+/// [RFC 6960 Section 4.2.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.2.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct SingleResponse<'a> {
+    pub cert_id: CertId<'a>,
+    pub cert_status: CertStatus,
+    pub this_update: GeneralizedTime,
+
+    #[asn1(context_specific = "0", optional = "true")]
+    pub next_update: Option<GeneralizedTime>,
+
+    #[asn1(context_specific = "1", optional = "true")]
+    pub single_extensions: Option<Extensions<'a>>,
+}
+
+/// `CertStatus` as defined in [RFC 6960 Section 4.2.1].
+///
+/// ```text
+/// CertStatus ::= CHOICE {
+///     good        [0]     IMPLICIT NULL,
+///     revoked     [1]     IMPLICIT RevokedInfo,
+///     unknown     [2]     IMPLICIT UnknownInfo
+/// }
+///
+/// UnknownInfo ::= NULL
+/// ```
+///
+/// [RFC 6960 Section 4.2.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.2.1
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Choice)]
+#[allow(missing_docs)]
+pub enum CertStatus {
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT")]
+    Good(Null),
+
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", constructed = "true")]
+    Revoked(RevokedInfo),
+
+    #[asn1(context_specific = "2", tag_mode = "IMPLICIT")]
+    Unknown(Null),
+}
+
+/// `RevokedInfo` as defined in [RFC 6960 Section 4.2.1].
+///
+/// ```text
+/// RevokedInfo ::= SEQUENCE {
+///     revocationTime              GeneralizedTime,
+///     revocationReason    [0]     EXPLICIT CRLReason OPTIONAL
+/// }
+/// ```
+///
+/// [RFC 6960 Section 4.2.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.2.1
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct RevokedInfo {
+    pub revocation_time: GeneralizedTime,
+
+    #[asn1(context_specific = "0", optional = "true")]
+    pub revocation_reason: Option<CrlReason>,
+}
+
+/// OCSP nonce extension as defined in [RFC 8954 Section 2.1].
+///
+/// ```text
+/// Nonce ::= OCTET STRING(SIZE(1..32))
+/// ```
+///
+/// [RFC 8954 Section 2.1]: https://datatracker.ietf.org/doc/html/rfc8954#section-2.1
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Newtype)]
+pub struct OcspNonce<'a>(pub OctetString<'a>);
+
+impl<'a> AssociatedOid for OcspNonce<'a> {
+    const OID: ObjectIdentifier = ID_PKIX_OCSP_NONCE;
+}
+
+impl<'a> AsExtension for OcspNonce<'a> {
+    const CRITICAL: bool = false;
+}
+
+/// Decode the single extension of type `T` in `extensions`, if present.
+fn find_extension<'a, T>(extensions: Option<&[crate::ext::Extension<'a>]>) -> Result<Option<T>>
+where
+    T: Decode<'a> + AssociatedOid,
+{
+    let mut iter = extensions
+        .unwrap_or(&[])
+        .iter()
+        .filter(|e| e.extn_id == T::OID);
+
+    match (iter.next(), iter.next()) {
+        (None, _) => Ok(None),
+        (Some(ext), None) => T::from_der(ext.extn_value).map(Some),
+        (Some(_), Some(_)) => Err(ErrorKind::Failed.into()),
+    }
+}
@@ -0,0 +1,425 @@
+//! Certification path building as described in [RFC 4158].
+//!
+//! Path building is the structural half of certification path validation:
+//! it finds chains of certificates from a leaf certificate to a trust anchor
+//! in which each certificate's issuer name matches the subject name of the
+//! next certificate. Where present, the `AuthorityKeyIdentifier` of each
+//! certificate must also match the `SubjectKeyIdentifier` of its issuer.
+//!
+//! No signatures are verified, and no constraints (e.g. `BasicConstraints`
+//! or `KeyUsage`) are checked. Each candidate path must subsequently be
+//! validated as described in [RFC 5280 Section 6] before it can be trusted.
+//!
+//! [RFC 4158]: https://datatracker.ietf.org/doc/html/rfc4158
+//! [RFC 5280 Section 6]: https://datatracker.ietf.org/doc/html/rfc5280#section-6
+
+use crate::{
+    attr::AttributeTypeAndValue,
+    certificate::{Certificate, PkiPath},
+    ext::pkix::{AuthorityKeyIdentifier, SubjectKeyIdentifier},
+    name::Name,
+    time::{Time, Validity},
+};
+
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use const_oid::{AssociatedOid, ObjectIdentifier};
+use der::{Decode, Tagged};
+
+/// Maximum number of certificates in a path, including the leaf and the
+/// trust anchor.
+pub const MAX_PATH_LEN: usize = 10;
+
+/// Maximum number of candidate paths returned by
+/// [`CertificatePool::build_path`].
+pub const MAX_PATHS: usize = 16;
+
+/// Maximum number of intermediate certificates visited while building
+/// paths, bounding the work done when many certificates share a name (e.g.
+/// cross-certificates).
+pub const MAX_VISITED: usize = 1024;
+
+/// Normalized form of a [`Name`], which is equal for two names if and only
+/// if they match according to [`Name::matches`].
+type NameKey = Vec<Vec<Vec<u8>>>;
+
+/// Collection of certificates indexed by subject name and
+/// `SubjectKeyIdentifier`, from which certification paths can be built.
+///
+/// Pools are used both for the intermediate certificates which may appear
+/// in a path, and for trust anchors.
+#[derive(Clone, Debug, Default)]
+pub struct CertificatePool<'a> {
+    /// Certificates in the pool, in the order they were added.
+    certificates: Vec<Certificate<'a>>,
+
+    /// Indexes of certificates by normalized subject name.
+    by_subject: BTreeMap<NameKey, Vec<usize>>,
+
+    /// Indexes of certificates by `SubjectKeyIdentifier`.
+    by_key_id: BTreeMap<&'a [u8], Vec<usize>>,
+}
+
+impl<'a> CertificatePool<'a> {
+    /// Create a new, empty [`CertificatePool`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a certificate to the pool.
+    ///
+    /// Certificates which are already present in the pool are ignored.
+    pub fn add(&mut self, certificate: Certificate<'a>) {
+        let subject = name_key(&certificate.tbs_certificate.subject);
+
+        if let Some(indexes) = self.by_subject.get(&subject) {
+            if indexes.iter().any(|&i| self.certificates[i] == certificate) {
+                return;
+            }
+        }
+
+        let index = self.certificates.len();
+        self.by_subject.entry(subject).or_default().push(index);
+
+        if let Some(key_id) = subject_key_id(&certificate) {
+            self.by_key_id.entry(key_id).or_default().push(index);
+        }
+
+        self.certificates.push(certificate);
+    }
+
+    /// Get the number of certificates in the pool.
+    pub fn len(&self) -> usize {
+        self.certificates.len()
+    }
+
+    /// Is the pool empty?
+    pub fn is_empty(&self) -> bool {
+        self.certificates.is_empty()
+    }
+
+    /// Iterate over the certificates in the pool, in the order they were
+    /// added.
+    pub fn iter(&self) -> impl Iterator<Item = &Certificate<'a>> {
+        self.certificates.iter()
+    }
+
+    /// Does the pool contain the given certificate?
+    pub fn contains(&self, certificate: &Certificate<'_>) -> bool {
+        self.by_subject(&certificate.tbs_certificate.subject)
+            .any(|c| c == certificate)
+    }
+
+    /// Find the certificates whose subject matches the given name.
+    ///
+    /// Names are compared using [`Name::matches`].
+    pub fn by_subject<'p>(
+        &'p self,
+        name: &Name<'_>,
+    ) -> impl Iterator<Item = &'p Certificate<'a>> + 'p {
+        self.lookup(self.by_subject.get(&name_key(name)))
+    }
+
+    /// Find the certificates with the given `SubjectKeyIdentifier`.
+    pub fn by_key_id<'p>(
+        &'p self,
+        key_id: &[u8],
+    ) -> impl Iterator<Item = &'p Certificate<'a>> + 'p {
+        self.lookup(self.by_key_id.get(key_id))
+    }
+
+    /// Find the certificates in the pool which may have issued the given
+    /// certificate.
+    ///
+    /// Candidates' subject names must match the certificate's issuer name.
+    /// If the certificate has an `AuthorityKeyIdentifier` containing a key
+    /// identifier, candidates which have a `SubjectKeyIdentifier` must also
+    /// have the same key identifier.
+    pub fn issuers_of<'p>(
+        &'p self,
+        certificate: &Certificate<'a>,
+    ) -> impl Iterator<Item = &'p Certificate<'a>> + 'p {
+        let key_id = authority_key_id(certificate);
+
+        self.by_subject(&certificate.tbs_certificate.issuer)
+            .filter(move |issuer| match (key_id, subject_key_id(issuer)) {
+                (Some(aki), Some(ski)) => aki == ski,
+                _ => true,
+            })
+    }
+
+    /// Build candidate certification paths from `leaf` to one of the given
+    /// `trust_anchors`, using the certificates in this pool as
+    /// intermediates.
+    ///
+    /// Paths are returned in order of increasing length. Each path starts
+    /// with the leaf and ends with a trust anchor, and contains at most
+    /// [`MAX_PATH_LEN`] certificates. A leaf which is itself a trust anchor
+    /// results in a path containing only the leaf.
+    ///
+    /// At most [`MAX_PATHS`] paths are returned, and the search stops once
+    /// [`MAX_VISITED`] intermediates have been visited. As shorter paths are
+    /// searched first, these limits only exclude the longest candidates.
+    ///
+    /// If `time` is provided, certificates which aren't valid at that time
+    /// are excluded. Trust anchors are exempt from this check, as their
+    /// validity period isn't part of the trust anchor information used for
+    /// path validation ([RFC 5280 Section 6.1.1]).
+    ///
+    /// See the [module-level documentation][`self`] for the checks which
+    /// are (and aren't) performed.
+    ///
+    /// [RFC 5280 Section 6.1.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-6.1.1
+    pub fn build_path<'p>(
+        &'p self,
+        leaf: &'p Certificate<'a>,
+        trust_anchors: &'p CertificatePool<'a>,
+        time: Option<Time>,
+    ) -> Vec<CertificatePath<'p, 'a>> {
+        if trust_anchors.contains(leaf) {
+            return vec![CertificatePath(vec![leaf])];
+        }
+
+        if let Some(time) = time {
+            if !is_valid_at(&leaf.tbs_certificate.validity, time) {
+                return Vec::new();
+            }
+        }
+
+        let mut search = PathSearch {
+            trust_anchors,
+            time,
+            paths: Vec::new(),
+            visited: 0,
+        };
+
+        // Search for paths of each length in turn, so the shortest paths are
+        // found before any limits are reached
+        for len in 2..=MAX_PATH_LEN {
+            self.extend_path(&mut vec![leaf], len, &mut search);
+        }
+
+        search.paths
+    }
+
+    /// Find the certificates indexed by the given entry.
+    fn lookup<'p>(
+        &'p self,
+        indexes: Option<&'p Vec<usize>>,
+    ) -> impl Iterator<Item = &'p Certificate<'a>> + 'p {
+        indexes
+            .into_iter()
+            .flatten()
+            .filter_map(move |&i| self.certificates.get(i))
+    }
+
+    /// Recursively extend the given partial path, adding each complete path
+    /// of length `len` which ends in a trust anchor to the search results.
+    fn extend_path<'p>(
+        &'p self,
+        path: &mut Vec<&'p Certificate<'a>>,
+        len: usize,
+        search: &mut PathSearch<'p, 'a>,
+    ) {
+        let certificate = match path.last() {
+            Some(&certificate) => certificate,
+            None => return,
+        };
+
+        if path.len() + 1 >= len {
+            for anchor in search.trust_anchors.issuers_of(certificate) {
+                if search.paths.len() >= MAX_PATHS {
+                    return;
+                }
+
+                let mut candidate = path.clone();
+                candidate.push(anchor);
+                search.paths.push(CertificatePath(candidate));
+            }
+
+            return;
+        }
+
+        for issuer in self.issuers_of(certificate) {
+            if search.is_exhausted() {
+                return;
+            }
+
+            // Avoid loops, as well as intermediates which are also trust
+            // anchors since paths ending at them are found separately
+            if path.contains(&issuer) || search.trust_anchors.contains(issuer) {
+                continue;
+            }
+
+            if let Some(time) = search.time {
+                if !is_valid_at(&issuer.tbs_certificate.validity, time) {
+                    continue;
+                }
+            }
+
+            search.visited += 1;
+            path.push(issuer);
+            self.extend_path(path, len, search);
+            path.pop();
+        }
+    }
+}
+
+/// State of the search performed by [`CertificatePool::build_path`].
+struct PathSearch<'p, 'a> {
+    /// Trust anchors which paths must end in.
+    trust_anchors: &'p CertificatePool<'a>,
+
+    /// Time at which certificates must be valid, if any.
+    time: Option<Time>,
+
+    /// Paths found so far.
+    paths: Vec<CertificatePath<'p, 'a>>,
+
+    /// Number of intermediates visited so far.
+    visited: usize,
+}
+
+impl PathSearch<'_, '_> {
+    /// Has either of the search limits been reached?
+    fn is_exhausted(&self) -> bool {
+        self.paths.len() >= MAX_PATHS || self.visited >= MAX_VISITED
+    }
+}
+
+impl<'a> Extend<Certificate<'a>> for CertificatePool<'a> {
+    fn extend<I: IntoIterator<Item = Certificate<'a>>>(&mut self, iter: I) {
+        for certificate in iter {
+            self.add(certificate);
+        }
+    }
+}
+
+impl<'a> FromIterator<Certificate<'a>> for CertificatePool<'a> {
+    fn from_iter<I: IntoIterator<Item = Certificate<'a>>>(iter: I) -> Self {
+        let mut pool = Self::new();
+        pool.extend(iter);
+        pool
+    }
+}
+
+/// Candidate certification path built by [`CertificatePool::build_path`].
+///
+/// Certificates are ordered from the leaf to the trust anchor, i.e. each
+/// certificate is issued by the next.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CertificatePath<'p, 'a>(Vec<&'p Certificate<'a>>);
+
+impl<'p, 'a> CertificatePath<'p, 'a> {
+    /// Get the certificates in this path, from the leaf to the trust anchor.
+    pub fn certificates(&self) -> &[&'p Certificate<'a>] {
+        &self.0
+    }
+
+    /// Get the number of certificates in this path, including the leaf and
+    /// the trust anchor.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Is this path empty?
+    ///
+    /// Paths built by [`CertificatePool::build_path`] are never empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Get the leaf certificate.
+    pub fn leaf(&self) -> Option<&'p Certificate<'a>> {
+        self.0.first().copied()
+    }
+
+    /// Get the trust anchor.
+    pub fn trust_anchor(&self) -> Option<&'p Certificate<'a>> {
+        self.0.last().copied()
+    }
+
+    /// Convert this path to a [`PkiPath`], which is ordered from the trust
+    /// anchor to the leaf.
+    pub fn to_pki_path(&self) -> PkiPath<'a> {
+        PkiPath(self.0.iter().rev().map(|&c| c.clone()).collect())
+    }
+}
+
+/// Is the given time within the validity period?
+fn is_valid_at(validity: &Validity, time: Time) -> bool {
+    let time = time.to_unix_duration();
+    validity.not_before.to_unix_duration() <= time && time <= validity.not_after.to_unix_duration()
+}
+
+/// Get the `SubjectKeyIdentifier` of a certificate, if it has one.
+fn subject_key_id<'a>(certificate: &Certificate<'a>) -> Option<&'a [u8]> {
+    extension_value(certificate, SubjectKeyIdentifier::OID)
+        .and_then(|value| SubjectKeyIdentifier::from_der(value).ok())
+        .map(|ski| ski.0.as_bytes())
+}
+
+/// Get the key identifier from the `AuthorityKeyIdentifier` of a
+/// certificate, if it has one.
+fn authority_key_id<'a>(certificate: &Certificate<'a>) -> Option<&'a [u8]> {
+    extension_value(certificate, AuthorityKeyIdentifier::OID)
+        .and_then(|value| AuthorityKeyIdentifier::from_der(value).ok())
+        .and_then(|aki| aki.key_identifier)
+        .map(|key_id| key_id.as_bytes())
+}
+
+/// Get the value of the extension with the given OID.
+fn extension_value<'a>(certificate: &Certificate<'a>, oid: ObjectIdentifier) -> Option<&'a [u8]> {
+    certificate
+        .tbs_certificate
+        .extensions
+        .as_deref()?
+        .iter()
+        .find(|extension| extension.extn_id == oid)
+        .map(|extension| extension.extn_value)
+}
+
+/// Compute the [`NameKey`] of a name.
+///
+/// Attributes within each RDN are sorted, and `PrintableString` values are
+/// case folded with insignificant spaces removed, mirroring the rules
+/// implemented by [`AttributeTypeAndValue::matches`].
+fn name_key(name: &Name<'_>) -> NameKey {
+    name.0
+        .iter()
+        .map(|rdn| {
+            let mut atvs = rdn.0.iter().map(atv_key).collect::<Vec<_>>();
+            atvs.sort();
+            atvs
+        })
+        .collect()
+}
+
+/// Compute the normalized form of an attribute.
+fn atv_key(atv: &AttributeTypeAndValue<'_>) -> Vec<u8> {
+    let oid = atv.oid.as_bytes();
+    let mut key = Vec::with_capacity(oid.len() + atv.value.value().len() + 2);
+
+    // OIDs are at most 39 bytes, so their length fits in a byte
+    key.push(oid.len() as u8);
+    key.extend_from_slice(oid);
+
+    match atv.value.printable_string() {
+        Ok(s) => {
+            key.push(0);
+
+            for (i, word) in s.as_str().split(' ').filter(|w| !w.is_empty()).enumerate() {
+                if i > 0 {
+                    key.push(b' ');
+                }
+
+                key.extend(word.bytes().map(|b| b.to_ascii_lowercase()));
+            }
+        }
+        Err(_) => {
+            key.push(atv.value.tag().into());
+            key.extend_from_slice(atv.value.value());
+        }
+    }
+
+    key
+}
@@ -0,0 +1,700 @@
+//! PKCS#12 (PFX) types as defined in [RFC 7292].
+//!
+//! A [`Pfx`] is typically protected by a password which is used both to
+//! verify its integrity (see [`Pfx::verify_mac`]) and to decrypt its
+//! contents (see [`Pfx::decrypt`]). Limits on the untrusted parameters used
+//! while doing so can be configured using [`DecryptOptions`].
+//!
+//! The following encryption algorithms are supported:
+//!
+//! - PBES2 with PBKDF2 and AES-CBC or 3DES-CBC, as produced by OpenSSL 3
+//! - PBES2 with scrypt and AES-CBC
+//! - the legacy PKCS#12 PBE schemes using 3DES-CBC and RC2-CBC
+//!
+//! [RFC 7292]: https://datatracker.ietf.org/doc/html/rfc7292
+
+mod error;
+mod kdf;
+
+pub mod document;
+
+pub use document::PfxDocument;
+pub use error::{Error, Result};
+
+use crate::attr::Attributes;
+use crate::certificate::document::CertificateDocument;
+use crate::cms::ContentInfo;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use cbc::cipher::{block_padding::Pkcs7, BlockCipher, BlockDecryptMut, InnerIvInit, KeyInit};
+use const_oid::db::rfc5912::{ID_SHA_1, ID_SHA_224, ID_SHA_256, ID_SHA_384, ID_SHA_512};
+use const_oid::ObjectIdentifier;
+use der::asn1::{Any, OctetString};
+use der::ber;
+use der::{Decode, Decoder, Encode, Enumerated, ErrorKind, Sequence, Tag, Tagged};
+use digest::{core_api::BlockSizeUser, Digest};
+use hmac::{Mac, SimpleHmac};
+use pkcs8::{pkcs5, PrivateKeyDocument};
+use spki::AlgorithmIdentifier;
+use zeroize::Zeroizing;
+
+/// Default value of [`DecryptOptions::max_iterations`].
+pub const DEFAULT_MAX_ITERATIONS: u32 = 10_000_000;
+
+/// Default value of [`DecryptOptions::max_scrypt_memory`] (32 MiB).
+pub const DEFAULT_MAX_SCRYPT_MEMORY: u64 = 32 * 1024 * 1024;
+
+/// Default value of [`DecryptOptions::max_scrypt_cost`].
+pub const DEFAULT_MAX_SCRYPT_COST: u64 = 1 << 20;
+
+/// Maximum nesting depth of `safeContentsBag`s accepted by [`Pfx::decrypt`].
+const MAX_SAFE_CONTENTS_DEPTH: u32 = 8;
+
+/// `pbeWithSHAAnd3-KeyTripleDES-CBC` Object Identifier (OID).
+pub const PBE_WITH_SHA_AND_3_KEY_TRIPLE_DES_CBC: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.1.3");
+
+/// `pbeWithSHAAnd2-KeyTripleDES-CBC` Object Identifier (OID).
+pub const PBE_WITH_SHA_AND_2_KEY_TRIPLE_DES_CBC: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.1.4");
+
+/// `pbeWithSHAAnd128BitRC2-CBC` Object Identifier (OID).
+pub const PBE_WITH_SHA_AND_128_BIT_RC2_CBC: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.1.5");
+
+/// `pbewithSHAAnd40BitRC2-CBC` Object Identifier (OID).
+pub const PBE_WITH_SHA_AND_40_BIT_RC2_CBC: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.1.6");
+
+/// `keyBag` Object Identifier (OID).
+pub const KEY_BAG: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.10.1.1");
+
+/// `pkcs8ShroudedKeyBag` Object Identifier (OID).
+pub const PKCS_8_SHROUDED_KEY_BAG: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.10.1.2");
+
+/// `certBag` Object Identifier (OID).
+pub const CERT_BAG: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.10.1.3");
+
+/// `crlBag` Object Identifier (OID).
+pub const CRL_BAG: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.10.1.4");
+
+/// `secretBag` Object Identifier (OID).
+pub const SECRET_BAG: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.10.1.5");
+
+/// `safeContentsBag` Object Identifier (OID).
+pub const SAFE_CONTENTS_BAG: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.12.10.1.6");
+
+/// `x509Certificate` certificate type Object Identifier (OID).
+pub const X509_CERTIFICATE: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.22.1");
+
+/// `friendlyName` attribute Object Identifier (OID).
+pub const PKCS_9_AT_FRIENDLY_NAME: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.20");
+
+/// `localKeyId` attribute Object Identifier (OID).
+pub const PKCS_9_AT_LOCAL_KEY_ID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.21");
+
+/// PFX version
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Enumerated)]
+#[asn1(type = "INTEGER")]
+#[repr(u8)]
+pub enum Version {
+    /// Version 3
+    V3 = 3,
+}
+
+impl Default for Version {
+    fn default() -> Self {
+        Self::V3
+    }
+}
+
+/// `PFX` as defined in [RFC 7292 Section 4].
+///
+/// ```text
+/// PFX ::= SEQUENCE {
+///     version     INTEGER {v3(3)}(v3,...),
+///     authSafe    ContentInfo,
+///     macData     MacData OPTIONAL
+/// }
+/// ```
+///
+/// [RFC 7292 Section 4]: https://datatracker.ietf.org/doc/html/rfc7292#section-4
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct Pfx<'a> {
+    pub version: Version,
+    pub auth_safe: ContentInfo<'a>,
+    pub mac_data: Option<MacData<'a>>,
+}
+
+impl<'a> Pfx<'a> {
+    /// Verify the integrity MAC of the PFX using the given password.
+    ///
+    /// Returns [`Error::MacVerificationFailed`] if the PFX has no MAC.
+    pub fn verify_mac(&self, password: &str) -> Result<()> {
+        self.verify_mac_with_options(password, &DecryptOptions::default())
+    }
+
+    /// Verify the integrity MAC of the PFX using the given password and
+    /// [`DecryptOptions`].
+    pub fn verify_mac_with_options(&self, password: &str, options: &DecryptOptions) -> Result<()> {
+        let mac_data = self.mac_data.as_ref().ok_or(Error::MacVerificationFailed)?;
+        options.check_iterations(mac_data.iterations)?;
+
+        let data = self.auth_safe_data()?;
+
+        match mac_data.mac.digest_algorithm.oid {
+            ID_SHA_1 => verify_hmac::<sha1::Sha1>(mac_data, password, data),
+            ID_SHA_224 => verify_hmac::<sha2::Sha224>(mac_data, password, data),
+            ID_SHA_256 => verify_hmac::<sha2::Sha256>(mac_data, password, data),
+            ID_SHA_384 => verify_hmac::<sha2::Sha384>(mac_data, password, data),
+            ID_SHA_512 => verify_hmac::<sha2::Sha512>(mac_data, password, data),
+            oid => Err(Error::UnsupportedAlgorithm { oid }),
+        }
+    }
+
+    /// Decrypt the contents of the PFX using the given password, returning
+    /// the bags it contains in order.
+    ///
+    /// The integrity MAC is verified first, and a PFX without one is
+    /// rejected with [`Error::MacVerificationFailed`]: see
+    /// [`Pfx::decrypt_with_options`] to accept those. The contents of
+    /// `safeContentsBag`s are flattened into the result.
+    pub fn decrypt(&self, password: &str) -> Result<Vec<Bag>> {
+        self.decrypt_with_options(password, &DecryptOptions::default())
+    }
+
+    /// Decrypt the contents of the PFX using the given password and
+    /// [`DecryptOptions`].
+    pub fn decrypt_with_options(
+        &self,
+        password: &str,
+        options: &DecryptOptions,
+    ) -> Result<Vec<Bag>> {
+        if self.mac_data.is_some() || !options.allow_missing_mac {
+            self.verify_mac_with_options(password, options)?;
+        }
+
+        let auth_safe = ber::to_der(self.auth_safe_data()?)?;
+        let mut bags = Vec::new();
+
+        for content_info in Vec::<ContentInfo<'_>>::from_der(&auth_safe)? {
+            let safe_contents = match content_info {
+                ContentInfo::Data(Some(data)) => Zeroizing::new(data.content.to_vec()),
+                ContentInfo::EncryptedData(Some(encrypted_data)) => {
+                    let eci = encrypted_data.encrypted_content_info;
+                    let ciphertext = eci
+                        .encrypted_content
+                        .ok_or(ErrorKind::Value { tag: Tag::Sequence })?;
+
+                    decrypt_data(
+                        &eci.content_encryption_algorithm,
+                        password,
+                        ciphertext,
+                        options,
+                    )?
+                }
+                content_info => {
+                    return Err(Error::UnsupportedAlgorithm {
+                        oid: content_info.content_type().to_oid(),
+                    })
+                }
+            };
+
+            push_bags(&safe_contents, password, options, &mut bags)?;
+        }
+
+        Ok(bags)
+    }
+
+    /// Get the DER-encoded `AuthenticatedSafe` covered by the MAC.
+    fn auth_safe_data(&self) -> Result<&'a [u8]> {
+        match self.auth_safe {
+            ContentInfo::Data(Some(data)) => Ok(data.content),
+            // Public-key integrity mode is not supported
+            ref content_info => Err(Error::UnsupportedAlgorithm {
+                oid: content_info.content_type().to_oid(),
+            }),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for Pfx<'a> {
+    type Error = der::Error;
+
+    fn try_from(bytes: &'a [u8]) -> der::Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+/// Options for [`Pfx::decrypt_with_options`] and
+/// [`Pfx::verify_mac_with_options`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct DecryptOptions {
+    /// Maximum iteration count accepted for the MAC and password-based
+    /// encryption key derivation.
+    ///
+    /// The iteration count is read from the PFX itself, so this bounds the
+    /// work an untrusted PFX can cause. Larger values are rejected with
+    /// [`Error::IterationCountTooLarge`]. Defaults to
+    /// [`DEFAULT_MAX_ITERATIONS`].
+    pub max_iterations: u32,
+
+    /// Decrypt a PFX which has no integrity MAC (i.e. whose `macData` is
+    /// absent) instead of rejecting it.
+    ///
+    /// The contents of such a PFX are not authenticated. Defaults to `false`.
+    pub allow_missing_mac: bool,
+
+    /// Maximum memory in bytes, i.e. `128 * r * (N + p)`, which scrypt key
+    /// derivation may use.
+    ///
+    /// Like the iteration count, the scrypt parameters are read from the PFX
+    /// itself. Larger values are rejected with
+    /// [`Error::ScryptParamsTooLarge`]. Defaults to
+    /// [`DEFAULT_MAX_SCRYPT_MEMORY`].
+    pub max_scrypt_memory: u64,
+
+    /// Maximum cost, i.e. `N * r * p`, accepted for scrypt key derivation.
+    ///
+    /// Larger values are rejected with [`Error::ScryptParamsTooLarge`].
+    /// Defaults to [`DEFAULT_MAX_SCRYPT_COST`].
+    pub max_scrypt_cost: u64,
+}
+
+impl DecryptOptions {
+    /// Check the given iteration count against [`DecryptOptions::max_iterations`].
+    fn check_iterations(&self, iterations: u32) -> Result<()> {
+        if iterations > self.max_iterations {
+            return Err(Error::IterationCountTooLarge { iterations });
+        }
+
+        Ok(())
+    }
+
+    /// Check the given scrypt parameters against
+    /// [`DecryptOptions::max_scrypt_memory`] and
+    /// [`DecryptOptions::max_scrypt_cost`].
+    fn check_scrypt(&self, params: &pkcs5::pbes2::ScryptParams<'_>) -> Result<()> {
+        let n = u64::from(params.cost_parameter);
+        let r = u64::from(params.block_size);
+        let p = u64::from(params.parallelization);
+
+        if 128 * r * (n + p) > self.max_scrypt_memory || n * r * p > self.max_scrypt_cost {
+            return Err(Error::ScryptParamsTooLarge);
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for DecryptOptions {
+    fn default() -> Self {
+        Self {
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+            allow_missing_mac: false,
+            max_scrypt_memory: DEFAULT_MAX_SCRYPT_MEMORY,
+            max_scrypt_cost: DEFAULT_MAX_SCRYPT_COST,
+        }
+    }
+}
+
+/// `MacData` as defined in [RFC 7292 Section 4].
+///
+/// ```text
+/// MacData ::= SEQUENCE {
+///     mac        DigestInfo,
+///     macSalt    OCTET STRING,
+///     iterations INTEGER DEFAULT 1
+/// }
+/// ```
+///
+/// [RFC 7292 Section 4]: https://datatracker.ietf.org/doc/html/rfc7292#section-4
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct MacData<'a> {
+    pub mac: DigestInfo<'a>,
+    pub mac_salt: OctetString<'a>,
+
+    #[asn1(default = "default_iterations")]
+    pub iterations: u32,
+}
+
+/// Default `iterations` of [`MacData`].
+fn default_iterations() -> u32 {
+    1
+}
+
+/// `DigestInfo` as defined in [RFC 8017 Section 9.2].
+///
+/// ```text
+/// DigestInfo ::= SEQUENCE {
+///     digestAlgorithm AlgorithmIdentifier,
+///     digest OCTET STRING
+/// }
+/// ```
+///
+/// [RFC 8017 Section 9.2]: https://datatracker.ietf.org/doc/html/rfc8017#section-9.2
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct DigestInfo<'a> {
+    pub digest_algorithm: AlgorithmIdentifier<'a>,
+    pub digest: OctetString<'a>,
+}
+
+/// `SafeBag` as defined in [RFC 7292 Section 4.2].
+///
+/// ```text
+/// SafeBag ::= SEQUENCE {
+///     bagId          BAG-TYPE.&id ({PKCS12BagSet}),
+///     bagValue       [0] EXPLICIT BAG-TYPE.&Type({PKCS12BagSet}{@bagId}),
+///     bagAttributes  SET OF PKCS12Attribute OPTIONAL
+/// }
+/// ```
+///
+/// [RFC 7292 Section 4.2]: https://datatracker.ietf.org/doc/html/rfc7292#section-4.2
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct SafeBag<'a> {
+    pub bag_id: ObjectIdentifier,
+
+    #[asn1(context_specific = "0")]
+    pub bag_value: Any<'a>,
+
+    pub bag_attributes: Option<Attributes<'a>>,
+}
+
+/// `CertBag` as defined in [RFC 7292 Section 4.2.3].
+///
+/// ```text
+/// CertBag ::= SEQUENCE {
+///     certId    BAG-TYPE.&id   ({CertTypes}),
+///     certValue [0] EXPLICIT BAG-TYPE.&Type ({CertTypes}{@certId})
+/// }
+/// ```
+///
+/// [RFC 7292 Section 4.2.3]: https://datatracker.ietf.org/doc/html/rfc7292#section-4.2.3
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct CertBag<'a> {
+    pub cert_id: ObjectIdentifier,
+
+    #[asn1(context_specific = "0")]
+    pub cert_value: Any<'a>,
+}
+
+/// `PKCS8ShroudedKeyBag` as defined in [RFC 7292 Section 4.2.2].
+///
+/// ```text
+/// PKCS8ShroudedKeyBag ::= EncryptedPrivateKeyInfo
+///
+/// EncryptedPrivateKeyInfo ::= SEQUENCE {
+///     encryptionAlgorithm  EncryptionAlgorithmIdentifier,
+///     encryptedData        EncryptedData
+/// }
+/// ```
+///
+/// Unlike [`pkcs8::EncryptedPrivateKeyInfo`], the encryption algorithm may
+/// be one of the PKCS#12 PBE schemes.
+///
+/// [RFC 7292 Section 4.2.2]: https://datatracker.ietf.org/doc/html/rfc7292#section-4.2.2
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct Pkcs8ShroudedKeyBag<'a> {
+    pub encryption_algorithm: AlgorithmIdentifier<'a>,
+    pub encrypted_data: OctetString<'a>,
+}
+
+/// `pkcs-12PbeParams` as defined in [RFC 7292 Appendix C].
+///
+/// ```text
+/// pkcs-12PbeParams ::= SEQUENCE {
+///     salt        OCTET STRING,
+///     iterations  INTEGER
+/// }
+/// ```
+///
+/// [RFC 7292 Appendix C]: https://datatracker.ietf.org/doc/html/rfc7292#appendix-C
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct PbeParameters<'a> {
+    pub salt: OctetString<'a>,
+    pub iterations: u32,
+}
+
+/// Decrypted bag from a [`Pfx`].
+#[derive(Clone, Debug)]
+pub struct Bag {
+    /// Value of the `friendlyName` attribute, if any.
+    pub friendly_name: Option<String>,
+
+    /// Value of the `localKeyId` attribute, if any.
+    ///
+    /// This is typically used to associate a private key with its
+    /// certificate.
+    pub local_key_id: Option<Vec<u8>>,
+
+    /// Value of the bag.
+    pub value: BagValue,
+}
+
+impl Bag {
+    /// Decode (and decrypt if needed) the given [`SafeBag`].
+    ///
+    /// The bag value is decoded using a decoder nested within `decoder`.
+    fn from_safe_bag<'a>(
+        decoder: &mut Decoder<'a>,
+        bag: &SafeBag<'a>,
+        password: &str,
+        options: &DecryptOptions,
+    ) -> Result<Self> {
+        let value = match bag.bag_id {
+            KEY_BAG => BagValue::Key(PrivateKeyDocument::try_from(bag.bag_value.to_vec()?)?),
+            PKCS_8_SHROUDED_KEY_BAG => {
+                let shrouded = bag
+                    .bag_value
+                    .decode_nested_into::<Pkcs8ShroudedKeyBag<'_>>(decoder)?;
+                let mut plaintext = decrypt_data(
+                    &shrouded.encryption_algorithm,
+                    password,
+                    shrouded.encrypted_data.as_bytes(),
+                    options,
+                )?;
+
+                // `PrivateKeyDocument` zeroizes the plaintext itself, including
+                // when it's rejected
+                BagValue::Key(PrivateKeyDocument::try_from(core::mem::take(
+                    &mut *plaintext,
+                ))?)
+            }
+            CERT_BAG => {
+                let cert_bag = bag.bag_value.decode_nested_into::<CertBag<'_>>(decoder)?;
+
+                if cert_bag.cert_id == X509_CERTIFICATE {
+                    let cert = cert_bag
+                        .cert_value
+                        .decode_nested_into::<OctetString<'_>>(decoder)?;
+                    BagValue::Certificate(CertificateDocument::try_from(cert.as_bytes())?)
+                } else {
+                    BagValue::Other {
+                        bag_id: bag.bag_id,
+                        value: bag.bag_value.to_vec()?,
+                    }
+                }
+            }
+            bag_id => BagValue::Other {
+                bag_id,
+                value: bag.bag_value.to_vec()?,
+            },
+        };
+
+        let mut friendly_name = None;
+        let mut local_key_id = None;
+
+        for attr in bag.bag_attributes.iter().flat_map(|attrs| attrs.iter()) {
+            let value = match attr.values.get(0) {
+                Some(value) => value,
+                None => continue,
+            };
+
+            match attr.oid {
+                PKCS_9_AT_FRIENDLY_NAME => friendly_name = Some(decode_bmp_string(value)?),
+                PKCS_9_AT_LOCAL_KEY_ID => {
+                    let value = value.decode_nested_into::<OctetString<'_>>(decoder)?;
+                    local_key_id = Some(value.as_bytes().to_vec())
+                }
+                _ => (),
+            }
+        }
+
+        Ok(Self {
+            friendly_name,
+            local_key_id,
+            value,
+        })
+    }
+}
+
+/// Value of a decrypted [`Bag`].
+#[derive(Clone, Debug)]
+pub enum BagValue {
+    /// Private key from a `keyBag` or `pkcs8ShroudedKeyBag`.
+    Key(PrivateKeyDocument),
+
+    /// X.509 certificate from a `certBag`.
+    Certificate(CertificateDocument),
+
+    /// Bag of another type, e.g. `crlBag` or `secretBag`.
+    Other {
+        /// Bag type.
+        bag_id: ObjectIdentifier,
+
+        /// DER-encoded bag value.
+        value: Vec<u8>,
+    },
+}
+
+/// Decode the BER or DER-encoded `SafeContents`, appending its bags to
+/// `bags`.
+fn push_bags(
+    safe_contents: &[u8],
+    password: &str,
+    options: &DecryptOptions,
+    bags: &mut Vec<Bag>,
+) -> Result<()> {
+    let safe_contents = Zeroizing::new(ber::to_der(safe_contents)?);
+    let mut decoder = Decoder::new(&safe_contents)?;
+    let value = decoder.any()?;
+    push_safe_contents(&mut decoder, value, password, options, 0, bags)?;
+    Ok(decoder.finish(())?)
+}
+
+/// Decode the given `SafeContents` using a decoder nested within `decoder`,
+/// appending its bags to `bags`.
+///
+/// `depth` is the number of `safeContentsBag`s `safe_contents` is nested in.
+fn push_safe_contents<'a>(
+    decoder: &mut Decoder<'a>,
+    safe_contents: Any<'a>,
+    password: &str,
+    options: &DecryptOptions,
+    depth: u32,
+    bags: &mut Vec<Bag>,
+) -> Result<()> {
+    if depth > MAX_SAFE_CONTENTS_DEPTH {
+        return Err(ErrorKind::DepthLimit {
+            max_depth: MAX_SAFE_CONTENTS_DEPTH,
+        }
+        .into());
+    }
+
+    for bag in safe_contents.decode_nested_into::<Vec<SafeBag<'a>>>(decoder)? {
+        if bag.bag_id == SAFE_CONTENTS_BAG {
+            push_safe_contents(decoder, bag.bag_value, password, options, depth + 1, bags)?;
+        } else {
+            bags.push(Bag::from_safe_bag(decoder, &bag, password, options)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// Verify the HMAC of `data` using a key derived from the password.
+fn verify_hmac<D>(mac_data: &MacData<'_>, password: &str, data: &[u8]) -> Result<()>
+where
+    D: Digest + BlockSizeUser + Clone,
+{
+    let key = kdf::derive::<D>(
+        &kdf::bmp_password(password),
+        mac_data.mac_salt.as_bytes(),
+        mac_data.iterations,
+        kdf::MAC_ID,
+        <D as Digest>::output_size(),
+    );
+
+    let mut mac = <SimpleHmac<D> as KeyInit>::new_from_slice(&key)
+        .map_err(|_| Error::MacVerificationFailed)?;
+    mac.update(data);
+    mac.verify_slice(mac_data.mac.digest.as_bytes())
+        .map_err(|_| Error::MacVerificationFailed)
+}
+
+/// Decrypt data encrypted with a PKCS#12 PBE scheme or PBES2.
+fn decrypt_data(
+    algorithm: &AlgorithmIdentifier<'_>,
+    password: &str,
+    ciphertext: &[u8],
+    options: &DecryptOptions,
+) -> Result<Zeroizing<Vec<u8>>> {
+    let key_len = match algorithm.oid {
+        PBE_WITH_SHA_AND_3_KEY_TRIPLE_DES_CBC => 24,
+        PBE_WITH_SHA_AND_2_KEY_TRIPLE_DES_CBC | PBE_WITH_SHA_AND_128_BIT_RC2_CBC => 16,
+        PBE_WITH_SHA_AND_40_BIT_RC2_CBC => 5,
+        _ => {
+            let scheme = pkcs5::EncryptionScheme::try_from(*algorithm)?;
+
+            if let Some(kdf) = scheme.pbes2().map(|params| &params.kdf) {
+                if let Some(pbkdf2) = kdf.pbkdf2() {
+                    options.check_iterations(pbkdf2.iteration_count)?;
+                }
+
+                if let Some(scrypt) = kdf.scrypt() {
+                    options.check_scrypt(scrypt)?;
+                }
+            }
+
+            return Ok(Zeroizing::new(scheme.decrypt(password, ciphertext)?));
+        }
+    };
+
+    let params = algorithm
+        .parameters_any()
+        .and_then(|params| Ok(params.decode_into::<PbeParameters<'_>>()?))
+        .map_err(|_| pkcs5::Error::AlgorithmParametersInvalid { oid: algorithm.oid })?;
+    options.check_iterations(params.iterations)?;
+
+    let password = kdf::bmp_password(password);
+    let salt = params.salt.as_bytes();
+
+    let key = kdf::derive::<sha1::Sha1>(&password, salt, params.iterations, kdf::KEY_ID, key_len);
+    let iv = kdf::derive::<sha1::Sha1>(&password, salt, params.iterations, kdf::IV_ID, 8);
+    let mut buffer = Zeroizing::new(ciphertext.to_vec());
+
+    let len = match algorithm.oid {
+        PBE_WITH_SHA_AND_3_KEY_TRIPLE_DES_CBC => cbc_decrypt(
+            des::TdesEde3::new_from_slice(&key).map_err(|_| Error::DecryptFailed)?,
+            &iv,
+            &mut buffer,
+        ),
+        PBE_WITH_SHA_AND_2_KEY_TRIPLE_DES_CBC => cbc_decrypt(
+            des::TdesEde2::new_from_slice(&key).map_err(|_| Error::DecryptFailed)?,
+            &iv,
+            &mut buffer,
+        ),
+        _ => cbc_decrypt(
+            rc2::Rc2::new_with_eff_key_len(&key, key_len * 8),
+            &iv,
+            &mut buffer,
+        ),
+    }?;
+
+    buffer.truncate(len);
+    Ok(buffer)
+}
+
+/// Decrypt `buffer` in place using the given cipher in CBC mode, returning
+/// the length of the unpadded plaintext.
+fn cbc_decrypt<C>(cipher: C, iv: &[u8], buffer: &mut [u8]) -> Result<usize>
+where
+    C: BlockCipher + BlockDecryptMut,
+{
+    cbc::Decryptor::inner_iv_slice_init(cipher, iv)
+        .map_err(|_| Error::DecryptFailed)?
+        .decrypt_padded_mut::<Pkcs7>(buffer)
+        .map(|plaintext| plaintext.len())
+        .map_err(|_| Error::DecryptFailed)
+}
+
+/// Decode a `BMPString` attribute value.
+fn decode_bmp_string(value: &Any<'_>) -> Result<String> {
+    let bytes = value.value();
+
+    if value.tag() != Tag::BmpString || bytes.len() % 2 != 0 {
+        return Err(Tag::BmpString.value_error().into());
+    }
+
+    char::decode_utf16(
+        bytes
+            .chunks_exact(2)
+            .map(|c| u16::from_be_bytes([c[0], c[1]])),
+    )
+    .collect::<core::result::Result<String, _>>()
+    .map_err(|_| Tag::BmpString.value_error().into())
+}
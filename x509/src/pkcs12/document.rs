@@ -0,0 +1,98 @@
+//! PfxDocument implementation
+
+use super::Pfx;
+use der::{ber, Error, Result};
+
+use alloc::vec::Vec;
+use core::fmt;
+use der::{Decode, Document};
+
+#[cfg(feature = "pem")]
+use {core::str::FromStr, der::pem};
+
+/// PKCS#12 `PFX` document.
+///
+/// This type provides storage for [`Pfx`] encoded as ASN.1
+/// DER with the invariant that the contained-document is "well-formed", i.e.
+/// it will parse successfully according to this crate's parsing rules.
+#[derive(Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs12")))]
+pub struct PfxDocument(Vec<u8>);
+
+impl PfxDocument {
+    /// Parse a BER-encoded [`Pfx`], converting it to DER.
+    ///
+    /// DER is a subset of BER, so this accepts DER-encoded input as well.
+    pub fn from_ber(bytes: &[u8]) -> Result<Self> {
+        Self::try_from(ber::to_der(bytes)?)
+    }
+}
+
+impl<'a> Document<'a> for PfxDocument {
+    type Message = Pfx<'a>;
+    const SENSITIVE: bool = true;
+}
+
+impl AsRef<[u8]> for PfxDocument {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl TryFrom<&[u8]> for PfxDocument {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+impl TryFrom<Pfx<'_>> for PfxDocument {
+    type Error = Error;
+
+    fn try_from(pfx: Pfx<'_>) -> Result<PfxDocument> {
+        Self::try_from(&pfx)
+    }
+}
+
+impl TryFrom<&Pfx<'_>> for PfxDocument {
+    type Error = Error;
+
+    fn try_from(pfx: &Pfx<'_>) -> Result<PfxDocument> {
+        Self::from_msg(pfx)
+    }
+}
+
+impl TryFrom<Vec<u8>> for PfxDocument {
+    type Error = Error;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self> {
+        // Ensure document is well-formed
+        Pfx::from_der(bytes.as_slice())?;
+        Ok(Self(bytes))
+    }
+}
+
+impl fmt::Debug for PfxDocument {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple("PfxDocument")
+            .field(&self.decode())
+            .finish()
+    }
+}
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl FromStr for PfxDocument {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_pem(s)
+    }
+}
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl pem::PemLabel for PfxDocument {
+    const TYPE_LABEL: &'static str = "PKCS12";
+}
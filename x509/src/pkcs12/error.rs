@@ -0,0 +1,82 @@
+//! Error types
+
+use const_oid::ObjectIdentifier;
+use core::fmt;
+
+/// Result type
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Error type
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// ASN.1 DER-related errors.
+    Asn1(der::Error),
+
+    /// Decryption failed, e.g. due to an incorrect password.
+    DecryptFailed,
+
+    /// Iteration count exceeds
+    /// [`DecryptOptions::max_iterations`][`super::DecryptOptions::max_iterations`].
+    IterationCountTooLarge {
+        /// Iteration count given in the PFX.
+        iterations: u32,
+    },
+
+    /// The integrity MAC of the PFX does not match, e.g. due to an incorrect
+    /// password.
+    MacVerificationFailed,
+
+    /// Errors relating to PKCS#5 (PBES2) encryption.
+    Pkcs5(pkcs8::pkcs5::Error),
+
+    /// scrypt parameters exceed
+    /// [`DecryptOptions::max_scrypt_memory`][`super::DecryptOptions::max_scrypt_memory`]
+    /// or [`DecryptOptions::max_scrypt_cost`][`super::DecryptOptions::max_scrypt_cost`].
+    ScryptParamsTooLarge,
+
+    /// Algorithm is not supported.
+    UnsupportedAlgorithm {
+        /// OID of the unsupported algorithm.
+        oid: ObjectIdentifier,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Asn1(err) => write!(f, "PKCS#12 ASN.1 error: {}", err),
+            Error::DecryptFailed => f.write_str("PKCS#12 decryption failed"),
+            Error::IterationCountTooLarge { iterations } => {
+                write!(f, "PKCS#12 iteration count {} is too large", iterations)
+            }
+            Error::MacVerificationFailed => f.write_str("PKCS#12 MAC verification failed"),
+            Error::Pkcs5(err) => write!(f, "{}", err),
+            Error::ScryptParamsTooLarge => f.write_str("PKCS#12 scrypt parameters are too large"),
+            Error::UnsupportedAlgorithm { oid } => {
+                write!(f, "PKCS#12 algorithm {} is unsupported", oid)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<der::Error> for Error {
+    fn from(err: der::Error) -> Error {
+        Error::Asn1(err)
+    }
+}
+
+impl From<der::ErrorKind> for Error {
+    fn from(err: der::ErrorKind) -> Error {
+        Error::Asn1(err.into())
+    }
+}
+
+impl From<pkcs8::pkcs5::Error> for Error {
+    fn from(err: pkcs8::pkcs5::Error) -> Error {
+        Error::Pkcs5(err)
+    }
+}
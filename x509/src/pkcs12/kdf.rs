@@ -0,0 +1,88 @@
+//! Key derivation function as defined in [RFC 7292 Appendix B].
+//!
+//! [RFC 7292 Appendix B]: https://datatracker.ietf.org/doc/html/rfc7292#appendix-B
+
+use alloc::vec::Vec;
+use digest::{core_api::BlockSizeUser, Digest};
+use zeroize::{Zeroize, Zeroizing};
+
+/// Diversifier for deriving encryption keys.
+pub(crate) const KEY_ID: u8 = 1;
+
+/// Diversifier for deriving initialization vectors.
+pub(crate) const IV_ID: u8 = 2;
+
+/// Diversifier for deriving MAC keys.
+pub(crate) const MAC_ID: u8 = 3;
+
+/// Encode a password as a NUL-terminated big-endian `BMPString`, as expected
+/// by [`derive`].
+pub(crate) fn bmp_password(password: &str) -> Zeroizing<Vec<u8>> {
+    Zeroizing::new(
+        password
+            .encode_utf16()
+            .chain(Some(0))
+            .flat_map(|c| c.to_be_bytes())
+            .collect(),
+    )
+}
+
+/// Derive `len` bytes of key material of the given kind (`id`) from a
+/// `BMPString`-encoded password.
+pub(crate) fn derive<D>(
+    password: &[u8],
+    salt: &[u8],
+    iterations: u32,
+    id: u8,
+    len: usize,
+) -> Zeroizing<Vec<u8>>
+where
+    D: Digest + BlockSizeUser,
+{
+    let v = D::block_size();
+    let diversifier = alloc::vec![id; v];
+
+    let mut input = fill(salt, v);
+    input.extend_from_slice(&fill(password, v));
+
+    let mut output = Zeroizing::new(Vec::with_capacity(len));
+
+    loop {
+        let mut a = D::new()
+            .chain_update(&diversifier)
+            .chain_update(&input)
+            .finalize();
+
+        for _ in 1..iterations {
+            a = D::digest(&a);
+        }
+
+        let n = a.len().min(len - output.len());
+        output.extend_from_slice(&a[..n]);
+
+        if output.len() == len {
+            a.as_mut_slice().zeroize();
+            return output;
+        }
+
+        // I_j = (I_j + B + 1) mod 2^(v*8) for every v-byte block of I
+        let b = fill(&a, v);
+        a.as_mut_slice().zeroize();
+
+        for block in input.chunks_mut(v) {
+            let mut carry = 1u16;
+
+            for (x, y) in block.iter_mut().zip(b.iter()).rev() {
+                carry += u16::from(*x) + u16::from(*y);
+                *x = carry as u8;
+                carry >>= 8;
+            }
+        }
+    }
+}
+
+/// Repeat `bytes` up to the next multiple of `v` bytes.
+fn fill(bytes: &[u8], v: usize) -> Zeroizing<Vec<u8>> {
+    let len = v * ((bytes.len() + v - 1) / v);
+    Zeroizing::new(bytes.iter().cycle().take(len).copied().collect())
+}
@@ -1,5 +1,7 @@
 //! PKCS#10 Certification Request types
 
+pub mod document;
+
 use crate::ext::Extension;
 use crate::{attr::Attributes, name::Name};
 
@@ -0,0 +1,89 @@
+//! CertReqDocument implementation
+
+use super::CertReq;
+use der::{Error, Result};
+
+use alloc::vec::Vec;
+use core::fmt;
+use der::{Decode, Document};
+
+#[cfg(feature = "pem")]
+use {core::str::FromStr, der::pem};
+
+/// Certification request document.
+///
+/// This type provides storage for [`CertReq`] encoded as ASN.1
+/// DER with the invariant that the contained-document is "well-formed", i.e.
+/// it will parse successfully according to this crate's parsing rules.
+#[derive(Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct CertReqDocument(Vec<u8>);
+
+impl<'a> Document<'a> for CertReqDocument {
+    type Message = CertReq<'a>;
+    const SENSITIVE: bool = false;
+}
+
+impl AsRef<[u8]> for CertReqDocument {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl TryFrom<&[u8]> for CertReqDocument {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+impl TryFrom<CertReq<'_>> for CertReqDocument {
+    type Error = Error;
+
+    fn try_from(req: CertReq<'_>) -> Result<CertReqDocument> {
+        Self::try_from(&req)
+    }
+}
+
+impl TryFrom<&CertReq<'_>> for CertReqDocument {
+    type Error = Error;
+
+    fn try_from(req: &CertReq<'_>) -> Result<CertReqDocument> {
+        Self::from_msg(req)
+    }
+}
+
+impl TryFrom<Vec<u8>> for CertReqDocument {
+    type Error = Error;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self> {
+        // Ensure document is well-formed
+        CertReq::from_der(bytes.as_slice())?;
+        Ok(Self(bytes))
+    }
+}
+
+impl fmt::Debug for CertReqDocument {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_tuple("CertReqDocument")
+            .field(&self.decode())
+            .finish()
+    }
+}
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl FromStr for CertReqDocument {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_pem(s)
+    }
+}
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl pem::PemLabel for CertReqDocument {
+    const TYPE_LABEL: &'static str = "CERTIFICATE REQUEST";
+}
@@ -0,0 +1,462 @@
+//! Time-Stamp Protocol (TSP) types as defined in [RFC 3161].
+//!
+//! [RFC 3161]: https://datatracker.ietf.org/doc/html/rfc3161
+
+use crate::cms::ContentInfo;
+use crate::ext::pkix::name::GeneralName;
+use crate::ext::Extensions;
+
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use const_oid::ObjectIdentifier;
+use der::asn1::{BitString, GeneralizedTime, OctetString, UIntBytes, Utf8String};
+use der::{
+    DateTime, Decode, DecodeValue, Decoder, Encode, EncodeValue, Encoder, Enumerated, ErrorKind,
+    FixedTag, Header, Length, Result, Sequence, Tag,
+};
+use spki::AlgorithmIdentifier;
+
+#[cfg(feature = "std")]
+use std::time::SystemTime;
+
+/// `id-ct-TSTInfo` Object Identifier (OID).
+pub const ID_CT_TST_INFO: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16.1.4");
+
+/// TSP `Version` as defined in [RFC 3161 Section 2.4.1].
+///
+/// ```text
+/// version INTEGER { v1(1) }
+/// ```
+///
+/// [RFC 3161 Section 2.4.1]: https://datatracker.ietf.org/doc/html/rfc3161#section-2.4.1
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Enumerated)]
+#[asn1(type = "INTEGER")]
+#[repr(u8)]
+pub enum Version {
+    /// Version 1 (default)
+    V1 = 1,
+}
+
+impl Default for Version {
+    fn default() -> Self {
+        Self::V1
+    }
+}
+
+/// `TimeStampReq` as defined in [RFC 3161 Section 2.4.1].
+///
+/// ```text
+/// TimeStampReq ::= SEQUENCE  {
+///     version                      INTEGER  { v1(1) },
+///     messageImprint               MessageImprint,
+///     reqPolicy             TSAPolicyId              OPTIONAL,
+///     nonce                 INTEGER                  OPTIONAL,
+///     certReq               BOOLEAN                  DEFAULT FALSE,
+///     extensions            [0] IMPLICIT Extensions  OPTIONAL  }
+///
+/// TSAPolicyId ::= OBJECT IDENTIFIER
+/// ```
+///
+/// [RFC 3161 Section 2.4.1]: https://datatracker.ietf.org/doc/html/rfc3161#section-2.4.1
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct TimeStampReq<'a> {
+    pub version: Version,
+    pub message_imprint: MessageImprint<'a>,
+    pub req_policy: Option<ObjectIdentifier>,
+    pub nonce: Option<UIntBytes<'a>>,
+
+    #[asn1(default = "Default::default")]
+    pub cert_req: bool,
+
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT", optional = "true")]
+    pub extensions: Option<Extensions<'a>>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for TimeStampReq<'a> {
+    type Error = der::Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+/// `MessageImprint` as defined in [RFC 3161 Section 2.4.1].
+///
+/// ```text
+/// MessageImprint ::= SEQUENCE  {
+///     hashAlgorithm                AlgorithmIdentifier,
+///     hashedMessage                OCTET STRING  }
+/// ```
+///
+/// [RFC 3161 Section 2.4.1]: https://datatracker.ietf.org/doc/html/rfc3161#section-2.4.1
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct MessageImprint<'a> {
+    pub hash_algorithm: AlgorithmIdentifier<'a>,
+    pub hashed_message: OctetString<'a>,
+}
+
+/// `TimeStampResp` as defined in [RFC 3161 Section 2.4.2].
+///
+/// ```text
+/// TimeStampResp ::= SEQUENCE  {
+///     status                  PKIStatusInfo,
+///     timeStampToken          TimeStampToken     OPTIONAL  }
+///
+/// TimeStampToken ::= ContentInfo
+/// ```
+///
+/// [RFC 3161 Section 2.4.2]: https://datatracker.ietf.org/doc/html/rfc3161#section-2.4.2
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct TimeStampResp<'a> {
+    pub status: PkiStatusInfo<'a>,
+    pub time_stamp_token: Option<ContentInfo<'a>>,
+}
+
+impl<'a> TimeStampResp<'a> {
+    /// Decode the [`TstInfo`] contained in the time-stamp token.
+    ///
+    /// Returns an error if the request was not granted, or if the token
+    /// does not contain a [`TstInfo`]. The signature of the token is not
+    /// verified.
+    pub fn tst_info(&self) -> Result<TstInfo<'a>> {
+        let token = match (self.status.status, &self.time_stamp_token) {
+            (PkiStatus::Granted | PkiStatus::GrantedWithMods, Some(token)) => token,
+            _ => return Err(ErrorKind::Failed.into()),
+        };
+
+        let content_info = match token {
+            ContentInfo::SignedData(Some(signed_data)) => signed_data.encap_content_info,
+            token => {
+                return Err(ErrorKind::OidUnknown {
+                    oid: token.content_type().to_oid(),
+                }
+                .into())
+            }
+        };
+
+        if content_info.e_content_type != ID_CT_TST_INFO {
+            return Err(ErrorKind::OidUnknown {
+                oid: content_info.e_content_type,
+            }
+            .into());
+        }
+
+        let e_content = content_info
+            .e_content
+            .ok_or_else(|| der::Error::from(ErrorKind::Failed))?;
+
+        TstInfo::from_der(e_content.as_bytes())
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for TimeStampResp<'a> {
+    type Error = der::Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+/// `PKIStatusInfo` as defined in [RFC 3161 Section 2.4.2].
+///
+/// ```text
+/// PKIStatusInfo ::= SEQUENCE {
+///     status        PKIStatus,
+///     statusString  PKIFreeText     OPTIONAL,
+///     failInfo      PKIFailureInfo  OPTIONAL  }
+///
+/// PKIFreeText ::= SEQUENCE SIZE (1..MAX) OF UTF8String
+/// ```
+///
+/// [RFC 3161 Section 2.4.2]: https://datatracker.ietf.org/doc/html/rfc3161#section-2.4.2
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct PkiStatusInfo<'a> {
+    pub status: PkiStatus,
+    pub status_string: Option<Vec<Utf8String<'a>>>,
+    pub fail_info: Option<BitString<'a>>,
+}
+
+/// `PKIStatus` as defined in [RFC 3161 Section 2.4.2].
+///
+/// ```text
+/// PKIStatus ::= INTEGER {
+///     granted                (0),
+///     grantedWithMods        (1),
+///     rejection              (2),
+///     waiting                (3),
+///     revocationWarning      (4),
+///     revocationNotification (5) }
+/// ```
+///
+/// [RFC 3161 Section 2.4.2]: https://datatracker.ietf.org/doc/html/rfc3161#section-2.4.2
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Enumerated)]
+#[asn1(type = "INTEGER")]
+#[repr(u32)]
+#[allow(missing_docs)]
+pub enum PkiStatus {
+    Granted = 0,
+    GrantedWithMods = 1,
+    Rejection = 2,
+    Waiting = 3,
+    RevocationWarning = 4,
+    RevocationNotification = 5,
+}
+
+/// `TSTInfo` as defined in [RFC 3161 Section 2.4.2].
+///
+/// ```text
+/// TSTInfo ::= SEQUENCE  {
+///     version                      INTEGER  { v1(1) },
+///     policy                       TSAPolicyId,
+///     messageImprint               MessageImprint,
+///     serialNumber                 INTEGER,
+///     genTime                      GeneralizedTime,
+///     accuracy                     Accuracy                 OPTIONAL,
+///     ordering                     BOOLEAN             DEFAULT FALSE,
+///     nonce                        INTEGER                  OPTIONAL,
+///     tsa                          [0] GeneralName          OPTIONAL,
+///     extensions                   [1] IMPLICIT Extensions  OPTIONAL  }
+/// ```
+///
+/// [RFC 3161 Section 2.4.2]: https://datatracker.ietf.org/doc/html/rfc3161#section-2.4.2
+#[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct TstInfo<'a> {
+    pub version: Version,
+    pub policy: ObjectIdentifier,
+    pub message_imprint: MessageImprint<'a>,
+    pub serial_number: UIntBytes<'a>,
+    pub gen_time: GenTime,
+    pub accuracy: Option<Accuracy>,
+
+    #[asn1(default = "Default::default")]
+    pub ordering: bool,
+
+    pub nonce: Option<UIntBytes<'a>>,
+
+    #[asn1(context_specific = "0", optional = "true")]
+    pub tsa: Option<GeneralName<'a>>,
+
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", optional = "true")]
+    pub extensions: Option<Extensions<'a>>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for TstInfo<'a> {
+    type Error = der::Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+/// `Accuracy` as defined in [RFC 3161 Section 2.4.2].
+///
+/// ```text
+/// Accuracy ::= SEQUENCE {
+///     seconds        INTEGER              OPTIONAL,
+///     millis     [0] INTEGER  (1..999)    OPTIONAL,
+///     micros     [1] INTEGER  (1..999)    OPTIONAL  }
+/// ```
+///
+/// [RFC 3161 Section 2.4.2]: https://datatracker.ietf.org/doc/html/rfc3161#section-2.4.2
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Sequence)]
+#[allow(missing_docs)]
+pub struct Accuracy {
+    pub seconds: Option<u32>,
+
+    #[asn1(context_specific = "0", tag_mode = "IMPLICIT", optional = "true")]
+    pub millis: Option<u16>,
+
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", optional = "true")]
+    pub micros: Option<u16>,
+}
+
+impl Accuracy {
+    /// Get the accuracy as a [`Duration`].
+    pub fn to_duration(&self) -> Duration {
+        Duration::from_secs(self.seconds.unwrap_or(0).into())
+            + Duration::from_millis(self.millis.unwrap_or(0).into())
+            + Duration::from_micros(self.micros.unwrap_or(0).into())
+    }
+}
+
+/// `genTime` of a [`TstInfo`].
+///
+/// This is a `GeneralizedTime` which, unlike [`GeneralizedTime`], may
+/// include fractional seconds as permitted by [RFC 3161 Section 2.4.2]:
+/// `YYYYMMDDhhmmss[.s...]Z`. Fractions are supported down to nanoseconds and
+/// must not have trailing zeros.
+///
+/// [RFC 3161 Section 2.4.2]: https://datatracker.ietf.org/doc/html/rfc3161#section-2.4.2
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct GenTime {
+    date_time: DateTime,
+    nanos: u32,
+}
+
+impl GenTime {
+    /// Maximum length of the value of an encoded [`GenTime`].
+    const MAX_LENGTH: usize = 25;
+
+    /// Create a new [`GenTime`] from a [`DateTime`] and fractional seconds.
+    pub fn new(date_time: DateTime, nanos: u32) -> Result<Self> {
+        if nanos >= 1_000_000_000 {
+            return Err(Self::TAG.value_error());
+        }
+
+        Ok(Self { date_time, nanos })
+    }
+
+    /// Create a [`GenTime`] without fractional seconds from a [`DateTime`].
+    pub fn from_date_time(date_time: DateTime) -> Self {
+        Self {
+            date_time,
+            nanos: 0,
+        }
+    }
+
+    /// Convert this [`GenTime`] into a [`DateTime`], truncating fractional
+    /// seconds.
+    pub fn to_date_time(&self) -> DateTime {
+        self.date_time
+    }
+
+    /// Get the fractional part of the seconds in nanoseconds.
+    pub fn subsec_nanos(&self) -> u32 {
+        self.nanos
+    }
+
+    /// Create a new [`GenTime`] given a [`Duration`] since `UNIX_EPOCH`
+    /// (a.k.a. "Unix time")
+    pub fn from_unix_duration(unix_duration: Duration) -> Result<Self> {
+        let date_time =
+            DateTime::from_unix_duration(unix_duration).map_err(|_| Self::TAG.value_error())?;
+
+        Self::new(date_time, unix_duration.subsec_nanos())
+    }
+
+    /// Get the duration of this timestamp since `UNIX_EPOCH`.
+    pub fn to_unix_duration(&self) -> Duration {
+        self.date_time.unix_duration() + Duration::from_nanos(self.nanos.into())
+    }
+
+    /// Instantiate from [`SystemTime`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn from_system_time(time: SystemTime) -> Result<Self> {
+        time.duration_since(SystemTime::UNIX_EPOCH)
+            .map_err(|_| Self::TAG.value_error())
+            .and_then(Self::from_unix_duration)
+    }
+
+    /// Convert to [`SystemTime`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn to_system_time(&self) -> SystemTime {
+        SystemTime::UNIX_EPOCH + self.to_unix_duration()
+    }
+
+    /// Encode the value into the given buffer, returning its length.
+    fn encode_into(&self, buf: &mut [u8; Self::MAX_LENGTH]) -> Result<usize> {
+        let date_time = GeneralizedTime::from_date_time(self.date_time).to_vec()?;
+
+        // Strip the tag, length and `Z` of the RFC 5280 encoding
+        let seconds = &date_time[2..date_time.len() - 1];
+        buf[..seconds.len()].copy_from_slice(seconds);
+        let mut len = seconds.len();
+
+        if self.nanos != 0 {
+            buf[len] = b'.';
+            len += 1;
+
+            let mut nanos = self.nanos;
+            let mut digits = 9;
+
+            while nanos % 10 == 0 {
+                nanos /= 10;
+                digits -= 1;
+            }
+
+            for i in (0..digits).rev() {
+                buf[len + i] = b'0' + (nanos % 10) as u8;
+                nanos /= 10;
+            }
+
+            len += digits;
+        }
+
+        buf[len] = b'Z';
+        Ok(len + 1)
+    }
+}
+
+impl<'a> DecodeValue<'a> for GenTime {
+    fn decode_value(decoder: &mut Decoder<'a>, header: Header) -> Result<Self> {
+        let bytes = OctetString::decode_value(decoder, header)?.as_bytes();
+
+        let (seconds, fraction) = match bytes {
+            [seconds @ .., b'Z'] if seconds.len() >= 14 => seconds.split_at(14),
+            _ => return Err(Self::TAG.value_error()),
+        };
+
+        // Validate the date and time using the RFC 5280 encoding
+        let mut rfc5280 = [0u8; 17];
+        rfc5280[..2].copy_from_slice(&[0x18, 15]);
+        rfc5280[2..16].copy_from_slice(seconds);
+        rfc5280[16] = b'Z';
+        let date_time = GeneralizedTime::from_der(&rfc5280)?.to_date_time();
+
+        let nanos = match fraction {
+            [] => 0,
+            [b'.', digits @ ..]
+                if (1..=9).contains(&digits.len())
+                    && digits.iter().all(u8::is_ascii_digit)
+                    && digits.last() != Some(&b'0') =>
+            {
+                let value = digits
+                    .iter()
+                    .fold(0, |acc, d| acc * 10 + u32::from(d - b'0'));
+
+                value * 10u32.pow(9 - digits.len() as u32)
+            }
+            _ => return Err(Self::TAG.value_error()),
+        };
+
+        Self::new(date_time, nanos)
+    }
+}
+
+impl EncodeValue for GenTime {
+    fn value_len(&self) -> Result<Length> {
+        let mut buf = [0u8; Self::MAX_LENGTH];
+        Length::try_from(self.encode_into(&mut buf)?)
+    }
+
+    fn encode_value(&self, encoder: &mut Encoder<'_>) -> Result<()> {
+        let mut buf = [0u8; Self::MAX_LENGTH];
+        let len = self.encode_into(&mut buf)?;
+        OctetString::new(&buf[..len])?.encode_value(encoder)
+    }
+}
+
+impl FixedTag for GenTime {
+    const TAG: Tag = Tag::GeneralizedTime;
+}
+
+impl From<DateTime> for GenTime {
+    fn from(date_time: DateTime) -> Self {
+        Self::from_date_time(date_time)
+    }
+}
+
+impl From<GeneralizedTime> for GenTime {
+    fn from(time: GeneralizedTime) -> Self {
+        Self::from_date_time(time.to_date_time())
+    }
+}
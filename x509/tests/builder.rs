@@ -0,0 +1,310 @@
+//! Certificate builder tests
+
+use const_oid::AssociatedOid;
+use core::time::Duration;
+use der::asn1::{BitString, ObjectIdentifier, UIntBytes, UtcTime};
+use der::{Decode, Document, Encode};
+use hex_literal::hex;
+use spki::{AlgorithmIdentifier, SubjectPublicKeyInfo};
+use x509_cert::attr::Attribute;
+use x509_cert::builder::{CertificateBuilder, RequestBuilder};
+use x509_cert::ext::pkix::name::GeneralName;
+use x509_cert::ext::pkix::{
+    BasicConstraints, ExtendedKeyUsage, KeyUsage, KeyUsages, SubjectAltName, SubjectKeyIdentifier,
+};
+use x509_cert::ext::{AsExtension, Extension, Extensions};
+use x509_cert::name::Name;
+use x509_cert::request::{self, CertReqInfo, ExtensionReq};
+use x509_cert::time::Validity;
+use x509_cert::{Certificate, TbsCertificate, Version};
+
+#[cfg(feature = "signature")]
+use signature::{Signature, Signer};
+
+/// `id-Ed25519` Object Identifier (OID)
+const ED25519_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+
+/// `id-ce-basicConstraints` Object Identifier (OID)
+const BASIC_CONSTRAINTS_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.29.19");
+
+/// `BasicConstraints` with `cA` set to `TRUE`
+const BASIC_CONSTRAINTS_CA: &[u8] = &hex!("30030101FF");
+
+/// Ed25519 public key
+const ED25519_PUBLIC_KEY: &[u8] =
+    &hex!("4D29167F3F1912A6F7ADFA293A051A15C05EC67B8F17267B1C5550DCE853BD0D");
+
+fn ed25519_algorithm_id() -> AlgorithmIdentifier<'static> {
+    AlgorithmIdentifier {
+        oid: ED25519_OID,
+        parameters: None,
+    }
+}
+
+fn validity() -> Validity {
+    Validity {
+        not_before: UtcTime::from_unix_duration(Duration::from_secs(1_600_000_000))
+            .unwrap()
+            .into(),
+        not_after: UtcTime::from_unix_duration(Duration::from_secs(1_900_000_000))
+            .unwrap()
+            .into(),
+    }
+}
+
+fn builder<'a>(name: &'a [u8]) -> CertificateBuilder<'a> {
+    let name = Name::from_der(name).unwrap();
+
+    CertificateBuilder::new(
+        UIntBytes::new(&[0x01]).unwrap(),
+        ed25519_algorithm_id(),
+        name.clone(),
+        validity(),
+        name,
+        SubjectPublicKeyInfo {
+            algorithm: ed25519_algorithm_id(),
+            subject_public_key: ED25519_PUBLIC_KEY,
+        },
+    )
+}
+
+#[test]
+fn build_tbs_certificate() {
+    let name = Name::encode_from_string("CN=Test CA,O=Example").unwrap();
+    let builder = builder(&name)
+        .extension(Extension {
+            extn_id: BASIC_CONSTRAINTS_OID,
+            critical: true,
+            extn_value: BASIC_CONSTRAINTS_CA,
+        })
+        .unwrap();
+
+    let tbs = builder.tbs_certificate();
+    assert_eq!(tbs.version, Version::V3);
+    assert_eq!(tbs.serial_number.as_bytes(), &[0x01]);
+    assert_eq!(tbs.issuer.to_string(), "CN=Test CA,O=Example");
+    assert_eq!(tbs.subject, tbs.issuer);
+    assert_eq!(tbs.validity, validity());
+    assert_eq!(tbs.extensions.as_ref().unwrap().len(), 1);
+
+    let signing_input = builder.signing_input().unwrap();
+    assert_eq!(&TbsCertificate::from_der(&signing_input).unwrap(), tbs);
+}
+
+#[test]
+fn reject_duplicate_extension() {
+    let name = Name::encode_from_string("CN=Test CA").unwrap();
+    let extension = Extension {
+        extn_id: BASIC_CONSTRAINTS_OID,
+        critical: true,
+        extn_value: BASIC_CONSTRAINTS_CA,
+    };
+
+    let builder = builder(&name).extension(extension.clone()).unwrap();
+    assert!(builder.extension(extension).is_err());
+}
+
+#[test]
+fn typed_extensions() {
+    let name = Name::encode_from_string("CN=Test CA").unwrap();
+
+    let basic_constraints = BasicConstraints {
+        ca: true,
+        path_len_constraint: Some(0),
+    };
+    let key_usage = KeyUsage(KeyUsages::KeyCertSign | KeyUsages::CRLSign);
+    let eku = ExtendedKeyUsage(vec![const_oid::db::rfc5280::ID_KP_SERVER_AUTH]);
+    let san = SubjectAltName(vec![GeneralName::DnsName(
+        der::asn1::Ia5String::new("example.com").unwrap(),
+    )]);
+    let ski = SubjectKeyIdentifier(der::asn1::OctetString::new(&[0x01, 0x02]).unwrap());
+
+    let mut bufs = [Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new()];
+    let [a, b, c, d, e] = &mut bufs;
+    let extensions = [
+        basic_constraints.to_extension(a).unwrap(),
+        key_usage.to_extension(b).unwrap(),
+        eku.to_extension(c).unwrap(),
+        san.to_extension(d).unwrap(),
+        ski.to_extension(e).unwrap(),
+    ];
+
+    let mut builder = builder(&name);
+    for extension in extensions {
+        builder = builder.extension(extension).unwrap();
+    }
+
+    let tbs = builder.tbs_certificate();
+    assert_eq!(
+        tbs.get::<BasicConstraints>().unwrap(),
+        Some((true, basic_constraints))
+    );
+    assert_eq!(tbs.get::<KeyUsage>().unwrap(), Some((true, key_usage)));
+    assert_eq!(tbs.get::<ExtendedKeyUsage>().unwrap(), Some((false, eku)));
+    assert_eq!(tbs.get::<SubjectAltName<'_>>().unwrap(), Some((false, san)));
+    assert_eq!(
+        tbs.get::<SubjectKeyIdentifier<'_>>().unwrap(),
+        Some((false, ski))
+    );
+    assert_eq!(
+        tbs.get::<x509_cert::ext::pkix::AuthorityKeyIdentifier<'_>>()
+            .unwrap(),
+        None
+    );
+}
+
+#[test]
+fn build_certificate() {
+    let name = Name::encode_from_string("CN=Test CA").unwrap();
+    let builder = builder(&name);
+    let tbs = builder.tbs_certificate().clone();
+
+    let signature = [0x42; 64];
+    let cert = builder.build(BitString::from_bytes(&signature).unwrap());
+    assert_eq!(cert.tbs_certificate, tbs);
+    assert_eq!(cert.signature_algorithm, ed25519_algorithm_id());
+    assert_eq!(cert.signature.raw_bytes(), &signature);
+
+    let der = cert.to_vec().unwrap();
+    assert_eq!(Certificate::from_der(&der).unwrap(), cert);
+}
+
+fn request_builder<'a>(name: &'a [u8]) -> RequestBuilder<'a> {
+    RequestBuilder::new(
+        Name::from_der(name).unwrap(),
+        SubjectPublicKeyInfo {
+            algorithm: ed25519_algorithm_id(),
+            subject_public_key: ED25519_PUBLIC_KEY,
+        },
+        ed25519_algorithm_id(),
+    )
+}
+
+#[test]
+fn build_request() {
+    let name = Name::encode_from_string("CN=example.com").unwrap();
+    let mut buf = Vec::new();
+    let san = SubjectAltName(vec![GeneralName::DnsName(
+        der::asn1::Ia5String::new("example.com").unwrap(),
+    )]);
+
+    let builder = request_builder(&name)
+        .extension(san.to_extension(&mut buf).unwrap())
+        .unwrap();
+
+    let signing_input = builder.signing_input().unwrap();
+    let info = CertReqInfo::from_der(&signing_input).unwrap();
+    assert_eq!(info.version, request::Version::V1);
+    assert_eq!(info.subject.to_string(), "CN=example.com");
+    assert_eq!(info.attributes.len(), 1);
+
+    let attribute = info.attributes.get(0).unwrap();
+    assert_eq!(attribute.oid, ExtensionReq::OID);
+    let extensions: Extensions<'_> = attribute.values.get(0).unwrap().decode_into().unwrap();
+    assert_eq!(extensions, [san.to_extension(&mut Vec::new()).unwrap()]);
+
+    let signature = [0x42; 64];
+    let doc = builder
+        .build(BitString::from_bytes(&signature).unwrap())
+        .unwrap();
+    let req = doc.decode();
+    assert_eq!(req.info, info);
+    assert_eq!(req.algorithm, ed25519_algorithm_id());
+    assert_eq!(req.signature.raw_bytes(), &signature);
+}
+
+#[test]
+fn build_request_without_attributes() {
+    let name = Name::encode_from_string("CN=example.com").unwrap();
+    let signing_input = request_builder(&name).signing_input().unwrap();
+    assert!(CertReqInfo::from_der(&signing_input)
+        .unwrap()
+        .attributes
+        .is_empty());
+}
+
+#[test]
+fn reject_request_attribute() {
+    let name = Name::encode_from_string("CN=example.com").unwrap();
+    let challenge_password = Attribute {
+        oid: ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.7"),
+        values: der::asn1::SetOfVec::try_from([der::asn1::Any::from(
+            der::asn1::Utf8String::new("password").unwrap(),
+        )])
+        .unwrap(),
+    };
+
+    let builder = request_builder(&name)
+        .attribute(challenge_password.clone())
+        .unwrap();
+    assert!(builder.clone().attribute(challenge_password).is_err());
+
+    // Extensions must be requested using `RequestBuilder::extension`
+    let extension_req = Attribute {
+        oid: ExtensionReq::OID,
+        values: Default::default(),
+    };
+    assert!(builder.attribute(extension_req).is_err());
+}
+
+/// Signature produced by [`DummySigner`].
+#[cfg(feature = "signature")]
+#[derive(Debug)]
+struct DummySignature(Vec<u8>);
+
+#[cfg(feature = "signature")]
+impl AsRef<[u8]> for DummySignature {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "signature")]
+impl Signature for DummySignature {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, signature::Error> {
+        Ok(Self(bytes.to_vec()))
+    }
+}
+
+/// Signer which "signs" a message by reversing it.
+#[cfg(feature = "signature")]
+struct DummySigner;
+
+#[cfg(feature = "signature")]
+impl Signer<DummySignature> for DummySigner {
+    fn try_sign(&self, msg: &[u8]) -> Result<DummySignature, signature::Error> {
+        Ok(DummySignature(msg.iter().rev().copied().collect()))
+    }
+}
+
+#[cfg(feature = "signature")]
+#[test]
+fn build_and_sign() {
+    let name = Name::encode_from_string("CN=Test CA").unwrap();
+    let builder = builder(&name);
+    let signing_input = builder.signing_input().unwrap();
+    let tbs = builder.tbs_certificate().clone();
+
+    let doc = builder.build_and_sign(&DummySigner).unwrap();
+    let cert = doc.decode();
+    assert_eq!(cert.tbs_certificate, tbs);
+    assert_eq!(cert.signature_algorithm, ed25519_algorithm_id());
+
+    let expected: Vec<u8> = signing_input.iter().rev().copied().collect();
+    assert_eq!(cert.signature.raw_bytes(), expected.as_slice());
+}
+
+#[cfg(feature = "signature")]
+#[test]
+fn build_and_sign_request() {
+    let name = Name::encode_from_string("CN=example.com").unwrap();
+    let builder = request_builder(&name);
+    let signing_input = builder.signing_input().unwrap();
+
+    let doc = builder.build_and_sign(&DummySigner).unwrap();
+    let req = doc.decode();
+    assert_eq!(req.info.to_vec().unwrap(), signing_input);
+
+    let expected: Vec<u8> = signing_input.iter().rev().copied().collect();
+    assert_eq!(req.signature.raw_bytes(), expected.as_slice());
+}
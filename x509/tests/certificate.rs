@@ -1,6 +1,6 @@
 //! Certificate tests
 use der::asn1::{BitString, ObjectIdentifier, UIntBytes};
-use der::{Decode, Decoder, Encode, Tag, Tagged};
+use der::{Decode, Decoder, Encode, Header, Length, RawDerOf, Sequence, Tag, TagNumber, Tagged};
 use hex_literal::hex;
 use spki::AlgorithmIdentifier;
 use x509_cert::Certificate;
@@ -344,3 +344,146 @@ fn decode_cert() {
         cert.signature.raw_bytes()
     );
 }
+
+#[test]
+fn decode_cert_with_legacy_strings() {
+    // Generated using OpenSSL with `string_mask = default`, which encodes the
+    // common name as a `TeletexString` and the organization as a `BMPString`
+    let der_encoded_cert = include_bytes!("examples/legacy-strings.der");
+
+    let cert = Certificate::from_der(der_encoded_cert).unwrap();
+    let subject = &cert.tbs_certificate.subject.0;
+    assert_eq!(subject[0].0.get(0).unwrap().value.tag(), Tag::TeletexString);
+    assert_eq!(subject[1].0.get(0).unwrap().value.tag(), Tag::BmpString);
+    assert_eq!(cert.to_vec().unwrap(), der_encoded_cert);
+
+    let lenient = Certificate::from_der_with_profile(der_encoded_cert, Profile::Lenient).unwrap();
+    assert_eq!(lenient, cert);
+
+    let err = Certificate::from_der_with_profile(der_encoded_cert, Profile::Rfc5280).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        der::ErrorKind::TagUnexpected {
+            expected: None,
+            actual: Tag::TeletexString
+        }
+    );
+}
+
+#[test]
+fn decode_cert_with_profile() {
+    let der_encoded_cert = include_bytes!("examples/amazon.der");
+
+    for profile in [Profile::Rfc5280, Profile::Lenient] {
+        let cert = Certificate::from_der_with_profile(der_encoded_cert, profile).unwrap();
+        assert_eq!(cert, Certificate::from_der(der_encoded_cert).unwrap());
+    }
+}
+
+#[test]
+fn reencode_cert_reverse() {
+    for der_encoded_cert in [
+        &include_bytes!("examples/amazon.der")[..],
+        &include_bytes!("examples/legacy-strings.der")[..],
+    ] {
+        let cert = Certificate::from_der(der_encoded_cert).unwrap();
+        let mut buf = [0u8; 4096];
+        assert_eq!(
+            cert.encode_to_slice_reverse(&mut buf).unwrap(),
+            der_encoded_cert
+        );
+    }
+}
+
+#[test]
+fn reencode_cert_dynamic() {
+    let der_encoded_cert = include_bytes!("examples/amazon.der");
+    let value = der::dynamic::Value::from_der(der_encoded_cert).unwrap();
+    assert_eq!(value.values().unwrap().len(), 3);
+    assert_eq!(value.to_vec().unwrap(), der_encoded_cert);
+}
+
+#[test]
+fn tbs_certificate_raw_bytes() {
+    let der_encoded_cert = include_bytes!("examples/amazon.der");
+    let cert = Certificate::from_der(der_encoded_cert).unwrap();
+
+    let mut decoder = Decoder::new(der_encoded_cert).unwrap();
+    let tbs = decoder
+        .sequence(|decoder| {
+            let tbs = decoder.tlv()?;
+            decoder.tlv()?;
+            decoder.tlv()?;
+            Ok(tbs)
+        })
+        .unwrap();
+
+    assert_eq!(tbs.tag(), Tag::Sequence);
+    assert_eq!(tbs.offset(), Length::new(4));
+    let end = usize::try_from(tbs.end_offset().unwrap()).unwrap();
+    assert_eq!(tbs.raw(), &der_encoded_cert[4..end]);
+    assert_eq!(tbs.raw(), cert.tbs_certificate.to_vec().unwrap());
+    assert_eq!(
+        tbs.decode_as::<TbsCertificate<'_>>().unwrap(),
+        cert.tbs_certificate
+    );
+}
+
+/// `Certificate` which retains the original encoding of its `TBSCertificate`.
+#[derive(Sequence)]
+struct RawCertificate<'a> {
+    tbs_certificate: RawDerOf<'a, TbsCertificate<'a>>,
+    signature_algorithm: AlgorithmIdentifier<'a>,
+    signature: BitString<'a>,
+}
+
+#[test]
+fn tbs_certificate_raw_der_of() {
+    let der_encoded_cert = include_bytes!("examples/amazon.der");
+    let cert = Certificate::from_der(der_encoded_cert).unwrap();
+    let raw_cert = RawCertificate::from_der(der_encoded_cert).unwrap();
+
+    assert_eq!(raw_cert.tbs_certificate.value(), &cert.tbs_certificate);
+    assert_eq!(
+        raw_cert.tbs_certificate.raw(),
+        cert.tbs_certificate.to_vec().unwrap()
+    );
+    assert_eq!(raw_cert.signature_algorithm, cert.signature_algorithm);
+    assert_eq!(raw_cert.signature, cert.signature);
+    assert_eq!(raw_cert.to_vec().unwrap(), der_encoded_cert);
+}
+
+#[test]
+fn version_default_round_trip() {
+    let der_encoded_cert =
+        include_bytes!("examples/026EDA6FA1EDFA8C253936C75B5EEBD954BFF452.fake.der");
+    let cert = Certificate::from_der(der_encoded_cert).unwrap();
+
+    // v3 is explicitly encoded as `[0] INTEGER 2`
+    let tbs_v3 = cert.tbs_certificate.to_vec().unwrap();
+    assert_eq!(
+        first_field_tag(&tbs_v3),
+        Tag::ContextSpecific {
+            constructed: true,
+            number: TagNumber::N0,
+        }
+    );
+
+    // v1 is the `DEFAULT`, so it is omitted
+    let mut tbs = cert.tbs_certificate.clone();
+    tbs.version = Version::V1;
+    tbs.extensions = None;
+    let tbs_v1 = tbs.to_vec().unwrap();
+    assert_eq!(first_field_tag(&tbs_v1), Tag::Integer);
+
+    let decoded = TbsCertificate::from_der(&tbs_v1).unwrap();
+    assert_eq!(decoded.version, Version::V1);
+    assert_eq!(decoded, tbs);
+}
+
+/// Get the tag of the first field of a DER-encoded `SEQUENCE`.
+fn first_field_tag(der: &[u8]) -> Tag {
+    let mut decoder = Decoder::new(der).unwrap();
+    Header::decode(&mut decoder).unwrap();
+    decoder.peek_tag().unwrap()
+}
@@ -84,3 +84,20 @@ fn encode_rsa_2048_der() {
     let cr_encoded = cr.to_vec().unwrap();
     assert_eq!(RSA_2048_DER_EXAMPLE, cr_encoded.as_slice());
 }
+
+#[cfg(feature = "pem")]
+#[test]
+fn decode_rsa_2048_pem() {
+    use der::Document;
+    use x509_cert::request::document::CertReqDocument;
+
+    let doc: CertReqDocument = include_str!("examples/rsa2048-csr.pem").parse().unwrap();
+    assert_eq!(doc.as_der(), RSA_2048_DER_EXAMPLE);
+
+    let pem = doc.to_pem(der::pem::LineEnding::LF).unwrap();
+    assert!(pem.starts_with("-----BEGIN CERTIFICATE REQUEST-----"));
+    assert_eq!(
+        CertReqDocument::from_pem(&pem).unwrap().as_der(),
+        doc.as_der()
+    );
+}
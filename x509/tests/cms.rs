@@ -0,0 +1,239 @@
+//! CMS tests
+
+use const_oid::db::rfc5911::ID_DATA;
+use der::{Decode, Document, Encode, Tag, TagNumber, Tagged};
+use hex_literal::hex;
+use x509_cert::certificate::document::CertificateDocument;
+use x509_cert::cms::{
+    CertificateBundle, CertificateChoices, CmsVersion, ContentInfo, ContentInfoDocument,
+    ContentType, EnvelopedDataContent, RecipientInfo, SignedDataContent, SignerIdentifier,
+};
+use x509_cert::{name::Name, Certificate};
+
+fn signed_data<'a>(content_info: &'a ContentInfo<'a>) -> &'a SignedDataContent<'a> {
+    match content_info {
+        ContentInfo::SignedData(Some(signed_data)) => signed_data,
+        other => panic!("unexpected content: {:?}", other),
+    }
+}
+
+fn enveloped_data<'a>(content_info: &'a ContentInfo<'a>) -> &'a EnvelopedDataContent<'a> {
+    match content_info {
+        ContentInfo::EnvelopedData(Some(enveloped_data)) => enveloped_data,
+        other => panic!("unexpected content: {:?}", other),
+    }
+}
+
+const MESSAGE: &[u8] = b"Hello, world!\n";
+
+#[test]
+fn decode_signed_data() {
+    let der_encoded_msg = include_bytes!("examples/cms-signed.der");
+    let content_info = ContentInfo::from_der(der_encoded_msg).unwrap();
+    assert_eq!(content_info.content_type(), ContentType::SignedData);
+
+    let signed_data = signed_data(&content_info);
+    assert_eq!(signed_data.version, CmsVersion::V1);
+    assert_eq!(signed_data.digest_algorithms.len(), 1);
+    assert_eq!(signed_data.encap_content_info.e_content_type, ID_DATA);
+    assert_eq!(
+        signed_data.encap_content_info.e_content.unwrap().as_bytes(),
+        MESSAGE
+    );
+    assert!(signed_data.crls.is_none());
+
+    let certificates = signed_data.certificates.as_ref().unwrap();
+    let cert = match &certificates.0[..] {
+        [CertificateChoices::Certificate(cert)] => cert.decode_into::<Certificate<'_>>().unwrap(),
+        other => panic!("unexpected certificates: {:?}", other),
+    };
+    assert_eq!(
+        cert.tbs_certificate.subject.to_string(),
+        "CN=Example Signer,O=Example,C=US"
+    );
+
+    let signer_info = &signed_data.signer_infos.0[0];
+    assert_eq!(signed_data.signer_infos.0.len(), 1);
+    assert_eq!(signer_info.version, CmsVersion::V1);
+    assert!(signer_info.signed_attrs.is_none());
+    assert_eq!(signer_info.signature.as_bytes().len(), 256);
+    match &signer_info.sid {
+        SignerIdentifier::IssuerAndSerialNumber(sid) => {
+            assert_eq!(
+                sid.issuer.decode_into::<Name<'_>>().unwrap(),
+                cert.tbs_certificate.issuer
+            );
+            assert_eq!(sid.serial_number.as_bytes(), &[0x02]);
+        }
+        other => panic!("unexpected signer identifier: {:?}", other),
+    }
+
+    assert_eq!(content_info.to_vec().unwrap(), der_encoded_msg);
+}
+
+#[test]
+fn decode_signed_data_ber() {
+    // Streamed with indefinite lengths and a segmented `eContent`
+    let ber_encoded_msg = include_bytes!("examples/cms-signed.ber");
+    assert!(ContentInfo::from_der(ber_encoded_msg).is_err());
+
+    let doc = ContentInfoDocument::from_ber(ber_encoded_msg).unwrap();
+    assert_eq!(doc.as_ref(), include_bytes!("examples/cms-signed.der"));
+
+    let content_info = doc.decode();
+    assert_eq!(
+        signed_data(&content_info)
+            .encap_content_info
+            .e_content
+            .unwrap()
+            .as_bytes(),
+        MESSAGE
+    );
+}
+
+#[test]
+fn decode_enveloped_data() {
+    let der_encoded_msg = include_bytes!("examples/cms-enveloped.der");
+    let content_info = ContentInfo::from_der(der_encoded_msg).unwrap();
+    assert_eq!(content_info.content_type(), ContentType::EnvelopedData);
+
+    let enveloped_data = enveloped_data(&content_info);
+    assert_eq!(enveloped_data.version, CmsVersion::V2);
+    assert!(enveloped_data.originator_info.is_none());
+    assert!(enveloped_data.unprotected_attrs.is_none());
+
+    // RSA recipient followed by an ECDH one
+    match &enveloped_data.recipient_infos.0[..] {
+        [RecipientInfo::Ktri(ktri), RecipientInfo::Other(kari)] => {
+            assert_eq!(ktri.version, CmsVersion::V0);
+            assert_eq!(ktri.encrypted_key.as_bytes().len(), 256);
+            assert!(matches!(
+                ktri.rid,
+                SignerIdentifier::IssuerAndSerialNumber(_)
+            ));
+            assert_eq!(
+                kari.tag(),
+                Tag::ContextSpecific {
+                    constructed: true,
+                    number: TagNumber::N1,
+                }
+            );
+        }
+        other => panic!("unexpected recipients: {:?}", other),
+    }
+
+    let eci = &enveloped_data.encrypted_content_info;
+    assert_eq!(eci.content_type, ContentType::Data);
+    assert_eq!(
+        eci.content_encryption_algorithm.oid,
+        const_oid::db::rfc5911::ID_AES_128_CBC
+    );
+    assert_eq!(eci.encrypted_content.unwrap().len(), 16);
+
+    assert_eq!(content_info.to_vec().unwrap(), der_encoded_msg);
+}
+
+#[test]
+fn decode_enveloped_data_ber() {
+    // Indefinite lengths for every constructed value, and non-minimal
+    // lengths for every primitive one
+    let ber_encoded_msg = include_bytes!("examples/cms-enveloped.ber");
+    let doc = ContentInfoDocument::from_ber(ber_encoded_msg).unwrap();
+    assert_eq!(doc.as_ref(), include_bytes!("examples/cms-enveloped.der"));
+
+    // DER input is passed through as-is
+    let der_encoded_msg = include_bytes!("examples/cms-enveloped.der");
+    let doc = ContentInfoDocument::from_ber(der_encoded_msg).unwrap();
+    assert_eq!(doc.as_ref(), der_encoded_msg);
+}
+
+#[test]
+fn ber_to_der() {
+    // Segmented OCTET STRING with a non-minimal length
+    let doc = ContentInfoDocument::from_ber(&hex!(
+        "3080"
+        "06092A864886F70D010701"
+        "A080"
+        "2480" "04820002ABCD" "0401EF" "0000"
+        "0000"
+        "0000"
+    ))
+    .unwrap();
+
+    assert_eq!(
+        doc.as_ref(),
+        hex!(
+            "3012"
+            "06092A864886F70D010701"
+            "A005"
+            "0403ABCDEF"
+        )
+    );
+
+    match doc.decode() {
+        ContentInfo::Data(Some(data)) => assert_eq!(data.content, hex!("ABCDEF")),
+        other => panic!("unexpected content: {:?}", other),
+    }
+}
+
+#[test]
+fn ber_errors() {
+    // Missing end-of-contents
+    assert!(ContentInfoDocument::from_ber(&hex!("308006092A864886F70D010701")).is_err());
+
+    // Indefinite length for a primitive value
+    assert!(ContentInfoDocument::from_ber(&hex!("3080068000000000")).is_err());
+
+    // Trailing data
+    assert!(
+        ContentInfoDocument::from_ber(&hex!("301006092A864886F70D010701A0030401FF00")).is_err()
+    );
+
+    // Unused bits in a segment other than the last
+    assert!(ContentInfoDocument::from_ber(&hex!(
+        "3080" "06092A864886F70D010701" "A080" "2380" "03020401" "03020000" "0000" "0000" "0000"
+    ))
+    .is_err());
+}
+
+#[test]
+fn certificate_bundle() {
+    let ca = include_bytes!("examples/ocsp-ca.der");
+    let signer = include_bytes!("examples/cms-signer.der");
+
+    // Created using `openssl crl2pkcs7 -nocrl`
+    let der_encoded_bundle = include_bytes!("examples/cms-bundle.p7b");
+    let bundle = CertificateBundle::from_pkcs7(der_encoded_bundle).unwrap();
+    assert_eq!(bundle.len(), 2);
+    assert_eq!(bundle.as_slice()[0].as_ref(), ca);
+    assert_eq!(bundle.as_slice()[1].as_ref(), signer);
+    assert_eq!(bundle.to_pkcs7().unwrap().as_ref(), der_encoded_bundle);
+
+    // Order is preserved
+    let bundle: CertificateBundle = [signer.as_slice(), ca]
+        .iter()
+        .map(|der| CertificateDocument::try_from(*der).unwrap())
+        .collect();
+    let doc = bundle.to_pkcs7().unwrap();
+    let roundtrip = CertificateBundle::from_pkcs7(doc.as_ref()).unwrap();
+    assert_eq!(roundtrip.as_slice()[0].as_ref(), signer);
+    assert_eq!(roundtrip.as_slice()[1].as_ref(), ca);
+
+    let content_info = doc.decode();
+    let signed_data = signed_data(&content_info);
+    assert!(signed_data.encap_content_info.e_content.is_none());
+    assert!(signed_data.signer_infos.0.is_empty());
+
+    // Certificates are extracted from signed messages too
+    let bundle = CertificateBundle::from_pkcs7(include_bytes!("examples/cms-signed.ber")).unwrap();
+    assert_eq!(bundle.iter().next().unwrap().as_ref(), signer);
+
+    // Empty bundle
+    let doc = CertificateBundle::default().to_pkcs7().unwrap();
+    assert!(CertificateBundle::from_pkcs7(doc.as_ref())
+        .unwrap()
+        .is_empty());
+
+    // Not signed data
+    assert!(CertificateBundle::from_pkcs7(include_bytes!("examples/cms-enveloped.der")).is_err());
+}
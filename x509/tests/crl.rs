@@ -1,5 +1,6 @@
-use der::Decode;
-use x509_cert::crl::CertificateList;
+use der::{Decode, Encode};
+use hex_literal::hex;
+use x509_cert::crl::{CertificateList, RevokedCert, RevokedCertificates};
 
 #[test]
 fn decode_crl() {
@@ -7,11 +8,62 @@ fn decode_crl() {
     let der_encoded_cert = include_bytes!("examples/GoodCACRL.crl");
     let crl = CertificateList::from_der(der_encoded_cert).unwrap();
     assert_eq!(2, crl.tbs_cert_list.crl_extensions.unwrap().len());
-    assert_eq!(2, crl.tbs_cert_list.revoked_certificates.unwrap().len());
+    assert_eq!(
+        2,
+        crl.tbs_cert_list
+            .revoked_certificates
+            .unwrap()
+            .iter()
+            .count()
+    );
 
     // CRL with an entry with no entry extensions
     let der_encoded_cert = include_bytes!("examples/tscpbcasha256.crl");
     let crl = CertificateList::from_der(der_encoded_cert).unwrap();
     assert_eq!(2, crl.tbs_cert_list.crl_extensions.unwrap().len());
-    assert_eq!(4, crl.tbs_cert_list.revoked_certificates.unwrap().len());
+    assert_eq!(
+        4,
+        crl.tbs_cert_list
+            .revoked_certificates
+            .unwrap()
+            .iter()
+            .count()
+    );
+}
+
+#[test]
+fn iterate_revoked_certificates() {
+    let der_encoded_cert = include_bytes!("examples/GoodCACRL.crl");
+    let crl = CertificateList::from_der(der_encoded_cert).unwrap();
+    let revoked = crl.tbs_cert_list.revoked_certificates.unwrap();
+
+    let entries = revoked
+        .iter()
+        .collect::<der::Result<Vec<RevokedCert<'_>>>>()
+        .unwrap();
+    assert_eq!(entries[0].serial_number.as_bytes(), &[0x0E]);
+    assert_eq!(entries[1].serial_number.as_bytes(), &[0x0F]);
+    assert_eq!(1, entries[0].crl_entry_extensions.as_ref().unwrap().len());
+
+    // Re-encoding preserves the original bytes
+    assert_eq!(crl.to_vec().unwrap(), der_encoded_cert);
+}
+
+#[test]
+fn revoked_certificates_lazy_error() {
+    // A valid entry followed by a truncated one
+    let revoked = RevokedCertificates::from_der(&hex!(
+        "3018"
+        "3012020101170D3130303130313038333030305A"
+        "30030201"
+    ))
+    .unwrap();
+
+    let mut iter = revoked.iter();
+    assert_eq!(
+        iter.next().unwrap().unwrap().serial_number.as_bytes(),
+        &[0x01]
+    );
+    assert!(iter.next().unwrap().is_err());
+    assert!(iter.next().is_none());
 }
@@ -78,3 +78,26 @@ fn plural(#[case] idx: usize, #[case] value: &[u8]) {
     let encoded = decoded.to_vec().unwrap();
     assert_eq!(value, encoded);
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn ip_addr() {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    let ipv4 = GeneralName::from_der(&hex!("8704C0A80001")).unwrap();
+    assert_eq!(
+        ipv4.ip_addr().unwrap(),
+        Some(IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)))
+    );
+
+    let ipv6 = GeneralName::from_der(&hex!("871020010DB8000000000000000000000001")).unwrap();
+    assert_eq!(
+        ipv6.ip_addr().unwrap(),
+        Some(IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)))
+    );
+
+    // Address and subnet mask, as used by name constraints
+    assert!(GeneralName::from_der(IPADDR).unwrap().ip_addr().is_err());
+
+    assert_eq!(GeneralName::from_der(URI).unwrap().ip_addr().unwrap(), None);
+}
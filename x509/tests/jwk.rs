@@ -0,0 +1,186 @@
+//! JSON Web Key (JWK) tests
+
+#![cfg(feature = "jwk")]
+
+use x509_cert::jwk::{EcCurve, Error, Jwk, KeyParams, OkpCurve};
+
+/// Check conversions of a JWK private key to and from its DER encodings.
+fn check_key(json: &str, spki: &[u8], pkcs8: &[u8]) -> Jwk {
+    let jwk: Jwk = serde_json::from_str(json).unwrap();
+    assert!(jwk.is_private());
+
+    // JSON round trip
+    let expected: serde_json::Value = serde_json::from_str(json).unwrap();
+    assert_eq!(serde_json::to_value(&jwk).unwrap(), expected);
+
+    // PKCS#8
+    assert_eq!(Jwk::from_pkcs8_der(pkcs8).unwrap(), jwk);
+    assert_eq!(jwk.to_pkcs8_der().unwrap(), pkcs8);
+
+    // SPKI
+    let public = Jwk::from_public_key_der(spki).unwrap();
+    assert!(!public.is_private());
+    assert_eq!(public, jwk.to_public());
+    assert_eq!(jwk.to_public_key_der().unwrap(), spki);
+    assert_eq!(public.to_public_key_der().unwrap(), spki);
+    assert_eq!(public.to_pkcs8_der(), Err(Error::MissingPrivateKey));
+
+    jwk
+}
+
+#[test]
+fn rsa2048() {
+    let jwk = check_key(
+        include_str!("examples/jwk-rsa2048.json"),
+        include_bytes!("examples/jwk-rsa2048-pub.der"),
+        include_bytes!("examples/jwk-rsa2048-priv.der"),
+    );
+
+    match &jwk.params {
+        KeyParams::Rsa(params) => {
+            assert_eq!(params.n.len(), 256);
+            assert_eq!(params.e, [0x01, 0x00, 0x01]);
+        }
+        other => panic!("unexpected key parameters: {:?}", other),
+    }
+
+    assert_eq!(jwk.to_sec1_der(), Err(Error::UnsupportedKeyType));
+}
+
+#[test]
+fn rsa_incomplete_private_key() {
+    let mut jwk: Jwk = serde_json::from_str(include_str!("examples/jwk-rsa2048.json")).unwrap();
+
+    if let KeyParams::Rsa(params) = &mut jwk.params {
+        params.qi = None;
+    }
+
+    assert_eq!(jwk.to_pkcs8_der(), Err(Error::KeyMalformed));
+}
+
+#[test]
+fn ec_p256() {
+    let jwk = check_key(
+        include_str!("examples/jwk-p256.json"),
+        include_bytes!("examples/jwk-p256-pub.der"),
+        include_bytes!("examples/jwk-p256-priv.der"),
+    );
+
+    let sec1 = include_bytes!("examples/jwk-p256-sec1.der");
+    assert_eq!(Jwk::from_sec1_der(sec1).unwrap(), jwk);
+    assert_eq!(jwk.to_sec1_der().unwrap(), sec1);
+}
+
+#[test]
+fn ec_p384() {
+    let jwk = check_key(
+        include_str!("examples/jwk-p384.json"),
+        include_bytes!("examples/jwk-p384-pub.der"),
+        include_bytes!("examples/jwk-p384-priv.der"),
+    );
+
+    match &jwk.params {
+        KeyParams::Ec(params) => assert_eq!(params.crv, EcCurve::P384),
+        other => panic!("unexpected key parameters: {:?}", other),
+    }
+}
+
+#[test]
+fn ec_p521() {
+    let jwk = check_key(
+        include_str!("examples/jwk-p521.json"),
+        include_bytes!("examples/jwk-p521-pub.der"),
+        include_bytes!("examples/jwk-p521-priv.der"),
+    );
+
+    match &jwk.params {
+        KeyParams::Ec(params) => {
+            assert_eq!(params.crv, EcCurve::P521);
+            assert_eq!(params.x.len(), 66);
+            assert_eq!(params.y.len(), 66);
+            assert_eq!(params.d.as_ref().unwrap().len(), 66);
+        }
+        other => panic!("unexpected key parameters: {:?}", other),
+    }
+}
+
+#[test]
+fn ec_wrong_coordinate_length() {
+    let mut jwk: Jwk = serde_json::from_str(include_str!("examples/jwk-p256.json")).unwrap();
+
+    if let KeyParams::Ec(params) = &mut jwk.params {
+        params.crv = EcCurve::P384;
+    }
+
+    assert_eq!(jwk.to_public_key_der(), Err(Error::KeyMalformed));
+}
+
+#[test]
+fn okp_ed25519() {
+    let jwk = check_key(
+        include_str!("examples/jwk-ed25519.json"),
+        include_bytes!("examples/jwk-ed25519-pub.der"),
+        include_bytes!("examples/jwk-ed25519-priv.der"),
+    );
+
+    match &jwk.params {
+        KeyParams::Okp(params) => assert_eq!(params.crv, OkpCurve::Ed25519),
+        other => panic!("unexpected key parameters: {:?}", other),
+    }
+}
+
+#[test]
+fn okp_ed25519_missing_public_key() {
+    assert_eq!(
+        Jwk::from_pkcs8_der(include_bytes!("examples/jwk-ed25519-priv-v1.der")),
+        Err(Error::MissingPublicKey)
+    );
+}
+
+#[test]
+fn okp_x25519() {
+    let jwk = check_key(
+        include_str!("examples/jwk-x25519.json"),
+        include_bytes!("examples/jwk-x25519-pub.der"),
+        include_bytes!("examples/jwk-x25519-priv.der"),
+    );
+
+    match &jwk.params {
+        KeyParams::Okp(params) => assert_eq!(params.crv, OkpCurve::X25519),
+        other => panic!("unexpected key parameters: {:?}", other),
+    }
+}
+
+#[test]
+fn optional_params() {
+    let json = r#"{
+        "kty": "OKP",
+        "crv": "Ed25519",
+        "x": "_vaPlQNaIDQ5eDL8Crcz6VVeVI9aJfqPF7MriMser0k",
+        "use": "sig",
+        "alg": "EdDSA",
+        "kid": "example",
+        "key_ops": ["verify"]
+    }"#;
+
+    let jwk: Jwk = serde_json::from_str(json).unwrap();
+    assert_eq!(jwk.key_use.as_deref(), Some("sig"));
+    assert_eq!(jwk.alg.as_deref(), Some("EdDSA"));
+    assert_eq!(jwk.kid.as_deref(), Some("example"));
+    assert_eq!(
+        jwk.to_public_key_der().unwrap(),
+        include_bytes!("examples/jwk-ed25519-pub.der")
+    );
+
+    // Unknown parameters are dropped
+    let value = serde_json::to_value(&jwk).unwrap();
+    assert!(value.get("key_ops").is_none());
+    assert_eq!(value["use"], "sig");
+}
+
+#[test]
+fn invalid_base64url() {
+    let json =
+        r#"{"kty": "OKP", "crv": "Ed25519", "x": "_vaPlQNaIDQ5eDL8Crcz6VVeVI9aJfqPF7MriMser0k="}"#;
+    assert!(serde_json::from_str::<Jwk>(json).is_err());
+}
@@ -0,0 +1,215 @@
+//! Certificate linting tests
+
+use const_oid::db::rfc5280::{
+    ID_CE_BASIC_CONSTRAINTS, ID_CE_INHIBIT_ANY_POLICY, ID_CE_KEY_USAGE, ID_CE_SUBJECT_ALT_NAME,
+    ID_CE_SUBJECT_KEY_IDENTIFIER,
+};
+use const_oid::db::rfc5912::SHA_1_WITH_RSA_ENCRYPTION;
+use core::time::Duration;
+use der::asn1::{BitString, GeneralizedTime, UIntBytes};
+use der::Decode;
+use hex_literal::hex;
+use x509_cert::ext::Extension;
+use x509_cert::lint::{Finding, Lint, Severity, Source};
+use x509_cert::name::Name;
+use x509_cert::time::Time;
+use x509_cert::{Certificate, Version};
+
+/// Certificate which has no findings.
+const AMAZON: &[u8] = include_bytes!("examples/amazon.der");
+
+/// `BasicConstraints` with `cA` set to `TRUE`
+const BASIC_CONSTRAINTS_CA: &[u8] = &hex!("30030101FF");
+
+fn lints(certificate: &Certificate<'_>) -> Vec<Lint> {
+    certificate
+        .lint()
+        .iter()
+        .map(|finding| finding.lint)
+        .collect()
+}
+
+#[test]
+fn lint_clean_certificate() {
+    let cert = Certificate::from_der(AMAZON).unwrap();
+    assert_eq!(cert.lint(), []);
+}
+
+#[test]
+fn lint_example_certificates() {
+    let cert = Certificate::from_der(include_bytes!("examples/GoodCACert.crt")).unwrap();
+    let findings = cert.lint();
+    assert_eq!(findings, [Finding::from(Lint::SerialNumberLowEntropy)]);
+    assert_eq!(findings[0].severity(), Severity::Warning);
+    assert_eq!(findings[0].source(), Source::CabfBaselineRequirements);
+
+    let cert = Certificate::from_der(include_bytes!(
+        "examples/026EDA6FA1EDFA8C253936C75B5EEBD954BFF452.fake.der"
+    ))
+    .unwrap();
+    let findings = cert.lint();
+    assert_eq!(
+        findings,
+        [Finding {
+            lint: Lint::InhibitAnyPolicyNotCritical,
+            oid: Some(ID_CE_INHIBIT_ANY_POLICY),
+        }]
+    );
+    assert_eq!(findings[0].severity(), Severity::Error);
+    assert_eq!(findings[0].source(), Source::Rfc5280);
+    assert_eq!(
+        findings[0].to_string(),
+        "inhibit any policy is not critical (2.5.29.54)"
+    );
+}
+
+#[test]
+fn lint_version() {
+    let mut cert = Certificate::from_der(AMAZON).unwrap();
+    cert.tbs_certificate.version = Version::V1;
+    cert.tbs_certificate.subject_unique_id = Some(BitString::from_bytes(&[0x01]).unwrap());
+
+    assert_eq!(
+        lints(&cert),
+        [
+            Lint::VersionNotV3,
+            Lint::UniqueIdRequiresV2,
+            Lint::UniqueIdPresent,
+            Lint::ExtensionsRequireV3,
+        ]
+    );
+}
+
+#[test]
+fn lint_serial_number() {
+    let mut cert = Certificate::from_der(AMAZON).unwrap();
+    cert.tbs_certificate.serial_number = UIntBytes::new(&[0x00]).unwrap();
+    assert_eq!(
+        lints(&cert),
+        [Lint::SerialNumberNotPositive, Lint::SerialNumberLowEntropy]
+    );
+
+    let serial_number = [0x7F; 21];
+    cert.tbs_certificate.serial_number = UIntBytes::new(&serial_number).unwrap();
+    assert_eq!(lints(&cert), [Lint::SerialNumberTooLong]);
+
+    // Encoded with a leading zero byte, making it 21 octets long
+    let serial_number = [0xFF; 20];
+    cert.tbs_certificate.serial_number = UIntBytes::new(&serial_number).unwrap();
+    assert_eq!(lints(&cert), [Lint::SerialNumberTooLong]);
+
+    let serial_number = [0x7F; 20];
+    cert.tbs_certificate.serial_number = UIntBytes::new(&serial_number).unwrap();
+    assert_eq!(lints(&cert), []);
+}
+
+#[test]
+fn lint_signature_algorithm() {
+    let mut cert = Certificate::from_der(AMAZON).unwrap();
+    cert.signature_algorithm.oid = SHA_1_WITH_RSA_ENCRYPTION;
+
+    assert_eq!(
+        cert.lint(),
+        [
+            Lint::SignatureAlgorithmMismatch.into(),
+            Finding {
+                lint: Lint::DeprecatedSignatureAlgorithm,
+                oid: Some(SHA_1_WITH_RSA_ENCRYPTION),
+            },
+        ]
+    );
+}
+
+#[test]
+fn lint_validity() {
+    let mut cert = Certificate::from_der(AMAZON).unwrap();
+    let validity = &mut cert.tbs_certificate.validity;
+    validity.not_before = Time::GeneralTime(validity.not_before.to_general_time());
+    assert_eq!(lints(&cert), [Lint::GeneralizedTimeBefore2050]);
+
+    // `notAfter` is in 2050, so must be `GeneralizedTime`
+    let validity = &mut cert.tbs_certificate.validity;
+    validity.not_before = validity.not_after;
+    validity.not_after = Time::GeneralTime(
+        GeneralizedTime::from_unix_duration(Duration::from_secs(2_524_608_000)).unwrap(),
+    );
+    assert_eq!(lints(&cert), [Lint::ValidityPeriodTooLong]);
+
+    // Subscriber certificates may be valid for at most 398 days
+    let not_before = cert.tbs_certificate.validity.not_before.to_unix_duration();
+    cert.tbs_certificate.validity.not_after =
+        Time::from_unix_duration(not_before + Duration::from_secs(398 * 86400 - 1)).unwrap();
+    assert_eq!(lints(&cert), []);
+}
+
+#[test]
+fn lint_extensions() {
+    let mut cert = Certificate::from_der(AMAZON).unwrap();
+    let extensions = cert.tbs_certificate.extensions.as_mut().unwrap();
+
+    for extension in extensions.iter_mut() {
+        if extension.extn_id == ID_CE_KEY_USAGE {
+            extension.critical = false;
+        }
+    }
+
+    let duplicate = extensions[0].clone();
+    extensions.push(duplicate.clone());
+    extensions.push(duplicate.clone());
+
+    assert_eq!(
+        cert.lint(),
+        [
+            Finding {
+                lint: Lint::DuplicateExtension,
+                oid: Some(duplicate.extn_id),
+            },
+            Finding {
+                lint: Lint::KeyUsageNotCritical,
+                oid: Some(ID_CE_KEY_USAGE),
+            },
+        ]
+    );
+
+    cert.tbs_certificate.extensions = Some(Vec::new());
+    assert_eq!(
+        lints(&cert),
+        [Lint::ExtensionsEmpty, Lint::AuthorityKeyIdMissing]
+    );
+}
+
+#[test]
+fn lint_ca_extensions() {
+    let mut cert = Certificate::from_der(AMAZON).unwrap();
+    let extensions = cert.tbs_certificate.extensions.as_mut().unwrap();
+    extensions.retain(|extension| {
+        extension.extn_id != ID_CE_BASIC_CONSTRAINTS
+            && extension.extn_id != ID_CE_SUBJECT_KEY_IDENTIFIER
+    });
+    extensions.push(Extension {
+        extn_id: ID_CE_BASIC_CONSTRAINTS,
+        critical: false,
+        extn_value: BASIC_CONSTRAINTS_CA,
+    });
+
+    assert_eq!(
+        lints(&cert),
+        [Lint::BasicConstraintsNotCritical, Lint::SubjectKeyIdMissing]
+    );
+}
+
+#[test]
+fn lint_empty_subject() {
+    let mut cert = Certificate::from_der(AMAZON).unwrap();
+    cert.tbs_certificate.subject = Name::default();
+
+    let extensions = cert.tbs_certificate.extensions.as_mut().unwrap();
+    assert!(extensions
+        .iter()
+        .any(|extension| extension.extn_id == ID_CE_SUBJECT_ALT_NAME && !extension.critical));
+    assert_eq!(lints(&cert), [Lint::SubjectAltNameNotCritical]);
+
+    let extensions = cert.tbs_certificate.extensions.as_mut().unwrap();
+    extensions.retain(|extension| extension.extn_id != ID_CE_SUBJECT_ALT_NAME);
+    assert_eq!(lints(&cert), [Lint::SubjectMissing]);
+}
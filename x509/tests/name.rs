@@ -1,11 +1,12 @@
 //! Name tests
 
 use const_oid::ObjectIdentifier;
-use der::asn1::{OctetString, SetOfVec, Utf8String};
-use der::{Any, Decode, Encode, Tag, Tagged};
+use core::str::FromStr;
+use der::asn1::{OctetString, PrintableString, SetOfVec, Utf8String};
+use der::{Any, Decode, Document, Encode, Tag, Tagged};
 use hex_literal::hex;
 use x509_cert::attr::AttributeTypeAndValue;
-use x509_cert::name::{Name, RdnSequence, RelativeDistinguishedName};
+use x509_cert::name::{document::NameDocument, Name, RdnSequence, RelativeDistinguishedName};
 
 #[test]
 fn decode_name() {
@@ -306,7 +307,7 @@ fn rdns_serde() {
 
     for (inputs, output, rdns) in values {
         let mut brdns = RdnSequence::default();
-        for rdn in rdns.iter() {
+        for rdn in rdns.iter().rev() {
             let sofv = SetOfVec::try_from(rdn.to_vec()).unwrap();
             brdns.0.push(RelativeDistinguishedName::from(sofv));
         }
@@ -334,3 +335,79 @@ fn rdns_serde() {
         }
     }
 }
+
+#[test]
+fn rdns_string_order() {
+    // C=US, O=Test Certificates 2011, CN=Good CA
+    let name = Name::from_der(&hex!("3040310B3009060355040613025553311F301D060355040A1316546573742043657274696669636174657320323031313110300E06035504031307476F6F64204341")[..]).unwrap();
+    assert_eq!(name.to_string(), "CN=Good CA,O=Test Certificates 2011,C=US");
+
+    let der = RdnSequence::encode_from_string("CN=Good CA,O=Test Certificates 2011,C=US").unwrap();
+    let parsed = RdnSequence::from_der(&der).unwrap();
+    assert_eq!(parsed.0[0].0.get(0).unwrap().oid, const_oid::db::rfc4519::C);
+    assert_eq!(
+        parsed.0[2].0.get(0).unwrap().oid,
+        const_oid::db::rfc4519::CN
+    );
+}
+
+#[test]
+fn name_document_from_str() {
+    let doc = NameDocument::from_str("OU=Sales+CN=Test\\, Inc.,O=Example,C=US").unwrap();
+    assert_eq!(doc.to_string(), "OU=Sales+CN=Test\\, Inc.,O=Example,C=US");
+
+    let name = doc.decode();
+    assert_eq!(name.0.len(), 3);
+    assert_eq!(name.0[2].0.len(), 2);
+    assert_eq!(NameDocument::try_from(&name).unwrap(), doc);
+
+    assert!(NameDocument::from_str("CN").is_err());
+    assert!(NameDocument::from_str("CN=foo\\zz").is_err());
+}
+
+#[test]
+fn rdns_matches() {
+    fn printable(oid: ObjectIdentifier, s: &str) -> AttributeTypeAndValue<'_> {
+        AttributeTypeAndValue {
+            oid,
+            value: Any::from(PrintableString::new(s).unwrap()),
+        }
+    }
+
+    fn name<'a>(rdns: &[&[AttributeTypeAndValue<'a>]]) -> RdnSequence<'a> {
+        RdnSequence(
+            rdns.iter()
+                .map(|rdn| {
+                    RelativeDistinguishedName::from(SetOfVec::try_from(rdn.to_vec()).unwrap())
+                })
+                .collect(),
+        )
+    }
+
+    let cn = const_oid::db::rfc4519::CN;
+    let ou = const_oid::db::rfc4519::OU;
+    let c = const_oid::db::rfc4519::C;
+
+    let a = name(&[&[printable(c, "US")], &[printable(cn, "Good  CA")]]);
+    let b = name(&[&[printable(c, "us")], &[printable(cn, " good ca ")]]);
+    assert!(a.matches(&b));
+    assert!(b.matches(&a));
+    assert_ne!(a, b);
+
+    // Values of other types must be identical
+    let utf8 = |s| AttributeTypeAndValue {
+        oid: cn,
+        value: Any::from(Utf8String::new(s).unwrap()),
+    };
+    assert!(name(&[&[utf8("Good CA")]]).matches(&name(&[&[utf8("Good CA")]])));
+    assert!(!name(&[&[utf8("Good CA")]]).matches(&name(&[&[utf8("good ca")]])));
+    assert!(!name(&[&[utf8("Good CA")]]).matches(&name(&[&[printable(cn, "Good CA")]])));
+
+    // RDN order is significant, attribute order within an RDN is not
+    let c = name(&[&[printable(cn, "Good CA")], &[printable(c, "US")]]);
+    assert!(!a.matches(&c));
+    let multi = name(&[&[printable(cn, "A"), printable(ou, "B")]]);
+    let multi_ci = name(&[&[printable(ou, "b"), printable(cn, "a")]]);
+    assert!(multi.matches(&multi_ci));
+    assert!(!multi.matches(&name(&[&[printable(cn, "A")]])));
+}
@@ -0,0 +1,138 @@
+//! OCSP tests
+
+use der::asn1::{GeneralizedTime, ObjectIdentifier};
+use der::{Decode, Encode};
+use hex_literal::hex;
+use x509_cert::ext::pkix::CrlReason;
+use x509_cert::ocsp::{
+    CertStatus, OcspRequest, OcspResponse, OcspResponseStatus, ResponderId, Version,
+};
+
+#[cfg(feature = "digest")]
+use {
+    der::asn1::Any,
+    spki::AlgorithmIdentifier,
+    x509_cert::{Certificate, OcspRequestBuilder},
+};
+
+/// `id-sha1` Object Identifier (OID)
+#[cfg(feature = "digest")]
+const SHA1_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.14.3.2.26");
+
+const NAME_HASH: [u8; 20] = hex!("6380FD4FDAE6B5A6CDCD725A3BC9FF20E2C1B01F");
+const KEY_HASH: [u8; 20] = hex!("DB5F7C75F745672B47C16E10039DE795747CFDDD");
+const NONCE: [u8; 16] = hex!("BC380DB60555BE0CC49EADE4D4F348A0");
+
+#[test]
+fn decode_request() {
+    let der_encoded_req = include_bytes!("examples/ocsp-req.der");
+    let req = OcspRequest::from_der(der_encoded_req).unwrap();
+    let tbs = &req.tbs_request;
+
+    assert_eq!(tbs.version, Version::V1);
+    assert!(tbs.requestor_name.is_none());
+    assert!(req.optional_signature.is_none());
+    assert_eq!(tbs.request_list.len(), 2);
+
+    let cert_id = &tbs.request_list[0].req_cert;
+    assert_eq!(
+        cert_id.hash_algorithm.oid,
+        ObjectIdentifier::new_unwrap("1.3.14.3.2.26")
+    );
+    assert_eq!(cert_id.issuer_name_hash.as_bytes(), NAME_HASH);
+    assert_eq!(cert_id.issuer_key_hash.as_bytes(), KEY_HASH);
+    assert_eq!(cert_id.serial_number.as_bytes(), &[0x10, 0x01]);
+    assert_eq!(
+        tbs.request_list[1].req_cert.serial_number.as_bytes(),
+        &[0x10, 0x02]
+    );
+
+    assert_eq!(tbs.nonce().unwrap().unwrap().0.as_bytes(), NONCE);
+    assert_eq!(req.to_vec().unwrap(), der_encoded_req);
+}
+
+#[test]
+fn decode_response() {
+    let der_encoded_resp = include_bytes!("examples/ocsp-resp.der");
+    let resp = OcspResponse::from_der(der_encoded_resp).unwrap();
+    assert_eq!(resp.response_status, OcspResponseStatus::Successful);
+    assert_eq!(resp.to_vec().unwrap(), der_encoded_resp);
+
+    let basic = resp.basic_response().unwrap();
+    let data = &basic.tbs_response_data;
+    assert_eq!(data.version, Version::V1);
+    match &data.responder_id {
+        ResponderId::ByName(name) => {
+            assert_eq!(name.to_string(), "CN=Example CA,O=Example,C=US")
+        }
+        other => panic!("unexpected responder id: {:?}", other),
+    }
+    assert_eq!(data.nonce().unwrap().unwrap().0.as_bytes(), NONCE);
+    assert_eq!(basic.certs.as_ref().unwrap().len(), 1);
+
+    assert_eq!(data.responses.len(), 2);
+    let good = &data.responses[0];
+    assert_eq!(good.cert_id.serial_number.as_bytes(), &[0x10, 0x01]);
+    assert_eq!(good.cert_id.issuer_name_hash.as_bytes(), NAME_HASH);
+    assert!(matches!(good.cert_status, CertStatus::Good(_)));
+    assert!(good.next_update.is_some());
+
+    let revoked = &data.responses[1];
+    assert_eq!(revoked.cert_id.serial_number.as_bytes(), &[0x10, 0x02]);
+    match revoked.cert_status {
+        CertStatus::Revoked(info) => {
+            assert_eq!(
+                info.revocation_time,
+                GeneralizedTime::from_der(&hex!("180F32303232303130313030303030305A")).unwrap()
+            );
+            assert_eq!(info.revocation_reason, Some(CrlReason::KeyCompromise));
+        }
+        other => panic!("unexpected status: {:?}", other),
+    }
+
+    assert_eq!(
+        basic.to_vec().unwrap(),
+        resp.response_bytes.unwrap().response.as_bytes()
+    );
+}
+
+#[test]
+fn decode_unsuccessful_response() {
+    // tryLater
+    let resp = OcspResponse::from_der(&hex!("30030A0103")).unwrap();
+    assert_eq!(resp.response_status, OcspResponseStatus::TryLater);
+    assert!(resp.response_bytes.is_none());
+    assert!(resp.basic_response().is_err());
+
+    // (4) is not used
+    assert!(OcspResponse::from_der(&hex!("30030A0104")).is_err());
+}
+
+#[cfg(feature = "digest")]
+#[test]
+fn build_request() {
+    let issuer = Certificate::from_der(include_bytes!("examples/ocsp-ca.der")).unwrap();
+    let good = Certificate::from_der(include_bytes!("examples/ocsp-good.der")).unwrap();
+    let revoked = Certificate::from_der(include_bytes!("examples/ocsp-revoked.der")).unwrap();
+
+    let sha1 = AlgorithmIdentifier {
+        oid: SHA1_OID,
+        parameters: Some(Any::NULL),
+    };
+
+    let req = OcspRequestBuilder::new()
+        .add_cert::<sha1::Sha1>(sha1, &good, &issuer)
+        .unwrap()
+        .add_cert::<sha1::Sha1>(sha1, &revoked, &issuer)
+        .unwrap()
+        .nonce(&NONCE)
+        .build()
+        .unwrap();
+
+    assert_eq!(req, include_bytes!("examples/ocsp-req.der"));
+
+    // The issuer of the certificate must match the subject of the issuer
+    assert!(OcspRequestBuilder::new()
+        .add_cert::<sha1::Sha1>(sha1, &issuer, &good)
+        .is_err());
+}
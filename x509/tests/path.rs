@@ -0,0 +1,360 @@
+//! Certification path building tests
+
+use core::time::Duration;
+use der::asn1::{BitString, ObjectIdentifier, OctetString, UIntBytes, UtcTime};
+use der::{Decode, Encode};
+use hex_literal::hex;
+use spki::{AlgorithmIdentifier, SubjectPublicKeyInfo};
+use x509_cert::builder::CertificateBuilder;
+use x509_cert::ext::pkix::{AuthorityKeyIdentifier, SubjectKeyIdentifier};
+use x509_cert::ext::AsExtension;
+use x509_cert::name::Name;
+use x509_cert::path::{CertificatePool, MAX_PATHS, MAX_PATH_LEN};
+use x509_cert::time::{Time, Validity};
+use x509_cert::Certificate;
+
+/// `id-Ed25519` Object Identifier (OID)
+const ED25519_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+
+/// Ed25519 public key
+const ED25519_PUBLIC_KEY: &[u8] =
+    &hex!("4D29167F3F1912A6F7ADFA293A051A15C05EC67B8F17267B1C5550DCE853BD0D");
+
+fn ed25519_algorithm_id() -> AlgorithmIdentifier<'static> {
+    AlgorithmIdentifier {
+        oid: ED25519_OID,
+        parameters: None,
+    }
+}
+
+fn time(secs: u64) -> Time {
+    UtcTime::from_unix_duration(Duration::from_secs(secs))
+        .unwrap()
+        .into()
+}
+
+fn validity(not_before: u64, not_after: u64) -> Validity {
+    Validity {
+        not_before: time(not_before),
+        not_after: time(not_after),
+    }
+}
+
+/// Build a DER encoded certificate with a dummy signature.
+fn certificate(
+    subject: &str,
+    issuer: &str,
+    ski: Option<&[u8]>,
+    aki: Option<&[u8]>,
+    validity: Validity,
+) -> Vec<u8> {
+    let subject = Name::encode_from_string(subject).unwrap();
+    let issuer = Name::encode_from_string(issuer).unwrap();
+    let mut ski_buf = Vec::new();
+    let mut aki_buf = Vec::new();
+
+    let mut builder = CertificateBuilder::new(
+        UIntBytes::new(&[0x01]).unwrap(),
+        ed25519_algorithm_id(),
+        Name::from_der(&issuer).unwrap(),
+        validity,
+        Name::from_der(&subject).unwrap(),
+        SubjectPublicKeyInfo {
+            algorithm: ed25519_algorithm_id(),
+            subject_public_key: ED25519_PUBLIC_KEY,
+        },
+    );
+
+    if let Some(ski) = ski {
+        let ski = SubjectKeyIdentifier(OctetString::new(ski).unwrap());
+        builder = builder
+            .extension(ski.to_extension(&mut ski_buf).unwrap())
+            .unwrap();
+    }
+
+    if let Some(aki) = aki {
+        let aki = AuthorityKeyIdentifier {
+            key_identifier: Some(OctetString::new(aki).unwrap()),
+            authority_cert_issuer: None,
+            authority_cert_serial_number: None,
+        };
+        builder = builder
+            .extension(aki.to_extension(&mut aki_buf).unwrap())
+            .unwrap();
+    }
+
+    builder
+        .build(BitString::from_bytes(&[0x42; 64]).unwrap())
+        .to_vec()
+        .unwrap()
+}
+
+fn long_validity() -> Validity {
+    validity(1_600_000_000, 1_900_000_000)
+}
+
+/// Get the subject names of each certificate in each path.
+fn subjects(paths: &[x509_cert::path::CertificatePath<'_, '_>]) -> Vec<Vec<String>> {
+    paths
+        .iter()
+        .map(|path| {
+            path.certificates()
+                .iter()
+                .map(|c| c.tbs_certificate.subject.to_string())
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn build_simple_path() {
+    let root = certificate(
+        "CN=Root",
+        "CN=Root",
+        Some(&[1]),
+        Some(&[1]),
+        long_validity(),
+    );
+    let int = certificate("CN=Int", "CN=Root", Some(&[2]), Some(&[1]), long_validity());
+    let leaf = certificate("CN=Leaf", "CN=Int", Some(&[3]), Some(&[2]), long_validity());
+
+    let root = Certificate::from_der(&root).unwrap();
+    let int = Certificate::from_der(&int).unwrap();
+    let leaf = Certificate::from_der(&leaf).unwrap();
+
+    let anchors = CertificatePool::from_iter([root.clone()]);
+    let mut pool = CertificatePool::new();
+    pool.add(int.clone());
+    pool.add(int.clone());
+    assert_eq!(pool.len(), 1);
+    assert!(pool.contains(&int));
+    assert!(!pool.contains(&leaf));
+    assert_eq!(pool.by_key_id(&[2]).count(), 1);
+
+    let paths = pool.build_path(&leaf, &anchors, None);
+    assert_eq!(subjects(&paths), [["CN=Leaf", "CN=Int", "CN=Root"]]);
+    assert_eq!(paths[0].leaf(), Some(&leaf));
+    assert_eq!(paths[0].trust_anchor(), Some(&root));
+
+    let pki_path = paths[0].to_pki_path();
+    assert_eq!(pki_path.0, [root.clone(), int, leaf.clone()]);
+
+    // A trust anchor is a path by itself
+    let paths = pool.build_path(&root, &anchors, None);
+    assert_eq!(subjects(&paths), [["CN=Root"]]);
+
+    // No path without the intermediate
+    assert!(CertificatePool::new()
+        .build_path(&leaf, &anchors, None)
+        .is_empty());
+}
+
+#[test]
+fn build_multiple_paths() {
+    let root_a = certificate("CN=Root A", "CN=Root A", Some(&[1]), None, long_validity());
+    let root_b = certificate("CN=Root B", "CN=Root B", Some(&[2]), None, long_validity());
+    let int = certificate(
+        "CN=Int",
+        "CN=Root A",
+        Some(&[3]),
+        Some(&[1]),
+        long_validity(),
+    );
+    let cross = certificate(
+        "CN=Root A",
+        "CN=Root B",
+        Some(&[1]),
+        Some(&[2]),
+        long_validity(),
+    );
+    let leaf = certificate("CN=Leaf", "CN=Int", None, Some(&[3]), long_validity());
+
+    let anchors = [&root_a, &root_b]
+        .iter()
+        .map(|der| Certificate::from_der(der).unwrap())
+        .collect::<CertificatePool<'_>>();
+    let pool = [&int, &cross]
+        .iter()
+        .map(|der| Certificate::from_der(der).unwrap())
+        .collect::<CertificatePool<'_>>();
+    let leaf = Certificate::from_der(&leaf).unwrap();
+
+    let paths = pool.build_path(&leaf, &anchors, None);
+    assert_eq!(
+        subjects(&paths),
+        [
+            vec!["CN=Leaf", "CN=Int", "CN=Root A"],
+            vec!["CN=Leaf", "CN=Int", "CN=Root A", "CN=Root B"],
+        ]
+    );
+}
+
+#[test]
+fn filter_by_validity() {
+    let root = certificate("CN=Root", "CN=Root", None, None, validity(1_000, 2_000));
+    let old = certificate(
+        "CN=Int",
+        "CN=Root",
+        Some(&[1]),
+        None,
+        validity(1_000, 1_500),
+    );
+    let new = certificate(
+        "CN=Int",
+        "CN=Root",
+        Some(&[2]),
+        None,
+        validity(1_400, 2_000),
+    );
+    let leaf = certificate("CN=Leaf", "CN=Int", None, None, validity(1_000, 2_000));
+
+    let anchors = CertificatePool::from_iter([Certificate::from_der(&root).unwrap()]);
+    let old = Certificate::from_der(&old).unwrap();
+    let new = Certificate::from_der(&new).unwrap();
+    let pool = CertificatePool::from_iter([old.clone(), new.clone()]);
+    let leaf = Certificate::from_der(&leaf).unwrap();
+
+    assert_eq!(pool.build_path(&leaf, &anchors, None).len(), 2);
+
+    let paths = pool.build_path(&leaf, &anchors, Some(time(1_200)));
+    assert_eq!(paths.len(), 1);
+    assert_eq!(paths[0].certificates()[1], &old);
+
+    let paths = pool.build_path(&leaf, &anchors, Some(time(1_800)));
+    assert_eq!(paths.len(), 1);
+    assert_eq!(paths[0].certificates()[1], &new);
+
+    // Trust anchors are exempt, but the leaf isn't
+    assert_eq!(pool.build_path(&leaf, &anchors, Some(time(1_450))).len(), 2);
+    assert!(pool
+        .build_path(&leaf, &anchors, Some(time(2_500)))
+        .is_empty());
+}
+
+#[test]
+fn match_key_identifiers() {
+    let root = certificate("CN=Root", "CN=Root", Some(&[1]), None, long_validity());
+    let other = certificate("CN=Root", "CN=Root", Some(&[2]), None, long_validity());
+    let no_ski = certificate("CN=Root", "CN=Root", None, None, long_validity());
+    let leaf = certificate("CN=Leaf", "CN=Root", None, Some(&[1]), long_validity());
+
+    let root = Certificate::from_der(&root).unwrap();
+    let anchors = [&other, &no_ski]
+        .iter()
+        .map(|der| Certificate::from_der(der).unwrap())
+        .chain([root.clone()])
+        .collect::<CertificatePool<'_>>();
+    let leaf = Certificate::from_der(&leaf).unwrap();
+
+    assert_eq!(anchors.by_subject(&leaf.tbs_certificate.issuer).count(), 3);
+
+    // Issuers without a `SubjectKeyIdentifier` are matched by name alone
+    let pool = CertificatePool::new();
+    let paths = pool.build_path(&leaf, &anchors, None);
+    assert_eq!(paths.len(), 2);
+    assert!(paths
+        .iter()
+        .all(|path| path.trust_anchor() != anchors.by_key_id(&[2]).next()));
+    assert!(paths.iter().any(|path| path.trust_anchor() == Some(&root)));
+}
+
+#[test]
+fn match_names_case_insensitively() {
+    // `PrintableString` values
+    let root = certificate(
+        "CN=#130D4578616D706C652020526F6F74",
+        "CN=#130D4578616D706C652020526F6F74",
+        None,
+        None,
+        long_validity(),
+    );
+    let leaf = certificate(
+        "CN=Leaf",
+        "CN=#130C6578616D706C6520726F6F74",
+        None,
+        None,
+        long_validity(),
+    );
+
+    let anchors = CertificatePool::from_iter([Certificate::from_der(&root).unwrap()]);
+    let leaf = Certificate::from_der(&leaf).unwrap();
+    let pool = CertificatePool::new();
+
+    assert_eq!(pool.build_path(&leaf, &anchors, None).len(), 1);
+}
+
+#[test]
+fn avoid_loops() {
+    let root = certificate("CN=Root", "CN=Root", None, None, long_validity());
+    let a = certificate("CN=A", "CN=B", None, None, long_validity());
+    let b = certificate("CN=B", "CN=A", None, None, long_validity());
+    let leaf = certificate("CN=Leaf", "CN=A", None, None, long_validity());
+
+    let anchors = CertificatePool::from_iter([Certificate::from_der(&root).unwrap()]);
+    let pool = [&a, &b]
+        .iter()
+        .map(|der| Certificate::from_der(der).unwrap())
+        .collect::<CertificatePool<'_>>();
+    let leaf = Certificate::from_der(&leaf).unwrap();
+
+    assert!(pool.build_path(&leaf, &anchors, None).is_empty());
+}
+
+#[test]
+fn limit_path_length() {
+    let names = (0..=MAX_PATH_LEN)
+        .map(|i| format!("CN={}", i))
+        .collect::<Vec<_>>();
+
+    // Certificate `i` is issued by `i + 1`, with the last being the root
+    let ders = (0..=MAX_PATH_LEN)
+        .map(|i| {
+            let issuer = names.get(i + 1).unwrap_or(&names[i]);
+            certificate(&names[i], issuer, None, None, long_validity())
+        })
+        .collect::<Vec<_>>();
+    let certs = ders
+        .iter()
+        .map(|der| Certificate::from_der(der).unwrap())
+        .collect::<Vec<_>>();
+
+    let anchors = CertificatePool::from_iter([certs[MAX_PATH_LEN].clone()]);
+    let pool = certs[1..MAX_PATH_LEN]
+        .iter()
+        .cloned()
+        .collect::<CertificatePool<'_>>();
+
+    // Path from certificate 0 would contain `MAX_PATH_LEN + 1` certificates
+    assert!(pool.build_path(&certs[0], &anchors, None).is_empty());
+
+    let paths = pool.build_path(&certs[1], &anchors, None);
+    assert_eq!(paths.len(), 1);
+    assert_eq!(paths[0].len(), MAX_PATH_LEN);
+}
+
+#[test]
+fn limit_cross_certificates() {
+    // Cross-certificates which share a name can all issue one another, so the
+    // number of possible paths grows factorially without search limits
+    let ders = (0..20)
+        .map(|i| certificate("CN=CA", "CN=CA", None, None, validity(i, 1_900_000_000)))
+        .collect::<Vec<_>>();
+    let pool = ders
+        .iter()
+        .map(|der| Certificate::from_der(der).unwrap())
+        .collect::<CertificatePool<'_>>();
+    assert_eq!(pool.len(), 20);
+
+    let leaf = certificate("CN=Leaf", "CN=CA", None, None, long_validity());
+    let leaf = Certificate::from_der(&leaf).unwrap();
+    let root = certificate("CN=Root", "CN=Root", None, None, long_validity());
+    let anchors = CertificatePool::from_iter([Certificate::from_der(&root).unwrap()]);
+    assert!(pool.build_path(&leaf, &anchors, None).is_empty());
+
+    let root = certificate("CN=CA", "CN=Root", None, None, long_validity());
+    let anchors = CertificatePool::from_iter([Certificate::from_der(&root).unwrap()]);
+    let paths = pool.build_path(&leaf, &anchors, None);
+    assert_eq!(paths.len(), MAX_PATHS);
+    assert_eq!(paths[0].len(), 2);
+    assert!(paths[1..].iter().all(|path| path.len() == 3));
+}
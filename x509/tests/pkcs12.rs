@@ -0,0 +1,290 @@
+//! PKCS#12 tests
+
+#![cfg(feature = "pkcs12")]
+
+use der::asn1::{Any, OctetString};
+use der::{Decode, Document, Encode, ErrorKind};
+use pkcs8::pkcs5::{self, pbes2};
+use spki::AlgorithmIdentifier;
+use x509_cert::cms::ContentInfo;
+use x509_cert::pkcs12::{
+    Bag, BagValue, DecryptOptions, Error, Pfx, PfxDocument, Pkcs8ShroudedKeyBag, SafeBag, Version,
+    PKCS_8_SHROUDED_KEY_BAG, SAFE_CONTENTS_BAG,
+};
+
+const PASSWORD: &str = "hunter2";
+const FRIENDLY_NAME: &str = "good.example.com";
+
+/// Check the decrypted bags of a PFX created using `openssl pkcs12 -export`
+/// with a CA certificate.
+fn check_bags(bags: &[Bag]) {
+    let (key, cert, ca) = match bags {
+        [cert, ca, key] => (key, cert, ca),
+        other => panic!("unexpected bags: {:?}", other),
+    };
+
+    match &key.value {
+        BagValue::Key(doc) => {
+            assert_eq!(doc.as_ref(), include_bytes!("examples/pkcs12-key.der"))
+        }
+        other => panic!("unexpected key bag: {:?}", other),
+    }
+
+    match &cert.value {
+        BagValue::Certificate(doc) => {
+            assert_eq!(doc.as_ref(), include_bytes!("examples/ocsp-good.der"))
+        }
+        other => panic!("unexpected certificate bag: {:?}", other),
+    }
+
+    match &ca.value {
+        BagValue::Certificate(doc) => {
+            assert_eq!(doc.as_ref(), include_bytes!("examples/ocsp-ca.der"))
+        }
+        other => panic!("unexpected certificate bag: {:?}", other),
+    }
+
+    // The key and its certificate share a `localKeyId`
+    assert_eq!(key.friendly_name.as_deref(), Some(FRIENDLY_NAME));
+    assert_eq!(cert.friendly_name.as_deref(), Some(FRIENDLY_NAME));
+    assert!(key.local_key_id.is_some());
+    assert_eq!(key.local_key_id, cert.local_key_id);
+    assert!(ca.friendly_name.is_none());
+    assert!(ca.local_key_id.is_none());
+}
+
+#[test]
+fn decrypt_pbes2() {
+    // AES-256-CBC with PBKDF2-HMAC-SHA256 and an HMAC-SHA256 MAC
+    let der_encoded_pfx = include_bytes!("examples/pkcs12-modern.p12");
+    let pfx = Pfx::from_der(der_encoded_pfx).unwrap();
+    assert_eq!(pfx.version, Version::V3);
+
+    let mac_data = pfx.mac_data.as_ref().unwrap();
+    assert_eq!(mac_data.iterations, 2048);
+    assert_eq!(
+        mac_data.mac.digest_algorithm.oid,
+        const_oid::db::rfc5912::ID_SHA_256
+    );
+
+    pfx.verify_mac(PASSWORD).unwrap();
+    check_bags(&pfx.decrypt(PASSWORD).unwrap());
+}
+
+#[test]
+fn decrypt_pkcs12_pbe() {
+    // Certificates and key encrypted with 3-key 3DES, HMAC-SHA1 MAC
+    let pfx = Pfx::from_der(include_bytes!("examples/pkcs12-legacy-3des.p12")).unwrap();
+    check_bags(&pfx.decrypt(PASSWORD).unwrap());
+
+    // Certificates encrypted with 40-bit RC2, key with 3-key 3DES
+    let pfx = Pfx::from_der(include_bytes!("examples/pkcs12-legacy-rc2.p12")).unwrap();
+    check_bags(&pfx.decrypt(PASSWORD).unwrap());
+}
+
+#[test]
+fn decrypt_unencrypted() {
+    let doc = PfxDocument::from_ber(include_bytes!("examples/pkcs12-plain.p12")).unwrap();
+    let bags = doc.decode().decrypt(PASSWORD).unwrap();
+
+    match &bags[..] {
+        [Bag {
+            value: BagValue::Certificate(cert),
+            ..
+        }, Bag {
+            value: BagValue::Key(key),
+            friendly_name,
+            ..
+        }] => {
+            assert_eq!(cert.as_ref(), include_bytes!("examples/ocsp-good.der"));
+            assert_eq!(key.as_ref(), include_bytes!("examples/pkcs12-key.der"));
+            assert_eq!(friendly_name.as_deref(), Some(FRIENDLY_NAME));
+        }
+        other => panic!("unexpected bags: {:?}", other),
+    }
+}
+
+#[test]
+fn wrong_password() {
+    for der_encoded_pfx in [
+        &include_bytes!("examples/pkcs12-modern.p12")[..],
+        include_bytes!("examples/pkcs12-legacy-3des.p12"),
+        include_bytes!("examples/pkcs12-plain.p12"),
+    ] {
+        let pfx = Pfx::from_der(der_encoded_pfx).unwrap();
+        assert_eq!(pfx.verify_mac("hunter3"), Err(Error::MacVerificationFailed));
+        assert_eq!(
+            pfx.decrypt("hunter3").unwrap_err(),
+            Error::MacVerificationFailed
+        );
+    }
+}
+
+#[test]
+fn missing_mac() {
+    let mut pfx = Pfx::from_der(include_bytes!("examples/pkcs12-legacy-3des.p12")).unwrap();
+    pfx.mac_data = None;
+    assert_eq!(pfx.verify_mac(PASSWORD), Err(Error::MacVerificationFailed));
+    assert_eq!(
+        pfx.decrypt(PASSWORD).unwrap_err(),
+        Error::MacVerificationFailed
+    );
+
+    // When explicitly allowed, the password is only checked by decryption
+    let options = DecryptOptions {
+        allow_missing_mac: true,
+        ..Default::default()
+    };
+    check_bags(&pfx.decrypt_with_options(PASSWORD, &options).unwrap());
+    assert!(pfx.decrypt_with_options("hunter3", &options).is_err());
+}
+
+#[test]
+fn max_iterations() {
+    let pfx = Pfx::from_der(include_bytes!("examples/pkcs12-modern.p12")).unwrap();
+    let options = DecryptOptions {
+        max_iterations: 2047,
+        ..Default::default()
+    };
+    let err = Error::IterationCountTooLarge { iterations: 2048 };
+    assert_eq!(pfx.verify_mac_with_options(PASSWORD, &options), Err(err));
+    assert_eq!(
+        pfx.decrypt_with_options(PASSWORD, &options).unwrap_err(),
+        err
+    );
+
+    // The PBKDF2 iteration count of the encrypted contents is checked too
+    let options = DecryptOptions {
+        max_iterations: 2047,
+        allow_missing_mac: true,
+        ..Default::default()
+    };
+    let mut pfx = pfx;
+    pfx.mac_data = None;
+    assert_eq!(
+        pfx.decrypt_with_options(PASSWORD, &options).unwrap_err(),
+        err
+    );
+}
+
+#[test]
+fn max_scrypt_params() {
+    let key = include_bytes!("examples/pkcs12-key.der");
+    let params = pbes2::Parameters {
+        kdf: pbes2::ScryptParams {
+            salt: b"saltsalt",
+            cost_parameter: 1024,
+            block_size: 8,
+            parallelization: 1,
+            key_length: None,
+        }
+        .into(),
+        encryption: pbes2::EncryptionScheme::Aes256Cbc { iv: &[0x42; 16] },
+    };
+    let scheme = pkcs5::EncryptionScheme::from(params);
+    let ciphertext = scheme.encrypt(PASSWORD, key).unwrap();
+
+    let algorithm = scheme.to_vec().unwrap();
+    let shrouded = Pkcs8ShroudedKeyBag {
+        encryption_algorithm: AlgorithmIdentifier::from_der(&algorithm).unwrap(),
+        encrypted_data: OctetString::new(&ciphertext).unwrap(),
+    }
+    .to_vec()
+    .unwrap();
+    let safe_contents = vec![SafeBag {
+        bag_id: PKCS_8_SHROUDED_KEY_BAG,
+        bag_value: Any::from_der(&shrouded).unwrap(),
+        bag_attributes: None,
+    }]
+    .to_vec()
+    .unwrap();
+    let auth_safe = vec![ContentInfo::new_data(&safe_contents)]
+        .to_vec()
+        .unwrap();
+    let pfx = Pfx {
+        version: Version::V3,
+        auth_safe: ContentInfo::new_data(&auth_safe),
+        mac_data: None,
+    };
+
+    let options = DecryptOptions {
+        allow_missing_mac: true,
+        ..Default::default()
+    };
+    match &pfx.decrypt_with_options(PASSWORD, &options).unwrap()[..] {
+        [Bag {
+            value: BagValue::Key(doc),
+            ..
+        }] => assert_eq!(doc.as_ref(), key),
+        other => panic!("unexpected bags: {:?}", other),
+    }
+
+    // 128 * r * (N + p) = 1049600 bytes
+    let options = DecryptOptions {
+        allow_missing_mac: true,
+        max_scrypt_memory: 1 << 20,
+        ..Default::default()
+    };
+    assert_eq!(
+        pfx.decrypt_with_options(PASSWORD, &options).unwrap_err(),
+        Error::ScryptParamsTooLarge
+    );
+
+    // N * r * p = 8192
+    let options = DecryptOptions {
+        allow_missing_mac: true,
+        max_scrypt_cost: 8191,
+        ..Default::default()
+    };
+    assert_eq!(
+        pfx.decrypt_with_options(PASSWORD, &options).unwrap_err(),
+        Error::ScryptParamsTooLarge
+    );
+}
+
+#[test]
+fn nested_safe_contents() {
+    /// Encode a `SafeContents` nested in `depth` `safeContentsBag`s.
+    fn nested_safe_contents(depth: usize) -> Vec<u8> {
+        let mut safe_contents = Vec::<SafeBag<'_>>::new().to_vec().unwrap();
+
+        for _ in 0..depth {
+            let bag = SafeBag {
+                bag_id: SAFE_CONTENTS_BAG,
+                bag_value: Any::from_der(&safe_contents).unwrap(),
+                bag_attributes: None,
+            };
+            safe_contents = vec![bag].to_vec().unwrap();
+        }
+
+        safe_contents
+    }
+
+    let options = DecryptOptions {
+        allow_missing_mac: true,
+        ..Default::default()
+    };
+
+    for (depth, ok) in [(8, true), (9, false)] {
+        let safe_contents = nested_safe_contents(depth);
+        let auth_safe = vec![ContentInfo::new_data(&safe_contents)]
+            .to_vec()
+            .unwrap();
+        let pfx = Pfx {
+            version: Version::V3,
+            auth_safe: ContentInfo::new_data(&auth_safe),
+            mac_data: None,
+        };
+
+        match pfx.decrypt_with_options(PASSWORD, &options) {
+            Ok(bags) => assert!(ok && bags.is_empty()),
+            Err(err) => assert_eq!(
+                (ok, err),
+                (
+                    false,
+                    Error::Asn1(ErrorKind::DepthLimit { max_depth: 8 }.into())
+                )
+            ),
+        }
+    }
+}
@@ -5,10 +5,11 @@ use der::{Decode, Encode, ErrorKind, Length, Tag, Tagged};
 use hex_literal::hex;
 use x509_cert::ext::pkix::crl::dp::{DistributionPoint, ReasonFlags, Reasons};
 use x509_cert::ext::pkix::name::{DistributionPointName, GeneralName, GeneralNames};
+use x509_cert::ext::pkix::sct::{HashAlgorithm, SctVersion, SerializedSct, SignatureAlgorithm};
 use x509_cert::ext::pkix::*;
 use x509_cert::ext::Extensions;
 use x509_cert::name::Name;
-use x509_cert::{Certificate, Version};
+use x509_cert::{Certificate, Profile, Version};
 
 use const_oid::db::rfc5280::*;
 use const_oid::db::rfc5912::ID_CE_CERTIFICATE_POLICIES;
@@ -106,6 +107,11 @@ fn spin_over_exts(exts: Extensions) {
                 assert_eq!(ext.extn_value, decoded.to_vec().unwrap());
             }
 
+            SignedCertificateTimestampList::OID => {
+                let decoded = SignedCertificateTimestampList::from_der(ext.extn_value).unwrap();
+                assert_eq!(ext.extn_value, decoded.to_vec().unwrap());
+            }
+
             _ => {
                 eprintln!("ignoring {} with criticality {}", ext.extn_id, ext.critical);
             }
@@ -1090,11 +1096,22 @@ fn decode_idp() {
     let err = idp.err().unwrap();
     assert_eq!(ErrorKind::Noncanonical { tag: Tag::Boolean }, err.kind());
 
-    // Tag on second RDN in first name is TeletexString (20) instead of PrintableString (19) (and TeletexString is not supported)
+    // Tag on second RDN in first name is TeletexString (20) instead of PrintableString (19)
+    // (TeletexString is decoded, but not permitted by the RFC 5280 profile)
     let idp =
         IssuingDistributionPoint::from_der(&hex!("30820168A0820161A082015DA4753073310B3009060355040613025553311F301D060355040A14165465737420436572746966696361746573203230313731183016060355040B130F696E64697265637443524C204341353129302706035504031320696E6469726563742043524C20666F7220696E64697265637443524C20434136A4753073310B3009060355040613025553311F301D060355040A13165465737420436572746966696361746573203230313731183016060355040B130F696E64697265637443524C204341353129302706035504031320696E6469726563742043524C20666F7220696E64697265637443524C20434137A46D306B310B3009060355040613025553311F301D060355040A13165465737420436572746966696361746573203230313731183016060355040B130F696E64697265637443524C204341353121301F0603550403131843524C3120666F7220696E64697265637443524C204341358401FF"));
-    let err = idp.err().unwrap();
-    assert_eq!(ErrorKind::TagUnknown { byte: 20u8.into() }, err.kind());
+    let idp = idp.unwrap();
+    match idp.distribution_point.unwrap() {
+        DistributionPointName::FullName(gns) => match &gns[0] {
+            GeneralName::DirectoryName(name) => {
+                assert_eq!(name.0[1].0.get(0).unwrap().value.tag(), Tag::TeletexString);
+                assert!(name.check_profile(Profile::Rfc5280).is_err());
+                assert!(name.check_profile(Profile::Lenient).is_ok());
+            }
+            _ => panic!("Expected DirectoryName"),
+        },
+        _ => panic!("Expected FullName"),
+    }
 
     // Length on second RDN in first name indicates more bytes than are present
     let idp =
@@ -1107,3 +1124,82 @@ fn decode_idp() {
         err.kind()
     );
 }
+
+#[test]
+fn decode_sct_list() {
+    let der_encoded_cert = include_bytes!("examples/amazon.der");
+    let cert = Certificate::from_der(der_encoded_cert).unwrap();
+    let exts = cert.tbs_certificate.extensions.unwrap();
+    let ext = exts
+        .iter()
+        .find(|ext| ext.extn_id == SignedCertificateTimestampList::OID)
+        .unwrap();
+    assert!(!ext.critical);
+
+    let scts = SignedCertificateTimestampList::from_der(ext.extn_value).unwrap();
+    assert_eq!(scts.0.len(), 3);
+    assert_eq!(ext.extn_value, scts.to_vec().unwrap());
+
+    let parsed: Vec<_> = scts.scts().collect();
+    assert_eq!(parsed.len(), 3);
+
+    let sct = parsed[0];
+    assert_eq!(sct.version, SctVersion::V1);
+    assert_eq!(
+        sct.log_id,
+        hex!("2979BEF09E393921F056739F63A577E5BE577D9C600AF8F94D5D265C255DC784")
+    );
+    // Oct  6 02:59:08.091 2021 GMT
+    assert_eq!(sct.timestamp, 1_633_489_148_091);
+    assert_eq!(sct.unix_duration().as_millis(), 1_633_489_148_091);
+    assert!(sct.extensions.is_empty());
+    assert_eq!(sct.signature.hash_algorithm, HashAlgorithm::Sha256);
+    assert_eq!(sct.signature.signature_algorithm, SignatureAlgorithm::Ecdsa);
+    assert_eq!(sct.signature.signature.len(), 0x47);
+    assert_eq!(&sct.signature.signature[..4], &hex!("30450221"));
+
+    assert_eq!(
+        parsed[1].log_id,
+        hex!("51A3B0F5FD01799C566DB837788F0CA47ACC1B27CBF79E88429A0DFED48B05E5")
+    );
+    assert_eq!(parsed[2].timestamp, 1_633_489_148_063);
+
+    // The inner TLS encoding is length-prefixed
+    let tls = scts.to_tls().unwrap();
+    assert_eq!(
+        usize::from(u16::from_be_bytes([tls[0], tls[1]])),
+        tls.len() - 2
+    );
+    assert_eq!(
+        SignedCertificateTimestampList::from_tls(&tls).unwrap(),
+        scts
+    );
+
+    // Truncated and trailing data, and an empty list
+    assert!(SignedCertificateTimestampList::from_tls(&tls[..tls.len() - 1]).is_err());
+    let mut trailing = tls.clone();
+    trailing.push(0);
+    assert!(SignedCertificateTimestampList::from_tls(&trailing).is_err());
+    assert!(SignedCertificateTimestampList::from_tls(&[0, 0]).is_err());
+    assert!(SignedCertificateTimestampList::default().to_vec().is_err());
+
+    // An SCT of an unknown version doesn't prevent using the others
+    let unknown = [1, 0xaa, 0xbb];
+    let mut with_unknown = scts.clone();
+    with_unknown.0.insert(1, SerializedSct::Unknown(&unknown));
+    let tls = with_unknown.to_tls().unwrap();
+    let decoded = SignedCertificateTimestampList::from_tls(&tls).unwrap();
+    assert_eq!(decoded, with_unknown);
+    assert_eq!(decoded.0[1], SerializedSct::Unknown(&unknown));
+    assert_eq!(decoded.scts().collect::<Vec<_>>(), parsed);
+
+    let der = with_unknown.to_vec().unwrap();
+    assert_eq!(der.len(), tls.len() + 4);
+    assert_eq!(
+        SignedCertificateTimestampList::from_der(&der).unwrap(),
+        with_unknown
+    );
+
+    // An empty `SerializedSCT` is still rejected
+    assert!(SignedCertificateTimestampList::from_tls(&[0, 2, 0, 0]).is_err());
+}
@@ -0,0 +1,152 @@
+//! Time-Stamp Protocol tests
+
+use const_oid::db::rfc5912::ID_SHA_256;
+use der::{DateTime, Decode, Encode};
+use hex_literal::hex;
+use std::time::Duration;
+use x509_cert::cms::ContentInfo;
+use x509_cert::ext::pkix::name::GeneralName;
+use x509_cert::tsp::{Accuracy, GenTime, PkiStatus, TimeStampReq, TimeStampResp, Version};
+
+/// SHA-256 digest of `Hello, world!\n`
+const MESSAGE_DIGEST: [u8; 32] =
+    hex!("d9014c4624844aa5bac314773d6b689ad467fa4e1d1a50a1b8a99d5a95f72ff5");
+
+const NONCE: [u8; 8] = hex!("B1CBCE0493705E77");
+
+#[test]
+fn decode_request() {
+    // Created using `openssl ts -query -sha256 -cert`
+    let der_encoded_req = include_bytes!("examples/tsp-req.der");
+    let req = TimeStampReq::from_der(der_encoded_req).unwrap();
+    assert_eq!(req.version, Version::V1);
+    assert_eq!(req.message_imprint.hash_algorithm.oid, ID_SHA_256);
+    assert_eq!(
+        req.message_imprint.hashed_message.as_bytes(),
+        MESSAGE_DIGEST
+    );
+    assert!(req.req_policy.is_none());
+    assert_eq!(req.nonce.unwrap().as_bytes(), NONCE);
+    assert!(req.cert_req);
+    assert!(req.extensions.is_none());
+
+    assert_eq!(req.to_vec().unwrap(), der_encoded_req);
+}
+
+#[test]
+fn decode_response() {
+    let der_encoded_resp = include_bytes!("examples/tsp-resp.der");
+    let resp = TimeStampResp::from_der(der_encoded_resp).unwrap();
+    assert_eq!(resp.status.status, PkiStatus::Granted);
+    assert!(resp.status.status_string.is_none());
+    assert!(resp.status.fail_info.is_none());
+
+    let tst_info = resp.tst_info().unwrap();
+    assert_eq!(tst_info.version, Version::V1);
+    assert_eq!(tst_info.policy.to_string(), "1.2.3.4.1");
+    assert_eq!(tst_info.message_imprint.hash_algorithm.oid, ID_SHA_256);
+    assert_eq!(
+        tst_info.message_imprint.hashed_message.as_bytes(),
+        MESSAGE_DIGEST
+    );
+    assert_eq!(tst_info.serial_number.as_bytes(), &[0x02]);
+    assert_eq!(tst_info.nonce.unwrap().as_bytes(), NONCE);
+    assert!(tst_info.ordering);
+    assert!(tst_info.extensions.is_none());
+
+    // `20261016162924.039Z`
+    let gen_time = tst_info.gen_time;
+    assert_eq!(
+        gen_time.to_date_time(),
+        DateTime::new(2026, 10, 16, 16, 29, 24).unwrap()
+    );
+    assert_eq!(gen_time.subsec_nanos(), 39_000_000);
+    assert_eq!(
+        gen_time.to_unix_duration(),
+        Duration::from_millis(1_792_168_164_039)
+    );
+
+    let accuracy = tst_info.accuracy.unwrap();
+    assert_eq!(
+        accuracy,
+        Accuracy {
+            seconds: Some(1),
+            millis: Some(500),
+            micros: Some(100),
+        }
+    );
+    assert_eq!(accuracy.to_duration(), Duration::from_micros(1_500_100));
+
+    match &tst_info.tsa {
+        Some(GeneralName::DirectoryName(name)) => {
+            assert_eq!(name.to_string(), "CN=Example TSA,O=Example,C=US")
+        }
+        other => panic!("unexpected TSA name: {:?}", other),
+    }
+
+    // The encoding of `TSTInfo` is signed, so it must round-trip exactly
+    let e_content = match resp.time_stamp_token.as_ref().unwrap() {
+        ContentInfo::SignedData(Some(signed_data)) => {
+            signed_data.encap_content_info.e_content.unwrap()
+        }
+        other => panic!("unexpected time-stamp token: {:?}", other),
+    };
+    assert_eq!(tst_info.to_vec().unwrap(), e_content.as_bytes());
+    assert_eq!(resp.to_vec().unwrap(), der_encoded_resp);
+}
+
+#[test]
+fn decode_rejected_response() {
+    // SHA-512 is not accepted by the TSA
+    let der_encoded_resp = include_bytes!("examples/tsp-rejected.der");
+    let resp = TimeStampResp::from_der(der_encoded_resp).unwrap();
+    assert_eq!(resp.status.status, PkiStatus::Rejection);
+    assert!(resp.time_stamp_token.is_none());
+    assert!(resp.tst_info().is_err());
+
+    let status_string = resp.status.status_string.as_ref().unwrap();
+    assert_eq!(
+        status_string[0].as_str(),
+        "Message digest algorithm is not supported."
+    );
+
+    // badAlg (0)
+    assert_eq!(resp.status.fail_info.unwrap().raw_bytes(), &[0x80]);
+    assert_eq!(resp.to_vec().unwrap(), der_encoded_resp);
+}
+
+#[test]
+fn gen_time() {
+    let date_time = DateTime::new(2026, 10, 16, 16, 29, 24).unwrap();
+
+    for (der_encoded_time, nanos) in [
+        (&b"\x18\x0f20261016162924Z"[..], 0),
+        (b"\x18\x1120261016162924.5Z", 500_000_000),
+        (b"\x18\x1320261016162924.039Z", 39_000_000),
+        (b"\x18\x1920261016162924.123456789Z", 123_456_789),
+    ] {
+        let time = GenTime::from_der(der_encoded_time).unwrap();
+        assert_eq!(time.to_date_time(), date_time);
+        assert_eq!(time.subsec_nanos(), nanos);
+        assert_eq!(time, GenTime::new(date_time, nanos).unwrap());
+        assert_eq!(time.to_vec().unwrap(), der_encoded_time);
+    }
+
+    // Trailing zeros, empty or too precise fractions, no seconds or zone
+    for der_encoded_time in [
+        &b"\x18\x1120261016162924.50Z"[..],
+        b"\x18\x1020261016162924.Z",
+        b"\x18\x1a20261016162924.1234567891Z",
+        b"\x18\x112026101616292.50Z",
+        b"\x18\x0d202610161629Z",
+        b"\x18\x1020261016162924.5",
+    ] {
+        assert!(GenTime::from_der(der_encoded_time).is_err());
+    }
+
+    assert!(GenTime::new(date_time, 1_000_000_000).is_err());
+
+    let time = GenTime::from_unix_duration(Duration::from_millis(1_792_168_164_039)).unwrap();
+    assert_eq!(time.to_date_time(), date_time);
+    assert_eq!(time.subsec_nanos(), 39_000_000);
+}
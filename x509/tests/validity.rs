@@ -1,8 +1,8 @@
 //! Validity tests
 
-use der::Encode;
+use der::{Decode, Encode};
 use hex_literal::hex;
-use x509_cert::time::Validity;
+use x509_cert::time::{Time, Validity};
 
 #[test]
 fn decode_validity() {
@@ -134,3 +134,49 @@ fn encode_validity() {
         &hex!("3020180F32303032303130313132303130305A170D3330313233313038333030305A")[..]
     );
 }
+
+#[test]
+fn time_conversions() {
+    // GeneralizedTime 01/01/2002 12:01:00 GMT, UTCTime 31/12/2030 08:30:00 GMT
+    let val = Validity::try_from(
+        &hex!("3020180F32303032303130313132303130305A170D3330313233313038333030305A")[..],
+    )
+    .unwrap();
+
+    let utc_time = val.not_before.to_utc_time().unwrap();
+    assert_eq!(utc_time.to_date_time(), val.not_before.to_date_time());
+    assert_eq!(
+        Time::from_date_time(val.not_before.to_date_time()),
+        Time::UtcTime(utc_time)
+    );
+
+    let general_time = val.not_after.to_general_time();
+    assert_eq!(general_time.to_date_time(), val.not_after.to_date_time());
+    assert_eq!(
+        val.not_after.to_utc_time().unwrap(),
+        Time::GeneralTime(general_time).to_utc_time().unwrap()
+    );
+
+    // GeneralizedTime 01/01/2050 12:01:00 GMT
+    let time = Time::from_der(&hex!("180F32303530303130313132303130305A")).unwrap();
+    assert!(time.to_utc_time().is_err());
+    assert_eq!(time.to_general_time().to_date_time(), time.to_date_time());
+    assert_eq!(Time::from_date_time(time.to_date_time()), time);
+}
+
+#[test]
+fn no_well_defined_expiration() {
+    // UTCTime 01/01/2010 08:30:00 GMT, GeneralizedTime 31/12/9999 23:59:59 GMT
+    let der = hex!("3020170D3130303130313038333030305A180F39393939313233313233353935395A");
+
+    let val = Validity::try_from(&der[..]).unwrap();
+    assert!(!val.not_before.is_infinity());
+    assert!(val.not_after.is_infinity());
+    assert_eq!(val.not_after, Time::INFINITY);
+
+    let val = Validity {
+        not_before: val.not_before,
+        not_after: Time::INFINITY,
+    };
+    assert_eq!(val.to_vec().unwrap(), der);
+}